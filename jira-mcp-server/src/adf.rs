@@ -0,0 +1,750 @@
+//! Atlassian Document Format
+//!
+//! JIRA Cloud's v3 API stores rich text such as comments as ADF, a JSON document
+//! tree. [`to_markdown`] renders a document for tool results so agents never see a
+//! raw JSON blob, and [`from_markdown`] builds one from the markdown agents write,
+//! so code blocks and lists survive when a comment is posted as ADF. Node types
+//! without a markdown equivalent keep their text content.
+
+use serde_json::{json, Value};
+
+/// Whether a value is an ADF document
+pub fn is_doc(value: &Value) -> bool {
+    value.get("type").and_then(Value::as_str) == Some("doc")
+}
+
+/// Text of a rich-text field
+///
+/// ADF documents, also when serialized into a string, are rendered to markdown;
+/// wiki markup and plain text are returned as-is.
+pub fn rich_text(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) if text.trim_start().starts_with('{') => {
+            match serde_json::from_str::<Value>(text) {
+                Ok(doc) if is_doc(&doc) => to_markdown(&doc),
+                _ => text.clone(),
+            }
+        }
+        Value::String(text) => text.clone(),
+        value if is_doc(value) => to_markdown(value),
+        value => value.to_string(),
+    }
+}
+
+/// Plain text of a node and everything below it
+///
+/// Sibling blocks are separated by newlines, hard breaks become newlines and
+/// rules "----"; marks are dropped.
+pub fn node_text(node: &Value) -> String {
+    match node_type(node) {
+        "text" => node
+            .get("text")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        "hardBreak" => "\n".to_string(),
+        "rule" => "----".to_string(),
+        _ => {
+            let nodes = children(node);
+            let separator = if nodes.iter().any(|n| n.get("content").is_some()) {
+                "\n"
+            } else {
+                ""
+            };
+            nodes
+                .iter()
+                .map(node_text)
+                .collect::<Vec<_>>()
+                .join(separator)
+        }
+    }
+}
+
+/// Render an ADF document as markdown
+pub fn to_markdown(doc: &Value) -> String {
+    blocks(children(doc))
+}
+
+fn node_type(node: &Value) -> &str {
+    node.get("type").and_then(Value::as_str).unwrap_or_default()
+}
+
+fn children(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a str> {
+    node.get("attrs")?.get(name)?.as_str()
+}
+
+fn is_inline(node: &Value) -> bool {
+    matches!(
+        node_type(node),
+        "text"
+            | "hardBreak"
+            | "mention"
+            | "emoji"
+            | "inlineCard"
+            | "status"
+            | "date"
+            | "mediaInline"
+            | "placeholder"
+            | "inlineExtension"
+    )
+}
+
+/// Block nodes separated by blank lines, skipping empty ones
+fn blocks(nodes: &[Value]) -> String {
+    nodes
+        .iter()
+        .map(block)
+        .filter(|block| !block.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Block nodes on consecutive lines, as inside list items and table cells
+fn tight_blocks(nodes: &[Value]) -> String {
+    if nodes.iter().all(is_inline) {
+        return inline(nodes);
+    }
+    nodes
+        .iter()
+        .map(block)
+        .filter(|block| !block.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn block(node: &Value) -> String {
+    match node_type(node) {
+        "paragraph" => inline(children(node)),
+        "heading" => {
+            let level = node
+                .get("attrs")
+                .and_then(|attrs| attrs.get("level"))
+                .and_then(Value::as_u64)
+                .unwrap_or(1)
+                .clamp(1, 6) as usize;
+            format!("{} {}", "#".repeat(level), inline(children(node)))
+        }
+        "bulletList" => list(node, |_, _| "- ".to_string()),
+        "orderedList" => {
+            let start = node
+                .get("attrs")
+                .and_then(|attrs| attrs.get("order"))
+                .and_then(Value::as_u64)
+                .unwrap_or(1);
+            list(node, |i, _| format!("{}. ", start + i as u64))
+        }
+        "taskList" => list(node, |_, item| {
+            if attr(item, "state") == Some("DONE") {
+                "- [x] ".to_string()
+            } else {
+                "- [ ] ".to_string()
+            }
+        }),
+        "codeBlock" => {
+            let code: String = children(node)
+                .iter()
+                .filter_map(|text| text.get("text").and_then(Value::as_str))
+                .collect();
+            format!(
+                "```{}\n{}\n```",
+                attr(node, "language").unwrap_or_default(),
+                code.trim_end_matches('\n')
+            )
+        }
+        "blockquote" | "panel" => blocks(children(node))
+            .lines()
+            .map(|line| format!("> {}", line).trim_end().to_string())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "expand" | "nestedExpand" => {
+            let body = blocks(children(node));
+            match attr(node, "title").filter(|title| !title.is_empty()) {
+                Some(title) => format!("**{}**\n\n{}", title, body),
+                None => body,
+            }
+        }
+        "rule" => "---".to_string(),
+        "table" => table(node),
+        "media" => media(node),
+        _ if is_inline(node) => inline_node(node),
+        _ => tight_blocks(children(node)),
+    }
+}
+
+/// List items on consecutive lines; continuation lines are indented under the marker
+fn list(node: &Value, marker: impl Fn(usize, &Value) -> String) -> String {
+    children(node)
+        .iter()
+        .enumerate()
+        .map(|(i, item)| {
+            let marker = marker(i, item);
+            let indent = " ".repeat(marker.len());
+            let content = tight_blocks(children(item));
+            let mut lines = content.lines();
+            std::iter::once(format!("{}{}", marker, lines.next().unwrap_or_default()))
+                .chain(lines.map(|line| {
+                    if line.is_empty() {
+                        String::new()
+                    } else {
+                        format!("{}{}", indent, line)
+                    }
+                }))
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Pipe table; a first row of header cells gets a separator line
+fn table(node: &Value) -> String {
+    let mut lines = Vec::new();
+    for (i, row) in children(node).iter().enumerate() {
+        let cells = children(row);
+        let texts: Vec<String> = cells
+            .iter()
+            .map(|cell| {
+                tight_blocks(children(cell))
+                    .replace('\n', " ")
+                    .replace('|', "\\|")
+            })
+            .collect();
+        lines.push(format!("| {} |", texts.join(" | ")));
+
+        let header = !cells.is_empty() && cells.iter().all(|c| node_type(c) == "tableHeader");
+        if i == 0 && header {
+            lines.push(format!("|{}|", vec![" --- "; cells.len()].join("|")));
+        }
+    }
+    lines.join("\n")
+}
+
+fn media(node: &Value) -> String {
+    match attr(node, "alt").filter(|alt| !alt.is_empty()) {
+        Some(alt) => format!("[attachment: {}]", alt),
+        None => "[attachment]".to_string(),
+    }
+}
+
+fn inline(nodes: &[Value]) -> String {
+    nodes.iter().map(inline_node).collect()
+}
+
+fn inline_node(node: &Value) -> String {
+    match node_type(node) {
+        "text" => with_marks(
+            node.get("text").and_then(Value::as_str).unwrap_or_default(),
+            node,
+        ),
+        "hardBreak" => "\n".to_string(),
+        "mention" => {
+            let name = attr(node, "text")
+                .or_else(|| attr(node, "id"))
+                .unwrap_or_default();
+            if name.starts_with('@') {
+                name.to_string()
+            } else {
+                format!("@{}", name)
+            }
+        }
+        "emoji" => attr(node, "text")
+            .or_else(|| attr(node, "shortName"))
+            .unwrap_or_default()
+            .to_string(),
+        "inlineCard" => attr(node, "url").unwrap_or_default().to_string(),
+        "status" => format!("[{}]", attr(node, "text").unwrap_or_default()),
+        // Dates are stored as a millisecond timestamp string
+        "date" => attr(node, "timestamp")
+            .and_then(|ms| ms.parse::<i64>().ok())
+            .and_then(chrono::DateTime::from_timestamp_millis)
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_default(),
+        "mediaInline" => media(node),
+        _ => inline(children(node)),
+    }
+}
+
+/// Wrap text in the markdown for its marks; code innermost, links outermost
+fn with_marks(text: &str, node: &Value) -> String {
+    let mut marks: Vec<&Value> = node
+        .get("marks")
+        .and_then(Value::as_array)
+        .map(|marks| marks.iter().collect())
+        .unwrap_or_default();
+    marks.sort_by_key(|mark| match node_type(mark) {
+        "code" => 0,
+        "link" => 2,
+        _ => 1,
+    });
+
+    let mut text = text.to_string();
+    for mark in marks {
+        text = match node_type(mark) {
+            "code" => format!("`{}`", text),
+            "strong" => format!("**{}**", text),
+            "em" => format!("*{}*", text),
+            "strike" => format!("~~{}~~", text),
+            "link" => match attr(mark, "href") {
+                Some(href) => format!("[{}]({})", text, href),
+                None => text,
+            },
+            _ => text,
+        };
+    }
+    text
+}
+
+/// Build an ADF document from markdown
+///
+/// Understands paragraphs, `#` headings, `-`/`*`/`1.` lists (nested by
+/// indentation), fenced code blocks, `>` quotes, `---` rules and the inline
+/// `code`, `**strong**`, `*em*`, `~~strike~~` and `[text](url)` spans. Anything
+/// else is kept as text.
+pub fn from_markdown(text: &str) -> Value {
+    let lines: Vec<&str> = text.lines().collect();
+    json!({
+        "type": "doc",
+        "version": 1,
+        "content": parse_blocks(&lines),
+    })
+}
+
+/// Paragraph nodes for plain text, taken literally rather than as markdown
+///
+/// Blank lines split paragraphs and single newlines become hard breaks.
+pub fn paragraphs(text: &str) -> Vec<Value> {
+    let mut paragraphs = Vec::new();
+    let mut lines: Vec<&str> = Vec::new();
+
+    for line in text.trim_end().lines().chain([""]) {
+        if !line.trim().is_empty() {
+            lines.push(line);
+            continue;
+        }
+        if lines.is_empty() {
+            continue;
+        }
+
+        let mut content = Vec::new();
+        for (i, line) in lines.drain(..).enumerate() {
+            if i > 0 {
+                content.push(json!({ "type": "hardBreak" }));
+            }
+            content.push(json!({ "type": "text", "text": line }));
+        }
+        paragraphs.push(json!({ "type": "paragraph", "content": content }));
+    }
+
+    paragraphs
+}
+
+fn parse_blocks(lines: &[&str]) -> Vec<Value> {
+    let mut nodes = Vec::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i].trim_start();
+        if line.is_empty() {
+            i += 1;
+        } else if let Some(fence) = line.strip_prefix("```") {
+            let start = i + 1;
+            i = start;
+            while i < lines.len() && !lines[i].trim_start().starts_with("```") {
+                i += 1;
+            }
+            let mut node = json!({ "type": "codeBlock" });
+            let language = fence.trim();
+            if !language.is_empty() {
+                node["attrs"] = json!({ "language": language });
+            }
+            let code = lines[start..i].join("\n");
+            node["content"] = if code.is_empty() {
+                json!([])
+            } else {
+                json!([{ "type": "text", "text": code }])
+            };
+            nodes.push(node);
+            // Skip the closing fence
+            i += 1;
+        } else if let Some((level, title)) = heading(line) {
+            nodes.push(json!({
+                "type": "heading",
+                "attrs": { "level": level },
+                "content": parse_inline(title),
+            }));
+            i += 1;
+        } else if is_rule(line) {
+            nodes.push(json!({ "type": "rule" }));
+            i += 1;
+        } else if line.starts_with('>') {
+            let mut quoted = Vec::new();
+            while let Some(rest) = lines.get(i).and_then(|l| l.trim_start().strip_prefix('>')) {
+                quoted.push(rest.strip_prefix(' ').unwrap_or(rest));
+                i += 1;
+            }
+            nodes.push(json!({ "type": "blockquote", "content": parse_blocks(&quoted) }));
+        } else if list_marker(lines[i]).is_some() {
+            let (list, next) = parse_list(lines, i);
+            nodes.push(list);
+            i = next;
+        } else {
+            // A paragraph runs to the next blank line or block start
+            let start = i;
+            i += 1;
+            while i < lines.len() && !lines[i].trim().is_empty() && !starts_block(lines[i]) {
+                i += 1;
+            }
+            nodes.push(paragraph(&lines[start..i]));
+        }
+    }
+    nodes
+}
+
+/// Paragraph whose lines are joined with hard breaks
+fn paragraph(lines: &[&str]) -> Value {
+    let mut content = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            content.push(json!({ "type": "hardBreak" }));
+        }
+        content.extend(parse_inline(line.trim()));
+    }
+    json!({ "type": "paragraph", "content": content })
+}
+
+fn starts_block(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```")
+        || trimmed.starts_with('>')
+        || heading(trimmed).is_some()
+        || is_rule(trimmed)
+        || list_marker(line).is_some()
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let title = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then(|| (level, title.trim()))
+}
+
+/// `---`, `***` or `___`, optionally spaced
+fn is_rule(line: &str) -> bool {
+    let chars: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+    chars.len() >= 3 && matches!(chars[0], '-' | '*' | '_') && chars.iter().all(|&c| c == chars[0])
+}
+
+struct ListMarker {
+    indent: usize,
+    ordered: Option<u64>,
+    /// Column where the item's text starts
+    content: usize,
+}
+
+fn list_marker(line: &str) -> Option<ListMarker> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    let rest = &line[indent..];
+    if ["- ", "* ", "+ "]
+        .iter()
+        .any(|bullet| rest.starts_with(bullet))
+    {
+        return Some(ListMarker {
+            indent,
+            ordered: None,
+            content: indent + 2,
+        });
+    }
+
+    let digits = rest.chars().take_while(char::is_ascii_digit).count();
+    let after = &rest[digits..];
+    if (1..=9).contains(&digits) && (after.starts_with(". ") || after.starts_with(") ")) {
+        return Some(ListMarker {
+            indent,
+            ordered: rest[..digits].parse().ok(),
+            content: indent + digits + 2,
+        });
+    }
+    None
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// Parse the list starting at `lines[start]`, returning it and the index after it
+fn parse_list(lines: &[&str], start: usize) -> (Value, usize) {
+    let Some(first) = list_marker(lines[start]) else {
+        return (paragraph(&lines[start..=start]), start + 1);
+    };
+    let is_sibling = |line: &str| {
+        list_marker(line).is_some_and(|m| {
+            m.indent == first.indent && m.ordered.is_some() == first.ordered.is_some()
+        })
+    };
+
+    let mut items = Vec::new();
+    let mut i = start;
+    while i < lines.len() && is_sibling(lines[i]) {
+        let Some(marker) = list_marker(lines[i]) else {
+            break;
+        };
+        let mut item_lines = vec![&lines[i][marker.content.min(lines[i].len())..]];
+        i += 1;
+
+        while i < lines.len() {
+            let line = lines[i];
+            if line.trim().is_empty() {
+                match lines.get(i + 1) {
+                    // A blank line inside an item, e.g. before a nested block
+                    Some(&next) if !next.trim().is_empty() && indent_of(next) > first.indent => {
+                        item_lines.push("");
+                        i += 1;
+                        continue;
+                    }
+                    // Blank lines between items keep the list together
+                    Some(&next) if is_sibling(next) => i += 1,
+                    _ => {}
+                }
+                break;
+            }
+
+            let indent = indent_of(line);
+            if indent > first.indent {
+                item_lines.push(&line[indent.min(marker.content)..]);
+            } else if starts_block(line) {
+                break;
+            } else {
+                // Lazy continuation of the item's paragraph
+                item_lines.push(line.trim());
+            }
+            i += 1;
+        }
+
+        let mut content = parse_blocks(&item_lines);
+        if content.is_empty() {
+            content.push(json!({ "type": "paragraph", "content": [] }));
+        }
+        items.push(json!({ "type": "listItem", "content": content }));
+    }
+
+    let mut list = match first.ordered {
+        Some(_) => json!({ "type": "orderedList", "content": items }),
+        None => json!({ "type": "bulletList", "content": items }),
+    };
+    if let Some(order) = first.ordered.filter(|&order| order != 1) {
+        list["attrs"] = json!({ "order": order });
+    }
+    (list, i)
+}
+
+fn parse_inline(text: &str) -> Vec<Value> {
+    let mut nodes = Vec::new();
+    push_inline(text, &[], &mut nodes);
+    nodes
+}
+
+fn push_inline(text: &str, marks: &[Value], out: &mut Vec<Value>) {
+    let mut plain = String::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if let Some(span) = span(rest, plain.chars().last()) {
+            push_text(&mut plain, marks, out);
+            let mut inner_marks: Vec<Value> = if span.mark["type"] == "code" {
+                // Code can only be combined with links
+                marks
+                    .iter()
+                    .filter(|mark| mark["type"] == "link")
+                    .cloned()
+                    .collect()
+            } else {
+                marks.to_vec()
+            };
+            inner_marks.push(span.mark);
+            if span.nested {
+                push_inline(span.content, &inner_marks, out);
+            } else {
+                push_text(&mut span.content.to_string(), &inner_marks, out);
+            }
+            rest = span.rest;
+            continue;
+        }
+        plain.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    push_text(&mut plain, marks, out);
+}
+
+/// Append `text` as a text node, leaving `text` empty
+fn push_text(text: &mut String, marks: &[Value], out: &mut Vec<Value>) {
+    if text.is_empty() {
+        return;
+    }
+    let mut node = json!({ "type": "text", "text": std::mem::take(text) });
+    if !marks.is_empty() {
+        node["marks"] = Value::Array(marks.to_vec());
+    }
+    out.push(node);
+}
+
+/// An inline span at the start of the text
+struct Span<'a> {
+    content: &'a str,
+    mark: Value,
+    rest: &'a str,
+    /// Whether the content may hold further spans
+    nested: bool,
+}
+
+fn span(text: &str, previous: Option<char>) -> Option<Span<'_>> {
+    if let Some(rest) = text.strip_prefix('`') {
+        let end = rest.find('`').filter(|&end| end > 0)?;
+        return Some(Span {
+            content: &rest[..end],
+            mark: json!({ "type": "code" }),
+            rest: &rest[end + 1..],
+            nested: false,
+        });
+    }
+
+    if let Some(rest) = text.strip_prefix('[') {
+        let close = rest.find("](")?;
+        let target = &rest[close + 2..];
+        let end = target.find(')')?;
+        if close == 0 || end == 0 {
+            return None;
+        }
+        return Some(Span {
+            content: &rest[..close],
+            mark: json!({ "type": "link", "attrs": { "href": &target[..end] } }),
+            rest: &target[end + 1..],
+            nested: true,
+        });
+    }
+
+    for (delimiter, mark) in [
+        ("**", "strong"),
+        ("__", "strong"),
+        ("~~", "strike"),
+        ("*", "em"),
+        ("_", "em"),
+    ] {
+        let Some(rest) = text.strip_prefix(delimiter) else {
+            continue;
+        };
+        // snake_case words are not emphasis
+        if delimiter.starts_with('_') && previous.is_some_and(char::is_alphanumeric) {
+            return None;
+        }
+        if rest.starts_with(char::is_whitespace) {
+            continue;
+        }
+        let Some(end) = rest.find(delimiter) else {
+            continue;
+        };
+        let content = &rest[..end];
+        let after = &rest[end + delimiter.len()..];
+        if content.is_empty()
+            || content.ends_with(char::is_whitespace)
+            || (delimiter.starts_with('_') && after.starts_with(char::is_alphanumeric))
+        {
+            continue;
+        }
+        return Some(Span {
+            content,
+            mark: json!({ "type": mark }),
+            rest: after,
+            nested: true,
+        });
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMMENT: &str = include_str!("../tests/fixtures/adf/comment.json");
+    const COMMENT_MARKDOWN: &str = include_str!("../tests/fixtures/adf/comment.md");
+
+    #[test]
+    fn test_renders_cloud_comment() {
+        let doc: Value = serde_json::from_str(COMMENT).unwrap();
+        assert_eq!(to_markdown(&doc), COMMENT_MARKDOWN.trim_end());
+    }
+
+    #[test]
+    fn test_rich_text_handles_serialized_documents() {
+        let doc: Value = serde_json::from_str(COMMENT).unwrap();
+        let serialized = Value::String(doc.to_string());
+        assert_eq!(rich_text(&serialized), to_markdown(&doc));
+
+        assert_eq!(rich_text(&json!("h2. Wiki *markup*")), "h2. Wiki *markup*");
+        assert_eq!(rich_text(&json!("{code}x{code}")), "{code}x{code}");
+        assert_eq!(rich_text(&Value::Null), "");
+    }
+
+    #[test]
+    fn test_markdown_round_trip() {
+        let markdown = "## Findings\n\n\
+            The **retry** loop in `Gateway::send` ignores *timeouts*, see \
+            [the runbook](https://example.com/runbook).\n\
+            Second line of the same paragraph.\n\n\
+            - first\n\
+            - second with `code`\n  \
+              - nested\n\
+            - third\n\n\
+            3. three\n\
+            4. four\n\n\
+            ```rust\nfn main() {\n    println!(\"hi\");\n}\n```\n\n\
+            > quoted\n\n\
+            ---\n\n\
+            snake_case_name stays ~~as~~ text";
+
+        let doc = from_markdown(markdown);
+        assert!(is_doc(&doc));
+        assert_eq!(to_markdown(&doc), markdown);
+    }
+
+    #[test]
+    fn test_from_markdown_nodes() {
+        let doc = from_markdown("- a\n\n- b\n\n1) one\n**`x`**");
+        let content = doc["content"].as_array().unwrap();
+        assert_eq!(content.len(), 2);
+        assert_eq!(content[0]["type"], "bulletList");
+        assert_eq!(content[0]["content"].as_array().unwrap().len(), 2);
+        assert_eq!(content[1]["type"], "orderedList");
+
+        // Lazy continuation line joins the item's paragraph
+        let item = &content[1]["content"][0]["content"][0];
+        assert_eq!(item["content"][1]["type"], "hardBreak");
+        assert_eq!(item["content"][2]["marks"], json!([{ "type": "code" }]));
+
+        assert_eq!(
+            from_markdown("")["content"],
+            json!([]),
+            "empty text is an empty document"
+        );
+    }
+
+    #[test]
+    fn test_plain_paragraphs_and_text() {
+        let nodes = paragraphs("\n\nFirst line\n**not bold**\n\n\nSecond  \n");
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0]["content"][1]["type"], "hardBreak");
+        assert_eq!(nodes[0]["content"][2]["text"], "**not bold**");
+        assert_eq!(
+            nodes[1]["content"],
+            json!([{ "type": "text", "text": "Second" }])
+        );
+
+        let doc = json!({ "type": "doc", "content": nodes });
+        assert_eq!(node_text(&doc), "First line\n**not bold**\nSecond");
+        assert!(paragraphs(" \n\n").is_empty());
+    }
+}
@@ -0,0 +1,441 @@
+//! Audit log of mutating operations
+//!
+//! Every tool call that changes JIRA is appended as one JSON line to `audit.log`
+//! in the state directory. Parameters are redacted before they are written:
+//! secrets are never logged, long free text (comment bodies, descriptions) is
+//! truncated and attachment payloads are dropped. When a tool returns a `diff`
+//! of what it changed (description rewrites), the diff is kept with the entry.
+//!
+//! The file is rotated once it grows past [`MAX_LOG_BYTES`], keeping
+//! [`ROTATED_FILES`] older files (`audit.log.1` is the most recent).
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use chrono::Utc;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::warn;
+
+const AUDIT_FILE: &str = "audit.log";
+
+/// Size at which the audit log is rotated
+pub const MAX_LOG_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Number of rotated files kept next to the active log
+pub const ROTATED_FILES: usize = 5;
+
+/// Longest free-text value kept in a logged parameter
+const MAX_TEXT_CHARS: usize = 200;
+
+/// Longest value of any other string parameter
+const MAX_VALUE_CHARS: usize = 1000;
+
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// Parameter names whose values are never logged (matched as substrings)
+const SECRET_KEYS: &[&str] = &[
+    "token",
+    "password",
+    "secret",
+    "authorization",
+    "api_key",
+    "apikey",
+    "credential",
+];
+
+/// Free-text parameters that are truncated to MAX_TEXT_CHARS
+const TEXT_KEYS: &[&str] = &[
+    "body",
+    "comment",
+    "comment_body",
+    "content",
+    "description",
+    "worklog_comment",
+    "acceptance_criteria",
+    "text",
+];
+
+/// Longest change diff kept with an entry
+const MAX_DIFF_CHARS: usize = 4000;
+
+/// Binary payloads, replaced by their size
+const PAYLOAD_KEYS: &[&str] = &["content_base64"];
+
+/// Outcome of an audited call
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum AuditOutcome {
+    Success,
+    Error,
+}
+
+/// One audited tool call
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AuditEntry {
+    /// When the call finished (RFC 3339, UTC)
+    pub timestamp: String,
+
+    /// MCP tool name
+    pub tool: String,
+
+    /// Issue keys named in the parameters or result
+    pub issue_keys: Vec<String>,
+
+    /// Redacted call parameters
+    pub params: Value,
+
+    /// Whether the call succeeded
+    pub outcome: AuditOutcome,
+
+    /// Error message for failed calls
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// Call duration in milliseconds
+    pub duration_ms: u64,
+
+    /// Unified diff of the change, for tools that report one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+}
+
+/// Append-only JSON-lines audit log with size-based rotation
+#[derive(Debug)]
+pub struct AuditLog {
+    path: PathBuf,
+    max_bytes: u64,
+    lock: Mutex<()>,
+}
+
+impl AuditLog {
+    pub fn new(state_dir: impl AsRef<Path>) -> Self {
+        Self::with_max_bytes(state_dir, MAX_LOG_BYTES)
+    }
+
+    pub fn with_max_bytes(state_dir: impl AsRef<Path>, max_bytes: u64) -> Self {
+        Self {
+            path: state_dir.as_ref().join(AUDIT_FILE),
+            max_bytes,
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Path of the active log file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Record a finished tool call
+    ///
+    /// `result` is the serialized tool result on success, or the error message.
+    /// Failing to write the log never fails the call itself; it is only logged.
+    pub fn record(
+        &self,
+        tool: &str,
+        params: &Value,
+        result: Result<&Value, &str>,
+        duration: Duration,
+    ) {
+        let mut issue_keys = Vec::new();
+        collect_issue_keys(params, &mut issue_keys);
+        if let Ok(result) = result {
+            collect_issue_keys(result, &mut issue_keys);
+        }
+
+        let entry = AuditEntry {
+            timestamp: Utc::now().to_rfc3339(),
+            tool: tool.to_string(),
+            issue_keys,
+            params: redact(params),
+            outcome: if result.is_ok() {
+                AuditOutcome::Success
+            } else {
+                AuditOutcome::Error
+            },
+            error: result.err().map(|e| truncate(e, MAX_VALUE_CHARS)),
+            duration_ms: duration.as_millis() as u64,
+            diff: result
+                .ok()
+                .and_then(|result| result.get("diff"))
+                .and_then(Value::as_str)
+                .map(|diff| truncate(diff, MAX_DIFF_CHARS)),
+        };
+
+        if let Err(e) = self.append(&entry) {
+            warn!("Failed to write audit entry for {}: {}", tool, e);
+        }
+    }
+
+    fn append(&self, entry: &AuditEntry) -> JiraMcpResult<()> {
+        let line = serde_json::to_string(entry)?;
+        let io_error = |e: std::io::Error| {
+            JiraMcpError::internal(format!("Failed to write {}: {}", self.path.display(), e))
+        };
+
+        let _guard = self
+            .lock
+            .lock()
+            .map_err(|_| JiraMcpError::internal("Audit log lock poisoned"))?;
+
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(io_error)?;
+        }
+
+        let size = std::fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+        if size > 0 && size + line.len() as u64 > self.max_bytes {
+            self.rotate().map_err(io_error)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        writeln!(file, "{}", line).map_err(io_error)
+    }
+
+    /// Shift audit.log -> audit.log.1 -> ... dropping the oldest file
+    fn rotate(&self) -> std::io::Result<()> {
+        for n in (1..ROTATED_FILES).rev() {
+            let from = self.rotated_path(n);
+            if from.exists() {
+                std::fs::rename(&from, self.rotated_path(n + 1))?;
+            }
+        }
+        std::fs::rename(&self.path, self.rotated_path(1))
+    }
+
+    fn rotated_path(&self, n: usize) -> PathBuf {
+        self.path.with_extension(format!("log.{}", n))
+    }
+
+    /// The most recent entries (newest first) matching the optional filters
+    pub fn recent(
+        &self,
+        limit: usize,
+        issue_key: Option<&str>,
+        tool: Option<&str>,
+    ) -> JiraMcpResult<Vec<AuditEntry>> {
+        let _guard = self
+            .lock
+            .lock()
+            .map_err(|_| JiraMcpError::internal("Audit log lock poisoned"))?;
+
+        let files = std::iter::once(self.path.clone())
+            .chain((1..=ROTATED_FILES).map(|n| self.rotated_path(n)));
+
+        let mut entries = Vec::new();
+        for file in files {
+            let content = match std::fs::read_to_string(&file) {
+                Ok(content) => content,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => {
+                    return Err(JiraMcpError::internal(format!(
+                        "Failed to read {}: {}",
+                        file.display(),
+                        e
+                    )))
+                }
+            };
+
+            for line in content.lines().rev() {
+                // Skip lines from a partial write rather than failing the whole read
+                let Ok(entry) = serde_json::from_str::<AuditEntry>(line) else {
+                    continue;
+                };
+
+                let key_matches = issue_key
+                    .is_none_or(|key| entry.issue_keys.iter().any(|k| k.eq_ignore_ascii_case(key)));
+                let tool_matches = tool.is_none_or(|tool| entry.tool == tool);
+
+                if key_matches && tool_matches {
+                    entries.push(entry);
+                    if entries.len() >= limit {
+                        return Ok(entries);
+                    }
+                }
+            }
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Redact parameters for logging
+///
+/// Secret-looking keys are replaced, free text is truncated and attachment
+/// payloads are replaced by their size. Other long strings are capped too.
+pub fn redact(value: &Value) -> Value {
+    redact_value(None, value)
+}
+
+fn redact_value(key: Option<&str>, value: &Value) -> Value {
+    let key = key.map(|k| k.to_lowercase());
+    let key = key.as_deref();
+
+    if key.is_some_and(is_secret_key) {
+        return Value::String(REDACTED.to_string());
+    }
+
+    match value {
+        Value::String(s) if key.is_some_and(|k| PAYLOAD_KEYS.contains(&k)) => {
+            Value::String(format!("[{} chars omitted]", s.chars().count()))
+        }
+        Value::String(s) if key.is_some_and(|k| TEXT_KEYS.contains(&k)) => {
+            Value::String(truncate(s, MAX_TEXT_CHARS))
+        }
+        Value::String(s) => Value::String(truncate(s, MAX_VALUE_CHARS)),
+        Value::Array(items) => {
+            Value::Array(items.iter().map(|item| redact_value(key, item)).collect())
+        }
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), redact_value(Some(k), v)))
+                .collect(),
+        ),
+        other => other.clone(),
+    }
+}
+
+/// Whether values under this (lowercase) key must never be logged
+pub(crate) fn is_secret_key(key: &str) -> bool {
+    SECRET_KEYS.iter().any(|s| key.contains(s))
+}
+
+pub(crate) fn truncate(s: &str, max_chars: usize) -> String {
+    let total = s.chars().count();
+    if total <= max_chars {
+        return s.to_string();
+    }
+    let kept: String = s.chars().take(max_chars).collect();
+    format!("{}… [truncated, {} chars]", kept, total)
+}
+
+/// Collect values of `*issue_key` / `*issue_keys` fields, in order, without duplicates
+pub(crate) fn collect_issue_keys(value: &Value, keys: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (name, v) in map {
+                if name.ends_with("issue_key") || name.ends_with("issue_keys") {
+                    let found: Vec<&str> = match v {
+                        Value::String(s) => vec![s.as_str()],
+                        Value::Array(items) => items.iter().filter_map(|i| i.as_str()).collect(),
+                        _ => Vec::new(),
+                    };
+                    for key in found {
+                        if !key.is_empty() && !keys.iter().any(|k| k == key) {
+                            keys.push(key.to_string());
+                        }
+                    }
+                } else {
+                    collect_issue_keys(v, keys);
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_issue_keys(item, keys);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_redact_secrets_and_truncate_text() {
+        let long_comment = "x".repeat(500);
+        let redacted = redact(&json!({
+            "issue_key": "PROJ-1",
+            "comment_body": long_comment,
+            "api_token": "abc123",
+            "auth": {"Password": "hunter2"},
+            "files": [{"filename": "a.png", "content_base64": "aGVsbG8="}]
+        }));
+
+        assert_eq!(redacted["issue_key"], "PROJ-1");
+        assert_eq!(redacted["api_token"], REDACTED);
+        assert_eq!(redacted["auth"]["Password"], REDACTED);
+        assert_eq!(redacted["files"][0]["filename"], "a.png");
+        assert_eq!(redacted["files"][0]["content_base64"], "[8 chars omitted]");
+
+        let comment = redacted["comment_body"].as_str().unwrap();
+        assert!(comment.starts_with(&"x".repeat(MAX_TEXT_CHARS)));
+        assert!(comment.ends_with("[truncated, 500 chars]"));
+        assert!(!redacted.to_string().contains("abc123"));
+        assert!(!redacted.to_string().contains("hunter2"));
+
+        // Short text is kept verbatim
+        let short = redact(&json!({"comment_body": "LGTM"}));
+        assert_eq!(short["comment_body"], "LGTM");
+    }
+
+    #[test]
+    fn test_collect_issue_keys() {
+        let mut keys = Vec::new();
+        collect_issue_keys(
+            &json!({
+                "inward_issue_key": "PROJ-1",
+                "outward_issue_key": "PROJ-2",
+                "issues": [{"parent_issue_key": "PROJ-1"}],
+                "issue_keys": ["PROJ-3"]
+            }),
+            &mut keys,
+        );
+        keys.sort();
+        assert_eq!(keys, vec!["PROJ-1", "PROJ-2", "PROJ-3"]);
+    }
+
+    #[test]
+    fn test_record_rotate_and_filter() {
+        let dir = std::env::temp_dir().join(format!("jira-audit-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let log = AuditLog::with_max_bytes(&dir, 400);
+
+        for key in ["PROJ-1", "PROJ-2", "PROJ-1"] {
+            log.record(
+                "add_comment",
+                &json!({"issue_key": key, "comment_body": "hi"}),
+                Ok(&json!({"issue_key": key})),
+                Duration::from_millis(5),
+            );
+        }
+        log.record(
+            "update_issue_description",
+            &json!({"issue_key": "PROJ-2", "content": "new", "mode": "replace"}),
+            Ok(&json!({"issue_key": "PROJ-2", "diff": "-old\n+new\n"})),
+            Duration::from_millis(3),
+        );
+        log.record(
+            "assign_issue",
+            &json!({"issue_key": "PROJ-1"}),
+            Err("Permission denied"),
+            Duration::from_millis(1),
+        );
+
+        // Small max size forces rotation, but reads span rotated files
+        assert!(dir.join("audit.log.1").exists());
+
+        let all = log.recent(10, None, None).unwrap();
+        assert_eq!(all.len(), 5);
+        assert_eq!(all[0].tool, "assign_issue");
+        assert_eq!(all[0].outcome, AuditOutcome::Error);
+        assert_eq!(all[1].diff.as_deref(), Some("-old\n+new\n"));
+        assert!(all[2].diff.is_none());
+
+        let proj1 = log.recent(10, Some("proj-1"), Some("add_comment")).unwrap();
+        assert_eq!(proj1.len(), 2);
+        assert_eq!(log.recent(1, None, None).unwrap().len(), 1);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
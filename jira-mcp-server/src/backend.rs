@@ -0,0 +1,733 @@
+//! JIRA backends
+//!
+//! [`JiraBackend`] is the set of operations [`JiraClient`](crate::jira_client::JiraClient)
+//! performs against JIRA. The client adds timeouts, retries, single-flight reads
+//! and the request log on top; a backend only answers the operations.
+//!
+//! [`GouqiBackend`] talks to a real instance through gouqi and is the default.
+//! [`MockBackend`] answers from JSON fixtures on disk and records writes in memory,
+//! so the tools (and the TodoTracker) run unchanged without network access.
+//!
+//! # Fixture layout
+//!
+//! Each operation reads the fixture of the REST resource it corresponds to, under
+//! `{dir}/{METHOD}/{root}/{path}.json` with the API version and query dropped.
+//! A file or directory named `_` matches any single path segment:
+//!
+//! ```text
+//! fixtures/GET/api/myself.json            get_current_user
+//! fixtures/GET/api/issue/_.json           get_issue("PROJ-1", ..)
+//! fixtures/GET/api/issue/_/votes.json     get("api", "/issue/PROJ-1/votes")
+//! fixtures/GET/api/search.json            search(..), paged by the mock
+//! fixtures/POST/api/issue.json            post("api", "/issue", ..)
+//! ```
+//!
+//! Several fixture directories can be layered, separated like `PATH`; earlier
+//! ones win, so a variant only holds the fixtures that differ from its base.
+//! A read without a fixture fails as not found, as does one whose fixture is
+//! `null` (which hides a resource of the base); a write without one returns `null`.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::query_escape;
+use async_trait::async_trait;
+use gouqi::r#async::Jira;
+use gouqi::relationships::{GraphOptions, IssueRelationships, RelationshipGraph};
+use gouqi::{AttachmentResponse, Credentials, Issue, SearchOptions, SearchResults};
+use gouqi::{Worklog, WorklogInput};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::debug;
+
+/// Environment variable that enables mock mode
+pub const MOCK_ENV_VAR: &str = "JIRA_MCP_MOCK";
+
+/// Environment variable pointing at the fixture directory
+pub const MOCK_FIXTURES_ENV_VAR: &str = "JIRA_MCP_MOCK_FIXTURES";
+
+/// Fixture directory used when mock mode is enabled without one
+pub const DEFAULT_MOCK_FIXTURES_DIR: &str = "tests/fixtures/mock";
+
+/// Result of a backend operation, with gouqi's error for JIRA failures
+pub type BackendResult<T> = Result<T, gouqi::Error>;
+
+/// Which parts of an issue [`JiraBackend::get_issue`] reads
+#[derive(Debug, Clone, Default)]
+pub struct IssueQuery {
+    /// Field IDs to return; empty for JIRA's default set
+    pub fields: Vec<String>,
+    /// Expansions, e.g. "changelog"
+    pub expand: Vec<String>,
+}
+
+impl IssueQuery {
+    /// Endpoint below the API root, e.g. "/issue/PROJ-1?expand=changelog"
+    pub fn endpoint(&self, issue_key: &str) -> String {
+        let mut params = Vec::new();
+        if !self.fields.is_empty() {
+            params.push(format!("fields={}", self.fields.join(",")));
+        }
+        if !self.expand.is_empty() {
+            params.push(format!("expand={}", self.expand.join(",")));
+        }
+
+        if params.is_empty() {
+            format!("/issue/{}", issue_key)
+        } else {
+            format!("/issue/{}?{}", issue_key, params.join("&"))
+        }
+    }
+}
+
+/// One page of the enhanced /search/jql endpoint (JIRA Cloud)
+#[derive(Debug, Clone, Default)]
+pub struct JqlPage {
+    pub max_results: usize,
+    pub expand: Vec<String>,
+    /// nextPageToken of the previous page; None for the first
+    pub next_page_token: Option<String>,
+}
+
+impl JqlPage {
+    /// Endpoint below the API root, all navigable fields included
+    pub fn endpoint(&self, jql: &str) -> String {
+        let mut endpoint = format!(
+            "/search/jql?jql={}&maxResults={}&fields=*navigable",
+            query_escape(jql),
+            self.max_results
+        );
+        if !self.expand.is_empty() {
+            endpoint.push_str(&format!("&expand={}", query_escape(&self.expand.join(","))));
+        }
+        if let Some(token) = &self.next_page_token {
+            endpoint.push_str(&format!("&nextPageToken={}", query_escape(token)));
+        }
+        endpoint
+    }
+}
+
+/// The JIRA operations the server performs
+///
+/// Resources without an operation of their own go through [`get`](Self::get),
+/// [`post`](Self::post), [`put`](Self::put) and [`delete`](Self::delete), which
+/// take the API root ("api" or "agile") and the endpoint below it. The provided
+/// operations are built on those; a backend overrides them where it answers
+/// differently.
+#[async_trait]
+pub trait JiraBackend: Send + Sync + std::fmt::Debug {
+    /// Read a resource, e.g. `get("api", "/issue/PROJ-1/votes")`
+    async fn get(&self, root: &str, endpoint: &str) -> BackendResult<Value>;
+
+    /// Create a resource; an empty response (204 No Content) reads as `null`
+    async fn post(&self, root: &str, endpoint: &str, body: Value) -> BackendResult<Value>;
+
+    /// Update a resource; see [`Self::post`]
+    async fn put(&self, root: &str, endpoint: &str, body: Value) -> BackendResult<Value>;
+
+    /// Delete a resource; see [`Self::post`]
+    async fn delete(&self, root: &str, endpoint: &str) -> BackendResult<Value>;
+
+    /// One page of the legacy /search endpoint
+    async fn search(&self, jql: &str, options: &SearchOptions) -> BackendResult<SearchResults>;
+
+    /// Log work on an issue
+    async fn add_worklog(&self, issue_key: &str, worklog: WorklogInput) -> BackendResult<Worklog>;
+
+    /// Links, parent and epic of one issue
+    async fn issue_relationships(
+        &self,
+        issue_key: &str,
+        options: GraphOptions,
+    ) -> BackendResult<RelationshipGraph>;
+
+    /// Attach files, given as (filename, content), to an issue
+    async fn upload_attachments(
+        &self,
+        issue_key: &str,
+        files: Vec<(String, Vec<u8>)>,
+    ) -> BackendResult<Vec<AttachmentResponse>>;
+
+    /// Raw content of an attachment
+    async fn download_attachment(&self, attachment_id: &str) -> BackendResult<Vec<u8>>;
+
+    /// The user the backend is authenticated as
+    async fn get_current_user(&self) -> BackendResult<Value> {
+        self.get("api", "/myself").await
+    }
+
+    /// An issue with the fields and expansions of `query`
+    async fn get_issue(&self, issue_key: &str, query: &IssueQuery) -> BackendResult<Value> {
+        self.get("api", &query.endpoint(issue_key)).await
+    }
+
+    /// One page of the enhanced /search/jql endpoint
+    async fn search_jql(&self, jql: &str, page: &JqlPage) -> BackendResult<Value> {
+        self.get("api", &page.endpoint(jql)).await
+    }
+
+    /// Approximate number of issues matching `jql` (JIRA Cloud)
+    async fn count_issues(&self, jql: &str) -> BackendResult<Value> {
+        self.post(
+            "api",
+            "/search/approximate-count",
+            serde_json::json!({ "jql": jql }),
+        )
+        .await
+    }
+
+    /// Comment on an issue
+    async fn add_comment(&self, issue_key: &str, body: Value) -> BackendResult<Value> {
+        self.post("api", &format!("/issue/{}/comment", issue_key), body)
+            .await
+    }
+
+    /// Transitions available on an issue, with the fields of their screens
+    async fn get_transitions(&self, issue_key: &str) -> BackendResult<Value> {
+        self.get(
+            "api",
+            &format!("/issue/{}/transitions?expand=transitions.fields", issue_key),
+        )
+        .await
+    }
+
+    /// Move an issue through a transition
+    async fn transition_issue(&self, issue_key: &str, body: Value) -> BackendResult<Value> {
+        self.post("api", &format!("/issue/{}/transitions", issue_key), body)
+            .await
+    }
+
+    /// Work logged on an issue
+    async fn get_worklogs(&self, issue_key: &str) -> BackendResult<Value> {
+        self.get("api", &format!("/issue/{}/worklog", issue_key))
+            .await
+    }
+}
+
+/// Backend that talks to a JIRA instance through gouqi
+#[derive(Debug)]
+pub struct GouqiBackend {
+    jira: Jira,
+    jira_url: String,
+    credentials: Credentials,
+}
+
+impl GouqiBackend {
+    pub fn new(jira_url: &str, credentials: Credentials) -> BackendResult<Self> {
+        Ok(Self {
+            jira: Jira::new(jira_url, credentials.clone())?,
+            jira_url: jira_url.to_string(),
+            credentials,
+        })
+    }
+
+    /// Run a call of gouqi's blocking client, for the attachment calls whose
+    /// async versions can't be sent between threads
+    ///
+    /// On timeout the blocking call is abandoned rather than cancelled.
+    async fn blocking<T, F>(&self, call: F) -> BackendResult<T>
+    where
+        T: Send + 'static,
+        F: FnOnce(gouqi::Jira) -> BackendResult<T> + Send + 'static,
+    {
+        let jira_url = self.jira_url.clone();
+        let credentials = self.credentials.clone();
+        tokio::task::spawn_blocking(move || call(gouqi::Jira::new(&jira_url, credentials)?))
+            .await
+            .map_err(|e| gouqi::Error::IO(std::io::Error::other(e)))?
+    }
+}
+
+#[async_trait]
+impl JiraBackend for GouqiBackend {
+    async fn get(&self, root: &str, endpoint: &str) -> BackendResult<Value> {
+        self.jira.get(root, endpoint).await
+    }
+
+    async fn post(&self, root: &str, endpoint: &str, body: Value) -> BackendResult<Value> {
+        self.jira.post(root, endpoint, body).await
+    }
+
+    async fn put(&self, root: &str, endpoint: &str, body: Value) -> BackendResult<Value> {
+        self.jira.put(root, endpoint, body).await
+    }
+
+    async fn delete(&self, root: &str, endpoint: &str) -> BackendResult<Value> {
+        self.jira.delete(root, endpoint).await
+    }
+
+    async fn search(&self, jql: &str, options: &SearchOptions) -> BackendResult<SearchResults> {
+        self.jira.search().list(jql, options).await
+    }
+
+    async fn add_worklog(&self, issue_key: &str, worklog: WorklogInput) -> BackendResult<Worklog> {
+        self.jira.issues().add_worklog(issue_key, worklog).await
+    }
+
+    async fn issue_relationships(
+        &self,
+        issue_key: &str,
+        options: GraphOptions,
+    ) -> BackendResult<RelationshipGraph> {
+        // Depth 0 makes gouqi fetch this issue's links only
+        self.jira
+            .issues()
+            .get_relationship_graph(issue_key, 0, Some(options))
+            .await
+    }
+
+    async fn upload_attachments(
+        &self,
+        issue_key: &str,
+        files: Vec<(String, Vec<u8>)>,
+    ) -> BackendResult<Vec<AttachmentResponse>> {
+        let issue_key = issue_key.to_string();
+        self.blocking(move |jira| {
+            let files = files
+                .iter()
+                .map(|(name, bytes)| (name.as_str(), bytes.clone()))
+                .collect();
+            jira.issues().upload_attachment(&issue_key, files)
+        })
+        .await
+    }
+
+    async fn download_attachment(&self, attachment_id: &str) -> BackendResult<Vec<u8>> {
+        let attachment_id = attachment_id.to_string();
+        self.blocking(move |jira| jira.attachments().download(&attachment_id))
+            .await
+    }
+}
+
+/// A write captured by the mock backend
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordedWrite {
+    /// HTTP method of the REST call (POST, PUT, DELETE)
+    pub method: String,
+    /// Resource path without API version or query (e.g. "api/issue/PROJ-1/comment")
+    pub path: String,
+    /// JSON body that was sent, if any
+    pub body: Option<Value>,
+}
+
+/// Backend that serves deterministic fixtures and records writes in memory
+#[derive(Debug)]
+pub struct MockBackend {
+    fixture_dirs: Vec<PathBuf>,
+    writes: Mutex<Vec<RecordedWrite>>,
+    searches: Mutex<Vec<String>>,
+    reads: Mutex<Vec<String>>,
+    requests: AtomicUsize,
+    read_delay_ms: AtomicU64,
+    write_delay_ms: AtomicU64,
+}
+
+impl MockBackend {
+    /// Serve the fixtures in `fixtures_dir`, a directory or a `PATH`-style list
+    /// of them searched in order
+    pub fn new(fixtures_dir: impl AsRef<std::ffi::OsStr>) -> JiraMcpResult<Self> {
+        let fixture_dirs: Vec<PathBuf> = std::env::split_paths(&fixtures_dir).collect();
+        if let Some(missing) = fixture_dirs.iter().find(|dir| !dir.is_dir()) {
+            return Err(JiraMcpError::config(format!(
+                "Mock fixtures directory not found: {}",
+                missing.display()
+            )));
+        }
+
+        Ok(Self {
+            fixture_dirs,
+            writes: Mutex::new(Vec::new()),
+            searches: Mutex::new(Vec::new()),
+            reads: Mutex::new(Vec::new()),
+            requests: AtomicUsize::new(0),
+            read_delay_ms: AtomicU64::new(0),
+            write_delay_ms: AtomicU64::new(0),
+        })
+    }
+
+    /// Operations handled so far, including the client's startup probes
+    pub fn request_count(&self) -> usize {
+        self.requests.load(Ordering::Relaxed)
+    }
+
+    /// Writes received so far, oldest first
+    pub fn writes(&self) -> Vec<RecordedWrite> {
+        self.writes.lock().unwrap().clone()
+    }
+
+    /// Writes received for a method and resource path
+    pub fn writes_to(&self, method: &str, path: &str) -> Vec<RecordedWrite> {
+        self.writes()
+            .into_iter()
+            .filter(|w| w.method == method && w.path == path)
+            .collect()
+    }
+
+    /// JQL of the searches and counts received so far, oldest first
+    pub fn searches(&self) -> Vec<String> {
+        self.searches.lock().unwrap().clone()
+    }
+
+    /// Endpoints of the GET requests received so far, query included (e.g.
+    /// "api/mypermissions?permissions=EDIT_ISSUES&issueKey=PROJ-1"), oldest first
+    pub fn reads(&self) -> Vec<String> {
+        self.reads.lock().unwrap().clone()
+    }
+
+    /// Answer reads only after `delay`, to simulate a slow instance
+    pub fn set_read_delay(&self, delay: Duration) {
+        self.read_delay_ms
+            .store(delay.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Answer writes only after `delay`, to simulate a slow instance
+    pub fn set_write_delay(&self, delay: Duration) {
+        self.write_delay_ms
+            .store(delay.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// Forget all recorded writes
+    pub fn clear_writes(&self) {
+        self.writes.lock().unwrap().clear();
+    }
+
+    /// Forget all recorded searches
+    pub fn clear_searches(&self) {
+        self.searches.lock().unwrap().clear();
+    }
+
+    fn record_search(&self, jql: &str) {
+        self.searches.lock().unwrap().push(jql.to_string());
+    }
+
+    /// Count a read and wait out the read delay
+    async fn begin_read(&self) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        let delay = self.read_delay_ms.load(Ordering::Relaxed);
+        if delay > 0 {
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+        }
+    }
+
+    /// Count and record a write, after the write delay
+    async fn record_write(&self, method: &str, path: &str, body: Option<Value>) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        let delay = self.write_delay_ms.load(Ordering::Relaxed);
+        if delay > 0 {
+            tokio::time::sleep(Duration::from_millis(delay)).await;
+        }
+        self.writes.lock().unwrap().push(RecordedWrite {
+            method: method.to_string(),
+            path: path.to_string(),
+            body,
+        });
+    }
+
+    /// The fixture for a method and resource path, if any directory has one
+    fn fixture(&self, method: &str, path: &str) -> BackendResult<Option<Value>> {
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        let Some(file) = self
+            .fixture_dirs
+            .iter()
+            .find_map(|dir| find_fixture(&dir.join(method), &segments))
+        else {
+            return Ok(None);
+        };
+
+        debug!("Serving mock fixture {}", file.display());
+        let content = std::fs::read_to_string(&file)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// The GET fixture of a resource; reads without one fail as not found
+    async fn read(&self, path: &str) -> BackendResult<Value> {
+        self.begin_read().await;
+        self.fixture("GET", path)?
+            .filter(|fixture| !fixture.is_null())
+            .ok_or(gouqi::Error::NotFound)
+    }
+
+    async fn write(&self, method: &str, path: &str, body: Option<Value>) -> BackendResult<Value> {
+        self.record_write(method, path, body).await;
+        Ok(self.fixture(method, path)?.unwrap_or(Value::Null))
+    }
+}
+
+#[async_trait]
+impl JiraBackend for MockBackend {
+    async fn get(&self, root: &str, endpoint: &str) -> BackendResult<Value> {
+        self.reads
+            .lock()
+            .unwrap()
+            .push(format!("{}/{}", root, endpoint.trim_start_matches('/')));
+        self.read(&resource_path(root, endpoint)).await
+    }
+
+    async fn post(&self, root: &str, endpoint: &str, body: Value) -> BackendResult<Value> {
+        self.write("POST", &resource_path(root, endpoint), Some(body))
+            .await
+    }
+
+    async fn put(&self, root: &str, endpoint: &str, body: Value) -> BackendResult<Value> {
+        self.write("PUT", &resource_path(root, endpoint), Some(body))
+            .await
+    }
+
+    async fn delete(&self, root: &str, endpoint: &str) -> BackendResult<Value> {
+        self.write("DELETE", &resource_path(root, endpoint), None)
+            .await
+    }
+
+    /// Pages through the issues of the search fixture; its `maxResults`, when
+    /// smaller than the request's, caps the page size like JIRA's own limit
+    async fn search(&self, jql: &str, options: &SearchOptions) -> BackendResult<SearchResults> {
+        self.record_search(jql);
+        let mut results: SearchResults = serde_json::from_value(self.read("api/search").await?)?;
+
+        let start = options.start_at().unwrap_or(0);
+        let max = options
+            .max_results()
+            .unwrap_or(50)
+            .min(results.max_results.max(1));
+        results.total = results.total.max(results.issues.len() as u64);
+        results.issues = results
+            .issues
+            .into_iter()
+            .skip(start as usize)
+            .take(max as usize)
+            .collect();
+        results.start_at = start;
+        results.max_results = max;
+        Ok(results)
+    }
+
+    async fn add_worklog(&self, issue_key: &str, worklog: WorklogInput) -> BackendResult<Worklog> {
+        let response = self
+            .write(
+                "POST",
+                &format!("api/issue/{}/worklog", issue_key),
+                Some(serde_json::to_value(&worklog)?),
+            )
+            .await?;
+        Ok(serde_json::from_value(response)?)
+    }
+
+    async fn issue_relationships(
+        &self,
+        issue_key: &str,
+        options: GraphOptions,
+    ) -> BackendResult<RelationshipGraph> {
+        let mut graph = RelationshipGraph::new("mock".to_string());
+        graph.metadata.root_issue = Some(issue_key.to_string());
+
+        // Like gouqi, an issue that can't be read leaves the graph empty
+        if let Ok(issue) = self.read(&format!("api/issue/{}", issue_key)).await {
+            let issue: Issue = serde_json::from_value(issue)?;
+            graph.add_issue(issue_key.to_string(), issue_relationships(&issue, &options));
+        }
+        Ok(graph)
+    }
+
+    async fn upload_attachments(
+        &self,
+        issue_key: &str,
+        files: Vec<(String, Vec<u8>)>,
+    ) -> BackendResult<Vec<AttachmentResponse>> {
+        let files: Vec<Value> = files
+            .iter()
+            .map(|(name, bytes)| serde_json::json!({"filename": name, "size": bytes.len()}))
+            .collect();
+        let response = self
+            .write(
+                "POST",
+                &format!("api/issue/{}/attachments", issue_key),
+                Some(serde_json::json!({ "files": files })),
+            )
+            .await?;
+        Ok(serde_json::from_value(response)?)
+    }
+
+    /// Content fixtures hold a JSON string, served as its UTF-8 bytes
+    async fn download_attachment(&self, attachment_id: &str) -> BackendResult<Vec<u8>> {
+        let content = self
+            .read(&format!("api/attachment/content/{}", attachment_id))
+            .await?;
+        Ok(match content {
+            Value::String(text) => text.into_bytes(),
+            other => other.to_string().into_bytes(),
+        })
+    }
+
+    async fn search_jql(&self, jql: &str, page: &JqlPage) -> BackendResult<Value> {
+        self.record_search(jql);
+        self.get("api", &page.endpoint(jql)).await
+    }
+
+    /// A search, so not recorded as a write
+    async fn count_issues(&self, jql: &str) -> BackendResult<Value> {
+        self.record_search(jql);
+        self.begin_read().await;
+        Ok(self
+            .fixture("POST", "api/search/approximate-count")?
+            .unwrap_or(Value::Null))
+    }
+}
+
+/// Resource path of a REST call, e.g. ("api", "/issue/PROJ-1?fields=labels")
+/// -> "api/issue/PROJ-1"
+fn resource_path(root: &str, endpoint: &str) -> String {
+    let endpoint = endpoint.split('?').next().unwrap_or_default();
+    format!("{}/{}", root, endpoint.trim_start_matches('/'))
+}
+
+/// Resolve a fixture file, preferring exact segment names over `_` wildcards
+fn find_fixture(dir: &Path, segments: &[&str]) -> Option<PathBuf> {
+    let (first, rest) = segments.split_first()?;
+
+    for name in [*first, "_"] {
+        if rest.is_empty() {
+            let file = dir.join(format!("{}.json", name));
+            if file.is_file() {
+                return Some(file);
+            }
+        } else if let Some(file) = find_fixture(&dir.join(name), rest) {
+            return Some(file);
+        }
+    }
+
+    None
+}
+
+/// Relationships of one issue, mapped the way gouqi's relationship graph maps them
+fn issue_relationships(issue: &Issue, options: &GraphOptions) -> IssueRelationships {
+    let mut relationships = IssueRelationships::new();
+
+    for link in issue.links().and_then(Result::ok).unwrap_or_default() {
+        let name = link.link_type.name.to_lowercase();
+        let wanted = |types: &Option<Vec<String>>| {
+            types
+                .as_ref()
+                .map(|types| types.contains(&link.link_type.name))
+        };
+        if wanted(&options.include_types) == Some(false)
+            || wanted(&options.exclude_types) == Some(true)
+        {
+            continue;
+        }
+
+        let (outward, inward) = match name.as_str() {
+            "blocks" | "causes" => ("blocks".to_string(), "blocked_by".to_string()),
+            "duplicate" | "duplicates" | "clones" => {
+                ("duplicates".to_string(), "duplicated_by".to_string())
+            }
+            "relates" | "relates to" => ("relates_to".to_string(), "relates_to".to_string()),
+            _ => (
+                format!("custom_{}", name),
+                format!("custom_{}_inward", name),
+            ),
+        };
+        if let Some(target) = &link.outward_issue {
+            if options.bidirectional || target.key != issue.key {
+                relationships.add_relationship(&outward, target.key.clone());
+            }
+        }
+        if let Some(target) = &link.inward_issue {
+            if options.bidirectional || target.key != issue.key {
+                relationships.add_relationship(&inward, target.key.clone());
+            }
+        }
+    }
+
+    relationships.parent = issue.parent().map(|parent| parent.key);
+    relationships
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_query_endpoint() {
+        assert_eq!(IssueQuery::default().endpoint("PROJ-1"), "/issue/PROJ-1");
+        let query = IssueQuery {
+            fields: vec!["created".to_string()],
+            expand: vec!["changelog".to_string()],
+        };
+        assert_eq!(
+            query.endpoint("PROJ-1"),
+            "/issue/PROJ-1?fields=created&expand=changelog"
+        );
+        assert_eq!(
+            resource_path("api", "/issue/PROJ-1?fields=labels"),
+            "api/issue/PROJ-1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mock_serves_fixtures_and_records_writes() {
+        let dir = std::env::temp_dir().join(format!("jira-mock-{}", std::process::id()));
+        let base = dir.join("base");
+        let overlay = dir.join("overlay");
+        std::fs::create_dir_all(base.join("GET/api/issue/_")).unwrap();
+        std::fs::create_dir_all(overlay.join("GET/api/issue")).unwrap();
+        std::fs::write(base.join("GET/api/issue/_.json"), r#"{"key": "ANY-1"}"#).unwrap();
+        std::fs::write(base.join("GET/api/issue/_/votes.json"), r#"{"votes": 1}"#).unwrap();
+        std::fs::write(overlay.join("GET/api/issue/_.json"), r#"{"key": "OVR-1"}"#).unwrap();
+
+        let backend = MockBackend::new(&base).unwrap();
+        let issue = backend
+            .get_issue("PROJ-7", &IssueQuery::default())
+            .await
+            .unwrap();
+        assert_eq!(issue["key"], "ANY-1");
+        let votes = backend.get("api", "/issue/PROJ-7/votes").await.unwrap();
+        assert_eq!(votes["votes"], 1);
+        assert!(matches!(
+            backend.get("api", "/project").await,
+            Err(gouqi::Error::NotFound)
+        ));
+
+        let response = backend
+            .post("api", "/issue/PROJ-7/votes", serde_json::json!({}))
+            .await
+            .unwrap();
+        assert!(response.is_null());
+        assert_eq!(backend.writes_to("POST", "api/issue/PROJ-7/votes").len(), 1);
+        assert_eq!(backend.request_count(), 4);
+
+        // Searches keep the JQL they were sent, counts included
+        std::fs::create_dir_all(base.join("GET/api")).unwrap();
+        std::fs::write(
+            base.join("GET/api/search.json"),
+            r#"{"total": 0, "maxResults": 50, "startAt": 0, "issues": []}"#,
+        )
+        .unwrap();
+        let options = SearchOptions::builder().max_results(10).build();
+        backend.search("project = PROJ", &options).await.unwrap();
+        backend.count_issues("assignee is EMPTY").await.unwrap();
+        assert_eq!(
+            backend.searches(),
+            vec![
+                "project = PROJ".to_string(),
+                "assignee is EMPTY".to_string()
+            ]
+        );
+        assert!(backend
+            .writes_to("POST", "api/search/approximate-count")
+            .is_empty());
+        backend.clear_searches();
+        assert!(backend.searches().is_empty());
+
+        // The first directory of a list wins, the rest fill in
+        let layered = std::env::join_paths([&overlay, &base]).unwrap();
+        let backend = MockBackend::new(layered).unwrap();
+        let issue = backend.get("api", "/issue/PROJ-7").await.unwrap();
+        assert_eq!(issue["key"], "OVR-1");
+        let votes = backend.get("api", "/issue/PROJ-7/votes").await.unwrap();
+        assert_eq!(votes["votes"], 1);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -2,49 +2,188 @@
 //!
 //! Provides TTL-based caching for JIRA metadata to improve performance
 //! and reduce API calls to the JIRA instance.
+//!
+//! Every cached map belongs to a [`CacheCategory`] with its own TTL and size cap
+//! (`cache_policies` in the config); once a map is full, the least recently used
+//! entries are evicted.
+//!
+//! With `cache_persist` enabled the cache is saved to the state directory
+//! periodically and on shutdown, and restored at startup. Restored entries keep
+//! the age they had when saved, so they expire on the original schedule.
 
+use crate::config::{CachePolicies, JiraConfig};
 use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::metrics;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 use tokio::task::JoinHandle;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
+
+const CACHE_FILE: &str = "metadata_cache.json";
+
+/// Bumped whenever the persisted layout changes; other versions are discarded
+const CACHE_FILE_VERSION: u32 = 5;
+
+/// Key of single-value segments (current user, labels, priorities, link types)
+const SINGLE: &str = "";
+
+/// Cache categories, each with its own TTL and size cap
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CacheCategory {
+    Users,
+    Projects,
+    Fields,
+    Boards,
+    #[serde(alias = "issues")]
+    IssueDetails,
+    LinkTypes,
+    Permissions,
+}
+
+impl CacheCategory {
+    pub const ALL: [CacheCategory; 7] = [
+        CacheCategory::Users,
+        CacheCategory::Projects,
+        CacheCategory::Fields,
+        CacheCategory::Boards,
+        CacheCategory::IssueDetails,
+        CacheCategory::LinkTypes,
+        CacheCategory::Permissions,
+    ];
+}
 
 /// Metadata cache with TTL management
 #[derive(Debug)]
 pub struct MetadataCache {
     /// Board name to ID mappings
-    board_mappings: RwLock<HashMap<String, CacheEntry<String>>>,
+    board_mappings: Segment<String>,
 
     /// Board metadata
-    board_info: RwLock<HashMap<String, CacheEntry<BoardInfo>>>,
+    board_info: Segment<BoardInfo>,
+
+    /// Board columns, estimation and filter by board ID
+    board_configurations: Segment<BoardConfiguration>,
 
     /// Project key to info mappings
-    project_info: RwLock<HashMap<String, CacheEntry<ProjectInfo>>>,
+    project_info: Segment<ProjectInfo>,
 
-    /// Issue types per project
-    project_issue_types: RwLock<HashMap<String, CacheEntry<Vec<IssueTypeInfo>>>>,
+    /// Issue types per project ("" holds the instance-wide list)
+    project_issue_types: Segment<Vec<IssueTypeInfo>>,
 
     /// User account ID mappings
-    user_mappings: RwLock<HashMap<String, CacheEntry<UserMapping>>>,
+    user_mappings: Segment<UserMapping>,
 
     /// Current user cache
-    current_user: RwLock<Option<CacheEntry<UserMapping>>>,
+    current_user: Segment<UserMapping>,
+
+    /// Instance-wide label list
+    labels: Segment<Vec<String>>,
+
+    /// Instance-wide priority list
+    priorities: Segment<Vec<PriorityInfo>>,
+
+    /// Status catalogs by project key ("" holds the instance-wide list)
+    statuses: Segment<Vec<StatusInfo>>,
+
+    /// Issue security levels by project key
+    security_levels: Segment<Vec<SecurityLevelInfo>>,
+
+    /// Instance-wide issue link types
+    link_types: Segment<Vec<IssueLinkTypeInfo>>,
 
-    /// Cache configuration
+    /// Permissions of the current user by project or issue key ("" for any project)
+    permissions: Segment<BTreeMap<String, bool>>,
+
+    /// TTL for categories without their own
     ttl: Duration,
 
+    /// Per-category TTLs and size caps
+    policies: CachePolicies,
+
+    /// Hit, miss and eviction counts per category
+    counters: HashMap<CacheCategory, CategoryCounters>,
+
+    /// Reference point for entry access times
+    epoch: Instant,
+
+    /// File the cache is persisted to, if persistence is enabled
+    persist_path: Option<PathBuf>,
+
     /// Cleanup task handle
     #[allow(dead_code)]
     cleanup_task: Option<JoinHandle<()>>,
 }
 
+/// One cached map and the category it counts towards
+#[derive(Debug)]
+struct Segment<T> {
+    category: CacheCategory,
+    entries: RwLock<HashMap<String, CacheEntry<T>>>,
+}
+
+impl<T> Segment<T> {
+    fn new(category: CacheCategory) -> Self {
+        Self {
+            category,
+            entries: RwLock::new(HashMap::new()),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.entries.read().map(|e| e.len()).unwrap_or(0)
+    }
+}
+
+#[derive(Debug, Default)]
+struct CategoryCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+    expired: AtomicU64,
+}
+
 /// Cache entry with timestamp
-#[derive(Debug, Clone)]
+#[derive(Debug)]
 struct CacheEntry<T> {
     value: T,
     created_at: Instant,
+    /// Milliseconds since the cache epoch of the last read or write, for LRU eviction
+    last_used: AtomicU64,
+    /// Loaded from the persisted cache rather than fetched this session
+    restored: bool,
+}
+
+/// Cache entry as written to disk, with a wall-clock fetch time
+#[derive(Debug, Serialize, Deserialize)]
+struct StoredEntry<T> {
+    value: T,
+    fetched_at: DateTime<Utc>,
+}
+
+/// Persisted cache segments (the current user and permissions are always re-fetched)
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CacheSnapshot {
+    version: u32,
+    board_mappings: HashMap<String, StoredEntry<String>>,
+    board_info: HashMap<String, StoredEntry<BoardInfo>>,
+    board_configurations: HashMap<String, StoredEntry<BoardConfiguration>>,
+    project_info: HashMap<String, StoredEntry<ProjectInfo>>,
+    project_issue_types: HashMap<String, StoredEntry<Vec<IssueTypeInfo>>>,
+    user_mappings: HashMap<String, StoredEntry<UserMapping>>,
+    labels: HashMap<String, StoredEntry<Vec<String>>>,
+    priorities: HashMap<String, StoredEntry<Vec<PriorityInfo>>>,
+    statuses: HashMap<String, StoredEntry<Vec<StatusInfo>>>,
+    security_levels: HashMap<String, StoredEntry<Vec<SecurityLevelInfo>>>,
+    link_types: HashMap<String, StoredEntry<Vec<IssueLinkTypeInfo>>>,
 }
 
 /// Board information
@@ -54,6 +193,68 @@ pub struct BoardInfo {
     pub name: String,
     pub type_: String, // scrum, kanban, etc.
     pub project_key: Option<String>,
+    /// Saved filter behind the board, for boards without a project location
+    #[serde(default)]
+    pub filter_id: Option<String>,
+}
+
+/// Column layout, estimation and saved filter of an agile board
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardConfiguration {
+    pub board_id: u64,
+    pub board_name: String,
+
+    /// Saved filter that selects the board's issues
+    pub filter_id: Option<String>,
+
+    /// JQL of that filter; None when it could not be read, e.g. a filter
+    /// that is not shared with the user
+    pub filter_jql: Option<String>,
+
+    /// Kanban sub-filter applied on top, e.g. hiding released issues
+    pub sub_query: Option<String>,
+
+    /// Columns from left to right
+    pub columns: Vec<BoardColumn>,
+
+    /// What the board estimates in; None when the board has no estimation
+    pub estimation: Option<BoardEstimation>,
+}
+
+/// A board column and the statuses mapped to it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardColumn {
+    pub name: String,
+    pub statuses: Vec<BoardColumnStatus>,
+
+    /// Column constraints, when set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max: Option<u64>,
+}
+
+/// A status mapped to a board column; the board only reports its ID
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardColumnStatus {
+    pub id: String,
+
+    /// Name and category key from the status catalog, when it lists the status
+    pub name: Option<String>,
+    pub category_key: Option<String>,
+}
+
+/// Statistic a board estimates in
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardEstimation {
+    /// "field" for a numeric field, or "issueCount"
+    pub estimation_type: String,
+
+    /// Field ID, e.g. "customfield_10002", for field estimation
+    pub field_id: Option<String>,
+
+    /// Field name as shown on the board, e.g. "Story Points"
+    pub field_name: Option<String>,
 }
 
 /// Project information
@@ -63,6 +264,9 @@ pub struct ProjectInfo {
     pub name: String,
     pub project_type: String,
     pub lead: Option<String>,
+    /// Team-managed (next-gen) Cloud project, which links epics through `parent`
+    #[serde(default)]
+    pub team_managed: bool,
 }
 
 /// Issue type information
@@ -72,6 +276,60 @@ pub struct IssueTypeInfo {
     pub name: String,
     pub description: Option<String>,
     pub subtask: bool,
+    /// English name on instances with localized type names (Cloud only)
+    #[serde(default)]
+    pub untranslated_name: Option<String>,
+    /// 1 for epics, 0 for standard types, -1 for sub-tasks (when reported)
+    #[serde(default)]
+    pub hierarchy_level: Option<i64>,
+}
+
+/// Priority information
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PriorityInfo {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Status information with its status category
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusInfo {
+    pub id: String,
+    pub name: String,
+    /// Category key: "new", "indeterminate" or "done"
+    pub category_key: String,
+    /// Category display name, e.g. "To Do", "In Progress", "Done"
+    pub category_name: String,
+}
+
+/// Issue security level of a project
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SecurityLevelInfo {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// Information about an issue link type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueLinkTypeInfo {
+    /// Link type ID
+    pub id: String,
+
+    /// Link type name (use this when creating links)
+    pub name: String,
+
+    /// Description when viewing from the inward issue
+    /// Example: "is blocked by"
+    pub inward: String,
+
+    /// Description when viewing from the outward issue
+    /// Example: "blocks"
+    pub outward: String,
+
+    /// Direct link to the link type
+    pub self_link: String,
 }
 
 /// User mapping information
@@ -88,198 +346,431 @@ impl<T> CacheEntry<T> {
         Self {
             value,
             created_at: Instant::now(),
+            last_used: AtomicU64::new(0),
+            restored: false,
         }
     }
 
     fn is_expired(&self, ttl: Duration) -> bool {
         self.created_at.elapsed() > ttl
     }
+
+    fn touch(&self, now: u64) {
+        self.last_used.store(now, Ordering::Relaxed);
+    }
+
+    /// Rebuild an entry from disk with its original age; None once expired
+    fn restore(stored: StoredEntry<T>, ttl: Duration) -> Option<Self> {
+        let age = (Utc::now() - stored.fetched_at)
+            .to_std()
+            .unwrap_or_default();
+        if age > ttl {
+            return None;
+        }
+
+        Some(Self {
+            created_at: Instant::now().checked_sub(age)?,
+            restored: true,
+            ..Self::new(stored.value)
+        })
+    }
+}
+
+impl<T: Clone> CacheEntry<T> {
+    fn store(&self) -> StoredEntry<T> {
+        let age = chrono::Duration::from_std(self.created_at.elapsed()).unwrap_or_default();
+        StoredEntry {
+            value: self.value.clone(),
+            fetched_at: Utc::now() - age,
+        }
+    }
 }
 
 impl MetadataCache {
-    /// Create a new metadata cache with the given TTL
+    /// Create a new metadata cache with the given TTL and default category policies
     pub fn new(ttl_seconds: u64) -> Self {
+        Self::with_policies(ttl_seconds, CachePolicies::default())
+    }
+
+    /// Create a cache with per-category TTLs and size caps
+    pub fn with_policies(ttl_seconds: u64, policies: CachePolicies) -> Self {
         Self {
-            board_mappings: RwLock::new(HashMap::new()),
-            board_info: RwLock::new(HashMap::new()),
-            project_info: RwLock::new(HashMap::new()),
-            project_issue_types: RwLock::new(HashMap::new()),
-            user_mappings: RwLock::new(HashMap::new()),
-            current_user: RwLock::new(None),
+            board_mappings: Segment::new(CacheCategory::Boards),
+            board_info: Segment::new(CacheCategory::Boards),
+            board_configurations: Segment::new(CacheCategory::Boards),
+            project_info: Segment::new(CacheCategory::Projects),
+            project_issue_types: Segment::new(CacheCategory::Projects),
+            user_mappings: Segment::new(CacheCategory::Users),
+            current_user: Segment::new(CacheCategory::Users),
+            labels: Segment::new(CacheCategory::Fields),
+            priorities: Segment::new(CacheCategory::Fields),
+            statuses: Segment::new(CacheCategory::Projects),
+            security_levels: Segment::new(CacheCategory::Projects),
+            link_types: Segment::new(CacheCategory::LinkTypes),
+            permissions: Segment::new(CacheCategory::Permissions),
             ttl: Duration::from_secs(ttl_seconds),
+            policies,
+            counters: CacheCategory::ALL
+                .into_iter()
+                .map(|category| (category, CategoryCounters::default()))
+                .collect(),
+            epoch: Instant::now(),
+            persist_path: None,
             cleanup_task: None,
         }
     }
 
-    /// Start background cleanup task
-    pub fn start_cleanup_task(self: Arc<Self>) -> JoinHandle<()> {
-        let cache = Arc::clone(&self);
-        let cleanup_interval = self.ttl / 2; // Cleanup twice as often as TTL
+    /// Create a cache persisted to `path`, restoring what was saved there
+    pub fn with_persistence(ttl_seconds: u64, path: PathBuf) -> Self {
+        Self::new(ttl_seconds).persisted_to(path)
+    }
 
-        tokio::spawn(async move {
-            let mut interval = tokio::time::interval(cleanup_interval);
-            loop {
-                interval.tick().await;
-                cache.cleanup_expired().await;
+    pub fn from_config(config: &JiraConfig) -> Self {
+        let cache = Self::with_policies(config.cache_ttl_seconds, config.cache_policies.clone());
+        if config.cache_persist {
+            cache.persisted_to(config.state_dir().join(CACHE_FILE))
+        } else {
+            cache
+        }
+    }
+
+    fn persisted_to(mut self, path: PathBuf) -> Self {
+        match std::fs::read_to_string(&path) {
+            Ok(content) => match self.restore(&content) {
+                Ok(restored) => info!(
+                    "Restored {} cache entries from {}",
+                    restored,
+                    path.display()
+                ),
+                Err(e) => warn!("Discarding cache file {}: {}", path.display(), e),
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!("Could not read cache file {}: {}", path.display(), e),
+        }
+
+        self.persist_path = Some(path);
+        self
+    }
+
+    /// TTL of a category
+    pub fn ttl(&self, category: CacheCategory) -> Duration {
+        self.policies
+            .get(category)
+            .ttl_seconds
+            .map_or(self.ttl, Duration::from_secs)
+    }
+
+    fn now(&self) -> u64 {
+        self.epoch.elapsed().as_millis() as u64
+    }
+
+    fn counters(&self, category: CacheCategory) -> &CategoryCounters {
+        &self.counters[&category]
+    }
+
+    /// Look up a live entry, counting the hit or miss
+    fn get<T: Clone>(&self, segment: &Segment<T>, key: &str) -> Option<T> {
+        let ttl = self.ttl(segment.category);
+        let value = segment.entries.read().ok().and_then(|entries| {
+            let entry = entries.get(key).filter(|entry| !entry.is_expired(ttl))?;
+            entry.touch(self.now());
+            Some(entry.value.clone())
+        });
+
+        let counters = self.counters(segment.category);
+        match value {
+            Some(_) => {
+                metrics::record_cache_hit();
+                counters.hits.fetch_add(1, Ordering::Relaxed)
             }
-        })
+            None => counters.misses.fetch_add(1, Ordering::Relaxed),
+        };
+        value
     }
 
-    /// Clean up expired entries
-    async fn cleanup_expired(&self) {
-        debug!("Running cache cleanup");
+    /// Insert an entry, evicting the least recently used ones beyond the cap
+    fn set<T>(&self, segment: &Segment<T>, key: String, value: T, what: &str) -> JiraMcpResult<()> {
+        let mut entries = segment.entries.write().map_err(|_| {
+            JiraMcpError::cache(format!("Failed to acquire write lock for {}", what))
+        })?;
+
+        let entry = CacheEntry::new(value);
+        entry.touch(self.now());
+        entries.insert(key, entry);
 
-        let mut cleaned_count = 0;
-
-        // Clean board mappings
-        if let Ok(mut board_mappings) = self.board_mappings.write() {
-            board_mappings.retain(|_, entry| {
-                let expired = entry.is_expired(self.ttl);
-                if expired {
-                    cleaned_count += 1;
-                }
-                !expired
-            });
+        let evicted = evict_lru(
+            &mut entries,
+            self.policies.get(segment.category).max_entries,
+        );
+        if evicted > 0 {
+            debug!("Evicted {} least recently used {} entries", evicted, what);
+            self.counters(segment.category)
+                .evictions
+                .fetch_add(evicted as u64, Ordering::Relaxed);
         }
+        Ok(())
+    }
 
-        // Clean board info
-        if let Ok(mut board_info) = self.board_info.write() {
-            board_info.retain(|_, entry| {
-                let expired = entry.is_expired(self.ttl);
-                if expired {
-                    cleaned_count += 1;
-                }
-                !expired
-            });
+    /// Drop expired entries and enforce the size cap; returns the number removed
+    fn prune<T>(&self, segment: &Segment<T>) -> usize {
+        let Ok(mut entries) = segment.entries.write() else {
+            return 0;
+        };
+        let ttl = self.ttl(segment.category);
+        let counters = self.counters(segment.category);
+
+        let before = entries.len();
+        entries.retain(|_, entry| !entry.is_expired(ttl));
+        let expired = before - entries.len();
+        counters
+            .expired
+            .fetch_add(expired as u64, Ordering::Relaxed);
+
+        let evicted = evict_lru(
+            &mut entries,
+            self.policies.get(segment.category).max_entries,
+        );
+        counters
+            .evictions
+            .fetch_add(evicted as u64, Ordering::Relaxed);
+
+        expired + evicted
+    }
+
+    fn clear<T>(&self, segment: &Segment<T>, what: &str) -> JiraMcpResult<()> {
+        segment
+            .entries
+            .write()
+            .map_err(|_| JiraMcpError::cache(format!("Failed to clear {}", what)))?
+            .clear();
+        Ok(())
+    }
+
+    /// Remove a segment's entries whose key is in `keys` (all of them when empty)
+    fn invalidate_segment<T>(
+        &self,
+        segment: &Segment<T>,
+        categories: &[CacheCategory],
+        keys: &[String],
+    ) -> usize {
+        if !categories.is_empty() && !categories.contains(&segment.category) {
+            return 0;
         }
+        let Ok(mut entries) = segment.entries.write() else {
+            return 0;
+        };
+
+        let before = entries.len();
+        if keys.is_empty() {
+            entries.clear();
+        } else {
+            entries.retain(|key, _| !keys.iter().any(|k| k.eq_ignore_ascii_case(key)));
+        }
+        before - entries.len()
+    }
+
+    /// Save the cache periodically while persistence is enabled
+    pub fn start_persist_task(self: Arc<Self>, interval: Duration) -> Option<JoinHandle<()>> {
+        self.persist_path.as_ref()?;
+
+        Some(tokio::spawn(async move {
+            let mut interval = tokio::time::interval(interval.max(Duration::from_secs(1)));
+            interval.tick().await; // The first tick completes immediately
+            loop {
+                interval.tick().await;
+                self.persist();
+            }
+        }))
+    }
+
+    /// Write the cache to disk; a no-op unless persistence is enabled
+    pub fn persist(&self) {
+        let Some(path) = &self.persist_path else {
+            return;
+        };
 
-        // Clean project info
-        if let Ok(mut project_info) = self.project_info.write() {
-            project_info.retain(|_, entry| {
-                let expired = entry.is_expired(self.ttl);
-                if expired {
-                    cleaned_count += 1;
-                }
-                !expired
-            });
+        if let Err(e) = self.save(path) {
+            warn!("Failed to persist cache to {}: {}", path.display(), e);
         }
+    }
+
+    fn save(&self, path: &Path) -> std::io::Result<()> {
+        let snapshot = CacheSnapshot {
+            version: CACHE_FILE_VERSION,
+            board_mappings: self.store(&self.board_mappings),
+            board_info: self.store(&self.board_info),
+            board_configurations: self.store(&self.board_configurations),
+            project_info: self.store(&self.project_info),
+            project_issue_types: self.store(&self.project_issue_types),
+            user_mappings: self.store(&self.user_mappings),
+            labels: self.store(&self.labels),
+            priorities: self.store(&self.priorities),
+            statuses: self.store(&self.statuses),
+            security_levels: self.store(&self.security_levels),
+            link_types: self.store(&self.link_types),
+        };
 
-        // Clean project issue types
-        if let Ok(mut project_issue_types) = self.project_issue_types.write() {
-            project_issue_types.retain(|_, entry| {
-                let expired = entry.is_expired(self.ttl);
-                if expired {
-                    cleaned_count += 1;
-                }
-                !expired
-            });
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
         }
+        let tmp_path = path.with_extension("json.tmp");
+        let content = serde_json::to_string(&snapshot).map_err(std::io::Error::other)?;
+        std::fs::write(&tmp_path, content)?;
+        std::fs::rename(&tmp_path, path)
+    }
 
-        // Clean user mappings
-        if let Ok(mut user_mappings) = self.user_mappings.write() {
-            user_mappings.retain(|_, entry| {
-                let expired = entry.is_expired(self.ttl);
-                if expired {
-                    cleaned_count += 1;
-                }
-                !expired
-            });
+    /// Load a saved snapshot, returning how many live entries were restored
+    fn restore(&self, content: &str) -> Result<usize, String> {
+        let raw: serde_json::Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+        let version = raw.get("version").and_then(|v| v.as_u64());
+        if version != Some(CACHE_FILE_VERSION as u64) {
+            return Err(format!(
+                "unsupported version {:?} (expected {})",
+                version, CACHE_FILE_VERSION
+            ));
         }
+        let snapshot: CacheSnapshot = serde_json::from_value(raw).map_err(|e| e.to_string())?;
+
+        Ok(self.load(&self.board_mappings, snapshot.board_mappings)
+            + self.load(&self.board_info, snapshot.board_info)
+            + self.load(&self.board_configurations, snapshot.board_configurations)
+            + self.load(&self.project_info, snapshot.project_info)
+            + self.load(&self.project_issue_types, snapshot.project_issue_types)
+            + self.load(&self.user_mappings, snapshot.user_mappings)
+            + self.load(&self.labels, snapshot.labels)
+            + self.load(&self.priorities, snapshot.priorities)
+            + self.load(&self.statuses, snapshot.statuses)
+            + self.load(&self.security_levels, snapshot.security_levels)
+            + self.load(&self.link_types, snapshot.link_types))
+    }
+
+    fn store<T: Clone>(&self, segment: &Segment<T>) -> HashMap<String, StoredEntry<T>> {
+        let ttl = self.ttl(segment.category);
+        segment
+            .entries
+            .read()
+            .map(|entries| {
+                entries
+                    .iter()
+                    .filter(|(_, entry)| !entry.is_expired(ttl))
+                    .map(|(key, entry)| (key.clone(), entry.store()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
 
-        // Clean current user
-        if let Ok(mut current_user) = self.current_user.write() {
-            if let Some(entry) = current_user.as_ref() {
-                if entry.is_expired(self.ttl) {
-                    *current_user = None;
-                    cleaned_count += 1;
-                }
+    fn load<T>(&self, segment: &Segment<T>, stored: HashMap<String, StoredEntry<T>>) -> usize {
+        let ttl = self.ttl(segment.category);
+        let Ok(mut entries) = segment.entries.write() else {
+            return 0;
+        };
+
+        let before = entries.len();
+        for (key, stored) in stored {
+            if let Some(entry) = CacheEntry::restore(stored, ttl) {
+                entry.touch(self.now());
+                entries.insert(key, entry);
             }
         }
+        let restored = entries.len() - before;
+        evict_lru(
+            &mut entries,
+            self.policies.get(segment.category).max_entries,
+        );
+        restored
+    }
+
+    /// Start background cleanup task
+    pub fn start_cleanup_task(self: Arc<Self>) -> JoinHandle<()> {
+        let cache = Arc::clone(&self);
+        // Cleanup twice as often as the shortest TTL
+        let shortest_ttl = CacheCategory::ALL
+            .into_iter()
+            .map(|category| self.ttl(category))
+            .min()
+            .unwrap_or(self.ttl);
+        let cleanup_interval = (shortest_ttl / 2).max(Duration::from_secs(1));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(cleanup_interval);
+            loop {
+                interval.tick().await;
+                cache.cleanup_expired().await;
+            }
+        })
+    }
+
+    /// Clean up expired entries and enforce size caps
+    async fn cleanup_expired(&self) {
+        debug!("Running cache cleanup");
+
+        let cleaned_count = self.prune(&self.board_mappings)
+            + self.prune(&self.board_info)
+            + self.prune(&self.board_configurations)
+            + self.prune(&self.project_info)
+            + self.prune(&self.project_issue_types)
+            + self.prune(&self.user_mappings)
+            + self.prune(&self.current_user)
+            + self.prune(&self.labels)
+            + self.prune(&self.priorities)
+            + self.prune(&self.statuses)
+            + self.prune(&self.security_levels)
+            + self.prune(&self.link_types)
+            + self.prune(&self.permissions);
 
         if cleaned_count > 0 {
-            debug!("Cleaned {} expired cache entries", cleaned_count);
+            debug!("Cleaned {} expired or evicted cache entries", cleaned_count);
         }
     }
 
     /// Get board ID by name
     pub fn get_board_id(&self, board_name: &str) -> Option<String> {
-        let board_mappings = self.board_mappings.read().ok()?;
-        let entry = board_mappings.get(board_name)?;
-
-        if entry.is_expired(self.ttl) {
-            None
-        } else {
-            Some(entry.value.clone())
-        }
+        self.get(&self.board_mappings, board_name)
     }
 
     /// Set board ID mapping
     pub fn set_board_id(&self, board_name: String, board_id: String) -> JiraMcpResult<()> {
-        let mut board_mappings = self
-            .board_mappings
-            .write()
-            .map_err(|_| JiraMcpError::cache("Failed to acquire write lock for board mappings"))?;
-
-        board_mappings.insert(board_name, CacheEntry::new(board_id));
-        Ok(())
+        self.set(&self.board_mappings, board_name, board_id, "board mappings")
     }
 
     /// Get board info by ID
     pub fn get_board_info(&self, board_id: &str) -> Option<BoardInfo> {
-        let board_info = self.board_info.read().ok()?;
-        let entry = board_info.get(board_id)?;
-
-        if entry.is_expired(self.ttl) {
-            None
-        } else {
-            Some(entry.value.clone())
-        }
+        self.get(&self.board_info, board_id)
     }
 
     /// Set board info
     pub fn set_board_info(&self, board_id: String, info: BoardInfo) -> JiraMcpResult<()> {
-        let mut board_info = self
-            .board_info
-            .write()
-            .map_err(|_| JiraMcpError::cache("Failed to acquire write lock for board info"))?;
+        self.set(&self.board_info, board_id, info, "board info")
+    }
 
-        board_info.insert(board_id, CacheEntry::new(info));
-        Ok(())
+    /// Get a board's configuration by board ID
+    pub fn get_board_configuration(&self, board_id: u64) -> Option<BoardConfiguration> {
+        self.get(&self.board_configurations, &board_id.to_string())
+    }
+
+    /// Set a board's configuration
+    pub fn set_board_configuration(&self, configuration: BoardConfiguration) -> JiraMcpResult<()> {
+        self.set(
+            &self.board_configurations,
+            configuration.board_id.to_string(),
+            configuration,
+            "board configurations",
+        )
     }
 
     /// Get project info by key
     pub fn get_project_info(&self, project_key: &str) -> Option<ProjectInfo> {
-        let project_info = self.project_info.read().ok()?;
-        let entry = project_info.get(project_key)?;
-
-        if entry.is_expired(self.ttl) {
-            None
-        } else {
-            Some(entry.value.clone())
-        }
+        self.get(&self.project_info, project_key)
     }
 
     /// Set project info
     pub fn set_project_info(&self, project_key: String, info: ProjectInfo) -> JiraMcpResult<()> {
-        let mut project_info = self
-            .project_info
-            .write()
-            .map_err(|_| JiraMcpError::cache("Failed to acquire write lock for project info"))?;
-
-        project_info.insert(project_key, CacheEntry::new(info));
-        Ok(())
+        self.set(&self.project_info, project_key, info, "project info")
     }
 
     /// Get issue types for a project
     pub fn get_project_issue_types(&self, project_key: &str) -> Option<Vec<IssueTypeInfo>> {
-        let project_issue_types = self.project_issue_types.read().ok()?;
-        let entry = project_issue_types.get(project_key)?;
-
-        if entry.is_expired(self.ttl) {
-            None
-        } else {
-            Some(entry.value.clone())
-        }
+        self.get(&self.project_issue_types, project_key)
     }
 
     /// Set issue types for a project
@@ -288,58 +779,131 @@ impl MetadataCache {
         project_key: String,
         issue_types: Vec<IssueTypeInfo>,
     ) -> JiraMcpResult<()> {
-        let mut project_issue_types = self.project_issue_types.write().map_err(|_| {
-            JiraMcpError::cache("Failed to acquire write lock for project issue types")
-        })?;
-
-        project_issue_types.insert(project_key, CacheEntry::new(issue_types));
-        Ok(())
+        self.set(
+            &self.project_issue_types,
+            project_key,
+            issue_types,
+            "project issue types",
+        )
     }
 
     /// Get user mapping by identifier (username or email)
     pub fn get_user_mapping(&self, identifier: &str) -> Option<UserMapping> {
-        let user_mappings = self.user_mappings.read().ok()?;
-        let entry = user_mappings.get(identifier)?;
-
-        if entry.is_expired(self.ttl) {
-            None
-        } else {
-            Some(entry.value.clone())
-        }
+        self.get(&self.user_mappings, identifier)
     }
 
     /// Set user mapping
     pub fn set_user_mapping(&self, identifier: String, mapping: UserMapping) -> JiraMcpResult<()> {
-        let mut user_mappings = self
-            .user_mappings
-            .write()
-            .map_err(|_| JiraMcpError::cache("Failed to acquire write lock for user mappings"))?;
-
-        user_mappings.insert(identifier, CacheEntry::new(mapping));
-        Ok(())
+        self.set(&self.user_mappings, identifier, mapping, "user mappings")
     }
 
     /// Get current user
     pub fn get_current_user(&self) -> Option<UserMapping> {
-        let current_user = self.current_user.read().ok()?;
-        let entry = current_user.as_ref()?;
-
-        if entry.is_expired(self.ttl) {
-            None
-        } else {
-            Some(entry.value.clone())
-        }
+        self.get(&self.current_user, SINGLE)
     }
 
     /// Set current user
     pub fn set_current_user(&self, user: UserMapping) -> JiraMcpResult<()> {
-        let mut current_user = self
-            .current_user
-            .write()
-            .map_err(|_| JiraMcpError::cache("Failed to acquire write lock for current user"))?;
+        self.set(&self.current_user, SINGLE.to_string(), user, "current user")
+    }
 
-        *current_user = Some(CacheEntry::new(user));
-        Ok(())
+    /// Get the cached instance-wide label list
+    pub fn get_labels(&self) -> Option<Vec<String>> {
+        self.get(&self.labels, SINGLE)
+    }
+
+    /// Set the instance-wide label list
+    pub fn set_labels(&self, labels: Vec<String>) -> JiraMcpResult<()> {
+        self.set(&self.labels, SINGLE.to_string(), labels, "labels")
+    }
+
+    /// Get the cached instance-wide priority list
+    pub fn get_priorities(&self) -> Option<Vec<PriorityInfo>> {
+        self.get(&self.priorities, SINGLE)
+    }
+
+    /// Set the instance-wide priority list
+    pub fn set_priorities(&self, priorities: Vec<PriorityInfo>) -> JiraMcpResult<()> {
+        self.set(
+            &self.priorities,
+            SINGLE.to_string(),
+            priorities,
+            "priorities",
+        )
+    }
+
+    /// Get the cached status catalog for a project, or the instance-wide one for `None`
+    pub fn get_statuses(&self, project_key: Option<&str>) -> Option<Vec<StatusInfo>> {
+        self.get(&self.statuses, project_key.unwrap_or(SINGLE))
+    }
+
+    /// Set the status catalog for a project, or the instance-wide one for `None`
+    pub fn set_statuses(
+        &self,
+        project_key: Option<&str>,
+        statuses: Vec<StatusInfo>,
+    ) -> JiraMcpResult<()> {
+        self.set(
+            &self.statuses,
+            project_key.unwrap_or(SINGLE).to_string(),
+            statuses,
+            "statuses",
+        )
+    }
+
+    /// Get the cached security levels of a project
+    pub fn get_security_levels(&self, project_key: &str) -> Option<Vec<SecurityLevelInfo>> {
+        self.get(&self.security_levels, project_key)
+    }
+
+    /// Set the security levels of a project
+    pub fn set_security_levels(
+        &self,
+        project_key: String,
+        levels: Vec<SecurityLevelInfo>,
+    ) -> JiraMcpResult<()> {
+        self.set(
+            &self.security_levels,
+            project_key,
+            levels,
+            "security levels",
+        )
+    }
+
+    /// Get the cached issue link types
+    pub fn get_link_types(&self) -> Option<Vec<IssueLinkTypeInfo>> {
+        self.get(&self.link_types, SINGLE)
+    }
+
+    /// Set the issue link types
+    pub fn set_link_types(&self, link_types: Vec<IssueLinkTypeInfo>) -> JiraMcpResult<()> {
+        self.set(
+            &self.link_types,
+            SINGLE.to_string(),
+            link_types,
+            "link types",
+        )
+    }
+
+    /// Get the cached permissions of the current user in a project or on an
+    /// issue (None for any project)
+    pub fn get_permissions(&self, key: Option<&str>) -> Option<BTreeMap<String, bool>> {
+        self.get(&self.permissions, key.unwrap_or(SINGLE))
+    }
+
+    /// Set the permissions of the current user in a project or on an issue
+    /// (None for any project)
+    pub fn set_permissions(
+        &self,
+        key: Option<&str>,
+        permissions: BTreeMap<String, bool>,
+    ) -> JiraMcpResult<()> {
+        self.set(
+            &self.permissions,
+            key.unwrap_or(SINGLE).to_string(),
+            permissions,
+            "permissions",
+        )
     }
 
     /// Resolve "me" or "current_user" to account ID
@@ -355,75 +919,178 @@ impl MetadataCache {
 
     /// Get cache statistics
     pub fn get_stats(&self) -> CacheStats {
-        let board_mappings_count = self.board_mappings.read().map(|m| m.len()).unwrap_or(0);
-
-        let board_info_count = self.board_info.read().map(|m| m.len()).unwrap_or(0);
-
-        let project_info_count = self.project_info.read().map(|m| m.len()).unwrap_or(0);
-
-        let project_issue_types_count = self
-            .project_issue_types
+        let labels_count = self
+            .labels
+            .entries
             .read()
-            .map(|m| m.len())
+            .map(|e| e.get(SINGLE).map_or(0, |entry| entry.value.len()))
             .unwrap_or(0);
 
-        let user_mappings_count = self.user_mappings.read().map(|m| m.len()).unwrap_or(0);
-
-        let has_current_user = self
-            .current_user
+        let priorities_count = self
+            .priorities
+            .entries
             .read()
-            .map(|u| u.is_some())
-            .unwrap_or(false);
+            .map(|e| e.get(SINGLE).map_or(0, |entry| entry.value.len()))
+            .unwrap_or(0);
+
+        let mut categories: BTreeMap<CacheCategory, CategoryStats> = CacheCategory::ALL
+            .into_iter()
+            .map(|category| (category, self.category_stats(category)))
+            .collect();
+        self.tally(&self.board_mappings, &mut categories);
+        self.tally(&self.board_info, &mut categories);
+        self.tally(&self.board_configurations, &mut categories);
+        self.tally(&self.project_info, &mut categories);
+        self.tally(&self.project_issue_types, &mut categories);
+        self.tally(&self.user_mappings, &mut categories);
+        self.tally(&self.current_user, &mut categories);
+        self.tally(&self.labels, &mut categories);
+        self.tally(&self.priorities, &mut categories);
+        self.tally(&self.statuses, &mut categories);
+        self.tally(&self.security_levels, &mut categories);
+        self.tally(&self.link_types, &mut categories);
+        self.tally(&self.permissions, &mut categories);
+
+        let restored_entries = categories.values().map(|c| c.restored_entries).sum();
+        let fetched_entries = categories
+            .values()
+            .map(|c| c.entries - c.restored_entries)
+            .sum();
 
         CacheStats {
-            board_mappings_count,
-            board_info_count,
-            project_info_count,
-            project_issue_types_count,
-            user_mappings_count,
-            has_current_user,
+            board_mappings_count: self.board_mappings.len(),
+            board_info_count: self.board_info.len(),
+            project_info_count: self.project_info.len(),
+            project_issue_types_count: self.project_issue_types.len(),
+            user_mappings_count: self.user_mappings.len(),
+            has_current_user: self.current_user.len() > 0,
+            labels_count,
+            priorities_count,
+            status_catalogs_count: self.statuses.len(),
+            has_link_types: self.link_types.len() > 0,
+            restored_entries,
+            fetched_entries,
             ttl_seconds: self.ttl.as_secs(),
+            persisted: self.persist_path.is_some(),
+            categories,
         }
     }
 
-    /// Clear all cache entries
-    pub fn clear_all(&self) -> JiraMcpResult<()> {
-        info!("Clearing all cache entries");
-
-        self.board_mappings
-            .write()
-            .map_err(|_| JiraMcpError::cache("Failed to clear board mappings"))?
-            .clear();
+    /// Counters and policy of a category, without entry counts
+    fn category_stats(&self, category: CacheCategory) -> CategoryStats {
+        let counters = self.counters(category);
+        let hits = counters.hits.load(Ordering::Relaxed);
+        let misses = counters.misses.load(Ordering::Relaxed);
+
+        CategoryStats {
+            entries: 0,
+            restored_entries: 0,
+            hits,
+            misses,
+            hit_rate: if hits + misses == 0 {
+                0.0
+            } else {
+                hits as f64 / (hits + misses) as f64
+            },
+            evictions: counters.evictions.load(Ordering::Relaxed),
+            expired: counters.expired.load(Ordering::Relaxed),
+            ttl_seconds: self.ttl(category).as_secs(),
+            max_entries: self.policies.get(category).max_entries,
+        }
+    }
 
-        self.board_info
-            .write()
-            .map_err(|_| JiraMcpError::cache("Failed to clear board info"))?
-            .clear();
+    /// Add a segment's entry counts to its category
+    fn tally<T>(
+        &self,
+        segment: &Segment<T>,
+        categories: &mut BTreeMap<CacheCategory, CategoryStats>,
+    ) {
+        let Ok(entries) = segment.entries.read() else {
+            return;
+        };
+        if let Some(stats) = categories.get_mut(&segment.category) {
+            stats.entries += entries.len();
+            stats.restored_entries += entries.values().filter(|entry| entry.restored).count();
+        }
+    }
 
-        self.project_info
-            .write()
-            .map_err(|_| JiraMcpError::cache("Failed to clear project info"))?
-            .clear();
+    /// Drop entries of the given categories (all categories when empty)
+    ///
+    /// With `keys`, only entries cached under one of them (project key, issue key,
+    /// board name or ID, user identifier; case-insensitive) are dropped; otherwise
+    /// the categories are emptied. Returns the number of entries removed.
+    pub fn invalidate(&self, categories: &[CacheCategory], keys: &[String]) -> usize {
+        let removed = self.invalidate_segment(&self.board_mappings, categories, keys)
+            + self.invalidate_segment(&self.board_info, categories, keys)
+            + self.invalidate_segment(&self.board_configurations, categories, keys)
+            + self.invalidate_segment(&self.project_info, categories, keys)
+            + self.invalidate_segment(&self.project_issue_types, categories, keys)
+            + self.invalidate_segment(&self.user_mappings, categories, keys)
+            + self.invalidate_segment(&self.current_user, categories, keys)
+            + self.invalidate_segment(&self.labels, categories, keys)
+            + self.invalidate_segment(&self.priorities, categories, keys)
+            + self.invalidate_segment(&self.statuses, categories, keys)
+            + self.invalidate_segment(&self.security_levels, categories, keys)
+            + self.invalidate_segment(&self.link_types, categories, keys)
+            + self.invalidate_segment(&self.permissions, categories, keys);
+
+        if removed > 0 {
+            debug!("Invalidated {} cache entries", removed);
+            self.persist();
+        }
+        removed
+    }
 
-        self.project_issue_types
-            .write()
-            .map_err(|_| JiraMcpError::cache("Failed to clear project issue types"))?
-            .clear();
+    /// Drop the instance-wide label list, after a write that may have created labels
+    pub fn invalidate_labels(&self) {
+        if self.invalidate_segment(&self.labels, &[], &[]) > 0 {
+            debug!("Invalidated cached labels");
+        }
+    }
 
-        self.user_mappings
-            .write()
-            .map_err(|_| JiraMcpError::cache("Failed to clear user mappings"))?
-            .clear();
+    /// Clear all cache entries
+    pub fn clear_all(&self) -> JiraMcpResult<()> {
+        info!("Clearing all cache entries");
 
-        *self
-            .current_user
-            .write()
-            .map_err(|_| JiraMcpError::cache("Failed to clear current user"))? = None;
+        self.clear(&self.board_mappings, "board mappings")?;
+        self.clear(&self.board_info, "board info")?;
+        self.clear(&self.board_configurations, "board configurations")?;
+        self.clear(&self.project_info, "project info")?;
+        self.clear(&self.project_issue_types, "project issue types")?;
+        self.clear(&self.user_mappings, "user mappings")?;
+        self.clear(&self.current_user, "current user")?;
+        self.clear(&self.labels, "labels")?;
+        self.clear(&self.priorities, "priorities")?;
+        self.clear(&self.statuses, "statuses")?;
+        self.clear(&self.security_levels, "security levels")?;
+        self.clear(&self.link_types, "link types")?;
+        self.clear(&self.permissions, "permissions")?;
+
+        // Otherwise the cleared entries would come back on the next start
+        self.persist();
 
         Ok(())
     }
 }
 
+/// Remove the least recently used entries beyond `max_entries`; returns how many
+fn evict_lru<T>(entries: &mut HashMap<String, CacheEntry<T>>, max_entries: usize) -> usize {
+    let excess = entries.len().saturating_sub(max_entries);
+    if excess == 0 {
+        return 0;
+    }
+
+    let mut by_use: Vec<(u64, String)> = entries
+        .iter()
+        .map(|(key, entry)| (entry.last_used.load(Ordering::Relaxed), key.clone()))
+        .collect();
+    by_use.sort();
+    for (_, key) in by_use.into_iter().take(excess) {
+        entries.remove(&key);
+    }
+    excess
+}
+
 /// Cache statistics
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheStats {
@@ -433,7 +1100,38 @@ pub struct CacheStats {
     pub project_issue_types_count: usize,
     pub user_mappings_count: usize,
     pub has_current_user: bool,
+    pub labels_count: usize,
+    pub priorities_count: usize,
+    pub status_catalogs_count: usize,
+    pub has_link_types: bool,
+    /// Entries restored from the persisted cache at startup
+    pub restored_entries: usize,
+    /// Entries fetched from JIRA this session
+    pub fetched_entries: usize,
+    /// Default TTL, for categories without their own
     pub ttl_seconds: u64,
+    /// Whether the cache is persisted to the state directory
+    pub persisted: bool,
+    /// Entry counts, hit rates and evictions per category
+    pub categories: BTreeMap<CacheCategory, CategoryStats>,
+}
+
+/// Statistics for one cache category
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryStats {
+    pub entries: usize,
+    /// Entries restored from the persisted cache
+    pub restored_entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+    /// hits / (hits + misses), 0 before the first lookup
+    pub hit_rate: f64,
+    /// Entries dropped to stay under max_entries
+    pub evictions: u64,
+    /// Entries dropped by the cleanup task after their TTL
+    pub expired: u64,
+    pub ttl_seconds: u64,
+    pub max_entries: usize,
 }
 
 #[cfg(test)]
@@ -510,6 +1208,159 @@ mod tests {
         assert_eq!(stats.board_mappings_count, 1);
     }
 
+    #[test]
+    fn test_labels_cache() {
+        let cache = MetadataCache::new(300);
+        assert!(cache.get_labels().is_none());
+
+        cache
+            .set_labels(vec!["backend".to_string(), "urgent".to_string()])
+            .unwrap();
+        assert_eq!(cache.get_labels().unwrap().len(), 2);
+        assert_eq!(cache.get_stats().labels_count, 2);
+
+        cache.clear_all().unwrap();
+        assert!(cache.get_labels().is_none());
+    }
+
+    #[test]
+    fn test_priorities_cache() {
+        let cache = MetadataCache::new(300);
+        assert!(cache.get_priorities().is_none());
+
+        cache
+            .set_priorities(vec![PriorityInfo {
+                id: "1".to_string(),
+                name: "Highest".to_string(),
+                description: None,
+            }])
+            .unwrap();
+        assert_eq!(cache.get_priorities().unwrap()[0].name, "Highest");
+        assert_eq!(cache.get_stats().priorities_count, 1);
+
+        cache.clear_all().unwrap();
+        assert!(cache.get_priorities().is_none());
+    }
+
+    #[test]
+    fn test_cache_persistence() {
+        let path = std::env::temp_dir().join(format!("jira-cache-{}.json", std::process::id()));
+        std::fs::remove_file(&path).ok();
+
+        let cache = MetadataCache::with_persistence(300, path.clone());
+        cache
+            .set_board_id("Team Board".to_string(), "42".to_string())
+            .unwrap();
+        cache.set_labels(vec!["backend".to_string()]).unwrap();
+        cache.persist();
+
+        let restored = MetadataCache::with_persistence(300, path.clone());
+        assert_eq!(restored.get_board_id("Team Board"), Some("42".to_string()));
+        assert_eq!(restored.get_labels().unwrap(), vec!["backend"]);
+        restored
+            .set_board_id("Other".to_string(), "7".to_string())
+            .unwrap();
+        let stats = restored.get_stats();
+        assert_eq!((stats.restored_entries, stats.fetched_entries), (2, 1));
+        assert!(stats.persisted);
+
+        // Entries keep their original age, so a shorter TTL drops them
+        let mut snapshot: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        snapshot["labels"][""]["fetched_at"] = serde_json::json!("2020-01-01T00:00:00Z");
+        std::fs::write(&path, snapshot.to_string()).unwrap();
+        let aged = MetadataCache::with_persistence(300, path.clone());
+        assert!(aged.get_labels().is_none());
+        assert!(aged.get_board_id("Team Board").is_some());
+
+        // Corrupt and version-mismatched files are discarded
+        std::fs::write(&path, "{not json").unwrap();
+        let corrupt = MetadataCache::with_persistence(300, path.clone());
+        assert_eq!(corrupt.get_stats().restored_entries, 0);
+        snapshot["version"] = serde_json::json!(CACHE_FILE_VERSION + 1);
+        std::fs::write(&path, snapshot.to_string()).unwrap();
+        let mismatched = MetadataCache::with_persistence(300, path.clone());
+        assert!(mismatched.get_board_id("Team Board").is_none());
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[test]
+    fn test_category_policies() {
+        let mut policies = CachePolicies::default();
+        policies.fields.ttl_seconds = Some(0);
+        policies.boards.max_entries = 2;
+        let cache = MetadataCache::with_policies(300, policies);
+
+        // Fields expire immediately while the other categories use the default TTL
+        cache.set_labels(vec!["backend".to_string()]).unwrap();
+        std::thread::sleep(Duration::from_millis(5));
+        assert!(cache.get_labels().is_none());
+        assert_eq!(cache.ttl(CacheCategory::Boards), Duration::from_secs(300));
+
+        // The least recently used board is evicted once the cap is reached
+        for (name, id) in [("a", "1"), ("b", "2")] {
+            cache
+                .set_board_id(name.to_string(), id.to_string())
+                .unwrap();
+            std::thread::sleep(Duration::from_millis(2));
+        }
+        assert!(cache.get_board_id("a").is_some());
+        std::thread::sleep(Duration::from_millis(2));
+        cache
+            .set_board_id("c".to_string(), "3".to_string())
+            .unwrap();
+        assert!(cache.get_board_id("a").is_some());
+        assert!(cache.get_board_id("b").is_none());
+
+        let stats = cache.get_stats();
+        let boards = &stats.categories[&CacheCategory::Boards];
+        assert_eq!(boards.entries, 2);
+        assert_eq!(boards.evictions, 1);
+        assert_eq!((boards.hits, boards.misses), (2, 1));
+        assert!((boards.hit_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(stats.categories[&CacheCategory::Fields].misses, 1);
+    }
+
+    #[test]
+    fn test_invalidate_by_category_and_key() {
+        let cache = MetadataCache::new(300);
+        let project = |key: &str| ProjectInfo {
+            key: key.to_string(),
+            name: key.to_string(),
+            project_type: "software".to_string(),
+            lead: None,
+            team_managed: false,
+        };
+        cache
+            .set_project_info("PROJ".to_string(), project("PROJ"))
+            .unwrap();
+        cache
+            .set_project_info("OTHER".to_string(), project("OTHER"))
+            .unwrap();
+        cache.set_statuses(Some("PROJ"), Vec::new()).unwrap();
+        cache.set_labels(vec!["backend".to_string()]).unwrap();
+        cache
+            .set_board_id("Team".to_string(), "7".to_string())
+            .unwrap();
+
+        // Keys only match entries cached under them, across the chosen categories
+        let removed = cache.invalidate(&[CacheCategory::Projects], &["proj".to_string()]);
+        assert_eq!(removed, 2);
+        assert!(cache.get_project_info("PROJ").is_none());
+        assert!(cache.get_statuses(Some("PROJ")).is_none());
+        assert!(cache.get_project_info("OTHER").is_some());
+
+        // A category without keys is emptied; others are left alone
+        assert_eq!(cache.invalidate(&[CacheCategory::Fields], &[]), 1);
+        assert!(cache.get_labels().is_none());
+        assert!(cache.get_board_id("Team").is_some());
+
+        // "issues" is accepted as the name of the issue details category
+        let category: CacheCategory = serde_json::from_str("\"issues\"").unwrap();
+        assert_eq!(category, CacheCategory::IssueDetails);
+    }
+
     #[tokio::test]
     async fn test_cache_expiration() {
         let cache = Arc::new(MetadataCache::new(1)); // 1 second TTL
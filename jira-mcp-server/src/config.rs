@@ -3,13 +3,31 @@
 //! Handles loading configuration from environment variables, TOML files,
 //! and provides sensible defaults for all settings.
 
+use crate::cache::CacheCategory;
+use crate::redaction::Redactor;
 use anyhow::{Context, Result};
+use chrono::FixedOffset;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use tracing::{debug, info, warn};
 
+/// `field_mappings` key for the start date custom field
+pub const START_DATE_FIELD: &str = "start_date";
+
+/// `field_mappings` key for the story points custom field
+pub const STORY_POINTS_FIELD: &str = "story_points";
+
+/// `field_mappings` key for the acceptance criteria custom field
+pub const ACCEPTANCE_CRITERIA_FIELD: &str = "acceptance_criteria";
+
+/// `field_mappings` key for the Epic Link custom field of company-managed projects
+pub const EPIC_LINK_FIELD: &str = "epic_link";
+
+/// `field_mappings` key for the "Checklist for Jira" custom field
+pub const CHECKLIST_FIELD: &str = "checklist";
+
 /// Main configuration structure for the JIRA MCP Server
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JiraConfig {
@@ -22,20 +40,719 @@ pub struct JiraConfig {
     /// Cache TTL in seconds (default: 300 = 5 minutes)
     pub cache_ttl_seconds: u64,
 
+    /// Persist cached metadata to the state directory so it survives restarts
+    /// (default: false)
+    #[serde(default)]
+    pub cache_persist: bool,
+
+    /// How often the persisted cache is saved, in seconds (default: 60); it is
+    /// also saved on shutdown
+    #[serde(default = "default_cache_persist_interval_seconds")]
+    pub cache_persist_interval_seconds: u64,
+
+    /// Per-category cache TTLs and size caps; unset TTLs use cache_ttl_seconds
+    #[serde(default)]
+    pub cache_policies: CachePolicies,
+
+    /// Prefetch common metadata in the background after startup (default: false)
+    #[serde(default)]
+    pub cache_warmup: bool,
+
+    /// Projects whose statuses and create metadata are prefetched by the warm-up
+    #[serde(default)]
+    pub cache_warmup_projects: Vec<String>,
+
+    /// Deployment type, overriding detection via /serverInfo (optional)
+    /// Set it when serverInfo is restricted on the instance
+    #[serde(default)]
+    pub deployment_type: Option<DeploymentType>,
+
     /// Maximum search results to return (default: 50, max: 200)
     pub max_search_results: u32,
 
     /// HTTP request timeout in seconds (default: 30)
     pub request_timeout_seconds: u64,
 
+    /// Per-operation-class timeouts; unset classes derive from request_timeout_seconds
+    #[serde(default)]
+    pub timeouts: RequestTimeouts,
+
+    /// Automatic retries of idempotent GET requests (default: off)
+    #[serde(default)]
+    pub retry: RetryConfig,
+
+    /// How old the last known connection state may get before get_server_status
+    /// probes JIRA again, in seconds (default: 300)
+    #[serde(default = "default_connection_check_interval_seconds")]
+    pub connection_check_interval_seconds: u64,
+
+    /// How long shutdown waits for in-flight writes to finish, in seconds
+    /// (default: 30)
+    #[serde(default = "default_shutdown_timeout_seconds")]
+    pub shutdown_timeout_seconds: u64,
+
     /// Rate limit per minute (default: 60)
     pub rate_limit_per_minute: u32,
 
+    /// Debug logging of outbound JIRA requests (default: off)
+    #[serde(default)]
+    pub request_log: RequestLogConfig,
+
+    /// Largest serialized tool result in characters before lists and long strings
+    /// are trimmed; 0 disables the limit (default: 100000)
+    #[serde(default = "default_max_response_chars")]
+    pub max_response_chars: usize,
+
+    /// Longest descriptions and comments written to JIRA, checked before sending
+    #[serde(default)]
+    pub write_limits: WriteLimitsConfig,
+
     /// Custom issue type mappings (semantic -> JIRA names)
     pub issue_type_mappings: HashMap<String, Vec<String>>,
 
     /// Custom status category mappings (semantic -> JIRA statuses)
     pub status_category_mappings: HashMap<String, Vec<String>>,
+
+    /// Custom field IDs by semantic name, for fields that differ between instances
+    /// (e.g. start_date = "customfield_10015"); story_points and acceptance_criteria
+    /// are detected from the field catalog when not set
+    #[serde(default)]
+    pub field_mappings: HashMap<String, String>,
+
+    /// Custom priority aliases (semantic -> JIRA priority names, first match wins);
+    /// entries override the built-in aliases such as "critical" and "normal"
+    #[serde(default)]
+    pub priority_mappings: HashMap<String, Vec<String>>,
+
+    /// Directory for state that must survive restarts, e.g. watch queries
+    /// (default: $XDG_STATE_HOME/jira-mcp-server or ~/.local/state/jira-mcp-server)
+    #[serde(default)]
+    pub state_dir: Option<String>,
+
+    /// Serve JIRA from fixtures in this directory instead of a real instance
+    /// (mock mode, for development and CI; see `backend::MockBackend`). A
+    /// `PATH`-style list layers several directories, earlier ones first.
+    #[serde(default)]
+    pub mock_fixtures_dir: Option<String>,
+
+    /// Undo entries kept per issue for undo_last_change; 0 disables undo (default: 20)
+    #[serde(default = "default_undo_history_depth")]
+    pub undo_history_depth: usize,
+
+    /// How long undo entries are kept, in seconds (default: 86400 = 24 hours)
+    #[serde(default = "default_undo_retention_seconds")]
+    pub undo_retention_seconds: u64,
+
+    /// Persist undo history to the state directory so it survives restarts (default: false)
+    #[serde(default)]
+    pub undo_persist: bool,
+
+    /// Idempotency keys remembered for create_issue and add_comment retries,
+    /// oldest evicted first; 0 disables replay (default: 1000)
+    #[serde(default = "default_idempotency_max_keys")]
+    pub idempotency_max_keys: usize,
+
+    /// How long an idempotency key replays its first result, in seconds
+    /// (default: 86400 = 24 hours)
+    #[serde(default = "default_idempotency_retention_seconds")]
+    pub idempotency_retention_seconds: u64,
+
+    /// Label, status and closing comment used by archive_issue
+    #[serde(default)]
+    pub archive: ArchiveConfig,
+
+    /// Rules evaluated by check_issue_quality
+    #[serde(default)]
+    pub quality_gate: QualityGateConfig,
+
+    /// Localized names for non-English instances, added to the semantic mappings
+    #[serde(default)]
+    pub locale_aliases: LocaleAliases,
+
+    /// Style and language of the `message` fields in sprint, bulk and todo results
+    #[serde(default)]
+    pub messages: MessagesConfig,
+
+    /// Patterns of secrets masked in descriptions, comments and history
+    #[serde(default)]
+    pub redaction: RedactionConfig,
+
+    /// Where the todo tools keep checklists: the description or a checklist field
+    #[serde(default)]
+    pub todos: TodoConfig,
+
+    /// Values create_issue fills in per project key when the call leaves them out,
+    /// e.g. `[project_defaults.FOO]` with `components = ["backend"]`
+    #[serde(default)]
+    pub project_defaults: HashMap<String, ProjectDefaults>,
+
+    /// Label added to every issue created through the server, e.g. "ai-created"
+    /// (default: none; create_issue's skip_auto_label opts out)
+    #[serde(default)]
+    pub auto_label_created_issues: Option<String>,
+
+    /// Line appended to comments added with add_comment, e.g. "— posted via JIRA
+    /// MCP Server on behalf of Jane" (default: none; skip_signature opts out)
+    #[serde(default)]
+    pub comment_signature: Option<String>,
+
+    /// Line appended to worklog comments, including those of the todo tools
+    /// (default: none; skip_signature opts out)
+    #[serde(default)]
+    pub worklog_signature: Option<String>,
+
+    /// Time zone that decides which day a worklog falls on, "UTC" or a fixed
+    /// offset such as "+02:00" (default: UTC)
+    #[serde(default)]
+    pub timezone: Option<String>,
+
+    /// Resolution transition_issue sets and retries with when a transition
+    /// fails for lack of one, e.g. "Done" or "Fixed" (default: none, the
+    /// error lists the allowed resolutions instead)
+    #[serde(default)]
+    pub default_resolution: Option<String>,
+
+    /// Check the (cached) permissions of the current user before writes and
+    /// fail fast with the missing permission instead of a 403 (default: false)
+    #[serde(default)]
+    pub permission_precheck: bool,
+}
+
+/// Create defaults for one project, under `[project_defaults.<KEY>]`
+///
+/// Each value only applies when create_issue (or an item of bulk_create_issues)
+/// does not set it: an explicit `labels` list replaces the default labels
+/// rather than adding to them, and custom fields are merged by field ID.
+/// Defaults are checked against the project's create metadata on first use.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProjectDefaults {
+    /// Issue type, semantic names allowed (e.g. "bug"); not used for subtasks
+    pub issue_type: Option<String>,
+
+    /// Labels, e.g. ["ai-created"]
+    pub labels: Vec<String>,
+
+    /// Component names, e.g. ["backend"]
+    pub components: Vec<String>,
+
+    /// Priority name or alias
+    pub priority: Option<String>,
+
+    /// Assignee username or account ID, or "me"
+    pub assignee: Option<String>,
+
+    /// Custom field values by field ID, e.g. customfield_10200 = { value = "Platform" }
+    pub custom_fields: HashMap<String, serde_json::Value>,
+}
+
+/// Alias tables for localized instances (semantic name -> localized JIRA names)
+///
+/// Entries extend the built-in and configured mappings instead of replacing
+/// them, e.g. `blocked = ["Bloqué"]` under `[locale_aliases.statuses]` or
+/// `high = ["Élevée"]` under `[locale_aliases.priorities]`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LocaleAliases {
+    /// Status names by semantic status (e.g. "blocked", "done")
+    pub statuses: HashMap<String, Vec<String>>,
+
+    /// Priority names by semantic priority (e.g. "high", "critical")
+    pub priorities: HashMap<String, Vec<String>>,
+
+    /// Issue type names by semantic type (e.g. "bug", "story")
+    pub issue_types: HashMap<String, Vec<String>>,
+}
+
+/// TTL and size cap for one cache category
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CachePolicy {
+    /// Seconds entries stay fresh (default: cache_ttl_seconds)
+    pub ttl_seconds: Option<u64>,
+
+    /// Entries kept per cached map before the least recently used are evicted
+    pub max_entries: usize,
+}
+
+impl Default for CachePolicy {
+    fn default() -> Self {
+        Self {
+            ttl_seconds: None,
+            max_entries: 1000,
+        }
+    }
+}
+
+/// Cache settings per category (`[cache_policies.fields]` etc.)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CachePolicies {
+    /// User mappings and the current user
+    pub users: CachePolicy,
+
+    /// Project info, issue types and status catalogs
+    pub projects: CachePolicy,
+
+    /// Field catalogs and value lists (labels, priorities); rarely change
+    pub fields: CachePolicy,
+
+    /// Board names and info
+    pub boards: CachePolicy,
+
+    /// Individual issues; change often, so kept briefly
+    pub issue_details: CachePolicy,
+
+    /// Issue link types
+    pub link_types: CachePolicy,
+
+    /// Permissions of the current user per project; kept briefly
+    pub permissions: CachePolicy,
+}
+
+impl Default for CachePolicies {
+    fn default() -> Self {
+        let hours = |hours: u64| CachePolicy {
+            ttl_seconds: Some(hours * 60 * 60),
+            max_entries: 100,
+        };
+
+        Self {
+            users: CachePolicy::default(),
+            projects: CachePolicy {
+                max_entries: 500,
+                ..Default::default()
+            },
+            fields: hours(4),
+            boards: CachePolicy {
+                max_entries: 500,
+                ..Default::default()
+            },
+            issue_details: CachePolicy {
+                ttl_seconds: Some(30),
+                max_entries: 1000,
+            },
+            link_types: hours(4),
+            permissions: CachePolicy {
+                ttl_seconds: Some(300),
+                max_entries: 100,
+            },
+        }
+    }
+}
+
+impl CachePolicies {
+    pub fn get(&self, category: CacheCategory) -> &CachePolicy {
+        match category {
+            CacheCategory::Users => &self.users,
+            CacheCategory::Projects => &self.projects,
+            CacheCategory::Fields => &self.fields,
+            CacheCategory::Boards => &self.boards,
+            CacheCategory::IssueDetails => &self.issue_details,
+            CacheCategory::LinkTypes => &self.link_types,
+            CacheCategory::Permissions => &self.permissions,
+        }
+    }
+}
+
+/// Kind of JIRA request, used to pick its timeout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationClass {
+    /// Single-resource lookups and metadata
+    Read,
+    /// Creates, updates, transitions and deletes
+    Write,
+    /// JQL searches and counts
+    Search,
+    /// Attachment downloads and uploads
+    Download,
+}
+
+impl OperationClass {
+    /// Key under `[timeouts]` in the TOML file
+    pub fn name(self) -> &'static str {
+        match self {
+            OperationClass::Read => "read",
+            OperationClass::Write => "write",
+            OperationClass::Search => "search",
+            OperationClass::Download => "download",
+        }
+    }
+
+    /// Environment variable overriding this class's timeout
+    pub fn env_var(self) -> &'static str {
+        match self {
+            OperationClass::Read => "JIRA_TIMEOUT_READ",
+            OperationClass::Write => "JIRA_TIMEOUT_WRITE",
+            OperationClass::Search => "JIRA_TIMEOUT_SEARCH",
+            OperationClass::Download => "JIRA_TIMEOUT_DOWNLOAD",
+        }
+    }
+}
+
+/// Timeouts in seconds per operation class
+///
+/// Unset reads and writes use `request_timeout_seconds`; searches get at least
+/// 60 seconds and downloads at least 300, since both legitimately run longer.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RequestTimeouts {
+    pub read: Option<u64>,
+    pub write: Option<u64>,
+    pub search: Option<u64>,
+    pub download: Option<u64>,
+}
+
+impl RequestTimeouts {
+    /// Effective timeout in seconds for `class`
+    pub fn seconds(&self, class: OperationClass, request_timeout_seconds: u64) -> u64 {
+        match class {
+            OperationClass::Read => self.read.unwrap_or(request_timeout_seconds),
+            OperationClass::Write => self.write.unwrap_or(request_timeout_seconds),
+            OperationClass::Search => self.search.unwrap_or(request_timeout_seconds.max(60)),
+            OperationClass::Download => self.download.unwrap_or(request_timeout_seconds.max(300)),
+        }
+    }
+
+    fn set(&mut self, class: OperationClass, seconds: u64) {
+        match class {
+            OperationClass::Read => self.read = Some(seconds),
+            OperationClass::Write => self.write = Some(seconds),
+            OperationClass::Search => self.search = Some(seconds),
+            OperationClass::Download => self.download = Some(seconds),
+        }
+    }
+}
+
+/// Retry policy for idempotent GET requests
+///
+/// Only network errors, timeouts and rate limiting are retried; writes never are.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetryConfig {
+    /// Retry failed GETs (default: false)
+    pub enabled: bool,
+
+    /// Attempts in total, including the first one (default: 3)
+    pub max_attempts: u32,
+
+    /// Delay before the first retry in milliseconds, doubled for each further
+    /// retry (default: 500)
+    pub initial_backoff_ms: u64,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_attempts: 3,
+            initial_backoff_ms: 500,
+        }
+    }
+}
+
+/// Logging of outbound JIRA requests, for get_recent_requests and debug output
+///
+/// Entries never carry credentials: secret-looking query parameters are redacted
+/// and request bodies are only recorded by size.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RequestLogConfig {
+    /// Record requests (default: false)
+    pub enabled: bool,
+
+    /// Entries kept in memory, oldest dropped first (default: 100)
+    pub capacity: usize,
+}
+
+impl Default for RequestLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            capacity: 100,
+        }
+    }
+}
+
+/// How the `message` fields of tool results are written
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageStyle {
+    /// Full sentences
+    #[default]
+    Verbose,
+    /// Short fragments such as "Moved 3 to Sprint 5"
+    Terse,
+    /// No message; the structured fields carry everything
+    None,
+}
+
+impl MessageStyle {
+    /// Parse the JIRA_MESSAGE_STYLE override
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "verbose" => Some(MessageStyle::Verbose),
+            "terse" => Some(MessageStyle::Terse),
+            "none" | "off" => Some(MessageStyle::None),
+            _ => None,
+        }
+    }
+}
+
+/// Style and locale of result messages (see `crate::messages`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MessagesConfig {
+    /// verbose, terse or none (default: verbose)
+    pub style: MessageStyle,
+
+    /// Language of the built-in templates, e.g. "de" (default: English)
+    pub locale: Option<String>,
+}
+
+/// Storage of the todos read and written by the todo tools
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TodoBackendKind {
+    /// The checklist field when the issue has a value in it, else the description
+    #[default]
+    Auto,
+    /// Markdown checkboxes in the description
+    Description,
+    /// The "Checklist for Jira" custom field set in `field_mappings.checklist`
+    ChecklistField,
+}
+
+impl TodoBackendKind {
+    /// Parse the JIRA_TODO_BACKEND override
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "auto" => Some(TodoBackendKind::Auto),
+            "description" => Some(TodoBackendKind::Description),
+            "checklist_field" | "checklist" => Some(TodoBackendKind::ChecklistField),
+            _ => None,
+        }
+    }
+}
+
+/// What transition_issue does with a running issue session when moving to done
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SessionOnDone {
+    /// Refuse the transition until the call passes time_spent_* explicitly
+    #[default]
+    RequireTime,
+    /// Log the elapsed time of the session and close it
+    AutoComplete,
+}
+
+impl SessionOnDone {
+    /// Parse the JIRA_TODO_SESSION_ON_DONE override
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "require_time" => Some(SessionOnDone::RequireTime),
+            "auto_complete" => Some(SessionOnDone::AutoComplete),
+            _ => None,
+        }
+    }
+}
+
+/// Where the todo tools keep checklists (see `crate::tools::todo_backend`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TodoConfig {
+    /// auto, description or checklist_field (default: auto)
+    pub backend: TodoBackendKind,
+
+    /// Backend per project key, overriding `backend`, e.g. `OPS = "checklist_field"`
+    pub project_backends: HashMap<String, TodoBackendKind>,
+
+    /// Start an issue work session when transition_issue moves an issue to an
+    /// in-progress status, unless the call sets track_time (default: false)
+    pub track_time_on_transition: bool,
+
+    /// require_time or auto_complete for a session still running when the
+    /// issue moves to done (default: require_time)
+    pub session_on_done: SessionOnDone,
+
+    /// Record finished work sessions in a SQLite database in the state
+    /// directory for query_work_history; needs the `work-history` cargo
+    /// feature (default: false)
+    pub work_history: bool,
+
+    /// Sessions kept in the work history before the oldest are pruned
+    /// (default: 100000)
+    pub work_history_max_sessions: usize,
+}
+
+impl Default for TodoConfig {
+    fn default() -> Self {
+        Self {
+            backend: TodoBackendKind::default(),
+            project_backends: HashMap::new(),
+            track_time_on_transition: false,
+            session_on_done: SessionOnDone::default(),
+            work_history: false,
+            work_history_max_sessions: 100_000,
+        }
+    }
+}
+
+impl TodoConfig {
+    /// Backend for an issue, by its project key
+    pub fn backend_for(&self, issue_key: &str) -> TodoBackendKind {
+        let project = issue_key.split('-').next().unwrap_or_default();
+        self.project_backends
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(project))
+            .map_or(self.backend, |(_, backend)| *backend)
+    }
+}
+
+/// Size limits of text written to JIRA (see `crate::tools::text_limit`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WriteLimitsConfig {
+    /// Longest description in characters (default: 32767, JIRA's own limit)
+    pub max_description_chars: usize,
+
+    /// Longest comment body in characters (default: 32767)
+    pub max_comment_chars: usize,
+}
+
+impl Default for WriteLimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_description_chars: 32_767,
+            max_comment_chars: 32_767,
+        }
+    }
+}
+
+/// Secret redaction in issue content (see `crate::redaction`)
+///
+/// The built-in patterns cover AWS, GitHub, Slack, Atlassian and Google keys,
+/// JWTs, private key blocks, bearer tokens and `password=...` assignments.
+/// Further ones go under `[[redaction.patterns]]`, e.g.
+/// `category = "internal_token"` and `pattern = "itk_[A-Za-z0-9]{32}"`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedactionConfig {
+    /// Redact issue content at all (default: true)
+    pub enabled: bool,
+
+    /// Apply the built-in patterns (default: true)
+    pub default_patterns: bool,
+
+    /// Additional patterns, applied after the built-in ones
+    pub patterns: Vec<RedactionRule>,
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            default_patterns: true,
+            patterns: Vec::new(),
+        }
+    }
+}
+
+/// One redaction pattern; matches become `[REDACTED:<category>]`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RedactionRule {
+    /// Name shown in the replacement, e.g. "aws_key"
+    pub category: String,
+
+    /// Regular expression; a group named `secret` limits the replacement to it
+    pub pattern: String,
+}
+
+/// Settings for archive_issue (label + terminal status + closing comment)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ArchiveConfig {
+    /// Label added to archived issues (default: "archived")
+    pub label: String,
+
+    /// Status archived issues are moved to (default: "Done")
+    pub status: String,
+
+    /// Resolution set by the transition, for workflows that ask for one (optional)
+    pub resolution: Option<String>,
+
+    /// Closing comment; `{reason}` is replaced by the reason given, and an empty
+    /// template skips the comment
+    pub comment_template: String,
+}
+
+impl Default for ArchiveConfig {
+    fn default() -> Self {
+        Self {
+            label: "archived".to_string(),
+            status: "Done".to_string(),
+            resolution: None,
+            comment_template: "Archived: {reason}".to_string(),
+        }
+    }
+}
+
+/// Rule set for check_issue_quality
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QualityGateConfig {
+    /// Minimum description length in characters; 0 disables the rule (default: 50)
+    pub min_description_length: usize,
+
+    /// Require acceptance criteria, as the field or a checkbox section (default: true)
+    pub require_acceptance_criteria: bool,
+
+    /// Require every description checkbox to be checked (default: true)
+    pub require_todos_complete: bool,
+
+    /// Require at least one fix version (default: false)
+    pub require_fix_version: bool,
+
+    /// Require story points (default: false)
+    pub require_story_points: bool,
+
+    /// Require that no unresolved issue blocks this one (default: true)
+    pub require_no_open_blockers: bool,
+
+    /// Additional field checks, e.g. `{ name = "has_component", field = "components" }`
+    pub custom_rules: Vec<CustomQualityRule>,
+}
+
+impl Default for QualityGateConfig {
+    fn default() -> Self {
+        Self {
+            min_description_length: 50,
+            require_acceptance_criteria: true,
+            require_todos_complete: true,
+            require_fix_version: false,
+            require_story_points: false,
+            require_no_open_blockers: true,
+            custom_rules: Vec::new(),
+        }
+    }
+}
+
+/// Field-presence or regex check on an issue field
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomQualityRule {
+    /// Rule name reported in the results
+    pub name: String,
+
+    /// Field ID, e.g. "components" or "customfield_10020"
+    pub field: String,
+
+    /// Regex the field's text must match; without it the field only has to be set
+    #[serde(default)]
+    pub pattern: Option<String>,
+
+    /// Remediation hint shown when the rule fails
+    #[serde(default)]
+    pub hint: Option<String>,
 }
 
 /// Authentication configuration
@@ -54,17 +771,102 @@ pub enum AuthConfig {
     Anonymous,
 }
 
+/// Which kind of JIRA deployment the server talks to
+///
+/// Cloud identifies users by account ID, Server and Data Center by username.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeploymentType {
+    Cloud,
+    Server,
+    DataCenter,
+}
+
+impl DeploymentType {
+    /// Parse a deploymentType from /serverInfo or the JIRA_DEPLOYMENT_TYPE override
+    pub fn parse(value: &str) -> Option<Self> {
+        let normalized: String = value
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase();
+        match normalized.as_str() {
+            "cloud" => Some(DeploymentType::Cloud),
+            "server" => Some(DeploymentType::Server),
+            "datacenter" | "dc" => Some(DeploymentType::DataCenter),
+            _ => None,
+        }
+    }
+
+    pub fn is_cloud(self) -> bool {
+        self == DeploymentType::Cloud
+    }
+
+    /// User reference for fields like assignee: `{"accountId": ..}` or `{"name": ..}`
+    pub fn user_field(self, user: &str) -> serde_json::Value {
+        if self.is_cloud() {
+            serde_json::json!({ "accountId": user })
+        } else {
+            serde_json::json!({ "name": user })
+        }
+    }
+
+    /// Wiki markup mentioning a user: `[~accountid:..]` on Cloud, `[~username]` otherwise
+    pub fn mention(self, user: &str) -> String {
+        if self.is_cloud() {
+            format!("[~accountid:{}]", user)
+        } else {
+            format!("[~{}]", user)
+        }
+    }
+}
+
 impl Default for JiraConfig {
     fn default() -> Self {
         Self {
             jira_url: String::new(),
             auth: AuthConfig::Anonymous,
             cache_ttl_seconds: 300, // 5 minutes
+            cache_persist: false,
+            cache_persist_interval_seconds: default_cache_persist_interval_seconds(),
+            cache_policies: CachePolicies::default(),
+            cache_warmup: false,
+            cache_warmup_projects: Vec::new(),
+            deployment_type: None,
             max_search_results: 50,
             request_timeout_seconds: 30,
+            timeouts: RequestTimeouts::default(),
+            retry: RetryConfig::default(),
+            connection_check_interval_seconds: default_connection_check_interval_seconds(),
+            shutdown_timeout_seconds: default_shutdown_timeout_seconds(),
             rate_limit_per_minute: 60,
+            request_log: RequestLogConfig::default(),
+            max_response_chars: default_max_response_chars(),
+            write_limits: WriteLimitsConfig::default(),
             issue_type_mappings: default_issue_type_mappings(),
             status_category_mappings: default_status_category_mappings(),
+            field_mappings: HashMap::new(),
+            priority_mappings: HashMap::new(),
+            state_dir: None,
+            mock_fixtures_dir: None,
+            undo_history_depth: default_undo_history_depth(),
+            undo_retention_seconds: default_undo_retention_seconds(),
+            undo_persist: false,
+            idempotency_max_keys: default_idempotency_max_keys(),
+            idempotency_retention_seconds: default_idempotency_retention_seconds(),
+            archive: ArchiveConfig::default(),
+            quality_gate: QualityGateConfig::default(),
+            locale_aliases: LocaleAliases::default(),
+            messages: MessagesConfig::default(),
+            redaction: RedactionConfig::default(),
+            todos: TodoConfig::default(),
+            project_defaults: HashMap::new(),
+            auto_label_created_issues: None,
+            comment_signature: None,
+            worklog_signature: None,
+            timezone: None,
+            default_resolution: None,
+            permission_precheck: false,
         }
     }
 }
@@ -95,6 +897,33 @@ impl JiraConfig {
         Ok(config)
     }
 
+    /// Offset of the configured timezone; UTC when none is set
+    pub fn utc_offset(&self) -> FixedOffset {
+        self.timezone
+            .as_deref()
+            .and_then(parse_utc_offset)
+            .unwrap_or_else(|| FixedOffset::east_opt(0).expect("zero offset is valid"))
+    }
+
+    /// Resolve the directory used for persistent state
+    pub fn state_dir(&self) -> PathBuf {
+        if let Some(dir) = &self.state_dir {
+            return PathBuf::from(dir);
+        }
+
+        if let Ok(state_home) = env::var("XDG_STATE_HOME") {
+            return PathBuf::from(state_home).join("jira-mcp-server");
+        }
+
+        match env::var("HOME") {
+            Ok(home) => PathBuf::from(home)
+                .join(".local")
+                .join("state")
+                .join("jira-mcp-server"),
+            Err(_) => PathBuf::from(".jira-mcp-server"),
+        }
+    }
+
     /// Load configuration from a TOML file
     fn load_from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
         let content = std::fs::read_to_string(path.as_ref())
@@ -155,6 +984,38 @@ impl JiraConfig {
             }
         }
 
+        if let Ok(persist) = env::var("JIRA_CACHE_PERSIST") {
+            self.cache_persist = persist == "1" || persist.eq_ignore_ascii_case("true");
+        }
+
+        if let Ok(warmup) = env::var("JIRA_CACHE_WARMUP") {
+            self.cache_warmup = warmup == "1" || warmup.eq_ignore_ascii_case("true");
+        }
+
+        if let Ok(projects) = env::var("JIRA_CACHE_WARMUP_PROJECTS") {
+            self.cache_warmup_projects = projects
+                .split(',')
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty())
+                .collect();
+        }
+
+        if let Ok(deployment) = env::var("JIRA_DEPLOYMENT_TYPE") {
+            match DeploymentType::parse(&deployment) {
+                Some(deployment_type) => {
+                    self.deployment_type = Some(deployment_type);
+                    debug!(
+                        "Set deployment type to {:?} from environment",
+                        deployment_type
+                    );
+                }
+                None => warn!(
+                    "Unknown JIRA_DEPLOYMENT_TYPE: {}, detecting it instead",
+                    deployment
+                ),
+            }
+        }
+
         if let Ok(max_results) = env::var("JIRA_MAX_RESULTS") {
             if let Ok(max) = max_results.parse::<u32>() {
                 self.max_search_results = max.min(200); // Cap at 200
@@ -175,6 +1036,55 @@ impl JiraConfig {
             }
         }
 
+        for class in [
+            OperationClass::Read,
+            OperationClass::Write,
+            OperationClass::Search,
+            OperationClass::Download,
+        ] {
+            if let Ok(timeout) = env::var(class.env_var()) {
+                if let Ok(timeout_seconds) = timeout.parse::<u64>() {
+                    self.timeouts.set(class, timeout_seconds);
+                    debug!(
+                        "Set {} timeout to {} seconds from environment",
+                        class.name(),
+                        timeout_seconds
+                    );
+                }
+            }
+        }
+
+        if let Ok(retry) = env::var("JIRA_RETRY_GETS") {
+            self.retry.enabled = retry == "1" || retry.eq_ignore_ascii_case("true");
+        }
+
+        if let Ok(attempts) = env::var("JIRA_RETRY_MAX_ATTEMPTS") {
+            if let Ok(attempts) = attempts.parse::<u32>() {
+                self.retry.max_attempts = attempts;
+                debug!("Set retry attempts to {} from environment", attempts);
+            }
+        }
+
+        if let Ok(interval) = env::var("JIRA_CONNECTION_CHECK_INTERVAL") {
+            if let Ok(seconds) = interval.parse::<u64>() {
+                self.connection_check_interval_seconds = seconds;
+                debug!(
+                    "Set connection check interval to {} seconds from environment",
+                    seconds
+                );
+            }
+        }
+
+        if let Ok(timeout) = env::var("JIRA_SHUTDOWN_TIMEOUT") {
+            if let Ok(seconds) = timeout.parse::<u64>() {
+                self.shutdown_timeout_seconds = seconds;
+                debug!(
+                    "Set shutdown timeout to {} seconds from environment",
+                    seconds
+                );
+            }
+        }
+
         if let Ok(rate_limit) = env::var("JIRA_RATE_LIMIT") {
             if let Ok(limit) = rate_limit.parse::<u32>() {
                 self.rate_limit_per_minute = limit;
@@ -182,11 +1092,221 @@ impl JiraConfig {
             }
         }
 
+        if let Ok(log_requests) = env::var("JIRA_LOG_REQUESTS") {
+            self.request_log.enabled =
+                log_requests == "1" || log_requests.eq_ignore_ascii_case("true");
+        }
+
+        if let Ok(size) = env::var("JIRA_REQUEST_LOG_SIZE") {
+            if let Ok(capacity) = size.parse::<usize>() {
+                self.request_log.capacity = capacity;
+                debug!("Set request log size to {} from environment", capacity);
+            }
+        }
+
+        if let Ok(style) = env::var("JIRA_MESSAGE_STYLE") {
+            match MessageStyle::parse(&style) {
+                Some(style) => {
+                    self.messages.style = style;
+                    debug!("Set message style to {:?} from environment", style);
+                }
+                None => warn!(
+                    "Unknown JIRA_MESSAGE_STYLE: {}, keeping {:?}",
+                    style, self.messages.style
+                ),
+            }
+        }
+
+        if let Ok(backend) = env::var("JIRA_TODO_BACKEND") {
+            match TodoBackendKind::parse(&backend) {
+                Some(backend) => {
+                    self.todos.backend = backend;
+                    debug!("Set todo backend to {:?} from environment", backend);
+                }
+                None => warn!(
+                    "Unknown JIRA_TODO_BACKEND: {}, keeping {:?}",
+                    backend, self.todos.backend
+                ),
+            }
+        }
+
+        if let Ok(track) = env::var("JIRA_TODO_TRACK_TIME_ON_TRANSITION") {
+            self.todos.track_time_on_transition =
+                track == "1" || track.eq_ignore_ascii_case("true");
+        }
+
+        if let Ok(on_done) = env::var("JIRA_TODO_SESSION_ON_DONE") {
+            match SessionOnDone::parse(&on_done) {
+                Some(on_done) => {
+                    self.todos.session_on_done = on_done;
+                    debug!("Set session on done to {:?} from environment", on_done);
+                }
+                None => warn!(
+                    "Unknown JIRA_TODO_SESSION_ON_DONE: {}, keeping {:?}",
+                    on_done, self.todos.session_on_done
+                ),
+            }
+        }
+
+        if let Ok(history) = env::var("JIRA_TODO_WORK_HISTORY") {
+            self.todos.work_history = history == "1" || history.eq_ignore_ascii_case("true");
+        }
+
+        if let Ok(max) = env::var("JIRA_TODO_WORK_HISTORY_MAX_SESSIONS") {
+            if let Ok(max) = max.parse::<usize>() {
+                self.todos.work_history_max_sessions = max;
+                debug!("Set work history cap to {} from environment", max);
+            }
+        }
+
+        if let Ok(locale) = env::var("JIRA_MESSAGE_LOCALE") {
+            debug!("Set message locale to {} from environment", locale);
+            self.messages.locale = Some(locale);
+        }
+
+        if let Ok(max_chars) = env::var("JIRA_MAX_RESPONSE_CHARS") {
+            if let Ok(max_chars) = max_chars.parse::<usize>() {
+                self.max_response_chars = max_chars;
+                debug!(
+                    "Set max response size to {} characters from environment",
+                    max_chars
+                );
+            }
+        }
+
+        for (var, limit) in [
+            (
+                "JIRA_MAX_DESCRIPTION_CHARS",
+                &mut self.write_limits.max_description_chars,
+            ),
+            (
+                "JIRA_MAX_COMMENT_CHARS",
+                &mut self.write_limits.max_comment_chars,
+            ),
+        ] {
+            if let Some(max_chars) = env::var(var).ok().and_then(|v| v.parse::<usize>().ok()) {
+                *limit = max_chars;
+                debug!("Set {} to {} from environment", var, max_chars);
+            }
+        }
+
+        for (var, key) in [
+            ("JIRA_START_DATE_FIELD", START_DATE_FIELD),
+            ("JIRA_STORY_POINTS_FIELD", STORY_POINTS_FIELD),
+            ("JIRA_ACCEPTANCE_CRITERIA_FIELD", ACCEPTANCE_CRITERIA_FIELD),
+            ("JIRA_EPIC_LINK_FIELD", EPIC_LINK_FIELD),
+            ("JIRA_CHECKLIST_FIELD", CHECKLIST_FIELD),
+        ] {
+            if let Ok(field_id) = env::var(var) {
+                debug!("Set {} field to {} from environment", key, field_id);
+                self.field_mappings.insert(key.to_string(), field_id);
+            }
+        }
+
+        if let Ok(state_dir) = env::var("JIRA_MCP_STATE_DIR") {
+            debug!("Set state directory to {} from environment", state_dir);
+            self.state_dir = Some(state_dir);
+        }
+
+        if let Ok(label) = env::var("JIRA_AUTO_LABEL_CREATED_ISSUES") {
+            self.auto_label_created_issues = Some(label).filter(|l| !l.trim().is_empty());
+        }
+
+        if let Ok(signature) = env::var("JIRA_COMMENT_SIGNATURE") {
+            self.comment_signature = Some(signature).filter(|s| !s.trim().is_empty());
+        }
+
+        if let Ok(signature) = env::var("JIRA_WORKLOG_SIGNATURE") {
+            self.worklog_signature = Some(signature).filter(|s| !s.trim().is_empty());
+        }
+
+        if let Ok(timezone) = env::var("JIRA_TIMEZONE") {
+            self.timezone = Some(timezone).filter(|tz| !tz.trim().is_empty());
+        }
+
+        if let Ok(resolution) = env::var("JIRA_DEFAULT_RESOLUTION") {
+            self.default_resolution = Some(resolution).filter(|r| !r.trim().is_empty());
+        }
+
+        if let Ok(precheck) = env::var("JIRA_PERMISSION_PRECHECK") {
+            self.permission_precheck = precheck == "1" || precheck.eq_ignore_ascii_case("true");
+        }
+
+        if let Ok(depth) = env::var("JIRA_UNDO_DEPTH") {
+            if let Ok(depth) = depth.parse::<usize>() {
+                self.undo_history_depth = depth;
+                debug!("Set undo history depth to {} from environment", depth);
+            }
+        }
+
+        if let Ok(retention) = env::var("JIRA_UNDO_RETENTION") {
+            if let Ok(seconds) = retention.parse::<u64>() {
+                self.undo_retention_seconds = seconds;
+                debug!("Set undo retention to {} seconds from environment", seconds);
+            }
+        }
+
+        if let Ok(persist) = env::var("JIRA_UNDO_PERSIST") {
+            self.undo_persist = persist == "1" || persist.eq_ignore_ascii_case("true");
+        }
+
+        if let Ok(retention) = env::var("JIRA_IDEMPOTENCY_RETENTION") {
+            if let Ok(seconds) = retention.parse::<u64>() {
+                self.idempotency_retention_seconds = seconds;
+                debug!(
+                    "Set idempotency key retention to {} seconds from environment",
+                    seconds
+                );
+            }
+        }
+
+        if env::var(crate::backend::MOCK_ENV_VAR).is_ok_and(|v| v == "1" || v == "true") {
+            let dir = env::var(crate::backend::MOCK_FIXTURES_ENV_VAR)
+                .unwrap_or_else(|_| crate::backend::DEFAULT_MOCK_FIXTURES_DIR.to_string());
+            info!("Mock mode enabled, serving JIRA fixtures from {}", dir);
+            self.mock_fixtures_dir = Some(dir);
+        }
+
         Ok(())
     }
 
     /// Validate the configuration
     fn validate(&self) -> Result<()> {
+        // Bad patterns fail here rather than on the first tool call
+        Redactor::new(&self.redaction)?;
+
+        // JIRA would reject every create with it
+        if let Some(label) = &self.auto_label_created_issues {
+            if label.chars().any(char::is_whitespace) {
+                return Err(anyhow::anyhow!(
+                    "auto_label_created_issues cannot contain spaces: {:?}",
+                    label
+                ));
+            }
+        }
+
+        // Day boundaries would silently fall back to UTC
+        if let Some(timezone) = &self.timezone {
+            if parse_utc_offset(timezone).is_none() {
+                return Err(anyhow::anyhow!(
+                    "timezone must be \"UTC\" or an offset like \"+02:00\". Got: {}",
+                    timezone
+                ));
+            }
+        }
+
+        // Pruning down to zero would drop each session as it is recorded
+        if self.todos.work_history && self.todos.work_history_max_sessions == 0 {
+            return Err(anyhow::anyhow!(
+                "todos.work_history_max_sessions must be at least 1"
+            ));
+        }
+
+        // Mock mode needs no JIRA instance or credentials
+        if self.is_mock() {
+            return Ok(());
+        }
+
         // Validate JIRA URL
         if self.jira_url.is_empty() {
             return Err(anyhow::anyhow!(
@@ -239,6 +1359,11 @@ impl JiraConfig {
         Ok(())
     }
 
+    /// Whether JIRA is served from mock fixtures
+    pub fn is_mock(&self) -> bool {
+        self.mock_fixtures_dir.is_some()
+    }
+
     /// Get the gouqi Credentials from AuthConfig
     pub fn to_gouqi_credentials(&self) -> gouqi::Credentials {
         match &self.auth {
@@ -253,6 +1378,38 @@ impl JiraConfig {
 }
 
 /// Default issue type mappings (semantic -> JIRA issue type names)
+fn default_undo_history_depth() -> usize {
+    20
+}
+
+fn default_undo_retention_seconds() -> u64 {
+    24 * 60 * 60
+}
+
+fn default_connection_check_interval_seconds() -> u64 {
+    5 * 60
+}
+
+fn default_shutdown_timeout_seconds() -> u64 {
+    30
+}
+
+fn default_idempotency_max_keys() -> usize {
+    1000
+}
+
+fn default_idempotency_retention_seconds() -> u64 {
+    24 * 60 * 60
+}
+
+fn default_cache_persist_interval_seconds() -> u64 {
+    60
+}
+
+fn default_max_response_chars() -> usize {
+    100_000
+}
+
 fn default_issue_type_mappings() -> HashMap<String, Vec<String>> {
     let mut mappings = HashMap::new();
 
@@ -332,6 +1489,37 @@ fn default_status_category_mappings() -> HashMap<String, Vec<String>> {
     mappings
 }
 
+/// Parse "UTC", "Z" or an offset like "+02:00", "-0530" or "UTC+1"
+fn parse_utc_offset(value: &str) -> Option<FixedOffset> {
+    let value = value.trim();
+    let offset = value
+        .strip_prefix("UTC")
+        .or_else(|| value.strip_prefix("GMT"))
+        .unwrap_or(value);
+    if offset.is_empty() || offset == "Z" {
+        return FixedOffset::east_opt(0);
+    }
+
+    let (sign, rest) = if let Some(rest) = offset.strip_prefix('+') {
+        (1, rest)
+    } else if let Some(rest) = offset.strip_prefix('-') {
+        (-1, rest)
+    } else {
+        return None;
+    };
+    let (hours, minutes) = match rest.split_once(':') {
+        Some((hours, minutes)) => (hours, minutes),
+        None if rest.len() == 4 && rest.is_ascii() => rest.split_at(2),
+        None => (rest, "0"),
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 14 || minutes > 59 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -345,6 +1533,28 @@ mod tests {
         assert_eq!(config.request_timeout_seconds, 30);
         assert!(!config.issue_type_mappings.is_empty());
         assert!(!config.status_category_mappings.is_empty());
+        assert!(config.state_dir.is_none());
+    }
+
+    #[test]
+    fn test_utc_offset() {
+        let offset = |timezone: &str| parse_utc_offset(timezone).map(|o| o.local_minus_utc());
+        assert_eq!(offset("UTC"), Some(0));
+        assert_eq!(offset("+02:00"), Some(7200));
+        assert_eq!(offset("-0530"), Some(-19800));
+        assert_eq!(offset("UTC+1"), Some(3600));
+        assert_eq!(offset("Europe/Berlin"), None);
+        assert_eq!(offset("+25:00"), None);
+        assert_eq!(JiraConfig::default().utc_offset().local_minus_utc(), 0);
+    }
+
+    #[test]
+    fn test_explicit_state_dir() {
+        let config = JiraConfig {
+            state_dir: Some("/tmp/jira-state".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.state_dir(), PathBuf::from("/tmp/jira-state"));
     }
 
     #[test]
@@ -353,12 +1563,14 @@ mod tests {
         env::set_var("JIRA_AUTH_TYPE", "pat");
         env::set_var("JIRA_TOKEN", "test_token");
         env::set_var("JIRA_CACHE_TTL", "600");
+        env::set_var("JIRA_DEPLOYMENT_TYPE", "Data Center");
 
         let mut config = JiraConfig::default();
         config.load_from_env().unwrap();
 
         assert_eq!(config.jira_url, "https://test.atlassian.net");
         assert_eq!(config.cache_ttl_seconds, 600);
+        assert_eq!(config.deployment_type, Some(DeploymentType::DataCenter));
 
         match config.auth {
             AuthConfig::PersonalAccessToken(token) => assert_eq!(token, "test_token"),
@@ -370,6 +1582,48 @@ mod tests {
         env::remove_var("JIRA_AUTH_TYPE");
         env::remove_var("JIRA_TOKEN");
         env::remove_var("JIRA_CACHE_TTL");
+        env::remove_var("JIRA_DEPLOYMENT_TYPE");
+    }
+
+    #[test]
+    fn test_deployment_type_user_references() {
+        assert_eq!(DeploymentType::parse("CLOUD"), Some(DeploymentType::Cloud));
+        assert_eq!(
+            DeploymentType::parse("data_center"),
+            Some(DeploymentType::DataCenter)
+        );
+        assert_eq!(DeploymentType::parse("hosted"), None);
+
+        let cloud = DeploymentType::Cloud;
+        assert_eq!(
+            cloud.user_field("5b10ac8d"),
+            serde_json::json!({"accountId": "5b10ac8d"})
+        );
+        assert_eq!(cloud.mention("5b10ac8d"), "[~accountid:5b10ac8d]");
+
+        let server = DeploymentType::Server;
+        assert_eq!(
+            server.user_field("jdoe"),
+            serde_json::json!({"name": "jdoe"})
+        );
+        assert_eq!(server.mention("jdoe"), "[~jdoe]");
+    }
+
+    #[test]
+    fn test_request_timeouts_per_class() {
+        let mut timeouts = RequestTimeouts::default();
+        assert_eq!(timeouts.seconds(OperationClass::Read, 30), 30);
+        assert_eq!(timeouts.seconds(OperationClass::Write, 30), 30);
+        assert_eq!(timeouts.seconds(OperationClass::Search, 30), 60);
+        assert_eq!(timeouts.seconds(OperationClass::Search, 90), 90);
+        assert_eq!(timeouts.seconds(OperationClass::Download, 30), 300);
+
+        timeouts.set(OperationClass::Search, 15);
+        assert_eq!(timeouts.seconds(OperationClass::Search, 30), 15);
+
+        let retry = RetryConfig::default();
+        assert!(!retry.enabled);
+        assert_eq!(retry.max_attempts, 3);
     }
 
     #[test]
@@ -386,5 +1640,25 @@ mod tests {
         // Valid URL should pass
         config.jira_url = "https://test.atlassian.net".to_string();
         assert!(config.validate().is_ok());
+
+        // Mock mode needs neither URL nor credentials
+        let config = JiraConfig {
+            mock_fixtures_dir: Some("tests/fixtures/mock".to_string()),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        // So are timezones
+        let mut broken_timezone = config.clone();
+        broken_timezone.timezone = Some("Europe/Berlin".to_string());
+        assert!(broken_timezone.validate().is_err());
+
+        // Redaction patterns are compiled at load time, in mock mode too
+        let mut config = config;
+        config.redaction.patterns.push(RedactionRule {
+            category: "broken".to_string(),
+            pattern: "[a-z".to_string(),
+        });
+        assert!(config.validate().is_err());
     }
 }
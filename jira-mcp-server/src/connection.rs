@@ -0,0 +1,145 @@
+//! Last known state of the connection to JIRA
+//!
+//! Every request JiraClient logs also updates a shared [`ConnectionState`]:
+//! a response from JIRA, even an error like "not found", shows the instance is
+//! reachable and the credentials work, while network and authentication errors
+//! show it is not. get_server_status reports this state with its age and only
+//! probes JIRA itself when the state is older than
+//! `connection_check_interval_seconds`, or when asked to with `force_check`.
+
+use crate::error::JiraMcpError;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// The outcome of the most recent request
+#[derive(Debug, Clone)]
+struct Observation {
+    connected: bool,
+    error: Option<String>,
+    at: DateTime<Utc>,
+    observed: Instant,
+}
+
+/// Connection state as reported by get_server_status
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ConnectionSnapshot {
+    /// Whether the last request reached JIRA with working credentials
+    pub connected: bool,
+
+    /// Error of the last request, when it failed to connect or authenticate
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+
+    /// When the state was observed (RFC 3339, UTC)
+    pub observed_at: String,
+
+    /// Seconds since the state was observed
+    pub age_seconds: u64,
+
+    /// Whether get_server_status probed JIRA for this state
+    pub probed: bool,
+}
+
+/// Connection state shared by all clones of the JIRA client
+#[derive(Debug, Default)]
+pub struct ConnectionState {
+    last: Mutex<Option<Observation>>,
+}
+
+impl ConnectionState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Update the state from the outcome of a request
+    ///
+    /// Errors that say nothing about the connection, like a response that could
+    /// not be parsed, leave the state as it was.
+    pub fn record(&self, outcome: Result<(), &JiraMcpError>) {
+        let (connected, error) = match outcome {
+            Ok(()) => (true, None),
+            Err(e @ (JiraMcpError::Network { .. } | JiraMcpError::Authentication { .. })) => {
+                (false, Some(e.to_string()))
+            }
+            Err(
+                JiraMcpError::Permission { .. }
+                | JiraMcpError::NotFound { .. }
+                | JiraMcpError::InvalidParameter { .. }
+                | JiraMcpError::RateLimit { .. }
+                | JiraMcpError::JqlError { .. },
+            ) => (true, None),
+            Err(_) => return,
+        };
+
+        let Ok(mut last) = self.last.lock() else {
+            return;
+        };
+        *last = Some(Observation {
+            connected,
+            error,
+            at: Utc::now(),
+            observed: Instant::now(),
+        });
+    }
+
+    /// The last observed state, if any request was made yet
+    pub fn snapshot(&self) -> Option<ConnectionSnapshot> {
+        let last = self.last.lock().ok()?.clone()?;
+        Some(ConnectionSnapshot {
+            connected: last.connected,
+            last_error: last.error,
+            observed_at: last.at.to_rfc3339(),
+            age_seconds: last.observed.elapsed().as_secs(),
+            probed: false,
+        })
+    }
+
+    /// Whether the state is missing or older than `max_age`
+    pub fn is_stale(&self, max_age: Duration) -> bool {
+        match self.last.lock() {
+            Ok(last) => last
+                .as_ref()
+                .is_none_or(|observation| observation.observed.elapsed() >= max_age),
+            Err(_) => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_outcomes_update_state() {
+        let state = ConnectionState::new();
+        assert!(state.snapshot().is_none());
+        assert!(state.is_stale(Duration::from_secs(300)));
+
+        state.record(Ok(()));
+        let snapshot = state.snapshot().unwrap();
+        assert!(snapshot.connected);
+        assert!(snapshot.last_error.is_none());
+        assert!(!state.is_stale(Duration::from_secs(300)));
+        assert!(state.is_stale(Duration::ZERO));
+
+        state.record(Err(&JiraMcpError::network("Connection refused")));
+        let snapshot = state.snapshot().unwrap();
+        assert!(!snapshot.connected);
+        assert!(snapshot.last_error.unwrap().contains("Connection refused"));
+
+        // JIRA answering with an error still means it is reachable
+        state.record(Err(&JiraMcpError::not_found("issue", "PROJ-1")));
+        assert!(state.snapshot().unwrap().connected);
+    }
+
+    #[test]
+    fn test_unrelated_errors_keep_state() {
+        let state = ConnectionState::new();
+        state.record(Err(&JiraMcpError::auth("401 Unauthorized")));
+        state.record(Err(&JiraMcpError::internal("unexpected response")));
+        assert!(!state.snapshot().unwrap().connected);
+    }
+}
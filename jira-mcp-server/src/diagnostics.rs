@@ -0,0 +1,527 @@
+//! Configuration, connection and permission diagnostics
+//!
+//! Misconfiguration (a wrong URL, a token for the wrong auth type, an
+//! unreachable host) otherwise shows up as a stack of error text at startup, or
+//! as writes failing one by one. `--check-config` and the run_diagnostics tool
+//! share this module: they list the resolved settings with secrets masked,
+//! check the connection, and ask JIRA (/mypermissions) whether the configured
+//! user may browse, comment, transition, edit and create issues. Every problem
+//! comes with a remediation hint.
+
+use crate::config::{AuthConfig, JiraConfig};
+use crate::error::JiraMcpError;
+use crate::jira_client::JiraClient;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Parameters for the run_diagnostics tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RunDiagnosticsParams {
+    /// Project to check permissions in (optional, default: whether each is held in any project)
+    /// Examples: "PROJ"
+    #[serde(default)]
+    pub project_key: Option<String>,
+}
+
+/// A capability the tools need, and the JIRA permission behind it
+struct Capability {
+    name: &'static str,
+    permission: &'static str,
+    needed_for: &'static str,
+}
+
+/// Capabilities probed, in the order they are reported
+const CAPABILITIES: &[Capability] = &[
+    Capability {
+        name: "browse",
+        permission: "BROWSE_PROJECTS",
+        needed_for: "searching and reading issues",
+    },
+    Capability {
+        name: "comment",
+        permission: "ADD_COMMENTS",
+        needed_for: "add_comment",
+    },
+    Capability {
+        name: "transition",
+        permission: "TRANSITION_ISSUES",
+        needed_for: "transition_issue and bulk_transition_issues",
+    },
+    Capability {
+        name: "edit",
+        permission: "EDIT_ISSUES",
+        needed_for: "updating descriptions, summaries, fields, labels and components",
+    },
+    Capability {
+        name: "create",
+        permission: "CREATE_ISSUES",
+        needed_for: "create_issue and bulk_create_issues",
+    },
+];
+
+/// A resolved setting, with secrets masked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConfigSetting {
+    pub name: String,
+    pub value: String,
+}
+
+/// Whether the user holds the permission behind a capability
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapabilityCheck {
+    /// "browse", "comment", "transition", "edit" or "create"
+    pub capability: String,
+    /// JIRA permission key, e.g. "ADD_COMMENTS"
+    pub permission: String,
+    /// Tools that need it
+    pub needed_for: String,
+    /// None when JIRA did not report the permission
+    pub granted: Option<bool>,
+}
+
+/// Something that is wrong, and how to fix it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Problem {
+    /// "config", "connection", "permissions" or a capability name
+    pub check: String,
+    pub message: String,
+    pub remediation: String,
+}
+
+/// Result of the run_diagnostics tool and `--check-config`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DiagnosticsReport {
+    /// Whether no problem was found
+    pub ok: bool,
+
+    /// Resolved settings, secrets masked
+    pub settings: Vec<ConfigSetting>,
+
+    /// Whether JIRA accepted the credentials
+    pub connected: bool,
+
+    /// Display name of the authenticated user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authenticated_user: Option<String>,
+
+    /// Detected deployment type
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deployment: Option<String>,
+
+    /// Project the permissions were checked in; None means in any project
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project_key: Option<String>,
+
+    /// Capabilities, when the connection worked
+    pub capabilities: Vec<CapabilityCheck>,
+
+    /// What is wrong, each with a remediation hint
+    pub problems: Vec<Problem>,
+}
+
+impl DiagnosticsReport {
+    fn new(config: &JiraConfig, project_key: Option<&str>) -> Self {
+        let mut report = Self {
+            settings: config_settings(config),
+            project_key: project_key.map(String::from),
+            ..Self::default()
+        };
+        if let Some(problem) = auth_mismatch(config, std::env::var("JIRA_AUTH_TYPE").ok()) {
+            report.problems.push(problem);
+        }
+        report
+    }
+
+    fn problem(&mut self, check: &str, message: impl fmt::Display, remediation: String) {
+        self.problems.push(Problem {
+            check: check.to_string(),
+            message: message.to_string(),
+            remediation,
+        });
+    }
+
+    fn finish(mut self) -> Self {
+        self.ok = self.problems.is_empty();
+        self
+    }
+}
+
+/// Check the connection and permissions through an existing client
+pub async fn diagnose(
+    client: &JiraClient,
+    config: &JiraConfig,
+    project_key: Option<&str>,
+) -> DiagnosticsReport {
+    let mut report = DiagnosticsReport::new(config, project_key);
+
+    match client.get_current_user().await {
+        Ok(user) => {
+            report.connected = true;
+            report.authenticated_user = Some(user.display_name);
+            report.deployment = Some(format!("{:?}", client.deployment_type()));
+        }
+        Err(e) => {
+            let remediation = connection_remediation(&e, config);
+            report.problem("connection", e, remediation);
+            return report.finish();
+        }
+    }
+
+    let permissions: Vec<&str> = CAPABILITIES.iter().map(|c| c.permission).collect();
+    match client
+        .get_my_permissions(project_key, None, &permissions)
+        .await
+    {
+        Ok(granted) => check_capabilities(&mut report, &granted),
+        Err(e) => {
+            let remediation = match (&e, project_key) {
+                (JiraMcpError::NotFound { .. }, Some(project)) => format!(
+                    "Project {} does not exist or is not visible to this user; check the key",
+                    project
+                ),
+                _ => "Permissions could not be checked; the tools will report missing \
+                      permissions as they fail"
+                    .to_string(),
+            };
+            report.problem("permissions", e, remediation);
+        }
+    }
+
+    report.finish()
+}
+
+/// Load the configuration, connect and diagnose, for `--check-config`
+pub async fn check_config(project_key: Option<&str>) -> DiagnosticsReport {
+    let config = match JiraConfig::load() {
+        Ok(config) => Arc::new(config),
+        Err(e) => {
+            let message = format!("{:#}", e);
+            let mut report = DiagnosticsReport::default();
+            let remediation = config_remediation(&message);
+            report.problem("config", message, remediation);
+            return report.finish();
+        }
+    };
+
+    match JiraClient::new(Arc::clone(&config)).await {
+        Ok(client) => diagnose(&client, &config, project_key).await,
+        Err(e) => {
+            let mut report = DiagnosticsReport::new(&config, project_key);
+            let remediation = connection_remediation(&e, &config);
+            report.problem("connection", e, remediation);
+            report.finish()
+        }
+    }
+}
+
+fn check_capabilities(report: &mut DiagnosticsReport, granted: &BTreeMap<String, bool>) {
+    let scope = match &report.project_key {
+        Some(project) => format!("project {}", project),
+        None => "any project".to_string(),
+    };
+
+    for capability in CAPABILITIES {
+        let held = granted.get(capability.permission).copied();
+        if held == Some(false) {
+            report.problem(
+                capability.name,
+                format!("The user lacks {} in {}", capability.permission, scope),
+                format!(
+                    "Ask a JIRA admin to grant {} through the permission scheme of {}; it is \
+                     needed for {}",
+                    capability.permission, scope, capability.needed_for
+                ),
+            );
+        }
+        report.capabilities.push(CapabilityCheck {
+            capability: capability.name.to_string(),
+            permission: capability.permission.to_string(),
+            needed_for: capability.needed_for.to_string(),
+            granted: held,
+        });
+    }
+}
+
+/// The settings worth checking, with secrets masked
+fn config_settings(config: &JiraConfig) -> Vec<ConfigSetting> {
+    let mut settings = Vec::new();
+    let mut set = |name: &str, value: String| {
+        settings.push(ConfigSetting {
+            name: name.to_string(),
+            value,
+        })
+    };
+
+    if let Some(dir) = &config.mock_fixtures_dir {
+        set("mock_fixtures_dir", dir.clone());
+    }
+    set("jira_url", config.jira_url.clone());
+    let auth = match &config.auth {
+        AuthConfig::PersonalAccessToken(token) => {
+            format!("personal_access_token (token {})", mask(token))
+        }
+        AuthConfig::Basic { username, password } => {
+            format!("basic (username {}, password {})", username, mask(password))
+        }
+        AuthConfig::Bearer(token) => format!("bearer (token {})", mask(token)),
+        AuthConfig::Anonymous => "anonymous".to_string(),
+    };
+    set("auth", auth);
+    set(
+        "deployment_type",
+        match config.deployment_type {
+            Some(deployment) => format!("{:?} (configured)", deployment),
+            None => "detected from /serverInfo".to_string(),
+        },
+    );
+    set("state_dir", config.state_dir().display().to_string());
+    set(
+        "request_timeout_seconds",
+        config.request_timeout_seconds.to_string(),
+    );
+    set(
+        "rate_limit_per_minute",
+        config.rate_limit_per_minute.to_string(),
+    );
+    set("max_search_results", config.max_search_results.to_string());
+    set("cache_ttl_seconds", config.cache_ttl_seconds.to_string());
+    let mut field_mappings: Vec<_> = config.field_mappings.iter().collect();
+    field_mappings.sort();
+    for (field, id) in field_mappings {
+        set(&format!("field_mappings.{}", field), id.clone());
+    }
+
+    settings
+}
+
+/// Mask a secret, keeping the last four characters of long ones to tell
+/// tokens apart
+fn mask(secret: &str) -> String {
+    let chars: Vec<char> = secret.chars().collect();
+    match chars.len() {
+        0 => "(empty)".to_string(),
+        len if len >= 12 => format!("****{}", chars[len - 4..].iter().collect::<String>()),
+        _ => "****".to_string(),
+    }
+}
+
+/// JIRA_AUTH_TYPE asks for a token that is not set, so the server silently
+/// runs anonymously
+fn auth_mismatch(config: &JiraConfig, auth_type: Option<String>) -> Option<Problem> {
+    let auth_type = auth_type?.to_lowercase();
+    let wants_token = matches!(
+        auth_type.as_str(),
+        "pat" | "personal_access_token" | "bearer"
+    );
+    if !wants_token || !matches!(config.auth, AuthConfig::Anonymous) || config.is_mock() {
+        return None;
+    }
+    Some(Problem {
+        check: "config".to_string(),
+        message: format!(
+            "JIRA_AUTH_TYPE is {} but no token is set, so requests are sent anonymously",
+            auth_type
+        ),
+        remediation: "Set JIRA_TOKEN to the token".to_string(),
+    })
+}
+
+/// Remediation for a configuration that failed to load
+fn config_remediation(error: &str) -> String {
+    let hint = if error.contains("JIRA URL is required") {
+        "Set JIRA_URL to the base URL of the instance, e.g. https://your-company.atlassian.net"
+    } else if error.contains("must start with http") {
+        "Include the scheme in JIRA_URL, e.g. https://jira.example.com"
+    } else if error.contains("JIRA_USERNAME") || error.contains("JIRA_PASSWORD") {
+        "Basic authentication needs both JIRA_USERNAME and JIRA_PASSWORD"
+    } else if error.contains("cannot be empty") {
+        "Set the token or password for the configured auth type (JIRA_TOKEN, JIRA_PASSWORD)"
+    } else if error.contains("config file") {
+        "Fix the config file named in the error, or remove it to configure through the environment"
+    } else {
+        "See the Configuration section of the README for the settings and their formats"
+    };
+    hint.to_string()
+}
+
+/// Remediation for a failed connection, tailored to the URL and auth type
+fn connection_remediation(error: &JiraMcpError, config: &JiraConfig) -> String {
+    let url = config.jira_url.trim_end_matches('/');
+    let cloud = url.contains(".atlassian.net");
+
+    match error {
+        JiraMcpError::Network { .. } => {
+            match ["/rest", "/browse", "/secure", "/jira/software"]
+                .iter()
+                .find(|suffix| url.contains(*suffix))
+            {
+                Some(suffix) => format!(
+                    "JIRA_URL must be the base URL of the instance; remove {} and what follows",
+                    suffix
+                ),
+                None => format!(
+                    "Check that {} is reachable from this machine (VPN, proxy, DNS, firewall) \
+                     and that JIRA_URL has the right scheme and host",
+                    url
+                ),
+            }
+        }
+        JiraMcpError::Authentication { .. } => match &config.auth {
+            AuthConfig::PersonalAccessToken(_) | AuthConfig::Bearer(_) if cloud => {
+                "JIRA Cloud takes API tokens through basic authentication: set \
+                 JIRA_AUTH_TYPE=basic, JIRA_USERNAME to the account's email address and \
+                 JIRA_PASSWORD to the API token"
+                    .to_string()
+            }
+            AuthConfig::Basic { username, .. } if cloud && !username.contains('@') => {
+                "JIRA Cloud expects the account's email address as JIRA_USERNAME".to_string()
+            }
+            AuthConfig::Basic { username, .. } if !cloud && username.contains('@') => {
+                "Server and Data Center expect the username, not the email address, as \
+                 JIRA_USERNAME; or use a personal access token with JIRA_AUTH_TYPE=pat"
+                    .to_string()
+            }
+            AuthConfig::Anonymous => {
+                "This instance requires authentication: set JIRA_AUTH_TYPE and the matching \
+                 credentials"
+                    .to_string()
+            }
+            _ => "The credentials were rejected: check that the token is current and not \
+                  revoked, and that JIRA_AUTH_TYPE matches the kind of token"
+                .to_string(),
+        },
+        JiraMcpError::Permission { .. } => "The user is authenticated but may not use JIRA: \
+                                            check that the account is active and has \
+                                            application access"
+            .to_string(),
+        JiraMcpError::RateLimit { .. } => {
+            "JIRA is throttling requests; try again later or lower JIRA_RATE_LIMIT".to_string()
+        }
+        _ => "Run with RUST_LOG=debug to see the failing request".to_string(),
+    }
+}
+
+impl fmt::Display for DiagnosticsReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.settings.is_empty() {
+            writeln!(f, "Configuration")?;
+            for setting in &self.settings {
+                writeln!(f, "  {}: {}", setting.name, setting.value)?;
+            }
+        }
+
+        if let Some(user) = &self.authenticated_user {
+            writeln!(f, "Connection")?;
+            writeln!(
+                f,
+                "  ✅ authenticated as {} ({})",
+                user,
+                self.deployment.as_deref().unwrap_or("unknown deployment")
+            )?;
+        }
+
+        if !self.capabilities.is_empty() {
+            match &self.project_key {
+                Some(project) => writeln!(f, "Permissions in {}", project)?,
+                None => writeln!(f, "Permissions in any project")?,
+            }
+            for check in &self.capabilities {
+                let mark = match check.granted {
+                    Some(true) => "✅",
+                    Some(false) => "❌",
+                    None => "❔",
+                };
+                writeln!(
+                    f,
+                    "  {} {} ({}): {}",
+                    mark, check.capability, check.permission, check.needed_for
+                )?;
+            }
+        }
+
+        if self.ok {
+            write!(f, "No problems found")
+        } else {
+            writeln!(f, "Problems")?;
+            for problem in &self.problems {
+                writeln!(f, "  ❌ {}: {}", problem.check, problem.message)?;
+                writeln!(f, "     fix: {}", problem.remediation)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(url: &str, auth: AuthConfig) -> JiraConfig {
+        JiraConfig {
+            jira_url: url.to_string(),
+            auth,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_settings_mask_secrets() {
+        let token = "ATATT3xFfGF0-secret-token-wxyz";
+        let settings = config_settings(&config(
+            "https://acme.atlassian.net",
+            AuthConfig::Basic {
+                username: "me@acme.com".to_string(),
+                password: token.to_string(),
+            },
+        ));
+        let auth = &settings.iter().find(|s| s.name == "auth").unwrap().value;
+        assert_eq!(auth, "basic (username me@acme.com, password ****wxyz)");
+        assert!(settings.iter().all(|s| !s.value.contains("secret")));
+
+        assert_eq!(mask("short"), "****");
+        assert_eq!(mask(""), "(empty)");
+    }
+
+    #[test]
+    fn test_connection_remediation() {
+        let auth_error = JiraMcpError::auth("401 Unauthorized");
+        let cloud_pat = config(
+            "https://acme.atlassian.net",
+            AuthConfig::PersonalAccessToken("token".to_string()),
+        );
+        assert!(connection_remediation(&auth_error, &cloud_pat).contains("JIRA_AUTH_TYPE=basic"));
+
+        let server_email = config(
+            "https://jira.acme.com",
+            AuthConfig::Basic {
+                username: "me@acme.com".to_string(),
+                password: "secret".to_string(),
+            },
+        );
+        assert!(connection_remediation(&auth_error, &server_email).contains("not the email"));
+
+        let network_error = JiraMcpError::network("Connection refused");
+        let rest_url = config(
+            "https://jira.acme.com/rest/api/2",
+            AuthConfig::Bearer("token".to_string()),
+        );
+        assert!(connection_remediation(&network_error, &rest_url).contains("remove /rest"));
+        assert!(connection_remediation(&network_error, &server_email)
+            .contains("jira.acme.com is reachable"));
+    }
+
+    #[test]
+    fn test_config_problems() {
+        assert!(config_remediation("JIRA URL is required. Set JIRA_URL ...").contains("JIRA_URL"));
+
+        let anonymous = config("https://jira.acme.com", AuthConfig::Anonymous);
+        let problem = auth_mismatch(&anonymous, Some("PAT".to_string())).unwrap();
+        assert!(problem.remediation.contains("JIRA_TOKEN"));
+        assert!(auth_mismatch(&anonymous, Some("anonymous".to_string())).is_none());
+        assert!(auth_mismatch(&anonymous, None).is_none());
+    }
+}
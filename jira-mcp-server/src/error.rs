@@ -3,11 +3,22 @@
 //! Provides structured error types that map to MCP JSON-RPC error codes
 //! and converts various error types from dependencies into MCP-compatible errors.
 
+use serde::Serialize;
 use serde_json::Value;
+use std::fmt;
 use thiserror::Error;
 
+/// JSON-RPC code for invalid method parameters
+pub const INVALID_PARAMS: i32 = -32602;
+
+/// JSON-RPC code for internal errors
+pub const INTERNAL_ERROR: i32 = -32603;
+
+/// Message of the error a transition fails with when it needs a resolution
+pub const RESOLUTION_REQUIRED: &str = "JIRA requires a resolution for this transition";
+
 /// Custom error types for the JIRA MCP Server
-#[derive(Debug, Error)]
+#[derive(Debug, Clone, Error)]
 pub enum JiraMcpError {
     /// Configuration errors (-32001)
     #[error("Configuration error: {message}")]
@@ -29,7 +40,7 @@ pub enum JiraMcpError {
     #[error("Not found: {resource} '{key}' not found")]
     NotFound { resource: String, key: String },
 
-    /// Invalid parameter errors (-32006)
+    /// Invalid parameter errors (-32602, JSON-RPC invalid params)
     #[error("Invalid parameter: {parameter} - {message}")]
     InvalidParameter { parameter: String, message: String },
 
@@ -59,11 +70,11 @@ impl JiraMcpError {
             JiraMcpError::Network { .. } => -32003,
             JiraMcpError::Permission { .. } => -32004,
             JiraMcpError::NotFound { .. } => -32005,
-            JiraMcpError::InvalidParameter { .. } => -32006,
+            JiraMcpError::InvalidParameter { .. } => INVALID_PARAMS,
             JiraMcpError::RateLimit { .. } => -32007,
             JiraMcpError::Cache { .. } => -32003, // Network error category
-            JiraMcpError::JqlError { .. } => -32006, // Invalid parameter category
-            JiraMcpError::Internal { .. } => -32603, // Internal error
+            JiraMcpError::JqlError { .. } => INVALID_PARAMS,
+            JiraMcpError::Internal { .. } => INTERNAL_ERROR,
         }
     }
 
@@ -83,6 +94,25 @@ impl JiraMcpError {
         }
     }
 
+    /// Whether repeating the same request may succeed (network errors,
+    /// timeouts and rate limiting); used to retry idempotent GETs
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            JiraMcpError::Network { .. } | JiraMcpError::RateLimit { .. }
+        )
+    }
+
+    /// Whether JIRA refused a transition for lack of a resolution (see
+    /// [`requires_resolution`])
+    pub fn is_resolution_required(&self) -> bool {
+        matches!(
+            self,
+            JiraMcpError::InvalidParameter { parameter, message }
+                if parameter == "resolution" && message == RESOLUTION_REQUIRED
+        )
+    }
+
     /// Get additional error data for MCP error responses
     pub fn error_data(&self) -> Option<Value> {
         let mut data = serde_json::Map::new();
@@ -90,6 +120,7 @@ impl JiraMcpError {
             "category".to_string(),
             Value::String(self.category().to_string()),
         );
+        data.insert("retryable".to_string(), Value::Bool(self.is_retryable()));
 
         match self {
             JiraMcpError::RateLimit { retry_after } => {
@@ -192,6 +223,16 @@ impl JiraMcpError {
 /// Convert from gouqi errors to JiraMcpError
 impl From<gouqi::Error> for JiraMcpError {
     fn from(err: gouqi::Error) -> Self {
+        let message = err.to_string();
+        if let Some(retry_after) = throttle_retry_after(&message) {
+            return JiraMcpError::rate_limit(retry_after);
+        }
+        if let gouqi::Error::Fault { errors, .. } = &err {
+            if requires_resolution(errors) {
+                return JiraMcpError::invalid_param("resolution", RESOLUTION_REQUIRED);
+            }
+        }
+
         match err {
             // Map gouqi errors to appropriate MCP error types
             gouqi::Error::Http(_) => JiraMcpError::network(format!("HTTP error: {}", err)),
@@ -244,8 +285,8 @@ impl From<anyhow::Error> for JiraMcpError {
             || lower_message.contains("timeout")
         {
             JiraMcpError::network(message)
-        } else if lower_message.contains("rate limit") || lower_message.contains("429") {
-            JiraMcpError::rate_limit(60) // Default retry after 60 seconds
+        } else if let Some(retry_after) = throttle_retry_after(&message) {
+            JiraMcpError::rate_limit(retry_after)
         } else if lower_message.contains("config") {
             JiraMcpError::config(message)
         } else {
@@ -256,22 +297,83 @@ impl From<anyhow::Error> for JiraMcpError {
 
 /// Helper function to extract retry-after from HTTP errors
 pub fn extract_retry_after(error_message: &str) -> Option<u64> {
-    // Simple pattern matching to extract retry-after seconds
-    // This would need to be more sophisticated in a real implementation
-    if error_message.contains("retry-after") {
-        // Try to extract the number
-        for word in error_message.split_whitespace() {
-            if let Ok(seconds) = word.parse::<u64>() {
-                return Some(seconds);
-            }
-        }
+    // The first number after the header name, e.g. "retry-after: 30"
+    let lower = error_message.to_lowercase();
+    let (_, after) = lower.split_once("retry-after")?;
+    after
+        .split(|c: char| !c.is_ascii_digit())
+        .find(|word| !word.is_empty())?
+        .parse()
+        .ok()
+}
+
+/// Seconds to wait if the error is JIRA throttling (429), 60 when it didn't say
+pub fn throttle_retry_after(error_message: &str) -> Option<u64> {
+    let lower = error_message.to_lowercase();
+    if !lower.contains("too many requests") && !lower.contains("rate limit") {
+        return None;
     }
-    None
+
+    Some(extract_retry_after(&lower).unwrap_or(60))
+}
+
+/// Whether a JIRA error response says a transition needs a resolution
+///
+/// JIRA reports it as a field error on `resolution`, e.g. `"resolution":
+/// "Resolution is required."`. Errors on other fields don't count, even when
+/// their message mentions a resolution, and neither do other resolution errors,
+/// such as the field not being on the transition screen.
+pub fn requires_resolution(errors: &gouqi::Errors) -> bool {
+    errors
+        .errors
+        .get("resolution")
+        .is_some_and(|message| message.to_lowercase().contains("required"))
 }
 
 /// Result type alias for JIRA MCP operations
 pub type JiraMcpResult<T> = Result<T, JiraMcpError>;
 
+/// Error of an MCP tool call, in the shape of a JSON-RPC error object
+///
+/// Keeps the code and `data` (category, retryable, resource, retry_after) of
+/// the [`JiraMcpError`] it came from, so clients can tell a bad parameter from
+/// a missing issue or a rate limit worth retrying without parsing the message.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ToolError {
+    pub code: i32,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<Value>,
+}
+
+impl fmt::Display for ToolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for ToolError {}
+
+impl From<JiraMcpError> for ToolError {
+    fn from(err: JiraMcpError) -> Self {
+        ToolError {
+            code: err.error_code(),
+            data: err.error_data(),
+            message: err.to_string(),
+        }
+    }
+}
+
+/// Errors without a category are classified by their message, as elsewhere
+impl From<anyhow::Error> for ToolError {
+    fn from(err: anyhow::Error) -> Self {
+        JiraMcpError::from(err).into()
+    }
+}
+
+/// Result type of the MCP tool methods
+pub type ToolResult<T> = Result<T, ToolError>;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -288,9 +390,11 @@ mod tests {
         );
         assert_eq!(
             JiraMcpError::invalid_param("status", "invalid").error_code(),
-            -32006
+            INVALID_PARAMS
         );
+        assert_eq!(JiraMcpError::jql("bad").error_code(), INVALID_PARAMS);
         assert_eq!(JiraMcpError::rate_limit(60).error_code(), -32007);
+        assert_eq!(JiraMcpError::internal("test").error_code(), INTERNAL_ERROR);
     }
 
     #[test]
@@ -304,6 +408,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_retryable_errors() {
+        assert!(JiraMcpError::network("timeout").is_retryable());
+        assert!(JiraMcpError::rate_limit(30).is_retryable());
+        assert!(!JiraMcpError::auth("test").is_retryable());
+        assert!(!JiraMcpError::not_found("issue", "KEY-123").is_retryable());
+        assert!(!JiraMcpError::invalid_param("status", "invalid").is_retryable());
+    }
+
     #[test]
     fn test_error_data() {
         let rate_limit_error = JiraMcpError::rate_limit(120);
@@ -311,6 +424,7 @@ mod tests {
 
         assert_eq!(data["category"], "rate_limit");
         assert_eq!(data["retry_after"], 120);
+        assert_eq!(data["retryable"], true);
 
         let not_found_error = JiraMcpError::not_found("issue", "KEY-123");
         let data = not_found_error.error_data().unwrap();
@@ -320,6 +434,33 @@ mod tests {
         assert_eq!(data["key"], "KEY-123");
     }
 
+    #[test]
+    fn test_tool_error_shape() {
+        let error = ToolError::from(JiraMcpError::not_found("issue", "KEY-123"));
+        assert_eq!(
+            serde_json::to_value(&error).unwrap(),
+            serde_json::json!({
+                "code": -32005,
+                "message": "Not found: issue 'KEY-123' not found",
+                "data": {
+                    "category": "not_found",
+                    "retryable": false,
+                    "resource": "issue",
+                    "key": "KEY-123"
+                }
+            })
+        );
+        assert_eq!(error.to_string(), "Not found: issue 'KEY-123' not found");
+
+        let error = ToolError::from(JiraMcpError::invalid_param("status", "unknown"));
+        assert_eq!(error.code, INVALID_PARAMS);
+        assert_eq!(error.data.unwrap()["parameter"], "status");
+
+        let error = ToolError::from(anyhow::anyhow!("429 Too Many Requests, retry-after 5"));
+        assert_eq!(error.code, -32007);
+        assert_eq!(error.data.unwrap()["retry_after"], 5);
+    }
+
     #[test]
     fn test_anyhow_conversion() {
         let auth_error = anyhow::anyhow!("Authentication failed");
@@ -338,5 +479,49 @@ mod tests {
             Some(60)
         );
         assert_eq!(extract_retry_after("No retry info"), None);
+        assert_eq!(
+            extract_retry_after("429 Too Many Requests (Retry-After: 30)"),
+            Some(30)
+        );
+    }
+
+    #[test]
+    fn test_throttle_retry_after() {
+        assert_eq!(throttle_retry_after("429 Too Many Requests"), Some(60));
+        assert_eq!(
+            throttle_retry_after("429 Too Many Requests, retry-after 5"),
+            Some(5)
+        );
+        assert_eq!(throttle_retry_after("404 Not Found: PROJ-429"), None);
+    }
+
+    #[test]
+    fn test_requires_resolution() {
+        let field_error = |field: &str, message: &str| -> gouqi::Errors {
+            serde_json::from_value(serde_json::json!({"errors": {field: message}})).unwrap()
+        };
+
+        assert!(requires_resolution(&field_error(
+            "resolution",
+            "Resolution is required."
+        )));
+        assert!(!requires_resolution(&field_error(
+            "resolution",
+            "Field 'resolution' cannot be set. It is not on the appropriate screen, or unknown."
+        )));
+        assert!(!requires_resolution(&field_error(
+            "summary",
+            "Field 'summary' is required"
+        )));
+        // Only the resolution field counts, whatever other messages mention
+        assert!(!requires_resolution(&field_error(
+            "customfield_10050",
+            "Root cause is required when the resolution is Fixed."
+        )));
+
+        assert!(
+            JiraMcpError::invalid_param("resolution", RESOLUTION_REQUIRED).is_resolution_required()
+        );
+        assert!(!JiraMcpError::invalid_param("resolution", "unknown").is_resolution_required());
     }
 }
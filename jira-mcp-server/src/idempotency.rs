@@ -0,0 +1,449 @@
+//! Idempotency keys for create_issue and add_comment
+//!
+//! A timeout during a create leaves the caller unsure whether JIRA made the
+//! issue, and retrying blindly makes a duplicate. Calls that pass an
+//! `idempotency_key` have their result remembered under it, together with the
+//! created issue key or comment ID. Replaying the key within
+//! `idempotency_retention_seconds` returns that result, marked `replayed`,
+//! instead of creating again.
+//!
+//! Keys are scoped by operation, expire after the retention window, and beyond
+//! `idempotency_max_keys` the oldest are evicted. The map is persisted to the
+//! state directory so a retry after a restart is still recognized. A retry
+//! arriving while the first call is still running waits for it instead of
+//! creating a second time.
+
+use crate::config::JiraConfig;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use chrono::{DateTime, Utc};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, warn};
+
+const IDEMPOTENCY_FILE: &str = "idempotency_keys.json";
+
+/// Longest accepted key
+const MAX_KEY_CHARS: usize = 200;
+
+/// Longest honoured retention (ten years); larger values would overflow `chrono`
+const MAX_RETENTION_SECONDS: u64 = 10 * 365 * 24 * 60 * 60;
+
+/// What a key's first call created
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IdempotencyEntry {
+    /// Tool the key was used with, e.g. "create_issue"
+    pub operation: String,
+
+    /// Issue key or comment ID that was created
+    pub resource: String,
+
+    /// When the first call finished
+    pub recorded_at: DateTime<Utc>,
+
+    /// The first call's result, returned again on replay
+    pub result: Value,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StoreState {
+    /// "<operation>:<key>" -> entry
+    entries: HashMap<String, IdempotencyEntry>,
+}
+
+/// Results of create calls by idempotency key
+#[derive(Debug)]
+pub struct IdempotencyStore {
+    state: Mutex<StoreState>,
+    /// "<operation>:<key>" -> lock held while a call with the key runs
+    in_flight: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+    max_keys: usize,
+    retention: chrono::Duration,
+    persist_path: Option<PathBuf>,
+}
+
+impl IdempotencyStore {
+    pub fn new(max_keys: usize, retention_seconds: u64, persist_path: Option<PathBuf>) -> Self {
+        let state = persist_path
+            .as_ref()
+            .and_then(|path| match std::fs::read_to_string(path) {
+                Ok(content) => serde_json::from_str(&content)
+                    .map_err(|e| {
+                        warn!(
+                            "Ignoring unreadable idempotency keys {}: {}",
+                            path.display(),
+                            e
+                        )
+                    })
+                    .ok(),
+                Err(_) => None,
+            })
+            .unwrap_or_default();
+
+        Self {
+            state: Mutex::new(state),
+            in_flight: Mutex::new(HashMap::new()),
+            max_keys,
+            retention: chrono::Duration::seconds(
+                retention_seconds.min(MAX_RETENTION_SECONDS) as i64
+            ),
+            persist_path,
+        }
+    }
+
+    pub fn from_config(config: &JiraConfig) -> Self {
+        Self::new(
+            config.idempotency_max_keys,
+            config.idempotency_retention_seconds,
+            Some(config.state_dir().join(IDEMPOTENCY_FILE)),
+        )
+    }
+
+    /// Run `call` unless `key` was already used with `operation`
+    ///
+    /// Without a key the call just runs. A known key returns the stored result
+    /// and `true`; otherwise the call runs and a successful result is stored
+    /// under the key, with `resource` naming what it created. Failed calls are
+    /// not stored, so they can be retried with the same key. Calls with the same
+    /// key run one at a time, so a concurrent retry replays the first result.
+    pub async fn run<R, F>(
+        &self,
+        operation: &str,
+        key: Option<&str>,
+        resource: impl FnOnce(&R) -> String,
+        call: F,
+    ) -> JiraMcpResult<(R, bool)>
+    where
+        R: Serialize + DeserializeOwned,
+        F: std::future::Future<Output = JiraMcpResult<R>>,
+    {
+        let Some(key) = key else {
+            return call.await.map(|result| (result, false));
+        };
+        let key = key.trim();
+        if key.is_empty() || key.chars().count() > MAX_KEY_CHARS {
+            return Err(JiraMcpError::invalid_param(
+                "idempotency_key",
+                format!("Idempotency key must be 1 to {} characters", MAX_KEY_CHARS),
+            ));
+        }
+
+        let id = entry_id(operation, key);
+        let lock = Arc::clone(
+            self.in_flight
+                .lock()
+                .unwrap()
+                .entry(id.clone())
+                .or_default(),
+        );
+        // Declared before `_running`, so the key is unlocked before it is released
+        let in_flight = InFlightGuard {
+            in_flight: &self.in_flight,
+            id,
+            lock,
+        };
+        let _running = in_flight.lock.lock().await;
+        self.run_locked(operation, key, resource, call).await
+    }
+
+    async fn run_locked<R, F>(
+        &self,
+        operation: &str,
+        key: &str,
+        resource: impl FnOnce(&R) -> String,
+        call: F,
+    ) -> JiraMcpResult<(R, bool)>
+    where
+        R: Serialize + DeserializeOwned,
+        F: std::future::Future<Output = JiraMcpResult<R>>,
+    {
+        if let Some(entry) = self.lookup(operation, key) {
+            debug!(
+                "Replaying {} for idempotency key '{}' ({})",
+                operation, key, entry.resource
+            );
+            let result = serde_json::from_value(entry.result)?;
+            return Ok((result, true));
+        }
+
+        let result = call.await?;
+        self.record(operation, key, resource(&result), &result);
+        Ok((result, false))
+    }
+
+    /// The unexpired entry for `key`, if any
+    pub fn lookup(&self, operation: &str, key: &str) -> Option<IdempotencyEntry> {
+        let mut state = self.state.lock().unwrap();
+        self.prune(&mut state);
+        state.entries.get(&entry_id(operation, key)).cloned()
+    }
+
+    /// Remember the result of the first call with `key`; a no-op when keys are
+    /// disabled (`idempotency_max_keys` 0)
+    pub fn record<R: Serialize>(&self, operation: &str, key: &str, resource: String, result: &R) {
+        if self.max_keys == 0 {
+            return;
+        }
+        let result = match serde_json::to_value(result) {
+            Ok(result) => result,
+            Err(e) => {
+                warn!("Not storing idempotency key '{}': {}", key, e);
+                return;
+            }
+        };
+
+        let mut state = self.state.lock().unwrap();
+        state.entries.insert(
+            entry_id(operation, key),
+            IdempotencyEntry {
+                operation: operation.to_string(),
+                resource,
+                recorded_at: Utc::now(),
+                result,
+            },
+        );
+        self.prune(&mut state);
+        self.save(&state);
+    }
+
+    /// Unexpired keys
+    pub fn len(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+        self.prune(&mut state);
+        state.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop expired keys, then the oldest ones beyond `max_keys`
+    fn prune(&self, state: &mut StoreState) {
+        let cutoff = Utc::now() - self.retention;
+        state.entries.retain(|_, entry| entry.recorded_at > cutoff);
+
+        let excess = state.entries.len().saturating_sub(self.max_keys);
+        if excess > 0 {
+            let mut by_age: Vec<(DateTime<Utc>, String)> = state
+                .entries
+                .iter()
+                .map(|(id, entry)| (entry.recorded_at, id.clone()))
+                .collect();
+            by_age.sort();
+            for (_, id) in by_age.into_iter().take(excess) {
+                state.entries.remove(&id);
+            }
+        }
+    }
+
+    fn save(&self, state: &StoreState) {
+        let Some(path) = &self.persist_path else {
+            return;
+        };
+
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| {
+                let tmp_path = path.with_extension("json.tmp");
+                let content = serde_json::to_string(state).map_err(std::io::Error::other)?;
+                std::fs::write(&tmp_path, content)?;
+                std::fs::rename(&tmp_path, path)
+            });
+
+        if let Err(e) = result {
+            warn!(
+                "Failed to persist idempotency keys to {}: {}",
+                path.display(),
+                e
+            );
+        }
+    }
+}
+
+/// Releases a key's in-flight entry when its call ends, even if cancelled
+struct InFlightGuard<'a> {
+    in_flight: &'a Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+    id: String,
+    lock: Arc<tokio::sync::Mutex<()>>,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut in_flight) = self.in_flight.lock() {
+            // Only this call and the map hold the lock: nobody is waiting on it
+            if Arc::strong_count(&self.lock) == 2 {
+                in_flight.remove(&self.id);
+            }
+        }
+    }
+}
+
+fn entry_id(operation: &str, key: &str) -> String {
+    format!("{}:{}", operation, key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_replay_returns_first_result() {
+        let store = IdempotencyStore::new(10, 3600, None);
+        let create = |summary: &str| {
+            let result = json!({"issue_key": "PROJ-1", "summary": summary});
+            async move { Ok::<_, JiraMcpError>(result) }
+        };
+        let issue_key = |result: &Value| result["issue_key"].as_str().unwrap().to_string();
+
+        let (first, replayed) = store
+            .run("create_issue", Some("abc"), issue_key, create("first"))
+            .await
+            .unwrap();
+        assert!(!replayed);
+
+        let (second, replayed) = store
+            .run("create_issue", Some(" abc "), issue_key, create("second"))
+            .await
+            .unwrap();
+        assert!(replayed);
+        assert_eq!(second, first);
+        assert_eq!(
+            store.lookup("create_issue", "abc").unwrap().resource,
+            "PROJ-1"
+        );
+
+        // Keys are scoped by operation, and calls without a key always run
+        let (_, replayed) = store
+            .run("add_comment", Some("abc"), issue_key, create("third"))
+            .await
+            .unwrap();
+        assert!(!replayed);
+        let (result, replayed) = store
+            .run("create_issue", None, issue_key, create("fourth"))
+            .await
+            .unwrap();
+        assert!(!replayed);
+        assert_eq!(result["summary"], "fourth");
+
+        assert!(store
+            .run("create_issue", Some(""), issue_key, create("empty"))
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_calls_with_one_key_create_once() {
+        let store = IdempotencyStore::new(10, 3600, None);
+        let calls = AtomicUsize::new(0);
+        let create = || async {
+            let n = calls.fetch_add(1, Ordering::SeqCst) + 1;
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok::<_, JiraMcpError>(json!({"issue_key": format!("PROJ-{}", n)}))
+        };
+        let issue_key = |result: &Value| result["issue_key"].as_str().unwrap().to_string();
+
+        let (first, second) = tokio::join!(
+            store.run("create_issue", Some("abc"), issue_key, create()),
+            store.run("create_issue", Some("abc"), issue_key, create()),
+        );
+        let (first, second) = (first.unwrap(), second.unwrap());
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(first.0, second.0);
+        assert_ne!(first.1, second.1);
+        assert!(store.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancelled_calls_release_their_key() {
+        let store = IdempotencyStore::new(10, 3600, None);
+        let never = store.run(
+            "create_issue",
+            Some("abc"),
+            |_: &Value| String::new(),
+            std::future::pending::<JiraMcpResult<Value>>(),
+        );
+        assert!(tokio::time::timeout(Duration::from_millis(10), never)
+            .await
+            .is_err());
+        assert!(store.in_flight.lock().unwrap().is_empty());
+        assert!(store.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_failed_calls_are_not_stored() {
+        let store = IdempotencyStore::new(10, 3600, None);
+        let result = store
+            .run(
+                "create_issue",
+                Some("retry-me"),
+                |_: &Value| String::new(),
+                async { Err(JiraMcpError::network("timed out")) },
+            )
+            .await;
+        assert!(result.is_err());
+        assert!(store.is_empty());
+    }
+
+    #[test]
+    fn test_eviction() {
+        let store = IdempotencyStore::new(2, 3600, None);
+        for (key, issue) in [("a", "PROJ-1"), ("b", "PROJ-2"), ("c", "PROJ-3")] {
+            store.record("create_issue", key, issue.to_string(), &json!({}));
+        }
+        assert_eq!(store.len(), 2);
+        assert!(store.lookup("create_issue", "a").is_none());
+        assert!(store.lookup("create_issue", "c").is_some());
+
+        let expired = IdempotencyStore::new(10, 0, None);
+        expired.record("create_issue", "a", "PROJ-1".to_string(), &json!({}));
+        assert!(expired.lookup("create_issue", "a").is_none());
+
+        // Retention beyond what chrono can represent is clamped, not a panic
+        let forever = IdempotencyStore::new(10, u64::MAX, None);
+        forever.record("create_issue", "a", "PROJ-1".to_string(), &json!({}));
+        assert!(forever.lookup("create_issue", "a").is_some());
+
+        let disabled = IdempotencyStore::new(0, 3600, None);
+        disabled.record("create_issue", "a", "PROJ-1".to_string(), &json!({}));
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn test_persistence() {
+        let path =
+            std::env::temp_dir().join(format!("jira-idempotency-{}.json", std::process::id()));
+        let store = IdempotencyStore::new(10, 3600, Some(path.clone()));
+        store.record(
+            "add_comment",
+            "note-1",
+            "10001".to_string(),
+            &json!({"comment": {"id": "10001"}}),
+        );
+
+        // Entries are stored by "<operation>:<key>"
+        let saved: Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let entry = &saved["entries"]["add_comment:note-1"];
+        assert_eq!(entry["operation"], "add_comment");
+        assert_eq!(entry["resource"], "10001");
+        assert_eq!(entry["result"]["comment"]["id"], "10001");
+
+        let reloaded = IdempotencyStore::new(10, 3600, Some(path.clone()));
+        assert_eq!(
+            reloaded.lookup("add_comment", "note-1").unwrap().resource,
+            "10001"
+        );
+
+        // An unreadable file starts an empty store
+        std::fs::write(&path, "not json").unwrap();
+        assert!(IdempotencyStore::new(10, 3600, Some(path.clone())).is_empty());
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -3,54 +3,364 @@
 //! Provides a higher-level interface to JIRA operations with error handling,
 //! retry logic, and MCP-friendly response formats.
 
-use crate::config::JiraConfig;
-use crate::error::{JiraMcpError, JiraMcpResult};
-use gouqi::issues::AddComment;
-use gouqi::r#async::Jira;
-use gouqi::{Comment, Issue, SearchOptions, Session, Worklog, WorklogInput, WorklogList};
+use crate::adf;
+use crate::backend::{GouqiBackend, IssueQuery, JiraBackend, JqlPage, MockBackend};
+use crate::cache::{
+    BoardColumn, BoardColumnStatus, BoardConfiguration, BoardEstimation, IssueLinkTypeInfo,
+    IssueTypeInfo, PriorityInfo, ProjectInfo, SecurityLevelInfo, StatusInfo,
+};
+use crate::config::{
+    DeploymentType, JiraConfig, OperationClass, WriteLimitsConfig, ACCEPTANCE_CRITERIA_FIELD,
+    EPIC_LINK_FIELD, STORY_POINTS_FIELD,
+};
+use crate::connection::ConnectionState;
+use crate::error::{throttle_retry_after, JiraMcpError, JiraMcpResult};
+use crate::jql;
+use crate::messages::Messages;
+use crate::metrics;
+use crate::redaction::Redactor;
+use crate::request_log::RequestLog;
+use crate::single_flight::SingleFlight;
+use crate::tools::rate_limiter::RateLimiter;
+use crate::tools::signature::sign;
+use gouqi::relationships::{GraphOptions, RelationshipGraph};
+use gouqi::{
+    AttachmentResponse, Issue, SearchOptions, Sprint, SprintResults, Worklog, WorklogInput,
+    WorklogList,
+};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::time::timeout;
 use tracing::{debug, error, info, instrument, warn};
 
+/// Issues requested per page from the enhanced /search/jql endpoint
+const ENHANCED_SEARCH_PAGE_SIZE: usize = 100;
+
 /// JIRA client wrapper that provides MCP-friendly operations
 #[derive(Debug, Clone)]
 pub struct JiraClient {
-    pub(crate) client: Arc<Jira>,
+    /// Only used through the methods here, which time, log and count every request
+    backend: Arc<dyn JiraBackend>,
     config: Arc<JiraConfig>,
+    mock: Option<Arc<MockBackend>>,
+    rate_limiter: RateLimiter,
+    request_log: Arc<RequestLog>,
+    connection: Arc<ConnectionState>,
+    server_info: ServerInfo,
+    field_map: FieldMap,
+    messages: Messages,
+    redactor: Redactor,
+    targeted_text_search: Arc<OnceLock<bool>>,
+    /// Response of identical GETs in flight, by path
+    shared_gets: Arc<SingleFlight<JiraMcpResult<serde_json::Value>>>,
+}
+
+/// Deployment type and version of the connected JIRA instance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ServerInfo {
+    pub deployment_type: DeploymentType,
+
+    /// JIRA version, when /serverInfo could be read
+    pub version: Option<String>,
+
+    /// Where the deployment type came from: "server_info", "config" or "url"
+    pub source: String,
+}
+
+/// Custom fields whose ID differs between instances
+///
+/// Resolved once when the client starts: `field_mappings` from the config win,
+/// else the field catalog is searched by name, else the IDs JIRA Cloud uses by
+/// default are assumed. Epic Link has no default: without the field, epics are
+/// linked through `parent` as in team-managed projects.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldMap {
+    pub story_points: DetectedField,
+    pub acceptance_criteria: DetectedField,
+    pub epic_link: DetectedField,
+}
+
+/// Field IDs chosen for one semantic field
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DetectedField {
+    /// Candidate field IDs, tried in order when reading an issue
+    pub field_ids: Vec<String>,
+
+    /// Where the IDs came from: "config", "field_catalog" or "default"
+    pub source: String,
+}
+
+impl FieldMap {
+    /// Catalog names matched (case-insensitively) for story points; company-managed
+    /// projects use the first, team-managed Cloud projects the second
+    const STORY_POINTS_NAMES: &'static [&'static str] = &["Story Points", "Story point estimate"];
+    const ACCEPTANCE_CRITERIA_NAMES: &'static [&'static str] = &["Acceptance Criteria"];
+    const EPIC_LINK_NAMES: &'static [&'static str] = &["Epic Link"];
+
+    /// Resolve from the configured mappings and the /field catalog, if it could be read
+    pub fn resolve(
+        field_mappings: &HashMap<String, String>,
+        catalog: Option<&[serde_json::Value]>,
+    ) -> Self {
+        Self {
+            story_points: DetectedField::resolve(
+                field_mappings.get(STORY_POINTS_FIELD),
+                catalog,
+                Self::STORY_POINTS_NAMES,
+                &["customfield_10016"],
+            ),
+            acceptance_criteria: DetectedField::resolve(
+                field_mappings.get(ACCEPTANCE_CRITERIA_FIELD),
+                catalog,
+                Self::ACCEPTANCE_CRITERIA_NAMES,
+                &["customfield_10100", "customfield_10007"],
+            ),
+            epic_link: DetectedField::resolve(
+                field_mappings.get(EPIC_LINK_FIELD),
+                catalog,
+                Self::EPIC_LINK_NAMES,
+                &[],
+            ),
+        }
+    }
+}
+
+impl DetectedField {
+    fn resolve(
+        configured: Option<&String>,
+        catalog: Option<&[serde_json::Value]>,
+        names: &[&str],
+        defaults: &[&str],
+    ) -> Self {
+        if let Some(field_id) = configured.map(|id| id.trim()).filter(|id| !id.is_empty()) {
+            return Self {
+                field_ids: vec![field_id.to_string()],
+                source: "config".to_string(),
+            };
+        }
+
+        let field_ids: Vec<String> = names
+            .iter()
+            .flat_map(|name| {
+                catalog.unwrap_or_default().iter().filter(move |field| {
+                    field["name"]
+                        .as_str()
+                        .is_some_and(|field_name| field_name.trim().eq_ignore_ascii_case(name))
+                })
+            })
+            .filter_map(|field| field["id"].as_str().map(String::from))
+            .collect();
+        if !field_ids.is_empty() {
+            return Self {
+                field_ids,
+                source: "field_catalog".to_string(),
+            };
+        }
+
+        Self {
+            field_ids: defaults.iter().map(|id| id.to_string()).collect(),
+            source: "default".to_string(),
+        }
+    }
+
+    /// Whether the instance has the field at all
+    pub fn is_present(&self) -> bool {
+        !self.field_ids.is_empty()
+    }
+
+    /// Value of the first candidate that is set on the issue
+    pub fn value_in<'a>(
+        &self,
+        fields: &'a BTreeMap<String, serde_json::Value>,
+    ) -> Option<&'a serde_json::Value> {
+        self.field_ids
+            .iter()
+            .filter_map(|id| fields.get(id))
+            .find(|value| !value.is_null())
+    }
+
+    /// Field to write on an issue: the first candidate set on it, else the first
+    /// candidate the issue has at all
+    pub fn field_for(&self, fields: &BTreeMap<String, serde_json::Value>) -> Option<&str> {
+        self.field_ids
+            .iter()
+            .find(|id| fields.get(*id).is_some_and(|value| !value.is_null()))
+            .or_else(|| self.field_ids.iter().find(|id| fields.contains_key(*id)))
+            .map(String::as_str)
+    }
+}
+
+/// A write sent through [`JiraClient::post_json`] and friends
+enum Write {
+    Post(serde_json::Value),
+    Put(serde_json::Value),
+    Delete,
+}
+
+impl Write {
+    fn method(&self) -> &'static str {
+        match self {
+            Write::Post(_) => "POST",
+            Write::Put(_) => "PUT",
+            Write::Delete => "DELETE",
+        }
+    }
+
+    fn body(&self) -> Option<&serde_json::Value> {
+        match self {
+            Write::Post(body) | Write::Put(body) => Some(body),
+            Write::Delete => None,
+        }
+    }
 }
 
 /// Search result wrapper with pagination info
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchResult {
     pub issues: Vec<IssueInfo>,
-    pub total: usize,
+    /// Total number of matches; None when JIRA doesn't report one (the enhanced
+    /// search on JIRA Cloud), in which case `is_last` tells whether more follow
+    pub total: Option<usize>,
     pub start_at: usize,
     pub max_results: usize,
     pub is_last: bool,
 }
 
+/// Every issue matching a query, fetched page by page
+#[derive(Debug, Clone)]
+pub struct PagedIssues {
+    pub issues: Vec<IssueInfo>,
+    /// Number of search requests made
+    pub pages: usize,
+    /// False when the cap was reached before the last page
+    pub complete: bool,
+}
+
 /// Simplified issue information for search results
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct IssueInfo {
     pub key: String,
     pub id: String,
+    /// Browse URL of the issue
+    #[serde(default)]
+    pub url: String,
     pub summary: String,
     pub description: Option<String>,
     pub issue_type: String,
     pub status: String,
+    /// Status category key: "new", "indeterminate" or "done" (not localized)
+    #[serde(default)]
+    pub status_category: Option<String>,
+    /// Color JIRA shows the status category in: "blue-gray", "yellow" or "green"
+    #[serde(default)]
+    pub status_color: Option<String>,
+    /// Icon of the issue type
+    #[serde(default)]
+    pub issue_type_icon_url: Option<String>,
     pub priority: Option<String>,
     pub assignee: Option<String>,
     pub reporter: Option<String>,
     pub created: String,
     pub updated: String,
+    /// Due date (YYYY-MM-DD), if set
+    #[serde(default)]
+    pub due_date: Option<String>,
     pub project_key: String,
     pub project_name: String,
     pub labels: Vec<String>,
     pub components: Vec<String>,
     pub story_points: Option<f64>,
     pub acceptance_criteria: Option<String>,
+    /// Short excerpt around the first text-query match (search results only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_snippet: Option<String>,
+    /// Field the text query matched in: "summary", "description" or "comment"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub match_field: Option<String>,
+    /// Comment bodies as text, when the response included them; only used to
+    /// find text-query matches, so never serialized
+    #[serde(skip)]
+    pub comments: Vec<String>,
+    /// Estimates and logged time, when time tracking is enabled and set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub time_tracking: Option<TimeTracking>,
+    /// Issue security level name; set means the issue is restricted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub security_level: Option<String>,
+    /// Resolution name, e.g. "Duplicate"; unset while the issue is unresolved
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution: Option<String>,
+}
+
+/// Time tracking rollup of an issue (JIRA's `timetracking` field)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct TimeTracking {
+    /// Original estimate in JIRA notation, e.g. "1w 2d"
+    pub original_estimate: Option<String>,
+    pub original_estimate_seconds: Option<u64>,
+    /// Remaining estimate in JIRA notation, e.g. "3d"
+    pub remaining_estimate: Option<String>,
+    pub remaining_estimate_seconds: Option<u64>,
+    /// Total time logged, in JIRA notation
+    pub time_spent: Option<String>,
+    pub time_spent_seconds: Option<u64>,
+}
+
+impl TimeTracking {
+    /// Parse JIRA's `timetracking` object; `None` when it holds no values
+    fn from_json(value: &serde_json::Value) -> Option<Self> {
+        let text = |name: &str| value[name].as_str().map(String::from);
+        let tracking = TimeTracking {
+            original_estimate: text("originalEstimate"),
+            original_estimate_seconds: value["originalEstimateSeconds"].as_u64(),
+            remaining_estimate: text("remainingEstimate"),
+            remaining_estimate_seconds: value["remainingEstimateSeconds"].as_u64(),
+            time_spent: text("timeSpent"),
+            time_spent_seconds: value["timeSpentSeconds"].as_u64(),
+        };
+        let is_empty = tracking.original_estimate.is_none()
+            && tracking.remaining_estimate.is_none()
+            && tracking.time_spent.is_none();
+        (!is_empty).then_some(tracking)
+    }
+}
+
+/// How JIRA displays an issue's status and type, read from the raw fields
+///
+/// Cloud and Server/Data Center both nest the category under the status; the
+/// icon URL points at an avatar endpoint that differs between them.
+#[derive(Debug, Default, PartialEq)]
+struct IssueStyle {
+    status_category: Option<String>,
+    status_color: Option<String>,
+    issue_type_icon_url: Option<String>,
+}
+
+impl IssueStyle {
+    fn from_fields(fields: &BTreeMap<String, serde_json::Value>) -> Self {
+        let text = |value: &serde_json::Value| value.as_str().map(String::from);
+        let category = fields.get("status").map(|status| &status["statusCategory"]);
+        Self {
+            status_category: category.and_then(|category| text(&category["key"])),
+            status_color: category.and_then(|category| text(&category["colorName"])),
+            issue_type_icon_url: fields
+                .get("issuetype")
+                .and_then(|issue_type| text(&issue_type["iconUrl"])),
+        }
+    }
+}
+
+impl IssueInfo {
+    /// Whether the issue is in a "done" status category, whatever its status is
+    /// called (e.g. "Terminé" on a French instance)
+    pub fn is_done(&self) -> bool {
+        self.status_category.as_deref() == Some("done")
+    }
 }
 
 /// Detailed issue information
@@ -63,6 +373,12 @@ pub struct IssueDetails {
     pub subtasks: Vec<IssueInfo>,
     pub parent: Option<IssueInfo>,
     pub linked_issues: Vec<LinkedIssue>,
+    /// Worklogs, most recent last; capped, see `worklog_summary.omitted`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worklogs: Option<Vec<WorklogInfo>>,
+    /// Logged time over all worklogs, including omitted ones
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub worklog_summary: Option<WorklogSummary>,
 }
 
 /// Comment information
@@ -73,6 +389,44 @@ pub struct CommentInfo {
     pub body: String,
     pub created: String,
     pub updated: String,
+    /// Permalink to the comment
+    #[serde(default)]
+    pub url: String,
+    /// Role/group restriction; None means the comment is visible to everyone with issue access
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<CommentVisibility>,
+    /// Whether a JSM comment is internal (agent-only); None outside service desk projects
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jsm_internal: Option<bool>,
+}
+
+impl CommentInfo {
+    /// When the comment was written, with the offset it was written in
+    pub fn created_at(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        use chrono::DateTime;
+        DateTime::parse_from_str(&self.created, "%Y-%m-%dT%H:%M:%S%.f%z")
+            .or_else(|_| DateTime::parse_from_rfc3339(&self.created))
+            .ok()
+    }
+}
+
+/// Kind of comment visibility restriction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum VisibilityType {
+    /// Project role, e.g. "Developers"
+    Role,
+    /// User group, e.g. "jira-software-users"
+    Group,
+}
+
+/// Comment visibility restriction, in JIRA's `visibility` shape
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CommentVisibility {
+    #[serde(rename = "type")]
+    pub visibility_type: VisibilityType,
+    /// Role or group name
+    pub value: String,
 }
 
 /// Worklog information
@@ -88,6 +442,52 @@ pub struct WorklogInfo {
     pub time_spent_seconds: Option<u64>,
 }
 
+impl WorklogInfo {
+    /// When the logged work started, with the offset it was logged in
+    pub fn started_at(&self) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        use chrono::DateTime;
+        DateTime::parse_from_str(&self.started, "%Y-%m-%dT%H:%M:%S%.f%z")
+            .or_else(|_| DateTime::parse_from_rfc3339(&self.started))
+            // The `time` crate's Display, e.g. "2026-01-06 14:00:00.0 +00:00:00"
+            .or_else(|_| DateTime::parse_from_str(&self.started, "%Y-%m-%d %H:%M:%S%.f %:z:00"))
+            .ok()
+    }
+}
+
+/// Logged time of an issue, totalled over its worklogs
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorklogSummary {
+    pub total_time_spent_seconds: u64,
+
+    /// Seconds logged per author (display name)
+    pub per_author: BTreeMap<String, u64>,
+
+    /// Number of worklogs on the issue
+    pub worklog_count: usize,
+
+    /// Worklogs left out of the response because of the cap
+    pub omitted: usize,
+}
+
+impl WorklogSummary {
+    /// Total the given worklogs
+    pub fn from_worklogs(worklogs: &[WorklogInfo]) -> Self {
+        let mut summary = WorklogSummary {
+            worklog_count: worklogs.len(),
+            ..Default::default()
+        };
+        for worklog in worklogs {
+            let seconds = worklog.time_spent_seconds.unwrap_or(0);
+            summary.total_time_spent_seconds += seconds;
+            *summary
+                .per_author
+                .entry(worklog.author.clone())
+                .or_default() += seconds;
+        }
+        summary
+    }
+}
+
 /// Attachment information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttachmentInfo {
@@ -97,6 +497,15 @@ pub struct AttachmentInfo {
     pub created: String,
     pub size: u64,
     pub mime_type: String,
+    /// Download URL of the attachment content
+    #[serde(default)]
+    pub content_url: String,
+    /// Thumbnail URL, for images
+    #[serde(default)]
+    pub thumbnail_url: Option<String>,
+    /// Account ID of the author (username on Server/Data Center)
+    #[serde(default)]
+    pub author_account_id: Option<String>,
 }
 
 /// History entry for issue changes
@@ -119,6 +528,34 @@ pub struct HistoryItem {
     pub to_string: Option<String>,
 }
 
+impl HistoryEntry {
+    fn from_json(history: &serde_json::Value) -> Option<Self> {
+        let text = |value: &serde_json::Value| value.as_str().map(String::from);
+        let items = history["items"]
+            .as_array()?
+            .iter()
+            .map(|item| HistoryItem {
+                field: text(&item["field"]).unwrap_or_default(),
+                field_type: text(&item["fieldtype"]).unwrap_or_default(),
+                from: text(&item["from"]),
+                from_string: text(&item["fromString"]),
+                to: text(&item["to"]),
+                to_string: text(&item["toString"]),
+            })
+            .collect();
+
+        Some(Self {
+            id: match &history["id"] {
+                serde_json::Value::Number(id) => id.to_string(),
+                id => text(id)?,
+            },
+            author: text(&history["author"]["displayName"]).unwrap_or_default(),
+            created: text(&history["created"])?,
+            items,
+        })
+    }
+}
+
 /// Linked issue information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LinkedIssue {
@@ -127,6 +564,9 @@ pub struct LinkedIssue {
     pub status: String,
     pub link_type: String,
     pub direction: String, // "inward" or "outward"
+    /// Full issue information, when get_issue_details was asked to expand links
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details: Option<IssueInfo>,
 }
 
 /// User information
@@ -138,26 +578,122 @@ pub struct UserInfo {
     pub active: bool,
 }
 
+/// Agile board information
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BoardInfo {
+    pub id: u64,
+    pub name: String,
+
+    /// Board type, e.g. "scrum" or "kanban"
+    pub board_type: String,
+
+    /// Project the board is located in, if any (filter boards may span several)
+    pub project_key: Option<String>,
+}
+
+impl BoardInfo {
+    fn from_json(board: &serde_json::Value) -> Option<Self> {
+        Some(Self {
+            id: board["id"].as_u64()?,
+            name: board["name"].as_str().unwrap_or_default().to_string(),
+            board_type: board["type"].as_str().unwrap_or_default().to_string(),
+            project_key: board["location"]["projectKey"].as_str().map(String::from),
+        })
+    }
+}
+
+/// A version (release) of a project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectVersion {
+    pub id: String,
+    pub name: String,
+    pub released: bool,
+    pub archived: bool,
+}
+
+/// Partial sprint update; fields left as None keep their current value
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SprintUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goal: Option<String>,
+    /// RFC 3339 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_date: Option<String>,
+    /// RFC 3339 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_date: Option<String>,
+    /// "active" or "closed"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state: Option<String>,
+}
+
 impl JiraClient {
     /// Create a new JIRA client with the given configuration
     #[instrument(skip_all)]
     pub async fn new(config: Arc<JiraConfig>) -> JiraMcpResult<Self> {
-        info!("Initializing JIRA client for URL: {}", config.jira_url);
+        let (backend, mock): (Arc<dyn JiraBackend>, _) = match &config.mock_fixtures_dir {
+            Some(dir) => {
+                info!("Initializing mock JIRA client with fixtures from {}", dir);
+                let mock = Arc::new(MockBackend::new(dir)?);
+                (Arc::clone(&mock) as _, Some(mock))
+            }
+            None => {
+                info!("Initializing JIRA client for URL: {}", config.jira_url);
+                let credentials = config.to_gouqi_credentials();
 
-        let credentials = config.to_gouqi_credentials();
+                // Create the async gouqi client with timeout
+                let backend = timeout(Duration::from_secs(config.request_timeout_seconds), async {
+                    GouqiBackend::new(&config.jira_url, credentials)
+                })
+                .await
+                .map_err(|_| JiraMcpError::network("Timeout connecting to JIRA instance"))?
+                .map_err(JiraMcpError::from)?;
+                (Arc::new(backend) as _, None)
+            }
+        };
 
-        // Create the async gouqi client with timeout
-        let client = timeout(Duration::from_secs(config.request_timeout_seconds), async {
-            Jira::new(&config.jira_url, credentials)
-        })
-        .await
-        .map_err(|_| JiraMcpError::network("Timeout connecting to JIRA instance"))?
-        .map_err(JiraMcpError::from)?;
+        let rate_limiter = RateLimiter::new(
+            (config.rate_limit_per_minute as usize).max(1),
+            Duration::from_secs(60),
+        );
+
+        let request_log = Arc::new(RequestLog::new(&config));
 
-        let jira_client = Self {
-            client: Arc::new(client),
+        let field_map = FieldMap::resolve(&config.field_mappings, None);
+        let messages = Messages::new(&config.messages);
+        let redactor = Redactor::new(&config.redaction)
+            .map_err(|e| JiraMcpError::config(format!("{:#}", e)))?;
+        let mut jira_client = Self {
+            backend,
             config,
+            mock,
+            rate_limiter,
+            request_log,
+            connection: Arc::new(ConnectionState::new()),
+            server_info: ServerInfo {
+                deployment_type: DeploymentType::Server,
+                version: None,
+                source: "url".to_string(),
+            },
+            field_map,
+            messages,
+            redactor,
+            targeted_text_search: Arc::new(OnceLock::new()),
+            shared_gets: Arc::default(),
         };
+        jira_client.server_info = jira_client.detect_server_info().await;
+        info!(
+            "JIRA deployment: {:?} (from {})",
+            jira_client.server_info.deployment_type, jira_client.server_info.source
+        );
+        jira_client.field_map = jira_client.detect_field_map().await;
+        info!(
+            "Story points field: {:?} (from {})",
+            jira_client.field_map.story_points.field_ids, jira_client.field_map.story_points.source
+        );
 
         // Test the connection
         jira_client.test_connection().await?;
@@ -171,6 +707,431 @@ impl JiraClient {
         &self.config.jira_url
     }
 
+    /// Renders the `message` fields of tool results
+    pub fn messages(&self) -> &Messages {
+        &self.messages
+    }
+
+    /// Length limits of descriptions and comments written through the tools
+    pub fn write_limits(&self) -> &WriteLimitsConfig {
+        &self.config.write_limits
+    }
+
+    /// Resolution transitions fall back to when JIRA requires one and the call
+    /// set none
+    pub fn default_resolution(&self) -> Option<&str> {
+        self.config.default_resolution.as_deref()
+    }
+
+    /// Text of a downloaded attachment, redacted like other issue content; None
+    /// when the bytes are not UTF-8
+    pub fn attachment_text(&self, bytes: &[u8]) -> Option<String> {
+        std::str::from_utf8(bytes)
+            .ok()
+            .map(|text| self.redactor.redact(text).into_owned())
+    }
+
+    /// Whether JIRA accepts text queries limited to single fields
+    /// (`summary ~ "..."`), None until search_issues first tried one
+    pub fn targeted_text_search(&self) -> Option<bool> {
+        self.targeted_text_search.get().copied()
+    }
+
+    /// Remember whether targeted text queries work; the first answer sticks
+    pub fn set_targeted_text_search(&self, supported: bool) {
+        let _ = self.targeted_text_search.set(supported);
+    }
+
+    /// Last known state of the connection, updated by every logged request
+    pub fn connection_state(&self) -> &ConnectionState {
+        &self.connection
+    }
+
+    /// The mock backend, when running in mock mode
+    pub fn mock_backend(&self) -> Option<Arc<MockBackend>> {
+        self.mock.clone()
+    }
+
+    /// Deployment type and version of the instance
+    pub fn server_info(&self) -> &ServerInfo {
+        &self.server_info
+    }
+
+    /// Deployment type of the instance, for request shapes that differ by deployment
+    pub fn deployment_type(&self) -> DeploymentType {
+        self.server_info.deployment_type
+    }
+
+    /// Custom field IDs for story points, acceptance criteria and Epic Link
+    pub fn field_map(&self) -> &FieldMap {
+        &self.field_map
+    }
+
+    /// Resolve the field map, reading the field catalog unless the config sets every field
+    async fn detect_field_map(&self) -> FieldMap {
+        let mappings = &self.config.field_mappings;
+        if [
+            STORY_POINTS_FIELD,
+            ACCEPTANCE_CRITERIA_FIELD,
+            EPIC_LINK_FIELD,
+        ]
+        .iter()
+        .all(|key| mappings.contains_key(*key))
+        {
+            return FieldMap::resolve(mappings, None);
+        }
+
+        let catalog: Result<Vec<serde_json::Value>, JiraMcpError> = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching field catalog",
+                "api",
+                "/field",
+                JiraMcpError::from,
+            )
+            .await;
+
+        match catalog {
+            Ok(catalog) => FieldMap::resolve(mappings, Some(&catalog)),
+            Err(e) => {
+                warn!(
+                    "Could not read the field catalog ({}), using default field IDs; set field_mappings to override",
+                    e
+                );
+                FieldMap::resolve(mappings, None)
+            }
+        }
+    }
+
+    /// Determine the deployment type: the config override, else /serverInfo,
+    /// else a guess from the URL (Atlassian Cloud sites live on atlassian.net)
+    async fn detect_server_info(&self) -> ServerInfo {
+        if let Some(deployment_type) = self.config.deployment_type {
+            return ServerInfo {
+                deployment_type,
+                version: None,
+                source: "config".to_string(),
+            };
+        }
+
+        let response: Result<serde_json::Value, JiraMcpError> = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching server info",
+                "api",
+                "/serverInfo",
+                JiraMcpError::from,
+            )
+            .await;
+
+        match response {
+            Ok(info) => ServerInfo {
+                // Older Server versions don't report a deployment type
+                deployment_type: info["deploymentType"]
+                    .as_str()
+                    .and_then(DeploymentType::parse)
+                    .unwrap_or(DeploymentType::Server),
+                version: info["version"].as_str().map(String::from),
+                source: "server_info".to_string(),
+            },
+            Err(e) => {
+                let deployment_type = if self.config.jira_url.contains(".atlassian.net") {
+                    DeploymentType::Cloud
+                } else {
+                    DeploymentType::Server
+                };
+                warn!(
+                    "Could not read serverInfo ({}), assuming {:?}; set JIRA_DEPLOYMENT_TYPE to override",
+                    e, deployment_type
+                );
+                ServerInfo {
+                    deployment_type,
+                    version: None,
+                    source: "url".to_string(),
+                }
+            }
+        }
+    }
+
+    /// Rate limiter sized by `rate_limit_per_minute`; background work waits on it,
+    /// and every request made through the client counts against its budget
+    pub fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    /// Log of the requests made through the client (empty unless `request_log.enabled`)
+    pub fn request_log(&self) -> &RequestLog {
+        &self.request_log
+    }
+
+    /// Record a request that did not go through `get_with_retry`, also updating
+    /// the connection state
+    fn log_request<T>(
+        &self,
+        method: &str,
+        endpoint: &str,
+        payload_bytes: Option<usize>,
+        started: Instant,
+        result: &JiraMcpResult<T>,
+    ) {
+        // Every response passes through here, so this is where a 429 is noted
+        // for the rate_limit block of tool results
+        if let Err(JiraMcpError::RateLimit { retry_after }) = result {
+            self.rate_limiter
+                .record_throttle(Duration::from_secs(*retry_after));
+        }
+        self.connection.record(result.as_ref().map(|_| ()));
+        self.request_log.record(
+            method,
+            endpoint,
+            payload_bytes,
+            result.as_ref().map(|_| ()),
+            started.elapsed(),
+        );
+    }
+
+    /// Timeout applied to requests of the given class
+    pub fn timeout_for(&self, class: OperationClass) -> Duration {
+        Duration::from_secs(
+            self.config
+                .timeouts
+                .seconds(class, self.config.request_timeout_seconds),
+        )
+    }
+
+    /// Run `request` under the timeout of `class`
+    ///
+    /// The timeout error names the limit that was hit and the setting raising it.
+    pub async fn with_timeout<F: Future>(
+        &self,
+        class: OperationClass,
+        what: &str,
+        request: F,
+    ) -> JiraMcpResult<F::Output> {
+        let limit = self.timeout_for(class);
+        self.rate_limiter.record_request();
+        metrics::record_api_calls(1);
+        timeout(limit, request).await.map_err(|_| {
+            JiraMcpError::network(format!(
+                "Timeout {} after {}s (raise timeouts.{} or {})",
+                what,
+                limit.as_secs(),
+                class.name(),
+                class.env_var()
+            ))
+        })
+    }
+
+    /// Run an idempotent GET under the timeout of `class`
+    ///
+    /// With `retry.enabled`, retryable failures (network errors, timeouts, rate
+    /// limiting) are repeated up to `retry.max_attempts` times with exponential
+    /// backoff. `map_err` classifies JIRA errors, e.g. a 404 as not found.
+    /// Each attempt is recorded in the request log under `endpoint`.
+    async fn get_with_retry<T, F, Fut>(
+        &self,
+        class: OperationClass,
+        what: &str,
+        endpoint: &str,
+        request: F,
+        map_err: impl Fn(gouqi::Error) -> JiraMcpError,
+    ) -> JiraMcpResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, gouqi::Error>>,
+    {
+        let retry = &self.config.retry;
+        let max_attempts = if retry.enabled {
+            retry.max_attempts.max(1)
+        } else {
+            1
+        };
+        let mut backoff = Duration::from_millis(retry.initial_backoff_ms);
+
+        let mut attempt = 1;
+        loop {
+            let started = Instant::now();
+            let result = self
+                .with_timeout(class, what, request())
+                .await
+                .and_then(|response| {
+                    response.map_err(|e| match throttle_retry_after(&e.to_string()) {
+                        // Throttling is retryable whatever the caller maps errors to
+                        Some(retry_after) => JiraMcpError::rate_limit(retry_after),
+                        None => map_err(e),
+                    })
+                });
+            self.log_request("GET", endpoint, None, started, &result);
+            match result {
+                Err(e) if attempt < max_attempts && e.is_retryable() => {
+                    warn!(
+                        "{} failed (attempt {}/{}), retrying in {}ms: {}",
+                        what,
+                        attempt,
+                        max_attempts,
+                        backoff.as_millis(),
+                        e
+                    );
+                    metrics::record_retry();
+                    tokio::time::sleep(backoff).await;
+                    backoff *= 2;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// [`Self::get_with_retry`] for `GET {root}{endpoint}`, shared with the
+    /// identical GET in flight, if any
+    ///
+    /// Parallel tool calls reading the same issue make one request this way
+    /// (see [`SingleFlight`]); only use it where `endpoint` identifies the
+    /// request completely, query included.
+    async fn get_shared<T: DeserializeOwned>(
+        &self,
+        class: OperationClass,
+        what: &str,
+        root: &str,
+        endpoint: &str,
+        map_err: impl Fn(gouqi::Error) -> JiraMcpError,
+    ) -> JiraMcpResult<T> {
+        self.read_shared(
+            class,
+            what,
+            &format!("{}{}", root, endpoint),
+            || self.backend.get(root, endpoint),
+            map_err,
+        )
+        .await
+    }
+
+    /// [`Self::get_with_retry`] for a read operation of the backend, shared
+    /// with the identical read in flight, if any
+    ///
+    /// `path` is the REST path the operation reads (root, endpoint and query),
+    /// which identifies it in the request log and among reads in flight.
+    async fn read_shared<T, F, Fut>(
+        &self,
+        class: OperationClass,
+        what: &str,
+        path: &str,
+        request: F,
+        map_err: impl Fn(gouqi::Error) -> JiraMcpError,
+    ) -> JiraMcpResult<T>
+    where
+        T: DeserializeOwned,
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<serde_json::Value, gouqi::Error>>,
+    {
+        let response = self
+            .shared_gets
+            .run(path, || {
+                self.get_with_retry(class, what, path, &request, map_err)
+            })
+            .await?;
+        serde_json::from_value(response)
+            .map_err(|e| JiraMcpError::internal(format!("Unexpected response {}: {}", what, e)))
+    }
+
+    /// `GET {root}{endpoint}` for resources without a typed method here
+    ///
+    /// Retried, shared and recorded in the request log like the typed reads
+    /// (see [`Self::get_with_retry`]).
+    pub async fn get_json<T: DeserializeOwned>(
+        &self,
+        class: OperationClass,
+        what: &str,
+        root: &str,
+        endpoint: &str,
+        map_err: impl Fn(gouqi::Error) -> JiraMcpError,
+    ) -> JiraMcpResult<T> {
+        self.get_shared(class, what, root, endpoint, map_err).await
+    }
+
+    /// `POST {root}{endpoint}` for writes without a typed method here
+    ///
+    /// An empty response (204 No Content) reads as JSON `null`.
+    pub async fn post_json<T: DeserializeOwned>(
+        &self,
+        what: &str,
+        root: &str,
+        endpoint: &str,
+        body: impl Serialize,
+        map_err: impl Fn(gouqi::Error) -> JiraMcpError,
+    ) -> JiraMcpResult<T> {
+        let body = serde_json::to_value(body)?;
+        self.write(Write::Post(body), what, root, endpoint, map_err)
+            .await
+    }
+
+    /// `PUT {root}{endpoint}`; see [`Self::post_json`]
+    pub async fn put_json<T: DeserializeOwned>(
+        &self,
+        what: &str,
+        root: &str,
+        endpoint: &str,
+        body: impl Serialize,
+        map_err: impl Fn(gouqi::Error) -> JiraMcpError,
+    ) -> JiraMcpResult<T> {
+        let body = serde_json::to_value(body)?;
+        self.write(Write::Put(body), what, root, endpoint, map_err)
+            .await
+    }
+
+    /// `DELETE {root}{endpoint}`; see [`Self::post_json`]
+    pub async fn delete_json<T: DeserializeOwned>(
+        &self,
+        what: &str,
+        root: &str,
+        endpoint: &str,
+        map_err: impl Fn(gouqi::Error) -> JiraMcpError,
+    ) -> JiraMcpResult<T> {
+        self.write(Write::Delete, what, root, endpoint, map_err)
+            .await
+    }
+
+    /// Send a write under the write timeout, recording it in the request log
+    ///
+    /// Writes are never retried: JIRA may have applied one that timed out.
+    async fn write<T: DeserializeOwned>(
+        &self,
+        request: Write,
+        what: &str,
+        root: &str,
+        endpoint: &str,
+        map_err: impl Fn(gouqi::Error) -> JiraMcpError,
+    ) -> JiraMcpResult<T> {
+        let method = request.method();
+        let payload_bytes = request.body().map(|body| body.to_string().len());
+        let started = Instant::now();
+        let send = async {
+            match request {
+                Write::Post(body) => self.backend.post(root, endpoint, body).await,
+                Write::Put(body) => self.backend.put(root, endpoint, body).await,
+                Write::Delete => self.backend.delete(root, endpoint).await,
+            }
+        };
+        let result = self
+            .with_timeout(OperationClass::Write, what, send)
+            .await
+            .and_then(|response| {
+                let response = response.and_then(|value| Ok(serde_json::from_value::<T>(value)?));
+                response.map_err(|e| match throttle_retry_after(&e.to_string()) {
+                    Some(retry_after) => JiraMcpError::rate_limit(retry_after),
+                    None => map_err(e),
+                })
+            });
+        self.log_request(
+            method,
+            &format!("{}{}", root, endpoint),
+            payload_bytes,
+            started,
+            &result,
+        );
+        result
+    }
+
     /// Test the connection to the JIRA instance
     #[instrument(skip_all)]
     async fn test_connection(&self) -> JiraMcpResult<()> {
@@ -193,24 +1154,31 @@ impl JiraClient {
     }
 
     /// Get current user information
+    ///
+    /// Read from /myself, which carries the account ID on Cloud and the username
+    /// Server and Data Center identify users by, along with the display name.
     #[instrument(skip_all)]
     pub async fn get_current_user(&self) -> JiraMcpResult<UserInfo> {
         debug!("Fetching current user information");
 
-        let timeout_duration = Duration::from_secs(self.config.request_timeout_seconds);
-
-        let session = timeout(timeout_duration, async {
-            // Use session() method to get current user info
-            self.client.session().await
-        })
-        .await
-        .map_err(|_| JiraMcpError::network("Timeout getting current user"))?
-        .map_err(JiraMcpError::from)?;
-
-        Ok(self.convert_session_to_user_info(&session))
+        let user: serde_json::Value = self
+            .read_shared(
+                OperationClass::Read,
+                "getting current user",
+                "api/myself",
+                || self.backend.get_current_user(),
+                JiraMcpError::from,
+            )
+            .await?;
+        user_info_from_json(&user, self.deployment_type())
+            .ok_or_else(|| JiraMcpError::internal("Invalid response from /myself"))
     }
 
     /// Search for issues using JQL
+    ///
+    /// JIRA Cloud is searched through the enhanced /search/jql endpoint, which pages
+    /// with tokens and reports no total; Server and Data Center (and Cloud instances
+    /// without the endpoint) use the legacy /search.
     #[instrument(skip(self))]
     pub async fn search_issues_jql(
         &self,
@@ -228,6 +1196,83 @@ impl JiraClient {
             jql, start, max
         );
 
+        let result = if self.deployment_type().is_cloud() {
+            match self
+                .search_enhanced(jql, start, max, expand.as_deref())
+                .await
+            {
+                Err(e) if e.category() == "not_found" => {
+                    debug!("Enhanced search not available, falling back to /search");
+                    self.search_legacy(jql, start, max, expand).await?
+                }
+                result => result?.0,
+            }
+        } else {
+            self.search_legacy(jql, start, max, expand).await?
+        };
+
+        info!(
+            "Found {} issues (showing {}-{} of {})",
+            result.issues.len(),
+            result.start_at,
+            result.start_at + result.issues.len(),
+            result
+                .total
+                .map_or_else(|| "unknown".to_string(), |total| total.to_string())
+        );
+
+        Ok(result)
+    }
+
+    /// Fetch every issue matching a JQL query, up to `cap` issues
+    ///
+    /// Pages through the results, following page tokens on the enhanced search
+    /// instead of re-walking them for each offset. Pass `usize::MAX` for no cap.
+    #[instrument(skip(self))]
+    pub async fn search_all_issues_jql(&self, jql: &str, cap: usize) -> JiraMcpResult<PagedIssues> {
+        if self.deployment_type().is_cloud() {
+            match self.search_enhanced(jql, 0, cap, None).await {
+                Err(e) if e.category() == "not_found" => {
+                    debug!("Enhanced search not available, falling back to /search");
+                }
+                result => {
+                    let (result, pages) = result?;
+                    return Ok(PagedIssues {
+                        issues: result.issues,
+                        pages,
+                        complete: result.is_last,
+                    });
+                }
+            }
+        }
+
+        let mut issues = Vec::new();
+        let mut pages = 1;
+        let mut page = self.search_legacy(jql, 0, cap.min(200), None).await?;
+        loop {
+            let done = page.is_last || page.issues.is_empty();
+            issues.append(&mut page.issues);
+            if done || issues.len() >= cap {
+                return Ok(PagedIssues {
+                    issues,
+                    pages,
+                    complete: done,
+                });
+            }
+            let max = (cap - issues.len()).min(200);
+            page = self.search_legacy(jql, issues.len(), max, None).await?;
+            pages += 1;
+        }
+    }
+
+    /// One page of the legacy /search endpoint
+    async fn search_legacy(
+        &self,
+        jql: &str,
+        start: usize,
+        max: usize,
+        expand: Option<Vec<String>>,
+    ) -> JiraMcpResult<SearchResult> {
         let mut search_options = SearchOptions::builder()
             .start_at(start as u64)
             .max_results(max as u64)
@@ -242,14 +1287,15 @@ impl JiraClient {
                 .build();
         }
 
-        let timeout_duration = Duration::from_secs(self.config.request_timeout_seconds);
-
-        let search_result = timeout(timeout_duration, async {
-            self.client.search().list(jql, &search_options).await
-        })
-        .await
-        .map_err(|_| JiraMcpError::network("Timeout during search"))?
-        .map_err(JiraMcpError::from)?;
+        let search_result = self
+            .get_with_retry(
+                OperationClass::Search,
+                "during search",
+                &format!("api/search?jql={}", jql),
+                || self.backend.search(jql, &search_options),
+                JiraMcpError::from,
+            )
+            .await?;
 
         // Convert to our format
         let issues: Vec<IssueInfo> = search_result
@@ -258,23 +1304,896 @@ impl JiraClient {
             .map(|issue| self.convert_issue_info(issue))
             .collect();
 
-        let result = SearchResult {
+        // JIRA may return fewer issues than requested (its own maxResults cap), so
+        // the last page is where the returned issues reach the total
+        let total = search_result.total as usize;
+        Ok(SearchResult {
+            is_last: search_result.start_at as usize + issues.len() >= total || issues.is_empty(),
             issues,
-            total: search_result.total as usize,
+            total: Some(total),
             start_at: search_result.start_at as usize,
             max_results: search_result.max_results as usize,
-            is_last: (start + max) >= (search_result.total as usize),
+        })
+    }
+
+    /// Issues `start..start + max` from the enhanced /search/jql endpoint (JIRA Cloud)
+    ///
+    /// The endpoint has no startAt and reports no total: an offset is reached by
+    /// following nextPageToken through the pages before it. Also returns the number
+    /// of pages requested.
+    async fn search_enhanced(
+        &self,
+        jql: &str,
+        start: usize,
+        max: usize,
+        expand: Option<&[String]>,
+    ) -> JiraMcpResult<(SearchResult, usize)> {
+        let wanted = start.saturating_add(max);
+        let mut fetched: Vec<Issue> = Vec::new();
+        let mut next_page_token: Option<String> = None;
+        let mut reached_end = false;
+        let mut pages = 0;
+
+        while fetched.len() < wanted {
+            pages += 1;
+            let page = JqlPage {
+                max_results: (wanted - fetched.len()).min(ENHANCED_SEARCH_PAGE_SIZE),
+                expand: expand.map(<[String]>::to_vec).unwrap_or_default(),
+                next_page_token: next_page_token.take(),
+            };
+
+            let response: serde_json::Value = self
+                .read_shared(
+                    OperationClass::Search,
+                    "during search",
+                    &format!("api{}", page.endpoint(jql)),
+                    || self.backend.search_jql(jql, &page),
+                    JiraMcpError::from,
+                )
+                .await?;
+
+            let page: Vec<Issue> =
+                serde_json::from_value(response["issues"].clone()).map_err(|e| {
+                    JiraMcpError::internal(format!("Unexpected search response: {}", e))
+                })?;
+            next_page_token = response["nextPageToken"].as_str().map(String::from);
+
+            let page_empty = page.is_empty();
+            fetched.extend(page);
+            if page_empty
+                || next_page_token.is_none()
+                || response["isLast"].as_bool().unwrap_or(false)
+            {
+                reached_end = true;
+                break;
+            }
+        }
+
+        let issues: Vec<IssueInfo> = fetched
+            .iter()
+            .skip(start)
+            .take(max)
+            .map(|issue| self.convert_issue_info(issue))
+            .collect();
+
+        let result = SearchResult {
+            issues,
+            total: None,
+            start_at: start,
+            max_results: max,
+            is_last: reached_end,
         };
+        Ok((result, pages))
+    }
 
-        info!(
-            "Found {} issues (showing {}-{} of {})",
-            result.issues.len(),
-            result.start_at,
-            result.start_at + result.issues.len(),
-            result.total
+    /// Fetch the raw values of selected fields of an issue
+    ///
+    /// Fields that are empty or unknown are absent from the returned map.
+    #[instrument(skip(self))]
+    pub async fn get_issue_fields(
+        &self,
+        issue_key: &str,
+        field_ids: &[String],
+    ) -> JiraMcpResult<serde_json::Map<String, serde_json::Value>> {
+        let query = IssueQuery {
+            fields: field_ids.to_vec(),
+            ..IssueQuery::default()
+        };
+
+        let response: serde_json::Value = self
+            .read_shared(
+                OperationClass::Read,
+                "fetching issue fields",
+                &format!("api{}", query.endpoint(issue_key)),
+                || self.backend.get_issue(issue_key, &query),
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("issue", issue_key)
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        Ok(response["fields"].as_object().cloned().unwrap_or_default())
+    }
+
+    /// Fields on the issue's edit screen, keyed by field ID
+    ///
+    /// Each entry carries JIRA's `name`, `schema`, `operations` and, for option
+    /// fields, `allowedValues`. Fields the caller cannot edit are absent.
+    #[instrument(skip(self))]
+    pub async fn get_edit_meta(
+        &self,
+        issue_key: &str,
+    ) -> JiraMcpResult<serde_json::Map<String, serde_json::Value>> {
+        let endpoint = format!("/issue/{}/editmeta", issue_key);
+
+        let response: serde_json::Value = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching edit metadata",
+                "api",
+                &endpoint,
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("issue", issue_key)
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        Ok(response["fields"].as_object().cloned().unwrap_or_default())
+    }
+
+    /// Count issues matching a JQL query without fetching issue bodies
+    #[instrument(skip(self))]
+    pub async fn count_issues_jql(&self, jql: &str) -> JiraMcpResult<usize> {
+        debug!("Counting issues with JQL: '{}'", jql);
+
+        // The enhanced search reports no total; JIRA Cloud counts separately
+        if self.deployment_type().is_cloud() {
+            let payload_bytes = Some(serde_json::json!({ "jql": jql }).to_string().len());
+            let started = Instant::now();
+            let result: JiraMcpResult<serde_json::Value> = self
+                .with_timeout(
+                    OperationClass::Search,
+                    "during issue count",
+                    self.backend.count_issues(jql),
+                )
+                .await
+                .and_then(|response| response.map_err(JiraMcpError::from));
+            self.log_request(
+                "POST",
+                "api/search/approximate-count",
+                payload_bytes,
+                started,
+                &result,
+            );
+
+            match result {
+                Ok(response) => {
+                    if let Some(count) = response["count"].as_u64() {
+                        return Ok(count as usize);
+                    }
+                    debug!("Unexpected approximate count response, falling back to /search");
+                }
+                Err(e) if e.category() == "not_found" => {
+                    debug!("Approximate count not available, falling back to /search");
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        // maxResults=0 makes JIRA return only the total
+        let search_options = SearchOptions::builder().start_at(0).max_results(0).build();
+
+        let search_result = self
+            .get_with_retry(
+                OperationClass::Search,
+                "during issue count",
+                &format!("api/search?jql={}", jql),
+                || self.backend.search(jql, &search_options),
+                JiraMcpError::from,
+            )
+            .await?;
+
+        Ok(search_result.total as usize)
+    }
+
+    /// Get an agile board by ID
+    #[instrument(skip(self))]
+    pub async fn get_board(&self, board_id: u64) -> JiraMcpResult<BoardInfo> {
+        let endpoint = format!("/board/{}", board_id);
+
+        let response: serde_json::Value = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching board",
+                "agile",
+                &endpoint,
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("board", board_id.to_string())
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        BoardInfo::from_json(&response)
+            .ok_or_else(|| JiraMcpError::internal("Unexpected board response"))
+    }
+
+    /// First agile board located in a project
+    #[instrument(skip(self))]
+    pub async fn find_project_board(&self, project_key: &str) -> JiraMcpResult<BoardInfo> {
+        let endpoint = format!("/board?projectKeyOrId={}&maxResults=1", project_key);
+
+        let response: serde_json::Value = self
+            .get_shared(
+                OperationClass::Read,
+                "looking up project board",
+                "agile",
+                &endpoint,
+                JiraMcpError::from,
+            )
+            .await?;
+
+        response["values"]
+            .as_array()
+            .and_then(|boards| boards.first())
+            .and_then(BoardInfo::from_json)
+            .ok_or_else(|| JiraMcpError::not_found("board for project", project_key))
+    }
+
+    /// A sprint as returned by the Agile API, including its goal
+    #[instrument(skip(self))]
+    pub async fn get_sprint_json(&self, sprint_id: u64) -> JiraMcpResult<serde_json::Value> {
+        let endpoint = format!("/sprint/{}", sprint_id);
+
+        self.get_shared(
+            OperationClass::Read,
+            "fetching sprint",
+            "agile",
+            &endpoint,
+            |e| {
+                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                    JiraMcpError::not_found("sprint", sprint_id.to_string())
+                } else {
+                    JiraMcpError::from(e)
+                }
+            },
+        )
+        .await
+    }
+
+    /// A sprint as gouqi reads it, which leaves out the goal
+    pub async fn get_sprint(&self, sprint_id: u64) -> JiraMcpResult<Sprint> {
+        let sprint = self.get_sprint_json(sprint_id).await?;
+        serde_json::from_value(sprint)
+            .map_err(|e| JiraMcpError::internal(format!("Unexpected sprint response: {}", e)))
+    }
+
+    /// One page of the sprints of a board
+    #[instrument(skip(self))]
+    pub async fn list_sprints(
+        &self,
+        board_id: u64,
+        start_at: u64,
+        max_results: u64,
+    ) -> JiraMcpResult<SprintResults> {
+        let endpoint = format!(
+            "/board/{}/sprint?startAt={}&maxResults={}",
+            board_id, start_at, max_results
         );
 
-        Ok(result)
+        self.get_shared(
+            OperationClass::Read,
+            "listing sprints",
+            "agile",
+            &endpoint,
+            |e| {
+                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                    JiraMcpError::not_found("board", board_id.to_string())
+                } else {
+                    JiraMcpError::from(e)
+                }
+            },
+        )
+        .await
+    }
+
+    /// Create a future sprint on a board
+    #[instrument(skip(self))]
+    pub async fn create_sprint(&self, board_id: u64, name: &str) -> JiraMcpResult<Sprint> {
+        self.post_json(
+            &format!("creating sprint '{}'", name),
+            "agile",
+            "/sprint",
+            serde_json::json!({ "name": name, "originBoardId": board_id }),
+            |e| {
+                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                    JiraMcpError::not_found("board", board_id.to_string())
+                } else {
+                    JiraMcpError::from(e)
+                }
+            },
+        )
+        .await
+    }
+
+    /// Move issues into a sprint; JIRA takes at most 50 per request
+    #[instrument(skip(self))]
+    pub async fn move_issues_to_sprint(
+        &self,
+        sprint_id: u64,
+        issue_keys: &[String],
+    ) -> JiraMcpResult<()> {
+        self.post_json::<serde_json::Value>(
+            &format!("moving issues to sprint {}", sprint_id),
+            "agile",
+            &format!("/sprint/{}/issue", sprint_id),
+            serde_json::json!({ "issues": issue_keys }),
+            |e| {
+                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                    JiraMcpError::not_found("sprint", sprint_id.to_string())
+                } else {
+                    JiraMcpError::from(e)
+                }
+            },
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Apply a partial update to a sprint and return the sprint as stored
+    ///
+    /// gouqi's `UpdateSprint` has no goal, so the update is posted directly. When
+    /// JIRA answers without a body, the sprint is read back.
+    #[instrument(skip(self))]
+    pub async fn update_sprint(
+        &self,
+        sprint_id: u64,
+        update: &SprintUpdate,
+    ) -> JiraMcpResult<serde_json::Value> {
+        let endpoint = format!("/sprint/{}", sprint_id);
+        let body = serde_json::to_value(update)?;
+        let payload_bytes = Some(body.to_string().len());
+
+        let started = Instant::now();
+        let result = self
+            .with_timeout(
+                OperationClass::Write,
+                &format!("updating sprint {}", sprint_id),
+                self.backend.post("agile", &endpoint, body),
+            )
+            .await
+            .and_then(|response| {
+                response.or_else(|e| {
+                    let message = e.to_string();
+                    if message.contains("expected value") {
+                        debug!("Ignoring deserialization error (likely 204 No Content response)");
+                        Ok(serde_json::Value::Null)
+                    } else if message.contains("404") || message.contains("Not Found") {
+                        Err(JiraMcpError::not_found("sprint", sprint_id.to_string()))
+                    } else if message.contains("400") {
+                        Err(JiraMcpError::invalid_param(
+                            "sprint",
+                            format!("JIRA rejected the sprint update: {}", message),
+                        ))
+                    } else {
+                        Err(JiraMcpError::from(e))
+                    }
+                })
+            });
+        self.log_request(
+            "POST",
+            &format!("agile{}", endpoint),
+            payload_bytes,
+            started,
+            &result,
+        );
+
+        match result? {
+            sprint if sprint.get("id").is_some() => Ok(sprint),
+            _ => self.get_sprint_json(sprint_id).await,
+        }
+    }
+
+    /// Agile board by name, preferring an exact (case-insensitive) match over
+    /// the first board whose name contains `name`
+    #[instrument(skip(self))]
+    pub async fn find_board_by_name(&self, name: &str) -> JiraMcpResult<BoardInfo> {
+        let endpoint = format!("/board?name={}&maxResults=50", query_escape(name));
+
+        let response: serde_json::Value = self
+            .get_shared(
+                OperationClass::Read,
+                "looking up board",
+                "agile",
+                &endpoint,
+                JiraMcpError::from,
+            )
+            .await?;
+
+        let boards: Vec<BoardInfo> = response["values"]
+            .as_array()
+            .map(|boards| boards.iter().filter_map(BoardInfo::from_json).collect())
+            .unwrap_or_default();
+        let exact = boards
+            .iter()
+            .position(|board| board.name.eq_ignore_ascii_case(name.trim()));
+
+        match exact {
+            Some(index) => Ok(boards[index].clone()),
+            None => boards
+                .into_iter()
+                .next()
+                .ok_or_else(|| JiraMcpError::not_found("board", name)),
+        }
+    }
+
+    /// ID of the saved filter that defines a board's issues
+    #[instrument(skip(self))]
+    pub async fn get_board_filter_id(&self, board_id: u64) -> JiraMcpResult<String> {
+        self.get_board_configuration(board_id)
+            .await?
+            .filter_id
+            .ok_or_else(|| JiraMcpError::internal("Board configuration has no filter ID"))
+    }
+
+    /// Columns, estimation and saved filter of a board
+    ///
+    /// The board reports column statuses by ID only, and the filter by ID;
+    /// status names and the filter JQL are left empty for the caller to fill in.
+    #[instrument(skip(self))]
+    pub async fn get_board_configuration(
+        &self,
+        board_id: u64,
+    ) -> JiraMcpResult<BoardConfiguration> {
+        let endpoint = format!("/board/{}/configuration", board_id);
+
+        let response: serde_json::Value = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching board configuration",
+                "agile",
+                &endpoint,
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("board", board_id.to_string())
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        Ok(board_configuration_from_json(board_id, &response))
+    }
+
+    /// JQL of a saved filter
+    #[instrument(skip(self))]
+    pub async fn get_filter_jql(&self, filter_id: &str) -> JiraMcpResult<String> {
+        let endpoint = format!("/filter/{}", filter_id);
+
+        let response: serde_json::Value = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching filter",
+                "api",
+                &endpoint,
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("filter", filter_id)
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        response["jql"]
+            .as_str()
+            .map(String::from)
+            .ok_or_else(|| JiraMcpError::internal("Filter response has no JQL"))
+    }
+
+    /// Issues in a board's backlog, in rank order
+    #[instrument(skip(self))]
+    pub async fn get_board_backlog(
+        &self,
+        board_id: u64,
+        max_results: usize,
+    ) -> JiraMcpResult<SearchResult> {
+        let max = max_results.min(200);
+        let endpoint = format!("/board/{}/backlog?maxResults={}", board_id, max);
+
+        let response: serde_json::Value = self
+            .get_shared(
+                OperationClass::Search,
+                "fetching board backlog",
+                "agile",
+                &endpoint,
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("board", board_id.to_string())
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        let issues: Vec<Issue> = serde_json::from_value(response["issues"].clone())
+            .map_err(|e| JiraMcpError::internal(format!("Unexpected backlog response: {}", e)))?;
+        let issues: Vec<IssueInfo> = issues
+            .iter()
+            .map(|issue| self.convert_issue_info(issue))
+            .collect();
+        let total = response["total"]
+            .as_u64()
+            .map_or(issues.len(), |t| t as usize);
+
+        Ok(SearchResult {
+            is_last: issues.len() >= total,
+            issues,
+            total: Some(total),
+            start_at: 0,
+            max_results: max,
+        })
+    }
+
+    /// Whether the current user holds each of `permissions` (keys such as
+    /// "ADD_COMMENTS"), on `issue_key`, in `project_key` or, without either, in
+    /// any project
+    ///
+    /// Permissions JIRA does not report are left out of the map.
+    #[instrument(skip(self))]
+    pub async fn get_my_permissions(
+        &self,
+        project_key: Option<&str>,
+        issue_key: Option<&str>,
+        permissions: &[&str],
+    ) -> JiraMcpResult<BTreeMap<String, bool>> {
+        let mut endpoint = format!("/mypermissions?permissions={}", permissions.join(","));
+        if let Some(issue_key) = issue_key {
+            endpoint.push_str(&format!("&issueKey={}", query_escape(issue_key)));
+        } else if let Some(project_key) = project_key {
+            endpoint.push_str(&format!("&projectKey={}", query_escape(project_key)));
+        }
+
+        let response: serde_json::Value = self
+            .get_shared(
+                OperationClass::Read,
+                "checking permissions",
+                "api",
+                &endpoint,
+                JiraMcpError::from,
+            )
+            .await?;
+
+        Ok(response["permissions"]
+            .as_object()
+            .map(|granted| {
+                granted
+                    .iter()
+                    .filter_map(|(key, permission)| {
+                        Some((key.clone(), permission["havePermission"].as_bool()?))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    /// List the priorities defined on the instance, highest first
+    #[instrument(skip(self))]
+    pub async fn get_priorities(&self) -> JiraMcpResult<Vec<PriorityInfo>> {
+        let response: Vec<serde_json::Value> = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching priorities",
+                "api",
+                "/priority",
+                JiraMcpError::from,
+            )
+            .await?;
+
+        Ok(response
+            .iter()
+            .filter_map(|priority| {
+                Some(PriorityInfo {
+                    id: priority["id"].as_str()?.to_string(),
+                    name: priority["name"].as_str()?.to_string(),
+                    description: priority["description"]
+                        .as_str()
+                        .filter(|d| !d.is_empty())
+                        .map(String::from),
+                })
+            })
+            .collect())
+    }
+
+    /// List the versions of a project, archived ones included
+    #[instrument(skip(self))]
+    pub async fn get_project_versions(
+        &self,
+        project_key: &str,
+    ) -> JiraMcpResult<Vec<ProjectVersion>> {
+        let endpoint = format!("/project/{}/versions", project_key);
+        let response: Vec<serde_json::Value> = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching project versions",
+                "api",
+                &endpoint,
+                |e| {
+                    if e.to_string().contains("404") {
+                        JiraMcpError::not_found("project", project_key)
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        Ok(response
+            .iter()
+            .filter_map(|version| {
+                Some(ProjectVersion {
+                    id: version["id"].as_str()?.to_string(),
+                    name: version["name"].as_str()?.to_string(),
+                    released: version["released"].as_bool().unwrap_or(false),
+                    archived: version["archived"].as_bool().unwrap_or(false),
+                })
+            })
+            .collect())
+    }
+
+    /// List the issue security levels of a project
+    ///
+    /// Only the levels the current user may set are returned, so an empty list
+    /// can also mean the "Set Issue Security" permission is missing.
+    #[instrument(skip(self))]
+    pub async fn get_security_levels(
+        &self,
+        project_key: &str,
+    ) -> JiraMcpResult<Vec<SecurityLevelInfo>> {
+        let endpoint = format!("/project/{}/securitylevel", project_key);
+        let response: serde_json::Value = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching security levels",
+                "api",
+                &endpoint,
+                |e| {
+                    if e.to_string().contains("404") {
+                        JiraMcpError::not_found("project", project_key)
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        Ok(response["levels"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|level| {
+                Some(SecurityLevelInfo {
+                    id: level["id"].as_str()?.to_string(),
+                    name: level["name"].as_str()?.to_string(),
+                    description: level["description"]
+                        .as_str()
+                        .filter(|d| !d.is_empty())
+                        .map(String::from),
+                })
+            })
+            .collect())
+    }
+
+    /// List statuses with their categories, for one project or the whole instance
+    ///
+    /// Project statuses come grouped by issue type and are merged here.
+    #[instrument(skip(self))]
+    pub async fn get_statuses(&self, project_key: Option<&str>) -> JiraMcpResult<Vec<StatusInfo>> {
+        let endpoint = match project_key {
+            Some(key) => format!("/project/{}/statuses", key),
+            None => "/status".to_string(),
+        };
+
+        let response: Vec<serde_json::Value> = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching statuses",
+                "api",
+                &endpoint,
+                |e| match project_key {
+                    Some(key) if e.to_string().contains("404") => {
+                        JiraMcpError::not_found("project", key)
+                    }
+                    _ => JiraMcpError::from(e),
+                },
+            )
+            .await?;
+
+        let raw_statuses: Vec<&serde_json::Value> = match project_key {
+            Some(_) => response
+                .iter()
+                .filter_map(|issue_type| issue_type["statuses"].as_array())
+                .flatten()
+                .collect(),
+            None => response.iter().collect(),
+        };
+
+        let mut statuses: Vec<StatusInfo> = Vec::new();
+        for status in raw_statuses {
+            let (Some(id), Some(name)) = (status["id"].as_str(), status["name"].as_str()) else {
+                continue;
+            };
+            if statuses.iter().any(|s| s.id == id) {
+                continue;
+            }
+            statuses.push(StatusInfo {
+                id: id.to_string(),
+                name: name.to_string(),
+                category_key: status["statusCategory"]["key"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+                category_name: status["statusCategory"]["name"]
+                    .as_str()
+                    .unwrap_or_default()
+                    .to_string(),
+            });
+        }
+
+        Ok(statuses)
+    }
+
+    /// List the issue types available in a project, or all issue types for `None`
+    ///
+    /// Project types come from create metadata, so team-managed projects get
+    /// their own type set.
+    #[instrument(skip(self))]
+    pub async fn get_issue_types(
+        &self,
+        project_key: Option<&str>,
+    ) -> JiraMcpResult<Vec<IssueTypeInfo>> {
+        let endpoint = match project_key {
+            Some(key) => format!("/issue/createmeta?projectKeys={}", key),
+            None => "/issuetype".to_string(),
+        };
+
+        let response: serde_json::Value = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching issue types",
+                "api",
+                &endpoint,
+                JiraMcpError::from,
+            )
+            .await?;
+
+        let raw_types = match project_key {
+            Some(key) => response["projects"]
+                .get(0)
+                .ok_or_else(|| JiraMcpError::not_found("project", key))?["issuetypes"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default(),
+            None => response.as_array().cloned().unwrap_or_default(),
+        };
+
+        Ok(raw_types
+            .iter()
+            .filter_map(|issue_type| {
+                Some(IssueTypeInfo {
+                    id: issue_type["id"].as_str()?.to_string(),
+                    name: issue_type["name"].as_str()?.to_string(),
+                    description: issue_type["description"]
+                        .as_str()
+                        .filter(|d| !d.is_empty())
+                        .map(String::from),
+                    subtask: issue_type["subtask"].as_bool().unwrap_or(false),
+                    untranslated_name: issue_type["untranslatedName"].as_str().map(String::from),
+                    hierarchy_level: issue_type["hierarchyLevel"].as_i64(),
+                })
+            })
+            .collect())
+    }
+
+    /// List the projects visible to the current user
+    #[instrument(skip(self))]
+    pub async fn get_projects(&self) -> JiraMcpResult<Vec<ProjectInfo>> {
+        let response: Vec<serde_json::Value> = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching projects",
+                "api",
+                "/project",
+                JiraMcpError::from,
+            )
+            .await?;
+
+        Ok(response
+            .iter()
+            .filter_map(|project| {
+                Some(ProjectInfo {
+                    key: project["key"].as_str()?.to_string(),
+                    name: project["name"].as_str()?.to_string(),
+                    project_type: project["projectTypeKey"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                    lead: project["lead"]["displayName"].as_str().map(String::from),
+                    team_managed: project["style"].as_str() == Some("next-gen")
+                        || project["simplified"].as_bool() == Some(true),
+                })
+            })
+            .collect())
+    }
+
+    /// List the issue link types defined on the instance
+    #[instrument(skip(self))]
+    pub async fn get_issue_link_types(&self) -> JiraMcpResult<Vec<IssueLinkTypeInfo>> {
+        // gouqi doesn't have a dedicated method for this, so we use the raw client
+        let response: serde_json::Value = self
+            .get_shared(
+                OperationClass::Read,
+                "fetching issue link types",
+                "api",
+                "/issueLinkType",
+                |e| JiraMcpError::internal(format!("Failed to get issue link types: {}", e)),
+            )
+            .await?;
+
+        let link_types_array = response
+            .get("issueLinkTypes")
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| {
+                JiraMcpError::internal("Invalid response format from JIRA API".to_string())
+            })?;
+
+        Ok(link_types_array
+            .iter()
+            .filter_map(|lt| {
+                Some(IssueLinkTypeInfo {
+                    id: lt.get("id")?.as_str()?.to_string(),
+                    name: lt.get("name")?.as_str()?.to_string(),
+                    inward: lt.get("inward")?.as_str()?.to_string(),
+                    outward: lt.get("outward")?.as_str()?.to_string(),
+                    self_link: lt.get("self")?.as_str()?.to_string(),
+                })
+            })
+            .collect())
+    }
+
+    /// An issue with all its fields as JIRA returns them, for reading fields
+    /// [`IssueInfo`] leaves out
+    #[instrument(skip(self))]
+    pub async fn get_raw_issue(&self, issue_key: &str) -> JiraMcpResult<Issue> {
+        let query = IssueQuery::default();
+        self.read_shared(
+            OperationClass::Read,
+            &format!("getting issue {}", issue_key),
+            &format!("api{}", query.endpoint(issue_key)),
+            || self.backend.get_issue(issue_key, &query),
+            |e| {
+                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                    JiraMcpError::not_found("issue", issue_key)
+                } else {
+                    JiraMcpError::from(e)
+                }
+            },
+        )
+        .await
     }
 
     /// Get detailed issue information
@@ -288,95 +2207,115 @@ impl JiraClient {
     ) -> JiraMcpResult<IssueDetails> {
         debug!("Fetching issue details for: {}", issue_key);
 
-        let timeout_duration = Duration::from_secs(self.config.request_timeout_seconds);
+        let map_issue_error = |e: gouqi::Error| {
+            if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                JiraMcpError::not_found("issue", issue_key)
+            } else {
+                JiraMcpError::from(e)
+            }
+        };
+        let what = format!("getting issue {}", issue_key);
 
         // Build expand parameters
-        let mut expand_fields = Vec::new();
+        let mut query = IssueQuery::default();
         if include_comments {
-            expand_fields.push("comment");
+            query.expand.push("comment".to_string());
         }
         if include_history {
-            expand_fields.push("changelog");
+            query.expand.push("changelog".to_string());
         }
 
-        // Get issue with expand parameters
-        let issue = if !expand_fields.is_empty() {
-            let expand_param = expand_fields.join(",");
-            let endpoint = format!("/issue/{}?expand={}", issue_key, expand_param);
+        let issue = self
+            .read_shared(
+                OperationClass::Read,
+                &what,
+                &format!("api{}", query.endpoint(issue_key)),
+                || self.backend.get_issue(issue_key, &query),
+                map_issue_error,
+            )
+            .await?;
+
+        Ok(self.convert_issue_details(
+            &issue,
+            include_comments,
+            include_attachments,
+            include_history,
+        ))
+    }
 
-            timeout(timeout_duration, async {
-                self.client.get("api", &endpoint).await
-            })
-            .await
-            .map_err(|_| JiraMcpError::network(format!("Timeout getting issue {}", issue_key)))?
-            .map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
-                    JiraMcpError::not_found("issue", issue_key)
-                } else {
-                    JiraMcpError::from(e)
-                }
-            })?
+    /// Fetch several issues with a single JQL search
+    ///
+    /// Search results carry no comments, so only attachments and history can be
+    /// included. Keys that don't exist are simply absent; moved issues come back
+    /// under their new key. JIRA may reject the whole query for an unknown key.
+    #[instrument(skip(self))]
+    pub async fn get_issues_details(
+        &self,
+        issue_keys: &[String],
+        include_attachments: bool,
+        include_history: bool,
+    ) -> JiraMcpResult<Vec<IssueDetails>> {
+        debug!("Fetching {} issues by key", issue_keys.len());
+
+        let jql = format!("{} IN ({})", jql::KEY, jql::quote_list(issue_keys));
+        let search_options = if include_history {
+            SearchOptions::builder()
+                .start_at(0)
+                .max_results(issue_keys.len() as u64)
+                .expand(vec!["changelog".to_string()])
+                .build()
         } else {
-            timeout(timeout_duration, async {
-                self.client.issues().get(issue_key).await
-            })
-            .await
-            .map_err(|_| JiraMcpError::network(format!("Timeout getting issue {}", issue_key)))?
-            .map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
-                    JiraMcpError::not_found("issue", issue_key)
-                } else {
-                    JiraMcpError::from(e)
-                }
-            })?
+            SearchOptions::builder()
+                .start_at(0)
+                .max_results(issue_keys.len() as u64)
+                .build()
         };
 
-        let issue_info = self.convert_issue_info(&issue);
+        let search_result = self
+            .get_with_retry(
+                OperationClass::Search,
+                "fetching issues by key",
+                &format!("api/search?jql={}", jql),
+                || self.backend.search(&jql, &search_options),
+                JiraMcpError::from,
+            )
+            .await?;
+
+        Ok(search_result
+            .issues
+            .iter()
+            .map(|issue| {
+                self.convert_issue_details(issue, false, include_attachments, include_history)
+            })
+            .collect())
+    }
+
+    /// Convert a gouqi Issue, fetched with the matching expands, to IssueDetails
+    fn convert_issue_details(
+        &self,
+        issue: &Issue,
+        include_comments: bool,
+        include_attachments: bool,
+        include_history: bool,
+    ) -> IssueDetails {
+        let issue_info = self.convert_issue_info(issue);
 
         // Extract comments from issue if requested
         let comments = if include_comments {
-            // Debug: Log available fields in the issue
-            debug!(
-                "Issue fields available: {:?}",
-                issue.fields.keys().collect::<Vec<_>>()
-            );
-
-            // Debug: Try to access the comment field directly to see what's happening
-            match issue.field::<serde_json::Value>("comment") {
-                Some(Ok(value)) => {
-                    debug!("Comment field exists in response");
-                    debug!(
-                        "Comment field value: {}",
-                        serde_json::to_string_pretty(&value)
-                            .unwrap_or_else(|_| "unable to serialize".to_string())
-                    );
-                }
-                Some(Err(e)) => {
-                    warn!("Comment field deserialization to Value failed: {}", e);
-                }
-                None => {
-                    warn!("Comment field not present in issue response - expand parameter may not have worked");
-                }
-            }
-
-            // Try to deserialize as Comments struct
-            let result = issue.comments();
-            if result.is_none() {
-                warn!("issue.comments() returned None - Comments struct deserialization failed");
-            } else {
-                debug!(
-                    "Successfully deserialized Comments struct with {} comments",
-                    result.as_ref().map(|c| c.comments.len()).unwrap_or(0)
-                );
-            }
-
-            result.map(|comments_obj| {
-                comments_obj
-                    .comments
-                    .iter()
-                    .map(|c| self.convert_comment_info(c))
-                    .collect()
-            })
+            issue
+                .field::<serde_json::Value>("comment")
+                .and_then(|result| result.ok())
+                .map(|comment| {
+                    comment["comments"]
+                        .as_array()
+                        .map(|comments| {
+                            comments
+                                .iter()
+                                .map(|c| self.convert_comment_info(&issue.key, c))
+                                .collect()
+                        })
+                        .unwrap_or_default()
+                })
         } else {
             None
         };
@@ -384,12 +2323,12 @@ impl JiraClient {
         // Extract attachments from issue fields if requested
         let attachments = if include_attachments {
             issue
-                .field::<Vec<gouqi::Attachment>>("attachment")
+                .field::<Vec<serde_json::Value>>("attachment")
                 .and_then(|result| result.ok())
                 .map(|attachments_vec| {
                     attachments_vec
                         .iter()
-                        .map(|a| self.convert_attachment_info(a))
+                        .filter_map(|a| self.convert_attachment_info(a))
                         .collect()
                 })
         } else {
@@ -413,7 +2352,7 @@ impl JiraClient {
         };
 
         // Extract linked issues
-        let linked_issues = self.extract_linked_issues(&issue);
+        let linked_issues = self.extract_linked_issues(issue);
 
         // Extract parent issue if this is a subtask
         let parent = issue
@@ -432,7 +2371,7 @@ impl JiraClient {
             })
             .unwrap_or_default();
 
-        Ok(IssueDetails {
+        IssueDetails {
             issue_info,
             comments,
             attachments,
@@ -440,77 +2379,107 @@ impl JiraClient {
             subtasks,
             parent,
             linked_issues,
-        })
+            worklogs: None,
+            worklog_summary: None,
+        }
     }
 
     /// Convert gouqi Issue to our IssueInfo format
     fn convert_issue_info(&self, issue: &Issue) -> IssueInfo {
-        // Extract story points - common field names: customfield_10016, Story Points, etc.
-        let story_points = issue
-            .field::<f64>("customfield_10016")
-            .and_then(|r| r.ok())
-            .or_else(|| issue.field::<f64>("Story Points").and_then(|r| r.ok()))
-            .or_else(|| {
-                // Try as integer and convert to f64
-                issue
-                    .field::<i64>("customfield_10016")
-                    .and_then(|r| r.ok())
-                    .map(|i| i as f64)
-            });
-
-        // Extract acceptance criteria - common field names
-        let acceptance_criteria = issue
-            .field::<String>("customfield_10100")
-            .and_then(|r| r.ok())
-            .or_else(|| {
-                issue
-                    .field::<String>("Acceptance Criteria")
-                    .and_then(|r| r.ok())
-            })
-            .or_else(|| {
-                issue
-                    .field::<String>("customfield_10007")
-                    .and_then(|r| r.ok())
-            });
+        let story_points = self
+            .field_map
+            .story_points
+            .value_in(&issue.fields)
+            .and_then(serde_json::Value::as_f64);
+
+        // Rich-text fields hold an ADF document on Cloud's v3 API
+        let acceptance_criteria = self
+            .field_map
+            .acceptance_criteria
+            .value_in(&issue.fields)
+            .map(adf::rich_text)
+            .filter(|text| !text.is_empty())
+            .map(|text| self.redactor.redact_owned(text));
+
+        let style = IssueStyle::from_fields(&issue.fields);
+        let raw_text = |field: &str| {
+            issue
+                .fields
+                .get(field)
+                .and_then(serde_json::Value::as_str)
+                .map(String::from)
+        };
 
         IssueInfo {
             key: issue.key.clone(),
             id: issue.id.clone(),
+            url: issue_browse_url(self.base_url(), &issue.key),
             summary: issue.summary().unwrap_or_default(),
-            description: issue.description(),
-            issue_type: issue
-                .issue_type()
-                .map(|it| it.name.clone())
-                .unwrap_or_default(),
-            status: issue.status().map(|s| s.name.clone()).unwrap_or_default(),
-            priority: issue.priority().map(|p| p.name.clone()),
-            assignee: issue.assignee().map(|u| u.display_name.clone()),
-            reporter: issue.reporter().map(|u| u.display_name.clone()),
-            created: issue.created().map(|dt| dt.to_string()).unwrap_or_default(),
-            updated: issue.updated().map(|dt| dt.to_string()).unwrap_or_default(),
-            project_key: issue.project().map(|p| p.key.clone()).unwrap_or_default(),
-            project_name: issue.project().map(|p| p.name.clone()).unwrap_or_default(),
+            description: issue
+                .description()
+                .map(|text| self.redactor.redact_owned(text)),
+            issue_type: field_text(&issue.fields, "issuetype", "name").unwrap_or_default(),
+            status: field_text(&issue.fields, "status", "name").unwrap_or_default(),
+            status_category: style.status_category,
+            status_color: style.status_color,
+            issue_type_icon_url: style.issue_type_icon_url,
+            priority: field_text(&issue.fields, "priority", "name"),
+            assignee: field_text(&issue.fields, "assignee", "displayName"),
+            reporter: field_text(&issue.fields, "reporter", "displayName"),
+            // Kept as JIRA sent them, e.g. "2024-01-15T10:30:00.000+0000"
+            created: raw_text("created").unwrap_or_default(),
+            updated: raw_text("updated").unwrap_or_default(),
+            due_date: issue.field::<String>("duedate").and_then(|r| r.ok()),
+            project_key: field_text(&issue.fields, "project", "key").unwrap_or_default(),
+            project_name: field_text(&issue.fields, "project", "name").unwrap_or_default(),
             labels: issue.labels(),
             components: Vec::new(), // Components would need to be implemented based on gouqi API
             story_points,
             acceptance_criteria,
+            match_snippet: None,
+            match_field: None,
+            time_tracking: issue
+                .field::<serde_json::Value>("timetracking")
+                .and_then(|r| r.ok())
+                .and_then(|tracking| TimeTracking::from_json(&tracking)),
+            security_level: issue
+                .field::<serde_json::Value>("security")
+                .and_then(|r| r.ok())
+                .and_then(|security| security["name"].as_str().map(String::from)),
+            resolution: field_text(&issue.fields, "resolution", "name"),
+            comments: issue
+                .fields
+                .get("comment")
+                .and_then(|comment| comment["comments"].as_array())
+                .map(|comments| {
+                    comments
+                        .iter()
+                        .map(|comment| adf::rich_text(&comment["body"]))
+                        .filter(|text| !text.is_empty())
+                        .map(|text| self.redactor.redact_owned(text))
+                        .collect()
+                })
+                .unwrap_or_default(),
         }
     }
 
-    /// Convert gouqi Session to our UserInfo format
-    fn convert_session_to_user_info(&self, session: &Session) -> UserInfo {
-        UserInfo {
-            account_id: session.name.clone(),
-            display_name: session.name.clone(),
-            email_address: None,
-            active: true,
-        }
-    }
+    /// Convert a comment resource to our CommentInfo format
+    ///
+    /// Works on the raw JSON since Cloud's v3 API returns the body as an ADF
+    /// document, which is rendered to markdown.
+    fn convert_comment_info(&self, issue_key: &str, comment: &serde_json::Value) -> CommentInfo {
+        let text = |value: &serde_json::Value| value.as_str().map(String::from);
+        let id = match &comment["id"] {
+            serde_json::Value::Number(id) => Some(id.to_string()),
+            id => text(id),
+        };
+        let url = match &id {
+            Some(id) => comment_permalink(self.base_url(), issue_key, id),
+            None => issue_browse_url(self.base_url(), issue_key),
+        };
 
-    /// Convert gouqi Comment to our CommentInfo format
-    fn convert_comment_info(&self, comment: &Comment) -> CommentInfo {
         CommentInfo {
-            id: comment.id.clone().unwrap_or_else(|| {
+            id: id.unwrap_or_else(|| {
                 format!(
                     "comment-{}",
                     SystemTime::now()
@@ -519,35 +2488,54 @@ impl JiraClient {
                         .as_secs()
                 )
             }),
-            author: comment
-                .author
-                .as_ref()
-                .map(|u| u.display_name.clone())
+            url,
+            author: text(&comment["author"]["displayName"])
                 .unwrap_or_else(|| "Unknown".to_string()),
-            body: comment.body.to_string(),
-            created: comment
-                .created
-                .as_ref()
-                .map(|dt| dt.to_string())
+            body: self.redactor.redact_owned(adf::rich_text(&comment["body"])),
+            created: text(&comment["created"])
                 .unwrap_or_else(|| "1970-01-01T00:00:00.000Z".to_string()),
-            updated: comment
-                .updated
-                .as_ref()
-                .map(|dt| dt.to_string())
+            updated: text(&comment["updated"])
                 .unwrap_or_else(|| "1970-01-01T00:00:00.000Z".to_string()),
+            visibility: comment.get("visibility").and_then(|v| {
+                let visibility_type = match text(&v["type"])?.to_lowercase().as_str() {
+                    "role" => VisibilityType::Role,
+                    "group" => VisibilityType::Group,
+                    _ => return None,
+                };
+                Some(CommentVisibility {
+                    visibility_type,
+                    value: text(&v["value"])?,
+                })
+            }),
+            jsm_internal: comment_jsm_internal(comment),
         }
     }
 
-    /// Convert gouqi Attachment to our AttachmentInfo format
-    fn convert_attachment_info(&self, attachment: &gouqi::Attachment) -> AttachmentInfo {
-        AttachmentInfo {
-            id: attachment.id.clone(),
-            filename: attachment.filename.clone(),
-            author: attachment.author.display_name.clone(),
-            created: attachment.created.clone(),
-            size: attachment.size,
-            mime_type: attachment.mime_type.clone(),
-        }
+    /// Convert an attachment resource to our AttachmentInfo format
+    ///
+    /// Works on the raw JSON to keep the content and thumbnail links and the
+    /// author's account ID.
+    fn convert_attachment_info(&self, attachment: &serde_json::Value) -> Option<AttachmentInfo> {
+        let text = |value: &serde_json::Value| value.as_str().map(String::from);
+        let id = match &attachment["id"] {
+            serde_json::Value::Number(id) => id.to_string(),
+            id => text(id)?,
+        };
+        let filename = text(&attachment["filename"]).unwrap_or_default();
+        let author = &attachment["author"];
+
+        Some(AttachmentInfo {
+            content_url: text(&attachment["content"])
+                .unwrap_or_else(|| attachment_content_url(self.base_url(), &id, &filename)),
+            thumbnail_url: text(&attachment["thumbnail"]),
+            author: text(&author["displayName"]).unwrap_or_default(),
+            author_account_id: text(&author["accountId"]).or_else(|| text(&author["name"])),
+            created: text(&attachment["created"]).unwrap_or_default(),
+            size: attachment["size"].as_u64().unwrap_or(0),
+            mime_type: text(&attachment["mimeType"]).unwrap_or_default(),
+            filename,
+            id,
+        })
     }
 
     /// Convert gouqi History to our HistoryEntry format
@@ -559,7 +2547,7 @@ impl JiraClient {
             history.author.name.as_deref().unwrap_or("unknown")
         );
 
-        HistoryEntry {
+        self.redact_history(HistoryEntry {
             id,
             author: history.author.display_name.clone(),
             created: history.created.clone(),
@@ -575,7 +2563,17 @@ impl JiraClient {
                     to_string: item.to_string.clone(),
                 })
                 .collect(),
+        })
+    }
+
+    /// Redact the old and new values of a history entry
+    fn redact_history(&self, mut entry: HistoryEntry) -> HistoryEntry {
+        for item in &mut entry.items {
+            for value in [&mut item.from_string, &mut item.to_string] {
+                *value = value.take().map(|text| self.redactor.redact_owned(text));
+            }
         }
+        entry
     }
 
     /// Extract linked issues from an Issue
@@ -592,23 +2590,21 @@ impl JiraClient {
                             Some(LinkedIssue {
                                 key: outward_issue.key.clone(),
                                 summary: outward_issue.summary().unwrap_or_default(),
-                                status: outward_issue
-                                    .status()
-                                    .map(|s| s.name.clone())
+                                status: field_text(&outward_issue.fields, "status", "name")
                                     .unwrap_or_else(|| "Unknown".to_string()),
                                 link_type: link.link_type.outward.clone(),
                                 direction: "outward".to_string(),
+                                details: None,
                             })
                         } else {
                             link.inward_issue.as_ref().map(|inward_issue| LinkedIssue {
                                 key: inward_issue.key.clone(),
                                 summary: inward_issue.summary().unwrap_or_default(),
-                                status: inward_issue
-                                    .status()
-                                    .map(|s| s.name.clone())
+                                status: field_text(&inward_issue.fields, "status", "name")
                                     .unwrap_or_else(|| "Unknown".to_string()),
                                 link_type: link.link_type.inward.clone(),
                                 direction: "inward".to_string(),
+                                details: None,
                             })
                         }
                     })
@@ -618,63 +2614,335 @@ impl JiraClient {
     }
 
     /// Get user information by username or account ID
+    ///
+    /// Cloud searches users by query (account ID, email or name); Server and Data
+    /// Center look the username up directly. If the lookup fails, the identifier is
+    /// used as-is so the caller can still build a query with it.
     #[instrument(skip(self))]
     pub async fn get_user_by_identifier(&self, identifier: &str) -> JiraMcpResult<UserInfo> {
         debug!("Fetching user info for: {}", identifier);
 
-        let _timeout_duration = Duration::from_secs(self.config.request_timeout_seconds);
+        let deployment_type = self.deployment_type();
+        let found = if deployment_type.is_cloud() {
+            self.search_users(identifier).await.ok().and_then(|users| {
+                // Prefer an exact match over the best fuzzy one
+                let exact = users.iter().position(|user| {
+                    user.account_id == identifier
+                        || user.display_name.eq_ignore_ascii_case(identifier)
+                        || user
+                            .email_address
+                            .as_deref()
+                            .is_some_and(|email| email.eq_ignore_ascii_case(identifier))
+                });
+                users.into_iter().nth(exact.unwrap_or(0))
+            })
+        } else {
+            let endpoint = format!("/user?username={}", query_escape(identifier));
+            self.get_shared::<serde_json::Value>(
+                OperationClass::Read,
+                "looking up user",
+                "api",
+                &endpoint,
+                JiraMcpError::from,
+            )
+            .await
+            .ok()
+            .and_then(|user| user_info_from_json(&user, deployment_type))
+        };
 
-        // For now, return a placeholder as gouqi may not have direct user lookup
-        // This would need to be implemented based on available gouqi methods
-        Ok(UserInfo {
-            account_id: identifier.to_string(),
-            display_name: identifier.to_string(),
-            email_address: None,
-            active: true,
-        })
+        match found {
+            Some(user) => Ok(user),
+            None => {
+                warn!("Could not look up user '{}', using it as given", identifier);
+                Ok(UserInfo {
+                    account_id: identifier.to_string(),
+                    display_name: identifier.to_string(),
+                    email_address: None,
+                    active: true,
+                })
+            }
+        }
     }
 
-    /// Add a comment to a JIRA issue
+    /// Search users by name, email or (on Cloud) account ID
+    ///
+    /// Cloud matches `query` against display names and emails; Server and Data
+    /// Center match it against usernames, names and emails.
     #[instrument(skip(self))]
+    pub async fn search_users(&self, query: &str) -> JiraMcpResult<Vec<UserInfo>> {
+        let deployment_type = self.deployment_type();
+        let param = if deployment_type.is_cloud() {
+            "query"
+        } else {
+            "username"
+        };
+        let endpoint = format!("/user/search?{}={}", param, query_escape(query));
+
+        let users = self
+            .get_shared::<Vec<serde_json::Value>>(
+                OperationClass::Read,
+                "searching users",
+                "api",
+                &endpoint,
+                JiraMcpError::from,
+            )
+            .await?;
+
+        Ok(users
+            .iter()
+            .filter_map(|user| user_info_from_json(user, deployment_type))
+            .collect())
+    }
+
+    /// Add a comment to a JIRA issue
+    ///
+    /// `body` is wiki markup (a string) or an ADF document. `visibility` restricts
+    /// the comment to a project role or group; `jsm_internal` marks it as an internal
+    /// (agent-only) JSM comment via the `sd.public.comment` property. The body is
+    /// sent as built here, since gouqi's AddComment only carries a string body.
+    #[instrument(skip(self, body))]
     pub async fn add_comment(
         &self,
         issue_key: &str,
-        comment_body: &str,
+        body: serde_json::Value,
+        visibility: Option<&CommentVisibility>,
+        jsm_internal: bool,
     ) -> JiraMcpResult<CommentInfo> {
         info!("Adding comment to issue: {}", issue_key);
 
-        let timeout_duration = Duration::from_secs(self.config.request_timeout_seconds);
-
-        // Create the comment request
-        let add_comment = AddComment::new(comment_body);
+        let what = format!("adding comment to issue {}", issue_key);
 
-        // Call the real gouqi comment API
-        let comment = timeout(timeout_duration, async {
-            self.client.issues().comment(issue_key, add_comment).await
-        })
-        .await
-        .map_err(|_| {
-            JiraMcpError::network(format!("Timeout adding comment to issue {}", issue_key))
-        })?
-        .map_err(|e| {
-            if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+        let map_comment_error = |e: gouqi::Error| {
+            let message = e.to_string();
+            if message.contains("404") || message.contains("Not Found") {
                 JiraMcpError::not_found("issue", issue_key)
-            } else if e.to_string().contains("403") || e.to_string().contains("Forbidden") {
+            } else if message.contains("403") || message.contains("Forbidden") {
                 JiraMcpError::permission(format!(
                     "Permission denied adding comment to issue {}",
                     issue_key
                 ))
+            } else if visibility.is_some() && message.contains("400") {
+                // Keep JIRA's message so unknown role/group names are explained
+                JiraMcpError::invalid_param("visibility", message)
             } else {
                 JiraMcpError::from(e)
             }
-        })?;
+        };
+
+        let mut body = serde_json::json!({ "body": body });
+        if let Some(visibility) = visibility {
+            body["visibility"] = serde_json::to_value(visibility)?;
+        }
+        if jsm_internal {
+            body["properties"] = serde_json::json!([
+                { "key": "sd.public.comment", "value": { "internal": true } }
+            ]);
+        }
+
+        let endpoint = format!("/issue/{}/comment", issue_key);
+        let payload_bytes = Some(body.to_string().len());
+        let started = Instant::now();
+        let result = self
+            .with_timeout(
+                OperationClass::Write,
+                &what,
+                self.backend.add_comment(issue_key, body),
+            )
+            .await
+            .and_then(|response| response.map_err(map_comment_error));
+        self.log_request(
+            "POST",
+            &format!("api{}", endpoint),
+            payload_bytes,
+            started,
+            &result,
+        );
+        let comment = result?;
 
         info!("Successfully added comment to issue {}", issue_key);
-        Ok(self.convert_comment_info(&comment))
+        let mut comment_info = self.convert_comment_info(issue_key, &comment);
+        if comment_info.jsm_internal.is_none() && jsm_internal {
+            comment_info.jsm_internal = Some(true);
+        }
+        Ok(comment_info)
+    }
+
+    /// Transitions available on an issue, with the fields of their screens
+    #[instrument(skip(self))]
+    pub async fn get_transitions<T: DeserializeOwned>(&self, issue_key: &str) -> JiraMcpResult<T> {
+        self.read_shared(
+            OperationClass::Read,
+            &format!("reading transitions of {}", issue_key),
+            &format!(
+                "api/issue/{}/transitions?expand=transitions.fields",
+                issue_key
+            ),
+            || self.backend.get_transitions(issue_key),
+            JiraMcpError::from,
+        )
+        .await
+    }
+
+    /// Move an issue through a transition; `body` carries the transition ID and
+    /// the fields of its screen
+    #[instrument(skip(self, body))]
+    pub async fn transition_issue(
+        &self,
+        issue_key: &str,
+        body: impl Serialize,
+    ) -> JiraMcpResult<()> {
+        let body = serde_json::to_value(body)?;
+        let payload_bytes = Some(body.to_string().len());
+        let started = Instant::now();
+        let result = self
+            .with_timeout(
+                OperationClass::Write,
+                &format!("transitioning {}", issue_key),
+                self.backend.transition_issue(issue_key, body),
+            )
+            .await
+            .and_then(|response| {
+                response
+                    .map(|_| ())
+                    .map_err(|e| match throttle_retry_after(&e.to_string()) {
+                        Some(retry_after) => JiraMcpError::rate_limit(retry_after),
+                        None => JiraMcpError::from(e),
+                    })
+            });
+        self.log_request(
+            "POST",
+            &format!("api/issue/{}/transitions", issue_key),
+            payload_bytes,
+            started,
+            &result,
+        );
+        result
+    }
+
+    /// Attach files, given as (filename, content), to an issue
+    #[instrument(skip(self, files))]
+    pub async fn upload_attachments(
+        &self,
+        issue_key: &str,
+        files: Vec<(String, Vec<u8>)>,
+    ) -> JiraMcpResult<Vec<AttachmentResponse>> {
+        let payload_bytes = Some(files.iter().map(|(_, bytes)| bytes.len()).sum());
+        let started = Instant::now();
+        let result = self
+            .with_timeout(
+                OperationClass::Download,
+                &format!("uploading attachments to {}", issue_key),
+                self.backend.upload_attachments(issue_key, files),
+            )
+            .await
+            .and_then(|response| response.map_err(JiraMcpError::from));
+        self.log_request(
+            "POST",
+            &format!("api/issue/{}/attachments", issue_key),
+            payload_bytes,
+            started,
+            &result,
+        );
+        result
+    }
+
+    /// Raw content of an attachment
+    #[instrument(skip(self))]
+    pub async fn download_attachment(&self, attachment_id: &str) -> JiraMcpResult<Vec<u8>> {
+        let started = Instant::now();
+        let result = self
+            .with_timeout(
+                OperationClass::Download,
+                &format!("downloading attachment {}", attachment_id),
+                self.backend.download_attachment(attachment_id),
+            )
+            .await
+            .and_then(|response| response.map_err(JiraMcpError::from));
+        self.log_request(
+            "GET",
+            &format!("api/attachment/content/{}", attachment_id),
+            None,
+            started,
+            &result,
+        );
+        result
+    }
+
+    /// Send a notification email about an issue
+    ///
+    /// `to` is JIRA's recipient restriction object
+    /// (`{"reporter": true, "users": [{"accountId": ..}], "groups": [{"name": ..}]}`).
+    #[instrument(skip(self, text_body))]
+    pub async fn notify_issue(
+        &self,
+        issue_key: &str,
+        subject: &str,
+        text_body: &str,
+        to: serde_json::Value,
+    ) -> JiraMcpResult<()> {
+        info!("Sending notification for issue: {}", issue_key);
+
+        let body = serde_json::json!({
+            "subject": subject,
+            "textBody": text_body,
+            "to": to,
+        });
+
+        let endpoint = format!("/issue/{}/notify", issue_key);
+        let payload_bytes = Some(body.to_string().len());
+        let started = Instant::now();
+        let result = self
+            .with_timeout(
+                OperationClass::Write,
+                &format!("sending notification for {}", issue_key),
+                self.backend.post("api", &endpoint, body),
+            )
+            .await
+            .and_then(|response| {
+                response.or_else(|e| {
+                    let message = e.to_string();
+                    // The notify endpoint returns 204 No Content on success
+                    if message.contains("expected value") {
+                        debug!("Ignoring deserialization error (likely 204 No Content response)");
+                        Ok(serde_json::Value::Null)
+                    } else if message.contains("404") || message.contains("Not Found") {
+                        Err(JiraMcpError::not_found("issue", issue_key))
+                    } else if message.contains("403") || message.contains("Forbidden") {
+                        Err(JiraMcpError::permission(format!(
+                            "Permission denied sending notifications for issue {}",
+                            issue_key
+                        )))
+                    } else if message.to_lowercase().contains("notification")
+                        && message.contains("400")
+                    {
+                        Err(JiraMcpError::invalid_param(
+                            "to",
+                            format!(
+                            "JIRA refused to send the notification. Outgoing email or notifying \
+                             these recipients is disabled on this instance: {}",
+                            message
+                        ),
+                        ))
+                    } else {
+                        Err(JiraMcpError::from(e))
+                    }
+                })
+            });
+        self.log_request(
+            "POST",
+            &format!("api{}", endpoint),
+            payload_bytes,
+            started,
+            &result,
+        );
+        result?;
+
+        info!("Notification sent for issue {}", issue_key);
+        Ok(())
     }
 
     /// Format duration in seconds to JIRA time format (e.g., "2h 30m", "1w 2d 3h")
-    fn format_duration_jira(seconds: u64) -> String {
+    pub fn format_duration_jira(seconds: u64) -> String {
         let mut remaining = seconds;
         let mut parts = Vec::new();
 
@@ -710,6 +2978,8 @@ impl JiraClient {
     }
 
     /// Add a worklog entry to an issue
+    ///
+    /// With `signed`, the comment ends with the configured worklog_signature.
     #[instrument(skip(self))]
     pub async fn add_worklog(
         &self,
@@ -717,14 +2987,13 @@ impl JiraClient {
         time_spent_seconds: u64,
         comment: Option<String>,
         started: Option<chrono::DateTime<chrono::Utc>>,
+        signed: bool,
     ) -> JiraMcpResult<WorklogInfo> {
         info!(
             "Adding worklog to issue {}: {} seconds",
             issue_key, time_spent_seconds
         );
 
-        let timeout_duration = Duration::from_secs(self.config.request_timeout_seconds);
-
         // Convert chrono DateTime to time OffsetDateTime
         // Note: gouqi uses the `time` crate for timestamps
         // Always provide a started time (defaults to now if not specified)
@@ -736,7 +3005,8 @@ impl JiraClient {
         // Always provide a comment (use default if none provided)
         // JIRA Cloud v3 API requires comment field to be present
         let comment_text = comment.unwrap_or_else(|| "Work logged".to_string());
-        worklog = worklog.with_comment(comment_text);
+        let signature = self.config.worklog_signature.as_deref().filter(|_| signed);
+        worklog = worklog.with_comment(sign(&comment_text, signature));
 
         // Provide time_spent in human-readable format (required by JIRA)
         let time_spent_str = Self::format_duration_jira(time_spent_seconds);
@@ -747,14 +3017,16 @@ impl JiraClient {
 
         // Use basic add_worklog without estimate adjustment options
         // This should work even if the issue has no initial estimate set
-        let result = timeout(timeout_duration, async {
-            self.client.issues().add_worklog(issue_key, worklog).await
-        })
-        .await
-        .map_err(|_| {
-            JiraMcpError::network(format!("Timeout adding worklog to issue {}", issue_key))
-        })?
-        .map_err(|e| {
+        let payload_bytes = serde_json::to_vec(&worklog).ok().map(|b| b.len());
+        let started = Instant::now();
+        let result = self
+            .with_timeout(
+                OperationClass::Write,
+                &format!("adding worklog to issue {}", issue_key),
+                self.backend.add_worklog(issue_key, worklog),
+            )
+            .await
+            .and_then(|response| response.map_err(|e| {
             let error_str = e.to_string();
             if error_str.contains("404") || error_str.contains("Not Found") {
                 JiraMcpError::not_found("issue", issue_key)
@@ -783,7 +3055,15 @@ impl JiraClient {
             } else {
                 JiraMcpError::internal(format!("Failed to add worklog: {}", e))
             }
-        })?;
+        }));
+        self.log_request(
+            "POST",
+            &format!("api/issue/{}/worklog", issue_key),
+            payload_bytes,
+            started,
+            &result,
+        );
+        let result = result?;
 
         info!(
             "Successfully added worklog to issue {}: {}",
@@ -792,27 +3072,108 @@ impl JiraClient {
         Ok(self.convert_worklog_info(&result))
     }
 
+    /// Links of one issue, as gouqi's relationship graph of the issue alone
+    #[instrument(skip(self, options))]
+    pub async fn get_issue_relationships(
+        &self,
+        issue_key: &str,
+        options: GraphOptions,
+    ) -> JiraMcpResult<RelationshipGraph> {
+        let started = Instant::now();
+        let result = self
+            .with_timeout(
+                OperationClass::Read,
+                &format!("reading links of {}", issue_key),
+                self.backend.issue_relationships(issue_key, options),
+            )
+            .await
+            .and_then(|graph| {
+                graph.map_err(|e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("issue", issue_key)
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                })
+            });
+        self.log_request(
+            "GET",
+            &format!("api/issue/{}", issue_key),
+            None,
+            started,
+            &result,
+        );
+        result
+    }
+
+    /// Changelog of an issue, with the number of entries JIRA has in total
+    ///
+    /// Read from the raw response, which carries the changelog next to the
+    /// fields. The total is larger than the entries returned when JIRA
+    /// capped the expanded changelog.
+    #[instrument(skip(self))]
+    pub async fn get_changelog(
+        &self,
+        issue_key: &str,
+    ) -> JiraMcpResult<(Vec<HistoryEntry>, usize)> {
+        let query = IssueQuery {
+            fields: vec!["created".to_string()],
+            expand: vec!["changelog".to_string()],
+        };
+
+        let response: serde_json::Value = self
+            .read_shared(
+                OperationClass::Read,
+                &format!("getting changelog of issue {}", issue_key),
+                &format!("api{}", query.endpoint(issue_key)),
+                || self.backend.get_issue(issue_key, &query),
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("issue", issue_key)
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        let histories: Vec<HistoryEntry> = response["changelog"]["histories"]
+            .as_array()
+            .map(|histories| {
+                histories
+                    .iter()
+                    .filter_map(HistoryEntry::from_json)
+                    .map(|entry| self.redact_history(entry))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let total = response["changelog"]["total"]
+            .as_u64()
+            .map_or(histories.len(), |total| total as usize);
+
+        Ok((histories, total))
+    }
+
     /// Get all worklogs for an issue
     #[instrument(skip(self))]
     pub async fn get_worklogs(&self, issue_key: &str) -> JiraMcpResult<Vec<WorklogInfo>> {
         info!("Getting worklogs for issue {}", issue_key);
 
-        let timeout_duration = Duration::from_secs(self.config.request_timeout_seconds);
-
-        let result: WorklogList = timeout(timeout_duration, async {
-            self.client.issues().get_worklogs(issue_key).await
-        })
-        .await
-        .map_err(|_| {
-            JiraMcpError::network(format!("Timeout getting worklogs for issue {}", issue_key))
-        })?
-        .map_err(|e| {
-            if e.to_string().contains("404") || e.to_string().contains("Not Found") {
-                JiraMcpError::not_found("issue", issue_key)
-            } else {
-                JiraMcpError::internal(format!("Failed to get worklogs: {}", e))
-            }
-        })?;
+        let result: WorklogList = self
+            .read_shared(
+                OperationClass::Read,
+                &format!("getting worklogs for issue {}", issue_key),
+                &format!("api/issue/{}/worklog", issue_key),
+                || self.backend.get_worklogs(issue_key),
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("issue", issue_key)
+                    } else {
+                        JiraMcpError::internal(format!("Failed to get worklogs: {}", e))
+                    }
+                },
+            )
+            .await?;
 
         info!(
             "Retrieved {} worklogs for issue {}",
@@ -836,10 +3197,13 @@ impl JiraClient {
                 .as_ref()
                 .map(|u| u.display_name.clone())
                 .unwrap_or_else(|| "Unknown".to_string()),
-            comment: worklog.comment.as_ref().map(|c| c.to_string()),
-            created: worklog.created.map(|dt| dt.to_string()).unwrap_or_default(),
-            updated: worklog.updated.map(|dt| dt.to_string()).unwrap_or_default(),
-            started: worklog.started.map(|dt| dt.to_string()).unwrap_or_default(),
+            comment: worklog
+                .comment
+                .as_ref()
+                .map(|c| self.redactor.redact_owned(c.to_string())),
+            created: worklog.created.map(jira_timestamp).unwrap_or_default(),
+            updated: worklog.updated.map(jira_timestamp).unwrap_or_default(),
+            started: worklog.started.map(jira_timestamp).unwrap_or_default(),
             time_spent: worklog.time_spent.clone(),
             time_spent_seconds: worklog.time_spent_seconds,
         }
@@ -852,39 +3216,433 @@ impl JiraClient {
         status_filter: Option<&[String]>,
         issue_types: Option<&[String]>,
     ) -> String {
-        let mut jql_parts = vec![format!("assignee = \"{}\"", account_id)];
+        let mut jql_parts = vec![format!("{} = {}", jql::ASSIGNEE, jql::quote(account_id))];
+        jql_parts.extend(jql::eq_or_in(
+            &jql::STATUS,
+            status_filter.unwrap_or_default(),
+        ));
+        jql_parts.extend(jql::eq_or_in(
+            &jql::ISSUE_TYPE,
+            issue_types.unwrap_or_default(),
+        ));
 
-        if let Some(statuses) = status_filter {
-            if !statuses.is_empty() {
-                let status_list = statuses
-                    .iter()
-                    .map(|s| format!("\"{}\"", s))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                jql_parts.push(format!("status IN ({})", status_list));
-            }
-        }
+        // Order by updated date descending
+        format!("{} ORDER BY updated DESC", jql_parts.join(" AND "))
+    }
+}
 
-        if let Some(types) = issue_types {
-            if !types.is_empty() {
-                let type_list = types
-                    .iter()
-                    .map(|t| format!("\"{}\"", t))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                jql_parts.push(format!("issuetype IN ({})", type_list));
-            }
-        }
+/// User from a /myself, /user or /user/search entry
+///
+/// The ID is the account ID on Cloud and the username on Server/Data Center.
+/// Format a timestamp gouqi parsed the way JIRA writes it, e.g.
+/// "2026-01-05T10:00:00.000+0000", so it sorts and parses like the timestamps
+/// read from raw JSON
+fn jira_timestamp(timestamp: time::OffsetDateTime) -> String {
+    use chrono::Offset;
+
+    let offset = chrono::FixedOffset::east_opt(timestamp.offset().whole_seconds())
+        .unwrap_or_else(|| chrono::Utc.fix());
+    chrono::DateTime::from_timestamp(timestamp.unix_timestamp(), timestamp.nanosecond())
+        .unwrap_or_default()
+        .with_timezone(&offset)
+        .format("%Y-%m-%dT%H:%M:%S%.3f%z")
+        .to_string()
+}
 
-        // Order by updated date descending
-        jql_parts.push("ORDER BY updated DESC".to_string());
+/// Whether a JSM comment is internal, from `jsdPublic` or, when the comment was
+/// read with its properties, the `sd.public.comment` property that marks it
+fn comment_jsm_internal(comment: &serde_json::Value) -> Option<bool> {
+    if let Some(public) = comment["jsdPublic"].as_bool() {
+        return Some(!public);
+    }
+    comment["properties"]
+        .as_array()?
+        .iter()
+        .find(|property| property["key"] == "sd.public.comment")
+        .map(|property| property["value"]["internal"].as_bool().unwrap_or(false))
+}
+
+/// A text property of an object-valued issue field, e.g. the status name
+///
+/// Read from the raw field rather than through gouqi's typed accessors, which
+/// give up on the whole object when JIRA leaves out a property they require
+/// (a status without `description`, a project without `projectTypeKey`).
+fn field_text(
+    fields: &BTreeMap<String, serde_json::Value>,
+    field: &str,
+    property: &str,
+) -> Option<String> {
+    fields
+        .get(field)
+        .and_then(|value| value[property].as_str())
+        .map(String::from)
+}
+
+fn user_info_from_json(
+    user: &serde_json::Value,
+    deployment_type: DeploymentType,
+) -> Option<UserInfo> {
+    let id_field = if deployment_type.is_cloud() {
+        "accountId"
+    } else {
+        "name"
+    };
+    let account_id = user[id_field].as_str()?.to_string();
+
+    Some(UserInfo {
+        display_name: user["displayName"]
+            .as_str()
+            .unwrap_or(&account_id)
+            .to_string(),
+        email_address: user["emailAddress"].as_str().map(String::from),
+        active: user["active"].as_bool().unwrap_or(true),
+        account_id,
+    })
+}
 
-        jql_parts.join(" AND ")
+/// Read a board's `/configuration` response
+///
+/// IDs are strings on Server and may be numbers on Cloud; both are accepted.
+fn board_configuration_from_json(board_id: u64, value: &serde_json::Value) -> BoardConfiguration {
+    let id = |value: &serde_json::Value| match value {
+        serde_json::Value::String(id) => Some(id.clone()),
+        serde_json::Value::Number(id) => Some(id.to_string()),
+        _ => None,
+    };
+    let text = |value: &serde_json::Value| value.as_str().map(String::from);
+
+    let columns = value["columnConfig"]["columns"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|column| BoardColumn {
+            name: column["name"].as_str().unwrap_or_default().to_string(),
+            statuses: column["statuses"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|status| id(&status["id"]))
+                .map(|id| BoardColumnStatus {
+                    id,
+                    name: None,
+                    category_key: None,
+                })
+                .collect(),
+            min: column["min"].as_u64(),
+            max: column["max"].as_u64(),
+        })
+        .collect();
+
+    let estimation = &value["estimation"];
+    BoardConfiguration {
+        board_id,
+        board_name: value["name"].as_str().unwrap_or_default().to_string(),
+        filter_id: id(&value["filter"]["id"]),
+        filter_jql: None,
+        sub_query: text(&value["subQuery"]["query"]).filter(|query| !query.trim().is_empty()),
+        columns,
+        estimation: text(&estimation["type"]).map(|estimation_type| BoardEstimation {
+            estimation_type,
+            field_id: text(&estimation["field"]["fieldId"]),
+            field_name: text(&estimation["field"]["displayName"]),
+        }),
     }
 }
 
+/// Browse URL of an issue
+///
+/// `base_url` may carry a context path (e.g. "https://example.com/jira") and a
+/// trailing slash.
+pub fn issue_browse_url(base_url: &str, issue_key: &str) -> String {
+    format!("{}/browse/{}", base_url.trim_end_matches('/'), issue_key)
+}
+
+/// Permalink of a comment, opening the issue scrolled to the comment
+pub fn comment_permalink(base_url: &str, issue_key: &str, comment_id: &str) -> String {
+    format!(
+        "{}?focusedCommentId={}#comment-{}",
+        issue_browse_url(base_url, issue_key),
+        comment_id,
+        comment_id
+    )
+}
+
+/// Download URL of an attachment's content
+pub fn attachment_content_url(base_url: &str, attachment_id: &str, filename: &str) -> String {
+    format!(
+        "{}/secure/attachment/{}/{}",
+        base_url.trim_end_matches('/'),
+        attachment_id,
+        query_escape(filename)
+    )
+}
+
+/// Attachment ID from a content or thumbnail URL on the JIRA instance at `base_url`
+///
+/// Accepts `/secure/attachment/{id}/{filename}`, `/secure/thumbnail/{id}/...` and
+/// `/rest/api/{version}/attachment/{content|thumbnail}/{id}`. URLs on other hosts
+/// yield None, so credentials are never sent elsewhere.
+pub fn attachment_id_from_url(base_url: &str, url: &str) -> Option<String> {
+    let base = format!("{}/", base_url.trim_end_matches('/'));
+    let path = url.trim().strip_prefix(&base)?;
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').collect();
+
+    let id = match segments.as_slice() {
+        ["secure", "attachment" | "thumbnail", id, ..] => *id,
+        ["rest", "api", _, "attachment", "content" | "thumbnail", id] => *id,
+        _ => return None,
+    };
+    (!id.is_empty() && id.chars().all(|c| c.is_ascii_digit())).then(|| id.to_string())
+}
+
+/// Percent-encode a query parameter value
+pub(crate) fn query_escape(value: &str) -> String {
+    value
+        .bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_jsm_internal() {
+        use serde_json::json;
+
+        assert_eq!(
+            comment_jsm_internal(&json!({"jsdPublic": false})),
+            Some(true)
+        );
+        assert_eq!(
+            comment_jsm_internal(&json!({"jsdPublic": true})),
+            Some(false)
+        );
+
+        let property =
+            |value| json!({"properties": [{"key": "sd.public.comment", "value": value}]});
+        assert_eq!(
+            comment_jsm_internal(&property(json!({"internal": true}))),
+            Some(true)
+        );
+        assert_eq!(
+            comment_jsm_internal(&property(json!({"internal": false}))),
+            Some(false)
+        );
+
+        // Outside service desk projects nothing says either way
+        assert_eq!(comment_jsm_internal(&json!({"body": "text"})), None);
+        assert_eq!(comment_jsm_internal(&json!({"properties": []})), None);
+    }
+
+    #[test]
+    fn test_urls_with_and_without_context_path() {
+        for base in ["https://jira.example.com", "https://jira.example.com/"] {
+            assert_eq!(
+                issue_browse_url(base, "PROJ-1"),
+                "https://jira.example.com/browse/PROJ-1"
+            );
+        }
+
+        let base = "https://example.com/jira/";
+        assert_eq!(
+            issue_browse_url(base, "PROJ-1"),
+            "https://example.com/jira/browse/PROJ-1"
+        );
+        assert_eq!(
+            comment_permalink(base, "PROJ-1", "10500"),
+            "https://example.com/jira/browse/PROJ-1?focusedCommentId=10500#comment-10500"
+        );
+        assert_eq!(
+            attachment_content_url(base, "20001", "gateway logs.txt"),
+            "https://example.com/jira/secure/attachment/20001/gateway%20logs.txt"
+        );
+    }
+
+    #[test]
+    fn test_attachment_id_from_url() {
+        let base = "https://example.com/jira/";
+        for url in [
+            "https://example.com/jira/secure/attachment/20001/gateway%20logs.txt",
+            "https://example.com/jira/secure/thumbnail/20001/_thumb_20001.png",
+            "https://example.com/jira/rest/api/3/attachment/content/20001",
+            "https://example.com/jira/rest/api/2/attachment/thumbnail/20001?redirect=false",
+        ] {
+            assert_eq!(
+                attachment_id_from_url(base, url).as_deref(),
+                Some("20001"),
+                "{}",
+                url
+            );
+        }
+
+        for url in [
+            "https://evil.example.org/jira/secure/attachment/20001/x.txt",
+            "https://example.com/secure/attachment/20001/x.txt",
+            "https://example.com/jira/secure/attachment/abc/x.txt",
+            "https://example.com/jira/browse/PROJ-1",
+        ] {
+            assert_eq!(attachment_id_from_url(base, url), None, "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_field_map_resolution() {
+        let catalog = vec![
+            serde_json::json!({"id": "customfield_10016", "name": "Story point estimate"}),
+            serde_json::json!({"id": "summary", "name": "Summary"}),
+            serde_json::json!({"id": "customfield_10002", "name": "Story Points"}),
+            serde_json::json!({"id": "customfield_10300", "name": "acceptance criteria "}),
+            serde_json::json!({"id": "customfield_10014", "name": "Epic Link"}),
+        ];
+
+        let detected = FieldMap::resolve(&HashMap::new(), Some(&catalog));
+        assert_eq!(
+            detected.story_points.field_ids,
+            vec!["customfield_10002", "customfield_10016"]
+        );
+        assert_eq!(detected.story_points.source, "field_catalog");
+        assert_eq!(
+            detected.acceptance_criteria.field_ids,
+            vec!["customfield_10300"]
+        );
+
+        // Config overrides the catalog
+        let mappings = HashMap::from([(
+            STORY_POINTS_FIELD.to_string(),
+            "customfield_10004".to_string(),
+        )]);
+        let configured = FieldMap::resolve(&mappings, Some(&catalog));
+        assert_eq!(configured.story_points.field_ids, vec!["customfield_10004"]);
+        assert_eq!(configured.story_points.source, "config");
+
+        let defaults = FieldMap::resolve(&HashMap::new(), None);
+        assert_eq!(defaults.story_points.field_ids, vec!["customfield_10016"]);
+        assert_eq!(defaults.acceptance_criteria.source, "default");
+        assert!(detected.epic_link.is_present());
+        assert!(!defaults.epic_link.is_present());
+
+        // The first candidate set on the issue is read; writes go to a field the issue has
+        let fields = BTreeMap::from([
+            ("customfield_10002".to_string(), serde_json::Value::Null),
+            ("customfield_10016".to_string(), serde_json::json!(5)),
+        ]);
+        assert_eq!(
+            detected.story_points.value_in(&fields),
+            Some(&serde_json::json!(5))
+        );
+        assert_eq!(
+            detected.story_points.field_for(&fields),
+            Some("customfield_10016")
+        );
+        let unset = BTreeMap::from([("customfield_10002".to_string(), serde_json::Value::Null)]);
+        assert_eq!(detected.story_points.value_in(&unset), None);
+        assert_eq!(
+            detected.story_points.field_for(&unset),
+            Some("customfield_10002")
+        );
+    }
+
+    #[test]
+    fn test_issue_style_from_cloud_and_server_fields() {
+        let cloud: BTreeMap<String, serde_json::Value> = serde_json::from_value(serde_json::json!({
+            "issuetype": {
+                "id": "10004",
+                "name": "Bug",
+                "iconUrl": "https://example.atlassian.net/rest/api/2/universal_avatar/view/type/issuetype/avatar/10303?size=medium",
+                "hierarchyLevel": 0
+            },
+            "status": {
+                "name": "Terminé",
+                "statusCategory": {"id": 3, "key": "done", "colorName": "green", "name": "Terminé"}
+            }
+        }))
+        .unwrap();
+        let style = IssueStyle::from_fields(&cloud);
+        assert_eq!(style.status_category.as_deref(), Some("done"));
+        assert_eq!(style.status_color.as_deref(), Some("green"));
+        assert!(style
+            .issue_type_icon_url
+            .unwrap()
+            .contains("/universal_avatar/view/type/issuetype/"));
+
+        let server: BTreeMap<String, serde_json::Value> = serde_json::from_value(serde_json::json!({
+            "issuetype": {
+                "id": "3",
+                "name": "Task",
+                "iconUrl": "https://jira.example.com/secure/viewavatar?size=xsmall&avatarId=10318&avatarType=issuetype",
+                "avatarId": 10318
+            },
+            "status": {
+                "iconUrl": "https://jira.example.com/images/icons/statuses/inprogress.png",
+                "name": "In Progress",
+                "statusCategory": {"id": 4, "key": "indeterminate", "colorName": "yellow", "name": "In Progress"}
+            }
+        }))
+        .unwrap();
+        let style = IssueStyle::from_fields(&server);
+        assert_eq!(style.status_category.as_deref(), Some("indeterminate"));
+        assert_eq!(style.status_color.as_deref(), Some("yellow"));
+        assert!(style
+            .issue_type_icon_url
+            .unwrap()
+            .contains("avatarType=issuetype"));
+
+        // Fields that were not requested leave everything unset
+        assert_eq!(
+            IssueStyle::from_fields(&BTreeMap::new()),
+            IssueStyle::default()
+        );
+    }
+
+    #[test]
+    fn test_board_configuration_from_json() {
+        let configuration = board_configuration_from_json(
+            84,
+            &serde_json::json!({
+                "id": 84,
+                "name": "Team board",
+                "type": "kanban",
+                "filter": {"id": 10100},
+                "subQuery": {"query": "fixVersion in unreleasedVersions() OR fixVersion is EMPTY"},
+                "columnConfig": {
+                    "columns": [
+                        {"name": "Backlog", "statuses": [{"id": "1"}]},
+                        {"name": "Review", "statuses": [{"id": "10010"}, {"id": 10011}], "max": 3},
+                        {"name": "Parked", "statuses": []}
+                    ],
+                    "constraintType": "issueCount"
+                },
+                "estimation": {
+                    "type": "field",
+                    "field": {"fieldId": "customfield_10002", "displayName": "Story Points"}
+                }
+            }),
+        );
+
+        assert_eq!(configuration.board_name, "Team board");
+        assert_eq!(configuration.filter_id.as_deref(), Some("10100"));
+        assert!(configuration.sub_query.unwrap().starts_with("fixVersion"));
+        let review = &configuration.columns[1];
+        let ids: Vec<&str> = review.statuses.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, vec!["10010", "10011"]);
+        assert_eq!((review.min, review.max), (None, Some(3)));
+        assert!(configuration.columns[2].statuses.is_empty());
+        let estimation = configuration.estimation.unwrap();
+        assert_eq!(estimation.field_id.as_deref(), Some("customfield_10002"));
+
+        // Boards without estimation or a sub-filter
+        let bare = board_configuration_from_json(1, &serde_json::json!({"filter": {"id": "7"}}));
+        assert!(bare.columns.is_empty());
+        assert!(bare.estimation.is_none() && bare.sub_query.is_none());
+    }
 
     // Note: Tests are commented out due to unsafe mock usage
     // Proper mocking would require a trait-based approach or dependency injection
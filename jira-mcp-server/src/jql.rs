@@ -0,0 +1,318 @@
+//! JQL building blocks
+//!
+//! Tool parameters (project keys, account ids, labels, free text) end up in JQL,
+//! and pasting them in with `format!` lets a value like `x" OR assignee is EMPTY OR "`
+//! change what a query selects. Every JQL construction site quotes values with
+//! [`quote`] (or [`eq_or_in`] for `=` / `IN` conditions) and names fields with a
+//! [`Field`], which only holds valid identifiers.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use std::borrow::Cow;
+use std::fmt;
+
+pub const ASSIGNEE: Field = Field::known("assignee");
+pub const COMMENT: Field = Field::known("comment");
+pub const COMPONENT: Field = Field::known("component");
+pub const CREATED: Field = Field::known("created");
+pub const CREATOR: Field = Field::known("creator");
+pub const DESCRIPTION: Field = Field::known("description");
+pub const EPIC_LINK: Field = Field::known("Epic Link");
+pub const FILTER: Field = Field::known("filter");
+pub const ISSUE_TYPE: Field = Field::known("issuetype");
+pub const KEY: Field = Field::known("key");
+pub const LABELS: Field = Field::known("labels");
+pub const PARENT: Field = Field::known("parent");
+pub const PRIORITY: Field = Field::known("priority");
+pub const PROJECT: Field = Field::known("project");
+pub const RANK: Field = Field::known("Rank");
+pub const REPORTER: Field = Field::known("reporter");
+pub const RESOLUTION: Field = Field::known("resolution");
+pub const RESOLVED: Field = Field::known("resolved");
+pub const SPRINT: Field = Field::known("Sprint");
+pub const STATUS: Field = Field::known("status");
+pub const STATUS_CATEGORY: Field = Field::known("statusCategory");
+pub const SUMMARY: Field = Field::known("summary");
+pub const TEXT: Field = Field::known("text");
+pub const UPDATED: Field = Field::known("updated");
+pub const WATCHER: Field = Field::known("watcher");
+pub const WORKLOG_AUTHOR: Field = Field::known("worklogAuthor");
+pub const WORKLOG_DATE: Field = Field::known("worklogDate");
+
+/// Escape a value for use inside a double-quoted JQL string
+///
+/// Backslashes and both quote characters are escaped, and control characters are
+/// written as `\n`, `\r`, `\t` or `\uXXXX`, so the value can never end the string.
+pub fn escape_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\'' => escaped.push_str("\\'"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A value as a JQL string literal, e.g. `"In Progress"`
+pub fn quote(value: &str) -> String {
+    format!("\"{}\"", escape_value(value))
+}
+
+/// Characters with a meaning in JIRA's text search syntax (Lucene)
+const TEXT_SEARCH_OPERATORS: &[char] = &[
+    '+', '-', '&', '|', '!', '(', ')', '{', '}', '[', ']', '^', '~', '*', '?', ':', '\\', '/', '"',
+];
+
+/// Free text as a JQL string literal for the `~` operator, matched literally
+///
+/// Text search operators in the text are escaped, and text with several words
+/// is wrapped in quotes so JIRA matches the phrase rather than any of its words:
+/// `login fails` becomes `"\"login fails\""`.
+pub fn quote_text(text: &str) -> String {
+    let mut term = String::with_capacity(text.len() + 2);
+    for c in text.trim().chars() {
+        if TEXT_SEARCH_OPERATORS.contains(&c) {
+            term.push('\\');
+        }
+        term.push(c);
+    }
+    if term.contains(char::is_whitespace) {
+        term = format!("\"{}\"", term);
+    }
+    quote(&term)
+}
+
+/// Comma-separated string literals for an `IN (...)` list
+pub fn quote_list<S: AsRef<str>>(values: &[S]) -> String {
+    values
+        .iter()
+        .map(|value| quote(value.as_ref()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// `field = "value"` for one value, `field IN ("a", "b")` for several, None for none
+pub fn eq_or_in<S: AsRef<str>>(field: &Field, values: &[S]) -> Option<String> {
+    match values {
+        [] => None,
+        [value] => Some(format!("{} = {}", field, quote(value.as_ref()))),
+        values => Some(format!("{} IN ({})", field, quote_list(values))),
+    }
+}
+
+/// A JQL field name
+///
+/// Plain identifiers (`assignee`, `customfield_10015`) and `cf[10015]` are written
+/// as-is; names with spaces (`Epic Link`, `Story Points`) are quoted. Anything that
+/// could carry an operator or end a string is rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field(Cow<'static, str>);
+
+impl Field {
+    /// A field name known at compile time; invalid names fail the build when
+    /// used in a constant
+    pub const fn known(name: &'static str) -> Self {
+        assert!(is_valid_name(name.as_bytes()), "invalid JQL field name");
+        Field(Cow::Borrowed(name))
+    }
+
+    /// Validate a field name coming from configuration or a tool parameter
+    pub fn parse(name: &str) -> JiraMcpResult<Self> {
+        let name = name.trim();
+        if !is_valid_name(name.as_bytes()) && !is_custom_field_ref(name) {
+            return Err(JiraMcpError::jql(format!(
+                "Invalid JQL field name: '{}'. Use letters, digits, spaces, '_', '-' or '.', \
+                 or the cf[12345] form",
+                name
+            )));
+        }
+        Ok(Field(Cow::Owned(name.to_string())))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.as_str();
+        let bare = is_custom_field_ref(name)
+            || name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+        if bare {
+            f.write_str(name)
+        } else {
+            f.write_str(&quote(name))
+        }
+    }
+}
+
+/// Letters, digits, single spaces and `_-.`, starting with a letter or `_`
+const fn is_valid_name(name: &[u8]) -> bool {
+    if name.is_empty() || !(name[0].is_ascii_alphabetic() || name[0] == b'_') {
+        return false;
+    }
+    let mut i = 0;
+    while i < name.len() {
+        let c = name[i];
+        let allowed = c.is_ascii_alphanumeric()
+            || c == b'_'
+            || c == b'-'
+            || c == b'.'
+            || (c == b' ' && i + 1 < name.len() && name[i + 1] != b' ');
+        if !allowed {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// `cf[12345]`
+fn is_custom_field_ref(name: &str) -> bool {
+    name.strip_prefix("cf[")
+        .and_then(|rest| rest.strip_suffix(']'))
+        .is_some_and(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parse `input` as exactly one JQL string literal and return its value
+    fn parse_literal(input: &str) -> Option<String> {
+        let mut chars = input.strip_prefix('"')?.chars();
+        let mut value = String::new();
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => match chars.next()? {
+                    'n' => value.push('\n'),
+                    'r' => value.push('\r'),
+                    't' => value.push('\t'),
+                    'u' => {
+                        let hex: String = chars.by_ref().take(4).collect();
+                        value.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+                    }
+                    c @ ('\\' | '"' | '\'') => value.push(c),
+                    _ => return None,
+                },
+                c => value.push(c),
+            }
+        }
+        // Nothing may follow the closing quote
+        chars.next().is_none().then_some(value)
+    }
+
+    /// Hand-picked injection attempts plus deterministic random mixes of the
+    /// characters that matter to the JQL lexer
+    fn adversarial_values() -> Vec<String> {
+        let mut values: Vec<String> = [
+            "\" OR assignee is EMPTY OR \"",
+            "x\") OR project = SECRET ORDER BY created --",
+            "\\",
+            "\\\"",
+            "ends with backslash\\",
+            "'single' \"double\"",
+            "line\nbreak\r\ttab",
+            "nul\u{0}byte",
+            "",
+            " ",
+            "ünïcödé ✓",
+        ]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+        let alphabet: Vec<char> = "\"'\\ ()=!~,ORAND\n\t\u{7}a1-".chars().collect();
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..500 {
+            let mut value = String::new();
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+            for _ in 0..(state >> 60) + 1 {
+                state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+                value.push(alphabet[(state >> 33) as usize % alphabet.len()]);
+            }
+            values.push(value);
+        }
+        values
+    }
+
+    #[test]
+    fn test_quote_yields_a_single_literal() {
+        for value in adversarial_values() {
+            let quoted = quote(&value);
+            assert_eq!(
+                parse_literal(&quoted).as_deref(),
+                Some(value.as_str()),
+                "{}",
+                quoted
+            );
+        }
+    }
+
+    #[test]
+    fn test_quote_text() {
+        assert_eq!(quote_text("timeout"), "\"timeout\"");
+        assert_eq!(quote_text(" login fails "), "\"\\\"login fails\\\"\"");
+        assert_eq!(quote_text("c++"), "\"c\\\\+\\\\+\"");
+
+        // The text search term is still a single JQL literal
+        for value in adversarial_values() {
+            assert!(parse_literal(&quote_text(&value)).is_some(), "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn test_eq_or_in_keeps_values_literal() {
+        for value in adversarial_values() {
+            let clause = eq_or_in(&LABELS, &[&value]).unwrap();
+            let literal = clause.strip_prefix("labels = ").unwrap();
+            assert_eq!(parse_literal(literal).as_deref(), Some(value.as_str()));
+
+            let clause = eq_or_in(&LABELS, &[value.as_str(), "b"]).unwrap();
+            let list = clause
+                .strip_prefix("labels IN (")
+                .and_then(|rest| rest.strip_suffix(", \"b\")"))
+                .unwrap();
+            assert_eq!(parse_literal(list).as_deref(), Some(value.as_str()));
+        }
+
+        assert_eq!(eq_or_in::<&str>(&LABELS, &[]), None);
+    }
+
+    #[test]
+    fn test_field_names() {
+        assert_eq!(ASSIGNEE.to_string(), "assignee");
+        assert_eq!(EPIC_LINK.to_string(), "\"Epic Link\"");
+        assert_eq!(Field::parse("cf[10015]").unwrap().to_string(), "cf[10015]");
+        assert_eq!(
+            Field::parse(" customfield_10015 ").unwrap().to_string(),
+            "customfield_10015"
+        );
+        assert_eq!(
+            Field::parse("Story Points").unwrap().to_string(),
+            "\"Story Points\""
+        );
+
+        for invalid in [
+            "",
+            "status = Done OR project",
+            "a\"b",
+            "labels)",
+            "cf[10015] OR",
+            "1field",
+            "two  spaces",
+        ] {
+            assert!(Field::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -13,56 +13,106 @@
 //! - **Issue Interaction**: Add comments and analyze issue relationship graphs
 //! - **Error Handling**: MCP-compliant error codes and messages
 
-use crate::cache::{MetadataCache, UserMapping};
+use crate::audit::{collect_issue_keys, AuditLog};
+use crate::backend::MockBackend;
+use crate::cache::{CacheCategory, MetadataCache, UserMapping};
 use crate::config::JiraConfig;
-use crate::error::{JiraMcpError, JiraMcpResult};
-use crate::jira_client::JiraClient;
+use crate::connection::ConnectionSnapshot;
+use crate::diagnostics::{DiagnosticsReport, RunDiagnosticsParams};
+use crate::error::{JiraMcpError, JiraMcpResult, ToolError, ToolResult};
+use crate::idempotency::IdempotencyStore;
+use crate::jira_client::{FieldMap, JiraClient, ServerInfo};
+use crate::metrics::{Measured, ToolPerformanceSummary, ToolStats};
+use crate::shutdown::ShutdownCoordinator;
+use crate::tools::param_validation::Validated;
+use crate::tools::rate_limiter::RateLimitStatus;
+use crate::tools::tool_response::ToolResponse;
 use crate::tools::{
-    AddCommentParams, AddCommentResult, AddCommentTool, AddTodoParams, AddTodoResult,
-    AssignIssueParams, AssignIssueResult, AssignIssueTool, BulkAddLabelsParams,
+    AddCommentParams, AddCommentResult, AddTodoParams, AddTodoResult, ArchiveIssueParams,
+    ArchiveIssueResult, AssignIssueParams, AssignIssueResult, BulkAddLabelsParams,
     BulkAddLabelsResult, BulkAssignIssuesParams, BulkAssignIssuesResult, BulkCreateIssuesParams,
-    BulkCreateIssuesResult, BulkOperationsTool, BulkTransitionIssuesParams,
-    BulkTransitionIssuesResult, BulkUpdateFieldsParams, BulkUpdateFieldsResult,
-    CancelTodoWorkParams, CancelTodoWorkResult, CheckpointTodoWorkParams, CheckpointTodoWorkResult,
-    CloseSprintParams, CloseSprintResult, CloseSprintTool, CompleteTodoWorkParams,
-    CompleteTodoWorkResult, ComponentsTool, CreateIssueParams, CreateIssueResult, CreateIssueTool,
-    CreateSprintParams, CreateSprintResult, CreateSprintTool, DeleteIssueLinkParams,
-    DeleteIssueLinkResult, DeleteIssueLinkTool, DownloadAttachmentParams, DownloadAttachmentResult,
-    DownloadAttachmentTool, GetActiveWorkSessionsResult, GetAvailableComponentsParams,
-    GetAvailableComponentsResult, GetAvailableLabelsParams, GetAvailableLabelsResult,
-    GetAvailableTransitionsParams, GetAvailableTransitionsResult, GetAvailableTransitionsTool,
-    GetCreateMetadataParams, GetCreateMetadataResult, GetCreateMetadataTool, GetCustomFieldsParams,
-    GetCustomFieldsResult, GetCustomFieldsTool, GetIssueDetailsParams, GetIssueDetailsResult,
-    GetIssueDetailsTool, GetIssueLinkTypesResult, GetIssueLinkTypesTool, GetSprintInfoParams,
-    GetSprintInfoResult, GetSprintInfoTool, GetSprintIssuesParams, GetSprintIssuesResult,
-    GetSprintIssuesTool, GetUserIssuesParams, GetUserIssuesResult, GetUserIssuesTool,
-    IssueRelationshipsParams, IssueRelationshipsResult, IssueRelationshipsTool, LabelsTool,
-    LinkIssuesParams, LinkIssuesResult, LinkIssuesTool, ListAttachmentsParams,
-    ListAttachmentsResult, ListAttachmentsTool, ListSprintsParams, ListSprintsResult,
-    ListSprintsTool, ListTodosParams, ListTodosResult, ManageLabelsParams, ManageLabelsResult,
-    MoveToSprintParams, MoveToSprintResult, MoveToSprintTool, PauseTodoWorkParams,
-    PauseTodoWorkResult, SearchIssuesParams, SearchIssuesResult, SearchIssuesTool,
-    SetTodoBaseParams, SetTodoBaseResult, StartSprintParams, StartSprintResult, StartSprintTool,
-    StartTodoWorkParams, StartTodoWorkResult, TodoTracker, TransitionIssueParams,
-    TransitionIssueResult, TransitionIssueTool, UpdateComponentsParams, UpdateComponentsResult,
-    UpdateCustomFieldsParams, UpdateCustomFieldsResult, UpdateCustomFieldsTool, UpdateDescription,
-    UpdateDescriptionParams, UpdateDescriptionResult, UpdateTodoParams, UpdateTodoResult,
-    UploadAttachmentParams, UploadAttachmentResult, UploadAttachmentTool,
+    BulkCreateIssuesResult, BulkTransitionIssuesParams, BulkTransitionIssuesResult,
+    BulkUpdateFieldsParams, BulkUpdateFieldsResult, CancelTodoWorkParams, CancelTodoWorkResult,
+    CheckIssueQualityParams, CheckIssueQualityResult, CheckpointTodoWorkParams,
+    CheckpointTodoWorkResult, ClearCacheScopeParams, ClearCacheScopeResult, CloseSprintParams,
+    CloseSprintResult, CompleteTodoWorkParams, CompleteTodoWorkResult, CountIssuesParams,
+    CountIssuesResult, CreateIssueParams, CreateIssueResult, CreateSprintParams,
+    CreateSprintResult, CreateWatchQueryParams, CreateWatchQueryResult, DeleteIssueLinkParams,
+    DeleteIssueLinkResult, DiffIssueSinceParams, DiffIssueSinceResult, DownloadAttachmentParams,
+    DownloadAttachmentResult, FindSimilarIssuesParams, FindSimilarIssuesResult,
+    GetActiveWorkSessionsResult, GetAuditLogParams, GetAuditLogResult,
+    GetAvailableComponentsParams, GetAvailableComponentsResult, GetAvailableLabelsParams,
+    GetAvailableLabelsResult, GetAvailableTransitionsParams, GetAvailableTransitionsResult,
+    GetBoardColumnIssuesParams, GetBoardColumnIssuesResult, GetBoardConfigurationParams,
+    GetBoardConfigurationResult, GetCreateMetadataParams, GetCreateMetadataResult,
+    GetCustomFieldsParams, GetCustomFieldsResult, GetDescriptionMetadataParams,
+    GetDescriptionMetadataResult, GetEstimateRollupParams, GetEstimateRollupResult,
+    GetIssueActivityParams, GetIssueActivityResult, GetIssueContextBundleParams,
+    GetIssueContextBundleResult, GetIssueDetailsParams, GetIssueDetailsResult,
+    GetIssueLinkTypesResult, GetIssuesByLabelParams, GetIssuesByLabelResult, GetIssuesParams,
+    GetIssuesResult, GetMyMentionsParams, GetMyMentionsResult, GetMyPermissionsParams,
+    GetMyPermissionsResult, GetMyTimesheetParams, GetMyTimesheetResult, GetProjectStatsParams,
+    GetProjectStatsResult, GetRecentRequestsParams, GetRecentRequestsResult, GetSprintInfoParams,
+    GetSprintInfoResult, GetSprintIssuesParams, GetSprintIssuesResult,
+    GetSprintPlanningSummaryParams, GetSprintPlanningSummaryResult, GetStandupSummaryParams,
+    GetStandupSummaryResult, GetUserIssuesParams, GetUserIssuesResult, GetVotesResult,
+    IssueRelationshipsParams, IssueRelationshipsResult, IssueVoteParams, LinkIssuesParams,
+    LinkIssuesResult, ListAttachmentsParams, ListAttachmentsResult, ListLabelsParams,
+    ListLabelsResult, ListPrioritiesParams, ListPrioritiesResult, ListSprintsParams,
+    ListSprintsResult, ListTodosAcrossIssuesParams, ListTodosAcrossIssuesResult, ListTodosParams,
+    ListTodosResult, ManageLabelsParams, ManageLabelsResult, MoveToSprintParams,
+    MoveToSprintResult, NotifyIssueParams, NotifyIssueResult, PauseTodoWorkParams,
+    PauseTodoWorkResult, PollWatchQueryParams, PollWatchQueryResult, QueryWorkHistoryParams,
+    QueryWorkHistoryResult, SearchIssuesParams, SearchIssuesResult, SetReporterParams,
+    SetReporterResult, SetTodoBaseParams, SetTodoBaseResult, StartSprintParams, StartSprintResult,
+    StartTodoWorkParams, StartTodoWorkResult, ToolContext, ToolSet, TransitionIssueParams,
+    TransitionIssueResult, UndoLastChangeParams, UndoLastChangeResult, UpdateComponentsParams,
+    UpdateComponentsResult, UpdateCustomFieldsParams, UpdateCustomFieldsResult,
+    UpdateDescriptionMetadataParams, UpdateDescriptionMetadataResult, UpdateDescriptionParams,
+    UpdateDescriptionResult, UpdateDescriptionSectionParams, UpdateDescriptionSectionResult,
+    UpdateSecurityLevelParams, UpdateSecurityLevelResult, UpdateSprintParams, UpdateSprintResult,
+    UpdateSummaryParams, UpdateSummaryResult, UpdateTodoParams, UpdateTodoResult,
+    UploadAttachmentParams, UploadAttachmentResult, VoteResult,
 };
+use crate::undo::UndoHistory;
+use crate::warmup::{CacheWarmup, WarmupStatus};
+use crate::work_history::WorkHistory;
 
-use pulseengine_mcp_macros::{mcp_server, mcp_tools};
+use pulseengine_mcp_macros::mcp_tools;
+use pulseengine_mcp_server::McpToolsProvider;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use tokio::task::JoinHandle;
 use tracing::{error, info, instrument, warn};
 
 // Re-export modules for external use
+pub mod adf;
+pub mod audit;
+pub mod backend;
 pub mod cache;
+pub mod clock;
 pub mod config;
+pub mod connection;
+pub mod diagnostics;
 pub mod error;
+pub mod idempotency;
 pub mod jira_client;
+pub mod jql;
+pub mod mcp_backend;
+pub mod messages;
+pub mod metrics;
+pub mod redaction;
+pub mod request_log;
 pub mod semantic_mapping;
+pub mod shutdown;
+pub mod single_flight;
 pub mod tools;
+pub mod undo;
+pub mod warmup;
+pub mod work_history;
 
 /// Server status information
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -72,21 +122,102 @@ pub struct JiraServerStatus {
     pub uptime_seconds: u64,
     pub jira_url: String,
     pub jira_connection_status: String,
+    /// Last known connection state, its age and whether this call probed JIRA
+    pub connection: Option<ConnectionSnapshot>,
     pub authenticated_user: Option<String>,
+    /// Deployment type (Cloud, Server, Data Center) and JIRA version
+    pub deployment: ServerInfo,
+    /// Custom field IDs chosen for story points and acceptance criteria
+    pub detected_fields: FieldMap,
     pub cache_stats: cache::CacheStats,
+    /// Progress of the background cache warm-up
+    pub cache_warmup: WarmupStatus,
+    /// Undo entries currently held for undo_last_change
+    pub undo_entries: usize,
+    /// JIRA throttling and the remaining request budget
+    pub rate_limit: RateLimitStatus,
+    /// Rolling averages of the recent calls per tool (tools with a performance
+    /// block only)
+    pub tool_performance: BTreeMap<String, ToolPerformanceSummary>,
+    /// Whether search_issues' search_in works here (None until first used)
+    pub targeted_text_search: Option<bool>,
+    /// Tools the server registers, deprecated aliases included
     pub tools_count: usize,
 }
 
+/// Parameters for the get_server_status tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetServerStatusParams {
+    /// Probe JIRA even when the last known connection state is recent
+    /// (optional, default: false)
+    #[serde(default)]
+    pub force_check: bool,
+}
+
+/// A deprecated tool name still served for existing agent prompts
+#[derive(Debug, Clone, Copy)]
+pub struct ToolAlias {
+    /// The old tool name
+    pub alias: &'static str,
+    /// The tool that replaces it
+    pub target: &'static str,
+    /// Server version that deprecated the name
+    pub since: &'static str,
+}
+
+/// Deprecated tool names and their replacements, one line per alias
+///
+/// The #[mcp_tools] macro only registers methods, so each alias also needs a
+/// method delegating to its target and wrapping the result with `deprecated()`;
+/// `test_tool_aliases_are_served` keeps the two in sync.
+pub const TOOL_ALIASES: &[ToolAlias] = &[ToolAlias {
+    alias: "list_attachments",
+    target: "list_issue_attachments",
+    since: "0.10.0",
+}];
+
+/// Notice returned with results of a deprecated tool name
+pub fn deprecation_notice(alias: &str) -> Option<String> {
+    TOOL_ALIASES
+        .iter()
+        .find(|entry| entry.alias == alias)
+        .map(|entry| {
+            format!(
+                "'{}' is deprecated since {} and will be removed; call '{}' instead",
+                entry.alias, entry.since, entry.target
+            )
+        })
+}
+
+/// A tool result served under a deprecated name, with the notice alongside its fields
+#[derive(Debug, Clone, Serialize)]
+pub struct Deprecated<T> {
+    #[serde(flatten)]
+    pub result: T,
+
+    pub deprecation: String,
+}
+
+impl<T> Deprecated<T> {
+    pub fn into_inner(self) -> T {
+        self.result
+    }
+}
+
+impl<T> std::ops::Deref for Deprecated<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.result
+    }
+}
+
 /// JIRA MCP Server
 ///
 /// Main server implementation that provides AI-friendly tools for JIRA interaction.
-/// Uses the #[mcp_server] macro for automatic MCP infrastructure generation.
-#[mcp_server(
-    name = "JIRA MCP Server",
-    version = "0.7.0",
-    description = "AI-friendly JIRA integration server with semantic search, commenting, and relationship analysis capabilities",
-    auth = "disabled" // Start with disabled for development, can be changed to "file" for production
-)]
+/// The tools are the #[mcp_tools] methods below; the MCP protocol side, resources
+/// included, is in [`mcp_backend`].
 #[derive(Clone)]
 pub struct JiraMcpServer {
     /// Server start time for uptime calculation
@@ -101,37 +232,26 @@ pub struct JiraMcpServer {
     /// Metadata cache
     cache: Arc<MetadataCache>,
 
+    /// Audit trail of mutating tool calls
+    audit_log: Arc<AuditLog>,
+
+    /// Previous values of overwritten fields, for undo
+    undo_history: Arc<UndoHistory>,
+
+    /// Metadata prefetch, started once the server is serving
+    cache_warmup: Arc<CacheWarmup>,
+
     /// Tool implementations
-    search_tool: Arc<SearchIssuesTool>,
-    issue_details_tool: Arc<GetIssueDetailsTool>,
-    user_issues_tool: Arc<GetUserIssuesTool>,
-    list_attachments_tool: Arc<ListAttachmentsTool>,
-    download_attachment_tool: Arc<DownloadAttachmentTool>,
-    upload_attachment_tool: Arc<UploadAttachmentTool>,
-    add_comment_tool: Arc<AddCommentTool>,
-    issue_relationships_tool: Arc<IssueRelationshipsTool>,
-    update_description_tool: Arc<UpdateDescription>,
-    get_available_transitions_tool: Arc<GetAvailableTransitionsTool>,
-    transition_issue_tool: Arc<TransitionIssueTool>,
-    assign_issue_tool: Arc<AssignIssueTool>,
-    get_custom_fields_tool: Arc<GetCustomFieldsTool>,
-    update_custom_fields_tool: Arc<UpdateCustomFieldsTool>,
-    create_issue_tool: Arc<CreateIssueTool>,
-    get_create_metadata_tool: Arc<GetCreateMetadataTool>,
-    todo_tracker: Arc<TodoTracker>,
-    list_sprints_tool: Arc<ListSprintsTool>,
-    get_sprint_info_tool: Arc<GetSprintInfoTool>,
-    get_sprint_issues_tool: Arc<GetSprintIssuesTool>,
-    move_to_sprint_tool: Arc<MoveToSprintTool>,
-    create_sprint_tool: Arc<CreateSprintTool>,
-    start_sprint_tool: Arc<StartSprintTool>,
-    close_sprint_tool: Arc<CloseSprintTool>,
-    link_issues_tool: Arc<LinkIssuesTool>,
-    delete_issue_link_tool: Arc<DeleteIssueLinkTool>,
-    get_issue_link_types_tool: Arc<GetIssueLinkTypesTool>,
-    labels_tool: Arc<LabelsTool>,
-    components_tool: Arc<ComponentsTool>,
-    bulk_operations_tool: Arc<BulkOperationsTool>,
+    tools: ToolSet,
+
+    /// Call counts and latencies per tool
+    tool_stats: Arc<ToolStats>,
+
+    /// Background tasks started with the server
+    background_tasks: Arc<Vec<(&'static str, JoinHandle<()>)>>,
+
+    /// In-flight writes, waited for on shutdown
+    shutdown: Arc<ShutdownCoordinator>,
 }
 
 impl Default for JiraMcpServer {
@@ -149,14 +269,22 @@ impl JiraMcpServer {
         info!("Initializing JIRA MCP Server");
 
         // Load configuration
-        let config = Arc::new(JiraConfig::load()?);
+        let config = JiraConfig::load()?;
         info!("Configuration loaded successfully");
 
-        // Create cache
-        let cache = Arc::new(MetadataCache::new(config.cache_ttl_seconds));
+        Self::build(Arc::new(config)).await
+    }
+
+    /// Create server with custom configuration (for testing)
+    #[instrument(skip(config))]
+    pub async fn with_config(config: JiraConfig) -> JiraMcpResult<Self> {
+        Self::build(Arc::new(config)).await
+    }
 
-        // Start cache cleanup task
-        let _cleanup_handle = Arc::clone(&cache).start_cleanup_task();
+    /// Build the server, its tools and background tasks from a configuration
+    async fn build(config: Arc<JiraConfig>) -> JiraMcpResult<Self> {
+        // Create cache
+        let cache = Arc::new(MetadataCache::from_config(&config));
 
         // Create JIRA client
         let jira_client = Arc::new(JiraClient::new(Arc::clone(&config)).await?);
@@ -181,103 +309,34 @@ impl JiraMcpServer {
         }
 
         // Create tool implementations
-        let search_tool = Arc::new(SearchIssuesTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let issue_details_tool = Arc::new(GetIssueDetailsTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let user_issues_tool = Arc::new(GetUserIssuesTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let list_attachments_tool = Arc::new(ListAttachmentsTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let download_attachment_tool = Arc::new(DownloadAttachmentTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let upload_attachment_tool = Arc::new(UploadAttachmentTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let add_comment_tool = Arc::new(AddCommentTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
+        let context = ToolContext {
+            jira_client: Arc::clone(&jira_client),
+            config: Arc::clone(&config),
+            cache: Arc::clone(&cache),
+            undo_history: Arc::new(UndoHistory::from_config(&config)),
+            audit_log: Arc::new(AuditLog::new(config.state_dir())),
+            idempotency: Arc::new(IdempotencyStore::from_config(&config)),
+            work_history: Arc::new(WorkHistory::from_config(&config)),
+        };
+        let tools = ToolSet::build(&context);
 
-        let issue_relationships_tool = Arc::new(IssueRelationshipsTool::new(
+        let cache_warmup = Arc::new(CacheWarmup::new(
             Arc::clone(&jira_client),
-            Arc::clone(&config),
             Arc::clone(&cache),
+            &config,
         ));
 
-        let update_description_tool = Arc::new(UpdateDescription::new(Arc::clone(&jira_client)));
-
-        let get_available_transitions_tool =
-            Arc::new(GetAvailableTransitionsTool::new(Arc::clone(&jira_client)));
-
-        let transition_issue_tool = Arc::new(TransitionIssueTool::new(Arc::clone(&jira_client)));
-
-        let assign_issue_tool = Arc::new(AssignIssueTool::new(Arc::clone(&jira_client)));
-
-        let get_custom_fields_tool = Arc::new(GetCustomFieldsTool::new(Arc::clone(&jira_client)));
-
-        let update_custom_fields_tool =
-            Arc::new(UpdateCustomFieldsTool::new(Arc::clone(&jira_client)));
-
-        let create_issue_tool = Arc::new(CreateIssueTool::new(Arc::clone(&jira_client)));
-
-        let get_create_metadata_tool =
-            Arc::new(GetCreateMetadataTool::new(Arc::clone(&jira_client)));
-
-        let todo_tracker = Arc::new(TodoTracker::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
+        // Start cache cleanup and persistence, and auto-checkpoint (every 30 minutes)
+        let mut background_tasks = vec![("cache_cleanup", Arc::clone(&cache).start_cleanup_task())];
+        if let Some(handle) = Arc::clone(&cache)
+            .start_persist_task(Duration::from_secs(config.cache_persist_interval_seconds))
+        {
+            background_tasks.push(("cache_persist", handle));
+        }
+        background_tasks.push((
+            "auto_checkpoint",
+            Arc::clone(&tools.todo_tracker).start_auto_checkpoint_task(30),
         ));
-
-        // Sprint management tools
-        let list_sprints_tool = Arc::new(ListSprintsTool::new(Arc::clone(&jira_client)));
-        let get_sprint_info_tool = Arc::new(GetSprintInfoTool::new(Arc::clone(&jira_client)));
-        let get_sprint_issues_tool = Arc::new(GetSprintIssuesTool::new(Arc::clone(&jira_client)));
-        let move_to_sprint_tool = Arc::new(MoveToSprintTool::new(Arc::clone(&jira_client)));
-        let create_sprint_tool = Arc::new(CreateSprintTool::new(Arc::clone(&jira_client)));
-        let start_sprint_tool = Arc::new(StartSprintTool::new(Arc::clone(&jira_client)));
-        let close_sprint_tool = Arc::new(CloseSprintTool::new(Arc::clone(&jira_client)));
-
-        // Issue linking tools
-        let link_issues_tool = Arc::new(LinkIssuesTool::new(Arc::clone(&jira_client)));
-        let delete_issue_link_tool = Arc::new(DeleteIssueLinkTool::new(Arc::clone(&jira_client)));
-        let get_issue_link_types_tool =
-            Arc::new(GetIssueLinkTypesTool::new(Arc::clone(&jira_client)));
-
-        // Labels and components tools
-        let labels_tool = Arc::new(LabelsTool::new(Arc::clone(&jira_client)));
-        let components_tool = Arc::new(ComponentsTool::new(Arc::clone(&jira_client)));
-
-        // Bulk operations tool
-        let bulk_operations_tool = Arc::new(BulkOperationsTool::new(Arc::clone(&jira_client)));
-
-        // Start auto-checkpoint background task (every 30 minutes)
-        let _auto_checkpoint_handle = Arc::clone(&todo_tracker).start_auto_checkpoint_task(30);
         info!("Auto-checkpoint task started (interval: 30 minutes)");
 
         info!("JIRA MCP Server initialized successfully");
@@ -287,190 +346,183 @@ impl JiraMcpServer {
             jira_client,
             config,
             cache,
-            search_tool,
-            issue_details_tool,
-            user_issues_tool,
-            list_attachments_tool,
-            download_attachment_tool,
-            upload_attachment_tool,
-            add_comment_tool,
-            issue_relationships_tool,
-            update_description_tool,
-            get_available_transitions_tool,
-            transition_issue_tool,
-            assign_issue_tool,
-            get_custom_fields_tool,
-            update_custom_fields_tool,
-            create_issue_tool,
-            get_create_metadata_tool,
-            todo_tracker,
-            list_sprints_tool,
-            get_sprint_info_tool,
-            get_sprint_issues_tool,
-            move_to_sprint_tool,
-            create_sprint_tool,
-            start_sprint_tool,
-            close_sprint_tool,
-            link_issues_tool,
-            delete_issue_link_tool,
-            get_issue_link_types_tool,
-            labels_tool,
-            components_tool,
-            bulk_operations_tool,
+            audit_log: context.audit_log,
+            undo_history: context.undo_history,
+            cache_warmup,
+            tools,
+            tool_stats: Arc::new(ToolStats::default()),
+            background_tasks: Arc::new(background_tasks),
+            shutdown: Arc::new(ShutdownCoordinator::new()),
         })
     }
 
-    /// Create server with custom configuration (for testing)
-    #[instrument(skip(config))]
-    pub async fn with_config(config: JiraConfig) -> JiraMcpResult<Self> {
-        let config = Arc::new(config);
-        let cache = Arc::new(MetadataCache::new(config.cache_ttl_seconds));
-        let _cleanup_handle = Arc::clone(&cache).start_cleanup_task();
-
-        let jira_client = Arc::new(JiraClient::new(Arc::clone(&config)).await?);
+    /// Names of the background tasks still running
+    ///
+    /// The cache warm-up is not among them; it is started once the server is
+    /// serving (see [`Self::cache_warmup`]).
+    pub fn background_tasks(&self) -> Vec<&'static str> {
+        self.background_tasks
+            .iter()
+            .filter(|(_, handle)| !handle.is_finished())
+            .map(|(name, _)| *name)
+            .collect()
+    }
 
-        // Try to initialize current user
-        if let Ok(current_user) = jira_client.get_current_user().await {
-            let user_mapping = UserMapping {
-                account_id: current_user.account_id,
-                display_name: current_user.display_name,
-                email_address: current_user.email_address,
-                username: None,
-            };
-            let _ = cache.set_current_user(user_mapping);
+    /// Wrap a tool result in its [`ToolResponse`] envelope
+    ///
+    /// Every #[mcp_tools] method returning a structured result goes through here, so
+    /// agents see JIRA throttling (and a low request budget) on any call instead of
+    /// only through slower responses, and every result is held to
+    /// `max_response_chars`.
+    fn respond<T>(&self, result: T) -> ToolResponse<T> {
+        ToolResponse {
+            result,
+            rate_limit: self.jira_client.rate_limiter().backpressure(),
+            response_budget: self.config.max_response_chars,
         }
+    }
 
-        let search_tool = Arc::new(SearchIssuesTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let issue_details_tool = Arc::new(GetIssueDetailsTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let user_issues_tool = Arc::new(GetUserIssuesTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let list_attachments_tool = Arc::new(ListAttachmentsTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let download_attachment_tool = Arc::new(DownloadAttachmentTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let upload_attachment_tool = Arc::new(UploadAttachmentTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let add_comment_tool = Arc::new(AddCommentTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let issue_relationships_tool = Arc::new(IssueRelationshipsTool::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
-
-        let update_description_tool = Arc::new(UpdateDescription::new(Arc::clone(&jira_client)));
+    /// Wrap a result served under a deprecated tool name (see `TOOL_ALIASES`)
+    fn deprecated<T>(&self, alias: &str, result: T) -> Deprecated<T> {
+        let deprecation = deprecation_notice(alias)
+            .unwrap_or_else(|| format!("'{}' is deprecated and will be removed", alias));
+        warn!("Deprecated tool name called: {}", deprecation);
+        Deprecated {
+            result,
+            deprecation,
+        }
+    }
 
-        let get_available_transitions_tool =
-            Arc::new(GetAvailableTransitionsTool::new(Arc::clone(&jira_client)));
+    /// Run a mutating tool call and append it to the audit log
+    ///
+    /// Every #[mcp_tools] method that changes JIRA goes through here, so new tools
+    /// get auditing, cache invalidation (and the usual error logging) by using it.
+    /// The call counts as in flight for shutdown until it was audited, and is
+    /// refused once shutdown began.
+    async fn audited<P, R, F, Fut>(&self, tool: &str, params: P, run: F) -> ToolResult<R>
+    where
+        P: Serialize,
+        R: Serialize,
+        F: FnOnce(P) -> Fut,
+        Fut: std::future::Future<Output = JiraMcpResult<R>>,
+    {
+        let _write = self.shutdown.begin_write(tool).map_err(|e| {
+            warn!("{} refused: {}", tool, e);
+            ToolError::from(e)
+        })?;
+        let params_json = serde_json::to_value(&params).unwrap_or_default();
+        let started = Instant::now();
+        let result = match self
+            .tools
+            .my_permissions_tool
+            .precheck(tool, &params_json)
+            .await
+        {
+            Ok(()) => run(params).await,
+            Err(e) => Err(e),
+        };
 
-        let transition_issue_tool = Arc::new(TransitionIssueTool::new(Arc::clone(&jira_client)));
+        match &result {
+            Ok(value) => {
+                let result_json = serde_json::to_value(value).unwrap_or_default();
+                self.invalidate_after_write(&params_json, &result_json);
+                self.audit_log
+                    .record(tool, &params_json, Ok(&result_json), started.elapsed());
+            }
+            Err(e) => {
+                self.audit_log
+                    .record(tool, &params_json, Err(&e.to_string()), started.elapsed());
+            }
+        }
 
-        let assign_issue_tool = Arc::new(AssignIssueTool::new(Arc::clone(&jira_client)));
+        result.map_err(|e| {
+            error!("{} failed: {}", tool, e);
+            ToolError::from(e)
+        })
+    }
 
-        let get_custom_fields_tool = Arc::new(GetCustomFieldsTool::new(Arc::clone(&jira_client)));
+    /// Run a tool call under [`metrics::measure`], fill in the result's
+    /// performance block and add the call to the averages in get_server_status
+    async fn measured<R, Fut>(&self, tool: &str, call: Fut) -> JiraMcpResult<R>
+    where
+        R: Measured,
+        Fut: std::future::Future<Output = JiraMcpResult<R>>,
+    {
+        let (result, performance) = metrics::measure(call).await;
+        self.tool_stats.record(tool, &performance);
+        result.map(|mut result| {
+            result.set_performance(performance);
+            result
+        })
+    }
 
-        let update_custom_fields_tool =
-            Arc::new(UpdateCustomFieldsTool::new(Arc::clone(&jira_client)));
+    /// Drop cached data a successful write may have made stale
+    ///
+    /// Cached details of the touched issues go, and so does the label list when
+    /// labels were added, since JIRA creates new labels on first use.
+    fn invalidate_after_write(&self, params: &serde_json::Value, result: &serde_json::Value) {
+        let mut issue_keys = Vec::new();
+        collect_issue_keys(params, &mut issue_keys);
+        collect_issue_keys(result, &mut issue_keys);
+        if !issue_keys.is_empty() {
+            self.cache
+                .invalidate(&[CacheCategory::IssueDetails], &issue_keys);
+        }
 
-        let create_issue_tool = Arc::new(CreateIssueTool::new(Arc::clone(&jira_client)));
+        if adds_labels(params) {
+            self.cache.invalidate_labels();
+        }
+    }
 
-        let get_create_metadata_tool =
-            Arc::new(GetCreateMetadataTool::new(Arc::clone(&jira_client)));
+    /// The mock backend serving JIRA fixtures, when running in mock mode
+    ///
+    /// Tests use it to inspect the writes tools made.
+    pub fn mock_backend(&self) -> Option<Arc<MockBackend>> {
+        self.jira_client.mock_backend()
+    }
 
-        let todo_tracker = Arc::new(TodoTracker::new(
-            Arc::clone(&jira_client),
-            Arc::clone(&config),
-            Arc::clone(&cache),
-        ));
+    /// The metadata cache, so it can be persisted on shutdown
+    pub fn metadata_cache(&self) -> Arc<MetadataCache> {
+        Arc::clone(&self.cache)
+    }
 
-        // Sprint management tools
-        let list_sprints_tool = Arc::new(ListSprintsTool::new(Arc::clone(&jira_client)));
-        let get_sprint_info_tool = Arc::new(GetSprintInfoTool::new(Arc::clone(&jira_client)));
-        let get_sprint_issues_tool = Arc::new(GetSprintIssuesTool::new(Arc::clone(&jira_client)));
-        let move_to_sprint_tool = Arc::new(MoveToSprintTool::new(Arc::clone(&jira_client)));
-        let create_sprint_tool = Arc::new(CreateSprintTool::new(Arc::clone(&jira_client)));
-        let start_sprint_tool = Arc::new(StartSprintTool::new(Arc::clone(&jira_client)));
-        let close_sprint_tool = Arc::new(CloseSprintTool::new(Arc::clone(&jira_client)));
+    /// The cache warm-up, to be started once the server is serving
+    pub fn cache_warmup(&self) -> Arc<CacheWarmup> {
+        Arc::clone(&self.cache_warmup)
+    }
 
-        // Issue linking tools
-        let link_issues_tool = Arc::new(LinkIssuesTool::new(Arc::clone(&jira_client)));
-        let delete_issue_link_tool = Arc::new(DeleteIssueLinkTool::new(Arc::clone(&jira_client)));
-        let get_issue_link_types_tool =
-            Arc::new(GetIssueLinkTypesTool::new(Arc::clone(&jira_client)));
+    /// The coordinator tracking in-flight writes
+    pub fn shutdown_coordinator(&self) -> Arc<ShutdownCoordinator> {
+        Arc::clone(&self.shutdown)
+    }
 
-        // Labels and components tools
-        let labels_tool = Arc::new(LabelsTool::new(Arc::clone(&jira_client)));
-        let components_tool = Arc::new(ComponentsTool::new(Arc::clone(&jira_client)));
+    /// Shut down without cutting writes short
+    ///
+    /// Refuses new writes, waits up to `shutdown_timeout_seconds` for the
+    /// running ones (each is audited when it finishes), then saves the active
+    /// work sessions and the cache. Returns the writes that did not finish in
+    /// time.
+    pub async fn shutdown(&self) -> usize {
+        let timeout = Duration::from_secs(self.config.shutdown_timeout_seconds);
+        let in_flight = self.shutdown.in_flight();
+        if in_flight > 0 {
+            info!("Waiting for {} in-flight writes to finish", in_flight);
+        }
 
-        // Bulk operations tool
-        let bulk_operations_tool = Arc::new(BulkOperationsTool::new(Arc::clone(&jira_client)));
+        let unfinished = self.shutdown.drain(timeout).await;
+        if unfinished > 0 {
+            warn!(
+                "{} writes still running after {}s, shutting down anyway",
+                unfinished,
+                timeout.as_secs()
+            );
+        }
 
-        Ok(Self {
-            start_time: Instant::now(),
-            jira_client,
-            config,
-            cache,
-            search_tool,
-            issue_details_tool,
-            user_issues_tool,
-            list_attachments_tool,
-            download_attachment_tool,
-            upload_attachment_tool,
-            add_comment_tool,
-            issue_relationships_tool,
-            update_description_tool,
-            get_available_transitions_tool,
-            transition_issue_tool,
-            assign_issue_tool,
-            get_custom_fields_tool,
-            update_custom_fields_tool,
-            create_issue_tool,
-            get_create_metadata_tool,
-            todo_tracker,
-            list_sprints_tool,
-            get_sprint_info_tool,
-            get_sprint_issues_tool,
-            move_to_sprint_tool,
-            create_sprint_tool,
-            start_sprint_tool,
-            close_sprint_tool,
-            link_issues_tool,
-            delete_issue_link_tool,
-            get_issue_link_types_tool,
-            labels_tool,
-            components_tool,
-            bulk_operations_tool,
-        })
+        if let Err(e) = self.tools.todo_tracker.persist_sessions().await {
+            warn!("{}", e);
+        }
+        self.cache.persist();
+        unfinished
     }
 
     /// Get server uptime in seconds
@@ -502,16 +554,28 @@ impl JiraMcpServer {
     /// # Examples
     /// - Find all stories assigned to me: `{"issue_types": ["story"], "assigned_to": "me"}`
     /// - Find bugs in project FOO: `{"issue_types": ["bug"], "project_key": "FOO"}`
+    /// - Exact phrase in summaries only: `{"query_text": "login fails", "search_in": ["summary"]}`
+    /// - Unassigned bugs Jane reported: `{"issue_types": ["bug"], "assigned_to": "unassigned", "reporter": "jane@example.com"}`
+    /// - What I looked at recently: `{"recent": true}`; what I created: `{"created_by": "me"}`
     /// - Find overdue issues: `{"status": ["open"], "created_after": "30 days ago"}`
+    /// - Oldest bugs first: `{"issue_types": ["bug"], "order_by": {"field": "created", "direction": "asc"}}`
+    /// - Sprint board by status: `{"project_key": "FOO", "group_by": "status"}`
+    /// - Urgent open work: `{"status": ["open"], "priority_filter": ["critical", "high"]}`
+    /// - Open work in an epic or sprint: `{"within": {"epic": "FOO-12"}, "status": ["open"]}`, `{"within": {"sprint": 42}}`
     #[instrument(skip(self))]
     pub async fn search_issues(
         &self,
-        params: SearchIssuesParams,
-    ) -> anyhow::Result<SearchIssuesResult> {
-        self.search_tool.execute(params).await.map_err(|e| {
-            error!("search_issues failed: {}", e);
-            anyhow::anyhow!(e)
-        })
+        params: Validated<SearchIssuesParams>,
+    ) -> ToolResult<ToolResponse<SearchIssuesResult>> {
+        let params = params.into_inner();
+        let response_budget = params.response_budget;
+        self.measured("search_issues", self.tools.search_tool.execute(params))
+            .await
+            .map_err(|e| {
+                error!("search_issues failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result).with_response_budget(response_budget))
     }
 
     /// Get detailed information about a specific JIRA issue
@@ -523,54 +587,105 @@ impl JiraMcpServer {
     /// - Get basic issue info: `{"issue_key": "PROJ-123"}`
     /// - Get issue with comments: `{"issue_key": "PROJ-123", "include_comments": true}`
     /// - Get full issue details: `{"issue_key": "PROJ-123", "include_comments": true, "include_attachments": true, "include_history": true}`
+    /// - Get an issue with full subtasks and linked issues: `{"issue_key": "PROJ-123", "expand_subtasks": true, "expand_linked": true}`
     #[instrument(skip(self))]
     pub async fn get_issue_details(
         &self,
         params: GetIssueDetailsParams,
-    ) -> anyhow::Result<GetIssueDetailsResult> {
-        self.issue_details_tool.execute(params).await.map_err(|e| {
+    ) -> ToolResult<ToolResponse<GetIssueDetailsResult>> {
+        let response_budget = params.response_budget;
+        self.measured(
+            "get_issue_details",
+            self.tools.issue_details_tool.execute(params),
+        )
+        .await
+        .map_err(|e| {
             error!("get_issue_details failed: {}", e);
-            anyhow::anyhow!(e)
+            ToolError::from(e)
         })
+        .map(|result| self.respond(result).with_response_budget(response_budget))
     }
 
     /// Get issues assigned to a specific user with filtering options
     ///
     /// Retrieves issues assigned to a user (defaults to current user) with various
     /// semantic filtering options for status, type, project, priority, and dates.
+    /// Several users, unassigned issues, and reporter/watcher roles are also supported.
     ///
     /// # Examples
     /// - Get my open issues: `{"status_filter": ["open", "in_progress"]}`
     /// - Get user's bugs: `{"username": "john.doe", "issue_types": ["bug"]}`
     /// - Get overdue issues: `{"due_date_filter": "overdue", "priority_filter": ["high"]}`
+    /// - Most urgent first: `{"status_filter": ["open"], "order_by": {"field": "priority"}}`
+    /// - Triage unassigned bugs: `{"username": "unassigned", "issue_types": ["bug"]}`
+    /// - Issues I reported: `{"role": "reporter"}`
+    /// - My work by project: `{"status_filter": ["open", "in_progress"], "group_by": "project"}`
+    /// - Team view: `{"usernames": ["me", "john.doe", "unassigned"]}`
+    /// - My issues about login: `{"status_filter": ["open"], "text_filter": "login"}`
     #[instrument(skip(self))]
     pub async fn get_user_issues(
         &self,
         params: GetUserIssuesParams,
-    ) -> anyhow::Result<GetUserIssuesResult> {
-        self.user_issues_tool.execute(params).await.map_err(|e| {
+    ) -> ToolResult<ToolResponse<GetUserIssuesResult>> {
+        let response_budget = params.response_budget;
+        self.measured(
+            "get_user_issues",
+            self.tools.user_issues_tool.execute(params),
+        )
+        .await
+        .map_err(|e| {
             error!("get_user_issues failed: {}", e);
-            anyhow::anyhow!(e)
+            ToolError::from(e)
         })
+        .map(|result| self.respond(result).with_response_budget(response_budget))
     }
 
     /// Get server status and connection information
     ///
     /// Returns comprehensive information about the server status, JIRA connection,
-    /// authenticated user, cache statistics, and available tools.
+    /// authenticated user, the custom fields used for story points and acceptance
+    /// criteria (`detected_fields`), cache statistics, and available tools.
+    ///
+    /// The connection status is the outcome of the last JIRA request, with its
+    /// age. JIRA is only probed when that is older than
+    /// `connection_check_interval_seconds` or with `force_check`.
+    ///
+    /// # Examples
+    /// - Status from the last known connection state: `{}`
+    /// - Check the connection now: `{"force_check": true}`
     #[instrument(skip(self))]
-    pub async fn get_server_status(&self) -> anyhow::Result<JiraServerStatus> {
+    pub async fn get_server_status(
+        &self,
+        params: GetServerStatusParams,
+    ) -> ToolResult<JiraServerStatus> {
         info!("Getting server status");
 
-        let connection_status = match self.jira_client.get_current_user().await {
-            Ok(_) => "Connected".to_string(),
-            Err(e) => format!("Connection Error: {}", e),
-        };
-
-        let authenticated_user = if connection_status == "Connected" {
-            Some(self.get_current_user_name().await)
-        } else {
-            None
+        let connection_state = self.jira_client.connection_state();
+        let max_age = Duration::from_secs(self.config.connection_check_interval_seconds);
+        let probed = params.force_check || connection_state.is_stale(max_age);
+        if probed {
+            // The probe updates the connection state like any other request
+            if let Err(e) = self.refresh_current_user_cache().await {
+                warn!("Connection check failed: {}", e);
+            }
+        }
+        let connection = connection_state
+            .snapshot()
+            .map(|snapshot| ConnectionSnapshot { probed, ..snapshot });
+
+        let (connection_status, authenticated_user) = match &connection {
+            Some(snapshot) if snapshot.connected => (
+                "Connected".to_string(),
+                Some(self.get_current_user_name().await),
+            ),
+            Some(snapshot) => (
+                format!(
+                    "Connection Error: {}",
+                    snapshot.last_error.as_deref().unwrap_or("unknown")
+                ),
+                None,
+            ),
+            None => ("Unknown".to_string(), None),
         };
 
         Ok(JiraServerStatus {
@@ -579,9 +694,17 @@ impl JiraMcpServer {
             uptime_seconds: self.get_uptime_seconds(),
             jira_url: self.config.jira_url.clone(),
             jira_connection_status: connection_status,
+            connection,
             authenticated_user,
+            deployment: self.jira_client.server_info().clone(),
+            detected_fields: self.jira_client.field_map().clone(),
             cache_stats: self.cache.get_stats(),
-            tools_count: 48, // search_issues, get_issue_details, get_user_issues, list_issue_attachments, download_attachment, upload_attachment, get_server_status, clear_cache, test_connection, add_comment, update_issue_description, get_issue_relationships, get_available_transitions, transition_issue, assign_issue, get_custom_fields, update_custom_fields, create_issue, get_create_metadata, list_todos, add_todo, update_todo, start_todo_work, complete_todo_work, checkpoint_todo_work, pause_todo_work, cancel_todo_work, get_active_work_sessions, set_todo_base, list_sprints, get_sprint_info, get_sprint_issues, move_to_sprint, create_sprint, start_sprint, close_sprint, link_issues, delete_issue_link, get_issue_link_types, manage_labels, get_available_labels, update_components, get_available_components, bulk_create_issues, bulk_transition_issues, bulk_update_fields, bulk_assign_issues, bulk_add_labels
+            cache_warmup: self.cache_warmup.status(),
+            undo_entries: self.undo_history.len(),
+            rate_limit: self.jira_client.rate_limiter().status(),
+            tool_performance: self.tool_stats.summary(),
+            targeted_text_search: self.jira_client.targeted_text_search(),
+            tools_count: <Self as McpToolsProvider>::get_available_tools(self).len(),
         })
     }
 
@@ -589,8 +712,9 @@ impl JiraMcpServer {
     ///
     /// Clears all cached metadata including board mappings, project info, user info,
     /// and issue types. Useful when JIRA configuration changes or for troubleshooting.
+    /// Use clear_cache_scope to refresh only part of it.
     #[instrument(skip(self))]
-    pub async fn clear_cache(&self) -> anyhow::Result<String> {
+    pub async fn clear_cache(&self) -> ToolResult<String> {
         info!("Clearing all cached metadata");
 
         match self.cache.clear_all() {
@@ -600,7 +724,7 @@ impl JiraMcpServer {
             }
             Err(e) => {
                 error!("Failed to clear cache: {}", e);
-                Err(anyhow::anyhow!("Failed to clear cache: {}", e))
+                Err(JiraMcpError::cache(format!("Failed to clear cache: {}", e)).into())
             }
         }
     }
@@ -616,36 +740,55 @@ impl JiraMcpServer {
     pub async fn list_issue_attachments(
         &self,
         params: ListAttachmentsParams,
-    ) -> anyhow::Result<ListAttachmentsResult> {
-        self.list_attachments_tool
+    ) -> ToolResult<ToolResponse<ListAttachmentsResult>> {
+        self.tools
+            .list_attachments_tool
             .execute(params)
             .await
             .map_err(|e| {
                 error!("list_issue_attachments failed: {}", e);
-                anyhow::anyhow!(e)
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
+    }
+
+    /// Deprecated: use list_issue_attachments
+    ///
+    /// Same parameters and result as list_issue_attachments, plus a `deprecation`
+    /// notice naming the replacement.
+    #[instrument(skip(self))]
+    pub async fn list_attachments(
+        &self,
+        params: ListAttachmentsParams,
+    ) -> ToolResult<Deprecated<ToolResponse<ListAttachmentsResult>>> {
+        self.list_issue_attachments(params)
+            .await
+            .map(|result| self.deprecated("list_attachments", result))
     }
 
     /// Download attachment content from a JIRA issue
     ///
-    /// Downloads the actual content of an attachment given its attachment ID.
-    /// Content is returned as base64 encoded string by default for safety.
+    /// Downloads the actual content of an attachment given its attachment ID or the
+    /// content URL returned by list_issue_attachments. Content is returned as base64 encoded string by default for safety.
     ///
     /// # Examples
     /// - Download attachment: `{"attachment_id": "12345"}`
+    /// - Download by content URL: `{"content_url": "https://your-domain.atlassian.net/rest/api/3/attachment/content/12345"}`
     /// - Download with size limit: `{"attachment_id": "12345", "max_size_bytes": 5242880}`
     /// - Download as raw content: `{"attachment_id": "12345", "base64_encoded": false}`
     pub async fn download_attachment(
         &self,
         params: DownloadAttachmentParams,
-    ) -> anyhow::Result<DownloadAttachmentResult> {
-        self.download_attachment_tool
+    ) -> ToolResult<ToolResponse<DownloadAttachmentResult>> {
+        self.tools
+            .download_attachment_tool
             .execute(params)
             .await
             .map_err(|e| {
                 error!("download_attachment failed: {}", e);
-                anyhow::anyhow!(e)
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
     }
 
     /// Upload attachments to a JIRA issue
@@ -666,14 +809,12 @@ impl JiraMcpServer {
     pub async fn upload_attachment(
         &self,
         params: UploadAttachmentParams,
-    ) -> anyhow::Result<UploadAttachmentResult> {
-        self.upload_attachment_tool
-            .execute(params)
-            .await
-            .map_err(|e| {
-                error!("upload_attachment failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<UploadAttachmentResult>> {
+        self.audited("upload_attachment", params, |params| {
+            self.tools.upload_attachment_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Test JIRA connection and authentication
@@ -681,7 +822,7 @@ impl JiraMcpServer {
     /// Performs a connection test to the configured JIRA instance and returns
     /// detailed information about the connection status and authenticated user.
     #[instrument(skip(self))]
-    pub async fn test_connection(&self) -> anyhow::Result<String> {
+    pub async fn test_connection(&self) -> ToolResult<String> {
         info!("Testing JIRA connection");
 
         match self.jira_client.get_current_user().await {
@@ -719,6 +860,32 @@ impl JiraMcpServer {
         }
     }
 
+    /// Diagnose configuration, connection and permissions
+    ///
+    /// Lists the resolved settings with secrets masked, checks that JIRA accepts
+    /// the credentials, and asks JIRA whether the user may browse, comment,
+    /// transition, edit and create issues, in one project or in any. Each
+    /// problem comes with a remediation hint. The same report is printed by
+    /// `jira-mcp-server --check-config`.
+    ///
+    /// # Examples
+    /// - Check permissions in any project: `{}`
+    /// - Check permissions in one project: `{"project_key": "PROJ"}`
+    #[instrument(skip(self))]
+    pub async fn run_diagnostics(
+        &self,
+        params: RunDiagnosticsParams,
+    ) -> ToolResult<DiagnosticsReport> {
+        info!("Running diagnostics");
+
+        Ok(diagnostics::diagnose(
+            &self.jira_client,
+            &self.config,
+            params.project_key.as_deref(),
+        )
+        .await)
+    }
+
     /// Add a comment to a JIRA issue
     ///
     /// Adds a comment to the specified JIRA issue with the provided text content.
@@ -728,12 +895,22 @@ impl JiraMcpServer {
     /// # Examples
     /// - Add a simple comment: `{"issue_key": "PROJ-123", "comment_body": "This looks good to me!"}`
     /// - Add a detailed comment: `{"issue_key": "PROJ-123", "comment_body": "I've tested this feature and found the following:\n\n1. Works as expected\n2. Performance is good\n3. Ready for deployment"}`
+    /// - Restrict to a role: `{"issue_key": "PROJ-123", "comment_body": "Root cause is in the auth service", "visibility": {"type": "role", "value": "Developers"}}`
+    /// - JSM internal note: `{"issue_key": "HELP-42", "comment_body": "Customer is on the legacy plan", "jsm_internal": true}`
+    /// - Mention a teammate: `{"issue_key": "PROJ-123", "comment_body": "can you review?", "mentions": ["jdoe"]}`
+    /// - Formatted on Cloud: `{"issue_key": "PROJ-123", "comment_body": "Fixed by:\n\n```bash\nmake migrate\n```", "adf": true}`
+    /// - Safe to retry: `{"issue_key": "PROJ-123", "comment_body": "Deployed 2.4.1", "idempotency_key": "deploy-2.4.1-PROJ-123"}`
+    /// - Long log excerpt, cut to max_comment_chars: `{"issue_key": "PROJ-123", "comment_body": "<log>", "auto_truncate": true}`
     #[instrument(skip(self))]
-    pub async fn add_comment(&self, params: AddCommentParams) -> anyhow::Result<AddCommentResult> {
-        self.add_comment_tool.execute(params).await.map_err(|e| {
-            error!("add_comment failed: {}", e);
-            anyhow::anyhow!(e)
+    pub async fn add_comment(
+        &self,
+        params: AddCommentParams,
+    ) -> ToolResult<ToolResponse<AddCommentResult>> {
+        self.audited("add_comment", params, |params| {
+            self.tools.add_comment_tool.execute(params)
         })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Update the description of a JIRA issue
@@ -743,22 +920,31 @@ impl JiraMcpServer {
     /// - prepend: Adds content to the beginning of the existing description
     /// - replace: Completely replaces the description with new content
     ///
+    /// The stored format is kept: Cloud ADF documents get new paragraph nodes,
+    /// wiki/markdown text is joined with a single blank line. `separator` adds a
+    /// horizontal rule ("rule") or a rule plus an update timestamp ("timestamped_rule").
+    /// To change a single section, use update_description_section instead.
+    /// `expected_updated` (from get_issue_details) refuses the write if the issue
+    /// changed since you read it. A description over max_description_chars
+    /// (default 32767) fails before it is sent; `auto_truncate` cuts it instead and
+    /// reports the cut characters in `truncated_chars`.
+    ///
     /// # Examples
     /// - Append to description: `{"issue_key": "PROJ-123", "content": "Additional context: This fixes the login issue"}`
     /// - Replace description: `{"issue_key": "PROJ-123", "content": "New complete description", "mode": "replace"}`
     /// - Prepend to description: `{"issue_key": "PROJ-123", "content": "⚠️ URGENT: ", "mode": "prepend"}`
+    /// - Append a timestamped log entry: `{"issue_key": "PROJ-123", "content": "Deployed to staging", "separator": "timestamped_rule"}`
+    /// - Preview a rewrite as a diff without writing: `{"issue_key": "PROJ-123", "content": "New description", "mode": "replace", "dry_run": true}`
     #[instrument(skip(self))]
     pub async fn update_issue_description(
         &self,
         params: UpdateDescriptionParams,
-    ) -> anyhow::Result<UpdateDescriptionResult> {
-        self.update_description_tool
-            .execute(params)
-            .await
-            .map_err(|e| {
-                error!("update_issue_description failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<UpdateDescriptionResult>> {
+        self.audited("update_issue_description", params, |params| {
+            self.tools.update_description_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Extract issue relationship graph
@@ -771,18 +957,23 @@ impl JiraMcpServer {
     /// - Basic relationship extraction: `{"root_issue_key": "PROJ-123"}`
     /// - Deep relationship analysis: `{"root_issue_key": "PROJ-123", "max_depth": 3}`
     /// - Custom relationship filters: `{"root_issue_key": "PROJ-123", "include_duplicates": true, "include_epic_links": false}`
+    /// - Within 5 seconds, in parts: `{"root_issue_key": "PROJ-123", "max_depth": 3, "time_budget_ms": 5000}`,
+    ///   then again with `"continuation_token"` from the result while `partial` is true
     #[instrument(skip(self))]
     pub async fn get_issue_relationships(
         &self,
         params: IssueRelationshipsParams,
-    ) -> anyhow::Result<IssueRelationshipsResult> {
-        self.issue_relationships_tool
-            .execute(params)
-            .await
-            .map_err(|e| {
-                error!("get_issue_relationships failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<IssueRelationshipsResult>> {
+        self.measured(
+            "get_issue_relationships",
+            self.tools.issue_relationships_tool.execute(params),
+        )
+        .await
+        .map_err(|e| {
+            error!("get_issue_relationships failed: {}", e);
+            ToolError::from(e)
+        })
+        .map(|result| self.respond(result))
     }
 
     /// Get available transitions for an issue
@@ -797,14 +988,16 @@ impl JiraMcpServer {
     pub async fn get_available_transitions(
         &self,
         params: GetAvailableTransitionsParams,
-    ) -> anyhow::Result<GetAvailableTransitionsResult> {
-        self.get_available_transitions_tool
+    ) -> ToolResult<ToolResponse<GetAvailableTransitionsResult>> {
+        self.tools
+            .get_available_transitions_tool
             .execute(params)
             .await
             .map_err(|e| {
                 error!("get_available_transitions failed: {}", e);
-                anyhow::anyhow!(e)
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
     }
 
     /// Transition an issue to a new status
@@ -813,23 +1006,31 @@ impl JiraMcpServer {
     /// You can specify the transition either by ID or by name. Optionally add
     /// a comment and/or set a resolution when transitioning.
     ///
+    /// With `track_time` (or `todos.track_time_on_transition` in the config), a
+    /// move to an in-progress status starts an issue work session and a move to
+    /// done logs and ends it; `work_session` in the result says which happened.
+    ///
+    /// When JIRA refuses the transition because it requires a resolution and
+    /// none was given, the configured `default_resolution` is applied and the
+    /// transition retried once (`default_resolution_applied` in the result).
+    /// Without a default, the error lists the resolutions the transition allows.
+    ///
     /// # Examples
     /// - Transition by name: `{"issue_key": "PROJ-123", "transition_name": "Start Progress"}`
     /// - Transition by ID: `{"issue_key": "PROJ-123", "transition_id": "11"}`
     /// - Transition with comment: `{"issue_key": "PROJ-123", "transition_name": "Done", "comment": "Work completed"}`
     /// - Transition with resolution: `{"issue_key": "PROJ-123", "transition_name": "Done", "resolution": "Fixed"}`
+    /// - Finish tracked work: `{"issue_key": "PROJ-123", "transition_name": "Done", "track_time": true, "time_spent_hours": 3}`
     #[instrument(skip(self))]
     pub async fn transition_issue(
         &self,
         params: TransitionIssueParams,
-    ) -> anyhow::Result<TransitionIssueResult> {
-        self.transition_issue_tool
-            .execute(params)
-            .await
-            .map_err(|e| {
-                error!("transition_issue failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<TransitionIssueResult>> {
+        self.audited("transition_issue", params, |params| {
+            self.tools.transition_issue_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Assign a JIRA issue to a user
@@ -837,6 +1038,9 @@ impl JiraMcpServer {
     /// Assigns an issue to a specific user or unassigns it. You can use:
     /// - "me" or "self" to assign to yourself
     /// - A specific username or account ID
+    /// - An email address or display name; an exact email match wins, then an
+    ///   exact display name. Ambiguous names fail with the candidates and their
+    ///   account IDs, and the result's `resolved` shows who was picked
     /// - null/empty to unassign the issue
     ///
     /// This is particularly useful for:
@@ -847,23 +1051,27 @@ impl JiraMcpServer {
     /// # Examples
     /// - Assign to yourself: `{"issue_key": "PROJ-123", "assignee": "me"}`
     /// - Assign to user: `{"issue_key": "PROJ-123", "assignee": "john.doe@example.com"}`
+    /// - Assign by name: `{"issue_key": "PROJ-123", "assignee": "John Doe"}`
     /// - Unassign: `{"issue_key": "PROJ-123", "assignee": null}`
     #[instrument(skip(self))]
     pub async fn assign_issue(
         &self,
         params: AssignIssueParams,
-    ) -> anyhow::Result<AssignIssueResult> {
-        self.assign_issue_tool.execute(params).await.map_err(|e| {
-            error!("assign_issue failed: {}", e);
-            anyhow::anyhow!(e)
+    ) -> ToolResult<ToolResponse<AssignIssueResult>> {
+        self.audited("assign_issue", params, |params| {
+            self.tools.assign_issue_tool.execute(params)
         })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Get custom fields from a JIRA issue
     ///
     /// Discovers and returns all custom fields present in a JIRA issue, including
     /// their field IDs, types, current values, and human-readable displays.
-    /// Also attempts to detect common fields like story points and acceptance criteria.
+    /// Each field says whether it is on the edit screen (`editable`) and, if so,
+    /// which operations and values it accepts. Also attempts to detect common
+    /// fields like story points and acceptance criteria.
     ///
     /// This is useful for:
     /// - Understanding what custom fields are available in your JIRA instance
@@ -876,14 +1084,16 @@ impl JiraMcpServer {
     pub async fn get_custom_fields(
         &self,
         params: GetCustomFieldsParams,
-    ) -> anyhow::Result<GetCustomFieldsResult> {
-        self.get_custom_fields_tool
+    ) -> ToolResult<ToolResponse<GetCustomFieldsResult>> {
+        self.tools
+            .get_custom_fields_tool
             .execute(params)
             .await
             .map_err(|e| {
                 error!("get_custom_fields failed: {}", e);
-                anyhow::anyhow!(e)
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
     }
 
     /// Update custom fields in a JIRA issue
@@ -896,24 +1106,30 @@ impl JiraMcpServer {
     /// - Update story points using auto-detection or explicit field ID
     /// - Update acceptance criteria using auto-detection or explicit field ID
     /// - Update any custom field by providing its field ID and value
+    /// - Set the due date and start date (ISO or relative, e.g. "next friday", "+2w");
+    ///   the due date may not be before the start date
+    ///
+    /// Fields missing from the issue's edit screen are rejected before anything is
+    /// written.
     ///
     /// # Examples
     /// - Set story points: `{"issue_key": "PROJ-123", "story_points": 5}`
     /// - Set acceptance criteria: `{"issue_key": "PROJ-123", "acceptance_criteria": "User can login successfully"}`
     /// - Update specific field: `{"issue_key": "PROJ-123", "custom_field_updates": {"customfield_10050": "value"}}`
     /// - Override field ID: `{"issue_key": "PROJ-123", "story_points": 8, "story_points_field_id": "customfield_10016"}`
+    /// - Set due date: `{"issue_key": "PROJ-123", "due_date": "next friday"}`
+    /// - Set start and due dates: `{"issue_key": "PROJ-123", "start_date": "2025-03-10", "due_date": "+2w"}`
+    /// - Clear due date: `{"issue_key": "PROJ-123", "due_date": "none"}`
     #[instrument(skip(self))]
     pub async fn update_custom_fields(
         &self,
-        params: UpdateCustomFieldsParams,
-    ) -> anyhow::Result<UpdateCustomFieldsResult> {
-        self.update_custom_fields_tool
-            .execute(params)
-            .await
-            .map_err(|e| {
-                error!("update_custom_fields failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+        params: Validated<UpdateCustomFieldsParams>,
+    ) -> ToolResult<ToolResponse<UpdateCustomFieldsResult>> {
+        self.audited("update_custom_fields", params.into_inner(), |params| {
+            self.tools.update_custom_fields_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Get issue creation metadata for a JIRA project
@@ -936,14 +1152,16 @@ impl JiraMcpServer {
     pub async fn get_create_metadata(
         &self,
         params: GetCreateMetadataParams,
-    ) -> anyhow::Result<GetCreateMetadataResult> {
-        self.get_create_metadata_tool
+    ) -> ToolResult<ToolResponse<GetCreateMetadataResult>> {
+        self.tools
+            .get_create_metadata_tool
             .execute(params)
             .await
             .map_err(|e| {
                 error!("get_create_metadata failed: {}", e);
-                anyhow::anyhow!(e)
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
     }
 
     /// Create a new JIRA issue
@@ -954,7 +1172,8 @@ impl JiraMcpServer {
     /// Key features:
     /// - Simple: Just provide summary and project_key for basic tasks
     /// - Smart defaults: Auto-detects subtasks, handles "assign_to_me", etc.
-    /// - initial_todos: Automatically formats todo checklists
+    /// - initial_todos: Automatically formats todo checklists, with optional
+    ///   sections and pre-checked items that list_todos picks up as-is
     /// - Custom fields: Full support for any custom field
     /// - Epic/Story points: Convenience parameters with auto-detection
     ///
@@ -964,20 +1183,43 @@ impl JiraMcpServer {
     /// - Allowed values for constrained fields
     /// - Custom field IDs and types
     ///
+    /// Priority names are checked against list_priorities (case-insensitive, with
+    /// aliases such as "critical"); unknown names fail with suggestions.
+    /// `security_level` restricts a sensitive issue from the start; it is matched
+    /// against the project's security levels (see update_security_level).
+    /// `affects_versions` must name unarchived project versions, and `due_date`
+    /// takes dates or expressions like "next friday". When JIRA rejects the issue
+    /// for a field the screen requires (or does not show), the error names the
+    /// field and the parameter to use.
+    ///
+    /// The result includes the created issue as JIRA stored it (`issue`) and the
+    /// fields picked for convenience parameters (`auto_detected_fields`, e.g. the
+    /// story points field), so no get_issue_details call is needed to confirm.
+    /// Pass `return_full_issue: false` to skip that read; bulk_create_issues skips
+    /// it unless asked. With an `idempotency_key`, retrying after a timeout is
+    /// safe: a key seen in the last 24 hours returns the first result with
+    /// `replayed: true` instead of creating a duplicate.
+    ///
     /// # Examples
     /// - Simple task: `{"project_key": "PROJ", "summary": "Fix login bug"}`
     /// - Bug with priority: `{"project_key": "PROJ", "summary": "Payment fails", "issue_type": "Bug", "priority": "High"}`
     /// - Story with todos: `{"project_key": "PROJ", "summary": "Dark mode", "issue_type": "Story", "initial_todos": ["Design colors", "Implement toggle"], "assign_to_me": true}`
+    /// - Imported plan: `{"project_key": "PROJ", "summary": "Search v2", "initial_todos": [{"text": "Spike", "completed": true, "section": "Research"}, {"text": "Add index", "section": "Backend"}]}`
     /// - Subtask: `{"parent_issue_key": "PROJ-123", "summary": "Write tests"}`
+    /// - Restricted bug: `{"project_key": "PROJ", "summary": "Token leak in logs", "issue_type": "Bug", "security_level": "Security Team"}`
+    /// - Bug report: `{"project_key": "PROJ", "summary": "Checkout hangs", "issue_type": "Bug", "environment": "Firefox 128, Windows 11", "affects_versions": ["2.3"], "due_date": "next friday"}`
+    /// - With duplicate check: `{"project_key": "PROJ", "summary": "Checkout timeout", "check_duplicates": true}`
+    /// - Safe to retry: `{"project_key": "PROJ", "summary": "Rotate API keys", "idempotency_key": "rotate-keys-2024-06"}`
     #[instrument(skip(self))]
     pub async fn create_issue(
         &self,
-        params: CreateIssueParams,
-    ) -> anyhow::Result<CreateIssueResult> {
-        self.create_issue_tool.execute(params).await.map_err(|e| {
-            error!("create_issue failed: {}", e);
-            anyhow::anyhow!(e)
+        params: Validated<CreateIssueParams>,
+    ) -> ToolResult<ToolResponse<CreateIssueResult>> {
+        self.audited("create_issue", params.into_inner(), |params| {
+            self.tools.create_issue_tool.execute(params)
         })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// List todos from an issue description
@@ -985,6 +1227,9 @@ impl JiraMcpServer {
     /// Parses markdown-style checkboxes from an issue description and returns
     /// them as structured todo items. Supports formats like `- [ ] todo` and `- [x] completed`.
     /// Allows filtering by status: open, completed, or wip (work in progress).
+    /// On instances with the "Checklist for Jira" app, todos come from its
+    /// checklist field instead (see `backend` in the result); all todo tools
+    /// work the same on both.
     ///
     /// # Examples
     /// - List all todos: `{"issue_key": "PROJ-123"}`
@@ -992,11 +1237,17 @@ impl JiraMcpServer {
     /// - List work in progress: `{"status_filter": ["wip"]}`
     /// - List open and wip: `{"status_filter": ["open", "wip"]}`
     #[instrument(skip(self))]
-    pub async fn list_todos(&self, params: ListTodosParams) -> anyhow::Result<ListTodosResult> {
-        self.todo_tracker.list_todos(params).await.map_err(|e| {
-            error!("list_todos failed: {}", e);
-            anyhow::anyhow!(e)
-        })
+    pub async fn list_todos(
+        &self,
+        params: ListTodosParams,
+    ) -> ToolResult<ToolResponse<ListTodosResult>> {
+        self.measured("list_todos", self.tools.todo_tracker.list_todos(params))
+            .await
+            .map_err(|e| {
+                error!("list_todos failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
     }
 
     /// Add a new todo to an issue description
@@ -1008,12 +1259,14 @@ impl JiraMcpServer {
     /// # Examples
     /// - Add todo at end: `{"issue_key": "PROJ-123", "todo_text": "Review code changes"}`
     /// - Add todo at beginning: `{"issue_key": "PROJ-123", "todo_text": "Urgent: Fix bug", "prepend": true}`
+    /// - Preview without writing: `{"issue_key": "PROJ-123", "todo_text": "Review code changes", "dry_run": true}`
     #[instrument(skip(self))]
-    pub async fn add_todo(&self, params: AddTodoParams) -> anyhow::Result<AddTodoResult> {
-        self.todo_tracker.add_todo(params).await.map_err(|e| {
-            error!("add_todo failed: {}", e);
-            anyhow::anyhow!(e)
+    pub async fn add_todo(&self, params: AddTodoParams) -> ToolResult<ToolResponse<AddTodoResult>> {
+        self.audited("add_todo", params, |params| {
+            self.measured("add_todo", self.tools.todo_tracker.add_todo(params))
         })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Update a todo's completion status
@@ -1024,12 +1277,17 @@ impl JiraMcpServer {
     /// # Examples
     /// - Complete a todo: `{"issue_key": "PROJ-123", "todo_id_or_index": "1", "completed": true}`
     /// - Reopen a todo: `{"issue_key": "PROJ-123", "todo_id_or_index": "todo-abc123", "completed": false}`
+    /// - Complete and return the diff: `{"issue_key": "PROJ-123", "todo_id_or_index": "1", "completed": true, "include_diff": true}`
     #[instrument(skip(self))]
-    pub async fn update_todo(&self, params: UpdateTodoParams) -> anyhow::Result<UpdateTodoResult> {
-        self.todo_tracker.update_todo(params).await.map_err(|e| {
-            error!("update_todo failed: {}", e);
-            anyhow::anyhow!(e)
+    pub async fn update_todo(
+        &self,
+        params: UpdateTodoParams,
+    ) -> ToolResult<ToolResponse<UpdateTodoResult>> {
+        self.audited("update_todo", params, |params| {
+            self.measured("update_todo", self.tools.todo_tracker.update_todo(params))
         })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Start tracking work time on a todo
@@ -1042,21 +1300,27 @@ impl JiraMcpServer {
     /// the issue MUST have an "Original Estimate" or "Remaining Estimate" field set.
     /// If the issue doesn't have an estimate, you'll get a clear error with instructions.
     ///
+    /// With `target_minutes`, get_active_work_sessions reports progress toward the
+    /// target, the server logs a reminder once it is passed, and complete_todo_work
+    /// compares the time worked against it.
+    ///
     /// # Examples
     /// - Start work on first todo: `{"issue_key": "PROJ-123", "todo_id_or_index": "1"}`
     /// - Start work by todo ID: `{"issue_key": "PROJ-123", "todo_id_or_index": "todo-abc123"}`
+    /// - A 25 minute pomodoro: `{"issue_key": "PROJ-123", "todo_id_or_index": "1", "target_minutes": 25}`
     #[instrument(skip(self))]
     pub async fn start_todo_work(
         &self,
         params: StartTodoWorkParams,
-    ) -> anyhow::Result<StartTodoWorkResult> {
-        self.todo_tracker
-            .start_todo_work(params)
-            .await
-            .map_err(|e| {
-                error!("start_todo_work failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<StartTodoWorkResult>> {
+        self.audited("start_todo_work", params, |params| {
+            self.measured(
+                "start_todo_work",
+                self.tools.todo_tracker.start_todo_work(params),
+            )
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Complete work on a todo and log time spent
@@ -1080,14 +1344,15 @@ impl JiraMcpServer {
     pub async fn complete_todo_work(
         &self,
         params: CompleteTodoWorkParams,
-    ) -> anyhow::Result<CompleteTodoWorkResult> {
-        self.todo_tracker
-            .complete_todo_work(params)
-            .await
-            .map_err(|e| {
-                error!("complete_todo_work failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<CompleteTodoWorkResult>> {
+        self.audited("complete_todo_work", params, |params| {
+            self.measured(
+                "complete_todo_work",
+                self.tools.todo_tracker.complete_todo_work(params),
+            )
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Checkpoint work progress - log time but keep session active
@@ -1114,14 +1379,15 @@ impl JiraMcpServer {
     pub async fn checkpoint_todo_work(
         &self,
         params: CheckpointTodoWorkParams,
-    ) -> anyhow::Result<CheckpointTodoWorkResult> {
-        self.todo_tracker
-            .checkpoint_todo_work(params)
-            .await
-            .map_err(|e| {
-                error!("checkpoint_todo_work failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<CheckpointTodoWorkResult>> {
+        self.audited("checkpoint_todo_work", params, |params| {
+            self.measured(
+                "checkpoint_todo_work",
+                self.tools.todo_tracker.checkpoint_todo_work(params),
+            )
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Set the base issue for todo operations
@@ -1140,11 +1406,17 @@ impl JiraMcpServer {
     pub async fn set_todo_base(
         &self,
         params: SetTodoBaseParams,
-    ) -> anyhow::Result<SetTodoBaseResult> {
-        self.todo_tracker.set_todo_base(params).await.map_err(|e| {
+    ) -> ToolResult<ToolResponse<SetTodoBaseResult>> {
+        self.measured(
+            "set_todo_base",
+            self.tools.todo_tracker.set_todo_base(params),
+        )
+        .await
+        .map_err(|e| {
             error!("set_todo_base failed: {}", e);
-            anyhow::anyhow!(e)
+            ToolError::from(e)
         })
+        .map(|result| self.respond(result))
     }
 
     /// Pause work on a todo and save progress
@@ -1164,14 +1436,15 @@ impl JiraMcpServer {
     pub async fn pause_todo_work(
         &self,
         params: PauseTodoWorkParams,
-    ) -> anyhow::Result<PauseTodoWorkResult> {
-        self.todo_tracker
-            .pause_todo_work(params)
-            .await
-            .map_err(|e| {
-                error!("pause_todo_work failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<PauseTodoWorkResult>> {
+        self.audited("pause_todo_work", params, |params| {
+            self.measured(
+                "pause_todo_work",
+                self.tools.todo_tracker.pause_todo_work(params),
+            )
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Cancel an active work session without logging time
@@ -1185,32 +1458,39 @@ impl JiraMcpServer {
     pub async fn cancel_todo_work(
         &self,
         params: CancelTodoWorkParams,
-    ) -> anyhow::Result<CancelTodoWorkResult> {
-        self.todo_tracker
-            .cancel_todo_work(params)
-            .await
-            .map_err(|e| {
-                error!("cancel_todo_work failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<CancelTodoWorkResult>> {
+        self.audited("cancel_todo_work", params, |params| {
+            self.measured(
+                "cancel_todo_work",
+                self.tools.todo_tracker.cancel_todo_work(params),
+            )
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Get all active work sessions
     ///
     /// Returns a list of all currently active work sessions showing what's being
-    /// tracked, when it started, and how long you've been working on it.
+    /// tracked, when it started, and how long you've been working on it. Sessions
+    /// started with `target_minutes` also show progress toward it and any overshoot.
     ///
     /// # Examples
     /// - List all active sessions: `{}`
     #[instrument(skip(self))]
-    pub async fn get_active_work_sessions(&self) -> anyhow::Result<GetActiveWorkSessionsResult> {
-        self.todo_tracker
-            .get_active_work_sessions()
-            .await
-            .map_err(|e| {
-                error!("get_active_work_sessions failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    pub async fn get_active_work_sessions(
+        &self,
+    ) -> ToolResult<ToolResponse<GetActiveWorkSessionsResult>> {
+        self.measured(
+            "get_active_work_sessions",
+            self.tools.todo_tracker.get_active_work_sessions(),
+        )
+        .await
+        .map_err(|e| {
+            error!("get_active_work_sessions failed: {}", e);
+            ToolError::from(e)
+        })
+        .map(|result| self.respond(result))
     }
 
     /// List sprints for a specific board
@@ -1226,14 +1506,16 @@ impl JiraMcpServer {
     pub async fn list_sprints(
         &self,
         params: ListSprintsParams,
-    ) -> anyhow::Result<ListSprintsResult> {
-        self.list_sprints_tool
+    ) -> ToolResult<ToolResponse<ListSprintsResult>> {
+        self.tools
+            .list_sprints_tool
             .execute(params)
             .await
             .map_err(|e: JiraMcpError| {
                 error!("list_sprints failed: {}", e);
-                anyhow::anyhow!(e)
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
     }
 
     /// Get detailed information about a specific sprint
@@ -1246,14 +1528,16 @@ impl JiraMcpServer {
     pub async fn get_sprint_info(
         &self,
         params: GetSprintInfoParams,
-    ) -> anyhow::Result<GetSprintInfoResult> {
-        self.get_sprint_info_tool
+    ) -> ToolResult<ToolResponse<GetSprintInfoResult>> {
+        self.tools
+            .get_sprint_info_tool
             .execute(params)
             .await
             .map_err(|e: JiraMcpError| {
                 error!("get_sprint_info failed: {}", e);
-                anyhow::anyhow!(e)
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
     }
 
     /// Get all issues in a specific sprint
@@ -1267,14 +1551,16 @@ impl JiraMcpServer {
     pub async fn get_sprint_issues(
         &self,
         params: GetSprintIssuesParams,
-    ) -> anyhow::Result<GetSprintIssuesResult> {
-        self.get_sprint_issues_tool
+    ) -> ToolResult<ToolResponse<GetSprintIssuesResult>> {
+        self.tools
+            .get_sprint_issues_tool
             .execute(params)
             .await
             .map_err(|e: JiraMcpError| {
                 error!("get_sprint_issues failed: {}", e);
-                anyhow::anyhow!(e)
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
     }
 
     /// Move issues to a sprint
@@ -1288,14 +1574,12 @@ impl JiraMcpServer {
     pub async fn move_to_sprint(
         &self,
         params: MoveToSprintParams,
-    ) -> anyhow::Result<MoveToSprintResult> {
-        self.move_to_sprint_tool
-            .execute(params)
-            .await
-            .map_err(|e: JiraMcpError| {
-                error!("move_to_sprint failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<MoveToSprintResult>> {
+        self.audited("move_to_sprint", params, |params| {
+            self.tools.move_to_sprint_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Create a new sprint on a board
@@ -1310,14 +1594,12 @@ impl JiraMcpServer {
     pub async fn create_sprint(
         &self,
         params: CreateSprintParams,
-    ) -> anyhow::Result<CreateSprintResult> {
-        self.create_sprint_tool
-            .execute(params)
-            .await
-            .map_err(|e: JiraMcpError| {
-                error!("create_sprint failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<CreateSprintResult>> {
+        self.audited("create_sprint", params, |params| {
+            self.tools.create_sprint_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Start a sprint
@@ -1327,25 +1609,27 @@ impl JiraMcpServer {
     ///
     /// Validations:
     /// - Sprint must be in "future" state (not already active or closed)
-    /// - End date must be set
+    /// - End date must be set and after the start date
     /// - Warns if sprint has no issues
     ///
+    /// The goal and dates are stored together with the state change, and the result
+    /// shows the sprint as JIRA stored it. Date-only values mean midnight UTC.
+    ///
     /// # Examples
     /// - Start with existing dates: `{"sprint_id": 123}`
     /// - Start and set end date: `{"sprint_id": 123, "end_date": "2025-02-03T23:59:59Z"}`
+    /// - Start with a goal: `{"sprint_id": 123, "end_date": "2025-02-03", "goal": "Ship the new checkout"}`
     /// - Start with custom start: `{"sprint_id": 123, "start_date": "2025-01-20T08:00:00Z", "end_date": "2025-02-03T18:00:00Z"}`
     #[instrument(skip(self))]
     pub async fn start_sprint(
         &self,
         params: StartSprintParams,
-    ) -> anyhow::Result<StartSprintResult> {
-        self.start_sprint_tool
-            .execute(params)
-            .await
-            .map_err(|e: JiraMcpError| {
-                error!("start_sprint failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<StartSprintResult>> {
+        self.audited("start_sprint", params, |params| {
+            self.tools.start_sprint_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Close a sprint
@@ -1354,9 +1638,12 @@ impl JiraMcpServer {
     /// incomplete issues to another sprint for continuity.
     ///
     /// Features:
-    /// - Calculates completion rate (done vs total issues)
-    /// - Optionally moves incomplete issues to next sprint
-    /// - Provides warnings about incomplete work
+    /// - Calculates completion rate (done vs total issues) over every page of the
+    ///   sprint, judging completion by status category
+    /// - Optionally moves incomplete issues to next sprint, 50 per request; a failed
+    ///   move leaves the sprint open and names the issues not moved
+    /// - Lists incomplete issues left in the closed sprint in remaining_issue_keys
+    /// - Provides warnings about incomplete work and closing before the planned end date
     /// - JIRA automatically sets complete date to current time
     ///
     /// # Examples
@@ -1366,14 +1653,38 @@ impl JiraMcpServer {
     pub async fn close_sprint(
         &self,
         params: CloseSprintParams,
-    ) -> anyhow::Result<CloseSprintResult> {
-        self.close_sprint_tool
-            .execute(params)
-            .await
-            .map_err(|e: JiraMcpError| {
-                error!("close_sprint failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<CloseSprintResult>> {
+        self.audited("close_sprint", params, |params| {
+            self.tools.close_sprint_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// Update a sprint's name, goal or dates
+    ///
+    /// Works on future and active sprints, e.g. to refine the goal after the sprint
+    /// has started. Only the given fields change; the result shows the sprint as
+    /// JIRA stored it.
+    ///
+    /// Validations:
+    /// - At least one of name, goal, start_date, end_date
+    /// - End date must be after the start date (given or already on the sprint)
+    /// - Date-only values ("2025-02-03") mean midnight UTC
+    ///
+    /// # Examples
+    /// - Change the goal: `{"sprint_id": 123, "goal": "Ship the new checkout"}`
+    /// - Extend the sprint: `{"sprint_id": 123, "end_date": "2025-02-07"}`
+    #[instrument(skip(self))]
+    pub async fn update_sprint(
+        &self,
+        params: UpdateSprintParams,
+    ) -> ToolResult<ToolResponse<UpdateSprintResult>> {
+        self.audited("update_sprint", params, |params| {
+            self.tools.update_sprint_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Link two issues together with a specific link type
@@ -1390,14 +1701,15 @@ impl JiraMcpServer {
     /// - Link two issues: `{"inward_issue_key": "PROJ-123", "outward_issue_key": "PROJ-456", "link_type": "Blocks"}`
     /// - Link with comment: `{"inward_issue_key": "PROJ-123", "outward_issue_key": "PROJ-456", "link_type": "Relates", "comment": "These are related"}`
     #[instrument(skip(self))]
-    pub async fn link_issues(&self, params: LinkIssuesParams) -> anyhow::Result<LinkIssuesResult> {
-        self.link_issues_tool
-            .execute(params)
-            .await
-            .map_err(|e: JiraMcpError| {
-                error!("link_issues failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    pub async fn link_issues(
+        &self,
+        params: LinkIssuesParams,
+    ) -> ToolResult<ToolResponse<LinkIssuesResult>> {
+        self.audited("link_issues", params, |params| {
+            self.tools.link_issues_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Delete an issue link
@@ -1411,14 +1723,12 @@ impl JiraMcpServer {
     pub async fn delete_issue_link(
         &self,
         params: DeleteIssueLinkParams,
-    ) -> anyhow::Result<DeleteIssueLinkResult> {
-        self.delete_issue_link_tool
-            .execute(params)
-            .await
-            .map_err(|e: JiraMcpError| {
-                error!("delete_issue_link failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<DeleteIssueLinkResult>> {
+        self.audited("delete_issue_link", params, |params| {
+            self.tools.delete_issue_link_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Get all available issue link types
@@ -1429,14 +1739,16 @@ impl JiraMcpServer {
     /// # Examples
     /// - Get all link types: `{}`
     #[instrument(skip(self))]
-    pub async fn get_issue_link_types(&self) -> anyhow::Result<GetIssueLinkTypesResult> {
-        self.get_issue_link_types_tool
+    pub async fn get_issue_link_types(&self) -> ToolResult<ToolResponse<GetIssueLinkTypesResult>> {
+        self.tools
+            .get_issue_link_types_tool
             .execute()
             .await
             .map_err(|e: JiraMcpError| {
                 error!("get_issue_link_types failed: {}", e);
-                anyhow::anyhow!(e)
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
     }
 
     /// Manage labels on a JIRA issue
@@ -1458,11 +1770,12 @@ impl JiraMcpServer {
     pub async fn manage_labels(
         &self,
         params: ManageLabelsParams,
-    ) -> anyhow::Result<ManageLabelsResult> {
-        self.labels_tool.manage_labels(params).await.map_err(|e| {
-            error!("manage_labels failed: {}", e);
-            anyhow::anyhow!(e)
+    ) -> ToolResult<ToolResponse<ManageLabelsResult>> {
+        self.audited("manage_labels", params, |params| {
+            self.tools.labels_tool.manage_labels(params)
         })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Get available labels
@@ -1478,14 +1791,16 @@ impl JiraMcpServer {
     pub async fn get_available_labels(
         &self,
         params: GetAvailableLabelsParams,
-    ) -> anyhow::Result<GetAvailableLabelsResult> {
-        self.labels_tool
+    ) -> ToolResult<ToolResponse<GetAvailableLabelsResult>> {
+        self.tools
+            .labels_tool
             .get_available_labels(params)
             .await
             .map_err(|e| {
                 error!("get_available_labels failed: {}", e);
-                anyhow::anyhow!(e)
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
     }
 
     /// Update components on a JIRA issue
@@ -1504,14 +1819,12 @@ impl JiraMcpServer {
     pub async fn update_components(
         &self,
         params: UpdateComponentsParams,
-    ) -> anyhow::Result<UpdateComponentsResult> {
-        self.components_tool
-            .update_components(params)
-            .await
-            .map_err(|e| {
-                error!("update_components failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<UpdateComponentsResult>> {
+        self.audited("update_components", params, |params| {
+            self.tools.components_tool.update_components(params)
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Get available components for a project
@@ -1525,14 +1838,16 @@ impl JiraMcpServer {
     pub async fn get_available_components(
         &self,
         params: GetAvailableComponentsParams,
-    ) -> anyhow::Result<GetAvailableComponentsResult> {
-        self.components_tool
+    ) -> ToolResult<ToolResponse<GetAvailableComponentsResult>> {
+        self.tools
+            .components_tool
             .get_available_components(params)
             .await
             .map_err(|e| {
                 error!("get_available_components failed: {}", e);
-                anyhow::anyhow!(e)
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
     }
 
     /// Bulk create multiple JIRA issues
@@ -1559,18 +1874,20 @@ impl JiraMcpServer {
     ///
     /// Note: initial_retry_delay_ms has a minimum of 500ms to prevent API hammering
     /// - Stop on error: `{"project_key": "PROJ", "issues": [...], "stop_on_error": true}`
+    /// - Safe to rerun: `{"project_key": "PROJ", "issues": [{"summary": "Task 1", "idempotency_key": "plan-1"}, {"summary": "Task 2", "idempotency_key": "plan-2"}]}`
     #[instrument(skip(self))]
     pub async fn bulk_create_issues(
         &self,
         params: BulkCreateIssuesParams,
-    ) -> anyhow::Result<BulkCreateIssuesResult> {
-        self.bulk_operations_tool
-            .bulk_create_issues(params)
-            .await
-            .map_err(|e| {
-                error!("bulk_create_issues failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<BulkCreateIssuesResult>> {
+        self.audited("bulk_create_issues", params, |params| {
+            self.measured(
+                "bulk_create_issues",
+                self.tools.bulk_operations_tool.bulk_create_issues(params),
+            )
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Bulk transition multiple issues to a new status
@@ -1596,14 +1913,17 @@ impl JiraMcpServer {
     pub async fn bulk_transition_issues(
         &self,
         params: BulkTransitionIssuesParams,
-    ) -> anyhow::Result<BulkTransitionIssuesResult> {
-        self.bulk_operations_tool
-            .bulk_transition_issues(params)
-            .await
-            .map_err(|e| {
-                error!("bulk_transition_issues failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<BulkTransitionIssuesResult>> {
+        self.audited("bulk_transition_issues", params, |params| {
+            self.measured(
+                "bulk_transition_issues",
+                self.tools
+                    .bulk_operations_tool
+                    .bulk_transition_issues(params),
+            )
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Bulk update fields on multiple issues
@@ -1616,24 +1936,28 @@ impl JiraMcpServer {
     /// - Automatic retry with exponential backoff (default: 3 retries)
     /// - Same field updates applied to all issues
     /// - Supports any JIRA field (standard or custom)
+    /// - `reporter` accepts "me", an account ID, an email or a display name, resolved
+    ///   once like set_reporter does (changing it needs "Modify Reporter")
     /// - Detailed success/failure reporting per issue
     ///
     /// # Examples
     /// - Update priority: `{"issue_keys": ["PROJ-1", "PROJ-2"], "field_updates": {"priority": {"name": "High"}}}`
     /// - Update custom field: `{"issue_keys": [...], "field_updates": {"customfield_10050": "value"}}`
     /// - Multiple fields: `{"issue_keys": [...], "field_updates": {"priority": {"name": "High"}, "labels": ["urgent"]}}`
+    /// - Change reporter: `{"issue_keys": [...], "field_updates": {"reporter": "jane.doe@example.com"}}`
     #[instrument(skip(self))]
     pub async fn bulk_update_fields(
         &self,
         params: BulkUpdateFieldsParams,
-    ) -> anyhow::Result<BulkUpdateFieldsResult> {
-        self.bulk_operations_tool
-            .bulk_update_fields(params)
-            .await
-            .map_err(|e| {
-                error!("bulk_update_fields failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<BulkUpdateFieldsResult>> {
+        self.audited("bulk_update_fields", params, |params| {
+            self.measured(
+                "bulk_update_fields",
+                self.tools.bulk_operations_tool.bulk_update_fields(params),
+            )
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Bulk assign multiple issues to a user
@@ -1656,14 +1980,15 @@ impl JiraMcpServer {
     pub async fn bulk_assign_issues(
         &self,
         params: BulkAssignIssuesParams,
-    ) -> anyhow::Result<BulkAssignIssuesResult> {
-        self.bulk_operations_tool
-            .bulk_assign_issues(params)
-            .await
-            .map_err(|e| {
-                error!("bulk_assign_issues failed: {}", e);
-                anyhow::anyhow!(e)
-            })
+    ) -> ToolResult<ToolResponse<BulkAssignIssuesResult>> {
+        self.audited("bulk_assign_issues", params, |params| {
+            self.measured(
+                "bulk_assign_issues",
+                self.tools.bulk_operations_tool.bulk_assign_issues(params),
+            )
+        })
+        .await
+        .map(|result| self.respond(result))
     }
 
     /// Bulk add or remove labels from multiple issues
@@ -1677,30 +2002,1012 @@ impl JiraMcpServer {
     /// - Add and/or remove labels in one operation
     /// - Same label changes applied to all issues
     /// - Detailed success/failure reporting per issue
+    /// - Optional near-duplicate label warnings (validate_labels) or existing-only mode
+    ///   (create_if_missing: false), checked against list_labels
     ///
     /// # Examples
     /// - Add labels: `{"issue_keys": ["PROJ-1", "PROJ-2"], "add_labels": ["urgent", "backend"]}`
     /// - Remove labels: `{"issue_keys": [...], "remove_labels": ["wontfix"]}`
     /// - Add and remove: `{"issue_keys": [...], "add_labels": ["reviewed"], "remove_labels": ["needs-review"]}`
+    /// - Existing labels only: `{"issue_keys": [...], "add_labels": ["tech-debt"], "create_if_missing": false}`
     #[instrument(skip(self))]
     pub async fn bulk_add_labels(
         &self,
         params: BulkAddLabelsParams,
-    ) -> anyhow::Result<BulkAddLabelsResult> {
-        self.bulk_operations_tool
-            .bulk_add_labels(params)
+    ) -> ToolResult<ToolResponse<BulkAddLabelsResult>> {
+        self.audited("bulk_add_labels", params, |params| {
+            self.measured(
+                "bulk_add_labels",
+                self.tools.bulk_operations_tool.bulk_add_labels(params),
+            )
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// Count issues matching semantic filters without fetching them
+    ///
+    /// Accepts the same filters as search_issues but only returns the number of
+    /// matching issues, which is much cheaper than searching. Optionally breaks the
+    /// count down by status category, priority, or issue type.
+    ///
+    /// # Examples
+    /// - Count open bugs: `{"issue_types": ["bug"], "status": ["open"]}`
+    /// - Project workload by priority: `{"project_key": "FOO", "facet_by": "priority"}`
+    /// - My issues per status category: `{"assigned_to": "me", "facet_by": "status_category"}`
+    #[instrument(skip(self))]
+    pub async fn count_issues(
+        &self,
+        params: CountIssuesParams,
+    ) -> ToolResult<ToolResponse<CountIssuesResult>> {
+        self.tools
+            .count_issues_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("count_issues failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Find existing issues similar to a proposed summary
+    ///
+    /// Runs a JIRA text search for the summary and ranks the results by word
+    /// overlap, returning each candidate's status and resolution. Use this before
+    /// create_issue to avoid filing duplicates (or pass check_duplicates to create_issue).
+    ///
+    /// # Examples
+    /// - Check a project: `{"summary_text": "Checkout fails with payment timeout", "project_key": "SHOP"}`
+    /// - Only open issues: `{"summary_text": "Login SSO error", "statuses": ["Open", "In Progress"], "limit": 5}`
+    #[instrument(skip(self))]
+    pub async fn find_similar_issues(
+        &self,
+        params: FindSimilarIssuesParams,
+    ) -> ToolResult<ToolResponse<FindSimilarIssuesResult>> {
+        self.tools
+            .find_similar_issues_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("find_similar_issues failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Send a notification email about an issue
+    ///
+    /// Explicitly emails selected participants (reporter, assignee, watchers, voters,
+    /// specific users or groups) instead of relying on watchers noticing an update.
+    /// At least one recipient must be selected.
+    ///
+    /// # Examples
+    /// - Ping assignee and watchers: `{"issue_key": "PROJ-123", "body": "Release is blocked on this", "to": {"assignee": true, "watchers": true}}`
+    /// - Notify a group: `{"issue_key": "PROJ-123", "subject": "Incident update", "body": "Rolled back at 14:05", "to": {"groups": ["sre"]}}`
+    #[instrument(skip(self))]
+    pub async fn notify_issue(
+        &self,
+        params: NotifyIssueParams,
+    ) -> ToolResult<ToolResponse<NotifyIssueResult>> {
+        self.audited("notify_issue", params, |params| {
+            self.tools.notify_issue_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// Vote for an issue as the current user
+    ///
+    /// JIRA does not allow voting on issues you reported; its explanation is
+    /// returned as-is. Voting again on an issue you already voted for is a no-op.
+    ///
+    /// # Examples
+    /// - Vote: `{"issue_key": "PROJ-123"}`
+    #[instrument(skip(self))]
+    pub async fn add_vote(&self, params: IssueVoteParams) -> ToolResult<ToolResponse<VoteResult>> {
+        self.audited("add_vote", params, |params| {
+            self.tools.votes_tool.add_vote(params)
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// Remove the current user's vote from an issue
+    ///
+    /// # Examples
+    /// - Unvote: `{"issue_key": "PROJ-123"}`
+    #[instrument(skip(self))]
+    pub async fn remove_vote(
+        &self,
+        params: IssueVoteParams,
+    ) -> ToolResult<ToolResponse<VoteResult>> {
+        self.audited("remove_vote", params, |params| {
+            self.tools.votes_tool.remove_vote(params)
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// Get the votes on an issue
+    ///
+    /// Returns the vote count, whether the current user has voted (useful before
+    /// add_vote/remove_vote), and the voters when the instance exposes them.
+    ///
+    /// # Examples
+    /// - Check votes: `{"issue_key": "PROJ-123"}`
+    #[instrument(skip(self))]
+    pub async fn get_votes(
+        &self,
+        params: IssueVoteParams,
+    ) -> ToolResult<ToolResponse<GetVotesResult>> {
+        self.tools
+            .votes_tool
+            .get_votes(params)
+            .await
+            .map_err(|e| {
+                error!("get_votes failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// List labels defined in the JIRA instance
+    ///
+    /// Returns the instance-wide label list (JIRA Cloud /label endpoint) with an
+    /// optional substring filter and pagination. The list is cached; pass refresh
+    /// to reload it. Check here before inventing a new label to avoid typo sprawl.
+    ///
+    /// # Examples
+    /// - All labels: `{}`
+    /// - Filter: `{"filter": "debt"}`
+    /// - Next page: `{"filter": "team", "start_at": 100, "max_results": 100}`
+    #[instrument(skip(self))]
+    pub async fn list_labels(
+        &self,
+        params: ListLabelsParams,
+    ) -> ToolResult<ToolResponse<ListLabelsResult>> {
+        self.tools
+            .labels_tool
+            .list_labels(params)
+            .await
+            .map_err(|e| {
+                error!("list_labels failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Find issues by label
+    ///
+    /// Convenience search for issues carrying any (or all) of the given labels.
+    ///
+    /// # Examples
+    /// - Any label: `{"labels": ["tech-debt", "refactor"]}`
+    /// - All labels in a project: `{"labels": ["backend", "urgent"], "match_all": true, "project_key": "PROJ"}`
+    #[instrument(skip(self))]
+    pub async fn get_issues_by_label(
+        &self,
+        params: GetIssuesByLabelParams,
+    ) -> ToolResult<ToolResponse<GetIssuesByLabelResult>> {
+        self.tools
+            .labels_tool
+            .get_issues_by_label(params)
+            .await
+            .map_err(|e| {
+                error!("get_issues_by_label failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Create a named watch query for change tracking
+    ///
+    /// Stores a JQL query (or semantic filters) together with the newest update
+    /// timestamp it currently matches. Later poll_watch_query calls report only what
+    /// changed since then. Watches are persisted in the state directory
+    /// (JIRA_MCP_STATE_DIR) and survive restarts; any number can coexist.
+    ///
+    /// # Examples
+    /// - Watch a project: `{"name": "proj", "project_key": "PROJ"}`
+    /// - Watch my open bugs: `{"name": "my-bugs", "issue_types": ["bug"], "assigned_to": "me", "status": ["open"]}`
+    /// - Raw JQL: `{"name": "release", "jql": "fixVersion = 2.0", "replace": true}`
+    #[instrument(skip(self))]
+    pub async fn create_watch_query(
+        &self,
+        params: CreateWatchQueryParams,
+    ) -> ToolResult<ToolResponse<CreateWatchQueryResult>> {
+        self.tools
+            .watch_queries_tool
+            .create_watch_query(params)
+            .await
+            .map_err(|e| {
+                error!("create_watch_query failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Poll a watch query for changes since the last poll
+    ///
+    /// Returns issues created or updated since the watch's cursor, oldest first,
+    /// with the fields that changed (from the changelog), then advances the cursor.
+    /// When has_more is true, poll again to get the remaining changes.
+    ///
+    /// # Examples
+    /// - Poll: `{"name": "proj"}`
+    /// - Small batches: `{"name": "proj", "max_results": 10}`
+    #[instrument(skip(self))]
+    pub async fn poll_watch_query(
+        &self,
+        params: PollWatchQueryParams,
+    ) -> ToolResult<ToolResponse<PollWatchQueryResult>> {
+        self.tools
+            .watch_queries_tool
+            .poll_watch_query(params)
+            .await
+            .map_err(|e| {
+                error!("poll_watch_query failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Get recent entries from the audit log of mutating operations
+    ///
+    /// Every tool call that changes JIRA is recorded with its (redacted) parameters,
+    /// affected issue keys, outcome and duration.
+    ///
+    /// # Examples
+    /// - Last 20 changes: `{"limit": 20}`
+    /// - Everything done to an issue: `{"issue_key": "PROJ-123"}`
+    /// - Recent transitions: `{"tool_name": "transition_issue"}`
+    #[instrument(skip(self))]
+    pub async fn get_audit_log(
+        &self,
+        params: GetAuditLogParams,
+    ) -> ToolResult<ToolResponse<GetAuditLogResult>> {
+        self.tools
+            .get_audit_log_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_audit_log failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Undo the most recent description or field change made through this server
+    ///
+    /// Covers update_issue_description, update_description_section,
+    /// update_description_metadata, update_custom_fields and todo operations that rewrite
+    /// the description. The previous values are only
+    /// restored if the issue still holds what was written; otherwise a conflict with both
+    /// values is reported.
+    ///
+    /// # Examples
+    /// - Revert the last todo/description rewrite: `{"issue_key": "PROJ-123"}`
+    #[instrument(skip(self))]
+    pub async fn undo_last_change(
+        &self,
+        params: UndoLastChangeParams,
+    ) -> ToolResult<ToolResponse<UndoLastChangeResult>> {
+        self.audited("undo_last_change", params, |params| {
+            self.tools.undo_last_change_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// Update an issue's summary, optionally with its labels and priority
+    ///
+    /// Renames an issue, or appends text to its current summary. Summaries are
+    /// validated against JIRA's 255-character limit before anything is sent.
+    /// Labels (replacing the whole set) and priority can be changed in the same call.
+    /// The previous values can be restored with undo_last_change.
+    ///
+    /// # Examples
+    /// - Rename: `{"issue_key": "PROJ-123", "summary": "Login fails for SSO users"}`
+    /// - Append: `{"issue_key": "PROJ-123", "summary": "(regression)", "append": true}`
+    /// - Rename and reprioritize: `{"issue_key": "PROJ-123", "summary": "SSO outage", "priority": "Critical", "labels": ["sso", "incident"]}`
+    #[instrument(skip(self))]
+    pub async fn update_issue_summary(
+        &self,
+        params: UpdateSummaryParams,
+    ) -> ToolResult<ToolResponse<UpdateSummaryResult>> {
+        self.audited("update_issue_summary", params, |params| {
+            self.tools.update_summary_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// List the priorities defined in the JIRA instance
+    ///
+    /// Returns the instance's priorities (highest first) and what the semantic
+    /// aliases ("critical", "normal", "low", ...) resolve to here. Priority
+    /// parameters on create_issue, update_issue_summary and search_issues are
+    /// validated against this list. The list is cached; pass refresh to reload it.
+    ///
+    /// # Examples
+    /// - All priorities: `{}`
+    /// - Reload after a scheme change: `{"refresh": true}`
+    #[instrument(skip(self))]
+    pub async fn list_priorities(
+        &self,
+        params: ListPrioritiesParams,
+    ) -> ToolResult<ToolResponse<ListPrioritiesResult>> {
+        self.tools
+            .priorities_tool
+            .list_priorities(params)
+            .await
+            .map_err(|e| {
+                error!("list_priorities failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Archive an issue instead of deleting it
+    ///
+    /// In one call: adds the archive label, transitions the issue to the archive
+    /// status, posts a closing comment and optionally moves it out of its sprint.
+    /// Label, status, resolution and comment template come from the `[archive]`
+    /// config section (defaults: label "archived", status "Done"). Every step is
+    /// reported separately; failures are not rolled back, so check `steps` and
+    /// retry only what failed. Steps already done are skipped.
+    ///
+    /// # Examples
+    /// - Archive: `{"issue_key": "PROJ-123", "reason": "duplicate of PROJ-100"}`
+    /// - Archive and drop from the sprint: `{"issue_key": "PROJ-123", "reason": "descoped", "remove_from_sprint": true}`
+    #[instrument(skip(self))]
+    pub async fn archive_issue(
+        &self,
+        params: ArchiveIssueParams,
+    ) -> ToolResult<ToolResponse<ArchiveIssueResult>> {
+        self.audited("archive_issue", params, |params| {
+            self.tools.archive_issue_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// Clear only part of the cached metadata
+    ///
+    /// Drops the given categories, or just the entries cached under specific keys, so
+    /// an agent can refresh stale data (e.g. after an admin adds an issue type) without
+    /// losing the rest of the cache. Writes made through this server invalidate the
+    /// affected entries automatically.
+    ///
+    /// # Examples
+    /// - Refresh a project's issue types and statuses: `{"categories": ["projects"], "keys": ["PROJ"]}`
+    /// - Reload labels and priorities: `{"categories": ["fields"]}`
+    /// - Forget everything cached for an issue: `{"keys": ["PROJ-123"]}`
+    #[instrument(skip(self))]
+    pub async fn clear_cache_scope(
+        &self,
+        params: ClearCacheScopeParams,
+    ) -> ToolResult<ToolResponse<ClearCacheScopeResult>> {
+        self.tools
+            .clear_cache_scope_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("clear_cache_scope failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Get several issues in one call
+    ///
+    /// Fetches up to 50 issues by key with one JQL search, falling back to concurrent
+    /// individual fetches where the search can't help (comments, moved issues).
+    /// Returns the issues keyed by requested key, in request order; keys that don't
+    /// exist are listed in `not_found` instead of failing the call.
+    ///
+    /// # Examples
+    /// - Review a list: `{"issue_keys": ["PROJ-1", "PROJ-2", "PROJ-3"]}`
+    /// - With history: `{"issue_keys": ["PROJ-1", "PROJ-2"], "include_history": true}`
+    #[instrument(skip(self))]
+    pub async fn get_issues(
+        &self,
+        params: GetIssuesParams,
+    ) -> ToolResult<ToolResponse<GetIssuesResult>> {
+        let response_budget = params.response_budget;
+        self.tools
+            .get_issues_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_issues failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result).with_response_budget(response_budget))
+    }
+
+    /// Check an issue against the configured quality gate
+    ///
+    /// Evaluates the `[quality_gate]` rules: minimum description length, acceptance
+    /// criteria (field or checkbox section), all todos complete, fix version, story
+    /// points, no open blockers, plus custom field-presence/regex rules. Returns
+    /// pass/fail per rule with a hint for each failure.
+    ///
+    /// # Examples
+    /// - Check before moving to review: `{"issue_key": "PROJ-123"}`
+    #[instrument(skip(self))]
+    pub async fn check_issue_quality(
+        &self,
+        params: CheckIssueQualityParams,
+    ) -> ToolResult<ToolResponse<CheckIssueQualityResult>> {
+        self.tools
+            .check_issue_quality_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("check_issue_quality failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Summarize a user's work for a standup
+    ///
+    /// Runs three get_user_issues queries concurrently: issues completed since
+    /// `since` ("yesterday"), issues in progress ("today") and issues in a blocked
+    /// status ("blockers"), and totals the time the user logged since then.
+    /// Bucket items carry only key, summary and status.
+    ///
+    /// # Examples
+    /// - My standup: `{}`
+    /// - After a weekend: `{"since": "3 days ago"}`
+    /// - A teammate in one project: `{"username": "john.doe", "project_filter": ["PROJ"]}`
+    #[instrument(skip(self))]
+    pub async fn get_standup_summary(
+        &self,
+        params: GetStandupSummaryParams,
+    ) -> ToolResult<ToolResponse<GetStandupSummaryResult>> {
+        self.tools
+            .standup_summary_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_standup_summary failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Summarize a board for sprint planning
+    ///
+    /// Returns the top of the ranked backlog, the stories among those candidates
+    /// that lack story points or acceptance criteria, open issues and points per
+    /// assignee, and the committed/completed points of the last three closed
+    /// sprints. Sections load independently; failures are listed in
+    /// `section_errors` without failing the call.
+    ///
+    /// # Examples
+    /// - Plan a project's next sprint: `{"project_key": "PROJ"}`
+    /// - A specific board, fewer candidates: `{"board_id": 42, "candidate_limit": 10}`
+    #[instrument(skip(self))]
+    pub async fn get_sprint_planning_summary(
+        &self,
+        params: GetSprintPlanningSummaryParams,
+    ) -> ToolResult<ToolResponse<GetSprintPlanningSummaryResult>> {
+        self.tools
+            .sprint_planning_summary_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_sprint_planning_summary failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Get the most recent outbound JIRA requests
+    ///
+    /// Each entry has the method, endpoint, query parameters, payload size,
+    /// outcome and duration of one request, with credentials redacted. Requests
+    /// are only recorded while `request_log.enabled` (JIRA_LOG_REQUESTS) is set.
+    ///
+    /// # Examples
+    /// - Last 20 requests: `{}`
+    /// - Last 100 requests: `{"limit": 100}`
+    #[instrument(skip(self))]
+    pub async fn get_recent_requests(
+        &self,
+        params: GetRecentRequestsParams,
+    ) -> ToolResult<ToolResponse<GetRecentRequestsResult>> {
+        self.tools
+            .get_recent_requests_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_recent_requests failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Change the reporter of an issue
+    ///
+    /// Useful after imports, or when an automation filed an issue on someone's
+    /// behalf. The reporter can be "me", an account ID (username on Server/Data
+    /// Center), an email or a display name, resolved like assign_issue does.
+    /// Fails with a clear error when the Reporter field is not on the issue's edit
+    /// screen or the "Modify Reporter" permission is missing.
+    ///
+    /// # Examples
+    /// - Take over as reporter: `{"issue_key": "PROJ-123", "reporter": "me"}`
+    /// - By email: `{"issue_key": "PROJ-123", "reporter": "jane.doe@example.com"}`
+    #[instrument(skip(self))]
+    pub async fn set_reporter(
+        &self,
+        params: SetReporterParams,
+    ) -> ToolResult<ToolResponse<SetReporterResult>> {
+        self.audited("set_reporter", params, |params| {
+            self.tools.set_reporter_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// Set or remove the security level of an issue
+    ///
+    /// Security levels restrict who can see an issue. The name is matched
+    /// case-insensitively against the levels of the issue's project, which are
+    /// returned in the result. Needs the "Set Issue Security" permission; without
+    /// it the error says so. Issue details show the current level as
+    /// `security_level`.
+    ///
+    /// # Examples
+    /// - Restrict: `{"issue_key": "PROJ-123", "security_level": "Security Team"}`
+    /// - Remove the restriction: `{"issue_key": "PROJ-123", "security_level": null}`
+    #[instrument(skip(self))]
+    pub async fn update_security_level(
+        &self,
+        params: UpdateSecurityLevelParams,
+    ) -> ToolResult<ToolResponse<UpdateSecurityLevelResult>> {
+        self.audited("update_security_level", params, |params| {
+            self.tools
+                .security_levels_tool
+                .update_security_level(params)
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// Edit one section of an issue description
+    ///
+    /// Finds the section under a markdown ("## Investigation") or wiki markup
+    /// ("h2. Investigation") heading and edits only that span, up to the next
+    /// heading of the same or a higher level; everything else in the description
+    /// is kept as it was. ADF descriptions (Cloud) are edited as nodes.
+    ///
+    /// Modes:
+    /// - replace_section (default): Replace the section's content, keeping the heading
+    /// - append_to_section: Add content after the section's existing content
+    /// - create_if_missing: Replace the section, or add it at the end if there is none
+    ///
+    /// The heading matches case-insensitively; include the level ("## Notes") to
+    /// tell same-named sections of different levels apart. Duplicate matches fail
+    /// with their line numbers. Pass `expected_updated` (from get_issue_details)
+    /// to refuse the write if the issue changed since you read it.
+    ///
+    /// # Examples
+    /// - Replace a section: `{"issue_key": "INC-42", "section_heading": "Investigation", "content": "Certificate expired at 09:12 UTC."}`
+    /// - Add a timeline entry: `{"issue_key": "INC-42", "section_heading": "## Timeline", "content": "- 09:30 certificate rotated", "mode": "append_to_section"}`
+    /// - Ensure a section exists: `{"issue_key": "INC-42", "section_heading": "h2. Root cause", "content": "TBD", "mode": "create_if_missing"}`
+    #[instrument(skip(self))]
+    pub async fn update_description_section(
+        &self,
+        params: UpdateDescriptionSectionParams,
+    ) -> ToolResult<ToolResponse<UpdateDescriptionSectionResult>> {
+        self.audited("update_description_section", params, |params| {
+            self.tools.update_description_section_tool.execute(params)
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// Catch up on what changed in an issue since a point in time
+    ///
+    /// Merges field changes from the changelog, comments, worklogs and attachments
+    /// newer than `since` into one chronological list. Each entry is one line, e.g.
+    /// "status: In Progress → In Review (by Alice, 2h ago)"; comments show their
+    /// author and first 200 characters. `since` takes timestamps and relative times.
+    ///
+    /// # Examples
+    /// - Catch me up: `{"issue_key": "PROJ-42", "since": "yesterday"}`
+    /// - Since a meeting: `{"issue_key": "PROJ-42", "since": "2024-01-15T09:00:00Z"}`
+    /// - Last few hours: `{"issue_key": "PROJ-42", "since": "4 hours ago"}`
+    #[instrument(skip(self))]
+    pub async fn diff_issue_since(
+        &self,
+        params: DiffIssueSinceParams,
+    ) -> ToolResult<ToolResponse<DiffIssueSinceResult>> {
+        self.tools
+            .diff_issue_since_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("diff_issue_since failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Get the activity stream of an issue
+    ///
+    /// Returns comments, worklogs, field changes, added attachments and added
+    /// links as one time-ordered list, oldest first. Every event has the same
+    /// shape: `timestamp`, `actor`, `type`, a one-line `summary_line` and
+    /// type-specific `details` (full comment body, old and new values, ...).
+    /// `limit` keeps the most recent events; `truncated` tells when older ones
+    /// were left out. The same stream is the `jira://issue/{issue_key}/activity`
+    /// resource.
+    ///
+    /// # Examples
+    /// - Everything: `{"issue_key": "PROJ-42"}`
+    /// - Recent comments: `{"issue_key": "PROJ-42", "since": "3 days ago", "event_types": ["comment"]}`
+    /// - Last status and link changes: `{"issue_key": "PROJ-42", "limit": 10, "event_types": ["field_change", "link_added"]}`
+    #[instrument(skip(self))]
+    pub async fn get_issue_activity(
+        &self,
+        params: GetIssueActivityParams,
+    ) -> ToolResult<ToolResponse<GetIssueActivityResult>> {
+        self.tools
+            .get_issue_activity_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_issue_activity failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Get a health snapshot of a project
+    ///
+    /// Returns open vs closed issues overall and per issue type, issues created
+    /// vs resolved per week over `window_days` (default 28), the assignees with
+    /// the most open issues and the oldest unresolved issues. The numbers come
+    /// from count-only queries run concurrently; `jql_queries` tells how many
+    /// queries the snapshot took.
+    ///
+    /// # Examples
+    /// - Last four weeks: `{"project_key": "PROJ"}`
+    /// - Last quarter: `{"project_key": "PROJ", "window_days": 91}`
+    #[instrument(skip(self))]
+    pub async fn get_project_stats(
+        &self,
+        params: GetProjectStatsParams,
+    ) -> ToolResult<ToolResponse<GetProjectStatsResult>> {
+        self.tools
+            .get_project_stats_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_project_stats failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Read the metadata tables of an issue description
+    ///
+    /// Parses every wiki markup (`|| Key || Value ||`) and markdown
+    /// (`| Key | Value |` over `|---|---|`) table of the description, or the
+    /// table nodes of an ADF description, into rows keyed by the header row.
+    /// Tables inside code blocks are ignored. Rows that cannot be read, e.g. with
+    /// a different number of cells than the header, are listed in `problems`
+    /// with their line instead of failing the whole table.
+    ///
+    /// # Examples
+    /// - Read the tables: `{"issue_key": "REL-12"}`
+    #[instrument(skip(self))]
+    pub async fn get_description_metadata(
+        &self,
+        params: GetDescriptionMetadataParams,
+    ) -> ToolResult<ToolResponse<GetDescriptionMetadataResult>> {
+        self.tools
+            .description_metadata_tool
+            .get_metadata(params)
+            .await
+            .map_err(|e| {
+                error!("get_description_metadata failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Change one cell of a metadata table in an issue description
+    ///
+    /// Finds the table by `table_index` (as listed by get_description_metadata)
+    /// and the row whose first column equals `row_key` (case-insensitive), then
+    /// replaces the cell in `column` (default: the second column). Only the cell
+    /// text changes; the rest of the description is kept as it was. Pass
+    /// `expected_updated` (from get_issue_details) to refuse the write if the
+    /// issue changed since you read it.
+    ///
+    /// # Examples
+    /// - Set a value: `{"issue_key": "REL-12", "table_index": 0, "row_key": "Owner", "value": "[~alice]"}`
+    /// - Set another column: `{"issue_key": "REL-12", "table_index": 1, "row_key": "Payments", "column": "Status", "value": "Ready"}`
+    /// - Preview: `{"issue_key": "REL-12", "table_index": 0, "row_key": "Risk", "value": "Low", "dry_run": true}`
+    #[instrument(skip(self))]
+    pub async fn update_description_metadata(
+        &self,
+        params: UpdateDescriptionMetadataParams,
+    ) -> ToolResult<ToolResponse<UpdateDescriptionMetadataResult>> {
+        self.audited("update_description_metadata", params, |params| {
+            self.tools.description_metadata_tool.update_metadata(params)
+        })
+        .await
+        .map(|result| self.respond(result))
+    }
+
+    /// Show the time you logged, per issue or per day
+    ///
+    /// Finds the issues you logged work on between `since` and `until` (both
+    /// default to today), reads their worklogs and totals your own entries.
+    /// Days are counted in the configured `timezone` (default: UTC). Each group
+    /// is broken down along the other dimension, and totals come in seconds and
+    /// JIRA notation ("1d 2h").
+    ///
+    /// # Examples
+    /// - What did I log today: `{}`
+    /// - The last week per day: `{"since": "7 days ago", "group_by": "day"}`
+    /// - A past range: `{"since": "2024-01-01", "until": "2024-01-05"}`
+    #[instrument(skip(self))]
+    pub async fn get_my_timesheet(
+        &self,
+        params: GetMyTimesheetParams,
+    ) -> ToolResult<ToolResponse<GetMyTimesheetResult>> {
+        self.tools
+            .get_my_timesheet_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_my_timesheet failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Everything a model needs about an issue, in one budget-aware call
+    ///
+    /// Assembles markdown from the description, the latest `comment_count`
+    /// comments (default 5, newest first), the todo list, the issues blocking
+    /// it and the text of small text attachments (up to `max_attachment_bytes`,
+    /// default 20000), in that order of priority. The bundle is kept under
+    /// `max_chars` (default 12000): a section that does not fit is cut and ends
+    /// with a "[truncated: ...]" marker, and `sections` says what each section
+    /// included.
+    ///
+    /// # Examples
+    /// - Default budget: `{"issue_key": "PROJ-123"}`
+    /// - Tight budget, no attachments: `{"issue_key": "PROJ-123", "max_chars": 4000, "max_attachment_bytes": 0}`
+    /// - More discussion: `{"issue_key": "PROJ-123", "comment_count": 20}`
+    #[instrument(skip(self))]
+    pub async fn get_issue_context_bundle(
+        &self,
+        params: GetIssueContextBundleParams,
+    ) -> ToolResult<ToolResponse<GetIssueContextBundleResult>> {
+        self.tools
+            .issue_context_bundle_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_issue_context_bundle failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Look back at the work sessions tracked with the todo tools
+    ///
+    /// Reads the local work history, which records every completed, paused
+    /// and cancelled todo work session when `todos.work_history` is on (the
+    /// server must be built with the `work-history` feature). Sessions started
+    /// between `since` (default "7 days ago") and `until` (default today) are
+    /// totalled per issue, day or todo; cancelled time is counted separately as
+    /// discarded. No JIRA calls are made.
+    ///
+    /// # Examples
+    /// - The last week per issue: `{}`
+    /// - One issue per day: `{"issue_key": "PROJ-123", "group_by": "day"}`
+    /// - Today's sessions: `{"since": "today", "include_sessions": true}`
+    #[instrument(skip(self))]
+    pub async fn query_work_history(
+        &self,
+        params: QueryWorkHistoryParams,
+    ) -> ToolResult<ToolResponse<QueryWorkHistoryResult>> {
+        self.tools
+            .query_work_history_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("query_work_history failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Estimates and logged time summed over an epic's or a task's children
+    ///
+    /// Follows epic children and subtasks up to `max_depth` levels down
+    /// (default 2, at most 5) and totals original estimate, remaining
+    /// estimate, logged time and story points. The parent's own values are
+    /// reported separately in `own`; `children` lists every child with its
+    /// own values and, for children with children, a rollup of those.
+    /// Children without an estimate count as zero and are counted in
+    /// `without_estimate`.
+    ///
+    /// # Examples
+    /// - Epic → story → subtask: `{"issue_key": "PROJ-100"}`
+    /// - Direct subtasks only: `{"issue_key": "PROJ-123", "max_depth": 1}`
+    #[instrument(skip(self))]
+    pub async fn get_estimate_rollup(
+        &self,
+        params: GetEstimateRollupParams,
+    ) -> ToolResult<ToolResponse<GetEstimateRollupResult>> {
+        self.tools
+            .estimate_rollup_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_estimate_rollup failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// A board's columns, the statuses mapped to them and its estimation field
+    ///
+    /// The board is picked by `board_id`, `board_name` or the first board of
+    /// `project_key`. Returns the columns in board order with the statuses
+    /// mapped to each (with WIP limits where set), what the board estimates in
+    /// and the JQL of its saved filter. `story_points_fields` shows where the
+    /// server reads story points from; a warning says so when the board
+    /// estimates in a different field. Cached per board.
+    ///
+    /// # Examples
+    /// - A project's board: `{"project_key": "PROJ"}`
+    /// - By name: `{"board_name": "Team Board"}`
+    #[instrument(skip(self))]
+    pub async fn get_board_configuration(
+        &self,
+        params: GetBoardConfigurationParams,
+    ) -> ToolResult<ToolResponse<GetBoardConfigurationResult>> {
+        self.tools
+            .board_configuration_tool
+            .execute(params)
             .await
             .map_err(|e| {
-                error!("bulk_add_labels failed: {}", e);
-                anyhow::anyhow!(e)
+                error!("get_board_configuration failed: {}", e);
+                ToolError::from(e)
             })
+            .map(|result| self.respond(result))
+    }
+
+    /// Issues in one column of a board, in rank order
+    ///
+    /// Selects the board like get_board_configuration and runs its saved filter
+    /// narrowed to the statuses mapped to `column` (case-insensitive). The
+    /// query used is returned as `jql`.
+    ///
+    /// # Examples
+    /// - What's in review: `{"project_key": "PROJ", "column": "Review"}`
+    /// - First 10 to do: `{"board_id": 42, "column": "To Do", "limit": 10}`
+    #[instrument(skip(self))]
+    pub async fn get_board_column_issues(
+        &self,
+        params: GetBoardColumnIssuesParams,
+    ) -> ToolResult<ToolResponse<GetBoardColumnIssuesResult>> {
+        self.tools
+            .board_column_issues_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_board_column_issues failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Comments that mention the current user, newest first
+    ///
+    /// Reads the comments of the issues updated since `since` (default
+    /// "7 days ago"; up to `max_issues` issues, optionally limited to
+    /// `project_keys`) and returns those that mention you, with the author,
+    /// time and a snippet around the mention. Pass the returned `cursor` as
+    /// `since` to get only new mentions; mentions already reported by this
+    /// server are left out unless `include_seen` is true.
+    ///
+    /// # Examples
+    /// - The last week: `{}`
+    /// - Since the last check: `{"since": "2024-01-15T10:30:00.000Z"}`
+    /// - One project today: `{"since": "today", "project_keys": ["PROJ"]}`
+    #[instrument(skip(self))]
+    pub async fn get_my_mentions(
+        &self,
+        params: GetMyMentionsParams,
+    ) -> ToolResult<ToolResponse<GetMyMentionsResult>> {
+        self.tools
+            .my_mentions_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_my_mentions failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Which permissions the current user holds
+    ///
+    /// Checks the permissions the tools depend on (browse, create, edit,
+    /// transition, comment, delete, log work, manage sprints) in `project_key`,
+    /// on `issue_key` or, without either, in any project. Answers are cached for
+    /// a few minutes; `refresh` asks JIRA again. Check before a batch of writes
+    /// instead of finding out from a 403. With `permission_precheck` enabled in
+    /// the config, writes are checked against these answers before they are sent.
+    ///
+    /// # Examples
+    /// - In a project: `{"project_key": "PROJ"}`
+    /// - On one issue: `{"issue_key": "PROJ-123"}`
+    /// - Anywhere: `{}`
+    #[instrument(skip(self))]
+    pub async fn get_my_permissions(
+        &self,
+        params: GetMyPermissionsParams,
+    ) -> ToolResult<ToolResponse<GetMyPermissionsResult>> {
+        self.tools
+            .my_permissions_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("get_my_permissions failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
+    }
+
+    /// Todos of several issues in one list
+    ///
+    /// Reads the todos of the issues in `issue_keys`, or of the issues picked by
+    /// `filters` (the get_user_issues parameters), and returns them as one flat
+    /// list of todos tagged with their issue key, with counts per issue and an
+    /// open/completed/wip summary. At most `max_issues` issues are read (default
+    /// 20, max 50). Issues that can't be read are listed in `failed`; the
+    /// others are still returned.
+    ///
+    /// # Examples
+    /// - Named issues: `{"issue_keys": ["PROJ-123", "PROJ-124"]}`
+    /// - Open todos of my in-progress issues: `{"filters": {"status_filter": ["in_progress"]}, "status_filter": ["open"]}`
+    /// - A project's recent issues: `{"filters": {"project_filter": ["PROJ"], "updated_since": "1 week ago"}, "max_issues": 30}`
+    #[instrument(skip(self))]
+    pub async fn list_todos_across_issues(
+        &self,
+        params: ListTodosAcrossIssuesParams,
+    ) -> ToolResult<ToolResponse<ListTodosAcrossIssuesResult>> {
+        self.tools
+            .todos_across_issues_tool
+            .execute(params)
+            .await
+            .map_err(|e| {
+                error!("list_todos_across_issues failed: {}", e);
+                ToolError::from(e)
+            })
+            .map(|result| self.respond(result))
     }
 }
 
 // Add any additional implementation methods here that are NOT MCP tools
 impl JiraMcpServer {
     /// Internal method to refresh current user cache
-    #[allow(dead_code)]
     async fn refresh_current_user_cache(&self) -> JiraMcpResult<()> {
         match self.jira_client.get_current_user().await {
             Ok(user) => {
@@ -1751,6 +3058,19 @@ impl JiraMcpServer {
     }
 }
 
+/// Whether tool parameters add labels anywhere (`labels` or `add_labels`, also nested)
+fn adds_labels(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Object(map) => map.iter().any(|(name, v)| {
+            ((name == "labels" || name == "add_labels")
+                && v.as_array().is_some_and(|labels| !labels.is_empty()))
+                || adds_labels(v)
+        }),
+        serde_json::Value::Array(items) => items.iter().any(adds_labels),
+        _ => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1779,4 +3099,95 @@ mod tests {
         // elapsed is u64, which is always >= 0, so we just check it's a reasonable value
         assert!(elapsed < 10); // Should be very small since we just started
     }
+
+    #[test]
+    fn test_deprecation_notice() {
+        assert_eq!(
+            deprecation_notice("list_attachments").as_deref(),
+            Some(
+                "'list_attachments' is deprecated since 0.10.0 and will be removed; \
+                 call 'list_issue_attachments' instead"
+            )
+        );
+        assert_eq!(deprecation_notice("list_issue_attachments"), None);
+    }
+
+    #[test]
+    fn test_tool_aliases_are_served() {
+        let source = include_str!("lib.rs");
+        let tools_block = &source[source.find("#[mcp_tools]").unwrap()..];
+        let method = |name: &str| {
+            let start = tools_block.find(&format!("    pub async fn {}(", name))?;
+            let chunk = &tools_block[start..];
+            Some(&chunk[..chunk.find("\n    }\n").unwrap()])
+        };
+
+        for entry in TOOL_ALIASES {
+            assert!(
+                method(entry.target).is_some(),
+                "{} points to unknown tool {}",
+                entry.alias,
+                entry.target
+            );
+            let body = method(entry.alias)
+                .unwrap_or_else(|| panic!("alias {} has no #[mcp_tools] method", entry.alias));
+            assert!(
+                body.contains(&format!("self.{}(", entry.target)),
+                "{} does not delegate to {}",
+                entry.alias,
+                entry.target
+            );
+            assert!(
+                body.contains(&format!("self.deprecated(\"{}\"", entry.alias)),
+                "{} does not attach the deprecation notice",
+                entry.alias
+            );
+        }
+    }
+
+    #[test]
+    fn test_mutating_tools_are_audited() {
+        // Tools whose names start with these verbs change JIRA and must go through audited()
+        const MUTATING_PREFIXES: &[&str] = &[
+            "add_",
+            "remove_",
+            "update_",
+            "create_",
+            "delete_",
+            "transition_",
+            "assign_",
+            "move_",
+            "start_",
+            "close_",
+            "cancel_",
+            "complete_",
+            "checkpoint_",
+            "pause_",
+            "link_",
+            "manage_",
+            "bulk_",
+            "notify_",
+            "upload_",
+            "archive_",
+        ];
+        // Tools matching a prefix that only touch local state
+        const LOCAL_ONLY: &[&str] = &["create_watch_query"];
+
+        let source = include_str!("lib.rs");
+        let tools_block = &source[source.find("#[mcp_tools]").unwrap()..];
+
+        for chunk in tools_block.split("    pub async fn ").skip(1) {
+            let name = &chunk[..chunk.find('(').unwrap()];
+            if !MUTATING_PREFIXES.iter().any(|p| name.starts_with(p)) || LOCAL_ONLY.contains(&name)
+            {
+                continue;
+            }
+            let body = &chunk[..chunk.find("\n    }\n").unwrap()];
+            assert!(
+                body.contains(&format!("self.audited(\"{}\"", name)),
+                "{} changes JIRA but is not wrapped in audited()",
+                name
+            );
+        }
+    }
 }
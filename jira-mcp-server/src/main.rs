@@ -3,15 +3,51 @@
 //! This server provides semantic tools for interacting with JIRA without
 //! requiring knowledge of JQL or JIRA internals.
 
-use jira_mcp_server::JiraMcpServer;
+use jira_mcp_server::{diagnostics, JiraMcpServer};
 use pulseengine_mcp_server::McpServerBuilder;
 use tracing::{error, info};
 
+/// Resolves on Ctrl-C, or SIGTERM where there are signals
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = terminate.recv() => {}
+                }
+                return;
+            }
+            Err(e) => error!("Failed to listen for SIGTERM: {}", e),
+        }
+    }
+    let _ = tokio::signal::ctrl_c().await;
+}
+
+/// `--check-config [--project KEY]`: print the diagnostics report and exit,
+/// non-zero when there are problems
+async fn check_config(args: &[String]) -> ! {
+    let project_key = args
+        .iter()
+        .position(|arg| arg == "--project")
+        .and_then(|i| args.get(i + 1));
+    let report = diagnostics::check_config(project_key.map(String::as_str)).await;
+    println!("{}", report);
+    std::process::exit(if report.ok { 0 } else { 1 });
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Configure logging for STDIO transport
     JiraMcpServer::configure_stdio_logging();
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.iter().any(|arg| arg == "--check-config") {
+        check_config(&args).await;
+    }
+
     info!("Starting JIRA MCP Server...");
 
     // Create the JIRA MCP server instance
@@ -27,6 +63,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             eprintln!("  - JIRA_URL environment variable is set");
             eprintln!("  - JIRA authentication is configured (JIRA_AUTH_TYPE, JIRA_TOKEN, etc.)");
             eprintln!("  - JIRA instance is accessible");
+            eprintln!("\nRun with --check-config for a diagnosis with remediation hints.");
             eprintln!("\nFor help, see the README.md file.");
             std::process::exit(1);
         }
@@ -34,12 +71,41 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     info!("Starting MCP server with STDIO transport...");
 
+    // Kept to finish in-flight writes and save state on shutdown
+    let handle = jira_server.clone();
+    let warmup = jira_server.cache_warmup();
+
     // Start the server using the macro-generated infrastructure
     let mut server = jira_server.serve_stdio().await?;
 
     info!("🚀 JIRA MCP Server is running and ready to serve requests");
 
-    server.run().await?;
+    // Prefetch metadata in the background (no-op unless cache_warmup is enabled)
+    let _warmup_handle = warmup.start();
+
+    let run = server.run();
+    tokio::pin!(run);
+    let result = tokio::select! {
+        result = &mut run => {
+            info!("Transport closed, shutting down");
+            handle.shutdown().await;
+            result
+        }
+        _ = shutdown_signal() => {
+            info!("Shutdown requested, refusing new writes");
+            // Keep serving while the in-flight writes finish
+            let shutdown = handle.shutdown();
+            tokio::pin!(shutdown);
+            tokio::select! {
+                _ = &mut shutdown => Ok(()),
+                result = &mut run => {
+                    shutdown.await;
+                    result
+                }
+            }
+        }
+    };
+    result?;
 
     Ok(())
 }
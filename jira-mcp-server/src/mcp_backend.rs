@@ -0,0 +1,328 @@
+//! MCP protocol side of [`JiraMcpServer`]
+//!
+//! Written out rather than generated by `#[mcp_server]`, whose backend cannot
+//! serve resources: `#[mcp_tools]` always registers an empty resource provider,
+//! and the code `#[mcp_resource]` generates does not build against the 0.13
+//! protocol types. Tools still come from the `#[mcp_tools]` methods.
+//!
+//! Resources:
+//! - `jira://issue/{issue_key}/activity`: the activity stream of an issue, as
+//!   get_issue_activity returns it with its defaults
+
+use crate::error::ToolError;
+use crate::tools::issue_activity::GetIssueActivityParams;
+use crate::JiraMcpServer;
+use pulseengine_mcp_protocol::{
+    CallToolRequestParam, CallToolResult, Content, ErrorCode, GetPromptRequestParam,
+    GetPromptResult, Implementation, ListPromptsResult, ListResourceTemplatesResult,
+    ListResourcesResult, ListToolsResult, LoggingCapability, PaginatedRequestParam,
+    PromptsCapability, ProtocolVersion, ReadResourceRequestParam, ReadResourceResult,
+    ResourceContents, ResourceTemplate, ResourcesCapability, ServerCapabilities, ServerInfo,
+    ToolsCapability,
+};
+use pulseengine_mcp_server::{
+    AuthConfig, BackendError, McpBackend, McpServer, McpServerBuilder, McpToolsProvider,
+    ServerConfig, ServerError,
+};
+use serde_json::Value;
+use tracing::error;
+
+const SERVER_NAME: &str = "JIRA MCP Server";
+const SERVER_VERSION: &str = "0.7.0";
+const SERVER_DESCRIPTION: &str = "AI-friendly JIRA integration server with semantic search, \
+                                  commenting, and relationship analysis capabilities";
+
+/// URI template of the issue activity resource
+pub const ISSUE_ACTIVITY_TEMPLATE: &str = "jira://issue/{issue_key}/activity";
+
+/// Error returned to MCP clients, as the JSON-RPC error object they receive
+#[derive(Debug, thiserror::Error)]
+#[error("{0}")]
+pub struct McpError(pub pulseengine_mcp_protocol::Error);
+
+impl From<BackendError> for McpError {
+    fn from(err: BackendError) -> Self {
+        McpError(err.into())
+    }
+}
+
+impl From<McpError> for pulseengine_mcp_protocol::Error {
+    fn from(err: McpError) -> Self {
+        err.0
+    }
+}
+
+/// The protocol only knows a fixed set of codes, so the closest one is picked
+/// by category; the category itself stays in `data`
+impl From<ToolError> for McpError {
+    fn from(err: ToolError) -> Self {
+        let category = err
+            .data
+            .as_ref()
+            .and_then(|data| data["category"].as_str())
+            .unwrap_or_default();
+        let code = match category {
+            "invalid_parameter" | "jql" => ErrorCode::InvalidParams,
+            "not_found" => ErrorCode::ResourceNotFound,
+            "permission" => ErrorCode::Forbidden,
+            "authentication" => ErrorCode::Unauthorized,
+            "rate_limit" => ErrorCode::RateLimitExceeded,
+            _ => ErrorCode::InternalError,
+        };
+        McpError(pulseengine_mcp_protocol::Error {
+            code,
+            message: err.message,
+            data: err.data,
+        })
+    }
+}
+
+/// Issue key of a `jira://issue/{issue_key}/activity` URI
+fn issue_activity_key(uri: &str) -> Option<&str> {
+    uri.strip_prefix("jira://issue/")?
+        .strip_suffix("/activity")
+        .filter(|key| !key.is_empty() && !key.contains('/'))
+}
+
+/// `#[mcp_tools]` only recognizes return types spelled `Result`, so the
+/// [`ToolResult`](crate::error::ToolResult) of a tool arrives serialized as a
+/// whole: `{"Ok": ...}` or `{"Err": ...}`, flagged as a success either way.
+/// This unwraps it, and a [`ToolError`] becomes an `isError` result whose
+/// structured content is the error object with its code and `data`.
+fn unwrap_tool_result(mut result: CallToolResult) -> CallToolResult {
+    let (value, is_error) = match result.structured_content.take() {
+        Some(Value::Object(mut object)) if object.len() == 1 => {
+            if let Some(value) = object.remove("Ok") {
+                (value, false)
+            } else if let Some(value) = object.remove("Err") {
+                (value, true)
+            } else {
+                result.structured_content = Some(Value::Object(object));
+                return result;
+            }
+        }
+        other => {
+            result.structured_content = other;
+            return result;
+        }
+    };
+
+    result.content = vec![Content::text(value.to_string())];
+    result.is_error = Some(is_error);
+    result.structured_content = Some(value);
+    result
+}
+
+impl JiraMcpServer {
+    /// Serve over stdin and stdout, without authentication
+    pub async fn serve_stdio(self) -> Result<McpServer<Self>, ServerError> {
+        let mut auth_config = AuthConfig::memory();
+        auth_config.enabled = false;
+        let config = ServerConfig {
+            server_info: self.get_server_info(),
+            auth_config,
+            ..ServerConfig::default()
+        };
+        McpServer::new(self, config).await
+    }
+
+    /// Read a resource by URI
+    async fn read(&self, uri: &str) -> Result<String, McpError> {
+        let Some(issue_key) = issue_activity_key(uri) else {
+            return Err(McpError(pulseengine_mcp_protocol::Error::invalid_params(
+                format!("Unknown resource: {}", uri),
+            )));
+        };
+
+        let activity = self
+            .tools
+            .get_issue_activity_tool
+            .execute(GetIssueActivityParams {
+                issue_key: issue_key.to_string(),
+                since: None,
+                limit: None,
+                event_types: None,
+            })
+            .await
+            .map_err(|e| {
+                error!("Reading {} failed: {}", uri, e);
+                ToolError::from(e)
+            })?;
+        serde_json::to_string(&activity).map_err(|e| {
+            McpError(pulseengine_mcp_protocol::Error::internal_error(format!(
+                "Failed to serialize {}: {}",
+                uri, e
+            )))
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl McpBackend for JiraMcpServer {
+    type Error = McpError;
+    type Config = ();
+
+    /// The server needs its JIRA connection, so it is built with
+    /// [`JiraMcpServer::new`] instead
+    async fn initialize(_config: ()) -> Result<Self, McpError> {
+        Err(BackendError::NotSupported("use JiraMcpServer::new()".to_string()).into())
+    }
+
+    fn get_server_info(&self) -> ServerInfo {
+        ServerInfo {
+            protocol_version: ProtocolVersion::default(),
+            capabilities: ServerCapabilities {
+                tools: Some(ToolsCapability {
+                    list_changed: Some(false),
+                }),
+                resources: Some(ResourcesCapability {
+                    subscribe: Some(false),
+                    list_changed: Some(false),
+                }),
+                prompts: Some(PromptsCapability {
+                    list_changed: Some(false),
+                }),
+                logging: Some(LoggingCapability {
+                    level: Some("info".to_string()),
+                }),
+                ..Default::default()
+            },
+            server_info: Implementation {
+                name: SERVER_NAME.to_string(),
+                version: SERVER_VERSION.to_string(),
+            },
+            instructions: Some(SERVER_DESCRIPTION.to_string()),
+        }
+    }
+
+    async fn health_check(&self) -> Result<(), McpError> {
+        Ok(())
+    }
+
+    async fn list_tools(
+        &self,
+        _request: PaginatedRequestParam,
+    ) -> Result<ListToolsResult, McpError> {
+        Ok(ListToolsResult {
+            tools: self.get_available_tools(),
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(&self, request: CallToolRequestParam) -> Result<CallToolResult, McpError> {
+        let result = self.call_tool_impl(request).await.map_err(McpError)?;
+        Ok(unwrap_tool_result(result))
+    }
+
+    /// Every resource takes a parameter, so they are all listed as templates
+    async fn list_resources(
+        &self,
+        request: PaginatedRequestParam,
+    ) -> Result<ListResourcesResult, McpError> {
+        Ok(ListResourcesResult {
+            resources: Vec::new(),
+            next_cursor: request.cursor,
+        })
+    }
+
+    async fn list_resource_templates(
+        &self,
+        _request: PaginatedRequestParam,
+    ) -> Result<ListResourceTemplatesResult, McpError> {
+        Ok(ListResourceTemplatesResult {
+            resource_templates: vec![ResourceTemplate {
+                uri_template: ISSUE_ACTIVITY_TEMPLATE.to_string(),
+                name: "issue_activity".to_string(),
+                description: Some(
+                    "Comments, worklogs, field changes, added attachments and added links \
+                     of an issue, oldest first (the 50 most recent)"
+                        .to_string(),
+                ),
+                mime_type: Some("application/json".to_string()),
+            }],
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+    ) -> Result<ReadResourceResult, McpError> {
+        let text = self.read(&request.uri).await?;
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents {
+                uri: request.uri,
+                mime_type: Some("application/json".to_string()),
+                text: Some(text),
+                blob: None,
+                _meta: None,
+            }],
+        })
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: PaginatedRequestParam,
+    ) -> Result<ListPromptsResult, McpError> {
+        Ok(ListPromptsResult {
+            prompts: Vec::new(),
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParam,
+    ) -> Result<GetPromptResult, McpError> {
+        Err(McpError(pulseengine_mcp_protocol::Error::invalid_params(
+            format!("Unknown prompt: {}", request.name),
+        )))
+    }
+}
+
+/// Provides `configure_stdio_logging`
+impl McpServerBuilder for JiraMcpServer {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_activity_key() {
+        assert_eq!(
+            issue_activity_key("jira://issue/PROJ-1/activity"),
+            Some("PROJ-1")
+        );
+        assert_eq!(issue_activity_key("jira://issue//activity"), None);
+        assert_eq!(issue_activity_key("jira://issue/PROJ-1/comments"), None);
+        assert_eq!(issue_activity_key("jira://issue/a/b/activity"), None);
+    }
+
+    #[test]
+    fn test_unwrap_tool_result() {
+        let wrapped = |value: Value| CallToolResult {
+            content: vec![Content::text(value.to_string())],
+            is_error: Some(false),
+            structured_content: Some(value),
+            _meta: None,
+        };
+
+        let ok = unwrap_tool_result(wrapped(serde_json::json!({"Ok": {"count": 1}})));
+        assert_eq!(ok.is_error, Some(false));
+        assert_eq!(ok.structured_content, Some(serde_json::json!({"count": 1})));
+
+        let err = unwrap_tool_result(wrapped(serde_json::json!({"Err": {"code": -32005}})));
+        assert_eq!(err.is_error, Some(true));
+        assert_eq!(
+            err.structured_content,
+            Some(serde_json::json!({"code": -32005}))
+        );
+
+        let other = unwrap_tool_result(wrapped(serde_json::json!({"Other": 1})));
+        assert_eq!(other.is_error, Some(false));
+        assert_eq!(
+            other.structured_content,
+            Some(serde_json::json!({"Other": 1}))
+        );
+    }
+}
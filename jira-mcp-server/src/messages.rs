@@ -0,0 +1,412 @@
+//! Human-readable `message` fields of tool results
+//!
+//! Sprint, bulk and todo results carry a one-line summary next to their
+//! structured fields. `[messages]` in the config picks how it is written:
+//! full sentences (the default), terse ones, or none at all to save tokens, and
+//! the language of the built-in templates. Only the message changes with the
+//! style; the structured fields are the same in every style.
+//!
+//! Messages are rendered from templates with `{name}` placeholders through the
+//! [`message!`] macro, e.g. `message!(messages, "sprint.created", sprint = name)`.
+
+use crate::config::{MessageStyle, MessagesConfig};
+use std::fmt::Display;
+use tracing::warn;
+
+/// Message ID, verbose template, terse template
+type Template = (&'static str, &'static str, &'static str);
+
+const EN: &[Template] = &[
+    (
+        "sprint.issues_moved",
+        "Successfully moved {count} issue(s) to sprint '{sprint}'",
+        "Moved {count} to {sprint}",
+    ),
+    (
+        "sprint.created",
+        "Successfully created sprint '{sprint}' (ID: {sprint_id}) on board {board_id}",
+        "Created sprint {sprint_id}",
+    ),
+    (
+        "sprint.started",
+        "Successfully started sprint '{sprint}' with {count} issue(s)",
+        "Started {sprint}",
+    ),
+    (
+        "sprint.closed",
+        "Successfully closed sprint '{sprint}' ({completed}/{total} completed)",
+        "Closed {sprint}: {completed}/{total} done",
+    ),
+    (
+        "sprint.closed_and_moved",
+        "Successfully closed sprint '{sprint}' ({completed}/{total} completed, {moved} moved to next sprint)",
+        "Closed {sprint}: {completed}/{total} done, {moved} moved",
+    ),
+    (
+        "sprint.updated",
+        "Updated {fields} of sprint '{sprint}' (ID: {sprint_id})",
+        "Updated {fields}",
+    ),
+    (
+        "bulk.created",
+        "Bulk created {succeeded}/{total} issues successfully ({failed} failed)",
+        "{succeeded}/{total} created, {failed} failed",
+    ),
+    (
+        "bulk.transitioned",
+        "Bulk transitioned {succeeded}/{total} issues successfully ({failed} failed)",
+        "{succeeded}/{total} transitioned, {failed} failed",
+    ),
+    (
+        "bulk.updated",
+        "Bulk updated {succeeded}/{total} issues successfully ({failed} failed)",
+        "{succeeded}/{total} updated, {failed} failed",
+    ),
+    (
+        "bulk.assigned",
+        "Bulk assigned {succeeded}/{total} issues successfully ({failed} failed)",
+        "{succeeded}/{total} assigned, {failed} failed",
+    ),
+    (
+        "bulk.labels_updated",
+        "Bulk updated labels on {succeeded}/{total} issues successfully ({failed} failed)",
+        "{succeeded}/{total} labeled, {failed} failed",
+    ),
+    (
+        "todo.base_set",
+        "Base issue set to {issue_key}. You can now omit issue_key in todo commands.",
+        "Base issue: {issue_key}",
+    ),
+    (
+        "todo.added",
+        "Todo added to issue {issue_key}",
+        "Added to {issue_key}",
+    ),
+    (
+        "todo.added_dry_run",
+        "Dry run: todo would be added to issue {issue_key}",
+        "Dry run: would add to {issue_key}",
+    ),
+    (
+        "todo.completed",
+        "Todo completed in issue {issue_key}",
+        "Completed in {issue_key}",
+    ),
+    (
+        "todo.completed_dry_run",
+        "Dry run: todo would be completed in issue {issue_key}",
+        "Dry run: would complete in {issue_key}",
+    ),
+    (
+        "todo.reopened",
+        "Todo reopened in issue {issue_key}",
+        "Reopened in {issue_key}",
+    ),
+    (
+        "todo.reopened_dry_run",
+        "Dry run: todo would be reopened in issue {issue_key}",
+        "Dry run: would reopen in {issue_key}",
+    ),
+    (
+        "todo.work_started",
+        "Started tracking work on todo in issue {issue_key}. Time will be logged when you complete, checkpoint, or auto-checkpoint runs.",
+        "Tracking work on {issue_key}",
+    ),
+    (
+        "todo.checkpoint",
+        "Logged {logged} to issue {issue_key}. Session continues. Total time logged: {total}",
+        "Logged {logged} to {issue_key} (total {total})",
+    ),
+    (
+        "todo.paused",
+        "Logged {logged} to issue {issue_key}. Session paused, you can start work again later.",
+        "Logged {logged} to {issue_key}, paused",
+    ),
+    (
+        "todo.canceled",
+        "Work session canceled. {discarded} of work was discarded (not logged).",
+        "Canceled, {discarded} discarded",
+    ),
+    (
+        "todo.work_completed",
+        "Logged {logged} to issue {issue_key} (total including checkpoints)",
+        "Logged {logged} to {issue_key}",
+    ),
+];
+
+const DE: &[Template] = &[
+    (
+        "sprint.issues_moved",
+        "{count} Vorgang/Vorgänge in Sprint '{sprint}' verschoben",
+        "{count} nach {sprint} verschoben",
+    ),
+    (
+        "sprint.created",
+        "Sprint '{sprint}' (ID: {sprint_id}) auf Board {board_id} angelegt",
+        "Sprint {sprint_id} angelegt",
+    ),
+    (
+        "sprint.started",
+        "Sprint '{sprint}' mit {count} Vorgang/Vorgängen gestartet",
+        "{sprint} gestartet",
+    ),
+    (
+        "sprint.closed",
+        "Sprint '{sprint}' abgeschlossen ({completed}/{total} erledigt)",
+        "{sprint} abgeschlossen: {completed}/{total} erledigt",
+    ),
+    (
+        "sprint.closed_and_moved",
+        "Sprint '{sprint}' abgeschlossen ({completed}/{total} erledigt, {moved} in den nächsten Sprint verschoben)",
+        "{sprint} abgeschlossen: {completed}/{total} erledigt, {moved} verschoben",
+    ),
+    (
+        "sprint.updated",
+        "{fields} von Sprint '{sprint}' (ID: {sprint_id}) geändert",
+        "{fields} geändert",
+    ),
+    (
+        "bulk.created",
+        "{succeeded}/{total} Vorgänge angelegt ({failed} fehlgeschlagen)",
+        "{succeeded}/{total} angelegt, {failed} fehlgeschlagen",
+    ),
+    (
+        "bulk.transitioned",
+        "Status von {succeeded}/{total} Vorgängen geändert ({failed} fehlgeschlagen)",
+        "{succeeded}/{total} übergeleitet, {failed} fehlgeschlagen",
+    ),
+    (
+        "bulk.updated",
+        "{succeeded}/{total} Vorgänge aktualisiert ({failed} fehlgeschlagen)",
+        "{succeeded}/{total} aktualisiert, {failed} fehlgeschlagen",
+    ),
+    (
+        "bulk.assigned",
+        "{succeeded}/{total} Vorgänge zugewiesen ({failed} fehlgeschlagen)",
+        "{succeeded}/{total} zugewiesen, {failed} fehlgeschlagen",
+    ),
+    (
+        "bulk.labels_updated",
+        "Labels von {succeeded}/{total} Vorgängen aktualisiert ({failed} fehlgeschlagen)",
+        "{succeeded}/{total} gelabelt, {failed} fehlgeschlagen",
+    ),
+    (
+        "todo.base_set",
+        "Basisvorgang ist jetzt {issue_key}. issue_key kann in Todo-Befehlen weggelassen werden.",
+        "Basisvorgang: {issue_key}",
+    ),
+    (
+        "todo.added",
+        "Todo zu Vorgang {issue_key} hinzugefügt",
+        "Zu {issue_key} hinzugefügt",
+    ),
+    (
+        "todo.added_dry_run",
+        "Probelauf: Todo würde zu Vorgang {issue_key} hinzugefügt",
+        "Probelauf: würde zu {issue_key} hinzufügen",
+    ),
+    (
+        "todo.completed",
+        "Todo in Vorgang {issue_key} erledigt",
+        "In {issue_key} erledigt",
+    ),
+    (
+        "todo.completed_dry_run",
+        "Probelauf: Todo in Vorgang {issue_key} würde erledigt",
+        "Probelauf: würde in {issue_key} erledigen",
+    ),
+    (
+        "todo.reopened",
+        "Todo in Vorgang {issue_key} wieder geöffnet",
+        "In {issue_key} wieder geöffnet",
+    ),
+    (
+        "todo.reopened_dry_run",
+        "Probelauf: Todo in Vorgang {issue_key} würde wieder geöffnet",
+        "Probelauf: würde in {issue_key} wieder öffnen",
+    ),
+    (
+        "todo.work_started",
+        "Zeiterfassung für Todo in Vorgang {issue_key} gestartet. Die Zeit wird beim Abschließen, bei einem Checkpoint oder beim automatischen Checkpoint gebucht.",
+        "Zeiterfassung für {issue_key} läuft",
+    ),
+    (
+        "todo.checkpoint",
+        "{logged} auf Vorgang {issue_key} gebucht. Die Sitzung läuft weiter. Insgesamt gebucht: {total}",
+        "{logged} auf {issue_key} gebucht (gesamt {total})",
+    ),
+    (
+        "todo.paused",
+        "{logged} auf Vorgang {issue_key} gebucht. Sitzung pausiert, die Arbeit kann später fortgesetzt werden.",
+        "{logged} auf {issue_key} gebucht, pausiert",
+    ),
+    (
+        "todo.canceled",
+        "Sitzung abgebrochen. {discarded} Arbeitszeit wurden verworfen (nicht gebucht).",
+        "Abgebrochen, {discarded} verworfen",
+    ),
+    (
+        "todo.work_completed",
+        "{logged} auf Vorgang {issue_key} gebucht (inklusive Checkpoints)",
+        "{logged} auf {issue_key} gebucht",
+    ),
+];
+
+/// Renders result messages in the configured style and locale
+#[derive(Debug, Clone)]
+pub struct Messages {
+    style: MessageStyle,
+    catalog: &'static [Template],
+}
+
+impl Default for Messages {
+    fn default() -> Self {
+        Self {
+            style: MessageStyle::Verbose,
+            catalog: EN,
+        }
+    }
+}
+
+impl Messages {
+    pub fn new(config: &MessagesConfig) -> Self {
+        Self {
+            style: config.style,
+            catalog: config.locale.as_deref().map_or(EN, catalog_for_locale),
+        }
+    }
+
+    /// Render the template `id` with `{name}` placeholders replaced by `args`;
+    /// empty when messages are turned off
+    pub fn render(&self, id: &str, args: &[(&str, &dyn Display)]) -> String {
+        let template = self
+            .catalog
+            .iter()
+            .chain(EN)
+            .find(|(template_id, _, _)| *template_id == id);
+        let Some((_, verbose, terse)) = template else {
+            warn!("No message template '{}'", id);
+            return String::new();
+        };
+
+        let mut message = match self.style {
+            MessageStyle::Verbose => verbose.to_string(),
+            MessageStyle::Terse => terse.to_string(),
+            MessageStyle::None => return String::new(),
+        };
+        for (name, value) in args {
+            message = message.replace(&format!("{{{}}}", name), &value.to_string());
+        }
+        message
+    }
+}
+
+/// Built-in templates for a locale such as "de", "de-AT" or "de_CH.UTF-8";
+/// English for locales without templates
+fn catalog_for_locale(locale: &str) -> &'static [Template] {
+    let language = locale
+        .split(['-', '_', '.'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    match language.as_str() {
+        "en" | "" => EN,
+        "de" => DE,
+        _ => {
+            warn!(
+                "No message templates for locale '{}', using English",
+                locale
+            );
+            EN
+        }
+    }
+}
+
+/// Render a result message, e.g.
+/// `message!(jira_client.messages(), "todo.added", issue_key = key)`
+macro_rules! message {
+    ($messages:expr, $id:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $messages.render(
+            $id,
+            &[$((stringify!($name), &$value as &dyn std::fmt::Display)),*],
+        )
+    };
+}
+pub(crate) use message;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(style: MessageStyle, locale: Option<&str>) -> Messages {
+        Messages::new(&MessagesConfig {
+            style,
+            locale: locale.map(String::from),
+        })
+    }
+
+    #[test]
+    fn test_render_styles() {
+        let render = |messages: Messages| {
+            message!(
+                messages,
+                "sprint.issues_moved",
+                count = 3,
+                sprint = "Sprint 5"
+            )
+        };
+
+        assert_eq!(
+            render(messages(MessageStyle::Verbose, None)),
+            "Successfully moved 3 issue(s) to sprint 'Sprint 5'"
+        );
+        assert_eq!(
+            render(messages(MessageStyle::Terse, None)),
+            "Moved 3 to Sprint 5"
+        );
+        assert_eq!(render(messages(MessageStyle::None, None)), "");
+        assert_eq!(
+            render(messages(MessageStyle::Terse, Some("de-AT"))),
+            "3 nach Sprint 5 verschoben"
+        );
+        assert_eq!(
+            render(messages(MessageStyle::Terse, Some("pt_BR"))),
+            "Moved 3 to Sprint 5"
+        );
+    }
+
+    #[test]
+    fn test_catalogs_match() {
+        let placeholders = |template: &str| {
+            let mut names: Vec<String> = template
+                .split('{')
+                .skip(1)
+                .filter_map(|rest| rest.split_once('}').map(|(name, _)| name.to_string()))
+                .collect();
+            names.sort();
+            names.dedup();
+            names
+        };
+
+        // Terse templates may leave values out but not add any
+        for (id, verbose, terse) in EN.iter().chain(DE) {
+            let available = placeholders(verbose);
+            assert!(
+                placeholders(terse)
+                    .iter()
+                    .all(|name| available.contains(name)),
+                "{}",
+                id
+            );
+        }
+
+        assert_eq!(EN.len(), DE.len());
+        for (id, verbose, _) in DE {
+            let (_, en_verbose, _) = EN
+                .iter()
+                .find(|(en_id, _, _)| en_id == id)
+                .unwrap_or_else(|| panic!("{} is missing in English", id));
+            assert_eq!(placeholders(verbose), placeholders(en_verbose), "{}", id);
+        }
+    }
+}
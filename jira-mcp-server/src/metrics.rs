@@ -0,0 +1,232 @@
+//! Per-call performance metrics of tool calls
+//!
+//! A tool call runs inside [`measure`], which puts a set of counters in a
+//! task-local. `JiraClient::with_timeout` counts every request against it,
+//! retried requests count as retries and `MetadataCache` hits as cache hits, so
+//! tools need no bookkeeping of their own. Work spawned onto other tasks (the
+//! bulk tools' join sets) keeps counting when wrapped in [`in_current_scope`].
+//! Outside a measured call the counters are simply not there and nothing is
+//! recorded.
+//!
+//! [`ToolStats`] keeps the last calls of every tool for the rolling averages in
+//! get_server_status.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Calls per tool the rolling averages are taken over
+const STATS_WINDOW: usize = 50;
+
+/// Timing and request counts of one tool call
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ToolPerformance {
+    /// Time taken by the call in milliseconds
+    pub duration_ms: u64,
+
+    /// Requests sent to JIRA, retries included
+    pub api_calls: u32,
+
+    /// Metadata lookups answered from the cache
+    pub cache_hits: u32,
+
+    /// Requests repeated after a retryable failure
+    pub retries: u32,
+}
+
+/// Results that carry a [`ToolPerformance`] block, filled in by [`measure`]'s
+/// caller once the call is done
+pub trait Measured {
+    fn set_performance(&mut self, performance: ToolPerformance);
+}
+
+/// Implement [`Measured`] for results with a `performance: ToolPerformance`
+/// field
+macro_rules! impl_measured {
+    ($($result:ty),* $(,)?) => {
+        $(
+            impl $crate::metrics::Measured for $result {
+                fn set_performance(&mut self, performance: $crate::metrics::ToolPerformance) {
+                    self.performance = performance;
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_measured;
+
+#[derive(Debug, Default)]
+struct Counters {
+    api_calls: AtomicU32,
+    cache_hits: AtomicU32,
+    retries: AtomicU32,
+}
+
+tokio::task_local! {
+    static COUNTERS: Arc<Counters>;
+}
+
+/// Run a tool call with fresh counters and return what it used
+pub async fn measure<F: Future>(call: F) -> (F::Output, ToolPerformance) {
+    let counters = Arc::new(Counters::default());
+    let started = Instant::now();
+    let output = COUNTERS.scope(Arc::clone(&counters), call).await;
+
+    let performance = ToolPerformance {
+        duration_ms: started.elapsed().as_millis() as u64,
+        api_calls: counters.api_calls.load(Ordering::Relaxed),
+        cache_hits: counters.cache_hits.load(Ordering::Relaxed),
+        retries: counters.retries.load(Ordering::Relaxed),
+    };
+    (output, performance)
+}
+
+/// Count `future` against the current call's counters even when it is spawned
+/// onto another task
+///
+/// The counters are picked up here, not when the future first runs, since by
+/// then it may be on a task without them.
+pub fn in_current_scope<F: Future>(future: F) -> impl Future<Output = F::Output> {
+    let counters = COUNTERS.try_with(Arc::clone).ok();
+    async move {
+        match counters {
+            Some(counters) => COUNTERS.scope(counters, future).await,
+            None => future.await,
+        }
+    }
+}
+
+fn count(counter: impl Fn(&Counters) -> &AtomicU32, n: u32) {
+    let _ = COUNTERS.try_with(|counters| counter(counters).fetch_add(n, Ordering::Relaxed));
+}
+
+/// Count requests sent to JIRA
+pub fn record_api_calls(n: u32) {
+    count(|counters| &counters.api_calls, n);
+}
+
+/// Count a metadata lookup answered from the cache
+pub fn record_cache_hit() {
+    count(|counters| &counters.cache_hits, 1);
+}
+
+/// Count a request repeated after a retryable failure
+pub fn record_retry() {
+    count(|counters| &counters.retries, 1);
+}
+
+/// Rolling averages of one tool's recent calls
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ToolPerformanceSummary {
+    /// Calls the averages cover (the most recent ones)
+    pub calls: usize,
+
+    /// Average call duration in milliseconds
+    pub avg_duration_ms: u64,
+
+    /// Slowest call in milliseconds
+    pub max_duration_ms: u64,
+
+    /// Average JIRA requests per call
+    pub avg_api_calls: f64,
+
+    /// Average cache hits per call
+    pub avg_cache_hits: f64,
+
+    /// Retries over all covered calls
+    pub total_retries: u32,
+}
+
+/// Recent [`ToolPerformance`] blocks per tool
+#[derive(Debug, Default)]
+pub struct ToolStats {
+    calls: Mutex<HashMap<String, VecDeque<ToolPerformance>>>,
+}
+
+impl ToolStats {
+    pub fn record(&self, tool: &str, performance: &ToolPerformance) {
+        let mut calls = self.calls.lock().unwrap();
+        let recent = calls.entry(tool.to_string()).or_default();
+        if recent.len() == STATS_WINDOW {
+            recent.pop_front();
+        }
+        recent.push_back(performance.clone());
+    }
+
+    /// Averages per tool, for tools called at least once
+    pub fn summary(&self) -> BTreeMap<String, ToolPerformanceSummary> {
+        let calls = self.calls.lock().unwrap();
+        calls
+            .iter()
+            .filter(|(_, recent)| !recent.is_empty())
+            .map(|(tool, recent)| {
+                let n = recent.len();
+                let sum =
+                    |value: fn(&ToolPerformance) -> u64| recent.iter().map(value).sum::<u64>();
+                let summary = ToolPerformanceSummary {
+                    calls: n,
+                    avg_duration_ms: sum(|p| p.duration_ms) / n as u64,
+                    max_duration_ms: recent.iter().map(|p| p.duration_ms).max().unwrap_or(0),
+                    avg_api_calls: sum(|p| p.api_calls as u64) as f64 / n as f64,
+                    avg_cache_hits: sum(|p| p.cache_hits as u64) as f64 / n as f64,
+                    total_retries: recent.iter().map(|p| p.retries).sum(),
+                };
+                (tool.clone(), summary)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_measure_counts_spawned_work() {
+        let ((), performance) = measure(async {
+            record_api_calls(1);
+            record_cache_hit();
+            tokio::spawn(in_current_scope(async {
+                record_api_calls(2);
+                record_retry();
+            }))
+            .await
+            .unwrap();
+        })
+        .await;
+
+        assert_eq!(performance.api_calls, 3);
+        assert_eq!(performance.cache_hits, 1);
+        assert_eq!(performance.retries, 1);
+
+        // Outside a measured call nothing is counted, and nothing breaks
+        record_api_calls(1);
+        let ((), performance) = measure(async {}).await;
+        assert_eq!(performance.api_calls, 0);
+    }
+
+    #[test]
+    fn test_tool_stats_window() {
+        let stats = ToolStats::default();
+        for duration_ms in 0..(STATS_WINDOW as u64 + 10) {
+            stats.record(
+                "search_issues",
+                &ToolPerformance {
+                    duration_ms,
+                    api_calls: 2,
+                    ..Default::default()
+                },
+            );
+        }
+
+        let summary = &stats.summary()["search_issues"];
+        assert_eq!(summary.calls, STATS_WINDOW);
+        assert_eq!(summary.max_duration_ms, STATS_WINDOW as u64 + 9);
+        assert_eq!(summary.avg_api_calls, 2.0);
+    }
+}
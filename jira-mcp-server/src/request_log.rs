@@ -0,0 +1,235 @@
+//! Log of outbound JIRA requests
+//!
+//! With `request_log.enabled`, every request made through `JiraClient` is
+//! recorded with its method, endpoint, query parameters, payload size, outcome
+//! and duration: as a structured `debug!` event and in an in-memory ring buffer
+//! read by the get_recent_requests tool. Credentials never end up in an entry:
+//! secret-looking query parameters are redacted, request bodies are recorded by
+//! size only and the Authorization header is reduced to its scheme.
+
+use crate::audit::{is_secret_key, truncate, REDACTED};
+use crate::config::{AuthConfig, JiraConfig};
+use crate::error::JiraMcpError;
+use chrono::Utc;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+use tracing::debug;
+
+/// Longest query parameter value or error message kept in an entry
+const MAX_VALUE_CHARS: usize = 500;
+
+/// One outbound request
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RequestLogEntry {
+    /// When the request finished (RFC 3339, UTC)
+    pub timestamp: String,
+
+    /// HTTP method, e.g. "GET"
+    pub method: String,
+
+    /// Path below the JIRA base URL, e.g. "api/issue/PROJ-1"
+    pub endpoint: String,
+
+    /// Query parameters, with secret values redacted
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub query: BTreeMap<String, String>,
+
+    /// Size of the request body in bytes, for requests that send one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payload_bytes: Option<usize>,
+
+    /// Authorization scheme sent, with the credential redacted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<String>,
+
+    /// "ok", or the error category of a failed request (e.g. "not_found")
+    pub status: String,
+
+    /// Error message of a failed request
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+
+    /// Request duration in milliseconds
+    pub duration_ms: u64,
+}
+
+/// Bounded in-memory log of outbound requests
+#[derive(Debug)]
+pub struct RequestLog {
+    enabled: bool,
+    capacity: usize,
+    authorization: Option<String>,
+    entries: Mutex<VecDeque<RequestLogEntry>>,
+}
+
+impl RequestLog {
+    pub fn new(config: &JiraConfig) -> Self {
+        let authorization = match &config.auth {
+            AuthConfig::Basic { .. } => Some(format!("Basic {}", REDACTED)),
+            AuthConfig::PersonalAccessToken(_) | AuthConfig::Bearer(_) => {
+                Some(format!("Bearer {}", REDACTED))
+            }
+            AuthConfig::Anonymous => None,
+        };
+
+        Self {
+            enabled: config.request_log.enabled,
+            capacity: config.request_log.capacity,
+            authorization,
+            entries: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Whether requests are recorded
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Record a finished request; a no-op unless the log is enabled
+    ///
+    /// `endpoint` is the path below the base URL and may carry a query string.
+    pub fn record(
+        &self,
+        method: &str,
+        endpoint: &str,
+        payload_bytes: Option<usize>,
+        outcome: Result<(), &JiraMcpError>,
+        duration: Duration,
+    ) {
+        if !self.enabled {
+            return;
+        }
+
+        let (path, query) = split_endpoint(endpoint);
+        let entry = RequestLogEntry {
+            timestamp: Utc::now().to_rfc3339(),
+            method: method.to_string(),
+            endpoint: path.to_string(),
+            query,
+            payload_bytes,
+            authorization: self.authorization.clone(),
+            status: match outcome {
+                Ok(()) => "ok".to_string(),
+                Err(e) => e.category().to_string(),
+            },
+            error: outcome
+                .err()
+                .map(|e| truncate(&e.to_string(), MAX_VALUE_CHARS)),
+            duration_ms: duration.as_millis() as u64,
+        };
+
+        debug!(
+            method = %entry.method,
+            endpoint = %entry.endpoint,
+            query = ?entry.query,
+            payload_bytes = ?entry.payload_bytes,
+            status = %entry.status,
+            duration_ms = entry.duration_ms,
+            "JIRA request"
+        );
+
+        let Ok(mut entries) = self.entries.lock() else {
+            return;
+        };
+        entries.push_back(entry);
+        while entries.len() > self.capacity {
+            entries.pop_front();
+        }
+    }
+
+    /// The most recent entries, newest first
+    pub fn recent(&self, limit: usize) -> Vec<RequestLogEntry> {
+        self.entries
+            .lock()
+            .map(|entries| entries.iter().rev().take(limit).cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Split "path?a=1&b=2" into the path and its redacted query parameters
+fn split_endpoint(endpoint: &str) -> (&str, BTreeMap<String, String>) {
+    let Some((path, query)) = endpoint.split_once('?') else {
+        return (endpoint, BTreeMap::new());
+    };
+
+    let params = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = if is_secret_key(&name.to_lowercase()) {
+                REDACTED.to_string()
+            } else {
+                truncate(value, MAX_VALUE_CHARS)
+            };
+            (name.to_string(), value)
+        })
+        .collect();
+
+    (path, params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RequestLogConfig;
+
+    fn log(capacity: usize) -> RequestLog {
+        RequestLog::new(&JiraConfig {
+            auth: AuthConfig::PersonalAccessToken("s3cr3t".to_string()),
+            request_log: RequestLogConfig {
+                enabled: true,
+                capacity,
+            },
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_redacts_secrets() {
+        let log = log(10);
+        log.record(
+            "GET",
+            "api/user/search?query=jane&access_token=abc123&apiKey=xyz",
+            None,
+            Ok(()),
+            Duration::from_millis(12),
+        );
+
+        let entry = &log.recent(1)[0];
+        assert_eq!(entry.endpoint, "api/user/search");
+        assert_eq!(entry.query["query"], "jane");
+        assert_eq!(entry.query["access_token"], REDACTED);
+        assert_eq!(entry.query["apiKey"], REDACTED);
+        assert_eq!(entry.authorization.as_deref(), Some("Bearer [REDACTED]"));
+
+        let serialized = serde_json::to_string(entry).unwrap();
+        assert!(!serialized.contains("abc123"));
+        assert!(!serialized.contains("s3cr3t"));
+    }
+
+    #[test]
+    fn test_ring_buffer_and_disabled() {
+        let log = log(2);
+        for key in ["PROJ-1", "PROJ-2", "PROJ-3"] {
+            log.record(
+                "GET",
+                &format!("api/issue/{}", key),
+                None,
+                Err(&JiraMcpError::not_found("issue", key)),
+                Duration::ZERO,
+            );
+        }
+
+        let endpoints: Vec<String> = log.recent(10).into_iter().map(|e| e.endpoint).collect();
+        assert_eq!(endpoints, vec!["api/issue/PROJ-3", "api/issue/PROJ-2"]);
+        assert_eq!(log.recent(1)[0].status, "not_found");
+
+        let disabled = RequestLog::new(&JiraConfig::default());
+        disabled.record("GET", "api/myself", None, Ok(()), Duration::ZERO);
+        assert!(disabled.recent(10).is_empty());
+    }
+}
@@ -4,18 +4,29 @@
 //! like "story", "bug", "in_progress" to actual JIRA issue types and statuses,
 //! and for building JQL queries from natural language parameters.
 
-use crate::cache::{IssueTypeInfo, MetadataCache};
+use crate::cache::{IssueTypeInfo, MetadataCache, StatusInfo};
 use crate::config::JiraConfig;
 use crate::error::{JiraMcpError, JiraMcpResult};
-use std::collections::HashSet;
+use crate::jira_client::JiraClient;
+use crate::jql;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::{debug, warn};
 
+pub mod date_expr;
+
+pub use date_expr::{resolve_date, resolve_instant, DateExpr};
+
 /// Semantic mapper that converts AI-friendly parameters to JIRA concepts
 #[derive(Debug)]
 pub struct SemanticMapper {
-    config: Arc<JiraConfig>,
     cache: Arc<MetadataCache>,
+    /// Configured mappings with the locale aliases merged in
+    issue_type_aliases: HashMap<String, Vec<String>>,
+    status_aliases: HashMap<String, Vec<String>>,
+    priority_aliases: HashMap<String, Vec<String>>,
 }
 
 /// JQL query builder result
@@ -24,6 +35,290 @@ pub struct JqlQuery {
     pub jql: String,
     pub estimated_results: Option<usize>,
     pub complexity: QueryComplexity,
+    /// Conditions joined with AND, each with the input it came from
+    pub clauses: Vec<JqlClause>,
+    /// Inputs that were given but left out of the query
+    pub ignored: Vec<IgnoredInput>,
+}
+
+/// A condition of a built query and the input that produced it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JqlClause {
+    /// Parameter name, or "default" for conditions nobody asked for
+    pub input: String,
+    pub jql: String,
+}
+
+impl JqlClause {
+    pub fn new(input: impl Into<String>, jql: impl Into<String>) -> Self {
+        Self {
+            input: input.into(),
+            jql: jql.into(),
+        }
+    }
+}
+
+/// An input that did not make it into the query, and why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IgnoredInput {
+    pub input: String,
+    pub reason: String,
+}
+
+impl JqlQuery {
+    /// Join `clauses` with AND and order by `order`
+    ///
+    /// Without any clause the query is limited to issues created in the last
+    /// 30 days, so it is not unbounded on JIRA Cloud. Complexity follows the
+    /// number of clauses, starting from `complexity`.
+    pub fn from_clauses(
+        mut clauses: Vec<JqlClause>,
+        order: &str,
+        mut complexity: QueryComplexity,
+    ) -> Self {
+        if clauses.len() > 3 {
+            complexity = QueryComplexity::Complex;
+        } else if clauses.len() > 1 {
+            complexity = QueryComplexity::Moderate;
+        }
+
+        if clauses.is_empty() {
+            clauses.push(JqlClause::new("default", "created >= -30d"));
+        }
+        let conditions = clauses
+            .iter()
+            .map(|clause| clause.jql.as_str())
+            .collect::<Vec<_>>()
+            .join(" AND ");
+
+        Self {
+            jql: format!("{} ORDER BY {}", conditions, order),
+            estimated_results: None,
+            complexity,
+            clauses,
+            ignored: Vec::new(),
+        }
+    }
+
+    /// Add a condition to the built query, keeping its ordering
+    ///
+    /// The clause replaces the 30-day default of a query without filters.
+    pub fn and(mut self, clause: JqlClause) -> Self {
+        self.clauses.retain(|existing| existing.input != "default");
+        self.clauses.push(clause);
+        let conditions = self
+            .clauses
+            .iter()
+            .map(|clause| clause.jql.as_str())
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        self.jql = match self.jql.find("ORDER BY") {
+            Some(index) => format!("{} {}", conditions, &self.jql[index..]),
+            None => conditions,
+        };
+        self
+    }
+
+    /// Record an input that was left out of the query
+    pub fn ignore(&mut self, input: impl Into<String>, reason: impl Into<String>) {
+        self.ignored.push(IgnoredInput {
+            input: input.into(),
+            reason: reason.into(),
+        });
+    }
+
+    /// Record each of `lists` that was given as an empty list
+    ///
+    /// Tools drop empty lists before building the query, so they would
+    /// otherwise vanish without a trace.
+    pub fn ignore_empty_lists(&mut self, lists: &[(&str, Option<&Vec<String>>)]) {
+        for (input, list) in lists {
+            if list.is_some_and(|list| list.is_empty()) {
+                self.ignore(*input, "empty list");
+            }
+        }
+    }
+
+    /// One line per input saying which JQL it became, then the ordering, then
+    /// the inputs that were ignored
+    pub fn explanation(&self) -> Vec<String> {
+        let mut lines: Vec<String> = self
+            .clauses
+            .iter()
+            .map(|clause| match clause.input.as_str() {
+                "default" => format!("no filters given: {} (last 30 days only)", clause.jql),
+                input => format!("{}: {}", input, clause.jql),
+            })
+            .collect();
+        if let Some(index) = self.jql.find("ORDER BY") {
+            lines.push(format!("ordering: {}", &self.jql[index..]));
+        }
+        lines.extend(
+            self.ignored
+                .iter()
+                .map(|ignored| format!("{}: ignored, {}", ignored.input, ignored.reason)),
+        );
+        lines
+    }
+
+    /// Replace the default ORDER BY clause with the requested ordering
+    pub fn with_order_by(mut self, order_by: &OrderBy) -> Self {
+        let conditions = match self.jql.find("ORDER BY") {
+            Some(index) => self.jql[..index].trim_end(),
+            None => self.jql.trim_end(),
+        };
+        self.jql = if conditions.is_empty() {
+            order_by.to_jql()
+        } else {
+            format!("{} {}", conditions, order_by.to_jql())
+        };
+        self
+    }
+}
+
+/// Fields that results can be ordered by
+pub const ORDER_BY_FIELDS: &[&str] = &[
+    "created",
+    "updated",
+    "priority",
+    "duedate",
+    "status",
+    "rank",
+    "key",
+    "lastviewed",
+];
+
+/// Sort direction for ordered results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SortDirection {
+    #[serde(alias = "ASC", alias = "ascending")]
+    Asc,
+    #[default]
+    #[serde(alias = "DESC", alias = "descending")]
+    Desc,
+}
+
+/// Issue fields a text query can be limited to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TextField {
+    Summary,
+    Description,
+    #[serde(alias = "comment")]
+    Comments,
+}
+
+impl TextField {
+    fn jql_field(self) -> jql::Field {
+        match self {
+            TextField::Summary => jql::SUMMARY,
+            TextField::Description => jql::DESCRIPTION,
+            TextField::Comments => jql::COMMENT,
+        }
+    }
+
+    /// `(summary ~ "..." OR description ~ "...")` over the given fields, with
+    /// the text matched literally and as a phrase (see [`jql::quote_text`])
+    pub fn search_clause(fields: &[TextField], text: &str) -> String {
+        let term = jql::quote_text(text);
+        let mut conditions: Vec<String> = Vec::new();
+        for field in fields {
+            let condition = format!("{} ~ {}", field.jql_field(), term);
+            if !conditions.contains(&condition) {
+                conditions.push(condition);
+            }
+        }
+        match conditions.as_slice() {
+            [condition] => condition.clone(),
+            _ => format!("({})", conditions.join(" OR ")),
+        }
+    }
+}
+
+/// Requested ordering of search results
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct OrderBy {
+    /// Field to order by
+    /// Examples: "created", "updated", "priority", "duedate", "status", "rank", "key", "lastViewed"
+    pub field: String,
+
+    /// Sort direction: "asc" or "desc" (default: "desc")
+    #[serde(default)]
+    pub direction: SortDirection,
+}
+
+impl OrderBy {
+    /// Validate the field against the whitelist
+    ///
+    /// Rank only has a meaning within a board, so it needs `has_agile_context`.
+    pub fn validate(&self, has_agile_context: bool) -> JiraMcpResult<()> {
+        let field = self.field.trim().to_lowercase();
+        if !ORDER_BY_FIELDS.contains(&field.as_str()) {
+            return Err(JiraMcpError::invalid_param(
+                "order_by",
+                format!(
+                    "Cannot order by '{}'. Supported fields: {}",
+                    self.field,
+                    ORDER_BY_FIELDS.join(", ")
+                ),
+            ));
+        }
+
+        if field == "rank" && !has_agile_context {
+            return Err(JiraMcpError::invalid_param(
+                "order_by",
+                "Ordering by rank follows a board's backlog order, so it requires a board filter. \
+                 Add a board filter or order by 'priority' instead.",
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Render as a JQL ORDER BY clause
+    pub fn to_jql(&self) -> String {
+        let field = match self.field.trim().to_lowercase().as_str() {
+            "rank" => "Rank".to_string(),
+            "lastviewed" => "lastViewed".to_string(),
+            other => other.to_string(),
+        };
+        let direction = match self.direction {
+            SortDirection::Asc => "ASC",
+            SortDirection::Desc => "DESC",
+        };
+        format!("ORDER BY {} {}", field, direction)
+    }
+}
+
+/// How search_issues' `recent` shortcut is expressed in JQL
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecentFilter {
+    /// Issues the current user viewed, most recently viewed first
+    History,
+
+    /// Fallback for instances without the issueHistory() function: issues the
+    /// current user created, reported or is assigned, most recently updated first
+    Involved,
+}
+
+impl RecentFilter {
+    fn clause(self) -> &'static str {
+        match self {
+            RecentFilter::History => "issuekey in issueHistory()",
+            RecentFilter::Involved => {
+                "(creator = currentUser() OR reporter = currentUser() OR assignee = currentUser())"
+            }
+        }
+    }
+
+    fn default_order(self) -> &'static str {
+        match self {
+            RecentFilter::History => "lastViewed DESC",
+            RecentFilter::Involved => "updated DESC",
+        }
+    }
 }
 
 /// Query complexity indicator
@@ -37,97 +332,331 @@ pub enum QueryComplexity {
 impl SemanticMapper {
     /// Create a new semantic mapper
     pub fn new(config: Arc<JiraConfig>, cache: Arc<MetadataCache>) -> Self {
-        Self { config, cache }
+        let locale = &config.locale_aliases;
+        let issue_type_aliases =
+            with_locale_aliases(config.issue_type_mappings.clone(), &locale.issue_types);
+        let status_aliases =
+            with_locale_aliases(config.status_category_mappings.clone(), &locale.statuses);
+        let priority_aliases = configured_priority_aliases(&config);
+
+        Self {
+            cache,
+            issue_type_aliases,
+            status_aliases,
+            priority_aliases,
+        }
     }
 
     /// Map semantic issue types to JIRA issue type names
+    ///
+    /// Resolves against the project's issue types when they are cached (see
+    /// `load_issue_types`), falling back to the instance-wide list and then to the
+    /// configured mappings. Against a known list, unknown types are rejected with
+    /// suggestions instead of producing JQL that JIRA rejects.
     pub fn map_issue_types(
         &self,
         semantic_types: &[String],
         project_key: Option<&str>,
     ) -> JiraMcpResult<Vec<String>> {
-        let mut jira_types = Vec::new();
+        let known = self.known_issue_types(project_key);
+        let mut jira_types: Vec<String> = Vec::new();
 
         for semantic_type in semantic_types {
-            let semantic_lower = semantic_type.to_lowercase();
-
-            // First try project-specific issue types from cache
-            if let Some(project) = project_key {
-                if let Some(project_types) = self.cache.get_project_issue_types(project) {
-                    let matching_types =
-                        self.find_matching_project_types(&semantic_lower, &project_types);
-                    if !matching_types.is_empty() {
-                        jira_types.extend(matching_types);
-                        continue;
+            let matches = match &known {
+                Some(known) => {
+                    let matches = self.match_issue_types(semantic_type, known);
+                    if matches.is_empty() {
+                        return Err(unknown_issue_type("issue_types", semantic_type, known));
                     }
+                    matches
                 }
-            }
+                None => match self
+                    .issue_type_aliases
+                    .get(&semantic_type.trim().to_lowercase())
+                {
+                    Some(mapped_types) => mapped_types.clone(),
+                    None => {
+                        // Last resort: use the semantic type as-is (capitalized)
+                        let capitalized = capitalize_first(semantic_type.trim());
+                        warn!(
+                            "No mapping found for issue type '{}', using '{}'",
+                            semantic_type, capitalized
+                        );
+                        vec![capitalized]
+                    }
+                },
+            };
 
-            // Fall back to configured mappings
-            if let Some(mapped_types) = self.config.issue_type_mappings.get(&semantic_lower) {
-                jira_types.extend(mapped_types.clone());
-            } else {
-                // Last resort: use the semantic type as-is (capitalized)
-                let capitalized = capitalize_first(semantic_type);
-                warn!(
-                    "No mapping found for issue type '{}', using '{}'",
-                    semantic_type, capitalized
-                );
-                jira_types.push(capitalized);
+            // Remove duplicates while preserving order
+            for jira_type in matches {
+                if !jira_types.contains(&jira_type) {
+                    jira_types.push(jira_type);
+                }
             }
         }
 
-        // Remove duplicates while preserving order
-        let unique_types: Vec<String> = jira_types
-            .into_iter()
-            .collect::<HashSet<_>>()
-            .into_iter()
-            .collect();
-
         debug!(
             "Mapped semantic types {:?} to JIRA types {:?}",
-            semantic_types, unique_types
+            semantic_types, jira_types
         );
-        Ok(unique_types)
+        Ok(jira_types)
     }
 
-    /// Map semantic status categories to JIRA status names
-    pub fn map_status_categories(
+    /// Resolve one issue type for creating an issue
+    ///
+    /// Returns the first matching type name in the project; without a cached
+    /// type list the name is passed through for JIRA to check.
+    pub fn resolve_issue_type(
         &self,
-        semantic_statuses: &[String],
-    ) -> JiraMcpResult<Vec<String>> {
-        let mut jira_statuses = Vec::new();
+        issue_type: &str,
+        project_key: Option<&str>,
+        parameter: &str,
+    ) -> JiraMcpResult<String> {
+        let Some(known) = self.known_issue_types(project_key) else {
+            return Ok(issue_type.trim().to_string());
+        };
 
-        for semantic_status in semantic_statuses {
-            let semantic_lower = semantic_status.to_lowercase();
+        self.match_issue_types(issue_type, &known)
+            .into_iter()
+            .next()
+            .ok_or_else(|| unknown_issue_type(parameter, issue_type, &known))
+    }
 
-            // Try configured mappings
-            if let Some(mapped_statuses) = self.config.status_category_mappings.get(&semantic_lower)
-            {
-                jira_statuses.extend(mapped_statuses.clone());
-            } else {
-                // Use as-is (capitalized)
-                let capitalized = capitalize_first(semantic_status);
-                warn!(
-                    "No mapping found for status '{}', using '{}'",
-                    semantic_status, capitalized
-                );
-                jira_statuses.push(capitalized);
+    /// Load a project's issue types (or all of them for `None`) into the cache
+    ///
+    /// Failures are logged and leave name-based mapping in place.
+    pub async fn load_issue_types(&self, jira_client: &JiraClient, project_key: Option<&str>) {
+        let cache_key = project_key.unwrap_or_default();
+        if self.cache.get_project_issue_types(cache_key).is_some() {
+            return;
+        }
+
+        let result = match jira_client.get_issue_types(project_key).await {
+            Ok(issue_types) => self
+                .cache
+                .set_project_issue_types(cache_key.to_string(), issue_types),
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            warn!("Could not load issue types, mapping them by name: {}", e);
+        }
+    }
+
+    /// The project's issue types if cached, else the instance-wide list
+    fn known_issue_types(&self, project_key: Option<&str>) -> Option<Vec<IssueTypeInfo>> {
+        project_key
+            .and_then(|key| self.cache.get_project_issue_types(key))
+            .or_else(|| self.cache.get_project_issue_types(""))
+    }
+
+    /// Issue type names in `known` matching a semantic or literal type name
+    fn match_issue_types(&self, issue_type: &str, known: &[IssueTypeInfo]) -> Vec<String> {
+        let issue_type = issue_type.trim();
+        let lower = issue_type.to_lowercase();
+        let is_named = |t: &IssueTypeInfo, name: &str| {
+            t.name.eq_ignore_ascii_case(name)
+                || t.untranslated_name
+                    .as_deref()
+                    .is_some_and(|u| u.eq_ignore_ascii_case(name))
+        };
+
+        // Exact name, including the English name of localized types
+        if let Some(found) = known.iter().find(|t| is_named(t, issue_type)) {
+            return vec![found.name.clone()];
+        }
+
+        // Configured aliases, in their order of preference
+        let mut matches: Vec<String> = Vec::new();
+        for alias in self.issue_type_aliases.get(&lower).into_iter().flatten() {
+            for found in known.iter().filter(|t| is_named(t, alias)) {
+                if !matches.contains(&found.name) {
+                    matches.push(found.name.clone());
+                }
             }
         }
+        if !matches.is_empty() {
+            return matches;
+        }
 
-        // Remove duplicates
-        let unique_statuses: Vec<String> = jira_statuses
-            .into_iter()
-            .collect::<HashSet<_>>()
-            .into_iter()
-            .collect();
+        // Epics and sub-tasks by hierarchy, whatever they are called
+        let by_hierarchy: Vec<String> = match lower.as_str() {
+            "epic" | "capability" => known
+                .iter()
+                .filter(|t| t.hierarchy_level == Some(1))
+                .map(|t| t.name.clone())
+                .collect(),
+            "subtask" | "sub-task" | "sub_task" => known
+                .iter()
+                .filter(|t| t.subtask)
+                .map(|t| t.name.clone())
+                .collect(),
+            _ => Vec::new(),
+        };
+        if !by_hierarchy.is_empty() {
+            return by_hierarchy;
+        }
 
-        debug!(
-            "Mapped semantic statuses {:?} to JIRA statuses {:?}",
-            semantic_statuses, unique_statuses
-        );
-        Ok(unique_statuses)
+        self.find_matching_project_types(&lower, known)
+    }
+
+    /// Resolve a status filter against the instance's status catalog
+    ///
+    /// Semantic categories ("open", "in_progress", "done") become `statusCategory`
+    /// conditions, so custom statuses like "Ready for QA" are included without
+    /// being listed. Named statuses and configured aliases (e.g. "blocked") become
+    /// explicit status lists, checked against the catalog when it is cached (see
+    /// `load_status_catalog`); the project's catalog is preferred over the
+    /// instance-wide one.
+    pub fn resolve_status_filter(
+        &self,
+        terms: &[String],
+        project_key: Option<&str>,
+    ) -> JiraMcpResult<StatusFilter> {
+        let catalog = project_key
+            .and_then(|key| self.cache.get_statuses(Some(key)))
+            .or_else(|| self.cache.get_statuses(None));
+        let mut filter = StatusFilter::default();
+
+        for term in terms {
+            let term = term.trim();
+            let normalized = term.to_lowercase().replace([' ', '-'], "_");
+
+            if let Some(category_key) = semantic_status_category(&normalized) {
+                let category_name = catalog
+                    .iter()
+                    .flatten()
+                    .find(|s| s.category_key == category_key)
+                    .map(|s| s.category_name.clone())
+                    .unwrap_or_else(|| default_category_name(category_key).to_string());
+                filter.add_category(category_name);
+                continue;
+            }
+
+            if let Some(catalog) = &catalog {
+                if let Some(status) = catalog.iter().find(|s| s.name.eq_ignore_ascii_case(term)) {
+                    filter.add_status(status.name.clone());
+                    continue;
+                }
+                if let Some(status) = catalog
+                    .iter()
+                    .find(|s| s.category_name.eq_ignore_ascii_case(term))
+                {
+                    filter.add_category(status.category_name.clone());
+                    continue;
+                }
+            }
+
+            if let Some(aliased) = self.status_aliases.get(&normalized) {
+                let names: Vec<String> = match &catalog {
+                    Some(catalog) => aliased
+                        .iter()
+                        .filter_map(|alias| {
+                            catalog
+                                .iter()
+                                .find(|s| s.name.eq_ignore_ascii_case(alias))
+                                .map(|s| s.name.clone())
+                        })
+                        .collect(),
+                    None => aliased.clone(),
+                };
+                if names.is_empty() {
+                    return Err(JiraMcpError::invalid_param(
+                        "status",
+                        format!(
+                            "None of the statuses for '{}' ({}) exist here. {}",
+                            term,
+                            aliased.join(", "),
+                            describe_catalog(catalog.as_deref())
+                        ),
+                    ));
+                }
+                names.into_iter().for_each(|name| filter.add_status(name));
+                continue;
+            }
+
+            if catalog.is_some() {
+                return Err(JiraMcpError::invalid_param(
+                    "status",
+                    format!(
+                        "Unknown status '{}'. {}",
+                        term,
+                        describe_catalog(catalog.as_deref())
+                    ),
+                ));
+            }
+
+            // No catalog to check against: use as-is (capitalized)
+            let capitalized = capitalize_first(term);
+            warn!(
+                "No mapping found for status '{}', using '{}'",
+                term, capitalized
+            );
+            filter.add_status(capitalized);
+        }
+
+        debug!("Resolved statuses {:?} to {:?}", terms, filter);
+        Ok(filter)
+    }
+
+    /// Load the status catalog into the cache unless it is already there
+    ///
+    /// Uses the project's statuses when a project is given, falling back to the
+    /// instance-wide list. Failures are logged and leave name-based mapping in place.
+    pub async fn load_status_catalog(&self, jira_client: &JiraClient, project_key: Option<&str>) {
+        if self.cache.get_statuses(project_key).is_some() {
+            return;
+        }
+
+        let result = match jira_client.get_statuses(project_key).await {
+            Ok(statuses) => self.cache.set_statuses(project_key, statuses),
+            Err(e) if project_key.is_some() && self.cache.get_statuses(None).is_none() => {
+                debug!("Project statuses unavailable ({}), loading all statuses", e);
+                match jira_client.get_statuses(None).await {
+                    Ok(statuses) => self.cache.set_statuses(None, statuses),
+                    Err(e) => Err(e),
+                }
+            }
+            Err(e) => Err(e),
+        };
+        if let Err(e) = result {
+            warn!(
+                "Could not load status catalog, mapping statuses by name: {}",
+                e
+            );
+        }
+    }
+
+    /// Map priority names and semantic aliases to JIRA priority names
+    ///
+    /// Uses the cached priority list (see `list_priorities`) when available, so
+    /// unknown names are rejected with suggestions instead of failing in JQL.
+    pub fn map_priorities(
+        &self,
+        priorities: &[String],
+        parameter: &str,
+    ) -> JiraMcpResult<Vec<String>> {
+        let known: Option<Vec<String>> = self
+            .cache
+            .get_priorities()
+            .map(|priorities| priorities.into_iter().map(|p| p.name).collect());
+
+        let mut names = Vec::new();
+        for priority in priorities {
+            let name = match &known {
+                Some(known) => {
+                    resolve_priority_name(priority, known, &self.priority_aliases, parameter)?
+                }
+                None => priority_aliases(priority, &self.priority_aliases)
+                    .and_then(|candidates| candidates.first().cloned())
+                    .unwrap_or_else(|| priority.trim().to_string()),
+            };
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        debug!("Mapped priorities {:?} to {:?}", priorities, names);
+        Ok(names)
     }
 
     /// Resolve user reference to account ID
@@ -151,16 +680,29 @@ impl SemanticMapper {
                 if let Some(user) = self.cache.get_user_mapping(user_ref) {
                     Ok(user.account_id)
                 } else {
-                    // Return the identifier as-is for now
-                    // In a real implementation, we might want to fetch user info
-                    warn!("User reference '{}' not found in cache", user_ref);
+                    // Taken as an account ID (username on Server/Data Center);
+                    // search_issues resolves names and emails before it gets here
+                    debug!("User reference '{}' not in cache, using it as is", user_ref);
                     Ok(user_ref.to_string())
                 }
             }
         }
     }
 
-    /// Build a JQL query from search parameters
+    /// JQL clause matching a user reference in a user field, where
+    /// "unassigned" matches issues with nobody in the field
+    fn user_clause(&self, field: &jql::Field, user_ref: &str) -> JiraMcpResult<String> {
+        let resolved_user = self.resolve_user_reference(user_ref)?;
+        Ok(if resolved_user == "UNASSIGNED" {
+            format!("{} is EMPTY", field)
+        } else {
+            format!("{} = {}", field, jql::quote(&resolved_user))
+        })
+    }
+
+    /// Build a JQL query from search parameters: the clauses of
+    /// [`Self::build_search_jql_with_components`] without the filters only
+    /// search_issues has
     #[allow(clippy::too_many_arguments)]
     pub fn build_search_jql(
         &self,
@@ -174,141 +716,38 @@ impl SemanticMapper {
         parent_filter: Option<&str>,
         epic_filter: Option<&str>,
     ) -> JiraMcpResult<JqlQuery> {
-        let mut jql_parts = Vec::new();
-        let mut complexity = QueryComplexity::Simple;
-
-        // Project filter (if specified)
-        if let Some(project) = project_key {
-            jql_parts.push(format!("project = \"{}\"", project));
-        }
-
-        // Text search (if specified)
-        if let Some(text) = query_text {
-            if !text.trim().is_empty() {
-                // Use JIRA text search
-                jql_parts.push(format!("text ~ \"{}\"", escape_jql_string(text)));
-                complexity = QueryComplexity::Complex;
-            }
-        }
-
-        // Issue types
-        if let Some(types) = issue_types {
-            if !types.is_empty() {
-                let jira_types = self.map_issue_types(types, project_key)?;
-                if !jira_types.is_empty() {
-                    let types_clause = if jira_types.len() == 1 {
-                        format!("issuetype = \"{}\"", jira_types[0])
-                    } else {
-                        let type_list = jira_types
-                            .iter()
-                            .map(|t| format!("\"{}\"", t))
-                            .collect::<Vec<_>>()
-                            .join(", ");
-                        format!("issuetype IN ({})", type_list)
-                    };
-                    jql_parts.push(types_clause);
-                }
-            }
-        }
-
-        // Assignee
-        if let Some(assignee) = assigned_to {
-            let resolved_user = self.resolve_user_reference(assignee)?;
-            let assignee_clause = if resolved_user == "UNASSIGNED" {
-                "assignee is EMPTY".to_string()
-            } else {
-                format!("assignee = \"{}\"", resolved_user)
-            };
-            jql_parts.push(assignee_clause);
-        }
-
-        // Status
-        if let Some(statuses) = status {
-            if !statuses.is_empty() {
-                let jira_statuses = self.map_status_categories(statuses)?;
-                if !jira_statuses.is_empty() {
-                    let status_clause = if jira_statuses.len() == 1 {
-                        format!("status = \"{}\"", jira_statuses[0])
-                    } else {
-                        let status_list = jira_statuses
-                            .iter()
-                            .map(|s| format!("\"{}\"", s))
-                            .collect::<Vec<_>>()
-                            .join(", ");
-                        format!("status IN ({})", status_list)
-                    };
-                    jql_parts.push(status_clause);
-                }
-            }
-        }
-
-        // Created after
-        if let Some(created) = created_after {
-            let date_clause = parse_date_filter(created)?;
-            jql_parts.push(format!("created >= \"{}\"", date_clause));
-        }
-
-        // Labels
-        if let Some(label_list) = labels {
-            if !label_list.is_empty() {
-                for label in label_list {
-                    jql_parts.push(format!("labels = \"{}\"", label));
-                }
-            }
-        }
-
-        // Parent filter
-        if let Some(parent) = parent_filter {
-            let parent_clause = match parent.to_lowercase().as_str() {
-                "none" => "parent is EMPTY".to_string(),
-                "any" => "parent is not EMPTY".to_string(),
-                issue_key => format!("parent = \"{}\"", issue_key),
-            };
-            jql_parts.push(parent_clause);
-        }
-
-        // Epic link filter
-        if let Some(epic) = epic_filter {
-            let epic_clause = match epic.to_lowercase().as_str() {
-                "none" => "\"Epic Link\" is EMPTY".to_string(),
-                "any" => "\"Epic Link\" is not EMPTY".to_string(),
-                epic_key => format!("\"Epic Link\" = \"{}\"", epic_key),
-            };
-            jql_parts.push(epic_clause);
-        }
-
-        // Determine complexity
-        if jql_parts.len() > 3 {
-            complexity = QueryComplexity::Complex;
-        } else if jql_parts.len() > 1 {
-            complexity = QueryComplexity::Moderate;
-        }
-
-        // Build final JQL
-        let jql = if jql_parts.is_empty() {
-            // Default query if no filters - add 30-day constraint to avoid unbounded queries on JIRA Cloud
-            "created >= -30d ORDER BY updated DESC".to_string()
-        } else {
-            let conditions = jql_parts.join(" AND ");
-            format!("{} ORDER BY updated DESC", conditions)
-        };
-
-        debug!("Built JQL query: {}", jql);
-
-        Ok(JqlQuery {
-            jql,
-            estimated_results: None, // Could be populated with estimate logic
-            complexity,
-        })
+        self.build_search_jql_with_components(
+            query_text,
+            None,
+            issue_types,
+            assigned_to,
+            None,
+            None,
+            project_key,
+            status,
+            created_after,
+            labels,
+            None,
+            parent_filter,
+            epic_filter,
+            None,
+            None,
+        )
     }
 
     /// Build a JQL query from search parameters with components support
+    ///
+    /// `assigned_to` and `reporter` take the same user references as
+    /// [`Self::resolve_user_reference`].
     #[allow(clippy::too_many_arguments)]
     pub fn build_search_jql_with_components(
         &self,
         query_text: Option<&str>,
+        search_in: Option<&[TextField]>,
         issue_types: Option<&[String]>,
         assigned_to: Option<&str>,
+        reporter: Option<&str>,
+        created_by: Option<&str>,
         project_key: Option<&str>,
         status: Option<&[String]>,
         created_after: Option<&str>,
@@ -316,104 +755,120 @@ impl SemanticMapper {
         components: Option<&[String]>,
         parent_filter: Option<&str>,
         epic_filter: Option<&str>,
+        priorities: Option<&[String]>,
+        recent: Option<RecentFilter>,
     ) -> JiraMcpResult<JqlQuery> {
-        let mut jql_parts = Vec::new();
+        let mut clauses = Vec::new();
+        let mut ignored = Vec::new();
         let mut complexity = QueryComplexity::Simple;
 
         // Project filter (if specified)
         if let Some(project) = project_key {
-            jql_parts.push(format!("project = \"{}\"", project));
+            clauses.push(JqlClause::new(
+                "project_key",
+                format!("{} = {}", jql::PROJECT, jql::quote(project)),
+            ));
         }
 
-        // Text search (if specified)
-        if let Some(text) = query_text {
-            if !text.trim().is_empty() {
-                // Use JIRA text search
-                jql_parts.push(format!("text ~ \"{}\"", escape_jql_string(text)));
-                complexity = QueryComplexity::Complex;
+        // Text search (if specified): all text fields, or only the requested ones
+        let text = query_text.filter(|text| !text.trim().is_empty());
+        if let Some(text) = text {
+            let text_clause = match search_in.filter(|fields| !fields.is_empty()) {
+                Some(fields) => JqlClause::new(
+                    "query_text, search_in",
+                    TextField::search_clause(fields, text),
+                ),
+                None => JqlClause::new(
+                    "query_text",
+                    format!("{} ~ {}", jql::TEXT, jql::quote(text)),
+                ),
+            };
+            clauses.push(text_clause);
+            complexity = QueryComplexity::Complex;
+        } else {
+            if query_text.is_some() {
+                ignored.push(("query_text", "blank text"));
+            }
+            if search_in.is_some_and(|fields| !fields.is_empty()) {
+                ignored.push(("search_in", "no query_text to search for"));
             }
         }
 
         // Issue types
         if let Some(types) = issue_types {
-            if !types.is_empty() {
+            if types.is_empty() {
+                ignored.push(("issue_types", "empty list"));
+            } else {
                 let jira_types = self.map_issue_types(types, project_key)?;
-                if !jira_types.is_empty() {
-                    let types_clause = if jira_types.len() == 1 {
-                        format!("issuetype = \"{}\"", jira_types[0])
-                    } else {
-                        let type_list = jira_types
-                            .iter()
-                            .map(|t| format!("\"{}\"", t))
-                            .collect::<Vec<_>>()
-                            .join(", ");
-                        format!("issuetype IN ({})", type_list)
-                    };
-                    jql_parts.push(types_clause);
+                match jql::eq_or_in(&jql::ISSUE_TYPE, &jira_types) {
+                    Some(types_clause) => clauses.push(JqlClause::new("issue_types", types_clause)),
+                    None => ignored.push(("issue_types", "matched no issue type")),
                 }
             }
         }
 
-        // Assignee
+        // Assignee, reporter and creator
         if let Some(assignee) = assigned_to {
-            let resolved_user = self.resolve_user_reference(assignee)?;
-            let assignee_clause = if resolved_user == "UNASSIGNED" {
-                "assignee is EMPTY".to_string()
-            } else {
-                format!("assignee = \"{}\"", resolved_user)
-            };
-            jql_parts.push(assignee_clause);
+            clauses.push(JqlClause::new(
+                "assigned_to",
+                self.user_clause(&jql::ASSIGNEE, assignee)?,
+            ));
+        }
+        if let Some(reporter) = reporter {
+            clauses.push(JqlClause::new(
+                "reporter",
+                self.user_clause(&jql::REPORTER, reporter)?,
+            ));
+        }
+        if let Some(creator) = created_by {
+            clauses.push(JqlClause::new(
+                "created_by",
+                self.user_clause(&jql::CREATOR, creator)?,
+            ));
         }
 
         // Status
         if let Some(statuses) = status {
-            if !statuses.is_empty() {
-                let jira_statuses = self.map_status_categories(statuses)?;
-                if !jira_statuses.is_empty() {
-                    let status_clause = if jira_statuses.len() == 1 {
-                        format!("status = \"{}\"", jira_statuses[0])
-                    } else {
-                        let status_list = jira_statuses
-                            .iter()
-                            .map(|s| format!("\"{}\"", s))
-                            .collect::<Vec<_>>()
-                            .join(", ");
-                        format!("status IN ({})", status_list)
-                    };
-                    jql_parts.push(status_clause);
+            if statuses.is_empty() {
+                ignored.push(("status", "empty list"));
+            } else {
+                let status_filter = self.resolve_status_filter(statuses, project_key)?;
+                match status_filter.to_jql() {
+                    Some(status_clause) => clauses.push(JqlClause::new("status", status_clause)),
+                    None => ignored.push(("status", "matched no status")),
                 }
             }
         }
 
         // Created after
         if let Some(created) = created_after {
-            let date_clause = parse_date_filter(created)?;
-            jql_parts.push(format!("created >= \"{}\"", date_clause));
+            let date_expr = DateExpr::parse(created, "created_after")?;
+            clauses.push(JqlClause::new(
+                "created_after",
+                date_expr.since_clause("created"),
+            ));
         }
 
         // Labels
         if let Some(label_list) = labels {
-            if !label_list.is_empty() {
-                for label in label_list {
-                    jql_parts.push(format!("labels = \"{}\"", label));
-                }
+            if label_list.is_empty() {
+                ignored.push(("labels", "empty list"));
+            }
+            for label in label_list {
+                clauses.push(JqlClause::new(
+                    "labels",
+                    format!("{} = {}", jql::LABELS, jql::quote(label)),
+                ));
             }
         }
 
         // Components
         if let Some(component_list) = components {
-            if !component_list.is_empty() {
-                let components_clause = if component_list.len() == 1 {
-                    format!("component = \"{}\"", component_list[0])
-                } else {
-                    let component_names = component_list
-                        .iter()
-                        .map(|c| format!("\"{}\"", c))
-                        .collect::<Vec<_>>()
-                        .join(", ");
-                    format!("component IN ({})", component_names)
-                };
-                jql_parts.push(components_clause);
+            match jql::eq_or_in(&jql::COMPONENT, component_list) {
+                Some(components_clause) => {
+                    clauses.push(JqlClause::new("components", components_clause))
+                }
+                None => ignored.push(("components", "empty list")),
             }
         }
 
@@ -422,9 +877,9 @@ impl SemanticMapper {
             let parent_clause = match parent.to_lowercase().as_str() {
                 "none" => "parent is EMPTY".to_string(),
                 "any" => "parent is not EMPTY".to_string(),
-                issue_key => format!("parent = \"{}\"", issue_key),
+                _ => format!("{} = {}", jql::PARENT, jql::quote(parent)),
             };
-            jql_parts.push(parent_clause);
+            clauses.push(JqlClause::new("parent_filter", parent_clause));
         }
 
         // Epic link filter
@@ -432,34 +887,37 @@ impl SemanticMapper {
             let epic_clause = match epic.to_lowercase().as_str() {
                 "none" => "\"Epic Link\" is EMPTY".to_string(),
                 "any" => "\"Epic Link\" is not EMPTY".to_string(),
-                epic_key => format!("\"Epic Link\" = \"{}\"", epic_key),
+                _ => format!("{} = {}", jql::EPIC_LINK, jql::quote(epic)),
             };
-            jql_parts.push(epic_clause);
+            clauses.push(JqlClause::new("epic_filter", epic_clause));
         }
 
-        // Determine complexity
-        if jql_parts.len() > 3 {
-            complexity = QueryComplexity::Complex;
-        } else if jql_parts.len() > 1 {
-            complexity = QueryComplexity::Moderate;
+        // Priorities
+        if let Some(priority_list) = priorities {
+            if priority_list.is_empty() {
+                ignored.push(("priority_filter", "empty list"));
+            } else {
+                let names = self.map_priorities(priority_list, "priority_filter")?;
+                clauses.extend(
+                    jql::eq_or_in(&jql::PRIORITY, &names)
+                        .map(|clause| JqlClause::new("priority_filter", clause)),
+                );
+            }
         }
 
-        // Build final JQL
-        let jql = if jql_parts.is_empty() {
-            // Default query if no filters - add 30-day constraint to avoid unbounded queries on JIRA Cloud
-            "created >= -30d ORDER BY updated DESC".to_string()
-        } else {
-            let conditions = jql_parts.join(" AND ");
-            format!("{} ORDER BY updated DESC", conditions)
-        };
+        // Recently viewed (or, without issueHistory(), recently involved) issues
+        if let Some(recent) = recent {
+            clauses.push(JqlClause::new("recent", recent.clause()));
+        }
 
-        debug!("Built JQL query: {}", jql);
+        let order = recent.map_or("updated DESC", RecentFilter::default_order);
+        let mut query = JqlQuery::from_clauses(clauses, order, complexity);
+        for (input, reason) in ignored {
+            query.ignore(input, reason);
+        }
 
-        Ok(JqlQuery {
-            jql,
-            estimated_results: None, // Could be populated with estimate logic
-            complexity,
-        })
+        debug!("Built JQL query: {}", query.jql);
+        Ok(query)
     }
 
     /// Build JQL for user-assigned issues
@@ -514,30 +972,23 @@ impl SemanticMapper {
 
             // Partial match for common patterns
             match semantic_type {
-                "story" => {
-                    if type_name_lower.contains("story") {
-                        matches.push(issue_type.name.clone());
-                    }
+                "story" if type_name_lower.contains("story") => {
+                    matches.push(issue_type.name.clone());
                 }
-                "bug" => {
-                    if type_name_lower.contains("bug") || type_name_lower.contains("defect") {
-                        matches.push(issue_type.name.clone());
-                    }
+                "bug" if type_name_lower.contains("bug") || type_name_lower.contains("defect") => {
+                    matches.push(issue_type.name.clone());
                 }
-                "feature" => {
-                    if type_name_lower.contains("feature") {
-                        matches.push(issue_type.name.clone());
-                    }
+                "feature" if type_name_lower.contains("feature") => {
+                    matches.push(issue_type.name.clone());
                 }
-                "task" => {
-                    if type_name_lower.contains("task") {
-                        matches.push(issue_type.name.clone());
-                    }
+                "task" if type_name_lower.contains("task") => {
+                    matches.push(issue_type.name.clone());
                 }
-                "capability" | "epic" => {
-                    if type_name_lower.contains("epic") || type_name_lower.contains("capability") {
-                        matches.push(issue_type.name.clone());
-                    }
+                "capability" | "epic"
+                    if type_name_lower.contains("epic")
+                        || type_name_lower.contains("capability") =>
+                {
+                    matches.push(issue_type.name.clone());
                 }
                 _ => {}
             }
@@ -547,69 +998,274 @@ impl SemanticMapper {
     }
 }
 
-/// Capitalize the first letter of a string
-fn capitalize_first(s: &str) -> String {
-    if s.is_empty() {
-        return s.to_string();
+/// JQL clause for a due date filter
+///
+/// Understands the semantic filters "overdue", "due_today", "due_this_week" and
+/// "no_due_date"; anything else is a date expression, where periods match issues
+/// due within them and single dates match issues due by that date.
+pub fn due_date_clause(filter: &str) -> JiraMcpResult<String> {
+    let normalized = filter.trim().to_lowercase().replace([' ', '-'], "_");
+    let clause = match normalized.as_str() {
+        "" => {
+            return Err(JiraMcpError::invalid_param(
+                "due_date_filter",
+                "due_date_filter cannot be empty",
+            ))
+        }
+        "overdue" => "due < now()".to_string(),
+        "due_today" => DateExpr::parse("today", "due_date_filter")?.within_clause("due"),
+        "due_this_week" => DateExpr::parse("this_week", "due_date_filter")?.within_clause("due"),
+        "no_due_date" => "due is EMPTY".to_string(),
+        _ => DateExpr::parse(filter, "due_date_filter")?.within_clause("due"),
+    };
+    Ok(clause)
+}
+
+/// Status filter split into status categories and explicit statuses
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusFilter {
+    /// Status category names, e.g. "In Progress"
+    pub categories: Vec<String>,
+
+    /// Explicit status names, e.g. "Ready for QA"
+    pub statuses: Vec<String>,
+}
+
+impl StatusFilter {
+    fn add_category(&mut self, name: String) {
+        if !self.categories.contains(&name) {
+            self.categories.push(name);
+        }
     }
 
-    let mut chars: Vec<char> = s.chars().collect();
-    chars[0] = chars[0].to_uppercase().next().unwrap_or(chars[0]);
-    chars.into_iter().collect()
+    fn add_status(&mut self, name: String) {
+        if !self.statuses.contains(&name) {
+            self.statuses.push(name);
+        }
+    }
+
+    /// JQL condition for the filter, or None when it is empty
+    pub fn to_jql(&self) -> Option<String> {
+        match (
+            jql::eq_or_in(&jql::STATUS_CATEGORY, &self.categories),
+            jql::eq_or_in(&jql::STATUS, &self.statuses),
+        ) {
+            (Some(categories), Some(statuses)) => Some(format!("({} OR {})", categories, statuses)),
+            (categories, statuses) => categories.or(statuses),
+        }
+    }
+
+    /// Human-readable list of what the filter matches
+    pub fn describe(&self) -> Vec<String> {
+        self.categories
+            .iter()
+            .map(|c| format!("category: {}", c))
+            .chain(self.statuses.iter().cloned())
+            .collect()
+    }
+}
+
+/// Status category key for a semantic status name
+fn semantic_status_category(normalized: &str) -> Option<&'static str> {
+    match normalized {
+        "open" | "todo" | "to_do" | "new" => Some("new"),
+        "in_progress" | "indeterminate" => Some("indeterminate"),
+        "done" => Some("done"),
+        _ => None,
+    }
+}
+
+/// JIRA's default name for a status category key
+fn default_category_name(category_key: &str) -> &'static str {
+    match category_key {
+        "new" => "To Do",
+        "indeterminate" => "In Progress",
+        _ => "Done",
+    }
 }
 
-/// Escape special characters in JQL string literals
-fn escape_jql_string(s: &str) -> String {
-    s.replace("\\", "\\\\").replace("\"", "\\\"")
+fn describe_catalog(catalog: Option<&[StatusInfo]>) -> String {
+    let names: Vec<&str> = catalog
+        .unwrap_or_default()
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    format!(
+        "Available statuses: {}; categories: open, in_progress, done",
+        names.join(", ")
+    )
 }
 
-/// Parse date filter string to JQL-compatible format
-pub fn parse_date_filter(date_str: &str) -> JiraMcpResult<String> {
-    let trimmed = date_str.trim().to_lowercase();
+/// Built-in semantic priority aliases, in order of preference
+///
+/// Cloud uses Highest/High/Medium/Low/Lowest by default, Server schemes often
+/// Blocker/Critical/Major/Minor/Trivial; the first candidate that exists wins.
+/// `priority_mappings` in the config overrides entries here.
+pub fn default_priority_mappings() -> HashMap<String, Vec<String>> {
+    let aliases: [(&str, &[&str]); 8] = [
+        ("critical", &["Highest", "Critical", "Blocker"]),
+        ("urgent", &["Highest", "Critical", "Blocker"]),
+        ("blocker", &["Blocker", "Highest"]),
+        ("high", &["High", "Major", "Critical"]),
+        ("normal", &["Medium", "Normal", "Major"]),
+        ("medium", &["Medium", "Normal", "Major"]),
+        ("low", &["Low", "Minor"]),
+        ("trivial", &["Lowest", "Trivial"]),
+    ];
+
+    aliases
+        .into_iter()
+        .map(|(alias, names)| {
+            (
+                alias.to_string(),
+                names.iter().map(|n| n.to_string()).collect(),
+            )
+        })
+        .collect()
+}
 
-    // Handle relative dates
-    if trimmed.contains("ago") {
-        // Parse patterns like "7 days ago", "2 weeks ago", "1 month ago"
-        if let Some(relative_date) = parse_relative_date(&trimmed) {
-            return Ok(relative_date);
+/// Priority aliases from the config: `priority_mappings` overrides, plus the
+/// `[locale_aliases.priorities]` names appended to the built-in or overridden entry
+pub fn configured_priority_aliases(config: &JiraConfig) -> HashMap<String, Vec<String>> {
+    let mut aliases = config.priority_mappings.clone();
+    let defaults = default_priority_mappings();
+    for key in config.locale_aliases.priorities.keys() {
+        let key = key.to_lowercase();
+        if let Some(names) = defaults.get(&key) {
+            aliases.entry(key).or_insert_with(|| names.clone());
         }
     }
 
-    // Handle absolute dates (try to parse as ISO date)
-    if trimmed.len() >= 10 && trimmed.contains("-") {
-        // Assume YYYY-MM-DD format, return as-is for JQL
-        return Ok(date_str.to_string());
+    with_locale_aliases(aliases, &config.locale_aliases.priorities)
+}
+
+/// Append localized names to an alias table, adding entries that are missing
+fn with_locale_aliases(
+    mut aliases: HashMap<String, Vec<String>>,
+    locale: &HashMap<String, Vec<String>>,
+) -> HashMap<String, Vec<String>> {
+    for (key, names) in locale {
+        let entry = aliases.entry(key.to_lowercase()).or_default();
+        for name in names {
+            if !entry.contains(name) {
+                entry.push(name.clone());
+            }
+        }
     }
+    aliases
+}
 
-    // Default fallback
-    Err(JiraMcpError::invalid_param(
-        "created_after",
-        format!(
-            "Invalid date format: '{}'. Use formats like '2024-01-01' or '7 days ago'",
-            date_str
-        ),
+/// Candidate names for a semantic priority alias, config overrides first
+fn priority_aliases(
+    priority: &str,
+    overrides: &HashMap<String, Vec<String>>,
+) -> Option<Vec<String>> {
+    let key = priority.trim().to_lowercase();
+    overrides
+        .get(&key)
+        .cloned()
+        .or_else(|| default_priority_mappings().remove(&key))
+}
+
+/// Resolve a priority name or alias against the instance's priorities
+///
+/// Matching is case-insensitive. Aliases ("critical", "normal") resolve to the
+/// first of their candidates the instance has. Unknown names are rejected with
+/// the closest matches and the full list.
+pub fn resolve_priority_name(
+    priority: &str,
+    known: &[String],
+    overrides: &HashMap<String, Vec<String>>,
+    parameter: &str,
+) -> JiraMcpResult<String> {
+    let trimmed = priority.trim();
+    let find = |name: &str| known.iter().find(|k| k.eq_ignore_ascii_case(name)).cloned();
+
+    if let Some(name) = find(trimmed) {
+        return Ok(name);
+    }
+    if let Some(name) = priority_aliases(trimmed, overrides)
+        .into_iter()
+        .flatten()
+        .find_map(|candidate| find(&candidate))
+    {
+        return Ok(name);
+    }
+
+    let known: Vec<&str> = known.iter().map(String::as_str).collect();
+    Err(unknown_name_error(
+        parameter,
+        ("priority", "priorities"),
+        trimmed,
+        &known,
     ))
 }
 
-/// Parse relative date strings like "7 days ago"
-fn parse_relative_date(date_str: &str) -> Option<String> {
-    let parts: Vec<&str> = date_str.split_whitespace().collect();
-    if parts.len() >= 3 && parts[parts.len() - 1] == "ago" {
-        if let Ok(num) = parts[0].parse::<u32>() {
-            let unit = parts[1];
-            match unit {
-                "day" | "days" => Some(format!("-{}d", num)),
-                "week" | "weeks" => Some(format!("-{}w", num)),
-                "month" | "months" => Some(format!("-{}M", num)),
-                "year" | "years" => Some(format!("-{}y", num)),
-                _ => None,
-            }
-        } else {
-            None
+/// Error for an unknown issue type, with suggestions from the known types
+fn unknown_issue_type(parameter: &str, issue_type: &str, known: &[IssueTypeInfo]) -> JiraMcpError {
+    let names: Vec<&str> = known.iter().map(|t| t.name.as_str()).collect();
+    unknown_name_error(
+        parameter,
+        ("issue type", "issue types"),
+        issue_type.trim(),
+        &names,
+    )
+}
+
+/// "Unknown X" error listing close matches ("Did you mean") and all known names
+fn unknown_name_error(
+    parameter: &str,
+    (kind, kinds): (&str, &str),
+    name: &str,
+    known: &[&str],
+) -> JiraMcpError {
+    let lowered = name.to_lowercase();
+    let suggestions: Vec<&str> = known
+        .iter()
+        .filter(|candidate| {
+            let candidate = candidate.to_lowercase();
+            edit_distance(&candidate, &lowered) <= 2
+                || (!lowered.is_empty()
+                    && (candidate.contains(&lowered) || lowered.contains(&candidate)))
+        })
+        .copied()
+        .collect();
+
+    let mut message = format!("Unknown {} '{}'.", kind, name);
+    if !suggestions.is_empty() {
+        message.push_str(&format!(" Did you mean: {}?", suggestions.join(", ")));
+    }
+    message.push_str(&format!(" Available {}: {}", kinds, known.join(", ")));
+    JiraMcpError::invalid_param(parameter, message)
+}
+
+/// Levenshtein distance, for "did you mean" suggestions
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
         }
-    } else {
-        None
     }
+
+    row[b.len()]
+}
+
+/// Capitalize the first letter of a string
+fn capitalize_first(s: &str) -> String {
+    if s.is_empty() {
+        return s.to_string();
+    }
+
+    let mut chars: Vec<char> = s.chars().collect();
+    chars[0] = chars[0].to_uppercase().next().unwrap_or(chars[0]);
+    chars.into_iter().collect()
 }
 
 #[cfg(test)]
@@ -638,17 +1294,178 @@ mod tests {
         assert!(jira_types.contains(&"Bug".to_string()));
     }
 
+    fn issue_type(name: &str, untranslated: Option<&str>, level: i64) -> IssueTypeInfo {
+        IssueTypeInfo {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            description: None,
+            subtask: level < 0,
+            untranslated_name: untranslated.map(str::to_string),
+            hierarchy_level: Some(level),
+        }
+    }
+
+    #[test]
+    fn test_issue_types_per_project() {
+        let cache = Arc::new(MetadataCache::new(300));
+        let mapper = SemanticMapper::new(create_test_config(), Arc::clone(&cache));
+        cache
+            .set_project_issue_types(
+                "DE".to_string(),
+                vec![
+                    issue_type("Fehler", Some("Bug"), 0),
+                    issue_type("Aufgabe", Some("Task"), 0),
+                    issue_type("Initiative", None, 1),
+                    issue_type("Unteraufgabe", None, -1),
+                ],
+            )
+            .unwrap();
+        cache
+            .set_project_issue_types(
+                String::new(),
+                vec![issue_type("Bug", None, 0), issue_type("Story", None, 0)],
+            )
+            .unwrap();
+
+        // Localized names, epics and sub-tasks resolve within the project
+        let types = ["bug", "epic", "subtask"].map(str::to_string);
+        assert_eq!(
+            mapper.map_issue_types(&types, Some("DE")).unwrap(),
+            vec!["Fehler", "Initiative", "Unteraufgabe"]
+        );
+        assert_eq!(
+            mapper
+                .resolve_issue_type("TASK", Some("DE"), "issue_type")
+                .unwrap(),
+            "Aufgabe"
+        );
+
+        // Projects without cached types fall back to the instance-wide list
+        assert_eq!(
+            mapper
+                .resolve_issue_type("story", Some("OTHER"), "issue_type")
+                .unwrap(),
+            "Story"
+        );
+
+        let err = mapper
+            .resolve_issue_type("Fehlr", Some("DE"), "issue_type")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Did you mean: Fehler"), "{}", err);
+    }
+
     #[test]
     fn test_status_mapping() {
         let config = create_test_config();
         let cache = Arc::new(MetadataCache::new(300));
         let mapper = SemanticMapper::new(config, cache);
 
+        // Without a status catalog, categories still map to statusCategory
         let semantic_statuses = vec!["open".to_string(), "in_progress".to_string()];
-        let jira_statuses = mapper.map_status_categories(&semantic_statuses).unwrap();
+        let filter = mapper
+            .resolve_status_filter(&semantic_statuses, None)
+            .unwrap();
+        assert_eq!(filter.categories, vec!["To Do", "In Progress"]);
+        assert!(filter.statuses.is_empty());
+        assert_eq!(
+            filter.to_jql().unwrap(),
+            "statusCategory IN (\"To Do\", \"In Progress\")"
+        );
 
-        assert!(jira_statuses.contains(&"Open".to_string()));
-        assert!(jira_statuses.contains(&"In Progress".to_string()));
+        // Configured aliases and unknown names become explicit statuses
+        let filter = mapper
+            .resolve_status_filter(&["blocked".to_string(), "triage".to_string()], None)
+            .unwrap();
+        assert!(filter.categories.is_empty());
+        assert!(filter.statuses.contains(&"Blocked".to_string()));
+        assert!(filter.statuses.contains(&"Triage".to_string()));
+    }
+
+    fn status(id: &str, name: &str, category_key: &str, category_name: &str) -> StatusInfo {
+        StatusInfo {
+            id: id.to_string(),
+            name: name.to_string(),
+            category_key: category_key.to_string(),
+            category_name: category_name.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_status_filter_with_catalog() {
+        let cache = Arc::new(MetadataCache::new(300));
+        let mapper = SemanticMapper::new(create_test_config(), Arc::clone(&cache));
+        cache
+            .set_statuses(
+                None,
+                vec![
+                    status("1", "Backlog", "new", "To Do"),
+                    status("3", "Doing", "indeterminate", "In Progress"),
+                    status("10", "Ready for QA", "indeterminate", "In Progress"),
+                    status("11", "On Hold", "indeterminate", "In Progress"),
+                    status("6", "Shipped", "done", "Done"),
+                ],
+            )
+            .unwrap();
+        let resolve = |terms: &[&str], project: Option<&str>| {
+            let terms: Vec<String> = terms.iter().map(|t| t.to_string()).collect();
+            mapper.resolve_status_filter(&terms, project)
+        };
+
+        // Categories cover custom statuses without naming them
+        assert_eq!(
+            resolve(&["in_progress"], None).unwrap().to_jql().unwrap(),
+            "statusCategory = \"In Progress\""
+        );
+
+        // Named statuses are matched case-insensitively and stay explicit
+        let filter = resolve(&["ready for qa"], None).unwrap();
+        assert_eq!(filter.statuses, vec!["Ready for QA"]);
+        assert_eq!(filter.to_jql().unwrap(), "status = \"Ready for QA\"");
+
+        // Mixed filters are OR-ed so either side matches
+        assert_eq!(
+            resolve(&["open", "Ready for QA"], None)
+                .unwrap()
+                .to_jql()
+                .unwrap(),
+            "(statusCategory = \"To Do\" OR status = \"Ready for QA\")"
+        );
+
+        // Category display names work as categories
+        assert_eq!(resolve(&["Done"], None).unwrap().categories, vec!["Done"]);
+
+        // Aliases are narrowed to statuses that exist here
+        assert_eq!(
+            resolve(&["blocked"], None).unwrap().statuses,
+            vec!["On Hold"]
+        );
+
+        // Unknown names fail with the catalog instead of producing bad JQL
+        match resolve(&["Waiting for Godot"], None).unwrap_err() {
+            JiraMcpError::InvalidParameter { parameter, message } => {
+                assert_eq!(parameter, "status");
+                assert!(message.contains("Ready for QA"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        // A project catalog takes precedence over the instance-wide one
+        cache
+            .set_statuses(
+                Some("QA"),
+                vec![status("20", "Verifying", "indeterminate", "In Progress")],
+            )
+            .unwrap();
+        assert_eq!(
+            resolve(&["verifying"], Some("QA")).unwrap().statuses,
+            vec!["Verifying"]
+        );
+        assert!(resolve(&["Ready for QA"], Some("QA")).is_err());
+        assert_eq!(
+            resolve(&["Ready for QA"], Some("OTHER")).unwrap().statuses,
+            vec!["Ready for QA"]
+        );
     }
 
     #[test]
@@ -678,6 +1495,320 @@ mod tests {
         assert_eq!(query.complexity, QueryComplexity::Complex);
     }
 
+    #[test]
+    fn test_jql_explanation() {
+        let config = create_test_config();
+        let cache = Arc::new(MetadataCache::new(300));
+        let mapper = SemanticMapper::new(config, cache);
+
+        let query = mapper
+            .build_search_jql_with_components(
+                Some("  "),
+                Some(&[TextField::Summary]),
+                None,
+                None,
+                None,
+                None,
+                Some("TEST"),
+                None,
+                None,
+                Some(&[]),
+                None,
+                Some("none"),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert_eq!(
+            query.explanation(),
+            vec![
+                "project_key: project = \"TEST\"",
+                "parent_filter: parent is EMPTY",
+                "ordering: ORDER BY updated DESC",
+                "query_text: ignored, blank text",
+                "search_in: ignored, no query_text to search for",
+                "labels: ignored, empty list",
+            ]
+        );
+
+        let mut unfiltered = mapper
+            .build_search_jql(None, None, None, None, None, None, None, None, None)
+            .unwrap();
+        assert_eq!(unfiltered.jql, "created >= -30d ORDER BY updated DESC");
+        unfiltered.ignore_empty_lists(&[("status", Some(&Vec::new())), ("labels", None)]);
+        assert_eq!(
+            unfiltered.explanation(),
+            vec![
+                "no filters given: created >= -30d (last 30 days only)",
+                "ordering: ORDER BY updated DESC",
+                "status: ignored, empty list",
+            ]
+        );
+
+        // An added condition replaces the 30-day default
+        let scoped = unfiltered.and(JqlClause::new("within.sprint", "Sprint = 42"));
+        assert_eq!(scoped.jql, "Sprint = 42 ORDER BY updated DESC");
+        assert_eq!(scoped.explanation()[0], "within.sprint: Sprint = 42");
+    }
+
+    #[test]
+    fn test_jql_building_escapes_values() {
+        let config = create_test_config();
+        let cache = Arc::new(MetadataCache::new(300));
+        let mapper = SemanticMapper::new(config, cache);
+
+        let injection = "x\" OR assignee is EMPTY OR \"";
+        let query = mapper
+            .build_search_jql_with_components(
+                Some(injection),
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(injection),
+                None,
+                None,
+                Some(&[injection.to_string()]),
+                Some(&[injection.to_string()]),
+                Some(injection),
+                Some(injection),
+                None,
+                None,
+            )
+            .unwrap();
+
+        let escaped = "\"x\\\" OR assignee is EMPTY OR \\\"\"";
+        assert_eq!(
+            query.jql,
+            format!(
+                "project = {e} AND text ~ {e} AND labels = {e} AND component = {e} \
+                 AND parent = {e} AND \"Epic Link\" = {e} ORDER BY updated DESC",
+                e = escaped
+            )
+        );
+    }
+
+    #[test]
+    fn test_text_search_fields() {
+        let clause = TextField::search_clause(
+            &[TextField::Summary, TextField::Comments, TextField::Summary],
+            "login fails",
+        );
+        assert_eq!(
+            clause,
+            "(summary ~ \"\\\"login fails\\\"\" OR comment ~ \"\\\"login fails\\\"\")"
+        );
+        assert_eq!(
+            TextField::search_clause(&[TextField::Description], "timeout"),
+            "description ~ \"timeout\""
+        );
+
+        let fields: Vec<TextField> =
+            serde_json::from_value(serde_json::json!(["summary", "comment"])).unwrap();
+        assert_eq!(fields, vec![TextField::Summary, TextField::Comments]);
+    }
+
+    #[test]
+    fn test_order_by() {
+        let order_by = OrderBy {
+            field: "Created".to_string(),
+            direction: SortDirection::Asc,
+        };
+        assert!(order_by.validate(false).is_ok());
+        assert_eq!(order_by.to_jql(), "ORDER BY created ASC");
+
+        let query = JqlQuery {
+            jql: "project = \"TEST\" ORDER BY updated DESC".to_string(),
+            estimated_results: None,
+            complexity: QueryComplexity::Simple,
+            clauses: Vec::new(),
+            ignored: Vec::new(),
+        }
+        .with_order_by(&order_by);
+        assert_eq!(query.jql, "project = \"TEST\" ORDER BY created ASC");
+
+        let unknown = OrderBy {
+            field: "summary".to_string(),
+            direction: SortDirection::Desc,
+        };
+        assert!(unknown.validate(true).is_err());
+
+        let rank = OrderBy {
+            field: "rank".to_string(),
+            direction: SortDirection::Asc,
+        };
+        assert!(rank.validate(false).is_err());
+        assert!(rank.validate(true).is_ok());
+        assert_eq!(rank.to_jql(), "ORDER BY Rank ASC");
+    }
+
+    #[test]
+    fn test_resolve_priority_name() {
+        let cloud: Vec<String> = ["Highest", "High", "Medium", "Low", "Lowest"]
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        let server: Vec<String> = ["Blocker", "Critical", "Major", "Minor", "Trivial"]
+            .iter()
+            .map(|p| p.to_string())
+            .collect();
+        let none = HashMap::new();
+        let resolve =
+            |p: &str, known: &[String]| resolve_priority_name(p, known, &none, "priority");
+
+        assert_eq!(resolve("high", &cloud).unwrap(), "High");
+        assert_eq!(resolve(" HIGHEST ", &cloud).unwrap(), "Highest");
+        assert_eq!(resolve("critical", &cloud).unwrap(), "Highest");
+        assert_eq!(resolve("normal", &cloud).unwrap(), "Medium");
+        assert_eq!(resolve("critical", &server).unwrap(), "Critical");
+        assert_eq!(resolve("normal", &server).unwrap(), "Major");
+        assert_eq!(resolve("low", &server).unwrap(), "Minor");
+
+        // Config overrides win over the built-in aliases
+        let overrides = HashMap::from([("normal".to_string(), vec!["Low".to_string()])]);
+        assert_eq!(
+            resolve_priority_name("normal", &cloud, &overrides, "priority").unwrap(),
+            "Low"
+        );
+
+        match resolve("Hihg", &cloud).unwrap_err() {
+            JiraMcpError::InvalidParameter { parameter, message } => {
+                assert_eq!(parameter, "priority");
+                assert!(message.contains("Did you mean: High?"));
+                assert!(message.contains("Available priorities: Highest, High"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+        assert!(resolve("P1", &cloud).is_err());
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_map_priorities() {
+        let cache = Arc::new(MetadataCache::new(300));
+        let mapper = SemanticMapper::new(create_test_config(), Arc::clone(&cache));
+        let priorities = vec!["critical".to_string(), "Low".to_string()];
+
+        // Without the instance list, aliases use their first candidate
+        assert_eq!(
+            mapper
+                .map_priorities(&priorities, "priority_filter")
+                .unwrap(),
+            vec!["Highest", "Low"]
+        );
+
+        cache
+            .set_priorities(
+                ["Blocker", "Critical", "Major", "Minor"]
+                    .iter()
+                    .map(|name| crate::cache::PriorityInfo {
+                        id: name.to_lowercase(),
+                        name: name.to_string(),
+                        description: None,
+                    })
+                    .collect(),
+            )
+            .unwrap();
+        assert_eq!(
+            mapper
+                .map_priorities(&priorities, "priority_filter")
+                .unwrap(),
+            vec!["Critical", "Minor"]
+        );
+        assert!(mapper
+            .map_priorities(&["Highest".to_string()], "priority_filter")
+            .is_err());
+    }
+
+    #[test]
+    fn test_locale_aliases() {
+        let mut config = JiraConfig {
+            jira_url: "https://test.atlassian.net".to_string(),
+            ..Default::default()
+        };
+        let alias = |key: &str, name: &str| (key.to_string(), vec![name.to_string()]);
+        config.locale_aliases.statuses = HashMap::from([alias("blocked", "Bloqué")]);
+        config.locale_aliases.priorities = HashMap::from([alias("High", "Élevée")]);
+        config.locale_aliases.issue_types = HashMap::from([alias("bug", "Anomalie")]);
+
+        let cache = Arc::new(MetadataCache::new(300));
+        assert_eq!(
+            configured_priority_aliases(&config)["high"],
+            vec!["High", "Major", "Critical", "Élevée"]
+        );
+
+        let mapper = SemanticMapper::new(Arc::new(config), Arc::clone(&cache));
+        cache
+            .set_statuses(
+                None,
+                vec![
+                    status("1", "À faire", "new", "À faire"),
+                    status("2", "Bloqué", "indeterminate", "En cours"),
+                    status("3", "Terminé", "done", "Terminé"),
+                ],
+            )
+            .unwrap();
+        cache
+            .set_priorities(
+                ["Critique", "Élevée", "Moyenne", "Faible"]
+                    .iter()
+                    .map(|name| crate::cache::PriorityInfo {
+                        id: name.to_lowercase(),
+                        name: name.to_string(),
+                        description: None,
+                    })
+                    .collect(),
+            )
+            .unwrap();
+        cache
+            .set_project_issue_types(
+                "FR".to_string(),
+                vec![
+                    issue_type("Anomalie", None, 0),
+                    issue_type("Tâche", None, 0),
+                ],
+            )
+            .unwrap();
+
+        // Locale names extend the English defaults rather than replacing them
+        let filter = mapper
+            .resolve_status_filter(&["blocked".to_string(), "done".to_string()], None)
+            .unwrap();
+        assert_eq!(filter.statuses, vec!["Bloqué"]);
+        assert_eq!(filter.categories, vec!["Terminé"]);
+        assert_eq!(
+            mapper
+                .map_priorities(&["high".to_string()], "priority_filter")
+                .unwrap(),
+            vec!["Élevée"]
+        );
+        assert_eq!(
+            mapper
+                .map_issue_types(&["bug".to_string()], Some("FR"))
+                .unwrap(),
+            vec!["Anomalie"]
+        );
+    }
+
+    #[test]
+    fn test_due_date_clause() {
+        assert_eq!(due_date_clause("overdue").unwrap(), "due < now()");
+        assert_eq!(
+            due_date_clause("due_today").unwrap(),
+            "due >= startOfDay() AND due <= endOfDay()"
+        );
+        assert_eq!(
+            due_date_clause("Due This Week").unwrap(),
+            "due >= startOfWeek() AND due <= endOfWeek()"
+        );
+        assert_eq!(due_date_clause("no_due_date").unwrap(), "due is EMPTY");
+        assert_eq!(due_date_clause("in 3 days").unwrap(), "due <= 3d");
+        assert!(due_date_clause("  ").is_err());
+        assert!(due_date_clause("someday").is_err());
+    }
+
     #[test]
     fn test_capitalize_first() {
         assert_eq!(capitalize_first("test"), "Test");
@@ -688,23 +1819,59 @@ mod tests {
 
     #[test]
     fn test_escape_jql_string() {
-        assert_eq!(escape_jql_string("test\"quote"), "test\\\"quote");
-        assert_eq!(escape_jql_string("test\\backslash"), "test\\\\backslash");
-        assert_eq!(escape_jql_string("normal text"), "normal text");
+        assert_eq!(jql::escape_value("test\"quote"), "test\\\"quote");
+        assert_eq!(jql::escape_value("test\\backslash"), "test\\\\backslash");
+        assert_eq!(jql::escape_value("normal text"), "normal text");
     }
 
     #[test]
-    fn test_relative_date_parsing() {
-        assert_eq!(parse_relative_date("7 days ago"), Some("-7d".to_string()));
-        assert_eq!(parse_relative_date("2 weeks ago"), Some("-2w".to_string()));
-        assert_eq!(parse_relative_date("1 month ago"), Some("-1M".to_string()));
-        assert_eq!(parse_relative_date("invalid format"), None);
-    }
+    fn test_created_after_uses_date_expr() {
+        let config = create_test_config();
+        let cache = Arc::new(MetadataCache::new(300));
+        let mapper = SemanticMapper::new(config, cache);
 
-    #[test]
-    fn test_date_filter_parsing() {
-        assert_eq!(parse_date_filter("2024-01-01").unwrap(), "2024-01-01");
-        assert_eq!(parse_date_filter("7 days ago").unwrap(), "-7d");
-        assert!(parse_date_filter("invalid").is_err());
+        let query = mapper
+            .build_search_jql(
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some("7 days ago"),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert!(query.jql.contains("created >= -7d"));
+
+        let query = mapper
+            .build_search_jql(
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some("2024-01-01"),
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+        assert!(query.jql.contains("created >= \"2024-01-01\""));
+
+        assert!(mapper
+            .build_search_jql(
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some("invalid"),
+                None,
+                None,
+                None
+            )
+            .is_err());
     }
 }
@@ -0,0 +1,541 @@
+//! Natural language date expressions
+//!
+//! Parses the date strings AI agents tend to send ("7 days ago", "yesterday",
+//! "next_week", "2024-01-01") into JQL values. Relative expressions are kept as
+//! native JQL offsets and functions (`-7d`, `startOfWeek()`) so they are evaluated
+//! by JIRA in the user's timezone rather than on the server.
+//!
+//! Writing a date field needs a concrete day instead, so [`resolve_date`] turns
+//! the same kind of input (plus weekday names and "+2w") into a calendar date.
+//! [`resolve_instant`] does the same for cutoffs compared against timestamps,
+//! where "2 hours ago" has to keep its hours.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use chrono::{DateTime, Datelike, Days, Duration, Months, NaiveDate, NaiveDateTime, Utc, Weekday};
+
+/// Human-readable list of accepted forms, used in error messages and tool docs
+pub const SUPPORTED_FORMS: &str = "ISO dates ('2024-01-01', '2024-01-01T10:30:00Z'), \
+     'N days/weeks/months/years ago', 'last N days', 'in N days', JQL offsets ('-7d'), \
+     'today', 'yesterday', 'tomorrow', 'this_week', 'last_week', 'next_week', \
+     'this_month', 'last_month', 'next_month', 'start of week', 'start of month', 'start of year'";
+
+/// Accepted forms for [`resolve_date`]
+pub const SUPPORTED_DATE_FORMS: &str = "ISO dates ('2024-01-01'), 'today', 'tomorrow', \
+     'yesterday', weekday names ('friday', 'next friday'), 'end of week', 'end of month', \
+     'next_week', 'next_month', 'in N days/weeks/months', 'N days from now', 'N days ago', \
+     offsets ('+2w', '3d', '-1d')";
+
+/// Accepted forms for [`resolve_instant`]
+pub const SUPPORTED_INSTANT_FORMS: &str = "ISO timestamps ('2024-01-01T10:30:00Z', \
+     '2024-01-01 10:30'), ISO dates ('2024-01-01'), 'now', 'today', 'yesterday', \
+     'N minutes/hours/days/weeks/months ago', 'last N days', JQL offsets ('-2h', '-7d')";
+
+/// A parsed date expression expressed as JQL values
+///
+/// `start` is always set. `end` is only set for expressions that describe a whole
+/// period (e.g. "this_week"), so callers can build range clauses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateExpr {
+    /// Inclusive lower bound, ready to be placed after a JQL operator
+    pub start: String,
+
+    /// Inclusive upper bound for period expressions
+    pub end: Option<String>,
+}
+
+impl DateExpr {
+    fn point(value: impl Into<String>) -> Self {
+        Self {
+            start: value.into(),
+            end: None,
+        }
+    }
+
+    fn period(start: impl Into<String>, end: impl Into<String>) -> Self {
+        Self {
+            start: start.into(),
+            end: Some(end.into()),
+        }
+    }
+
+    /// Parse a date expression, naming `parameter` in the error on failure
+    pub fn parse(input: &str, parameter: &str) -> JiraMcpResult<Self> {
+        parse_expr(input).ok_or_else(|| {
+            JiraMcpError::invalid_param(
+                parameter,
+                format!(
+                    "Invalid date expression: '{}'. Supported forms: {}",
+                    input, SUPPORTED_FORMS
+                ),
+            )
+        })
+    }
+
+    /// `field >= start`, e.g. for created_after / updated_since
+    pub fn since_clause(&self, field: &str) -> String {
+        format!("{} >= {}", field, self.start)
+    }
+
+    /// `field <= end`, falling back to the start for point expressions
+    pub fn until_clause(&self, field: &str) -> String {
+        format!(
+            "{} <= {}",
+            field,
+            self.end.as_deref().unwrap_or(&self.start)
+        )
+    }
+
+    /// Range clause for period expressions, `until_clause` otherwise
+    pub fn within_clause(&self, field: &str) -> String {
+        match &self.end {
+            Some(end) => format!("{} >= {} AND {} <= {}", field, self.start, field, end),
+            None => self.until_clause(field),
+        }
+    }
+}
+
+fn parse_expr(input: &str) -> Option<DateExpr> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        return None;
+    }
+
+    if let Some(absolute) = parse_absolute(trimmed) {
+        return Some(DateExpr::point(absolute));
+    }
+
+    // Normalize "next week", "next-week" and "next_week" to the same keyword
+    let normalized = trimmed
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '_' || c == '-')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if let Some(keyword) = parse_keyword(&normalized) {
+        return Some(keyword);
+    }
+
+    parse_offset(trimmed).map(DateExpr::point)
+}
+
+/// ISO dates and datetimes, returned as quoted JQL date literals
+fn parse_absolute(input: &str) -> Option<String> {
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        return Some(format!("\"{}\"", date.format("%Y-%m-%d")));
+    }
+
+    let datetime = DateTime::parse_from_rfc3339(input)
+        .map(|dt| dt.naive_utc())
+        .ok()
+        .or_else(|| NaiveDateTime::parse_from_str(input, "%Y-%m-%dT%H:%M:%S").ok())
+        .or_else(|| NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M").ok())?;
+
+    Some(format!("\"{}\"", datetime.format("%Y-%m-%d %H:%M")))
+}
+
+/// Named days, periods and period starts
+fn parse_keyword(normalized: &str) -> Option<DateExpr> {
+    let expr = match normalized {
+        "now" => DateExpr::point("now()"),
+        "today" => DateExpr::period("startOfDay()", "endOfDay()"),
+        "yesterday" => DateExpr::period("startOfDay(-1d)", "endOfDay(-1d)"),
+        "tomorrow" => DateExpr::period("startOfDay(1d)", "endOfDay(1d)"),
+        "this week" => DateExpr::period("startOfWeek()", "endOfWeek()"),
+        "last week" => DateExpr::period("startOfWeek(-1w)", "endOfWeek(-1w)"),
+        "next week" => DateExpr::period("startOfWeek(1w)", "endOfWeek(1w)"),
+        "this month" => DateExpr::period("startOfMonth()", "endOfMonth()"),
+        "last month" => DateExpr::period("startOfMonth(-1M)", "endOfMonth(-1M)"),
+        "next month" => DateExpr::period("startOfMonth(1M)", "endOfMonth(1M)"),
+        "this year" => DateExpr::period("startOfYear()", "endOfYear()"),
+        "start of day" => DateExpr::point("startOfDay()"),
+        "start of week" => DateExpr::point("startOfWeek()"),
+        "start of month" => DateExpr::point("startOfMonth()"),
+        "start of year" => DateExpr::point("startOfYear()"),
+        _ => return None,
+    };
+    Some(expr)
+}
+
+/// Relative offsets: "7 days ago", "last 2 weeks", "in 3 days", "-7d"
+fn parse_offset(raw: &str) -> Option<String> {
+    if let Some(offset) = parse_jql_offset(raw) {
+        return Some(offset);
+    }
+
+    // Dashes are significant here ("-7 days ago" is not a valid expression)
+    let lowered = raw.to_lowercase();
+    let parts: Vec<&str> = lowered
+        .split(|c: char| c.is_whitespace() || c == '_')
+        .filter(|part| !part.is_empty())
+        .collect();
+    let (amount, unit, sign) = match parts.as_slice() {
+        [amount, unit, "ago"] => (*amount, *unit, "-"),
+        ["last", amount, unit] | ["past", amount, unit] => (*amount, *unit, "-"),
+        ["in", amount, unit] => (*amount, *unit, ""),
+        [amount, unit, "from", "now"] => (*amount, *unit, ""),
+        _ => return None,
+    };
+
+    let amount = amount.parse::<u32>().ok()?;
+    let unit = match unit {
+        "minute" | "minutes" => "m",
+        "hour" | "hours" => "h",
+        "day" | "days" => "d",
+        "week" | "weeks" => "w",
+        "month" | "months" => "M",
+        "year" | "years" => "y",
+        _ => return None,
+    };
+
+    Some(format!("{}{}{}", sign, amount, unit))
+}
+
+/// Native JQL relative offsets such as "-7d", "2w" or "-1M"
+fn parse_jql_offset(raw: &str) -> Option<String> {
+    let digits = raw.strip_prefix('-').unwrap_or(raw);
+    let unit = digits.chars().last()?;
+    let number = &digits[..digits.len() - unit.len_utf8()];
+
+    if number.is_empty() || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    if !matches!(unit, 'm' | 'h' | 'd' | 'w' | 'M' | 'y') {
+        return None;
+    }
+
+    Some(raw.to_string())
+}
+
+/// Resolve a date expression to a calendar date relative to `today`
+///
+/// Weekdays resolve to their next occurrence (today included); "next friday"
+/// is the Friday of next week. Weeks start on Monday, "end of week" is Sunday,
+/// and "next_week"/"next_month" mean the first day of that period.
+pub fn resolve_date(input: &str, today: NaiveDate, parameter: &str) -> JiraMcpResult<NaiveDate> {
+    resolve(input.trim(), today).ok_or_else(|| {
+        JiraMcpError::invalid_param(
+            parameter,
+            format!(
+                "Invalid date: '{}'. Supported forms: {}",
+                input, SUPPORTED_DATE_FORMS
+            ),
+        )
+    })
+}
+
+fn resolve(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    if input.is_empty() {
+        return None;
+    }
+
+    if let Ok(date) = NaiveDate::parse_from_str(input, "%Y-%m-%d") {
+        return Some(date);
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(input) {
+        return Some(datetime.date_naive());
+    }
+
+    let normalized = input
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let start_of_week = today - Days::new(today.weekday().num_days_from_monday() as u64);
+    let start_of_month = today.with_day(1)?;
+
+    match normalized.as_str() {
+        "today" | "now" => return Some(today),
+        "tomorrow" => return today.checked_add_days(Days::new(1)),
+        "yesterday" => return today.checked_sub_days(Days::new(1)),
+        "end of week" | "this week" => return start_of_week.checked_add_days(Days::new(6)),
+        "next week" => return start_of_week.checked_add_days(Days::new(7)),
+        "end of month" | "this month" => {
+            return start_of_month
+                .checked_add_months(Months::new(1))?
+                .checked_sub_days(Days::new(1))
+        }
+        "next month" => return start_of_month.checked_add_months(Months::new(1)),
+        _ => {}
+    }
+
+    let parts: Vec<&str> = normalized.split(' ').collect();
+    let weekday = match parts.as_slice() {
+        [day] | ["this", day] => day.parse::<Weekday>().ok().map(|day| (day, false)),
+        ["next", day] => day.parse::<Weekday>().ok().map(|day| (day, true)),
+        _ => None,
+    };
+    if let Some((weekday, next_week)) = weekday {
+        let target = weekday.num_days_from_monday() as u64;
+        return if next_week {
+            start_of_week.checked_add_days(Days::new(7 + target))
+        } else {
+            let current = today.weekday().num_days_from_monday() as u64;
+            today.checked_add_days(Days::new((7 + target - current) % 7))
+        };
+    }
+
+    // "+2w" is not valid JQL, so it is only accepted here
+    let offset = input.strip_prefix('+').unwrap_or(input);
+    let offset = parse_offset(offset)?;
+    let (amount, unit) = offset.split_at(offset.len() - 1);
+    let negative = amount.starts_with('-');
+    let amount: u32 = amount.trim_start_matches('-').parse().ok()?;
+
+    match (unit, negative) {
+        ("d", false) => today.checked_add_days(Days::new(amount.into())),
+        ("d", true) => today.checked_sub_days(Days::new(amount.into())),
+        ("w", false) => today.checked_add_days(Days::new(u64::from(amount) * 7)),
+        ("w", true) => today.checked_sub_days(Days::new(u64::from(amount) * 7)),
+        ("M", false) => today.checked_add_months(Months::new(amount)),
+        ("M", true) => today.checked_sub_months(Months::new(amount)),
+        ("y", false) => today.checked_add_months(Months::new(amount.checked_mul(12)?)),
+        ("y", true) => today.checked_sub_months(Months::new(amount.checked_mul(12)?)),
+        // Minutes and hours don't make sense for a date field
+        _ => None,
+    }
+}
+
+/// Resolve a point in time relative to `now`
+///
+/// Timestamps without an offset, dates, "today" and "yesterday" are taken as
+/// UTC; dates and days mean their midnight. Offsets always point into the past,
+/// so "-2h" and "2 hours ago" are the same.
+pub fn resolve_instant(
+    input: &str,
+    now: DateTime<Utc>,
+    parameter: &str,
+) -> JiraMcpResult<DateTime<Utc>> {
+    instant(input.trim(), now).ok_or_else(|| {
+        JiraMcpError::invalid_param(
+            parameter,
+            format!(
+                "Invalid time: '{}'. Supported forms: {}",
+                input, SUPPORTED_INSTANT_FORMS
+            ),
+        )
+    })
+}
+
+fn instant(input: &str, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if input.is_empty() {
+        return None;
+    }
+
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(input) {
+        return Some(datetime.with_timezone(&Utc));
+    }
+    let naive = NaiveDateTime::parse_from_str(input, "%Y-%m-%dT%H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(input, "%Y-%m-%d %H:%M"))
+        .ok()
+        .or_else(|| {
+            NaiveDate::parse_from_str(input, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)
+        });
+    if let Some(naive) = naive {
+        return Some(naive.and_utc());
+    }
+
+    let midnight = |date: NaiveDate| date.and_hms_opt(0, 0, 0).map(|day| day.and_utc());
+    match input.to_lowercase().as_str() {
+        "now" => return Some(now),
+        "today" => return midnight(now.date_naive()),
+        "yesterday" => return midnight(now.date_naive().checked_sub_days(Days::new(1))?),
+        _ => {}
+    }
+
+    let offset = parse_offset(input)?;
+    let (amount, unit) = offset.split_at(offset.len() - 1);
+    let amount: u32 = amount.trim_start_matches('-').parse().ok()?;
+    let hours = |n: u32| now.checked_sub_signed(Duration::hours(n.into()));
+
+    match unit {
+        "m" => now.checked_sub_signed(Duration::minutes(amount.into())),
+        "h" => hours(amount),
+        "d" => hours(amount.checked_mul(24)?),
+        "w" => hours(amount.checked_mul(24 * 7)?),
+        "M" => now.checked_sub_months(Months::new(amount)),
+        "y" => now.checked_sub_months(Months::new(amount.checked_mul(12)?)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start(input: &str) -> String {
+        DateExpr::parse(input, "test").unwrap().start
+    }
+
+    #[test]
+    fn test_iso_dates() {
+        assert_eq!(start("2024-01-01"), "\"2024-01-01\"");
+        assert_eq!(start(" 2024-12-31 "), "\"2024-12-31\"");
+        assert_eq!(start("2024-01-01T10:30:00Z"), "\"2024-01-01 10:30\"");
+        assert_eq!(start("2024-01-01T10:30:00+02:00"), "\"2024-01-01 08:30\"");
+        assert_eq!(start("2024-01-01T10:30:00"), "\"2024-01-01 10:30\"");
+        assert_eq!(start("2024-01-01 10:30"), "\"2024-01-01 10:30\"");
+        assert!(DateExpr::parse("2024-13-01", "test").is_err());
+        assert!(DateExpr::parse("2024-02-30", "test").is_err());
+    }
+
+    #[test]
+    fn test_relative_ago() {
+        assert_eq!(start("7 days ago"), "-7d");
+        assert_eq!(start("1 day ago"), "-1d");
+        assert_eq!(start("2 weeks ago"), "-2w");
+        assert_eq!(start("1 month ago"), "-1M");
+        assert_eq!(start("3 Years Ago"), "-3y");
+        assert_eq!(start("4 hours ago"), "-4h");
+        assert_eq!(start("30 minutes ago"), "-30m");
+        assert!(DateExpr::parse("seven days ago", "test").is_err());
+        assert!(DateExpr::parse("7 fortnights ago", "test").is_err());
+        assert!(DateExpr::parse("-7 days ago", "test").is_err());
+    }
+
+    #[test]
+    fn test_last_and_future_offsets() {
+        assert_eq!(start("last 7 days"), "-7d");
+        assert_eq!(start("past 2 weeks"), "-2w");
+        assert_eq!(start("in 3 days"), "3d");
+        assert_eq!(start("2 weeks from now"), "2w");
+    }
+
+    #[test]
+    fn test_native_jql_offsets() {
+        assert_eq!(start("-7d"), "-7d");
+        assert_eq!(start("-1M"), "-1M");
+        assert_eq!(start("2w"), "2w");
+        assert!(DateExpr::parse("-d", "test").is_err());
+        assert!(DateExpr::parse("-7x", "test").is_err());
+        assert!(DateExpr::parse("7", "test").is_err());
+    }
+
+    #[test]
+    fn test_named_periods() {
+        let today = DateExpr::parse("today", "test").unwrap();
+        assert_eq!(today.start, "startOfDay()");
+        assert_eq!(today.end.as_deref(), Some("endOfDay()"));
+
+        let yesterday = DateExpr::parse("Yesterday", "test").unwrap();
+        assert_eq!(yesterday.start, "startOfDay(-1d)");
+
+        // Underscore, dash and space spellings are equivalent
+        for input in ["next_week", "next week", "next-week", "NEXT_WEEK"] {
+            let next_week = DateExpr::parse(input, "test").unwrap();
+            assert_eq!(next_week.start, "startOfWeek(1w)");
+            assert_eq!(next_week.end.as_deref(), Some("endOfWeek(1w)"));
+        }
+
+        assert_eq!(start("this_week"), "startOfWeek()");
+        assert_eq!(start("last_month"), "startOfMonth(-1M)");
+        assert_eq!(start("now"), "now()");
+    }
+
+    #[test]
+    fn test_start_of_period() {
+        let expr = DateExpr::parse("start of month", "test").unwrap();
+        assert_eq!(expr.start, "startOfMonth()");
+        assert_eq!(expr.end, None);
+        assert_eq!(start("start_of_week"), "startOfWeek()");
+        assert_eq!(start("Start of Year"), "startOfYear()");
+    }
+
+    #[test]
+    fn test_clauses() {
+        let ago = DateExpr::parse("7 days ago", "test").unwrap();
+        assert_eq!(ago.since_clause("updated"), "updated >= -7d");
+        assert_eq!(ago.within_clause("due"), "due <= -7d");
+
+        let date = DateExpr::parse("2024-01-01", "test").unwrap();
+        assert_eq!(date.until_clause("due"), "due <= \"2024-01-01\"");
+
+        let week = DateExpr::parse("this_week", "test").unwrap();
+        assert_eq!(week.since_clause("created"), "created >= startOfWeek()");
+        assert_eq!(week.until_clause("due"), "due <= endOfWeek()");
+        assert_eq!(
+            week.within_clause("due"),
+            "due >= startOfWeek() AND due <= endOfWeek()"
+        );
+    }
+
+    #[test]
+    fn test_error_lists_supported_forms() {
+        let err = DateExpr::parse("sometime soon", "updated_since").unwrap_err();
+        match err {
+            JiraMcpError::InvalidParameter { parameter, message } => {
+                assert_eq!(parameter, "updated_since");
+                assert!(message.contains("sometime soon"));
+                assert!(message.contains("days/weeks/months/years ago"));
+                assert!(message.contains("next_week"));
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+
+        assert!(DateExpr::parse("", "test").is_err());
+        assert!(DateExpr::parse("   ", "test").is_err());
+    }
+
+    #[test]
+    fn test_resolve_date() {
+        // A Wednesday
+        let today = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+        let date = |input: &str| resolve_date(input, today, "due_date").unwrap().to_string();
+
+        assert_eq!(date("2025-04-01"), "2025-04-01");
+        assert_eq!(date("2025-04-01T10:00:00Z"), "2025-04-01");
+        assert_eq!(date("today"), "2025-03-12");
+        assert_eq!(date("Tomorrow"), "2025-03-13");
+
+        assert_eq!(date("friday"), "2025-03-14");
+        assert_eq!(date("wednesday"), "2025-03-12");
+        assert_eq!(date("monday"), "2025-03-17");
+        assert_eq!(date("next friday"), "2025-03-21");
+        assert_eq!(date("next_monday"), "2025-03-17");
+
+        assert_eq!(date("end of week"), "2025-03-16");
+        assert_eq!(date("next_week"), "2025-03-17");
+        assert_eq!(date("end_of_month"), "2025-03-31");
+        assert_eq!(date("next month"), "2025-04-01");
+
+        assert_eq!(date("+2w"), "2025-03-26");
+        assert_eq!(date("3d"), "2025-03-15");
+        assert_eq!(date("-1d"), "2025-03-11");
+        assert_eq!(date("in 1 month"), "2025-04-12");
+        assert_eq!(date("2 weeks from now"), "2025-03-26");
+        assert_eq!(date("3 days ago"), "2025-03-09");
+
+        assert!(resolve_date("4h", today, "due_date").is_err());
+        assert!(resolve_date("next fortnight", today, "due_date").is_err());
+        assert!(resolve_date("", today, "due_date").is_err());
+    }
+
+    #[test]
+    fn test_resolve_instant() {
+        let now = "2025-03-12T15:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        let at = |input: &str| resolve_instant(input, now, "since").unwrap().to_rfc3339();
+
+        assert_eq!(at("2025-03-10T08:00:00+02:00"), "2025-03-10T06:00:00+00:00");
+        assert_eq!(at("2025-03-10T08:00:00"), "2025-03-10T08:00:00+00:00");
+        assert_eq!(at("2025-03-10 08:00"), "2025-03-10T08:00:00+00:00");
+        assert_eq!(at("2025-03-10"), "2025-03-10T00:00:00+00:00");
+
+        assert_eq!(at("now"), "2025-03-12T15:30:00+00:00");
+        assert_eq!(at("today"), "2025-03-12T00:00:00+00:00");
+        assert_eq!(at("Yesterday"), "2025-03-11T00:00:00+00:00");
+
+        assert_eq!(at("2 hours ago"), "2025-03-12T13:30:00+00:00");
+        assert_eq!(at("-2h"), "2025-03-12T13:30:00+00:00");
+        assert_eq!(at("30 minutes ago"), "2025-03-12T15:00:00+00:00");
+        assert_eq!(at("last 3 days"), "2025-03-09T15:30:00+00:00");
+        assert_eq!(at("1 week ago"), "2025-03-05T15:30:00+00:00");
+        assert_eq!(at("1 month ago"), "2025-02-12T15:30:00+00:00");
+
+        assert!(resolve_instant("friday", now, "since").is_err());
+        assert!(resolve_instant("", now, "since").is_err());
+    }
+}
@@ -0,0 +1,151 @@
+//! Graceful shutdown that lets in-flight writes finish
+//!
+//! A client closing stdio, or a SIGTERM, in the middle of a description rewrite
+//! must not kill the process between reading and writing the issue. Every
+//! mutating tool call holds a [`WriteGuard`] from the [`ShutdownCoordinator`]
+//! while it runs. Once shutdown begins no new guards are handed out, so new
+//! writes are refused, and [`ShutdownCoordinator::drain`] waits (bounded) for
+//! the guards still held to be dropped before state is flushed and the process
+//! exits.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use std::time::Duration;
+use tokio::sync::watch;
+
+#[derive(Debug, Default, Clone, Copy)]
+struct WriteState {
+    in_flight: usize,
+    closing: bool,
+}
+
+/// Tracks in-flight writes and refuses new ones once shutdown began
+#[derive(Debug)]
+pub struct ShutdownCoordinator {
+    state: watch::Sender<WriteState>,
+}
+
+impl Default for ShutdownCoordinator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownCoordinator {
+    pub fn new() -> Self {
+        Self {
+            state: watch::Sender::new(WriteState::default()),
+        }
+    }
+
+    /// Register a write that is about to start
+    ///
+    /// Fails once shutdown began, before anything was sent to JIRA. The write
+    /// counts as in flight until the guard is dropped.
+    pub fn begin_write(&self, tool: &str) -> JiraMcpResult<WriteGuard<'_>> {
+        let mut accepted = false;
+        self.state.send_if_modified(|state| {
+            accepted = !state.closing;
+            if accepted {
+                state.in_flight += 1;
+            }
+            accepted
+        });
+
+        if accepted {
+            Ok(WriteGuard { coordinator: self })
+        } else {
+            Err(JiraMcpError::internal(format!(
+                "The server is shutting down; {} was not started",
+                tool
+            )))
+        }
+    }
+
+    /// Refuse new writes from now on
+    pub fn close(&self) {
+        self.state.send_modify(|state| state.closing = true);
+    }
+
+    /// Whether shutdown began
+    pub fn is_closing(&self) -> bool {
+        self.state.borrow().closing
+    }
+
+    /// Writes currently running
+    pub fn in_flight(&self) -> usize {
+        self.state.borrow().in_flight
+    }
+
+    /// Refuse new writes and wait up to `timeout` for the running ones to finish
+    ///
+    /// Returns the number of writes still running when the timeout expired.
+    pub async fn drain(&self, timeout: Duration) -> usize {
+        self.close();
+        let mut state = self.state.subscribe();
+        let drained = tokio::time::timeout(timeout, state.wait_for(|state| state.in_flight == 0))
+            .await
+            .is_ok();
+        if drained {
+            0
+        } else {
+            self.in_flight()
+        }
+    }
+}
+
+/// Marks a write as in flight until dropped
+#[derive(Debug)]
+pub struct WriteGuard<'a> {
+    coordinator: &'a ShutdownCoordinator,
+}
+
+impl Drop for WriteGuard<'_> {
+    fn drop(&mut self) {
+        self.coordinator
+            .state
+            .send_modify(|state| state.in_flight -= 1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_drain_waits_for_writes() {
+        let idle = ShutdownCoordinator::new();
+        assert_eq!(idle.drain(Duration::from_millis(10)).await, 0);
+
+        let coordinator = Arc::new(ShutdownCoordinator::new());
+        let writer = {
+            let coordinator = Arc::clone(&coordinator);
+            let (started_tx, started_rx) = tokio::sync::oneshot::channel();
+            let handle = tokio::spawn(async move {
+                let _guard = coordinator.begin_write("add_comment").unwrap();
+                started_tx.send(()).unwrap();
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            });
+            started_rx.await.unwrap();
+            handle
+        };
+
+        assert_eq!(coordinator.in_flight(), 1);
+        assert_eq!(coordinator.drain(Duration::from_secs(5)).await, 0);
+        assert_eq!(coordinator.in_flight(), 0);
+        writer.await.unwrap();
+
+        // No new writes once shutdown began
+        let error = coordinator.begin_write("create_issue").unwrap_err();
+        assert!(error.to_string().contains("shutting down"));
+        assert_eq!(coordinator.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_drain_is_bounded() {
+        let coordinator = ShutdownCoordinator::new();
+        let _guard = coordinator.begin_write("update_issue_description").unwrap();
+        assert_eq!(coordinator.drain(Duration::from_millis(20)).await, 1);
+        assert!(coordinator.is_closing());
+    }
+}
@@ -0,0 +1,126 @@
+//! Coalescing of identical concurrent requests
+//!
+//! Agents often fire several tool calls at once that read the same issue. The
+//! first caller for a key runs the request; callers arriving while it is in
+//! flight wait for it and get a clone of its result, errors included. Nothing
+//! is kept once the request finished, so a later call (say, a retry after a
+//! failure) makes a new request; caching is left to the metadata cache.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::sync::OnceCell;
+
+/// Requests in flight by key, e.g. "GET api/issue/PROJ-1"
+#[derive(Debug)]
+pub struct SingleFlight<T> {
+    in_flight: Mutex<HashMap<String, Arc<OnceCell<T>>>>,
+}
+
+impl<T> Default for SingleFlight<T> {
+    fn default() -> Self {
+        Self {
+            in_flight: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T: Clone> SingleFlight<T> {
+    /// Run `request`, or share the result of the one in flight for `key`
+    ///
+    /// When the caller running the request is cancelled, one of the waiting
+    /// callers runs its own request instead.
+    pub async fn run<F, Fut>(&self, key: &str, request: F) -> T
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let cell = Arc::clone(
+            self.in_flight
+                .lock()
+                .unwrap()
+                .entry(key.to_string())
+                .or_default(),
+        );
+        let result = cell.get_or_init(request).await.clone();
+
+        let mut in_flight = self.in_flight.lock().unwrap();
+        if in_flight
+            .get(key)
+            .is_some_and(|current| Arc::ptr_eq(current, &cell))
+        {
+            in_flight.remove(key);
+        }
+        result
+    }
+
+    /// Keys with a request in flight
+    pub fn in_flight(&self) -> usize {
+        self.in_flight.lock().unwrap().len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+
+    async fn slow_request(calls: &AtomicUsize, result: Result<u32, String>) -> Result<u32, String> {
+        calls.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        result
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_calls_share_one_request() {
+        let flight = SingleFlight::default();
+        let calls = AtomicUsize::new(0);
+
+        let request = || slow_request(&calls, Ok(7));
+        let results = tokio::join!(
+            flight.run("GET api/issue/PROJ-1", request),
+            flight.run("GET api/issue/PROJ-1", request),
+            flight.run("GET api/issue/PROJ-1", request)
+        );
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(results, (Ok(7), Ok(7), Ok(7)));
+        assert_eq!(flight.in_flight(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_errors_are_shared_but_not_kept() {
+        let flight = SingleFlight::default();
+        let calls = AtomicUsize::new(0);
+        let failing = || slow_request(&calls, Err("timeout".to_string()));
+
+        let (first, second) = tokio::join!(
+            flight.run("GET api/myself", failing),
+            flight.run("GET api/myself", failing)
+        );
+        assert_eq!(first, Err("timeout".to_string()));
+        assert_eq!(second, first);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // The next call makes a new request
+        let retried = flight
+            .run("GET api/myself", || slow_request(&calls, Ok(1)))
+            .await;
+        assert_eq!(retried, Ok(1));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_different_keys_are_not_coalesced() {
+        let flight = SingleFlight::default();
+        let calls = AtomicUsize::new(0);
+
+        let results = tokio::join!(
+            flight.run("GET api/issue/PROJ-1", || slow_request(&calls, Ok(1))),
+            flight.run("GET api/issue/PROJ-2", || slow_request(&calls, Ok(2)))
+        );
+        assert_eq!(results, (Ok(1), Ok(2)));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+}
@@ -3,12 +3,18 @@
 //! This tool allows AI agents to add comments to JIRA issues with
 //! simple parameters and comprehensive error handling.
 
+use crate::adf;
 use crate::cache::MetadataCache;
 use crate::config::JiraConfig;
 use crate::error::{JiraMcpError, JiraMcpResult};
-use crate::jira_client::{CommentInfo, JiraClient};
+use crate::idempotency::IdempotencyStore;
+use crate::jira_client::{CommentInfo, CommentVisibility, JiraClient, VisibilityType};
+use crate::tools::issue_key::normalize_issue_id_or_key;
+use crate::tools::signature::{sign, signature_chars};
+use crate::tools::text_limit::fit_text;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
 use std::sync::Arc;
 use tracing::{info, instrument};
 
@@ -25,8 +31,40 @@ pub struct AddCommentParams {
     pub comment_body: String,
 
     /// Visibility restriction (optional)
-    /// Can be used to restrict comment visibility to specific groups or roles
-    pub visibility: Option<String>,
+    /// Restricts the comment to a project role or group
+    /// Examples: {"type": "role", "value": "Developers"}, {"type": "group", "value": "jira-admins"}
+    pub visibility: Option<CommentVisibility>,
+
+    /// Mark as an internal JSM comment, hidden from customers (optional, default: false)
+    /// Only meaningful in Jira Service Management projects
+    #[serde(default)]
+    pub jsm_internal: bool,
+
+    /// Users to @mention at the start of the comment (optional)
+    /// Account IDs on Cloud, usernames on Server/Data Center, or "me"
+    #[serde(default)]
+    pub mentions: Vec<String>,
+
+    /// Send the body as an ADF document converted from markdown (optional, default: false)
+    /// Keeps code blocks, lists and links formatted on JIRA Cloud; ignored on
+    /// Server/Data Center, where the body is sent as wiki markup
+    #[serde(default)]
+    pub adf: bool,
+
+    /// Cut a body longer than max_comment_chars (default 32767) and end it with
+    /// a truncation marker, instead of failing (default: false)
+    #[serde(default)]
+    pub auto_truncate: bool,
+
+    /// Leave out the comment_signature configured for the server (default: false)
+    #[serde(default)]
+    pub skip_signature: bool,
+
+    /// Key that makes retries safe (optional): a repeated call with the same key
+    /// within 24 hours returns the comment added first, with `replayed: true`,
+    /// instead of commenting again
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
 }
 
 /// Result from the add_comment tool
@@ -41,6 +79,14 @@ pub struct AddCommentResult {
     /// Success message
     pub message: String,
 
+    /// Characters auto_truncate cut from the end of the body
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncated_chars: Option<usize>,
+
+    /// True when the idempotency_key was seen before and nothing was added
+    #[serde(default)]
+    pub replayed: bool,
+
     /// Performance information
     pub performance: CommentPerformance,
 }
@@ -61,10 +107,10 @@ pub struct CommentPerformance {
 /// Implementation of the add_comment tool
 pub struct AddCommentTool {
     jira_client: Arc<JiraClient>,
-    #[allow(dead_code)]
     config: Arc<JiraConfig>,
     #[allow(dead_code)]
     cache: Arc<MetadataCache>,
+    idempotency: Arc<IdempotencyStore>,
 }
 
 impl AddCommentTool {
@@ -73,11 +119,13 @@ impl AddCommentTool {
         jira_client: Arc<JiraClient>,
         config: Arc<JiraConfig>,
         cache: Arc<MetadataCache>,
+        idempotency: Arc<IdempotencyStore>,
     ) -> Self {
         Self {
             jira_client,
             config,
             cache,
+            idempotency,
         }
     }
 
@@ -87,6 +135,30 @@ impl AddCommentTool {
         comment_length = params.comment_body.len(),
     ))]
     pub async fn execute(&self, params: AddCommentParams) -> JiraMcpResult<AddCommentResult> {
+        let idempotency_key = params.idempotency_key.clone();
+        let (mut result, replayed) = self
+            .idempotency
+            .run(
+                "add_comment",
+                idempotency_key.as_deref(),
+                |result: &AddCommentResult| result.comment.id.clone(),
+                self.add(params),
+            )
+            .await?;
+        if replayed {
+            info!(
+                "Idempotency key already added comment {} to {}, not commenting again",
+                result.comment.id, result.issue_key
+            );
+            result.replayed = true;
+        }
+        Ok(result)
+    }
+
+    async fn add(&self, mut params: AddCommentParams) -> JiraMcpResult<AddCommentResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
         let start_time = std::time::Instant::now();
         let mut api_calls = 0u32;
 
@@ -94,11 +166,50 @@ impl AddCommentTool {
 
         // Validate parameters
         self.validate_params(&params)?;
+        // Signed inside the idempotent call, so a replay is not signed again
+        let signature = self
+            .config
+            .comment_signature
+            .as_deref()
+            .filter(|_| !params.skip_signature);
+        let (comment_body, truncated_chars) = fit_text(
+            &params.comment_body,
+            self.jira_client
+                .write_limits()
+                .max_comment_chars
+                .saturating_sub(signature_chars(signature)),
+            params.auto_truncate,
+            "comment_body",
+            "comment body",
+        )?;
+        let comment_body = sign(&comment_body, signature);
+
+        let deployment_type = self.jira_client.deployment_type();
+        let mentioned = self
+            .resolve_mentions(&params.mentions, &mut api_calls)
+            .await?;
+
+        let body = if params.adf && deployment_type.is_cloud() {
+            adf_comment(&comment_body, &mentioned)
+        } else if mentioned.is_empty() {
+            Value::String(comment_body)
+        } else {
+            let markup: Vec<String> = mentioned
+                .iter()
+                .map(|user| deployment_type.mention(user))
+                .collect();
+            Value::String(format!("{} {}", markup.join(" "), comment_body))
+        };
 
         // Add the comment using the JIRA client
         let comment = self
             .jira_client
-            .add_comment(&params.issue_key, &params.comment_body)
+            .add_comment(
+                &params.issue_key,
+                body,
+                params.visibility.as_ref(),
+                params.jsm_internal,
+            )
             .await?;
 
         api_calls += 1;
@@ -110,10 +221,30 @@ impl AddCommentTool {
             duration.as_millis()
         );
 
+        // Make restrictions explicit so agents don't quote the comment publicly
+        let message = match &comment.visibility {
+            Some(visibility) => format!(
+                "Restricted comment added to issue {} (visible to {} '{}' only)",
+                params.issue_key,
+                match visibility.visibility_type {
+                    VisibilityType::Role => "role",
+                    VisibilityType::Group => "group",
+                },
+                visibility.value
+            ),
+            None if params.jsm_internal => format!(
+                "Internal comment added to issue {} (not visible to customers)",
+                params.issue_key
+            ),
+            None => format!("Comment successfully added to issue {}", params.issue_key),
+        };
+
         Ok(AddCommentResult {
             comment,
             issue_key: params.issue_key.clone(),
-            message: format!("Comment successfully added to issue {}", params.issue_key),
+            message,
+            truncated_chars,
+            replayed: false,
             performance: CommentPerformance {
                 duration_ms: duration.as_millis() as u64,
                 api_calls,
@@ -122,24 +253,27 @@ impl AddCommentTool {
         })
     }
 
-    /// Validate add comment parameters
-    fn validate_params(&self, params: &AddCommentParams) -> JiraMcpResult<()> {
-        // Validate issue key
-        if params.issue_key.trim().is_empty() {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key cannot be empty",
-            ));
-        }
-
-        // Validate issue key format (basic check)
-        if !params.issue_key.contains('-') {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key must be in format 'PROJECT-NUMBER' (e.g., 'PROJ-123')",
-            ));
+    /// Account IDs (usernames on Server/Data Center) of the users to mention, with "me" resolved
+    async fn resolve_mentions(
+        &self,
+        users: &[String],
+        api_calls: &mut u32,
+    ) -> JiraMcpResult<Vec<String>> {
+        let mut mentioned = Vec::with_capacity(users.len());
+        for user in users {
+            let user = if user == "me" {
+                *api_calls += 1;
+                self.jira_client.get_current_user().await?.account_id
+            } else {
+                user.trim().to_string()
+            };
+            mentioned.push(user);
         }
+        Ok(mentioned)
+    }
 
+    /// Validate add comment parameters
+    fn validate_params(&self, params: &AddCommentParams) -> JiraMcpResult<()> {
         // Validate comment body
         if params.comment_body.trim().is_empty() {
             return Err(JiraMcpError::invalid_param(
@@ -148,20 +282,19 @@ impl AddCommentTool {
             ));
         }
 
-        // Check comment length (JIRA has limits)
-        if params.comment_body.len() > 32_768 {
+        if params.mentions.iter().any(|m| m.trim().is_empty()) {
             return Err(JiraMcpError::invalid_param(
-                "comment_body",
-                "Comment body cannot exceed 32,768 characters",
+                "mentions",
+                "Mentions cannot contain empty entries",
             ));
         }
 
         // Validate visibility if provided
         if let Some(visibility) = &params.visibility {
-            if visibility.trim().is_empty() {
+            if visibility.value.trim().is_empty() {
                 return Err(JiraMcpError::invalid_param(
                     "visibility",
-                    "Visibility cannot be empty if provided",
+                    "Visibility value must name a role or group",
                 ));
             }
         }
@@ -170,6 +303,33 @@ impl AddCommentTool {
     }
 }
 
+/// ADF document for a markdown comment, with the mentions leading its first paragraph
+fn adf_comment(markdown: &str, account_ids: &[String]) -> Value {
+    let mut doc = adf::from_markdown(markdown);
+    if account_ids.is_empty() {
+        return doc;
+    }
+
+    let mut mentions = Vec::with_capacity(account_ids.len() * 2);
+    for account_id in account_ids {
+        mentions.push(json!({ "type": "mention", "attrs": { "id": account_id } }));
+        mentions.push(json!({ "type": "text", "text": " " }));
+    }
+
+    let starts_with_paragraph = doc["content"][0]["type"] == "paragraph";
+    if let Some(content) = doc["content"].as_array_mut() {
+        match content.first_mut() {
+            Some(first) if starts_with_paragraph => {
+                if let Some(inline) = first["content"].as_array_mut() {
+                    inline.splice(0..0, mentions);
+                }
+            }
+            _ => content.insert(0, json!({ "type": "paragraph", "content": mentions })),
+        }
+    }
+    doc
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(unused_imports)]
@@ -181,6 +341,12 @@ mod tests {
             issue_key: "TEST-123".to_string(),
             comment_body: "This is a test comment".to_string(),
             visibility: None,
+            jsm_internal: false,
+            mentions: Vec::new(),
+            adf: false,
+            auto_truncate: false,
+            skip_signature: false,
+            idempotency_key: None,
         }
     }
 
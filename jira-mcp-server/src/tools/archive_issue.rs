@@ -0,0 +1,299 @@
+//! Archive issue tool
+//!
+//! Many organisations forbid deleting issues; the usual substitute is to label
+//! the issue, move it to a terminal status and leave a closing comment. This tool
+//! does all of that in one call and reports each step separately, so a partial
+//! failure can be retried without redoing the steps that already worked.
+
+use crate::config::{ArchiveConfig, JiraConfig};
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_key;
+use crate::tools::transitions::TransitionIssueTool;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// Parameters for the archive_issue tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ArchiveIssueParams {
+    /// The JIRA issue key to archive (e.g., "PROJ-123")
+    pub issue_key: String,
+
+    /// Why the issue is being archived; fills `{reason}` in the closing comment
+    /// Examples: "duplicate of PROJ-100", "no longer relevant after the redesign"
+    #[serde(default)]
+    pub reason: Option<String>,
+
+    /// Also move the issue out of its sprint into the backlog (default: false)
+    #[serde(default)]
+    pub remove_from_sprint: bool,
+}
+
+/// Outcome of one archive step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ArchiveStepStatus {
+    /// The step made its change
+    Succeeded,
+    /// Nothing to do (already done, or not requested)
+    Skipped,
+    /// The step failed; see `detail`
+    Failed,
+}
+
+/// One step of archiving an issue
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct ArchiveStep {
+    /// Step name: "label", "transition", "comment" or "remove_from_sprint"
+    pub step: String,
+
+    /// What happened
+    pub status: ArchiveStepStatus,
+
+    /// Details, or the error for failed steps
+    pub detail: String,
+}
+
+/// Result from the archive_issue tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ArchiveIssueResult {
+    /// Issue key
+    pub issue_key: String,
+
+    /// True when no step failed
+    pub archived: bool,
+
+    /// Each step in the order it ran
+    pub steps: Vec<ArchiveStep>,
+
+    /// Summary message
+    pub message: String,
+}
+
+/// Tool for archiving issues instead of deleting them
+pub struct ArchiveIssueTool {
+    jira_client: Arc<JiraClient>,
+    config: Arc<JiraConfig>,
+    transition_tool: Arc<TransitionIssueTool>,
+}
+
+impl ArchiveIssueTool {
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        config: Arc<JiraConfig>,
+        transition_tool: Arc<TransitionIssueTool>,
+    ) -> Self {
+        Self {
+            jira_client,
+            config,
+            transition_tool,
+        }
+    }
+
+    #[instrument(skip(self), fields(issue_key = %params.issue_key))]
+    pub async fn execute(
+        &self,
+        mut params: ArchiveIssueParams,
+    ) -> JiraMcpResult<ArchiveIssueResult> {
+        params.issue_key =
+            normalize_issue_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        info!("Archiving issue: {}", params.issue_key);
+        let archive = &self.config.archive;
+
+        // Fails fast on unknown issues before anything is changed
+        let fields = self
+            .jira_client
+            .get_issue_fields(&params.issue_key, &["labels".to_string()])
+            .await?;
+
+        let mut steps = vec![
+            self.add_label(&params.issue_key, &fields, archive).await,
+            self.transition(&params.issue_key, archive).await,
+            self.comment(&params.issue_key, params.reason.as_deref(), archive)
+                .await,
+        ];
+        if params.remove_from_sprint {
+            steps.push(self.remove_from_sprint(&params.issue_key).await);
+        } else {
+            steps.push(skipped("remove_from_sprint", "Not requested"));
+        }
+
+        let failed: Vec<&str> = steps
+            .iter()
+            .filter(|s| s.status == ArchiveStepStatus::Failed)
+            .map(|s| s.step.as_str())
+            .collect();
+        let message = if failed.is_empty() {
+            format!("Archived {}", params.issue_key)
+        } else {
+            warn!(
+                "Archiving {} partially failed: {}",
+                params.issue_key,
+                failed.join(", ")
+            );
+            format!(
+                "Archiving {} incomplete; failed steps: {}. Succeeded steps were not \
+                 rolled back; retry only the failed ones",
+                params.issue_key,
+                failed.join(", ")
+            )
+        };
+
+        Ok(ArchiveIssueResult {
+            issue_key: params.issue_key,
+            archived: failed.is_empty(),
+            steps,
+            message,
+        })
+    }
+
+    async fn add_label(
+        &self,
+        issue_key: &str,
+        fields: &serde_json::Map<String, Value>,
+        archive: &ArchiveConfig,
+    ) -> ArchiveStep {
+        let already_labelled = fields
+            .get("labels")
+            .and_then(Value::as_array)
+            .is_some_and(|labels| labels.iter().any(|l| l.as_str() == Some(&archive.label)));
+        if already_labelled {
+            return skipped("label", format!("Already labelled '{}'", archive.label));
+        }
+
+        let endpoint = format!("/issue/{}", issue_key);
+        let body = serde_json::json!({ "update": { "labels": [{ "add": archive.label }] } });
+        match self
+            .jira_client
+            .put_json::<Value>(
+                &format!("labelling {}", issue_key),
+                "api",
+                &endpoint,
+                body,
+                JiraMcpError::from,
+            )
+            .await
+        {
+            Ok(_) => succeeded("label", format!("Added label '{}'", archive.label)),
+            Err(e) => failed("label", e),
+        }
+    }
+
+    async fn transition(&self, issue_key: &str, archive: &ArchiveConfig) -> ArchiveStep {
+        match self
+            .transition_tool
+            .transition_to_status(issue_key, &archive.status, archive.resolution.as_deref())
+            .await
+        {
+            Ok(Some(transition)) => succeeded(
+                "transition",
+                format!(
+                    "Transitioned via '{}' to '{}'",
+                    transition.name, transition.to_status
+                ),
+            ),
+            Ok(None) => skipped("transition", format!("Already in '{}'", archive.status)),
+            Err(e) => failed("transition", e),
+        }
+    }
+
+    async fn comment(
+        &self,
+        issue_key: &str,
+        reason: Option<&str>,
+        archive: &ArchiveConfig,
+    ) -> ArchiveStep {
+        let body = render_comment(&archive.comment_template, reason);
+        if body.is_empty() {
+            return skipped("comment", "No comment template configured");
+        }
+
+        match self
+            .jira_client
+            .add_comment(issue_key, body.into(), None, false)
+            .await
+        {
+            Ok(comment) => succeeded("comment", format!("Added comment {}", comment.id)),
+            Err(e) => failed("comment", e),
+        }
+    }
+
+    async fn remove_from_sprint(&self, issue_key: &str) -> ArchiveStep {
+        // Moving an issue to the backlog removes it from any sprint
+        let body = serde_json::json!({ "issues": [issue_key] });
+        match self
+            .jira_client
+            .post_json::<Value>(
+                &format!("moving {} to the backlog", issue_key),
+                "agile",
+                "/backlog/issue",
+                body,
+                JiraMcpError::from,
+            )
+            .await
+        {
+            Ok(_) => succeeded("remove_from_sprint", "Moved to the backlog"),
+            Err(e) => failed("remove_from_sprint", e),
+        }
+    }
+}
+
+/// Fill the closing comment template; the reason defaults to "no reason given"
+fn render_comment(template: &str, reason: Option<&str>) -> String {
+    let reason = reason
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .unwrap_or("no reason given");
+    template.replace("{reason}", reason).trim().to_string()
+}
+
+fn succeeded(step: &str, detail: impl Into<String>) -> ArchiveStep {
+    ArchiveStep {
+        step: step.to_string(),
+        status: ArchiveStepStatus::Succeeded,
+        detail: detail.into(),
+    }
+}
+
+fn skipped(step: &str, detail: impl Into<String>) -> ArchiveStep {
+    ArchiveStep {
+        step: step.to_string(),
+        status: ArchiveStepStatus::Skipped,
+        detail: detail.into(),
+    }
+}
+
+fn failed(step: &str, error: impl std::fmt::Display) -> ArchiveStep {
+    ArchiveStep {
+        step: step.to_string(),
+        status: ArchiveStepStatus::Failed,
+        detail: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_comment() {
+        assert_eq!(
+            render_comment("Archived: {reason}", Some(" duplicate of PROJ-1 ")),
+            "Archived: duplicate of PROJ-1"
+        );
+        assert_eq!(
+            render_comment("Archived: {reason}", None),
+            "Archived: no reason given"
+        );
+        assert_eq!(
+            render_comment("Closed by cleanup", Some("x")),
+            "Closed by cleanup"
+        );
+        assert_eq!(render_comment("  ", Some("x")), "");
+    }
+}
@@ -1,12 +1,15 @@
+use crate::cache::{MetadataCache, UserMapping};
+use crate::config::DeploymentType;
 use crate::error::{JiraMcpError, JiraMcpResult};
-use crate::jira_client::JiraClient;
+use crate::jira_client::{JiraClient, UserInfo};
+use crate::tools::issue_key::normalize_issue_id_or_key;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tracing::{info, instrument};
+use tracing::{info, instrument, warn};
 
 /// Parameters for assigning an issue
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct AssignIssueParams {
     /// The JIRA issue key (e.g., "PROJ-123")
     pub issue_key: String,
@@ -14,6 +17,8 @@ pub struct AssignIssueParams {
     /// The assignee to set. Can be:
     /// - "me" or "self" to assign to yourself
     /// - A username or account ID
+    /// - An email address or display name, resolved through a user search
+    ///   (an exact email match wins, then an exact display-name match)
     /// - null or empty string to unassign
     #[serde(default)]
     pub assignee: Option<String>,
@@ -28,45 +33,62 @@ pub struct AssignIssueResult {
     /// The new assignee (or "Unassigned" if cleared)
     pub assignee: String,
 
+    /// How the assignee parameter was resolved (absent when unassigning)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<ResolvedUser>,
+
     /// Success message
     pub message: String,
 }
 
+/// A user parameter (assignee, reporter) and the user it was resolved to
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResolvedUser {
+    /// The user as given
+    pub input: String,
+
+    /// Account ID (username on Server/Data Center) of the user
+    pub account_id: String,
+
+    /// Display name of the user
+    pub display_name: String,
+
+    /// How the user was found: "current_user", "account_id", "email",
+    /// "display_name" or "cache"
+    pub matched_by: String,
+}
+
 /// Tool for assigning JIRA issues
 pub struct AssignIssueTool {
     jira_client: Arc<JiraClient>,
+    cache: Arc<MetadataCache>,
 }
 
 impl AssignIssueTool {
-    pub fn new(jira_client: Arc<JiraClient>) -> Self {
-        Self { jira_client }
+    pub fn new(jira_client: Arc<JiraClient>, cache: Arc<MetadataCache>) -> Self {
+        Self { jira_client, cache }
     }
 
     #[instrument(skip(self))]
-    pub async fn execute(&self, params: AssignIssueParams) -> JiraMcpResult<AssignIssueResult> {
+    pub async fn execute(&self, mut params: AssignIssueParams) -> JiraMcpResult<AssignIssueResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
         info!(
             "Assigning issue {} to {:?}",
             params.issue_key, params.assignee
         );
 
-        // Normalize assignee value
-        let assignee_value = match params.assignee.as_deref() {
-            Some("me") | Some("self") => {
-                // Get current user's account ID
-                let user_info = self.jira_client.get_current_user().await?;
-                Some(user_info.account_id)
-            }
+        let resolved = match params.assignee.as_deref().map(str::trim) {
             Some("") | None => None, // Unassign
-            Some(assignee) => Some(assignee.to_string()),
+            Some(assignee) => Some(self.resolve_user(assignee, "assignee").await?),
         };
 
         // Build the update payload
-        let update_body = if let Some(assignee) = &assignee_value {
+        let update_body = if let Some(resolved) = &resolved {
             serde_json::json!({
                 "fields": {
-                    "assignee": {
-                        "accountId": assignee
-                    }
+                    "assignee": self.jira_client.deployment_type().user_field(&resolved.account_id)
                 }
             })
         } else {
@@ -81,25 +103,30 @@ impl AssignIssueTool {
         // Make the API call
         let endpoint = format!("/issue/{}", params.issue_key);
         self.jira_client
-            .client
-            .put::<(), _>("api", &endpoint, update_body)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
-                    JiraMcpError::not_found("issue", &params.issue_key)
-                } else if e.to_string().contains("400") || e.to_string().contains("Bad Request") {
-                    JiraMcpError::invalid_param("assignee", format!("Invalid assignee: {}", e))
-                } else {
-                    JiraMcpError::internal(format!("Failed to assign issue: {}", e))
-                }
-            })?;
+            .put_json::<serde_json::Value>(
+                &format!("assigning {}", params.issue_key),
+                "api",
+                &endpoint,
+                update_body,
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("issue", &params.issue_key)
+                    } else if e.to_string().contains("400") || e.to_string().contains("Bad Request")
+                    {
+                        JiraMcpError::invalid_param("assignee", format!("Invalid assignee: {}", e))
+                    } else {
+                        JiraMcpError::internal(format!("Failed to assign issue: {}", e))
+                    }
+                },
+            )
+            .await?;
 
-        let assignee_display = assignee_value
-            .as_deref()
-            .unwrap_or("Unassigned")
+        let assignee_display = resolved
+            .as_ref()
+            .map_or("Unassigned", |resolved| resolved.display_name.as_str())
             .to_string();
 
-        let message = if assignee_value.is_some() {
+        let message = if resolved.is_some() {
             format!(
                 "Successfully assigned {} to {}",
                 params.issue_key, assignee_display
@@ -113,7 +140,179 @@ impl AssignIssueTool {
         Ok(AssignIssueResult {
             issue_key: params.issue_key,
             assignee: assignee_display,
+            resolved,
             message,
         })
     }
+
+    /// Resolve "me", an account ID, an email or a display name to a single user
+    ///
+    /// `param` names the parameter in errors. Also used by set_reporter and
+    /// bulk_update_fields.
+    pub async fn resolve_user(&self, input: &str, param: &str) -> JiraMcpResult<ResolvedUser> {
+        let resolved = |user: UserInfo, matched_by: &str| ResolvedUser {
+            input: input.to_string(),
+            account_id: user.account_id,
+            display_name: user.display_name,
+            matched_by: matched_by.to_string(),
+        };
+
+        if input == "me" || input == "self" {
+            let user = self.jira_client.get_current_user().await?;
+            return Ok(resolved(user, "current_user"));
+        }
+
+        let deployment_type = self.jira_client.deployment_type();
+        if is_account_id(input, deployment_type) {
+            return Ok(ResolvedUser {
+                input: input.to_string(),
+                account_id: input.to_string(),
+                display_name: input.to_string(),
+                matched_by: "account_id".to_string(),
+            });
+        }
+
+        let cache_key = input.to_lowercase();
+        if let Some(mapping) = self.cache.get_user_mapping(&cache_key) {
+            return Ok(ResolvedUser {
+                input: input.to_string(),
+                account_id: mapping.account_id,
+                display_name: mapping.display_name,
+                matched_by: "cache".to_string(),
+            });
+        }
+
+        let users = self.jira_client.search_users(input).await?;
+
+        // Every user with an email is cached, so assigning them by email later
+        // needs no search
+        for user in &users {
+            if let Some(email) = &user.email_address {
+                self.cache_user(email.to_lowercase(), user, deployment_type);
+            }
+        }
+
+        let (user, matched_by) = pick_user(input, &users, deployment_type, param)?;
+        self.cache_user(cache_key, &user, deployment_type);
+        Ok(resolved(user, matched_by))
+    }
+
+    fn cache_user(&self, key: String, user: &UserInfo, deployment_type: DeploymentType) {
+        let mapping = UserMapping {
+            account_id: user.account_id.clone(),
+            display_name: user.display_name.clone(),
+            email_address: user.email_address.clone(),
+            username: (!deployment_type.is_cloud()).then(|| user.account_id.clone()),
+        };
+        if let Err(e) = self.cache.set_user_mapping(key.clone(), mapping) {
+            warn!("Failed to cache user '{}': {}", key, e);
+        }
+    }
+}
+
+/// Whether `input` is used as the user ID itself
+///
+/// Server and Data Center usernames are taken as-is unless they look like an
+/// email or a name (contain '@' or whitespace). Cloud account IDs are either 24
+/// alphanumeric characters or carry a ':' prefix (e.g. "557058:f58131cb-...").
+fn is_account_id(input: &str, deployment_type: DeploymentType) -> bool {
+    if input.contains('@') || input.contains(char::is_whitespace) {
+        return false;
+    }
+    !deployment_type.is_cloud()
+        || input.contains(':')
+        || (input.len() >= 24 && input.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Pick the user a search for `input` resolves to
+///
+/// An exact email match wins, then a single exact display-name match. Anything
+/// else is an error listing the candidates, so the caller can retry with an
+/// account ID.
+fn pick_user(
+    input: &str,
+    users: &[UserInfo],
+    deployment_type: DeploymentType,
+    param: &str,
+) -> JiraMcpResult<(UserInfo, &'static str)> {
+    let users: Vec<&UserInfo> = users.iter().filter(|user| user.active).collect();
+
+    let by_email = users.iter().find(|user| {
+        user.email_address
+            .as_deref()
+            .is_some_and(|email| email.eq_ignore_ascii_case(input))
+    });
+    if let Some(user) = by_email {
+        return Ok(((*user).clone(), "email"));
+    }
+
+    let by_name: Vec<&UserInfo> = users
+        .iter()
+        .copied()
+        .filter(|user| user.display_name.eq_ignore_ascii_case(input))
+        .collect();
+    if let [user] = by_name.as_slice() {
+        return Ok(((*user).clone(), "display_name"));
+    }
+
+    if users.is_empty() {
+        return Err(JiraMcpError::invalid_param(
+            param,
+            format!("No active user matches '{}'", input),
+        ));
+    }
+
+    let (problem, candidates) = if by_name.is_empty() {
+        ("No exact email or display-name match", users)
+    } else {
+        ("Several users share this display name", by_name)
+    };
+    let id_label = if deployment_type.is_cloud() {
+        "accountId"
+    } else {
+        "username"
+    };
+    let candidates = candidates
+        .iter()
+        .map(|user| {
+            format!(
+                "{} <{}> ({}: {})",
+                user.display_name,
+                user.email_address.as_deref().unwrap_or("no email"),
+                id_label,
+                user.account_id
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+    Err(JiraMcpError::invalid_param(
+        param,
+        format!(
+            "{} for '{}'. Candidates: {}. Retry with one of these {}s",
+            problem, input, candidates, id_label
+        ),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_account_id() {
+        let cloud = DeploymentType::Cloud;
+        assert!(is_account_id("5b10a2844c20165700ede21g", cloud));
+        assert!(is_account_id(
+            "557058:f58131cb-b67d-43c7-b30d-6b58d40bd077",
+            cloud
+        ));
+        assert!(!is_account_id("jdoe", cloud));
+        assert!(!is_account_id("Jane Doe", cloud));
+        assert!(!is_account_id("jane.doe@example.com", cloud));
+
+        let server = DeploymentType::Server;
+        assert!(is_account_id("jdoe", server));
+        assert!(!is_account_id("Jane Doe", server));
+        assert!(!is_account_id("jane.doe@example.com", server));
+    }
 }
@@ -0,0 +1,74 @@
+//! Audit log tool
+//!
+//! Reads back the audit trail of mutating tool calls written by `crate::audit`.
+
+use crate::audit::{AuditEntry, AuditLog};
+use crate::config::JiraConfig;
+use crate::error::JiraMcpResult;
+use crate::tools::issue_key::normalize_issue_key;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+const DEFAULT_LIMIT: usize = 50;
+const MAX_LIMIT: usize = 500;
+
+/// Parameters for the get_audit_log tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetAuditLogParams {
+    /// Maximum entries to return, newest first (optional, default: 50, max: 500)
+    pub limit: Option<usize>,
+
+    /// Only entries affecting this issue (optional)
+    /// Examples: "PROJ-123"
+    pub issue_key: Option<String>,
+
+    /// Only entries for this tool (optional)
+    /// Examples: "transition_issue", "bulk_update_fields"
+    pub tool_name: Option<String>,
+}
+
+/// Result from the get_audit_log tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct GetAuditLogResult {
+    /// Matching entries, newest first
+    pub entries: Vec<AuditEntry>,
+
+    /// Path of the active audit log file
+    pub log_path: String,
+}
+
+/// Tool for reading the audit log
+pub struct GetAuditLogTool {
+    audit_log: Arc<AuditLog>,
+    config: Arc<JiraConfig>,
+}
+
+impl GetAuditLogTool {
+    pub fn new(audit_log: Arc<AuditLog>, config: Arc<JiraConfig>) -> Self {
+        Self { audit_log, config }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, params: GetAuditLogParams) -> JiraMcpResult<GetAuditLogResult> {
+        let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let issue_key = params
+            .issue_key
+            .as_deref()
+            .map(|key| normalize_issue_key(&self.config.jira_url, key, "issue_key"))
+            .transpose()?;
+
+        let entries =
+            self.audit_log
+                .recent(limit, issue_key.as_deref(), params.tool_name.as_deref())?;
+
+        info!("Returning {} audit log entries", entries.len());
+
+        Ok(GetAuditLogResult {
+            entries,
+            log_path: self.audit_log.path().display().to_string(),
+        })
+    }
+}
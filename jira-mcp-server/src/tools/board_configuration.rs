@@ -0,0 +1,498 @@
+//! Board configuration and the issues in a board column
+//!
+//! get_board_configuration reads a board's columns with the statuses mapped to
+//! them, what the board estimates in and the JQL of its saved filter, and
+//! caches the result per board (the "boards" cache category). The board only
+//! reports status IDs; names come from the status catalog.
+//! get_board_column_issues answers "what's in the Review column": the board's
+//! filter narrowed to the statuses of one column, in rank order.
+
+use crate::cache::{BoardColumn, BoardColumnStatus, BoardConfiguration, MetadataCache, StatusInfo};
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::{BoardInfo, DetectedField, IssueInfo, JiraClient};
+use crate::jql;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+const DEFAULT_COLUMN_LIMIT: u32 = 50;
+const MAX_COLUMN_LIMIT: u32 = 200;
+
+/// Parameters for the get_board_configuration tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetBoardConfigurationParams {
+    /// Board ID (optional if board_name or project_key is given)
+    pub board_id: Option<u64>,
+
+    /// Board name, exact or partial (optional)
+    /// Examples: "Team Board"
+    pub board_name: Option<String>,
+
+    /// Project whose first board is used (optional)
+    /// Examples: "PROJ"
+    pub project_key: Option<String>,
+}
+
+/// Result from the get_board_configuration tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetBoardConfigurationResult {
+    #[serde(flatten)]
+    pub configuration: BoardConfiguration,
+
+    /// Fields the server reads story points from; see detected_fields in
+    /// get_server_status
+    pub story_points_fields: Vec<String>,
+
+    /// Whether the configuration came from the cache
+    pub cached: bool,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Parameters for the get_board_column_issues tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetBoardColumnIssuesParams {
+    /// Board ID (optional if board_name or project_key is given)
+    pub board_id: Option<u64>,
+
+    /// Board name, exact or partial (optional)
+    pub board_name: Option<String>,
+
+    /// Project whose first board is used (optional)
+    pub project_key: Option<String>,
+
+    /// Column name, case-insensitive
+    /// Examples: "Review", "In Progress"
+    pub column: String,
+
+    /// Issues to return (optional, default: 50, max: 200)
+    pub limit: Option<u32>,
+}
+
+/// Result from the get_board_column_issues tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetBoardColumnIssuesResult {
+    pub board_id: u64,
+    pub board_name: String,
+
+    /// Column name as configured on the board
+    pub column: String,
+
+    /// Statuses mapped to the column
+    pub statuses: Vec<BoardColumnStatus>,
+
+    /// Query that selected the issues
+    pub jql: String,
+
+    /// Issues in the column, in rank order
+    pub issues: Vec<IssueInfo>,
+
+    /// Issues in the column in total, when JIRA reports it
+    pub total: Option<usize>,
+
+    /// Whether more issues are in the column than were returned
+    pub has_more: bool,
+}
+
+/// Implementation of the get_board_configuration tool
+pub struct GetBoardConfigurationTool {
+    jira_client: Arc<JiraClient>,
+    cache: Arc<MetadataCache>,
+}
+
+impl GetBoardConfigurationTool {
+    pub fn new(jira_client: Arc<JiraClient>, cache: Arc<MetadataCache>) -> Self {
+        Self { jira_client, cache }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: GetBoardConfigurationParams,
+    ) -> JiraMcpResult<GetBoardConfigurationResult> {
+        let board = self
+            .resolve_board(
+                params.board_id,
+                params.board_name.as_deref(),
+                params.project_key.as_deref(),
+            )
+            .await?;
+        let (configuration, cached) = self.configuration(board.id).await?;
+
+        let story_points = &self.jira_client.field_map().story_points;
+        let mut warnings = Vec::new();
+        if configuration.filter_id.is_some() && configuration.filter_jql.is_none() {
+            warnings.push(
+                "The board's saved filter could not be read; it may not be shared with you"
+                    .to_string(),
+            );
+        }
+        warnings.extend(estimation_warning(&configuration, story_points));
+
+        info!(
+            "Board {} has {} columns{}",
+            configuration.board_id,
+            configuration.columns.len(),
+            if cached { " (cached)" } else { "" }
+        );
+
+        Ok(GetBoardConfigurationResult {
+            configuration,
+            story_points_fields: story_points.field_ids.clone(),
+            cached,
+            warnings,
+        })
+    }
+
+    /// Board by ID, by name, or the first board of a project, in that order
+    pub async fn resolve_board(
+        &self,
+        board_id: Option<u64>,
+        board_name: Option<&str>,
+        project_key: Option<&str>,
+    ) -> JiraMcpResult<BoardInfo> {
+        let board_name = board_name.map(str::trim).filter(|name| !name.is_empty());
+        let project_key = project_key.map(str::trim).filter(|key| !key.is_empty());
+        match (board_id, board_name, project_key) {
+            (Some(board_id), _, _) => self.jira_client.get_board(board_id).await,
+            (None, Some(name), _) => self.jira_client.find_board_by_name(name).await,
+            (None, None, Some(project_key)) => {
+                self.jira_client
+                    .find_project_board(&project_key.to_uppercase())
+                    .await
+            }
+            (None, None, None) => Err(JiraMcpError::invalid_param(
+                "board_id",
+                "Provide board_id, board_name or project_key",
+            )),
+        }
+    }
+
+    /// A board's configuration with status names and filter JQL filled in,
+    /// and whether it came from the cache
+    pub async fn configuration(&self, board_id: u64) -> JiraMcpResult<(BoardConfiguration, bool)> {
+        if let Some(configuration) = self.cache.get_board_configuration(board_id) {
+            return Ok((configuration, true));
+        }
+
+        let mut configuration = self.jira_client.get_board_configuration(board_id).await?;
+        name_statuses(&mut configuration.columns, &self.status_catalog().await);
+        if let Some(filter_id) = &configuration.filter_id {
+            match self.jira_client.get_filter_jql(filter_id).await {
+                Ok(jql) => configuration.filter_jql = Some(jql),
+                Err(e) => warn!(
+                    "Could not read filter {} of board {}: {}",
+                    filter_id, board_id, e
+                ),
+            }
+        }
+
+        if let Err(e) = self.cache.set_board_configuration(configuration.clone()) {
+            warn!("Failed to cache configuration of board {}: {}", board_id, e);
+        }
+        Ok((configuration, false))
+    }
+
+    /// The instance-wide status catalog, from the cache when loaded; empty when
+    /// it cannot be read, which leaves the statuses unnamed
+    async fn status_catalog(&self) -> Vec<StatusInfo> {
+        if let Some(statuses) = self.cache.get_statuses(None) {
+            return statuses;
+        }
+        match self.jira_client.get_statuses(None).await {
+            Ok(statuses) => {
+                if let Err(e) = self.cache.set_statuses(None, statuses.clone()) {
+                    warn!("Failed to cache statuses: {}", e);
+                }
+                statuses
+            }
+            Err(e) => {
+                warn!("Could not load the status catalog: {}", e);
+                Vec::new()
+            }
+        }
+    }
+}
+
+/// Implementation of the get_board_column_issues tool
+pub struct GetBoardColumnIssuesTool {
+    jira_client: Arc<JiraClient>,
+    board_configuration_tool: Arc<GetBoardConfigurationTool>,
+}
+
+impl GetBoardColumnIssuesTool {
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        board_configuration_tool: Arc<GetBoardConfigurationTool>,
+    ) -> Self {
+        Self {
+            jira_client,
+            board_configuration_tool,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: GetBoardColumnIssuesParams,
+    ) -> JiraMcpResult<GetBoardColumnIssuesResult> {
+        let limit = params.limit.unwrap_or(DEFAULT_COLUMN_LIMIT);
+        if limit == 0 || limit > MAX_COLUMN_LIMIT {
+            return Err(JiraMcpError::invalid_param(
+                "limit",
+                format!("limit must be between 1 and {}", MAX_COLUMN_LIMIT),
+            ));
+        }
+
+        let board = self
+            .board_configuration_tool
+            .resolve_board(
+                params.board_id,
+                params.board_name.as_deref(),
+                params.project_key.as_deref(),
+            )
+            .await?;
+        let (configuration, _) = self
+            .board_configuration_tool
+            .configuration(board.id)
+            .await?;
+        let column = find_column(&configuration.columns, &params.column)?;
+        let jql = column_jql(&configuration, column)?;
+
+        let search = self
+            .jira_client
+            .search_issues_jql(&jql, None, Some(limit as usize), None)
+            .await?;
+
+        info!(
+            "Column '{}' of board {}: {} issues",
+            column.name,
+            configuration.board_id,
+            search.issues.len()
+        );
+
+        Ok(GetBoardColumnIssuesResult {
+            board_id: configuration.board_id,
+            board_name: configuration.board_name.clone(),
+            column: column.name.clone(),
+            statuses: column.statuses.clone(),
+            jql,
+            has_more: !search.is_last,
+            total: search.total,
+            issues: search.issues,
+        })
+    }
+}
+
+/// Fill in status names and categories from the catalog
+fn name_statuses(columns: &mut [BoardColumn], catalog: &[StatusInfo]) {
+    for status in columns.iter_mut().flat_map(|column| &mut column.statuses) {
+        if let Some(known) = catalog.iter().find(|known| known.id == status.id) {
+            status.name = Some(known.name.clone());
+            status.category_key = Some(known.category_key.clone());
+        }
+    }
+}
+
+/// Hint when the board estimates in a custom field other than the one story
+/// points are read from, unless the field was set in the config on purpose
+fn estimation_warning(
+    configuration: &BoardConfiguration,
+    story_points: &DetectedField,
+) -> Option<String> {
+    let estimation = configuration.estimation.as_ref()?;
+    let field_id = estimation
+        .field_id
+        .as_deref()
+        .filter(|id| id.starts_with("customfield_"))?;
+    if story_points.source == "config" || story_points.field_ids.iter().any(|id| id == field_id) {
+        return None;
+    }
+    Some(format!(
+        "The board estimates in {} ({}), but story points are read from {}; set \
+         field_mappings.story_points = \"{}\" to use the board's field",
+        estimation.field_name.as_deref().unwrap_or("a custom field"),
+        field_id,
+        if story_points.field_ids.is_empty() {
+            "no field".to_string()
+        } else {
+            story_points.field_ids.join(", ")
+        },
+        field_id
+    ))
+}
+
+/// Column by name, ignoring case
+fn find_column<'a>(columns: &'a [BoardColumn], name: &str) -> JiraMcpResult<&'a BoardColumn> {
+    columns
+        .iter()
+        .find(|column| column.name.eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| {
+            JiraMcpError::invalid_param(
+                "column",
+                format!(
+                    "The board has no column '{}'. Columns: {}",
+                    name,
+                    columns
+                        .iter()
+                        .map(|column| column.name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            )
+        })
+}
+
+/// The board's filter narrowed to a column's statuses, in rank order
+///
+/// Statuses are matched by ID, which stays the same when a status is renamed
+/// or localized; a Kanban sub-filter is applied as on the board.
+fn column_jql(configuration: &BoardConfiguration, column: &BoardColumn) -> JiraMcpResult<String> {
+    let filter_id = configuration
+        .filter_id
+        .as_deref()
+        .filter(|id| id.parse::<u64>().is_ok())
+        .ok_or_else(|| JiraMcpError::internal("The board has no saved filter"))?;
+    let status_ids: Vec<&str> = column
+        .statuses
+        .iter()
+        .map(|status| status.id.as_str())
+        .filter(|id| id.parse::<u64>().is_ok())
+        .collect();
+    if status_ids.is_empty() {
+        return Err(JiraMcpError::invalid_param(
+            "column",
+            format!("No statuses are mapped to the '{}' column", column.name),
+        ));
+    }
+
+    let mut query = format!(
+        "{} = {} AND {} IN ({})",
+        jql::FILTER,
+        filter_id,
+        jql::STATUS,
+        status_ids.join(", ")
+    );
+    if let Some(sub_query) = &configuration.sub_query {
+        query.push_str(&format!(" AND ({})", sub_query));
+    }
+    query.push_str(&format!(" ORDER BY {} ASC", jql::RANK));
+    Ok(query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::BoardEstimation;
+
+    fn status(id: &str) -> BoardColumnStatus {
+        BoardColumnStatus {
+            id: id.to_string(),
+            name: None,
+            category_key: None,
+        }
+    }
+
+    fn configuration() -> BoardConfiguration {
+        BoardConfiguration {
+            board_id: 42,
+            board_name: "Team board".to_string(),
+            filter_id: Some("10100".to_string()),
+            filter_jql: Some("project = PROJ ORDER BY Rank ASC".to_string()),
+            sub_query: None,
+            columns: vec![
+                BoardColumn {
+                    name: "To Do".to_string(),
+                    statuses: vec![status("1")],
+                    min: None,
+                    max: None,
+                },
+                BoardColumn {
+                    name: "Review".to_string(),
+                    statuses: vec![status("10010"), status("10011")],
+                    min: None,
+                    max: Some(3),
+                },
+                BoardColumn {
+                    name: "Parked".to_string(),
+                    statuses: Vec::new(),
+                    min: None,
+                    max: None,
+                },
+            ],
+            estimation: Some(BoardEstimation {
+                estimation_type: "field".to_string(),
+                field_id: Some("customfield_10002".to_string()),
+                field_name: Some("Story Points".to_string()),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_column_jql() {
+        let mut configuration = configuration();
+        let review = find_column(&configuration.columns, " review ").unwrap();
+        assert_eq!(
+            column_jql(&configuration, review).unwrap(),
+            "filter = 10100 AND status IN (10010, 10011) ORDER BY Rank ASC"
+        );
+
+        configuration.sub_query = Some("fixVersion is EMPTY".to_string());
+        let to_do = find_column(&configuration.columns, "To Do").unwrap();
+        assert_eq!(
+            column_jql(&configuration, to_do).unwrap(),
+            "filter = 10100 AND status IN (1) AND (fixVersion is EMPTY) ORDER BY Rank ASC"
+        );
+
+        let parked = find_column(&configuration.columns, "parked").unwrap();
+        assert!(column_jql(&configuration, parked).is_err());
+
+        let err = find_column(&configuration.columns, "Done").unwrap_err();
+        assert!(err.to_string().contains("To Do, Review, Parked"));
+    }
+
+    #[test]
+    fn test_name_statuses() {
+        let mut configuration = configuration();
+        let catalog = vec![StatusInfo {
+            id: "10010".to_string(),
+            name: "In Review".to_string(),
+            category_key: "indeterminate".to_string(),
+            category_name: "In Progress".to_string(),
+        }];
+        name_statuses(&mut configuration.columns, &catalog);
+
+        let review = &configuration.columns[1].statuses;
+        assert_eq!(review[0].name.as_deref(), Some("In Review"));
+        assert_eq!(review[0].category_key.as_deref(), Some("indeterminate"));
+        assert!(review[1].name.is_none());
+    }
+
+    #[test]
+    fn test_estimation_warning() {
+        let configuration = configuration();
+        let detected = |field_ids: &[&str], source: &str| DetectedField {
+            field_ids: field_ids.iter().map(|id| id.to_string()).collect(),
+            source: source.to_string(),
+        };
+
+        assert!(estimation_warning(
+            &configuration,
+            &detected(&["customfield_10002"], "field_catalog")
+        )
+        .is_none());
+        assert!(
+            estimation_warning(&configuration, &detected(&["customfield_10016"], "config"))
+                .is_none()
+        );
+
+        let warning =
+            estimation_warning(&configuration, &detected(&["customfield_10016"], "default"))
+                .unwrap();
+        assert!(warning.contains("field_mappings.story_points = \"customfield_10002\""));
+    }
+}
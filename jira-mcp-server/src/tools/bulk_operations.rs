@@ -5,7 +5,13 @@
 
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::JiraClient;
-use crate::tools::{CreateIssueParams, CreateIssueResult};
+use crate::messages::message;
+use crate::metrics::{self, impl_measured, ToolPerformance};
+use crate::tools::issue_key::normalize_issue_keys;
+use crate::tools::labels::find_near_duplicate_labels;
+use crate::tools::{
+    AssignIssueTool, CreateIssueParams, CreateIssueResult, CreateIssueTool, LabelsTool,
+};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -25,7 +31,7 @@ const MAX_RETRY_DELAY_MS: u64 = 30000; // 30 seconds
 // =============================================================================
 
 /// Parameters for bulk creating issues
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct BulkCreateIssuesParams {
     /// Project key where issues will be created
     pub project_key: String,
@@ -83,7 +89,11 @@ pub struct BulkCreateIssuesResult {
     /// Total execution time in milliseconds
     pub execution_time_ms: u64,
 
-    /// Summary message
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
+
+    /// Summary message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
 }
 
@@ -92,7 +102,7 @@ pub struct BulkCreateIssuesResult {
 // =============================================================================
 
 /// Parameters for bulk transitioning issues
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct BulkTransitionIssuesParams {
     /// List of issue keys to transition
     pub issue_keys: Vec<String>,
@@ -163,7 +173,11 @@ pub struct BulkTransitionIssuesResult {
     /// Total execution time in milliseconds
     pub execution_time_ms: u64,
 
-    /// Summary message
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
+
+    /// Summary message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
 }
 
@@ -172,12 +186,16 @@ pub struct BulkTransitionIssuesResult {
 // =============================================================================
 
 /// Parameters for bulk updating fields
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct BulkUpdateFieldsParams {
     /// List of issue keys to update
     pub issue_keys: Vec<String>,
 
     /// Field updates to apply to all issues (field_id -> value)
+    ///
+    /// `reporter` may be given as a plain string ("me", an account ID, an email or
+    /// a display name); it is resolved to a user once, the same way set_reporter
+    /// resolves it.
     pub field_updates: HashMap<String, serde_json::Value>,
 
     /// Whether to stop on first error (default: false)
@@ -226,7 +244,11 @@ pub struct BulkUpdateFieldsResult {
     /// Total execution time in milliseconds
     pub execution_time_ms: u64,
 
-    /// Summary message
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
+
+    /// Summary message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
 }
 
@@ -235,7 +257,7 @@ pub struct BulkUpdateFieldsResult {
 // =============================================================================
 
 /// Parameters for bulk assigning issues
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct BulkAssignIssuesParams {
     /// List of issue keys to assign
     pub issue_keys: Vec<String>,
@@ -275,10 +297,14 @@ pub struct BulkAssignIssuesResult {
     /// Total execution time in milliseconds
     pub execution_time_ms: u64,
 
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
+
     /// Assignee that was set
     pub assignee: String,
 
-    /// Summary message
+    /// Summary message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
 }
 
@@ -287,7 +313,7 @@ pub struct BulkAssignIssuesResult {
 // =============================================================================
 
 /// Parameters for bulk adding/removing labels
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct BulkAddLabelsParams {
     /// List of issue keys to update
     pub issue_keys: Vec<String>,
@@ -300,6 +326,16 @@ pub struct BulkAddLabelsParams {
     #[serde(default)]
     pub remove_labels: Vec<String>,
 
+    /// Allow adding labels that don't exist anywhere yet (default: true)
+    /// JIRA creates labels implicitly on first use; set false to only allow existing labels
+    #[serde(default)]
+    pub create_if_missing: Option<bool>,
+
+    /// Warn when an added label is a near-duplicate of an existing one (default: false)
+    /// Catches case differences, `_` vs `-`, and a trailing "s" (e.g. "Backends" vs "backend")
+    #[serde(default)]
+    pub validate_labels: bool,
+
     /// Whether to stop on first error (default: false)
     #[serde(default)]
     pub stop_on_error: bool,
@@ -332,10 +368,26 @@ pub struct BulkAddLabelsResult {
     /// Total execution time in milliseconds
     pub execution_time_ms: u64,
 
-    /// Summary message
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
+
+    /// Summary message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
+
+    /// Added labels that closely match an existing label
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub label_warnings: Vec<String>,
 }
 
+impl_measured!(
+    BulkCreateIssuesResult,
+    BulkTransitionIssuesResult,
+    BulkUpdateFieldsResult,
+    BulkAssignIssuesResult,
+    BulkAddLabelsResult,
+);
+
 // =============================================================================
 // Tool Implementations
 // =============================================================================
@@ -343,11 +395,24 @@ pub struct BulkAddLabelsResult {
 /// Tool for bulk operations on JIRA issues
 pub struct BulkOperationsTool {
     jira_client: Arc<JiraClient>,
+    labels_tool: Arc<LabelsTool>,
+    create_issue_tool: Arc<CreateIssueTool>,
+    assign_issue_tool: Arc<AssignIssueTool>,
 }
 
 impl BulkOperationsTool {
-    pub fn new(jira_client: Arc<JiraClient>) -> Self {
-        Self { jira_client }
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        labels_tool: Arc<LabelsTool>,
+        create_issue_tool: Arc<CreateIssueTool>,
+        assign_issue_tool: Arc<AssignIssueTool>,
+    ) -> Self {
+        Self {
+            jira_client,
+            labels_tool,
+            create_issue_tool,
+            assign_issue_tool,
+        }
     }
 
     /// Get the effective concurrency limit
@@ -404,8 +469,9 @@ impl BulkOperationsTool {
                 Err(e) => {
                     // Check if error is retryable
                     let error_str = e.to_string();
-                    let is_rate_limit =
-                        error_str.contains("429") || error_str.contains("rate limit");
+                    let is_rate_limit = matches!(e, JiraMcpError::RateLimit { .. })
+                        || error_str.contains("429")
+                        || error_str.contains("rate limit");
                     let is_timeout =
                         error_str.contains("timeout") || error_str.contains("timed out");
                     let is_server_error = error_str.contains("500")
@@ -432,6 +498,7 @@ impl BulkOperationsTool {
                     );
 
                     // Wait before retrying
+                    metrics::record_retry();
                     tokio::time::sleep(tokio::time::Duration::from_millis(delay_ms)).await;
 
                     // Exponential backoff: double the delay, but cap at MAX_RETRY_DELAY_MS
@@ -486,21 +553,23 @@ impl BulkOperationsTool {
             if issue_params.project_key.is_none() {
                 issue_params.project_key = Some(params.project_key.clone());
             }
+            // Reading every created issue back would double the calls
+            issue_params.return_full_issue.get_or_insert(false);
 
-            let client = Arc::clone(&self.jira_client);
+            let create_issue_tool = Arc::clone(&self.create_issue_tool);
             let stop_on_error = params.stop_on_error;
             let retry_config = (max_retries, initial_retry_delay_ms);
 
-            join_set.spawn(async move {
+            join_set.spawn(metrics::in_current_scope(async move {
                 let result = Self::create_single_issue_with_retry(
-                    client,
+                    create_issue_tool,
                     issue_params,
                     retry_config.0,
                     retry_config.1,
                 )
                 .await;
                 (index, result)
-            });
+            }));
 
             pending_count += 1;
 
@@ -584,26 +653,25 @@ impl BulkOperationsTool {
             success_count,
             failure_count,
             execution_time_ms: execution_time,
-            message: format!(
-                "Bulk created {}/{} issues successfully ({} failed)",
-                success_count, total_issues, failure_count
+            performance: ToolPerformance::default(),
+            message: message!(
+                self.jira_client.messages(),
+                "bulk.created",
+                succeeded = success_count,
+                total = total_issues,
+                failed = failure_count,
             ),
         })
     }
 
     async fn create_single_issue_with_retry(
-        client: Arc<JiraClient>,
+        create_issue_tool: Arc<CreateIssueTool>,
         params: CreateIssueParams,
         max_retries: usize,
         initial_delay_ms: u64,
     ) -> JiraMcpResult<CreateIssueResult> {
-        use crate::tools::CreateIssueTool;
-
         Self::retry_with_backoff(
-            || async {
-                let tool = CreateIssueTool::new(Arc::clone(&client));
-                tool.execute(params.clone()).await
-            },
+            || async { create_issue_tool.execute(params.clone()).await },
             max_retries,
             initial_delay_ms,
             &format!("create_issue({})", params.summary),
@@ -618,8 +686,14 @@ impl BulkOperationsTool {
     #[instrument(skip(self))]
     pub async fn bulk_transition_issues(
         &self,
-        params: BulkTransitionIssuesParams,
+        mut params: BulkTransitionIssuesParams,
     ) -> JiraMcpResult<BulkTransitionIssuesResult> {
+        params.issue_keys = normalize_issue_keys(
+            self.jira_client.base_url(),
+            &params.issue_keys,
+            "issue_keys",
+        )?;
+
         let start_time = std::time::Instant::now();
         let total_issues = params.issue_keys.len();
 
@@ -658,7 +732,7 @@ impl BulkOperationsTool {
             let resolution = params.resolution.clone();
             let retry_config = (max_retries, initial_retry_delay_ms);
 
-            join_set.spawn(async move {
+            join_set.spawn(metrics::in_current_scope(async move {
                 let result = Self::transition_single_issue_with_retry(
                     client,
                     issue_key.clone(),
@@ -671,7 +745,7 @@ impl BulkOperationsTool {
                 )
                 .await;
                 (issue_key, result)
-            });
+            }));
 
             pending_count += 1;
 
@@ -751,9 +825,13 @@ impl BulkOperationsTool {
             success_count,
             failure_count,
             execution_time_ms: execution_time,
-            message: format!(
-                "Bulk transitioned {}/{} issues successfully ({} failed)",
-                success_count, total_issues, failure_count
+            performance: ToolPerformance::default(),
+            message: message!(
+                self.jira_client.messages(),
+                "bulk.transitioned",
+                succeeded = success_count,
+                total = total_issues,
+                failed = failure_count,
             ),
         })
     }
@@ -777,6 +855,10 @@ impl BulkOperationsTool {
             transition_name,
             comment,
             resolution,
+            track_time: None,
+            time_spent_hours: None,
+            time_spent_minutes: None,
+            time_spent_seconds: None,
         };
 
         Self::retry_with_backoff(
@@ -799,8 +881,14 @@ impl BulkOperationsTool {
     #[instrument(skip(self))]
     pub async fn bulk_update_fields(
         &self,
-        params: BulkUpdateFieldsParams,
+        mut params: BulkUpdateFieldsParams,
     ) -> JiraMcpResult<BulkUpdateFieldsResult> {
+        params.issue_keys = normalize_issue_keys(
+            self.jira_client.base_url(),
+            &params.issue_keys,
+            "issue_keys",
+        )?;
+
         let start_time = std::time::Instant::now();
         let total_issues = params.issue_keys.len();
 
@@ -820,6 +908,21 @@ impl BulkOperationsTool {
             ));
         }
 
+        // A reporter given by name or email is resolved once for all issues
+        let mut field_updates = params.field_updates.clone();
+        if let Some(reporter) = field_updates.get("reporter").and_then(|v| v.as_str()) {
+            let resolved = self
+                .assign_issue_tool
+                .resolve_user(reporter, "field_updates.reporter")
+                .await?;
+            field_updates.insert(
+                "reporter".to_string(),
+                self.jira_client
+                    .deployment_type()
+                    .user_field(&resolved.account_id),
+            );
+        }
+
         let concurrency_limit = self.get_concurrency_limit(params.max_concurrent);
         let (max_retries, initial_retry_delay_ms) =
             self.get_retry_config(params.max_retries, params.initial_retry_delay_ms);
@@ -833,10 +936,10 @@ impl BulkOperationsTool {
         for issue_key in params.issue_keys.iter() {
             let client = Arc::clone(&self.jira_client);
             let issue_key = issue_key.clone();
-            let field_updates = params.field_updates.clone();
+            let field_updates = field_updates.clone();
             let retry_config = (max_retries, initial_retry_delay_ms);
 
-            join_set.spawn(async move {
+            join_set.spawn(metrics::in_current_scope(async move {
                 let result = Self::update_single_issue_fields_with_retry(
                     client,
                     issue_key.clone(),
@@ -846,7 +949,7 @@ impl BulkOperationsTool {
                 )
                 .await;
                 (issue_key, result)
-            });
+            }));
 
             pending_count += 1;
 
@@ -924,9 +1027,13 @@ impl BulkOperationsTool {
             success_count,
             failure_count,
             execution_time_ms: execution_time,
-            message: format!(
-                "Bulk updated {}/{} issues successfully ({} failed)",
-                success_count, total_issues, failure_count
+            performance: ToolPerformance::default(),
+            message: message!(
+                self.jira_client.messages(),
+                "bulk.updated",
+                succeeded = success_count,
+                total = total_issues,
+                failed = failure_count,
             ),
         })
     }
@@ -946,16 +1053,20 @@ impl BulkOperationsTool {
 
                 let endpoint = format!("/issue/{}", issue_key);
                 client
-                    .client
-                    .put::<(), _>("api", &endpoint, update_body)
-                    .await
-                    .map_err(|e| {
-                        if e.to_string().contains("404") {
-                            JiraMcpError::not_found("issue", &issue_key)
-                        } else {
-                            JiraMcpError::internal(format!("Failed to update issue: {}", e))
-                        }
-                    })?;
+                    .put_json::<serde_json::Value>(
+                        &format!("updating {}", issue_key),
+                        "api",
+                        &endpoint,
+                        update_body,
+                        |e| {
+                            if e.to_string().contains("404") {
+                                JiraMcpError::not_found("issue", &issue_key)
+                            } else {
+                                JiraMcpError::internal(format!("Failed to update issue: {}", e))
+                            }
+                        },
+                    )
+                    .await?;
 
                 Ok(())
             },
@@ -973,8 +1084,14 @@ impl BulkOperationsTool {
     #[instrument(skip(self))]
     pub async fn bulk_assign_issues(
         &self,
-        params: BulkAssignIssuesParams,
+        mut params: BulkAssignIssuesParams,
     ) -> JiraMcpResult<BulkAssignIssuesResult> {
+        params.issue_keys = normalize_issue_keys(
+            self.jira_client.base_url(),
+            &params.issue_keys,
+            "issue_keys",
+        )?;
+
         let start_time = std::time::Instant::now();
         let total_issues = params.issue_keys.len();
 
@@ -1018,7 +1135,7 @@ impl BulkOperationsTool {
             let assignee = assignee_value.clone();
             let retry_config = (max_retries, initial_retry_delay_ms);
 
-            join_set.spawn(async move {
+            join_set.spawn(metrics::in_current_scope(async move {
                 let result = Self::assign_single_issue_with_retry(
                     client,
                     issue_key.clone(),
@@ -1028,7 +1145,7 @@ impl BulkOperationsTool {
                 )
                 .await;
                 (issue_key, result)
-            });
+            }));
 
             pending_count += 1;
 
@@ -1106,10 +1223,14 @@ impl BulkOperationsTool {
             success_count,
             failure_count,
             execution_time_ms: execution_time,
+            performance: ToolPerformance::default(),
             assignee: assignee_display,
-            message: format!(
-                "Bulk assigned {}/{} issues successfully ({} failed)",
-                success_count, total_issues, failure_count
+            message: message!(
+                self.jira_client.messages(),
+                "bulk.assigned",
+                succeeded = success_count,
+                total = total_issues,
+                failed = failure_count,
             ),
         })
     }
@@ -1126,9 +1247,7 @@ impl BulkOperationsTool {
                 let update_body = if let Some(assignee_id) = &assignee {
                     serde_json::json!({
                         "fields": {
-                            "assignee": {
-                                "accountId": assignee_id
-                            }
+                            "assignee": client.deployment_type().user_field(assignee_id)
                         }
                     })
                 } else {
@@ -1141,16 +1260,20 @@ impl BulkOperationsTool {
 
                 let endpoint = format!("/issue/{}", issue_key);
                 client
-                    .client
-                    .put::<(), _>("api", &endpoint, update_body)
-                    .await
-                    .map_err(|e| {
-                        if e.to_string().contains("404") {
-                            JiraMcpError::not_found("issue", &issue_key)
-                        } else {
-                            JiraMcpError::internal(format!("Failed to assign issue: {}", e))
-                        }
-                    })?;
+                    .put_json::<serde_json::Value>(
+                        &format!("updating {}", issue_key),
+                        "api",
+                        &endpoint,
+                        update_body,
+                        |e| {
+                            if e.to_string().contains("404") {
+                                JiraMcpError::not_found("issue", &issue_key)
+                            } else {
+                                JiraMcpError::internal(format!("Failed to assign issue: {}", e))
+                            }
+                        },
+                    )
+                    .await?;
 
                 Ok(())
             },
@@ -1168,8 +1291,14 @@ impl BulkOperationsTool {
     #[instrument(skip(self))]
     pub async fn bulk_add_labels(
         &self,
-        params: BulkAddLabelsParams,
+        mut params: BulkAddLabelsParams,
     ) -> JiraMcpResult<BulkAddLabelsResult> {
+        params.issue_keys = normalize_issue_keys(
+            self.jira_client.base_url(),
+            &params.issue_keys,
+            "issue_keys",
+        )?;
+
         let start_time = std::time::Instant::now();
         let total_issues = params.issue_keys.len();
 
@@ -1189,6 +1318,40 @@ impl BulkOperationsTool {
             ));
         }
 
+        let create_if_missing = params.create_if_missing.unwrap_or(true);
+        let label_warnings = if !params.add_labels.is_empty()
+            && (params.validate_labels || !create_if_missing)
+        {
+            let existing = self.labels_tool.known_labels(false).await?;
+            let warnings = find_near_duplicate_labels(&params.add_labels, &existing);
+
+            if !create_if_missing {
+                let missing: Vec<&str> = params
+                    .add_labels
+                    .iter()
+                    .filter(|label| !existing.contains(label))
+                    .map(|label| label.as_str())
+                    .collect();
+                if !missing.is_empty() {
+                    let mut message = format!(
+                        "Labels do not exist yet: {}. Set create_if_missing to true to create them.",
+                        missing.join(", ")
+                    );
+                    if !warnings.is_empty() {
+                        message.push_str(&format!(" Did you mean: {}", warnings.join("; ")));
+                    }
+                    return Err(JiraMcpError::invalid_param("add_labels", message));
+                }
+            }
+
+            for warning in &warnings {
+                warn!("{}", warning);
+            }
+            warnings
+        } else {
+            Vec::new()
+        };
+
         let concurrency_limit = self.get_concurrency_limit(params.max_concurrent);
         let (max_retries, initial_retry_delay_ms) =
             self.get_retry_config(params.max_retries, params.initial_retry_delay_ms);
@@ -1206,7 +1369,7 @@ impl BulkOperationsTool {
             let remove_labels = params.remove_labels.clone();
             let retry_config = (max_retries, initial_retry_delay_ms);
 
-            join_set.spawn(async move {
+            join_set.spawn(metrics::in_current_scope(async move {
                 let result = Self::update_single_issue_labels_with_retry(
                     client,
                     issue_key.clone(),
@@ -1217,7 +1380,7 @@ impl BulkOperationsTool {
                 )
                 .await;
                 (issue_key, result)
-            });
+            }));
 
             pending_count += 1;
 
@@ -1297,10 +1460,15 @@ impl BulkOperationsTool {
             success_count,
             failure_count,
             execution_time_ms: execution_time,
-            message: format!(
-                "Bulk updated labels on {}/{} issues successfully ({} failed)",
-                success_count, total_issues, failure_count
+            performance: ToolPerformance::default(),
+            message: message!(
+                self.jira_client.messages(),
+                "bulk.labels_updated",
+                succeeded = success_count,
+                total = total_issues,
+                failed = failure_count,
             ),
+            label_warnings,
         })
     }
 
@@ -1332,16 +1500,20 @@ impl BulkOperationsTool {
 
                 let endpoint = format!("/issue/{}", issue_key);
                 client
-                    .client
-                    .put::<(), _>("api", &endpoint, update_body)
-                    .await
-                    .map_err(|e| {
-                        if e.to_string().contains("404") {
-                            JiraMcpError::not_found("issue", &issue_key)
-                        } else {
-                            JiraMcpError::internal(format!("Failed to update labels: {}", e))
-                        }
-                    })?;
+                    .put_json::<serde_json::Value>(
+                        &format!("updating {}", issue_key),
+                        "api",
+                        &endpoint,
+                        update_body,
+                        |e| {
+                            if e.to_string().contains("404") {
+                                JiraMcpError::not_found("issue", &issue_key)
+                            } else {
+                                JiraMcpError::internal(format!("Failed to update labels: {}", e))
+                            }
+                        },
+                    )
+                    .await?;
 
                 Ok(())
             },
@@ -0,0 +1,389 @@
+//! Issue quality gate
+//!
+//! Evaluates the `[quality_gate]` rules from the configuration against an issue
+//! (description, acceptance criteria, todos, fix version, story points, blockers
+//! and custom field checks) and reports pass/fail per rule with a hint on how to
+//! fix each failure, in one deterministic call.
+
+use crate::config::{CustomQualityRule, JiraConfig, QualityGateConfig};
+use crate::error::JiraMcpResult;
+use crate::jira_client::{IssueDetails, JiraClient};
+use crate::tools::issue_key::normalize_issue_id_or_key;
+use crate::tools::todo_tracker::TodoTracker;
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// Parameters for the check_issue_quality tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CheckIssueQualityParams {
+    /// JIRA issue key (required)
+    /// Examples: "PROJ-123"
+    pub issue_key: String,
+}
+
+/// Outcome of a single quality rule
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct QualityRuleResult {
+    /// Rule name, e.g. "min_description_length"
+    pub rule: String,
+
+    pub passed: bool,
+
+    /// What was found
+    pub detail: String,
+
+    /// How to fix a failure
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
+/// Result from the check_issue_quality tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CheckIssueQualityResult {
+    pub issue_key: String,
+
+    /// Whether every enabled rule passed
+    pub passed: bool,
+
+    pub passed_count: usize,
+    pub failed_count: usize,
+
+    /// Per-rule results, in evaluation order
+    pub rules: Vec<QualityRuleResult>,
+}
+
+/// Implementation of the check_issue_quality tool
+pub struct CheckIssueQualityTool {
+    jira_client: Arc<JiraClient>,
+    config: Arc<JiraConfig>,
+}
+
+impl CheckIssueQualityTool {
+    pub fn new(jira_client: Arc<JiraClient>, config: Arc<JiraConfig>) -> Self {
+        Self {
+            jira_client,
+            config,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: CheckIssueQualityParams,
+    ) -> JiraMcpResult<CheckIssueQualityResult> {
+        let issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        let gate = &self.config.quality_gate;
+        let details = self
+            .jira_client
+            .get_issue_details(&issue_key, false, false, false)
+            .await?;
+
+        // Raw values for the fix version and custom rules
+        let mut field_ids: Vec<String> = gate
+            .custom_rules
+            .iter()
+            .map(|rule| rule.field.clone())
+            .collect();
+        if gate.require_fix_version {
+            field_ids.push("fixVersions".to_string());
+        }
+        let fields = if field_ids.is_empty() {
+            serde_json::Map::new()
+        } else {
+            self.jira_client
+                .get_issue_fields(&issue_key, &field_ids)
+                .await?
+        };
+
+        let mut rules = evaluate_static_rules(gate, &details, &fields);
+        if gate.require_no_open_blockers {
+            rules.push(self.check_blockers(&details).await);
+        }
+        rules.extend(
+            gate.custom_rules
+                .iter()
+                .map(|rule| evaluate_custom_rule(rule, fields.get(&rule.field))),
+        );
+
+        let passed_count = rules.iter().filter(|r| r.passed).count();
+        let failed_count = rules.len() - passed_count;
+        info!(
+            "Quality gate for {}: {} passed, {} failed",
+            issue_key, passed_count, failed_count
+        );
+
+        Ok(CheckIssueQualityResult {
+            issue_key,
+            passed: failed_count == 0,
+            passed_count,
+            failed_count,
+            rules,
+        })
+    }
+
+    /// Fails while any issue blocking this one is not in a done status category
+    async fn check_blockers(&self, details: &IssueDetails) -> QualityRuleResult {
+        let blocker_keys: Vec<String> = details
+            .linked_issues
+            .iter()
+            .filter(|link| link.link_type.eq_ignore_ascii_case("is blocked by"))
+            .map(|link| link.key.clone())
+            .collect();
+        if blocker_keys.is_empty() {
+            return rule_result("no_open_blockers", true, "No blocking issues", None);
+        }
+
+        match self
+            .jira_client
+            .get_issues_details(&blocker_keys, false, false)
+            .await
+        {
+            Ok(blockers) => {
+                let open: Vec<String> = blockers
+                    .iter()
+                    .filter(|b| !b.issue_info.is_done())
+                    .map(|b| format!("{} ({})", b.issue_info.key, b.issue_info.status))
+                    .collect();
+                if open.is_empty() {
+                    rule_result(
+                        "no_open_blockers",
+                        true,
+                        format!("All {} blocking issues are done", blocker_keys.len()),
+                        None,
+                    )
+                } else {
+                    rule_result(
+                        "no_open_blockers",
+                        false,
+                        format!("Blocked by {}", open.join(", ")),
+                        Some("Resolve the blocking issues or remove the links if they no longer apply"),
+                    )
+                }
+            }
+            Err(e) => {
+                warn!("Could not check blockers: {}", e);
+                rule_result(
+                    "no_open_blockers",
+                    false,
+                    format!("Could not check blocking issues: {}", e),
+                    Some("Retry, or check the blocking issues manually"),
+                )
+            }
+        }
+    }
+}
+
+fn rule_result(
+    rule: &str,
+    passed: bool,
+    detail: impl Into<String>,
+    hint: Option<&str>,
+) -> QualityRuleResult {
+    QualityRuleResult {
+        rule: rule.to_string(),
+        passed,
+        detail: detail.into(),
+        hint: if passed { None } else { hint.map(String::from) },
+    }
+}
+
+/// Rules decided from the issue alone, skipping those the config disables
+fn evaluate_static_rules(
+    gate: &QualityGateConfig,
+    details: &IssueDetails,
+    fields: &serde_json::Map<String, Value>,
+) -> Vec<QualityRuleResult> {
+    let issue = &details.issue_info;
+    let description = issue.description.as_deref().unwrap_or("").trim();
+    let checkboxes: Vec<bool> = description
+        .lines()
+        .filter(|line| TodoTracker::parse_checkbox_line(line.trim()).is_some())
+        .map(|line| line.contains("[x]") || line.contains("[X]"))
+        .collect();
+
+    let mut rules = Vec::new();
+
+    if gate.min_description_length > 0 {
+        let length = description.chars().count();
+        rules.push(rule_result(
+            "min_description_length",
+            length >= gate.min_description_length,
+            format!(
+                "Description has {} characters (minimum {})",
+                length, gate.min_description_length
+            ),
+            Some("Describe the problem, context and expected outcome in the description"),
+        ));
+    }
+
+    if gate.require_acceptance_criteria {
+        let has_field = issue
+            .acceptance_criteria
+            .as_deref()
+            .is_some_and(|ac| !ac.trim().is_empty());
+        let detail = if has_field {
+            "Acceptance criteria field is set".to_string()
+        } else if !checkboxes.is_empty() {
+            format!("Description has {} checkbox items", checkboxes.len())
+        } else {
+            "No acceptance criteria field and no checkbox section".to_string()
+        };
+        rules.push(rule_result(
+            "acceptance_criteria",
+            has_field || !checkboxes.is_empty(),
+            detail,
+            Some("Fill in the acceptance criteria field or add a '- [ ] ...' checklist to the description"),
+        ));
+    }
+
+    if gate.require_todos_complete {
+        let open = checkboxes.iter().filter(|done| !**done).count();
+        rules.push(rule_result(
+            "todos_complete",
+            open == 0,
+            format!("{} of {} todos open", open, checkboxes.len()),
+            Some("Complete the open todos (complete_todo_work) or remove the ones that no longer apply"),
+        ));
+    }
+
+    if gate.require_fix_version {
+        let versions = field_text(fields.get("fixVersions"));
+        rules.push(rule_result(
+            "fix_version",
+            versions.is_some(),
+            match &versions {
+                Some(versions) => format!("Fix version: {}", versions),
+                None => "No fix version set".to_string(),
+            },
+            Some("Set the fix version the change ships in"),
+        ));
+    }
+
+    if gate.require_story_points {
+        rules.push(rule_result(
+            "story_points",
+            issue.story_points.is_some(),
+            match issue.story_points {
+                Some(points) => format!("{} story points", points),
+                None => "No story points set".to_string(),
+            },
+            Some("Estimate the issue and set its story points"),
+        ));
+    }
+
+    rules
+}
+
+/// Check a custom rule against the field's raw value
+fn evaluate_custom_rule(rule: &CustomQualityRule, value: Option<&Value>) -> QualityRuleResult {
+    let hint = rule.hint.as_deref();
+    let Some(text) = field_text(value) else {
+        return rule_result(
+            &rule.name,
+            false,
+            format!("{} is not set", rule.field),
+            hint.or(Some("Set the field")),
+        );
+    };
+
+    let Some(pattern) = &rule.pattern else {
+        return rule_result(&rule.name, true, format!("{} is set", rule.field), None);
+    };
+
+    match Regex::new(pattern) {
+        Ok(regex) if regex.is_match(&text) => rule_result(
+            &rule.name,
+            true,
+            format!("{} matches {}", rule.field, pattern),
+            None,
+        ),
+        Ok(_) => rule_result(
+            &rule.name,
+            false,
+            format!("{} '{}' does not match {}", rule.field, text, pattern),
+            hint,
+        ),
+        Err(e) => rule_result(
+            &rule.name,
+            false,
+            format!("Invalid pattern {}: {}", pattern, e),
+            Some("Fix the rule's pattern in the [quality_gate] configuration"),
+        ),
+    }
+}
+
+/// Text of a raw field value: strings as-is, named objects by name, lists joined;
+/// `None` for unset or empty fields
+fn field_text(value: Option<&Value>) -> Option<String> {
+    let text = match value? {
+        Value::Null => return None,
+        Value::String(s) => s.clone(),
+        Value::Array(items) => items
+            .iter()
+            .filter_map(|item| field_text(Some(item)))
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Object(object) => ["name", "value", "displayName", "key"]
+            .iter()
+            .find_map(|k| object.get(*k).and_then(|v| v.as_str()))
+            .map(String::from)
+            .unwrap_or_else(|| Value::Object(object.clone()).to_string()),
+        other => other.to_string(),
+    };
+    (!text.trim().is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_field_text() {
+        assert_eq!(field_text(None), None);
+        assert_eq!(field_text(Some(&json!(null))), None);
+        assert_eq!(field_text(Some(&json!([]))), None);
+        assert_eq!(
+            field_text(Some(&json!([{"name": "1.2"}, {"name": "1.3"}]))),
+            Some("1.2, 1.3".to_string())
+        );
+        assert_eq!(
+            field_text(Some(&json!({"value": "High"}))),
+            Some("High".to_string())
+        );
+        assert_eq!(field_text(Some(&json!(5))), Some("5".to_string()));
+    }
+
+    #[test]
+    fn test_custom_rule() {
+        let rule = CustomQualityRule {
+            name: "release_train".to_string(),
+            field: "customfield_10020".to_string(),
+            pattern: Some("^RT-\\d+$".to_string()),
+            hint: Some("Pick the release train".to_string()),
+        };
+
+        let passed = evaluate_custom_rule(&rule, Some(&json!("RT-12")));
+        assert!(passed.passed);
+        assert!(passed.hint.is_none());
+
+        let failed = evaluate_custom_rule(&rule, Some(&json!("later")));
+        assert!(!failed.passed);
+        assert_eq!(failed.hint.as_deref(), Some("Pick the release train"));
+
+        assert!(!evaluate_custom_rule(&rule, None).passed);
+
+        let presence = CustomQualityRule {
+            pattern: None,
+            ..rule
+        };
+        assert!(evaluate_custom_rule(&presence, Some(&json!([{"name": "Checkout"}]))).passed);
+    }
+}
@@ -0,0 +1,99 @@
+//! Targeted cache invalidation
+//!
+//! Unlike clear_cache, only the given categories and/or keys are dropped, so the
+//! current user and unrelated metadata don't have to be resolved again.
+
+use crate::cache::{CacheCategory, MetadataCache};
+use crate::error::{JiraMcpError, JiraMcpResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// Parameters for the clear_cache_scope tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ClearCacheScopeParams {
+    /// Categories to clear (optional, default: all categories)
+    /// Values: "users", "projects", "fields", "boards", "issues", "link_types",
+    /// "permissions"
+    #[serde(default)]
+    pub categories: Vec<CacheCategory>,
+
+    /// Only drop entries cached under these keys (optional, case-insensitive)
+    /// Examples: ["PROJ"] for a project's issue types and statuses, ["PROJ-123"]
+    #[serde(default)]
+    pub keys: Vec<String>,
+}
+
+/// Result from the clear_cache_scope tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ClearCacheScopeResult {
+    /// Number of cache entries removed
+    pub removed: usize,
+
+    /// Categories that were cleared
+    pub categories: Vec<CacheCategory>,
+
+    /// Keys the invalidation was limited to (empty: whole categories)
+    pub keys: Vec<String>,
+
+    /// Human-readable summary
+    pub message: String,
+}
+
+/// Tool for invalidating parts of the metadata cache
+pub struct ClearCacheScopeTool {
+    cache: Arc<MetadataCache>,
+}
+
+impl ClearCacheScopeTool {
+    pub fn new(cache: Arc<MetadataCache>) -> Self {
+        Self { cache }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: ClearCacheScopeParams,
+    ) -> JiraMcpResult<ClearCacheScopeResult> {
+        let keys: Vec<String> = params
+            .keys
+            .iter()
+            .map(|k| k.trim().to_string())
+            .filter(|k| !k.is_empty())
+            .collect();
+        if keys.is_empty() && params.categories.is_empty() {
+            return Err(JiraMcpError::invalid_param(
+                "categories or keys",
+                "Specify categories and/or keys to invalidate; use clear_cache to clear everything",
+            ));
+        }
+
+        let categories = if params.categories.is_empty() {
+            CacheCategory::ALL.to_vec()
+        } else {
+            params.categories
+        };
+
+        let removed = self.cache.invalidate(&categories, &keys);
+        info!("Invalidated {} cache entries", removed);
+
+        let scope = if keys.is_empty() {
+            String::new()
+        } else {
+            format!(" for {}", keys.join(", "))
+        };
+        Ok(ClearCacheScopeResult {
+            removed,
+            message: format!(
+                "Removed {} cached entr{}{}",
+                removed,
+                if removed == 1 { "y" } else { "ies" },
+                scope
+            ),
+            categories,
+            keys,
+        })
+    }
+}
@@ -1,5 +1,7 @@
+use crate::config::OperationClass;
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_id_or_key;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -7,7 +9,7 @@ use std::sync::Arc;
 use tracing::{info, instrument};
 
 /// Parameters for updating issue components
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct UpdateComponentsParams {
     /// The JIRA issue key (e.g., "PROJ-123")
     pub issue_key: String,
@@ -77,8 +79,11 @@ impl ComponentsTool {
     #[instrument(skip(self))]
     pub async fn update_components(
         &self,
-        params: UpdateComponentsParams,
+        mut params: UpdateComponentsParams,
     ) -> JiraMcpResult<UpdateComponentsResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
         info!(
             "Updating components for issue {}: {:?}",
             params.issue_key, params.components
@@ -112,29 +117,37 @@ impl ComponentsTool {
         // Make the API call
         let endpoint = format!("/issue/{}", params.issue_key);
         self.jira_client
-            .client
-            .put::<(), _>("api", &endpoint, update_body)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
-                    JiraMcpError::not_found("issue", &params.issue_key)
-                } else if e.to_string().contains("component") {
-                    JiraMcpError::invalid_param("components", format!("Invalid component: {}", e))
-                } else {
-                    JiraMcpError::internal(format!("Failed to update components: {}", e))
-                }
-            })?;
+            .put_json::<Value>(
+                &format!("updating the components of {}", params.issue_key),
+                "api",
+                &endpoint,
+                update_body,
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("issue", &params.issue_key)
+                    } else if e.to_string().contains("component") {
+                        JiraMcpError::invalid_param(
+                            "components",
+                            format!("Invalid component: {}", e),
+                        )
+                    } else {
+                        JiraMcpError::internal(format!("Failed to update components: {}", e))
+                    }
+                },
+            )
+            .await?;
 
         // Fetch the issue to get current components
         let issue: Value = self
             .jira_client
-            .client
-            .get(
+            .get_json(
+                OperationClass::Read,
+                &format!("reading the components of {}", params.issue_key),
                 "api",
                 &format!("/issue/{}?fields=components", params.issue_key),
+                |e| JiraMcpError::internal(format!("Failed to fetch updated issue: {}", e)),
             )
-            .await
-            .map_err(|e| JiraMcpError::internal(format!("Failed to fetch updated issue: {}", e)))?;
+            .await?;
 
         let components: Vec<ComponentInfo> = issue["fields"]["components"]
             .as_array()
@@ -188,16 +201,20 @@ impl ComponentsTool {
 
         let response: Value = self
             .jira_client
-            .client
-            .get("api", &endpoint)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
-                    JiraMcpError::not_found("project", &params.project_key)
-                } else {
-                    JiraMcpError::internal(format!("Failed to get components: {}", e))
-                }
-            })?;
+            .get_json(
+                OperationClass::Read,
+                &format!("listing the components of {}", params.project_key),
+                "api",
+                &endpoint,
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("project", &params.project_key)
+                    } else {
+                        JiraMcpError::internal(format!("Failed to get components: {}", e))
+                    }
+                },
+            )
+            .await?;
 
         let components: Vec<ComponentInfo> = response
             .as_array()
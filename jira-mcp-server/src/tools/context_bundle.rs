@@ -0,0 +1,608 @@
+//! Issue context bundle for feeding a model
+//!
+//! Collects what a model needs to reason about an issue in one call instead of
+//! four or five: the description, the latest comments, the todo list, the
+//! issues blocking it and the text of small text attachments. The sections are
+//! filled in that order until `max_chars` is spent; a section that does not fit
+//! is cut at an item boundary (or inside its first item) and ends with a
+//! marker saying what was left out, and sections after it are dropped. The
+//! same issue and budget always yield the same bundle.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::{AttachmentInfo, CommentInfo, IssueDetails, JiraClient};
+use crate::tools::download_attachment::{DownloadAttachmentParams, DownloadAttachmentTool};
+use crate::tools::issue_key::normalize_issue_key;
+use crate::tools::response_budget::MIN_RESPONSE_BUDGET;
+use crate::tools::todo_tracker::{ListTodosParams, TodoItem, TodoStatus, TodoTracker};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+const DEFAULT_MAX_CHARS: usize = 12_000;
+const DEFAULT_COMMENT_COUNT: usize = 5;
+const MAX_COMMENT_COUNT: usize = 50;
+const DEFAULT_MAX_ATTACHMENT_BYTES: u64 = 20_000;
+
+/// Text attachments read per bundle
+const MAX_ATTACHMENTS: usize = 5;
+
+/// Room kept for the marker that ends a cut section
+const MARKER_RESERVE: usize = 100;
+
+/// An item is only cut when at least this much of it fits; otherwise it is
+/// left out whole
+const MIN_CUT_CHARS: usize = 200;
+
+/// File extensions read as text when JIRA reports a generic mime type
+const TEXT_EXTENSIONS: &[&str] = &[
+    "txt", "log", "md", "csv", "json", "xml", "yaml", "yml", "toml", "ini", "sql", "diff", "patch",
+];
+
+/// Parameters for the get_issue_context_bundle tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetIssueContextBundleParams {
+    /// JIRA issue key (required)
+    /// Examples: "PROJ-123"
+    pub issue_key: String,
+
+    /// Most characters of the bundle text (optional, default: 12000, min: 1000)
+    pub max_chars: Option<usize>,
+
+    /// Latest comments to include (optional, default: 5, max: 50)
+    pub comment_count: Option<usize>,
+
+    /// Largest attachment, in bytes, whose text is included (optional, default: 20000)
+    /// Only text attachments (text/*, JSON, XML, YAML, logs) are read; 0 skips them
+    pub max_attachment_bytes: Option<u64>,
+}
+
+/// What went into one section of the bundle
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BundleSection {
+    /// "description", "comments", "todos", "blockers" or "attachments"
+    pub name: String,
+
+    /// Items the issue has for this section
+    pub items: usize,
+
+    /// Items in the bundle, including one that was cut
+    pub included: usize,
+
+    /// Whether anything of the section was cut or left out
+    pub truncated: bool,
+}
+
+/// Result from the get_issue_context_bundle tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetIssueContextBundleResult {
+    pub issue_key: String,
+    pub summary: String,
+    pub status: String,
+
+    /// Markdown with one heading per section, in priority order
+    pub bundle: String,
+
+    /// Characters of `bundle`, at most max_chars
+    pub bundle_chars: usize,
+
+    pub max_chars: usize,
+
+    /// Per section, what was included
+    pub sections: Vec<BundleSection>,
+
+    /// Whether any section was cut or left out for the budget
+    pub truncated: bool,
+
+    /// Parts that could not be read, e.g. an attachment download that failed
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// One section's text before it is fitted to the budget
+#[derive(Debug, Clone)]
+struct SectionInput {
+    name: &'static str,
+    title: &'static str,
+    /// Plural noun of the items, for the marker
+    noun: &'static str,
+    /// Text between items
+    separator: &'static str,
+    /// In the order they should survive the budget
+    items: Vec<String>,
+}
+
+/// Implementation of the get_issue_context_bundle tool
+pub struct GetIssueContextBundleTool {
+    jira_client: Arc<JiraClient>,
+    todo_tracker: Arc<TodoTracker>,
+    download_attachment_tool: Arc<DownloadAttachmentTool>,
+}
+
+impl GetIssueContextBundleTool {
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        todo_tracker: Arc<TodoTracker>,
+        download_attachment_tool: Arc<DownloadAttachmentTool>,
+    ) -> Self {
+        Self {
+            jira_client,
+            todo_tracker,
+            download_attachment_tool,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: GetIssueContextBundleParams,
+    ) -> JiraMcpResult<GetIssueContextBundleResult> {
+        let issue_key =
+            normalize_issue_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+        let max_chars = params.max_chars.unwrap_or(DEFAULT_MAX_CHARS);
+        if max_chars < MIN_RESPONSE_BUDGET {
+            return Err(JiraMcpError::invalid_param(
+                "max_chars",
+                format!("max_chars must be at least {}", MIN_RESPONSE_BUDGET),
+            ));
+        }
+        let comment_count = params
+            .comment_count
+            .unwrap_or(DEFAULT_COMMENT_COUNT)
+            .min(MAX_COMMENT_COUNT);
+        let max_attachment_bytes = params
+            .max_attachment_bytes
+            .unwrap_or(DEFAULT_MAX_ATTACHMENT_BYTES);
+
+        let details = self
+            .jira_client
+            .get_issue_details(
+                &issue_key,
+                comment_count > 0,
+                max_attachment_bytes > 0,
+                false,
+            )
+            .await?;
+
+        let mut warnings = Vec::new();
+        let todos = match self
+            .todo_tracker
+            .list_todos(ListTodosParams {
+                issue_key: Some(issue_key.clone()),
+                status_filter: None,
+                format: Default::default(),
+            })
+            .await
+        {
+            Ok(result) => result.todos,
+            Err(e) => {
+                warn!("Could not read todos of {}: {}", issue_key, e);
+                warnings.push(format!("todos: {}", e));
+                Vec::new()
+            }
+        };
+
+        let mut attachment_texts = Vec::new();
+        let candidates = text_attachments(
+            details.attachments.as_deref().unwrap_or_default(),
+            max_attachment_bytes,
+        );
+        for attachment in candidates.into_iter().take(MAX_ATTACHMENTS) {
+            let download = self
+                .download_attachment_tool
+                .execute(DownloadAttachmentParams {
+                    attachment_id: Some(attachment.id.clone()),
+                    content_url: None,
+                    base64_encoded: Some(false),
+                    max_size_bytes: Some(max_attachment_bytes),
+                    save_to_path: None,
+                    return_content: Some(true),
+                })
+                .await;
+            match download {
+                Ok(result) => attachment_texts.push(format!(
+                    "{} ({} bytes):\n{}",
+                    attachment.filename,
+                    attachment.size,
+                    result.content.unwrap_or_default()
+                )),
+                Err(e) => {
+                    warn!("Could not read attachment {}: {}", attachment.filename, e);
+                    warnings.push(format!("{}: {}", attachment.filename, e));
+                }
+            }
+        }
+
+        let header = format!(
+            "# {}: {}\n{} · {} · {}\n\n",
+            details.issue_info.key,
+            details.issue_info.summary,
+            details.issue_info.issue_type,
+            details.issue_info.status,
+            details
+                .issue_info
+                .assignee
+                .as_deref()
+                .unwrap_or("Unassigned")
+        );
+        let inputs = section_inputs(&details, comment_count, &todos, attachment_texts);
+        let (bundle, sections) = assemble(&header, inputs, max_chars);
+        let truncated = sections.iter().any(|section| section.truncated);
+        let bundle_chars = bundle.chars().count();
+
+        info!(
+            "Context bundle of {}: {} of {} chars{}",
+            issue_key,
+            bundle_chars,
+            max_chars,
+            if truncated { ", truncated" } else { "" }
+        );
+
+        Ok(GetIssueContextBundleResult {
+            issue_key,
+            summary: details.issue_info.summary,
+            status: details.issue_info.status,
+            bundle,
+            bundle_chars,
+            max_chars,
+            sections,
+            truncated,
+            warnings,
+        })
+    }
+}
+
+/// Text attachments no larger than `max_bytes`, smallest first
+fn text_attachments(attachments: &[AttachmentInfo], max_bytes: u64) -> Vec<&AttachmentInfo> {
+    let mut texts: Vec<&AttachmentInfo> = attachments
+        .iter()
+        .filter(|attachment| attachment.size > 0 && attachment.size <= max_bytes)
+        .filter(|attachment| is_text(attachment))
+        .collect();
+    texts.sort_by(|a, b| a.size.cmp(&b.size).then_with(|| a.id.cmp(&b.id)));
+    texts
+}
+
+fn is_text(attachment: &AttachmentInfo) -> bool {
+    let mime = attachment.mime_type.to_ascii_lowercase();
+    if mime.starts_with("text/")
+        || ["json", "xml", "yaml", "x-sh"]
+            .iter()
+            .any(|kind| mime.ends_with(kind))
+    {
+        return true;
+    }
+    attachment
+        .filename
+        .rsplit_once('.')
+        .is_some_and(|(_, extension)| {
+            TEXT_EXTENSIONS.contains(&extension.to_ascii_lowercase().as_str())
+        })
+}
+
+/// The sections in priority order, with the newest comments first
+fn section_inputs(
+    details: &IssueDetails,
+    comment_count: usize,
+    todos: &[TodoItem],
+    attachment_texts: Vec<String>,
+) -> Vec<SectionInput> {
+    let description = details
+        .issue_info
+        .description
+        .as_deref()
+        .map(str::trim)
+        .filter(|description| !description.is_empty());
+    let comments: Vec<&CommentInfo> = details
+        .comments
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .rev()
+        .take(comment_count)
+        .collect();
+
+    vec![
+        SectionInput {
+            name: "description",
+            title: "Description",
+            noun: "parts",
+            separator: "\n\n",
+            items: description.map(str::to_string).into_iter().collect(),
+        },
+        SectionInput {
+            name: "comments",
+            title: "Comments (newest first)",
+            noun: "older comments",
+            separator: "\n\n",
+            items: comments
+                .iter()
+                .map(|comment| {
+                    format!(
+                        "{} ({}):\n{}",
+                        comment.author,
+                        comment.created,
+                        comment.body.trim()
+                    )
+                })
+                .collect(),
+        },
+        SectionInput {
+            name: "todos",
+            title: "Todos",
+            noun: "todos",
+            separator: "\n",
+            items: todos
+                .iter()
+                .map(|todo| {
+                    let mark = match todo.status {
+                        TodoStatus::Completed => "x",
+                        TodoStatus::Wip => "~",
+                        TodoStatus::Open => " ",
+                    };
+                    format!("- [{}] {}", mark, todo.text)
+                })
+                .collect(),
+        },
+        SectionInput {
+            name: "blockers",
+            title: "Blocked by",
+            noun: "blockers",
+            separator: "\n",
+            items: details
+                .linked_issues
+                .iter()
+                .filter(|link| link.link_type.eq_ignore_ascii_case("is blocked by"))
+                .map(|link| format!("- {} ({}): {}", link.key, link.status, link.summary))
+                .collect(),
+        },
+        SectionInput {
+            name: "attachments",
+            title: "Attachments",
+            noun: "attachments",
+            separator: "\n\n",
+            items: attachment_texts,
+        },
+    ]
+}
+
+/// Fill the sections in order into at most `max_chars` characters
+///
+/// Items are added whole while they fit and leave room for a marker. The first
+/// one that does not is cut to what is left (or left out when that is under
+/// `MIN_CUT_CHARS`), a marker names what was dropped, and later sections are
+/// left out unless they fit whole.
+fn assemble(
+    header: &str,
+    sections: Vec<SectionInput>,
+    max_chars: usize,
+) -> (String, Vec<BundleSection>) {
+    let mut bundle = truncate_chars(header, max_chars).to_string();
+    let mut remaining = max_chars - bundle.chars().count();
+    let mut summaries = Vec::new();
+
+    for section in sections {
+        let mut summary = BundleSection {
+            name: section.name.to_string(),
+            items: section.items.len(),
+            included: 0,
+            truncated: false,
+        };
+        if section.items.is_empty() {
+            summaries.push(summary);
+            continue;
+        }
+
+        let heading = format!("## {}\n", section.title);
+        let separator_chars = section.separator.chars().count();
+        let item_chars: Vec<usize> = section
+            .items
+            .iter()
+            .map(|item| item.chars().count())
+            .collect();
+        let whole_chars = heading.chars().count()
+            + item_chars
+                .iter()
+                .map(|chars| chars + separator_chars)
+                .sum::<usize>()
+            + 1;
+        // Enough room to cut the first item rather than leave it out
+        let start_chars =
+            heading.chars().count() + 1 + MARKER_RESERVE + separator_chars + MIN_CUT_CHARS;
+        let fits_whole = whole_chars <= remaining;
+        if !fits_whole && start_chars > remaining {
+            summary.truncated = true;
+            summaries.push(summary);
+            continue;
+        }
+
+        let mut text = heading;
+        let mut left = remaining - text.chars().count() - 1;
+        for (index, item) in section.items.iter().enumerate() {
+            let last = index + 1 == section.items.len();
+            let needed = item_chars[index] + separator_chars;
+            let reserve = if last || fits_whole {
+                0
+            } else {
+                MARKER_RESERVE
+            };
+            if needed + reserve <= left {
+                text.push_str(item);
+                text.push_str(section.separator);
+                left -= needed;
+                summary.included += 1;
+                continue;
+            }
+
+            // Cut this item, or leave it out when too little of it fits
+            summary.truncated = true;
+            let mut omitted = section.items.len() - index - 1;
+            let keep = left.saturating_sub(MARKER_RESERVE + separator_chars);
+            let cut_chars = if keep >= MIN_CUT_CHARS {
+                text.push_str(truncate_chars(item, keep));
+                text.push('\n');
+                summary.included += 1;
+                item_chars[index] - keep
+            } else {
+                omitted += 1;
+                0
+            };
+            text.push_str(&marker(cut_chars, omitted, section.noun));
+            text.push_str(section.separator);
+            break;
+        }
+        text.push('\n');
+
+        remaining -= text.chars().count();
+        bundle.push_str(&text);
+        summaries.push(summary);
+    }
+
+    (bundle.trim_end().to_string(), summaries)
+}
+
+/// What a cut section left out, e.g. "[truncated: 1200 chars cut, 3 more
+/// older comments omitted]"
+fn marker(cut_chars: usize, omitted: usize, noun: &str) -> String {
+    match (cut_chars, omitted) {
+        (0, omitted) => format!("[truncated: {} more {} omitted]", omitted, noun),
+        (cut, 0) => format!("[truncated: {} chars cut]", cut),
+        (cut, omitted) => format!(
+            "[truncated: {} chars cut, {} more {} omitted]",
+            cut, omitted, noun
+        ),
+    }
+}
+
+/// The first `max_chars` characters of `text`
+fn truncate_chars(text: &str, max_chars: usize) -> &str {
+    match text.char_indices().nth(max_chars) {
+        Some((index, _)) => &text[..index],
+        None => text,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(name: &'static str, items: Vec<String>) -> SectionInput {
+        SectionInput {
+            name,
+            title: name,
+            noun: "items",
+            separator: "\n\n",
+            items,
+        }
+    }
+
+    fn oversized_issue() -> Vec<SectionInput> {
+        vec![
+            section("description", vec!["d".repeat(3000)]),
+            section(
+                "comments",
+                (0..10)
+                    .map(|i| format!("comment {}: {}", i, "c".repeat(400)))
+                    .collect(),
+            ),
+            section(
+                "todos",
+                vec!["- [ ] one".to_string(), "- [x] two".to_string()],
+            ),
+            section("blockers", vec![]),
+            section("attachments", vec!["a".repeat(5000)]),
+        ]
+    }
+
+    #[test]
+    fn test_everything_fits_untouched() {
+        let (bundle, sections) = assemble("# PROJ-1: Title\n\n", oversized_issue(), 100_000);
+        assert!(bundle.starts_with("# PROJ-1: Title\n\n## description\n"));
+        assert!(!bundle.contains("[truncated"));
+        assert!(sections.iter().all(|section| !section.truncated));
+        assert_eq!(sections[1].included, 10);
+        assert_eq!(sections[3].items, 0);
+    }
+
+    #[test]
+    fn test_oversized_issue_is_cut_in_priority_order() {
+        for max_chars in [1_000, 2_500, 4_000, 6_000, 9_000] {
+            let (bundle, sections) = assemble("# PROJ-1\n\n", oversized_issue(), max_chars);
+            assert!(
+                bundle.chars().count() <= max_chars,
+                "{} chars over a budget of {}",
+                bundle.chars().count(),
+                max_chars
+            );
+            // Deterministic
+            assert_eq!(
+                assemble("# PROJ-1\n\n", oversized_issue(), max_chars).0,
+                bundle
+            );
+            // A later section never survives a cut earlier one, except whole
+            let first_cut = sections.iter().position(|section| section.truncated);
+            if let Some(first_cut) = first_cut {
+                for later in &sections[first_cut + 1..] {
+                    assert!(later.included == 0 || later.included == later.items);
+                }
+            }
+        }
+
+        // The description is cut inside, with a marker
+        let (bundle, sections) = assemble("# PROJ-1\n\n", oversized_issue(), 1_000);
+        assert!(bundle.contains("chars cut]"), "{}", bundle);
+        assert_eq!(sections[0].included, 1);
+        assert!(sections[0].truncated);
+        assert_eq!(sections[1].included, 0);
+        assert!(sections[1].truncated);
+        // The todos still fit whole after the comments were dropped
+        assert_eq!(sections[2].included, 2);
+
+        // Room for the description and some comments: the oldest are dropped
+        let (bundle, sections) = assemble("# PROJ-1\n\n", oversized_issue(), 4_000);
+        assert!(!sections[0].truncated);
+        assert!(sections[1].included > 0 && sections[1].included < 10);
+        assert!(bundle.contains("more items omitted]"), "{}", bundle);
+        assert!(bundle.contains("comment 0:"));
+        assert!(!bundle.contains("comment 9:"));
+        assert!(sections[4].truncated);
+    }
+
+    #[test]
+    fn test_multibyte_text_is_cut_on_char_boundaries() {
+        let sections = vec![section("description", vec!["ü".repeat(5000)])];
+        let (bundle, summaries) = assemble("", sections, 1_000);
+        assert!(bundle.chars().count() <= 1_000);
+        assert!(summaries[0].truncated);
+        assert_eq!(truncate_chars("äöü", 2), "äö");
+    }
+
+    #[test]
+    fn test_text_attachments() {
+        let attachment = |id: &str, filename: &str, mime_type: &str, size| AttachmentInfo {
+            id: id.to_string(),
+            filename: filename.to_string(),
+            author: "Me".to_string(),
+            created: String::new(),
+            size,
+            mime_type: mime_type.to_string(),
+            content_url: String::new(),
+            thumbnail_url: None,
+            author_account_id: None,
+        };
+        let attachments = vec![
+            attachment("1", "screenshot.png", "image/png", 500),
+            attachment("2", "server.log", "application/octet-stream", 900),
+            attachment("3", "notes.txt", "text/plain", 300),
+            attachment("4", "dump.json", "application/json", 50_000),
+        ];
+
+        let ids: Vec<&str> = text_attachments(&attachments, 20_000)
+            .iter()
+            .map(|attachment| attachment.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["3", "2"]);
+        assert!(text_attachments(&attachments, 0).is_empty());
+    }
+}
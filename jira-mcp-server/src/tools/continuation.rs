@@ -0,0 +1,155 @@
+//! Time budgets and continuation tokens of long-running tools
+//!
+//! Composite tools can take longer than an MCP client is willing to wait. With
+//! `time_budget_ms`, such a tool works in steps and stops once another step
+//! would not fit into the budget. It then returns what it has with
+//! `partial: true` and a `continuation_token` holding the remaining work, which
+//! the agent passes back to carry on in a follow-up call.
+//!
+//! Tokens are URL-safe base64 of a small JSON envelope naming the tool that
+//! issued them, so a token handed to the wrong tool, or mangled on the way, is
+//! rejected with a parameter error instead of being misread.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use base64::{engine::general_purpose, Engine as _};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// Version of the token envelope
+const TOKEN_VERSION: u8 = 1;
+
+#[derive(Serialize, Deserialize)]
+struct Envelope<T> {
+    v: u8,
+    tool: String,
+    state: T,
+}
+
+/// Encode the remaining work of `tool` as a continuation token
+pub fn encode_token<T: Serialize>(tool: &str, state: &T) -> JiraMcpResult<String> {
+    let envelope = Envelope {
+        v: TOKEN_VERSION,
+        tool: tool.to_string(),
+        state,
+    };
+    Ok(general_purpose::URL_SAFE_NO_PAD.encode(serde_json::to_vec(&envelope)?))
+}
+
+/// Decode a continuation token issued by `tool`
+pub fn decode_token<T: DeserializeOwned>(tool: &str, token: &str) -> JiraMcpResult<T> {
+    let invalid = |reason: &str| {
+        JiraMcpError::invalid_param(
+            "continuation_token",
+            format!(
+                "Not a continuation token of {} ({}); pass the token from the previous result unchanged",
+                tool, reason
+            ),
+        )
+    };
+
+    let bytes = general_purpose::URL_SAFE_NO_PAD
+        .decode(token.trim())
+        .map_err(|_| invalid("not base64"))?;
+    let envelope: Envelope<serde_json::Value> =
+        serde_json::from_slice(&bytes).map_err(|_| invalid("unreadable"))?;
+    if envelope.v != TOKEN_VERSION {
+        return Err(invalid("issued by another server version"));
+    }
+    if envelope.tool != tool {
+        return Err(invalid(&format!("issued by {}", envelope.tool)));
+    }
+    serde_json::from_value(envelope.state).map_err(|_| invalid("unreadable"))
+}
+
+/// Time left for a call with `time_budget_ms`
+///
+/// Steps are timed, and another step is only started while more time is left
+/// than the longest step so far took. At least one step always runs, so every
+/// call makes progress.
+#[derive(Debug)]
+pub struct TimeBudget {
+    started: Instant,
+    budget: Option<Duration>,
+    longest_step: Duration,
+    steps: usize,
+}
+
+impl TimeBudget {
+    /// A budget of `time_budget_ms`; None is unlimited
+    pub fn new(time_budget_ms: Option<u64>) -> JiraMcpResult<Self> {
+        if time_budget_ms == Some(0) {
+            return Err(JiraMcpError::invalid_param(
+                "time_budget_ms",
+                "The time budget must be at least 1 millisecond",
+            ));
+        }
+
+        Ok(Self {
+            started: Instant::now(),
+            budget: time_budget_ms.map(Duration::from_millis),
+            longest_step: Duration::ZERO,
+            steps: 0,
+        })
+    }
+
+    /// Whether another step fits into the remaining time
+    pub fn allows_step(&self) -> bool {
+        let Some(budget) = self.budget else {
+            return true;
+        };
+        self.steps == 0 || self.started.elapsed() + self.longest_step < budget
+    }
+
+    /// Record a finished step that started at `step_started`
+    pub fn finish_step(&mut self, step_started: Instant) {
+        self.longest_step = self.longest_step.max(step_started.elapsed());
+        self.steps += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_token_round_trip() {
+        let state = json!({"frontier": [["PROJ-2", 1]], "visited": ["PROJ-1"]});
+        let token = encode_token("get_issue_relationships", &state).unwrap();
+        assert!(token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+
+        let decoded: serde_json::Value = decode_token("get_issue_relationships", &token).unwrap();
+        assert_eq!(decoded, state);
+    }
+
+    #[test]
+    fn test_token_rejected_for_other_tool_or_garbage() {
+        let token = encode_token("get_issue_relationships", &json!({})).unwrap();
+
+        let error = decode_token::<serde_json::Value>("search_issues", &token).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("issued by get_issue_relationships"));
+
+        assert!(decode_token::<serde_json::Value>("search_issues", "not a token!").is_err());
+    }
+
+    #[test]
+    fn test_time_budget() {
+        let unlimited = TimeBudget::new(None).unwrap();
+        assert!(unlimited.allows_step());
+
+        // The first step always runs, later ones only while time is left
+        let mut budget = TimeBudget::new(Some(1)).unwrap();
+        assert!(budget.allows_step());
+        let step = Instant::now();
+        std::thread::sleep(Duration::from_millis(2));
+        budget.finish_step(step);
+        assert!(!budget.allows_step());
+
+        assert!(TimeBudget::new(Some(0)).is_err());
+    }
+}
@@ -0,0 +1,380 @@
+//! Count issues tool
+//!
+//! Answers "how many?" questions using the same semantic filters as search_issues,
+//! without fetching any issue bodies. Optionally breaks the count down by a small
+//! enumerable dimension (facet) with one count query per value.
+
+use crate::cache::MetadataCache;
+use crate::config::JiraConfig;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::jql;
+use crate::semantic_mapping::SemanticMapper;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// Upper bound on count queries issued for a single facet breakdown
+const MAX_FACET_QUERIES: usize = 20;
+
+/// Dimension to break a count down by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum FacetBy {
+    /// Semantic status categories from the configuration (open, in_progress, ...)
+    StatusCategory,
+    /// Priorities defined in the JIRA instance
+    Priority,
+    /// Semantic issue types from the configuration (story, bug, ...)
+    IssueType,
+}
+
+/// Parameters for the count_issues tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CountIssuesParams {
+    /// Natural language search text (optional)
+    pub query_text: Option<String>,
+
+    /// Semantic issue types (optional)
+    /// Examples: ["story", "bug", "feature", "task", "capability"]
+    pub issue_types: Option<Vec<String>>,
+
+    /// User assignment filter (optional)
+    /// Examples: "me", "current_user", username, or "unassigned"
+    pub assigned_to: Option<String>,
+
+    /// Project key for project-scoped counts (optional)
+    pub project_key: Option<String>,
+
+    /// Semantic status categories (optional)
+    /// Examples: ["open", "in_progress", "done", "blocked"]
+    #[serde(alias = "status_filter")]
+    pub status: Option<Vec<String>>,
+
+    /// Created after date filter (optional)
+    /// Examples: "2024-01-01", "7 days ago", "start of month"
+    pub created_after: Option<String>,
+
+    /// Label filters (optional)
+    pub labels: Option<Vec<String>>,
+
+    /// Component filters (optional)
+    pub components: Option<Vec<String>>,
+
+    /// Parent issue filter (optional)
+    /// Examples: "none", "any", "PROJ-123"
+    pub parent_filter: Option<String>,
+
+    /// Epic link filter (optional)
+    /// Examples: "none", "any", "PROJ-456"
+    pub epic_filter: Option<String>,
+
+    /// Break the total down by a dimension (optional)
+    /// Each facet query adds the facet value to the other filters, so a status
+    /// or issue_types filter still applies to every facet
+    /// Examples: "status_category", "priority", "issue_type"
+    pub facet_by: Option<FacetBy>,
+}
+
+/// Result from the count_issues tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CountIssuesResult {
+    /// Number of issues matching the filters
+    pub total: usize,
+
+    /// The JQL query that was counted
+    pub jql_query: String,
+
+    /// Facet value -> issue count (only when facet_by is set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facets: Option<BTreeMap<String, usize>>,
+
+    /// Set when facet values were dropped to stay within the query cap
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub facet_note: Option<String>,
+
+    /// Number of JIRA API calls made
+    pub api_calls: u32,
+
+    /// Time taken in milliseconds
+    pub duration_ms: u64,
+}
+
+/// Implementation of the count_issues tool
+pub struct CountIssuesTool {
+    jira_client: Arc<JiraClient>,
+    semantic_mapper: Arc<SemanticMapper>,
+    config: Arc<JiraConfig>,
+}
+
+impl CountIssuesTool {
+    /// Create a new count issues tool
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        config: Arc<JiraConfig>,
+        cache: Arc<MetadataCache>,
+    ) -> Self {
+        let semantic_mapper = Arc::new(SemanticMapper::new(Arc::clone(&config), cache));
+
+        Self {
+            jira_client,
+            semantic_mapper,
+            config,
+        }
+    }
+
+    /// Execute the count_issues tool
+    #[instrument(skip(self))]
+    pub async fn execute(&self, params: CountIssuesParams) -> JiraMcpResult<CountIssuesResult> {
+        let start_time = std::time::Instant::now();
+        let mut api_calls = 0u32;
+
+        let jql = self.build_jql(&params)?;
+        let total = self.jira_client.count_issues_jql(&jql).await?;
+        api_calls += 1;
+
+        let (facets, facet_note) = match params.facet_by {
+            Some(facet_by) => {
+                let (values, note) =
+                    cap_facet_values(self.facet_values(facet_by, &mut api_calls).await?);
+                let mut facets = BTreeMap::new();
+                for value in values {
+                    let clause =
+                        self.facet_clause(facet_by, &value, params.project_key.as_deref())?;
+                    let count = match clause {
+                        Some(clause) => {
+                            api_calls += 1;
+                            self.jira_client
+                                .count_issues_jql(&facet_jql(&jql, &clause))
+                                .await?
+                        }
+                        // A value matching nothing in JIRA counts no issues
+                        None => 0,
+                    };
+                    facets.insert(value, count);
+                }
+                (Some(facets), note)
+            }
+            None => (None, None),
+        };
+
+        info!("Counted {} issues with {} API calls", total, api_calls);
+
+        Ok(CountIssuesResult {
+            total,
+            jql_query: jql,
+            facets,
+            facet_note,
+            api_calls,
+            duration_ms: start_time.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Build the JQL conditions (without ORDER BY, which counts don't need)
+    fn build_jql(&self, params: &CountIssuesParams) -> JiraMcpResult<String> {
+        let non_empty = |arr: &Option<Vec<String>>| -> Option<Vec<String>> {
+            arr.as_ref().filter(|arr| !arr.is_empty()).cloned()
+        };
+
+        let status = non_empty(&params.status);
+        let issue_types = non_empty(&params.issue_types);
+        let labels = non_empty(&params.labels);
+        let components = non_empty(&params.components);
+
+        let query = self.semantic_mapper.build_search_jql_with_components(
+            params.query_text.as_deref(),
+            None,
+            issue_types.as_deref(),
+            params.assigned_to.as_deref(),
+            None,
+            None,
+            params.project_key.as_deref(),
+            status.as_deref(),
+            params.created_after.as_deref(),
+            labels.as_deref(),
+            components.as_deref(),
+            params.parent_filter.as_deref(),
+            params.epic_filter.as_deref(),
+            None,
+            None,
+        )?;
+
+        let conditions = strip_order_by(&query.jql);
+        if conditions.is_empty() {
+            return Err(JiraMcpError::jql("Count query produced no conditions"));
+        }
+
+        Ok(conditions.to_string())
+    }
+
+    /// JQL condition for one facet value, or None when it matches nothing
+    fn facet_clause(
+        &self,
+        facet_by: FacetBy,
+        value: &str,
+        project_key: Option<&str>,
+    ) -> JiraMcpResult<Option<String>> {
+        let value = [value.to_string()];
+        Ok(match facet_by {
+            FacetBy::StatusCategory => self
+                .semantic_mapper
+                .resolve_status_filter(&value, project_key)?
+                .to_jql(),
+            FacetBy::IssueType => jql::eq_or_in(
+                &jql::ISSUE_TYPE,
+                &self.semantic_mapper.map_issue_types(&value, project_key)?,
+            ),
+            FacetBy::Priority => jql::eq_or_in(&jql::PRIORITY, &value),
+        })
+    }
+
+    /// Distinct values for a facet, sorted
+    async fn facet_values(
+        &self,
+        facet_by: FacetBy,
+        api_calls: &mut u32,
+    ) -> JiraMcpResult<Vec<String>> {
+        let mut values: Vec<String> = match facet_by {
+            FacetBy::StatusCategory => self
+                .config
+                .status_category_mappings
+                .keys()
+                .cloned()
+                .collect(),
+            FacetBy::IssueType => self.config.issue_type_mappings.keys().cloned().collect(),
+            FacetBy::Priority => {
+                let priorities = self.jira_client.get_priorities().await?;
+                *api_calls += 1;
+
+                priorities
+                    .into_iter()
+                    .map(|priority| priority.name)
+                    .collect()
+            }
+        };
+        values.sort();
+        values.dedup();
+        Ok(values)
+    }
+}
+
+/// JQL conditions without a trailing ORDER BY clause
+fn strip_order_by(jql: &str) -> &str {
+    match jql.find("ORDER BY") {
+        Some(index) => jql[..index].trim_end(),
+        None => jql.trim_end(),
+    }
+}
+
+/// Count query for one facet: the user's filters AND the facet's condition
+fn facet_jql(conditions: &str, facet_clause: &str) -> String {
+    format!("{} AND {}", conditions, facet_clause)
+}
+
+/// Keep the first `MAX_FACET_QUERIES` facet values, with a note when some were dropped
+fn cap_facet_values(mut values: Vec<String>) -> (Vec<String>, Option<String>) {
+    if values.len() <= MAX_FACET_QUERIES {
+        return (values, None);
+    }
+
+    warn!(
+        "Facet has {} values, only counting the first {}",
+        values.len(),
+        MAX_FACET_QUERIES
+    );
+    let note = format!(
+        "Only the first {} of {} facet values were counted",
+        MAX_FACET_QUERIES,
+        values.len()
+    );
+    values.truncate(MAX_FACET_QUERIES);
+    (values, Some(note))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tool on the mock fixtures; facet clauses need no requests
+    async fn mock_tool() -> CountIssuesTool {
+        let config = Arc::new(JiraConfig {
+            mock_fixtures_dir: Some(
+                concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/mock").to_string(),
+            ),
+            ..Default::default()
+        });
+        let jira_client = Arc::new(JiraClient::new(Arc::clone(&config)).await.unwrap());
+        let cache = Arc::new(MetadataCache::new(300));
+        CountIssuesTool::new(jira_client, config, cache)
+    }
+
+    fn params(filters: serde_json::Value) -> CountIssuesParams {
+        serde_json::from_value(filters).unwrap()
+    }
+
+    #[test]
+    fn test_strip_order_by() {
+        assert_eq!(
+            strip_order_by("project = \"PROJ\" ORDER BY updated DESC"),
+            "project = \"PROJ\""
+        );
+        assert_eq!(strip_order_by("project = \"PROJ\" "), "project = \"PROJ\"");
+        assert_eq!(strip_order_by("ORDER BY created DESC"), "");
+    }
+
+    #[test]
+    fn test_cap_facet_values() {
+        let values: Vec<String> = (0..3).map(|i| format!("v{}", i)).collect();
+        assert_eq!(cap_facet_values(values.clone()), (values, None));
+
+        let values: Vec<String> = (0..MAX_FACET_QUERIES + 5)
+            .map(|i| format!("v{:02}", i))
+            .collect();
+        let (kept, note) = cap_facet_values(values);
+        assert_eq!(kept.len(), MAX_FACET_QUERIES);
+        assert_eq!(kept[0], "v00");
+        assert_eq!(
+            note.as_deref(),
+            Some("Only the first 20 of 25 facet values were counted")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_facet_queries_keep_the_user_filters() {
+        let tool = mock_tool().await;
+        let filters = params(serde_json::json!({
+            "project_key": "PROJ",
+            "status": ["open"],
+            "issue_types": ["bug"]
+        }));
+        let base = tool.build_jql(&filters).unwrap();
+        assert!(!base.contains("ORDER BY"), "{}", base);
+        assert!(base.contains("statusCategory"), "{}", base);
+
+        let by_type = tool
+            .facet_clause(FacetBy::IssueType, "story", Some("PROJ"))
+            .unwrap()
+            .unwrap();
+        let jql = facet_jql(&base, &by_type);
+        assert!(jql.starts_with(&base), "{}", jql);
+        assert!(jql.ends_with(&format!("AND {}", by_type)), "{}", jql);
+
+        let by_status = tool
+            .facet_clause(FacetBy::StatusCategory, "done", Some("PROJ"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(by_status, "statusCategory = \"Done\"");
+        let jql = facet_jql(&base, &by_status);
+        // An open-only count faceted by done stays open-only (and so counts none)
+        assert!(jql.contains("statusCategory = \"To Do\""), "{}", jql);
+        assert!(jql.ends_with("AND statusCategory = \"Done\""), "{}", jql);
+
+        assert_eq!(
+            tool.facet_clause(FacetBy::Priority, "High", None).unwrap(),
+            Some("priority = \"High\"".to_string())
+        );
+    }
+}
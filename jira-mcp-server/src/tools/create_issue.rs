@@ -1,13 +1,26 @@
+use crate::cache::MetadataCache;
+use crate::config::{JiraConfig, OperationClass, ProjectDefaults};
 use crate::error::{JiraMcpError, JiraMcpResult};
-use crate::jira_client::JiraClient;
+use crate::idempotency::IdempotencyStore;
+use crate::jira_client::{issue_browse_url, IssueInfo, JiraClient, ProjectVersion};
+use crate::semantic_mapping::SemanticMapper;
+use crate::tools::find_similar_issues::{
+    FindSimilarIssuesParams, FindSimilarIssuesTool, SimilarIssue, DUPLICATE_SCORE_THRESHOLD,
+};
+use crate::tools::issue_key::normalize_issue_key;
+use crate::tools::priorities::PrioritiesTool;
+use crate::tools::security_levels::SecurityLevelsTool;
+use crate::tools::text_limit::fit_text;
+use crate::tools::update_custom_fields::parse_date_param;
+use chrono::Utc;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::sync::Arc;
-use tracing::{info, instrument};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+use tracing::{info, instrument, warn};
 
 /// Parameters for creating a new JIRA issue
-#[derive(Debug, Clone, Deserialize, JsonSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CreateIssueParams {
     /// Project key where the issue will be created (e.g., "PROJ", "DEV")
     /// Can be inferred from parent_issue_key if not provided
@@ -23,12 +36,14 @@ pub struct CreateIssueParams {
     #[serde(default)]
     pub description: Option<String>,
 
-    /// Issue type (default: "Task")
-    /// Common types: "Task", "Bug", "Story", "Epic", "Subtask"
+    /// Issue type (default: "Task"), checked against the project's issue types
+    /// Common types: "Task", "Bug", "Story", "Epic", "Subtask"; semantic names like
+    /// "bug" resolve to the project's own type (e.g. "Defect", or "Fehler" when localized)
     #[serde(default)]
     pub issue_type: Option<String>,
 
-    /// Priority (e.g., "High", "Medium", "Low", "Highest", "Lowest")
+    /// Priority (e.g., "High", "Medium", "Low", "Highest", "Lowest"), case-insensitive.
+    /// Aliases like "critical" or "normal" map to the instance's scheme (see list_priorities).
     /// Defaults to project's default priority if not specified
     #[serde(default)]
     pub priority: Option<String>,
@@ -44,12 +59,35 @@ pub struct CreateIssueParams {
     #[serde(default)]
     pub labels: Vec<String>,
 
+    /// Leave out the auto_label_created_issues label configured for the server
+    /// (default: false)
+    #[serde(default)]
+    pub skip_auto_label: bool,
+
     /// Components to add to the issue
     #[serde(default)]
     pub components: Vec<String>,
 
+    /// Environment the problem occurs in, e.g. browser, OS or deployment (bug reports)
+    #[serde(default)]
+    pub environment: Option<String>,
+
+    /// Names of the project versions affected by a bug (case-insensitive),
+    /// checked against the project's versions
+    #[serde(default)]
+    pub affects_versions: Vec<String>,
+
+    /// Due date: YYYY-MM-DD or an expression like "tomorrow", "next friday", "+3d"
+    #[serde(default)]
+    pub due_date: Option<String>,
+
+    /// Issue security level name (case-insensitive) or ID, for sensitive issues.
+    /// Must be one of the project's levels; needs the "Set Issue Security" permission
+    #[serde(default)]
+    pub security_level: Option<String>,
+
     /// Parent issue key for creating subtasks (e.g., "PROJ-123")
-    /// If provided, issue_type will be set to "Subtask" automatically
+    /// If provided, issue_type will be set to the project's sub-task type automatically
     #[serde(default)]
     pub parent_issue_key: Option<String>,
 
@@ -67,18 +105,68 @@ pub struct CreateIssueParams {
     pub custom_fields: HashMap<String, serde_json::Value>,
 
     /// Initial todo checklist items to add to description
-    /// Automatically formats as markdown checkboxes
+    /// Automatically formats as markdown checkboxes. Each item is a plain
+    /// string (an open todo under "## Tasks") or `{text, completed?, section?}`;
+    /// sections become "## <section>" headings and completed items "- [x]"
     #[serde(default)]
-    pub initial_todos: Vec<String>,
+    pub initial_todos: Vec<InitialTodo>,
 
     /// Auto-assign to yourself (default: false)
     /// Convenience shorthand for assignee: "me"
     #[serde(default)]
     pub assign_to_me: bool,
+
+    /// Search for similar existing issues before creating (default: false)
+    /// Matches are returned in possible_duplicates; creation still proceeds
+    #[serde(default)]
+    pub check_duplicates: bool,
+
+    /// Abort instead of creating when a likely duplicate is found (default: false)
+    /// Implies check_duplicates
+    #[serde(default)]
+    pub fail_on_duplicate: bool,
+
+    /// Fetch the created issue and return it in `issue` (default: true, but false
+    /// in bulk_create_issues); saves a get_issue_details call to confirm the fields
+    #[serde(default)]
+    pub return_full_issue: Option<bool>,
+
+    /// Cut a description longer than max_description_chars (default 32767) and
+    /// end it with a truncation marker, instead of failing (default: false)
+    #[serde(default)]
+    pub auto_truncate: bool,
+
+    /// Key that makes retries safe (optional): a repeated call with the same key
+    /// within 24 hours returns the issue created first, with `replayed: true`,
+    /// instead of creating another one
+    #[serde(default)]
+    pub idempotency_key: Option<String>,
+}
+
+/// An initial todo for create_issue
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum InitialTodo {
+    /// Open todo under the "Tasks" heading
+    Text(String),
+
+    /// Todo with completion state and section
+    Item {
+        /// The todo text
+        text: String,
+
+        /// Render as already done ("- [x]") (default: false)
+        #[serde(default)]
+        completed: bool,
+
+        /// Heading to group the todo under (default: "Tasks")
+        #[serde(default)]
+        section: Option<String>,
+    },
 }
 
 /// Result from creating an issue
-#[derive(Debug, Serialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct CreateIssueResult {
     /// The created issue key (e.g., "PROJ-456")
     pub issue_key: String,
@@ -100,24 +188,105 @@ pub struct CreateIssueResult {
 
     /// Success message
     pub message: String,
+
+    /// Likely duplicates found by check_duplicates, best match first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub possible_duplicates: Vec<SimilarIssue>,
+
+    /// The created issue as JIRA stored it (unless return_full_issue is false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue: Option<IssueInfo>,
+
+    /// Fields chosen for convenience parameters, e.g. "story_points" ->
+    /// "customfield_10016"
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub auto_detected_fields: BTreeMap<String, String>,
+
+    /// Characters auto_truncate cut from the end of the description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncated_chars: Option<usize>,
+
+    /// True when the idempotency_key was seen before and nothing was created
+    #[serde(default)]
+    pub replayed: bool,
+
+    /// Values taken from `[project_defaults.<KEY>]` because the call left them
+    /// out, by parameter name, e.g. "components" -> ["backend"]
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub applied_defaults: BTreeMap<String, serde_json::Value>,
 }
 
 /// Tool for creating JIRA issues
 pub struct CreateIssueTool {
     jira_client: Arc<JiraClient>,
+    semantic_mapper: Arc<SemanticMapper>,
+    priorities: Arc<PrioritiesTool>,
+    security_levels: Arc<SecurityLevelsTool>,
+    idempotency: Arc<IdempotencyStore>,
+    config: Arc<JiraConfig>,
+    /// Problems found in the project defaults, by project key and issue type,
+    /// checked against the create metadata on first use
+    checked_defaults: Mutex<HashMap<(String, String), Vec<DefaultsProblem>>>,
 }
 
+/// A project default the create screen rejects: (setting, problem)
+type DefaultsProblem = (String, String);
+
 impl CreateIssueTool {
-    pub fn new(jira_client: Arc<JiraClient>) -> Self {
-        Self { jira_client }
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        config: Arc<JiraConfig>,
+        cache: Arc<MetadataCache>,
+        priorities: Arc<PrioritiesTool>,
+        security_levels: Arc<SecurityLevelsTool>,
+        idempotency: Arc<IdempotencyStore>,
+    ) -> Self {
+        let semantic_mapper = Arc::new(SemanticMapper::new(Arc::clone(&config), cache));
+
+        Self {
+            jira_client,
+            semantic_mapper,
+            priorities,
+            security_levels,
+            idempotency,
+            config,
+            checked_defaults: Mutex::new(HashMap::new()),
+        }
     }
 
     #[instrument(skip(self))]
     pub async fn execute(&self, params: CreateIssueParams) -> JiraMcpResult<CreateIssueResult> {
+        let idempotency_key = params.idempotency_key.clone();
+        let (mut result, replayed) = self
+            .idempotency
+            .run(
+                "create_issue",
+                idempotency_key.as_deref(),
+                |result: &CreateIssueResult| result.issue_key.clone(),
+                self.create(params),
+            )
+            .await?;
+        if replayed {
+            info!(
+                "Idempotency key already created {}, not creating again",
+                result.issue_key
+            );
+            result.replayed = true;
+        }
+        Ok(result)
+    }
+
+    async fn create(&self, mut params: CreateIssueParams) -> JiraMcpResult<CreateIssueResult> {
         info!("Creating new JIRA issue: {}", params.summary);
 
+        params.parent_issue_key = params
+            .parent_issue_key
+            .as_deref()
+            .map(|key| normalize_issue_key(self.jira_client.base_url(), key, "parent_issue_key"))
+            .transpose()?;
+
         // Determine project key
-        let project_key = if let Some(key) = params.project_key {
+        let project_key = if let Some(key) = params.project_key.take() {
             key
         } else if let Some(parent_key) = &params.parent_issue_key {
             // Extract project key from parent (e.g., "PROJ-123" -> "PROJ")
@@ -138,30 +307,113 @@ impl CreateIssueTool {
             ));
         };
 
-        // Determine issue type
-        let issue_type = if params.parent_issue_key.is_some() {
-            "Subtask".to_string()
-        } else {
-            params.issue_type.unwrap_or_else(|| "Task".to_string())
+        // Fill in what the call left out from the project's configured defaults
+        let defaults = self.project_defaults(&project_key);
+        let applied_defaults = defaults
+            .map(|(_, defaults)| apply_project_defaults(&mut params, defaults))
+            .unwrap_or_default();
+        let param_name = |param: &str| match defaults {
+            Some((key, _)) if applied_defaults.contains_key(param) => {
+                format!("project_defaults.{}.{}", key, param)
+            }
+            _ => param.to_string(),
         };
 
+        // Mark issues created through the server, unless the call opts out
+        if let Some(label) = &self.config.auto_label_created_issues {
+            if !params.skip_auto_label && !params.labels.contains(label) {
+                params.labels.push(label.clone());
+            }
+        }
+
         // Build description with initial todos if provided
         let description = if !params.initial_todos.is_empty() {
-            let todo_lines: Vec<String> = params
-                .initial_todos
-                .iter()
-                .map(|todo| format!("- [ ] {}", todo))
-                .collect();
-
-            let todo_section = format!("\n\n## Tasks\n\n{}", todo_lines.join("\n"));
+            let todo_section = render_initial_todos(&params.initial_todos)?;
 
             match params.description {
-                Some(desc) => format!("{}{}", desc, todo_section),
-                None => todo_section.trim_start().to_string(),
+                Some(desc) => format!("{}\n\n{}", desc, todo_section),
+                None => todo_section,
             }
         } else {
             params.description.unwrap_or_default()
         };
+        let (description, truncated_chars) = fit_text(
+            &description,
+            self.jira_client.write_limits().max_description_chars,
+            params.auto_truncate,
+            "description",
+            "description",
+        )?;
+
+        // Validate the priority before anything is created
+        let priority = match &params.priority {
+            Some(priority) => Some(
+                self.priorities
+                    .resolve_priority(priority, &param_name("priority"))
+                    .await?,
+            ),
+            None => None,
+        };
+
+        let security_level = match params.security_level.as_deref().map(str::trim) {
+            Some("") | None => None,
+            Some(name) => Some(
+                self.security_levels
+                    .resolve_security_level(&project_key, name, "security_level")
+                    .await?,
+            ),
+        };
+
+        let due_date = params
+            .due_date
+            .as_deref()
+            .map(|input| parse_date_param(input, Utc::now().date_naive(), "due_date"))
+            .transpose()?
+            .flatten();
+
+        let affects_versions = if params.affects_versions.is_empty() {
+            Vec::new()
+        } else {
+            let versions = self.jira_client.get_project_versions(&project_key).await?;
+            params
+                .affects_versions
+                .iter()
+                .map(|name| find_version(&versions, name, &project_key).cloned())
+                .collect::<JiraMcpResult<Vec<_>>>()?
+        };
+
+        // Determine issue type from the project's own issue types
+        self.semantic_mapper
+            .load_issue_types(&self.jira_client, Some(&project_key))
+            .await;
+        let requested_type = if params.parent_issue_key.is_some() {
+            "Subtask"
+        } else {
+            params.issue_type.as_deref().unwrap_or("Task")
+        };
+        let issue_type = self.semantic_mapper.resolve_issue_type(
+            requested_type,
+            Some(&project_key),
+            &param_name("issue_type"),
+        )?;
+
+        if let Some((key, defaults)) = defaults {
+            self.check_project_defaults(
+                key,
+                defaults,
+                &project_key,
+                &issue_type,
+                &applied_defaults,
+            )
+            .await?;
+        }
+
+        let possible_duplicates = if params.check_duplicates || params.fail_on_duplicate {
+            self.check_duplicates(&params.summary, &project_key, params.fail_on_duplicate)
+                .await?
+        } else {
+            Vec::new()
+        };
 
         // Determine assignee
         let assignee_value = if params.assign_to_me {
@@ -195,12 +447,12 @@ impl CreateIssueTool {
             fields["description"] = serde_json::json!(description);
         }
 
-        if let Some(priority) = params.priority {
+        if let Some(priority) = priority {
             fields["priority"] = serde_json::json!({ "name": priority });
         }
 
         if let Some(assignee_id) = assignee_id {
-            fields["assignee"] = serde_json::json!({ "accountId": assignee_id });
+            fields["assignee"] = self.jira_client.deployment_type().user_field(&assignee_id);
         }
 
         if !params.labels.is_empty() {
@@ -215,6 +467,25 @@ impl CreateIssueTool {
                 .collect::<Vec<_>>());
         }
 
+        if let Some(environment) = params.environment.filter(|e| !e.trim().is_empty()) {
+            fields["environment"] = serde_json::json!(environment);
+        }
+
+        if !affects_versions.is_empty() {
+            fields["versions"] = serde_json::json!(affects_versions
+                .iter()
+                .map(|version| serde_json::json!({ "id": version.id }))
+                .collect::<Vec<_>>());
+        }
+
+        if let Some(due_date) = due_date {
+            fields["duedate"] = serde_json::json!(due_date.to_string());
+        }
+
+        if let Some(level) = &security_level {
+            fields["security"] = serde_json::json!({ "id": level.id });
+        }
+
         if let Some(parent_key) = params.parent_issue_key {
             fields["parent"] = serde_json::json!({ "key": parent_key });
         }
@@ -224,16 +495,17 @@ impl CreateIssueTool {
             fields[field_id] = value;
         }
 
-        // Handle common custom fields with convenience parameters
+        // Handle common custom fields with convenience parameters; custom_fields
+        // entries for the same field win
+        let mut auto_detected_fields = BTreeMap::new();
         if let Some(story_points) = params.story_points {
-            // Try common story points field IDs
-            // Users can override with custom_fields if different
-            if !fields
-                .as_object()
-                .unwrap()
-                .contains_key("customfield_10016")
-            {
-                fields["customfield_10016"] = serde_json::json!(story_points);
+            let candidates = &self.jira_client.field_map().story_points.field_ids;
+            let given = candidates
+                .iter()
+                .any(|id| fields.as_object().is_some_and(|f| f.contains_key(id)));
+            if let Some(field_id) = candidates.first().filter(|_| !given) {
+                fields[field_id] = serde_json::json!(story_points);
+                auto_detected_fields.insert("story_points".to_string(), field_id.clone());
             }
         }
 
@@ -245,6 +517,8 @@ impl CreateIssueTool {
                 .contains_key("customfield_10014")
             {
                 fields["customfield_10014"] = serde_json::json!(epic_link);
+                auto_detected_fields
+                    .insert("epic_link".to_string(), "customfield_10014".to_string());
             }
         }
 
@@ -253,26 +527,38 @@ impl CreateIssueTool {
 
         let response: serde_json::Value = self
             .jira_client
-            .client
-            .post("api", "/issue", create_body)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("project is required")
-                    || e.to_string().contains("project does not exist")
-                {
-                    JiraMcpError::invalid_param(
-                        "project_key",
-                        format!("Invalid project: {}", project_key),
-                    )
-                } else if e.to_string().contains("valid issue type") {
-                    JiraMcpError::invalid_param(
-                        "issue_type",
-                        format!("Invalid issue type: {}", issue_type),
-                    )
-                } else {
-                    JiraMcpError::internal(format!("Failed to create issue: {}", e))
-                }
-            })?;
+            .post_json(
+                &format!("creating an issue in {}", project_key),
+                "api",
+                "/issue",
+                create_body,
+                |e| {
+                    if e.to_string().contains("project is required")
+                        || e.to_string().contains("project does not exist")
+                    {
+                        JiraMcpError::invalid_param(
+                            "project_key",
+                            format!("Invalid project: {}", project_key),
+                        )
+                    } else if e.to_string().contains("valid issue type") {
+                        JiraMcpError::invalid_param(
+                            "issue_type",
+                            format!("Invalid issue type: {}", issue_type),
+                        )
+                    } else if security_level.is_some() && e.to_string().contains("security") {
+                        JiraMcpError::permission(format!(
+                            "Creating an issue with a security level needs the \"Set Issue \
+                             Security\" permission in project {}: {}",
+                            project_key, e
+                        ))
+                    } else if let Some(error) = rejected_fields_error(&e.to_string()) {
+                        error
+                    } else {
+                        JiraMcpError::internal(format!("Failed to create issue: {}", e))
+                    }
+                },
+            )
+            .await?;
 
         let issue_key = response["key"]
             .as_str()
@@ -284,10 +570,27 @@ impl CreateIssueTool {
             .ok_or_else(|| JiraMcpError::internal("No issue ID in response"))?
             .to_string();
 
-        let issue_url = format!("{}/browse/{}", self.jira_client.base_url(), issue_key);
+        let issue_url = issue_browse_url(self.jira_client.base_url(), &issue_key);
 
         info!("Successfully created issue: {}", issue_key);
 
+        // The issue exists at this point, so a failed read-back only loses the echo
+        let issue = if params.return_full_issue.unwrap_or(true) {
+            match self
+                .jira_client
+                .get_issue_details(&issue_key, false, false, false)
+                .await
+            {
+                Ok(details) => Some(details.issue_info),
+                Err(e) => {
+                    warn!("Created {} but could not read it back: {}", issue_key, e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
         Ok(CreateIssueResult {
             issue_key: issue_key.clone(),
             issue_id,
@@ -302,6 +605,598 @@ impl CreateIssueTool {
                 project_key,
                 issue_url
             ),
+            possible_duplicates,
+            issue,
+            auto_detected_fields,
+            truncated_chars,
+            replayed: false,
+            applied_defaults,
         })
     }
+
+    /// Configured defaults for a project, with the key as configured
+    fn project_defaults(&self, project_key: &str) -> Option<(&str, &ProjectDefaults)> {
+        self.config
+            .project_defaults
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(project_key))
+            .map(|(key, defaults)| (key.as_str(), defaults))
+    }
+
+    /// Reject applied defaults the project's create screen does not accept
+    ///
+    /// The create metadata is fetched once per project and issue type and the
+    /// problems found are kept; when it cannot be fetched the defaults are sent
+    /// as they are and JIRA gets the final say.
+    async fn check_project_defaults(
+        &self,
+        config_key: &str,
+        defaults: &ProjectDefaults,
+        project_key: &str,
+        issue_type: &str,
+        applied: &BTreeMap<String, serde_json::Value>,
+    ) -> JiraMcpResult<()> {
+        let cache_key = (project_key.to_uppercase(), issue_type.to_string());
+        let cached = self
+            .checked_defaults
+            .lock()
+            .unwrap()
+            .get(&cache_key)
+            .cloned();
+        let problems = match cached {
+            Some(problems) => problems,
+            None => {
+                let endpoint = format!(
+                    "/issue/createmeta?projectKeys={}&expand=projects.issuetypes.fields",
+                    project_key
+                );
+                let metadata: serde_json::Value = match self
+                    .jira_client
+                    .get_json(
+                        OperationClass::Read,
+                        &format!("reading create metadata of {}", project_key),
+                        "api",
+                        &endpoint,
+                        JiraMcpError::from,
+                    )
+                    .await
+                {
+                    Ok(metadata) => metadata,
+                    Err(e) => {
+                        warn!(
+                            "Could not load create metadata to check the defaults of {}: {}",
+                            project_key, e
+                        );
+                        return Ok(());
+                    }
+                };
+                let fields = metadata["projects"][0]["issuetypes"]
+                    .as_array()
+                    .and_then(|types| {
+                        types
+                            .iter()
+                            .find(|t| t["name"].as_str() == Some(issue_type))
+                    })
+                    .and_then(|t| t["fields"].as_object());
+                let problems = match fields {
+                    Some(fields) => project_default_problems(defaults, fields),
+                    None => Vec::new(),
+                };
+                self.checked_defaults
+                    .lock()
+                    .unwrap()
+                    .insert(cache_key, problems.clone());
+                problems
+            }
+        };
+
+        // Only defaults this call used can fail it
+        let is_applied = |param: &str| match param.split_once('.') {
+            Some((group, field_id)) => applied
+                .get(group)
+                .is_some_and(|v| v.get(field_id).is_some()),
+            None => applied.contains_key(param),
+        };
+        match problems.iter().find(|(param, _)| is_applied(param)) {
+            Some((param, problem)) => Err(JiraMcpError::invalid_param(
+                format!("project_defaults.{}.{}", config_key, param),
+                format!("{} for {} issues in {}", problem, issue_type, project_key),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Look for likely duplicates of the proposed summary in the project
+    ///
+    /// Search failures only abort creation when `fail_on_duplicate` is set.
+    async fn check_duplicates(
+        &self,
+        summary: &str,
+        project_key: &str,
+        fail_on_duplicate: bool,
+    ) -> JiraMcpResult<Vec<SimilarIssue>> {
+        let finder = FindSimilarIssuesTool::new(Arc::clone(&self.jira_client));
+        let params = FindSimilarIssuesParams {
+            summary_text: summary.to_string(),
+            project_key: Some(project_key.to_string()),
+            limit: None,
+            statuses: None,
+        };
+
+        let candidates = match finder.execute(params).await {
+            Ok(result) => result.candidates,
+            Err(e) if !fail_on_duplicate => {
+                warn!("Duplicate check failed, creating issue anyway: {}", e);
+                return Ok(Vec::new());
+            }
+            Err(e) => return Err(e),
+        };
+
+        let duplicates: Vec<SimilarIssue> = candidates
+            .into_iter()
+            .filter(|c| c.score >= DUPLICATE_SCORE_THRESHOLD)
+            .collect();
+
+        if fail_on_duplicate && !duplicates.is_empty() {
+            let keys = duplicates
+                .iter()
+                .map(|d| format!("{} ({}, score {:.2})", d.key, d.status, d.score))
+                .collect::<Vec<_>>()
+                .join(", ");
+            return Err(JiraMcpError::invalid_param(
+                "summary",
+                format!(
+                    "Possible duplicates found: {}. Set fail_on_duplicate to false to create anyway.",
+                    keys
+                ),
+            ));
+        }
+
+        Ok(duplicates)
+    }
+}
+
+/// create_issue parameter that sets a JIRA field, for fields with their own parameter
+pub fn create_issue_param(field_id: &str) -> Option<&'static str> {
+    Some(match field_id {
+        "project" => "project_key",
+        "summary" => "summary",
+        "description" => "description",
+        "issuetype" => "issue_type",
+        "priority" => "priority",
+        "assignee" => "assignee",
+        "labels" => "labels",
+        "components" => "components",
+        "parent" => "parent_issue_key",
+        "environment" => "environment",
+        "versions" => "affects_versions",
+        "duedate" => "due_date",
+        "security" => "security_level",
+        _ => return None,
+    })
+}
+
+/// Fill unset parameters from a project's defaults, returning what was applied
+fn apply_project_defaults(
+    params: &mut CreateIssueParams,
+    defaults: &ProjectDefaults,
+) -> BTreeMap<String, serde_json::Value> {
+    let mut applied = BTreeMap::new();
+
+    // Subtasks always get the project's sub-task type
+    if params.issue_type.is_none() && params.parent_issue_key.is_none() {
+        if let Some(issue_type) = &defaults.issue_type {
+            params.issue_type = Some(issue_type.clone());
+            applied.insert("issue_type".to_string(), serde_json::json!(issue_type));
+        }
+    }
+    if params.priority.is_none() {
+        if let Some(priority) = &defaults.priority {
+            params.priority = Some(priority.clone());
+            applied.insert("priority".to_string(), serde_json::json!(priority));
+        }
+    }
+    if params.assignee.is_none() && !params.assign_to_me {
+        if let Some(assignee) = &defaults.assignee {
+            params.assignee = Some(assignee.clone());
+            applied.insert("assignee".to_string(), serde_json::json!(assignee));
+        }
+    }
+    if params.labels.is_empty() && !defaults.labels.is_empty() {
+        params.labels = defaults.labels.clone();
+        applied.insert("labels".to_string(), serde_json::json!(defaults.labels));
+    }
+    if params.components.is_empty() && !defaults.components.is_empty() {
+        params.components = defaults.components.clone();
+        applied.insert(
+            "components".to_string(),
+            serde_json::json!(defaults.components),
+        );
+    }
+
+    let mut custom_fields = serde_json::Map::new();
+    for (field_id, value) in &defaults.custom_fields {
+        if !params.custom_fields.contains_key(field_id) {
+            params.custom_fields.insert(field_id.clone(), value.clone());
+            custom_fields.insert(field_id.clone(), value.clone());
+        }
+    }
+    if !custom_fields.is_empty() {
+        applied.insert(
+            "custom_fields".to_string(),
+            serde_json::Value::Object(custom_fields),
+        );
+    }
+
+    applied
+}
+
+/// Defaults the create screen of an issue type rejects, e.g. ("components",
+/// "Unknown component 'backend'")
+fn project_default_problems(
+    defaults: &ProjectDefaults,
+    fields: &serde_json::Map<String, serde_json::Value>,
+) -> Vec<DefaultsProblem> {
+    let mut problems = Vec::new();
+    let not_on_screen = |field: &str| format!("{} is not on the create screen", field);
+
+    if !defaults.labels.is_empty() && !fields.contains_key("labels") {
+        problems.push(("labels".to_string(), not_on_screen("labels")));
+    }
+    if defaults.assignee.is_some() && !fields.contains_key("assignee") {
+        problems.push(("assignee".to_string(), not_on_screen("assignee")));
+    }
+    if !defaults.components.is_empty() {
+        match fields.get("components") {
+            None => problems.push(("components".to_string(), not_on_screen("components"))),
+            Some(field) => {
+                let allowed: Vec<&str> = field["allowedValues"]
+                    .as_array()
+                    .map(|values| values.iter().filter_map(|v| v["name"].as_str()).collect())
+                    .unwrap_or_default();
+                for component in &defaults.components {
+                    if !allowed
+                        .iter()
+                        .any(|name| name.eq_ignore_ascii_case(component.trim()))
+                    {
+                        problems.push((
+                            "components".to_string(),
+                            format!(
+                                "Unknown component '{}'. Available: {}",
+                                component,
+                                if allowed.is_empty() {
+                                    "none".to_string()
+                                } else {
+                                    allowed.join(", ")
+                                }
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    let mut custom_fields: Vec<&String> = defaults.custom_fields.keys().collect();
+    custom_fields.sort();
+    for field_id in custom_fields {
+        if !fields.contains_key(field_id) {
+            problems.push((
+                format!("custom_fields.{}", field_id),
+                not_on_screen(field_id),
+            ));
+        }
+    }
+
+    problems
+}
+
+/// Heading for initial todos without a section
+const DEFAULT_TODO_SECTION: &str = "Tasks";
+
+/// Render initial todos as markdown checkboxes grouped under "## <section>"
+/// headings, sections in order of first appearance
+fn render_initial_todos(todos: &[InitialTodo]) -> JiraMcpResult<String> {
+    let mut sections: Vec<(&str, Vec<String>)> = Vec::new();
+
+    for todo in todos {
+        let (text, completed, section) = match todo {
+            InitialTodo::Text(text) => (text.trim(), false, None),
+            InitialTodo::Item {
+                text,
+                completed,
+                section,
+            } => (text.trim(), *completed, section.as_deref().map(str::trim)),
+        };
+        let section = section
+            .filter(|section| !section.is_empty())
+            .unwrap_or(DEFAULT_TODO_SECTION);
+        if text.is_empty() || text.contains('\n') || section.contains('\n') {
+            return Err(JiraMcpError::invalid_param(
+                "initial_todos",
+                format!(
+                    "Todo text and section must be a single non-empty line, got {:?}",
+                    text
+                ),
+            ));
+        }
+
+        let line = format!("- [{}] {}", if completed { "x" } else { " " }, text);
+        match sections.iter_mut().find(|(name, _)| *name == section) {
+            Some((_, lines)) => lines.push(line),
+            None => sections.push((section, vec![line])),
+        }
+    }
+
+    Ok(sections
+        .iter()
+        .map(|(name, lines)| format!("## {}\n\n{}", name, lines.join("\n")))
+        .collect::<Vec<_>>()
+        .join("\n\n"))
+}
+
+/// Find an unarchived version by case-insensitive name
+fn find_version<'a>(
+    versions: &'a [ProjectVersion],
+    name: &str,
+    project_key: &str,
+) -> JiraMcpResult<&'a ProjectVersion> {
+    let name = name.trim();
+    let active: Vec<&ProjectVersion> = versions.iter().filter(|v| !v.archived).collect();
+    active
+        .iter()
+        .find(|version| version.name.eq_ignore_ascii_case(name))
+        .copied()
+        .ok_or_else(|| {
+            let available = if active.is_empty() {
+                "none".to_string()
+            } else {
+                active
+                    .iter()
+                    .map(|version| version.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            JiraMcpError::invalid_param(
+                "affects_versions",
+                format!(
+                    "Unknown version '{}' in project {}. Available: {}",
+                    name, project_key, available
+                ),
+            )
+        })
+}
+
+/// Explain a create rejected for specific fields, e.g. a field the project's
+/// screen requires or one that is not on the screen
+fn rejected_fields_error(message: &str) -> Option<JiraMcpError> {
+    let rejected = rejected_fields(message);
+    let (first, _) = rejected.first()?;
+
+    let details = rejected
+        .iter()
+        .map(|(field, reason)| {
+            let lower = reason.to_lowercase();
+            let hint = if lower.contains("required") {
+                match create_issue_param(field) {
+                    Some(param) => format!("required by the project's screen; set `{}`", param),
+                    None => format!(
+                        "required by the project's screen; set it in custom_fields as \"{}\"",
+                        field
+                    ),
+                }
+            } else if lower.contains("screen") || lower.contains("cannot be set") {
+                "not on the create screen for this issue type; leave it out".to_string()
+            } else {
+                "see get_create_metadata for allowed values".to_string()
+            };
+            format!("{} ({}: {})", field, reason, hint)
+        })
+        .collect::<Vec<_>>()
+        .join("; ");
+
+    Some(JiraMcpError::invalid_param(
+        create_issue_param(first).unwrap_or("custom_fields"),
+        format!("JIRA rejected the issue fields: {}", details),
+    ))
+}
+
+/// `"field": "reason"` pairs from JIRA's `errors` object in an error message
+///
+/// Works on both the JSON body and its debug rendering, which differ in spacing.
+fn rejected_fields(message: &str) -> Vec<(String, String)> {
+    let mut fields = Vec::new();
+    let mut rest = message;
+    while let Some(start) = rest.find('"') {
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('"') else {
+            break;
+        };
+        let key = &after[..end];
+        rest = &after[end + 1..];
+
+        let Some(value) = rest
+            .trim_start()
+            .strip_prefix(':')
+            .and_then(|tail| tail.trim_start().strip_prefix('"'))
+        else {
+            continue;
+        };
+        let Some(value_end) = value.find('"') else {
+            break;
+        };
+        if !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            fields.push((key.to_string(), value[..value_end].to_string()));
+        }
+        rest = &value[value_end + 1..];
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::todo_tracker::TodoTracker;
+
+    #[test]
+    fn test_initial_todos_round_trip() {
+        let todos: Vec<InitialTodo> = serde_json::from_value(serde_json::json!([
+            "Write plan",
+            {"text": "Add endpoint", "completed": true, "section": "Backend"},
+            {"text": "Add migration", "section": "Backend"},
+            {"text": "Review plan", "completed": true},
+            {"text": "Wire up form", "section": " Frontend "}
+        ]))
+        .unwrap();
+
+        let todo_section = render_initial_todos(&todos).unwrap();
+        assert_eq!(
+            todo_section,
+            "## Tasks\n\n- [ ] Write plan\n- [x] Review plan\n\n\
+             ## Backend\n\n- [x] Add endpoint\n- [ ] Add migration\n\n\
+             ## Frontend\n\n- [ ] Wire up form"
+        );
+
+        let description = format!("Imported plan\n\n{}", todo_section);
+        let parsed: Vec<_> = TodoTracker::parse_todos(&description)
+            .into_iter()
+            .map(|todo| {
+                (
+                    todo.text,
+                    todo.completed,
+                    todo.section.unwrap(),
+                    todo.line_number,
+                )
+            })
+            .collect();
+        let expected = [
+            ("Write plan", false, "Tasks", 4),
+            ("Review plan", true, "Tasks", 5),
+            ("Add endpoint", true, "Backend", 9),
+            ("Add migration", false, "Backend", 10),
+            ("Wire up form", false, "Frontend", 14),
+        ];
+        assert_eq!(parsed.len(), expected.len());
+        for (todo, (text, completed, section, line)) in parsed.iter().zip(expected) {
+            assert_eq!(todo, &(text.into(), completed, section.into(), line));
+        }
+
+        let error = render_initial_todos(&[InitialTodo::Text("  ".into())])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("initial_todos"), "{}", error);
+    }
+
+    #[test]
+    fn test_rejected_fields() {
+        let json = r#"{"errorMessages":[],"errors":{"environment":"Environment is required.","customfield_10200":"Team is required."}}"#;
+        assert_eq!(
+            rejected_fields(json),
+            vec![
+                (
+                    "environment".to_string(),
+                    "Environment is required.".to_string()
+                ),
+                (
+                    "customfield_10200".to_string(),
+                    "Team is required.".to_string()
+                ),
+            ]
+        );
+
+        let debug = "Jira Client Error (400 Bad Request):\nErrors {\n    error_messages: [\"x\"],\n    errors: {\n        \"duedate\": \"Field 'duedate' cannot be set. It is not on the appropriate screen, or unknown.\",\n    },\n}";
+        assert_eq!(rejected_fields(debug)[0].0, "duedate");
+        assert!(rejected_fields("Failed: connection reset").is_empty());
+
+        let error = rejected_fields_error(json).unwrap().to_string();
+        assert!(
+            error.contains("Invalid parameter: environment"),
+            "{}",
+            error
+        );
+        assert!(error.contains("set `environment`"), "{}", error);
+        assert!(
+            error.contains("custom_fields as \"customfield_10200\""),
+            "{}",
+            error
+        );
+        let error = rejected_fields_error(debug).unwrap().to_string();
+        assert!(error.contains("leave it out"), "{}", error);
+    }
+
+    #[test]
+    fn test_project_defaults() {
+        let defaults = ProjectDefaults {
+            issue_type: Some("Bug".to_string()),
+            priority: Some("High".to_string()),
+            assignee: Some("me".to_string()),
+            components: vec!["backend".to_string(), "infra".to_string()],
+            custom_fields: HashMap::from([
+                ("customfield_1".to_string(), serde_json::json!("a")),
+                ("customfield_2".to_string(), serde_json::json!("b")),
+            ]),
+            ..ProjectDefaults::default()
+        };
+        let mut params: CreateIssueParams = serde_json::from_value(serde_json::json!({
+            "summary": "Subtask",
+            "parent_issue_key": "PROJ-1",
+            "priority": "Low",
+            "assign_to_me": true,
+            "custom_fields": {"customfield_1": "explicit"}
+        }))
+        .unwrap();
+
+        let applied = apply_project_defaults(&mut params, &defaults);
+        assert_eq!(
+            applied.keys().collect::<Vec<_>>(),
+            ["components", "custom_fields"]
+        );
+        assert_eq!(
+            applied["custom_fields"],
+            serde_json::json!({"customfield_2": "b"})
+        );
+        assert_eq!(params.issue_type, None);
+        assert_eq!(params.priority.as_deref(), Some("Low"));
+        assert_eq!(params.assignee, None);
+        assert_eq!(params.custom_fields["customfield_1"], "explicit");
+
+        let fields = serde_json::json!({
+            "components": {"allowedValues": [{"name": "Backend"}]},
+            "customfield_1": {}
+        });
+        let problems = project_default_problems(&defaults, fields.as_object().unwrap());
+        assert_eq!(
+            problems,
+            vec![
+                (
+                    "assignee".to_string(),
+                    "assignee is not on the create screen".to_string()
+                ),
+                (
+                    "components".to_string(),
+                    "Unknown component 'infra'. Available: Backend".to_string()
+                ),
+                (
+                    "custom_fields.customfield_2".to_string(),
+                    "customfield_2 is not on the create screen".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_version() {
+        let version = |id: &str, name: &str, archived| ProjectVersion {
+            id: id.to_string(),
+            name: name.to_string(),
+            released: false,
+            archived,
+        };
+        let versions = vec![version("1", "2.0", false), version("2", "1.0", true)];
+
+        assert_eq!(find_version(&versions, " 2.0 ", "PROJ").unwrap().id, "1");
+        let error = find_version(&versions, "1.0", "PROJ")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("Available: 2.0"), "{}", error);
+    }
 }
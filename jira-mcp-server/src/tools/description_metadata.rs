@@ -0,0 +1,895 @@
+//! Metadata tables in issue descriptions
+//!
+//! Many teams keep structured data in a description table, e.g. a wiki markup
+//! `|| Key || Value ||` table or its markdown equivalent. get_description_metadata
+//! reads every table as rows keyed by the header row, and
+//! update_description_metadata rewrites one cell, found by table index and row
+//! key, keeping the rest of the description byte for byte (ADF descriptions on
+//! Cloud are edited as table nodes). Rows that cannot be read are reported one
+//! by one instead of failing the whole table.
+
+use crate::adf;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_key;
+use crate::tools::update_description::{EditOptions, UpdateDescription};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::ops::Range;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// Parameters for the get_description_metadata tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetDescriptionMetadataParams {
+    /// The JIRA issue key (e.g., "PROJ-123")
+    pub issue_key: String,
+}
+
+/// Parameters for the update_description_metadata tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateDescriptionMetadataParams {
+    /// The JIRA issue key (e.g., "PROJ-123")
+    pub issue_key: String,
+
+    /// Index of the table, as listed by get_description_metadata (first table: 0)
+    pub table_index: usize,
+
+    /// Value in the row's first column, matched case-insensitively
+    /// Examples: "Owner", "Release"
+    pub row_key: String,
+
+    /// Header of the column to change, matched case-insensitively
+    /// (optional, default: the second column)
+    pub column: Option<String>,
+
+    /// The new cell text, on one line
+    pub value: String,
+
+    /// Preview only: return the new description and a diff without writing (default: false)
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Include a unified diff of the change in the result (default: false; always on for dry_run)
+    #[serde(default)]
+    pub include_diff: bool,
+
+    /// Only write if the issue's `updated` timestamp still equals this value
+    /// (as returned by get_issue_details)
+    #[serde(default)]
+    pub expected_updated: Option<String>,
+}
+
+/// Markup a table was written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TableFormat {
+    /// `| a | b |` with a `|---|---|` line under the header
+    Markdown,
+    /// `|| a || b ||` header, `| a | b |` rows
+    Wiki,
+    /// Table node of an Atlassian Document Format description
+    Adf,
+}
+
+/// A table of the description
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetadataTable {
+    /// Position among the description's tables, starting at 0
+    pub index: usize,
+
+    pub format: TableFormat,
+
+    /// Line of the header row (text descriptions), starting at 1
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+
+    /// Column names, from the header row
+    pub headers: Vec<String>,
+
+    /// Rows below the header, keyed by column name
+    pub rows: Vec<BTreeMap<String, String>>,
+}
+
+/// A table row that could not be read
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TableProblem {
+    /// Index of the table
+    pub table: usize,
+
+    /// Row within the table, starting at 1 for the header row
+    pub row: usize,
+
+    /// Line in the description (text descriptions), starting at 1
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+
+    pub message: String,
+}
+
+/// Result from the get_description_metadata tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetDescriptionMetadataResult {
+    pub issue_key: String,
+
+    /// Tables in the order they appear
+    pub tables: Vec<MetadataTable>,
+
+    /// Rows and tables that were left out, with the reason
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub problems: Vec<TableProblem>,
+}
+
+/// Result from the update_description_metadata tool
+#[derive(Debug, Serialize)]
+pub struct UpdateDescriptionMetadataResult {
+    pub success: bool,
+    pub issue_key: String,
+    pub table_index: usize,
+
+    /// The row key and column as they appear in the table
+    pub row_key: String,
+    pub column: String,
+
+    /// The cell text before the change
+    pub previous_value: String,
+
+    pub value: String,
+
+    /// The new description (for confirmation)
+    pub new_description: String,
+
+    /// True when nothing was written (dry_run)
+    pub dry_run: bool,
+
+    /// Unified diff from the current to the new description (dry_run or include_diff)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+}
+
+/// Implementation of get_description_metadata and update_description_metadata
+pub struct DescriptionMetadataTool {
+    jira_client: Arc<JiraClient>,
+    update_description: Arc<UpdateDescription>,
+}
+
+impl DescriptionMetadataTool {
+    pub fn new(jira_client: Arc<JiraClient>, update_description: Arc<UpdateDescription>) -> Self {
+        Self {
+            jira_client,
+            update_description,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_metadata(
+        &self,
+        params: GetDescriptionMetadataParams,
+    ) -> JiraMcpResult<GetDescriptionMetadataResult> {
+        let issue_key =
+            normalize_issue_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        info!("Reading description tables of {}", issue_key);
+
+        let fields = self
+            .jira_client
+            .get_issue_fields(&issue_key, &["description".to_string()])
+            .await?;
+        let description = fields.get("description").cloned().unwrap_or(Value::Null);
+
+        let (tables, problems) = parse_tables(&description);
+        Ok(GetDescriptionMetadataResult {
+            issue_key,
+            tables: tables.iter().map(ParsedTable::to_metadata).collect(),
+            problems,
+        })
+    }
+
+    #[instrument(skip(self), fields(issue_key = %params.issue_key))]
+    pub async fn update_metadata(
+        &self,
+        mut params: UpdateDescriptionMetadataParams,
+    ) -> JiraMcpResult<UpdateDescriptionMetadataResult> {
+        params.issue_key =
+            normalize_issue_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+        if params.value.contains('\n') {
+            return Err(JiraMcpError::invalid_param(
+                "value",
+                "A table cell holds a single line; remove the line breaks",
+            ));
+        }
+
+        info!(
+            "Updating row '{}' of table {} in the description of {}",
+            params.row_key, params.table_index, params.issue_key
+        );
+
+        let mut outcome = CellEdit::default();
+        let edit = self
+            .update_description
+            .edit_description(
+                &params.issue_key,
+                EditOptions {
+                    expected_updated: params.expected_updated.as_deref(),
+                    dry_run: params.dry_run,
+                    include_diff: params.include_diff,
+                    auto_truncate: false,
+                },
+                "update_description_metadata",
+                |current| {
+                    let (value, cell) = edit_cell(
+                        current,
+                        params.table_index,
+                        &params.row_key,
+                        params.column.as_deref(),
+                        &params.value,
+                    )?;
+                    outcome = cell;
+                    Ok(value)
+                },
+            )
+            .await?;
+
+        Ok(UpdateDescriptionMetadataResult {
+            success: true,
+            issue_key: params.issue_key,
+            table_index: params.table_index,
+            row_key: outcome.row_key,
+            column: outcome.column,
+            previous_value: outcome.previous_value,
+            value: params.value,
+            new_description: edit.new_description,
+            dry_run: params.dry_run,
+            diff: edit.diff,
+        })
+    }
+}
+
+/// Where a cell's text lives in the description
+#[derive(Debug, Clone)]
+enum CellPosition {
+    /// Byte range in a text description; for an empty cell, the whole space
+    /// between its pipes
+    Text { span: Range<usize>, empty: bool },
+    /// Indexes of the table node, row and cell in an ADF document
+    Adf {
+        node: usize,
+        row: usize,
+        cell: usize,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Cell {
+    text: String,
+    position: CellPosition,
+}
+
+#[derive(Debug)]
+struct ParsedTable {
+    index: usize,
+    format: TableFormat,
+    line: Option<usize>,
+    headers: Vec<String>,
+    rows: Vec<Vec<Cell>>,
+}
+
+impl ParsedTable {
+    fn to_metadata(&self) -> MetadataTable {
+        MetadataTable {
+            index: self.index,
+            format: self.format,
+            line: self.line,
+            headers: self.headers.clone(),
+            rows: self
+                .rows
+                .iter()
+                .map(|row| {
+                    self.headers
+                        .iter()
+                        .cloned()
+                        .zip(row.iter().map(|cell| cell.text.clone()))
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Which cell was edited
+#[derive(Debug, Default)]
+struct CellEdit {
+    row_key: String,
+    column: String,
+    previous_value: String,
+}
+
+/// Tables of a description, with the rows that could not be read
+fn parse_tables(description: &Value) -> (Vec<ParsedTable>, Vec<TableProblem>) {
+    if adf::is_doc(description) {
+        adf_tables(description)
+    } else {
+        text_tables(description.as_str().unwrap_or_default())
+    }
+}
+
+/// Header names, with empty and repeated names made unique
+fn header_names(
+    cells: &[Cell],
+    table: usize,
+    line: Option<usize>,
+    problems: &mut Vec<TableProblem>,
+) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    for (i, cell) in cells.iter().enumerate() {
+        let mut name = if cell.text.is_empty() {
+            format!("column {}", i + 1)
+        } else {
+            cell.text.clone()
+        };
+        if names.contains(&name) {
+            problems.push(TableProblem {
+                table,
+                row: 1,
+                line,
+                message: format!(
+                    "header '{}' is repeated; column {} is named '{} ({})'",
+                    name,
+                    i + 1,
+                    name,
+                    i + 1
+                ),
+            });
+            name = format!("{} ({})", name, i + 1);
+        }
+        names.push(name);
+    }
+    names
+}
+
+/// Rows with as many cells as the header; the others are reported
+fn table_rows(
+    rows: Vec<(Option<usize>, Vec<Cell>)>,
+    columns: usize,
+    table: usize,
+    problems: &mut Vec<TableProblem>,
+) -> Vec<Vec<Cell>> {
+    let mut kept = Vec::new();
+    for (i, (line, cells)) in rows.into_iter().enumerate() {
+        if cells.len() == columns {
+            kept.push(cells);
+        } else {
+            problems.push(TableProblem {
+                table,
+                row: i + 2,
+                line,
+                message: format!(
+                    "row has {} cells but the header has {}; left out",
+                    cells.len(),
+                    columns
+                ),
+            });
+        }
+    }
+    kept
+}
+
+/// Tables of a wiki markup or markdown description, ignoring code blocks
+fn text_tables(text: &str) -> (Vec<ParsedTable>, Vec<TableProblem>) {
+    let mut groups: Vec<Vec<(usize, usize, &str)>> = Vec::new();
+    let mut in_code = false;
+    let mut in_table = false;
+    let mut offset = 0;
+
+    for (number, line) in text.split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += line.len();
+        let trimmed = line.trim();
+        // {code}/{noformat} open and close with the same tag
+        let wiki_tags = trimmed.matches("{code").count() + trimmed.matches("{noformat").count();
+        if trimmed.starts_with("```") || wiki_tags % 2 == 1 {
+            in_code = !in_code;
+            in_table = false;
+        } else if !in_code && trimmed.starts_with('|') {
+            if !in_table {
+                groups.push(Vec::new());
+            }
+            if let Some(group) = groups.last_mut() {
+                group.push((number + 1, start, line));
+            }
+            in_table = true;
+        } else {
+            in_table = false;
+        }
+    }
+
+    let mut tables = Vec::new();
+    let mut problems = Vec::new();
+    for group in groups {
+        let index = tables.len();
+        let (header_line, header_start, header) = group[0];
+        let (format, body) = if header.trim_start().starts_with("||") {
+            (TableFormat::Wiki, &group[1..])
+        } else if group.get(1).is_some_and(|(_, _, line)| is_separator(line)) {
+            (TableFormat::Markdown, &group[2..])
+        } else {
+            problems.push(TableProblem {
+                table: index,
+                row: 1,
+                line: Some(header_line),
+                message: "table has no header row (start it with '|| Name || Value ||' or put \
+                          a '|---|---|' line under the first row); left out"
+                    .to_string(),
+            });
+            // Keep the numbering stable for update_description_metadata
+            tables.push(ParsedTable {
+                index,
+                format: TableFormat::Wiki,
+                line: Some(header_line),
+                headers: Vec::new(),
+                rows: Vec::new(),
+            });
+            continue;
+        };
+        let wiki = format == TableFormat::Wiki;
+
+        let header_cells = split_cells(header, header_start, wiki);
+        let headers = header_names(&header_cells, index, Some(header_line), &mut problems);
+        let rows = body
+            .iter()
+            .map(|(line, start, text)| (Some(*line), split_cells(text, *start, wiki)))
+            .collect();
+        let rows = table_rows(rows, headers.len(), index, &mut problems);
+
+        tables.push(ParsedTable {
+            index,
+            format,
+            line: Some(header_line),
+            headers,
+            rows,
+        });
+    }
+
+    (tables, problems)
+}
+
+/// A markdown `|---|:--:|` line
+fn is_separator(line: &str) -> bool {
+    let line = line.trim();
+    line.starts_with('|')
+        && line.contains('-')
+        && line
+            .chars()
+            .all(|c| matches!(c, '|' | '-' | ':' | ' ' | '\t'))
+}
+
+/// Cells of a table row starting at byte `offset` of the description
+///
+/// In wiki markup `||` separates header cells, and pipes inside `[link|url]`
+/// or `{macro|param}` do not split; in markdown `\|` is an escaped pipe.
+fn split_cells(line: &str, offset: usize, wiki: bool) -> Vec<Cell> {
+    let content = line.trim_end();
+    let bytes = content.as_bytes();
+    let mut delimiters: Vec<Range<usize>> = Vec::new();
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' if !wiki => i += 1,
+            b'[' | b'{' if wiki => depth += 1,
+            b']' | b'}' if wiki => depth = depth.saturating_sub(1),
+            b'|' if depth == 0 => {
+                let end = if wiki && bytes.get(i + 1) == Some(&b'|') {
+                    i + 2
+                } else {
+                    i + 1
+                };
+                delimiters.push(i..end);
+                i = end;
+                continue;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    let mut cells = Vec::new();
+    for (n, delimiter) in delimiters.iter().enumerate() {
+        let raw = delimiter.end
+            ..delimiters
+                .get(n + 1)
+                .map_or(content.len(), |next| next.start);
+        let segment = &content[raw.clone()];
+        let is_last = n + 1 == delimiters.len();
+        if is_last && segment.trim().is_empty() {
+            break;
+        }
+
+        let text = segment.trim();
+        let leading = segment.len() - segment.trim_start().len();
+        let position = if text.is_empty() {
+            CellPosition::Text {
+                span: offset + raw.start..offset + raw.end,
+                empty: true,
+            }
+        } else {
+            let start = offset + raw.start + leading;
+            CellPosition::Text {
+                span: start..start + text.len(),
+                empty: false,
+            }
+        };
+        let text = if wiki {
+            text.to_string()
+        } else {
+            text.replace("\\|", "|")
+        };
+        cells.push(Cell { text, position });
+    }
+    cells
+}
+
+/// Top-level table nodes of an ADF description
+fn adf_tables(doc: &Value) -> (Vec<ParsedTable>, Vec<TableProblem>) {
+    let mut tables = Vec::new();
+    let mut problems = Vec::new();
+    let nodes = doc
+        .get("content")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    for (node_index, node) in nodes.iter().enumerate() {
+        if node.get("type").and_then(Value::as_str) != Some("table") {
+            continue;
+        }
+        let index = tables.len();
+        let mut rows: Vec<(Option<usize>, Vec<Cell>)> = node
+            .get("content")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| row.get("type").and_then(Value::as_str) == Some("tableRow"))
+            .map(|(row_index, row)| {
+                let cells = row
+                    .get("content")
+                    .and_then(Value::as_array)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+                    .iter()
+                    .enumerate()
+                    .map(|(cell_index, cell)| Cell {
+                        text: adf::node_text(cell).trim().to_string(),
+                        position: CellPosition::Adf {
+                            node: node_index,
+                            row: row_index,
+                            cell: cell_index,
+                        },
+                    })
+                    .collect();
+                (None, cells)
+            })
+            .collect();
+
+        if rows.is_empty() {
+            problems.push(TableProblem {
+                table: index,
+                row: 1,
+                line: None,
+                message: "table has no rows".to_string(),
+            });
+            rows.push((None, Vec::new()));
+        }
+        let (_, header_cells) = rows.remove(0);
+        let headers = header_names(&header_cells, index, None, &mut problems);
+        let rows = table_rows(rows, headers.len(), index, &mut problems);
+
+        tables.push(ParsedTable {
+            index,
+            format: TableFormat::Adf,
+            line: None,
+            headers,
+            rows,
+        });
+    }
+
+    (tables, problems)
+}
+
+/// Set one cell of the stored description, keeping everything else
+fn edit_cell(
+    current: &Value,
+    table_index: usize,
+    row_key: &str,
+    column: Option<&str>,
+    value: &str,
+) -> JiraMcpResult<(Value, CellEdit)> {
+    let (tables, problems) = parse_tables(current);
+    let Some(table) = tables.get(table_index) else {
+        return Err(JiraMcpError::invalid_param(
+            "table_index",
+            format!(
+                "The description has {} table(s); table_index starts at 0",
+                tables.len()
+            ),
+        ));
+    };
+
+    let column_index = match column {
+        Some(column) => table
+            .headers
+            .iter()
+            .position(|header| header.trim().eq_ignore_ascii_case(column.trim()))
+            .ok_or_else(|| {
+                JiraMcpError::invalid_param(
+                    "column",
+                    format!(
+                        "No column '{}' in table {} (columns: {})",
+                        column,
+                        table_index,
+                        table.headers.join(", ")
+                    ),
+                )
+            })?,
+        None if table.headers.len() >= 2 => 1,
+        None => {
+            return Err(JiraMcpError::invalid_param(
+                "column",
+                format!(
+                    "Table {} has fewer than two columns; name the column to change",
+                    table_index
+                ),
+            ))
+        }
+    };
+
+    let key = row_key.trim().to_lowercase();
+    let matches: Vec<&Vec<Cell>> = table
+        .rows
+        .iter()
+        .filter(|row| row[0].text.to_lowercase() == key)
+        .collect();
+    let row = match matches.as_slice() {
+        [row] => *row,
+        [] => {
+            let skipped = problems
+                .iter()
+                .filter(|problem| problem.table == table_index)
+                .count();
+            let mut message = format!(
+                "No row '{}' in table {} (rows: {})",
+                row_key,
+                table_index,
+                table
+                    .rows
+                    .iter()
+                    .map(|row| row[0].text.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            if skipped > 0 {
+                message.push_str(&format!(
+                    "; {} row(s) could not be read, see get_description_metadata",
+                    skipped
+                ));
+            }
+            return Err(JiraMcpError::invalid_param("row_key", message));
+        }
+        _ => {
+            return Err(JiraMcpError::invalid_param(
+                "row_key",
+                format!(
+                    "'{}' is the key of {} rows in table {}; make the keys unique first",
+                    row_key,
+                    matches.len(),
+                    table_index
+                ),
+            ))
+        }
+    };
+
+    let cell = &row[column_index];
+    let outcome = CellEdit {
+        row_key: row[0].text.clone(),
+        column: table.headers[column_index].clone(),
+        previous_value: cell.text.clone(),
+    };
+
+    let new_value = match &cell.position {
+        CellPosition::Text { span, empty } => {
+            let text = current.as_str().unwrap_or_default();
+            let wiki = table.format == TableFormat::Wiki;
+            let written = if wiki {
+                value.to_string()
+            } else {
+                value.replace('|', "\\|")
+            };
+            if split_cells(&format!("|{}|", written), 0, wiki).len() > 1 {
+                return Err(JiraMcpError::invalid_param(
+                    "value",
+                    "A wiki markup cell cannot contain '|' outside [links] or {macros}",
+                ));
+            }
+            let written = if *empty {
+                format!(" {} ", written)
+            } else {
+                written
+            };
+            let mut new_text = text.to_string();
+            new_text.replace_range(span.clone(), &written);
+            Value::String(new_text)
+        }
+        CellPosition::Adf { node, row, cell } => {
+            let mut doc = current.clone();
+            let mut content = adf::paragraphs(value);
+            if content.is_empty() {
+                content.push(serde_json::json!({ "type": "paragraph", "content": [] }));
+            }
+            doc["content"][*node]["content"][*row]["content"][*cell]["content"] =
+                Value::Array(content);
+            doc
+        }
+    };
+
+    Ok((new_value, outcome))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const WIKI: &str = "h2. Release\n\
+                        \n\
+                        || Key || Value ||\n\
+                        | Owner | [~alice] |\n\
+                        | Docs | [Runbook|https://wiki.example.com/x] |\n\
+                        | Broken |\n\
+                        | Risk |   |\n\
+                        \n\
+                        {code}\n\
+                        | not | a | table |\n\
+                        {code}\n";
+
+    const MARKDOWN: &str = "Notes first.\n\
+                            \n\
+                            | Field | Value | Note |\n\
+                            |---|:---:|---|\n\
+                            | Owner | Bob | on call \\| backup |\n\
+                            | Émoji ✨ | 日本 | |\n";
+
+    fn rows(table: &MetadataTable) -> Vec<Vec<(&str, &str)>> {
+        table
+            .rows
+            .iter()
+            .map(|row| {
+                table
+                    .headers
+                    .iter()
+                    .map(|h| (h.as_str(), row[h].as_str()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_wiki_table() {
+        let (tables, problems) = parse_tables(&json!(WIKI));
+        assert_eq!(tables.len(), 1);
+        let table = tables[0].to_metadata();
+        assert_eq!(table.format, TableFormat::Wiki);
+        assert_eq!(table.line, Some(3));
+        assert_eq!(
+            rows(&table),
+            vec![
+                vec![("Key", "Owner"), ("Value", "[~alice]")],
+                vec![
+                    ("Key", "Docs"),
+                    ("Value", "[Runbook|https://wiki.example.com/x]")
+                ],
+                vec![("Key", "Risk"), ("Value", "")],
+            ]
+        );
+
+        // The short row is reported by itself
+        assert_eq!(problems.len(), 1);
+        assert_eq!((problems[0].row, problems[0].line), (4, Some(6)));
+        assert!(problems[0].message.contains("1 cells but the header has 2"));
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let (tables, problems) = parse_tables(&json!(MARKDOWN));
+        assert!(problems.is_empty(), "{:?}", problems);
+        let table = tables[0].to_metadata();
+        assert_eq!(table.format, TableFormat::Markdown);
+        assert_eq!(
+            rows(&table),
+            vec![
+                vec![
+                    ("Field", "Owner"),
+                    ("Value", "Bob"),
+                    ("Note", "on call | backup")
+                ],
+                vec![("Field", "Émoji ✨"), ("Value", "日本"), ("Note", "")],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_table_without_header_is_reported() {
+        let (tables, problems) = parse_tables(&json!("| a | b |\n| c | d |\n"));
+        assert!(tables[0].rows.is_empty());
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].message.contains("no header row"));
+    }
+
+    #[test]
+    fn test_edit_text_cell() {
+        let edit = |text: &str, key: &str, column: Option<&str>, value: &str| {
+            let (value, _) = edit_cell(&json!(text), 0, key, column, value).unwrap();
+            value.as_str().unwrap().to_string()
+        };
+
+        assert_eq!(
+            edit(WIKI, "owner", None, "[~bob]"),
+            WIKI.replace("| Owner | [~alice] |", "| Owner | [~bob] |")
+        );
+        assert_eq!(
+            edit(WIKI, "Risk", Some("value"), "low"),
+            WIKI.replace("| Risk |   |", "| Risk | low |")
+        );
+        assert_eq!(
+            edit(MARKDOWN, "émoji ✨", Some("Note"), "a|b"),
+            MARKDOWN.replace("| 日本 | |", "| 日本 | a\\|b |")
+        );
+
+        let error = edit_cell(&json!(WIKI), 0, "Owner", None, "a | b").unwrap_err();
+        assert!(error.to_string().contains("cannot contain '|'"));
+        let error = edit_cell(&json!(WIKI), 0, "Broken", None, "x").unwrap_err();
+        assert!(
+            error.to_string().contains("1 row(s) could not be read"),
+            "{}",
+            error
+        );
+        let error = edit_cell(&json!(WIKI), 1, "Owner", None, "x").unwrap_err();
+        assert!(error.to_string().contains("has 1 table(s)"));
+    }
+
+    #[test]
+    fn test_adf_table() {
+        let cell = |kind: &str, text: &str| json!({"type": kind, "content": [{"type": "paragraph", "content": [{"type": "text", "text": text}]}]});
+        let doc = json!({
+            "type": "doc",
+            "version": 1,
+            "content": [
+                {"type": "paragraph", "content": [{"type": "text", "text": "Intro"}]},
+                {"type": "table", "content": [
+                    {"type": "tableRow", "content": [cell("tableHeader", "Key"), cell("tableHeader", "Value")]},
+                    {"type": "tableRow", "content": [cell("tableCell", "Owner"), cell("tableCell", "Alice")]},
+                    {"type": "tableRow", "content": [cell("tableCell", "Orphan")]}
+                ]}
+            ]
+        });
+
+        let (tables, problems) = parse_tables(&doc);
+        assert_eq!(
+            rows(&tables[0].to_metadata()),
+            vec![vec![("Key", "Owner"), ("Value", "Alice")]]
+        );
+        assert_eq!((problems[0].row, problems[0].line), (3, None));
+
+        let (edited, outcome) = edit_cell(&doc, 0, "OWNER", None, "Bob").unwrap();
+        assert_eq!(outcome.previous_value, "Alice");
+        assert_eq!(outcome.row_key, "Owner");
+        let mut expected = doc.clone();
+        expected["content"][1]["content"][1]["content"][1] = cell("tableCell", "Bob");
+        assert_eq!(edited, expected);
+    }
+}
@@ -1,10 +1,10 @@
 //! Download attachment tool for retrieving attachment content
 //!
 //! This tool downloads the actual content of a JIRA attachment
-//! given its attachment ID or URL.
+//! given its attachment ID or its content URL.
 
 use crate::cache::MetadataCache;
-use crate::config::JiraConfig;
+use crate::config::{JiraConfig, OperationClass};
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::JiraClient;
 use base64::{engine::general_purpose, Engine as _};
@@ -18,9 +18,13 @@ use tracing::{info, warn};
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DownloadAttachmentParams {
-    /// Attachment ID (required)
+    /// Attachment ID (this or content_url is required)
     /// This can be obtained from the list_issue_attachments tool
-    pub attachment_id: String,
+    pub attachment_id: Option<String>,
+
+    /// Attachment content URL, as returned by list_issue_attachments
+    /// (this or attachment_id is required). Must point at the configured JIRA instance.
+    pub content_url: Option<String>,
 
     /// Whether to return content as base64 encoded string (optional, default: true)
     /// If false, will return binary content (not recommended for large files)
@@ -134,14 +138,14 @@ impl DownloadAttachmentTool {
         let start_time = std::time::Instant::now();
         let mut api_calls = 0u32;
 
+        // Validate parameters
+        let attachment_id = self.validate_params(&params)?;
+
         info!(
             "Executing download_attachment tool for attachment: {}",
-            params.attachment_id
+            attachment_id
         );
 
-        // Validate parameters
-        self.validate_params(&params)?;
-
         let base64_encoded = params.base64_encoded.unwrap_or(true);
         let max_size = params.max_size_bytes.unwrap_or(10 * 1024 * 1024); // 10MB default
 
@@ -150,7 +154,7 @@ impl DownloadAttachmentTool {
         let should_return_content = params.return_content.unwrap_or(!should_save);
 
         // First, get attachment metadata to check size and get info
-        let attachment_metadata = self.get_attachment_metadata(&params.attachment_id).await?;
+        let attachment_metadata = self.get_attachment_metadata(&attachment_id).await?;
         api_calls += 1;
 
         // Check file size
@@ -165,7 +169,7 @@ impl DownloadAttachmentTool {
         }
 
         // Download the actual content (raw bytes)
-        let content_bytes = self.download_content(&params.attachment_id).await?;
+        let content_bytes = self.download_content(&attachment_id).await?;
         api_calls += 1;
 
         let bytes_downloaded = content_bytes.len() as u64;
@@ -193,10 +197,12 @@ impl DownloadAttachmentTool {
             } else {
                 // Try to convert to UTF-8 string
                 Some(
-                    String::from_utf8(content_bytes.clone()).unwrap_or_else(|_| {
-                        // If not valid UTF-8, return base64 anyway
-                        general_purpose::STANDARD.encode(&content_bytes)
-                    }),
+                    self.jira_client
+                        .attachment_text(&content_bytes)
+                        .unwrap_or_else(|| {
+                            // If not valid UTF-8, return base64 anyway
+                            general_purpose::STANDARD.encode(&content_bytes)
+                        }),
                 )
             }
         } else {
@@ -212,7 +218,7 @@ impl DownloadAttachmentTool {
 
         info!(
             "Downloaded attachment {} ({} bytes) in {}ms",
-            params.attachment_id,
+            attachment_id,
             bytes_downloaded,
             duration.as_millis()
         );
@@ -264,15 +270,36 @@ impl DownloadAttachmentTool {
         })
     }
 
-    /// Validate download attachment parameters
-    fn validate_params(&self, params: &DownloadAttachmentParams) -> JiraMcpResult<()> {
-        // Validate attachment ID
-        if params.attachment_id.trim().is_empty() {
-            return Err(JiraMcpError::invalid_param(
-                "attachment_id",
-                "Attachment ID is required. Use the list_issue_attachments tool to get attachment IDs.",
-            ));
-        }
+    /// Validate download attachment parameters, returning the attachment ID
+    fn validate_params(&self, params: &DownloadAttachmentParams) -> JiraMcpResult<String> {
+        let attachment_id = match (&params.attachment_id, &params.content_url) {
+            (Some(_), Some(_)) => {
+                return Err(JiraMcpError::invalid_param(
+                    "content_url",
+                    "Provide either attachment_id or content_url, not both",
+                ));
+            }
+            (Some(id), None) if !id.trim().is_empty() => id.trim().to_string(),
+            (None, Some(url)) => {
+                crate::jira_client::attachment_id_from_url(self.jira_client.base_url(), url)
+                    .ok_or_else(|| {
+                        JiraMcpError::invalid_param(
+                            "content_url",
+                            format!(
+                                "'{}' is not an attachment content URL of the configured JIRA instance ({})",
+                                url,
+                                self.jira_client.base_url()
+                            ),
+                        )
+                    })?
+            }
+            _ => {
+                return Err(JiraMcpError::invalid_param(
+                    "attachment_id",
+                    "Attachment ID is required. Use the list_issue_attachments tool to get attachment IDs or content URLs.",
+                ));
+            }
+        };
 
         // Validate max size
         if let Some(max_size) = params.max_size_bytes {
@@ -291,7 +318,7 @@ impl DownloadAttachmentTool {
             }
         }
 
-        Ok(())
+        Ok(attachment_id)
     }
 
     /// Get attachment metadata using gouqi API
@@ -299,12 +326,15 @@ impl DownloadAttachmentTool {
         &self,
         attachment_id: &str,
     ) -> JiraMcpResult<AttachmentMetadata> {
-        // Use gouqi's attachments().get() to fetch metadata
-        let attachment = self
+        let attachment: gouqi::AttachmentResponse = self
             .jira_client
-            .client
-            .attachments()
-            .get(attachment_id)
+            .get_json(
+                OperationClass::Read,
+                &format!("reading attachment {}", attachment_id),
+                "api",
+                &format!("/attachment/{}", attachment_id),
+                JiraMcpError::from,
+            )
             .await?;
 
         Ok(AttachmentMetadata {
@@ -317,22 +347,9 @@ impl DownloadAttachmentTool {
         })
     }
 
-    /// Download attachment content as raw bytes using gouqi API
+    /// Download attachment content as raw bytes
     async fn download_content(&self, attachment_id: &str) -> JiraMcpResult<Vec<u8>> {
-        // Use gouqi's attachments().download() to fetch raw bytes
-        // Use spawn_blocking with sync client to work around Send issues
-        let jira_url = self.config.jira_url.clone();
-        let credentials = self.config.to_gouqi_credentials();
-        let id = attachment_id.to_string();
-
-        let content_bytes = tokio::task::spawn_blocking(move || {
-            let sync_client = gouqi::Jira::new(&jira_url, credentials)?;
-            sync_client.attachments().download(&id)
-        })
-        .await
-        .map_err(|e| JiraMcpError::internal(format!("Task join error: {}", e)))??;
-
-        Ok(content_bytes)
+        self.jira_client.download_attachment(attachment_id).await
     }
 
     /// Validate and prepare filesystem path for saving
@@ -395,7 +412,8 @@ mod tests {
     #[allow(dead_code)]
     fn create_test_params() -> DownloadAttachmentParams {
         DownloadAttachmentParams {
-            attachment_id: "12345".to_string(),
+            attachment_id: Some("12345".to_string()),
+            content_url: None,
             base64_encoded: Some(true),
             max_size_bytes: Some(1024 * 1024), // 1MB
             save_to_path: None,
@@ -0,0 +1,533 @@
+//! Estimate rollup across an issue's children
+//!
+//! Sums original estimate, remaining estimate, logged time and story points
+//! over everything below an epic or a parent task: epic children, subtasks
+//! and, up to `max_depth` levels down, their children in turn. Each level is
+//! fetched with one search per parent, run concurrently. The parent's own
+//! values are reported next to the rollup rather than added to it, since
+//! JIRA's own `timetracking` of a parent does not include its children.
+//! Children without an estimate or story points are counted, not guessed.
+
+use crate::config::JiraConfig;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::{IssueInfo, JiraClient, PagedIssues};
+use crate::jql;
+use crate::tools::issue_key::normalize_issue_key;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tracing::{info, instrument};
+
+const DEFAULT_MAX_DEPTH: usize = 2;
+const MAX_DEPTH: usize = 5;
+
+/// Children counted per rollup
+const MAX_ROLLUP_ISSUES: usize = 500;
+
+/// Child searches in flight at once
+const MAX_CONCURRENT_FETCHES: usize = 5;
+
+/// Parameters for the get_estimate_rollup tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetEstimateRollupParams {
+    /// Epic or parent issue (e.g. "PROJ-123")
+    pub issue_key: String,
+
+    /// Levels of children to follow, 1 to 5 (default: 2, enough for
+    /// epic → story → subtask)
+    pub max_depth: Option<usize>,
+}
+
+/// Estimates of a single issue, as set in JIRA
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EstimateValues {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub original_estimate_seconds: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_estimate_seconds: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_spent_seconds: Option<u64>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub story_points: Option<f64>,
+}
+
+impl EstimateValues {
+    fn from_issue(issue: &IssueInfo) -> Self {
+        let tracking = issue.time_tracking.as_ref();
+        Self {
+            original_estimate_seconds: tracking.and_then(|t| t.original_estimate_seconds),
+            remaining_estimate_seconds: tracking.and_then(|t| t.remaining_estimate_seconds),
+            time_spent_seconds: tracking.and_then(|t| t.time_spent_seconds),
+            story_points: issue.story_points,
+        }
+    }
+
+    /// Whether an original or a remaining estimate is set
+    fn has_estimate(&self) -> bool {
+        self.original_estimate_seconds.is_some() || self.remaining_estimate_seconds.is_some()
+    }
+}
+
+/// Estimates summed over a set of issues
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EstimateTotals {
+    pub issue_count: usize,
+
+    pub original_estimate_seconds: u64,
+    /// original_estimate_seconds in JIRA notation, e.g. "2w 3d"
+    pub original_estimate: String,
+
+    pub remaining_estimate_seconds: u64,
+    pub remaining_estimate: String,
+
+    pub time_spent_seconds: u64,
+    pub time_spent: String,
+
+    pub story_points: f64,
+
+    /// Issues with neither an original nor a remaining estimate
+    pub without_estimate: usize,
+
+    /// Issues without story points
+    pub without_story_points: usize,
+}
+
+impl EstimateTotals {
+    fn add(&mut self, values: &EstimateValues) {
+        self.issue_count += 1;
+        self.original_estimate_seconds += values.original_estimate_seconds.unwrap_or(0);
+        self.remaining_estimate_seconds += values.remaining_estimate_seconds.unwrap_or(0);
+        self.time_spent_seconds += values.time_spent_seconds.unwrap_or(0);
+        self.story_points += values.story_points.unwrap_or(0.0);
+        if !values.has_estimate() {
+            self.without_estimate += 1;
+        }
+        if values.story_points.is_none() {
+            self.without_story_points += 1;
+        }
+    }
+
+    fn merge(&mut self, other: &EstimateTotals) {
+        self.issue_count += other.issue_count;
+        self.original_estimate_seconds += other.original_estimate_seconds;
+        self.remaining_estimate_seconds += other.remaining_estimate_seconds;
+        self.time_spent_seconds += other.time_spent_seconds;
+        self.story_points += other.story_points;
+        self.without_estimate += other.without_estimate;
+        self.without_story_points += other.without_story_points;
+    }
+
+    /// Fill in the durations in JIRA notation
+    fn finished(mut self) -> Self {
+        self.original_estimate = jira_duration(self.original_estimate_seconds);
+        self.remaining_estimate = jira_duration(self.remaining_estimate_seconds);
+        self.time_spent = jira_duration(self.time_spent_seconds);
+        self
+    }
+}
+
+/// One issue below the rolled-up parent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChildEstimate {
+    pub key: String,
+    pub summary: String,
+    pub issue_type: String,
+    pub status: String,
+
+    /// Issue this one was found under
+    pub parent_key: String,
+
+    /// 1 for direct children, 2 for their children, and so on
+    pub depth: usize,
+
+    /// The issue's own estimates
+    #[serde(flatten)]
+    pub estimate: EstimateValues,
+
+    /// Totals over this issue's own children, when it has any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rollup: Option<EstimateTotals>,
+}
+
+impl ChildEstimate {
+    fn new(issue: IssueInfo, parent_key: &str, depth: usize) -> Self {
+        Self {
+            estimate: EstimateValues::from_issue(&issue),
+            key: issue.key,
+            summary: issue.summary,
+            issue_type: issue.issue_type,
+            status: issue.status,
+            parent_key: parent_key.to_string(),
+            depth,
+            rollup: None,
+        }
+    }
+}
+
+/// Result from the get_estimate_rollup tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetEstimateRollupResult {
+    pub issue_key: String,
+    pub summary: String,
+    pub issue_type: String,
+
+    /// The parent's own estimates, not included in `rollup`
+    pub own: EstimateValues,
+
+    /// Totals over all children found, at every depth
+    pub rollup: EstimateTotals,
+
+    /// Every child found, level by level; entries with children of their own
+    /// carry a rollup of those
+    pub children: Vec<ChildEstimate>,
+
+    pub max_depth: usize,
+
+    /// Deepest level that had children
+    pub depth_reached: usize,
+
+    /// True when more than 500 children were found and the rest were left out
+    pub truncated: bool,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Implementation of the get_estimate_rollup tool
+pub struct GetEstimateRollupTool {
+    jira_client: Arc<JiraClient>,
+    config: Arc<JiraConfig>,
+}
+
+impl GetEstimateRollupTool {
+    pub fn new(jira_client: Arc<JiraClient>, config: Arc<JiraConfig>) -> Self {
+        Self {
+            jira_client,
+            config,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: GetEstimateRollupParams,
+    ) -> JiraMcpResult<GetEstimateRollupResult> {
+        let issue_key = normalize_issue_key(&self.config.jira_url, &params.issue_key, "issue_key")?;
+        let max_depth = params.max_depth.unwrap_or(DEFAULT_MAX_DEPTH);
+        if !(1..=MAX_DEPTH).contains(&max_depth) {
+            return Err(JiraMcpError::invalid_param(
+                "max_depth",
+                format!("max_depth must be between 1 and {}", MAX_DEPTH),
+            ));
+        }
+
+        let parent = self
+            .jira_client
+            .get_issue_details(&issue_key, false, false, false)
+            .await?
+            .issue_info;
+
+        let mut seen = HashSet::from([parent.key.clone()]);
+        let mut children = Vec::new();
+        let mut level = vec![parent.key.clone()];
+        let mut depth_reached = 0;
+        let mut truncated = false;
+        for depth in 1..=max_depth {
+            if level.is_empty() || truncated {
+                break;
+            }
+            let remaining = MAX_ROLLUP_ISSUES - children.len();
+            if remaining == 0 {
+                truncated = true;
+                break;
+            }
+            let mut next = Vec::new();
+            for (parent_key, paged) in self.fetch_children(&level, remaining).await? {
+                truncated |= !paged.complete;
+                for child in new_children(&parent_key, depth, paged.issues, &mut seen) {
+                    next.push(child.key.clone());
+                    children.push(child);
+                }
+            }
+            if children.len() > MAX_ROLLUP_ISSUES {
+                children.truncate(MAX_ROLLUP_ISSUES);
+                truncated = true;
+            }
+            if !next.is_empty() {
+                depth_reached = depth;
+            }
+            level = next;
+        }
+
+        let rollup = roll_up(&parent.key, &mut children);
+        let mut warnings = Vec::new();
+        if truncated {
+            warnings.push(format!(
+                "Only the first {} children are counted; the rollup is incomplete",
+                MAX_ROLLUP_ISSUES
+            ));
+        }
+        if rollup.without_estimate > 0 {
+            warnings.push(format!(
+                "{} of {} children have no estimate and count as zero",
+                rollup.without_estimate, rollup.issue_count
+            ));
+        }
+
+        info!(
+            "Estimate rollup of {}: {} children over {} levels, {}s remaining",
+            parent.key, rollup.issue_count, depth_reached, rollup.remaining_estimate_seconds
+        );
+
+        Ok(GetEstimateRollupResult {
+            own: EstimateValues::from_issue(&parent),
+            issue_key: parent.key,
+            summary: parent.summary,
+            issue_type: parent.issue_type,
+            rollup,
+            children,
+            max_depth,
+            depth_reached,
+            truncated,
+            warnings,
+        })
+    }
+
+    /// Children of each of `parent_keys`, in the order of `parent_keys`
+    async fn fetch_children(
+        &self,
+        parent_keys: &[String],
+        cap: usize,
+    ) -> JiraMcpResult<Vec<(String, PagedIssues)>> {
+        let epic_link_field = self.jira_client.field_map().epic_link.is_present();
+        let mut pending = parent_keys.iter().cloned().enumerate();
+        let mut fetched = Vec::with_capacity(parent_keys.len());
+        let mut join_set = JoinSet::new();
+        loop {
+            while join_set.len() < MAX_CONCURRENT_FETCHES {
+                let Some((index, parent_key)) = pending.next() else {
+                    break;
+                };
+                let jira_client = Arc::clone(&self.jira_client);
+                join_set.spawn(async move {
+                    let jql = children_jql(&parent_key, epic_link_field);
+                    let result = jira_client.search_all_issues_jql(&jql, cap).await;
+                    (index, parent_key, result)
+                });
+            }
+
+            let Some(joined) = join_set.join_next().await else {
+                break;
+            };
+            let (index, parent_key, result) = joined
+                .map_err(|e| JiraMcpError::internal(format!("Child search task failed: {}", e)))?;
+            fetched.push((index, parent_key, result?));
+        }
+
+        fetched.sort_by_key(|(index, _, _)| *index);
+        Ok(fetched
+            .into_iter()
+            .map(|(_, parent_key, paged)| (parent_key, paged))
+            .collect())
+    }
+}
+
+/// JQL matching the subtasks and epic children of an issue
+///
+/// Subtasks, and epic children in team-managed projects, point at their
+/// parent through `parent`; epic children in company-managed projects through
+/// Epic Link, which is only queried when the instance has the field.
+fn children_jql(parent_key: &str, epic_link_field: bool) -> String {
+    let key = jql::quote(parent_key);
+    if epic_link_field {
+        format!(
+            "{} = {} OR {} = {} ORDER BY {} ASC",
+            jql::PARENT,
+            key,
+            jql::EPIC_LINK,
+            key,
+            jql::KEY
+        )
+    } else {
+        format!("{} = {} ORDER BY {} ASC", jql::PARENT, key, jql::KEY)
+    }
+}
+
+/// Children not seen before, so an issue reached twice is counted once
+fn new_children(
+    parent_key: &str,
+    depth: usize,
+    issues: Vec<IssueInfo>,
+    seen: &mut HashSet<String>,
+) -> Vec<ChildEstimate> {
+    issues
+        .into_iter()
+        .filter(|issue| seen.insert(issue.key.clone()))
+        .map(|issue| ChildEstimate::new(issue, parent_key, depth))
+        .collect()
+}
+
+/// Total the children below `root_key`, setting each child's own rollup
+///
+/// `children` must list parents before their children, as the level-by-level
+/// fetch does; walking it backwards then finishes every subtree before the
+/// issue above it.
+fn roll_up(root_key: &str, children: &mut [ChildEstimate]) -> EstimateTotals {
+    let mut subtrees: HashMap<String, EstimateTotals> = HashMap::new();
+    for child in children.iter_mut().rev() {
+        let mut subtree = subtrees.remove(&child.key).unwrap_or_default();
+        child.rollup = (subtree.issue_count > 0).then(|| subtree.clone().finished());
+        subtree.add(&child.estimate);
+        subtrees
+            .entry(child.parent_key.clone())
+            .or_default()
+            .merge(&subtree);
+    }
+    subtrees.remove(root_key).unwrap_or_default().finished()
+}
+
+/// Seconds in JIRA notation; unlike worklog input, zero stays zero
+fn jira_duration(seconds: u64) -> String {
+    if seconds == 0 {
+        "0m".to_string()
+    } else {
+        JiraClient::format_duration_jira(seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn child(
+        key: &str,
+        parent_key: &str,
+        depth: usize,
+        original_hours: Option<u64>,
+        spent_hours: Option<u64>,
+        story_points: Option<f64>,
+    ) -> ChildEstimate {
+        ChildEstimate {
+            key: key.to_string(),
+            summary: format!("Summary of {}", key),
+            issue_type: if depth == 1 { "Story" } else { "Sub-task" }.to_string(),
+            status: "To Do".to_string(),
+            parent_key: parent_key.to_string(),
+            depth,
+            estimate: EstimateValues {
+                original_estimate_seconds: original_hours.map(|h| h * 3600),
+                remaining_estimate_seconds: original_hours
+                    .map(|h| (h * 3600).saturating_sub(spent_hours.unwrap_or(0) * 3600)),
+                time_spent_seconds: spent_hours.map(|h| h * 3600),
+                story_points,
+            },
+            rollup: None,
+        }
+    }
+
+    #[test]
+    fn test_roll_up_epic_story_subtask() {
+        // EPIC-1 → STORY-1 (subtasks SUB-1, SUB-2) and STORY-2 (no estimate)
+        let mut children = vec![
+            child("STORY-1", "EPIC-1", 1, Some(4), None, Some(5.0)),
+            child("STORY-2", "EPIC-1", 1, None, None, Some(3.0)),
+            child("SUB-1", "STORY-1", 2, Some(2), Some(1), None),
+            child("SUB-2", "STORY-1", 2, None, Some(2), None),
+        ];
+
+        let rollup = roll_up("EPIC-1", &mut children);
+        assert_eq!(rollup.issue_count, 4);
+        assert_eq!(rollup.original_estimate_seconds, 6 * 3600);
+        assert_eq!(rollup.original_estimate, "6h");
+        assert_eq!(rollup.remaining_estimate_seconds, 5 * 3600);
+        assert_eq!(rollup.time_spent_seconds, 3 * 3600);
+        assert_eq!(rollup.story_points, 8.0);
+        assert_eq!(rollup.without_estimate, 2);
+        assert_eq!(rollup.without_story_points, 2);
+
+        let story = children[0].rollup.as_ref().unwrap();
+        assert_eq!(story.issue_count, 2);
+        assert_eq!(story.original_estimate_seconds, 2 * 3600);
+        assert_eq!(story.time_spent, "3h");
+        assert_eq!(story.without_estimate, 1);
+        assert!(children[1].rollup.is_none());
+        assert!(children[2].rollup.is_none());
+    }
+
+    #[test]
+    fn test_roll_up_without_children() {
+        let rollup = roll_up("PROJ-1", &mut []);
+        assert_eq!(rollup.issue_count, 0);
+        assert_eq!(rollup.remaining_estimate, "0m");
+    }
+
+    #[test]
+    fn test_new_children_skips_issues_seen_before() {
+        let issue = |key: &str| IssueInfo {
+            key: key.to_string(),
+            id: "1".to_string(),
+            url: String::new(),
+            summary: String::new(),
+            description: None,
+            issue_type: "Story".to_string(),
+            status: "To Do".to_string(),
+            status_category: None,
+            status_color: None,
+            issue_type_icon_url: None,
+            priority: None,
+            assignee: None,
+            reporter: None,
+            created: String::new(),
+            updated: String::new(),
+            due_date: None,
+            project_key: "PROJ".to_string(),
+            project_name: String::new(),
+            labels: Vec::new(),
+            components: Vec::new(),
+            story_points: None,
+            acceptance_criteria: None,
+            match_snippet: None,
+            match_field: None,
+            time_tracking: None,
+            security_level: None,
+            resolution: None,
+            comments: Vec::new(),
+        };
+        let mut seen = HashSet::from(["EPIC-1".to_string()]);
+
+        let first = new_children(
+            "EPIC-1",
+            1,
+            vec![issue("PROJ-1"), issue("PROJ-2")],
+            &mut seen,
+        );
+        assert_eq!(first.len(), 2);
+
+        // A cycle back to the epic, or a story reached twice, adds nothing
+        let second = new_children(
+            "PROJ-1",
+            2,
+            vec![issue("EPIC-1"), issue("PROJ-2")],
+            &mut seen,
+        );
+        assert!(second.is_empty());
+    }
+
+    #[test]
+    fn test_children_jql() {
+        assert_eq!(
+            children_jql("PROJ-7", true),
+            "parent = \"PROJ-7\" OR \"Epic Link\" = \"PROJ-7\" ORDER BY key ASC"
+        );
+        assert_eq!(
+            children_jql("PROJ-7", false),
+            "parent = \"PROJ-7\" ORDER BY key ASC"
+        );
+    }
+}
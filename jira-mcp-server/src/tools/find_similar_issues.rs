@@ -0,0 +1,271 @@
+//! Find similar issues tool
+//!
+//! Looks for existing issues whose text resembles a proposed summary, so agents can
+//! spot duplicates (e.g. a recurring incident) before filing a new issue.
+//! Candidates come from a JIRA `text ~` search and are ranked locally by token overlap.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::jql;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+const DEFAULT_LIMIT: u32 = 10;
+const MAX_LIMIT: u32 = 50;
+
+/// Score at or above which a candidate is reported as a possible duplicate on create
+pub const DUPLICATE_SCORE_THRESHOLD: f64 = 0.5;
+
+/// Words that carry no signal for similarity
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "in", "is", "it", "of", "on",
+    "or", "the", "to", "with", "when", "after", "not",
+];
+
+/// Parameters for the find_similar_issues tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FindSimilarIssuesParams {
+    /// Proposed issue summary to compare against (required)
+    /// Examples: "Checkout fails with payment gateway timeout"
+    pub summary_text: String,
+
+    /// Restrict the search to one project (optional)
+    pub project_key: Option<String>,
+
+    /// Maximum candidates to return (optional, default: 10, max: 50)
+    pub limit: Option<u32>,
+
+    /// Only consider issues in these JIRA statuses (optional)
+    /// Examples: ["Open", "In Progress"], ["Done"]
+    pub statuses: Option<Vec<String>>,
+}
+
+/// A ranked similar issue
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SimilarIssue {
+    /// Issue key
+    pub key: String,
+
+    /// Issue summary
+    pub summary: String,
+
+    /// Current status name
+    pub status: String,
+
+    /// Resolution name, if the issue is resolved
+    pub resolution: Option<String>,
+
+    /// Token overlap with the proposed summary (0.0 - 1.0)
+    pub score: f64,
+
+    /// Tokens shared with the proposed summary
+    pub matched_tokens: Vec<String>,
+}
+
+/// Result from the find_similar_issues tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct FindSimilarIssuesResult {
+    /// Candidates ranked by score, best first
+    pub candidates: Vec<SimilarIssue>,
+
+    /// The JQL query that was executed
+    pub jql_query: String,
+
+    /// Number of issues JIRA returned before ranking
+    pub searched: usize,
+}
+
+/// Tool for finding issues similar to a proposed summary
+pub struct FindSimilarIssuesTool {
+    jira_client: Arc<JiraClient>,
+}
+
+impl FindSimilarIssuesTool {
+    pub fn new(jira_client: Arc<JiraClient>) -> Self {
+        Self { jira_client }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: FindSimilarIssuesParams,
+    ) -> JiraMcpResult<FindSimilarIssuesResult> {
+        let proposed_tokens = tokenize(&params.summary_text);
+        if proposed_tokens.is_empty() {
+            return Err(JiraMcpError::invalid_param(
+                "summary_text",
+                "summary_text must contain at least one meaningful word",
+            ));
+        }
+
+        let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let jql = build_similarity_jql(
+            &params.summary_text,
+            params.project_key.as_deref(),
+            params.statuses.as_deref(),
+        );
+
+        // Fetch more than we return so local ranking has something to work with
+        let issues = self
+            .jira_client
+            .search_issues_jql(&jql, None, Some((limit as usize * 3).min(100)), None)
+            .await
+            .map_err(|e| {
+                if e.to_string().contains("400") {
+                    JiraMcpError::invalid_param(
+                        "summary_text",
+                        format!("JIRA rejected the similarity search: {}", e),
+                    )
+                } else {
+                    e
+                }
+            })?
+            .issues;
+        let searched = issues.len();
+
+        let mut candidates: Vec<SimilarIssue> = issues
+            .into_iter()
+            .map(|issue| {
+                let (score, matched_tokens) =
+                    similarity(&proposed_tokens, &tokenize(&issue.summary));
+                SimilarIssue {
+                    key: issue.key,
+                    summary: issue.summary,
+                    status: issue.status,
+                    resolution: issue.resolution,
+                    score,
+                    matched_tokens,
+                }
+            })
+            .collect();
+
+        candidates.sort_by(|a, b| {
+            b.score
+                .partial_cmp(&a.score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        candidates.truncate(limit as usize);
+
+        info!(
+            "Found {} similar issue candidates (searched {})",
+            candidates.len(),
+            searched
+        );
+
+        Ok(FindSimilarIssuesResult {
+            candidates,
+            jql_query: jql,
+            searched,
+        })
+    }
+}
+
+/// Build the `text ~` query for a proposed summary
+///
+/// Lucene operators are stripped so arbitrary summaries can't break the text query,
+/// and quotes/backslashes are escaped for the JQL string literal.
+fn build_similarity_jql(
+    summary_text: &str,
+    project_key: Option<&str>,
+    statuses: Option<&[String]>,
+) -> String {
+    let text: String = summary_text
+        .chars()
+        .map(|c| match c {
+            '+' | '-' | '&' | '|' | '!' | '(' | ')' | '{' | '}' | '[' | ']' | '^' | '~' | '*'
+            | '?' | ':' | '/' => ' ',
+            _ => c,
+        })
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut jql_parts = vec![format!("{} ~ {}", jql::TEXT, jql::quote(&text))];
+
+    if let Some(project) = project_key {
+        jql_parts.push(format!("{} = {}", jql::PROJECT, jql::quote(project)));
+    }
+
+    if let Some(statuses) = statuses.filter(|s| !s.is_empty()) {
+        jql_parts.push(format!(
+            "{} IN ({})",
+            jql::STATUS,
+            jql::quote_list(statuses)
+        ));
+    }
+
+    format!("{} ORDER BY updated DESC", jql_parts.join(" AND "))
+}
+
+/// Lowercased alphanumeric tokens without stopwords
+fn tokenize(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(|token| token.to_lowercase())
+        .filter(|token| !STOPWORDS.contains(&token.as_str()))
+        .collect()
+}
+
+/// Jaccard overlap of two token sets, plus the shared tokens (sorted)
+fn similarity(proposed: &HashSet<String>, candidate: &HashSet<String>) -> (f64, Vec<String>) {
+    let mut shared: Vec<String> = proposed.intersection(candidate).cloned().collect();
+    shared.sort();
+
+    let union = proposed.union(candidate).count();
+    let score = if union == 0 {
+        0.0
+    } else {
+        shared.len() as f64 / union as f64
+    };
+
+    ((score * 100.0).round() / 100.0, shared)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokenize_drops_stopwords_and_punctuation() {
+        let tokens = tokenize("The checkout fails: payment-gateway TIMEOUT!");
+        let mut tokens: Vec<_> = tokens.into_iter().collect();
+        tokens.sort();
+        assert_eq!(
+            tokens,
+            vec!["checkout", "fails", "gateway", "payment", "timeout"]
+        );
+    }
+
+    #[test]
+    fn test_similarity_scores() {
+        let proposed = tokenize("Checkout fails with payment timeout");
+        let (score, shared) = similarity(&proposed, &tokenize("checkout fails: payment timeout"));
+        assert_eq!(score, 1.0);
+        assert_eq!(shared.len(), 4);
+
+        let (score, shared) = similarity(&proposed, &tokenize("Payment timeout in reports"));
+        assert_eq!(shared, vec!["payment", "timeout"]);
+        assert!(score > 0.0 && score < DUPLICATE_SCORE_THRESHOLD);
+
+        let (score, _) = similarity(&proposed, &tokenize("Unrelated dashboard bug"));
+        assert_eq!(score, 0.0);
+    }
+
+    #[test]
+    fn test_similarity_jql_escapes_input() {
+        let jql = build_similarity_jql(
+            r#"Login "SSO" fails (prod) - urgent\"#,
+            Some("OPS"),
+            Some(&["Open".to_string(), "Done".to_string()]),
+        );
+        assert_eq!(
+            jql,
+            r#"text ~ "Login \"SSO\" fails prod urgent\\" AND project = "OPS" AND status IN ("Open", "Done") ORDER BY updated DESC"#
+        );
+    }
+}
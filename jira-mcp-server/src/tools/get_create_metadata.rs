@@ -1,5 +1,7 @@
+use crate::config::OperationClass;
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::JiraClient;
+use crate::tools::create_issue::create_issue_param;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -38,6 +40,11 @@ pub struct FieldInfo {
     /// Whether this field is required
     pub required: bool,
 
+    /// create_issue parameter that sets this field (e.g. "affects_versions" for
+    /// "versions"); fields without one go in custom_fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub create_issue_param: Option<String>,
+
     /// Field type (e.g., "string", "array", "option", "user", "priority")
     pub field_type: String,
 
@@ -150,16 +157,20 @@ impl GetCreateMetadataTool {
 
         let response: serde_json::Value = self
             .jira_client
-            .client
-            .get("api", &endpoint)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") {
-                    JiraMcpError::not_found("project", &params.project_key)
-                } else {
-                    JiraMcpError::internal(format!("Failed to get metadata: {}", e))
-                }
-            })?;
+            .get_json(
+                OperationClass::Read,
+                &format!("reading create metadata of {}", params.project_key),
+                "api",
+                &endpoint,
+                |e| {
+                    if e.to_string().contains("404") {
+                        JiraMcpError::not_found("project", &params.project_key)
+                    } else {
+                        JiraMcpError::internal(format!("Failed to get metadata: {}", e))
+                    }
+                },
+            )
+            .await?;
 
         let projects = response["projects"]
             .as_array()
@@ -249,6 +260,7 @@ impl GetCreateMetadataTool {
                     field_id: field_id.clone(),
                     name: name.clone(),
                     required,
+                    create_issue_param: create_issue_param(field_id).map(String::from),
                     field_type: field_type.clone(),
                     allowed_values,
                     schema,
@@ -279,6 +291,9 @@ impl GetCreateMetadataTool {
                             | "components"
                             | "assignee"
                             | "duedate"
+                            | "environment"
+                            | "versions"
+                            | "security"
                             | "timetracking"
                     ) {
                         optional_standard.push(field_id.clone());
@@ -304,13 +319,16 @@ impl GetCreateMetadataTool {
             });
         }
 
-        if issue_types.is_empty() && params.issue_type.is_some() {
+        if let Some(issue_type) = params
+            .issue_type
+            .as_ref()
+            .filter(|_| issue_types.is_empty())
+        {
             return Err(JiraMcpError::invalid_param(
                 "issue_type",
                 format!(
                     "Issue type '{}' not found in project {}",
-                    params.issue_type.unwrap(),
-                    params.project_key
+                    issue_type, params.project_key
                 ),
             ));
         }
@@ -329,13 +347,42 @@ impl GetCreateMetadataTool {
         ];
 
         if !all_required_fields.is_empty() {
-            let common_required: Vec<String> = all_required_fields.iter().cloned().collect();
+            let mut common_required: Vec<String> = all_required_fields.iter().cloned().collect();
+            common_required.sort();
             usage_hints.push(format!(
                 "Common required fields across all types: {}",
                 common_required.join(", ")
             ));
         }
 
+        // Screens can require fields beyond summary and issue type (environment and
+        // affected versions on bug screens, for example); name the parameter to pass
+        for issue_type in &issue_types {
+            let extra: Vec<String> = issue_type
+                .all_fields
+                .iter()
+                .filter(|field| {
+                    field.required
+                        && field.has_default_value != Some(true)
+                        && !matches!(
+                            field.field_id.as_str(),
+                            "project" | "summary" | "issuetype" | "reporter"
+                        )
+                })
+                .map(|field| match &field.create_issue_param {
+                    Some(param) => format!("{} (create_issue: {})", field.name, param),
+                    None => format!("{} (custom_fields: {})", field.name, field.field_id),
+                })
+                .collect();
+            if !extra.is_empty() {
+                usage_hints.push(format!(
+                    "{} issues also require: {}",
+                    issue_type.name,
+                    extra.join(", ")
+                ));
+            }
+        }
+
         usage_hints.push(
             "For custom fields, use the field_id (e.g., 'customfield_10016') in custom_fields parameter".to_string()
         );
@@ -1,7 +1,11 @@
 //! Tool for discovering custom fields in JIRA issues
+//!
+//! Fields are annotated from the issue's edit metadata, so agents can tell which
+//! ones update_custom_fields is able to set before trying.
 
-use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::error::JiraMcpResult;
 use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_id_or_key;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -31,6 +35,18 @@ pub struct CustomFieldInfo {
 
     /// Human-readable value representation
     pub value_display: String,
+
+    /// Whether the field is on the issue's edit screen; update_custom_fields
+    /// can only set editable fields
+    pub editable: bool,
+
+    /// Update operations JIRA accepts for the field, e.g. "set", "add", "remove"
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub operations: Vec<String>,
+
+    /// Values the field accepts, for option fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_values: Option<Vec<String>>,
 }
 
 /// Result from getting custom fields
@@ -78,24 +94,17 @@ impl GetCustomFieldsTool {
     #[instrument(skip(self))]
     pub async fn execute(
         &self,
-        params: GetCustomFieldsParams,
+        mut params: GetCustomFieldsParams,
     ) -> JiraMcpResult<GetCustomFieldsResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
         info!("Getting custom fields for issue: {}", params.issue_key);
 
         // Get issue with all fields
-        let issue = self
-            .jira_client
-            .client
-            .issues()
-            .get(&params.issue_key)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
-                    JiraMcpError::not_found("issue", &params.issue_key)
-                } else {
-                    JiraMcpError::from(e)
-                }
-            })?;
+        let issue = self.jira_client.get_raw_issue(&params.issue_key).await?;
+
+        let edit_meta = self.jira_client.get_edit_meta(&params.issue_key).await?;
 
         let mut custom_fields = Vec::new();
         let mut detected_mappings = DetectedMappings {
@@ -169,15 +178,40 @@ impl GetCustomFieldsTool {
                 detected_mappings.epic_link_field = Some(field_id.clone());
             }
 
+            let meta = edit_meta.get(field_id);
             custom_fields.push(CustomFieldInfo {
                 field_id: field_id.clone(),
-                field_name: None, // We'd need field metadata API to get names
+                field_name: meta.and_then(|m| m["name"].as_str()).map(String::from),
                 field_type,
                 value: value.clone(),
                 value_display,
+                editable: meta.is_some(),
+                operations: meta
+                    .and_then(|m| m["operations"].as_array())
+                    .map(|ops| {
+                        ops.iter()
+                            .filter_map(|op| op.as_str().map(String::from))
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                allowed_values: meta
+                    .and_then(|m| m["allowedValues"].as_array())
+                    .map(|values| values.iter().filter_map(allowed_value_label).collect()),
             });
         }
 
+        // Configured or catalog-detected fields win over the value heuristics
+        let field_map = self.jira_client.field_map();
+        if let Some(field_id) = field_map.story_points.field_for(&issue.fields) {
+            detected_mappings.story_points_field = Some(field_id.to_string());
+        }
+        if let Some(field_id) = field_map.acceptance_criteria.field_for(&issue.fields) {
+            detected_mappings.acceptance_criteria_field = Some(field_id.to_string());
+        }
+        if let Some(field_id) = field_map.epic_link.field_for(&issue.fields) {
+            detected_mappings.epic_link_field = Some(field_id.to_string());
+        }
+
         info!(
             "Found {} custom fields for issue {}",
             custom_fields.len(),
@@ -192,3 +226,11 @@ impl GetCustomFieldsTool {
         })
     }
 }
+
+/// Display label of an allowedValues entry: its value, name or ID
+fn allowed_value_label(value: &serde_json::Value) -> Option<String> {
+    ["value", "name", "id"]
+        .iter()
+        .find_map(|key| value[*key].as_str())
+        .map(String::from)
+}
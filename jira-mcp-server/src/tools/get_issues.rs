@@ -0,0 +1,264 @@
+//! Batch issue retrieval
+//!
+//! Fetches up to 50 issues in one call: a single `key in (...)` search where the
+//! search API can provide what was asked for, and concurrent individual fetches
+//! for the rest (comments, moved issues, or when JIRA rejects the query).
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::{IssueDetails, JiraClient};
+use crate::tools::issue_key;
+use schemars::JsonSchema;
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tracing::{info, instrument, warn};
+
+/// Maximum number of keys per call
+const MAX_ISSUE_KEYS: usize = 50;
+
+/// Individual fetches in flight at once
+const MAX_CONCURRENT_FETCHES: usize = 5;
+
+/// Parameters for the get_issues tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetIssuesParams {
+    /// Issue keys to fetch, at most 50 (required)
+    /// Examples: ["PROJ-123", "PROJ-124"]
+    pub issue_keys: Vec<String>,
+
+    /// Include comments (optional, default: false)
+    /// Comments aren't part of search results, so each issue is fetched individually
+    pub include_comments: Option<bool>,
+
+    /// Include attachment metadata (optional, default: false)
+    pub include_attachments: Option<bool>,
+
+    /// Include change history (optional, default: false)
+    pub include_history: Option<bool>,
+
+    /// Maximum characters of the serialized result, overriding max_response_chars (optional, min: 1000)
+    /// Trailing list items are dropped and long strings shortened; `truncated` says what
+    pub response_budget: Option<usize>,
+}
+
+/// Result from the get_issues tool
+#[derive(Debug, Clone, Serialize)]
+pub struct GetIssuesResult {
+    /// Issues by requested key, in request order
+    #[serde(serialize_with = "serialize_in_order")]
+    pub issues: Vec<(String, IssueDetails)>,
+
+    /// Requested keys that don't exist or aren't visible
+    pub not_found: Vec<String>,
+
+    /// Keys that could not be fetched for another reason, with the error
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub failed: Vec<String>,
+
+    /// Performance information
+    pub performance: GetIssuesPerformance,
+}
+
+/// Performance metrics for batch issue retrieval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetIssuesPerformance {
+    /// Time taken for the operation in milliseconds
+    pub duration_ms: u64,
+
+    /// Number of JIRA API calls made
+    pub api_calls: u32,
+
+    /// Issues returned by the batch search
+    pub fetched_by_search: usize,
+
+    /// Issues fetched one by one
+    pub fetched_individually: usize,
+}
+
+/// Serialize key/issue pairs as a JSON object, keeping their order
+fn serialize_in_order<S: Serializer>(
+    issues: &[(String, IssueDetails)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(issues.iter().map(|(key, details)| (key, details)))
+}
+
+/// Implementation of the get_issues tool
+pub struct GetIssuesTool {
+    jira_client: Arc<JiraClient>,
+}
+
+impl GetIssuesTool {
+    pub fn new(jira_client: Arc<JiraClient>) -> Self {
+        Self { jira_client }
+    }
+
+    #[instrument(skip(self), fields(keys = params.issue_keys.len()))]
+    pub async fn execute(&self, params: GetIssuesParams) -> JiraMcpResult<GetIssuesResult> {
+        let start_time = std::time::Instant::now();
+        let keys = normalize_issue_keys(self.jira_client.base_url(), &params.issue_keys)?;
+
+        let include_comments = params.include_comments.unwrap_or(false);
+        let include_attachments = params.include_attachments.unwrap_or(false);
+        let include_history = params.include_history.unwrap_or(false);
+
+        let mut api_calls = 0u32;
+        let mut found: HashMap<String, IssueDetails> = HashMap::new();
+
+        // One search for everything it can provide
+        if !include_comments {
+            api_calls += 1;
+            match self
+                .jira_client
+                .get_issues_details(&keys, include_attachments, include_history)
+                .await
+            {
+                Ok(issues) => {
+                    for details in issues {
+                        found.insert(details.issue_info.key.clone(), details);
+                    }
+                }
+                Err(e) => warn!("Batch search failed, fetching issues one by one: {}", e),
+            }
+        }
+        let fetched_by_search = found.len();
+
+        // Individual fetches for the rest, e.g. moved issues or comments
+        let missing: Vec<String> = keys
+            .iter()
+            .filter(|key| !found.contains_key(*key))
+            .cloned()
+            .collect();
+        let fetched_individually = missing.len();
+        api_calls += missing.len() as u32;
+
+        let mut not_found = Vec::new();
+        let mut failed = Vec::new();
+        let mut pending = missing.into_iter();
+        let mut join_set = JoinSet::new();
+        loop {
+            while join_set.len() < MAX_CONCURRENT_FETCHES {
+                let Some(key) = pending.next() else {
+                    break;
+                };
+                let jira_client = Arc::clone(&self.jira_client);
+                join_set.spawn(async move {
+                    let result = jira_client
+                        .get_issue_details(
+                            &key,
+                            include_comments,
+                            include_attachments,
+                            include_history,
+                        )
+                        .await;
+                    (key, result)
+                });
+            }
+
+            let Some(joined) = join_set.join_next().await else {
+                break;
+            };
+            match joined {
+                Ok((key, Ok(details))) => {
+                    found.insert(key, details);
+                }
+                Ok((key, Err(JiraMcpError::NotFound { .. }))) => not_found.push(key),
+                Ok((key, Err(e))) => {
+                    warn!("Could not fetch {}: {}", key, e);
+                    failed.push(format!("{}: {}", key, e));
+                }
+                Err(e) => {
+                    warn!("Fetch task failed: {}", e);
+                    failed.push(format!("task: {}", e));
+                }
+            }
+        }
+
+        // Back into request order
+        let issues: Vec<(String, IssueDetails)> = keys
+            .iter()
+            .filter_map(|key| found.remove(key).map(|details| (key.clone(), details)))
+            .collect();
+        not_found.sort_by_key(|key| keys.iter().position(|k| k == key));
+        failed.sort();
+
+        let duration_ms = start_time.elapsed().as_millis() as u64;
+        info!(
+            "Fetched {} of {} issues in {}ms ({} API calls)",
+            issues.len(),
+            keys.len(),
+            duration_ms,
+            api_calls
+        );
+
+        Ok(GetIssuesResult {
+            issues,
+            not_found,
+            failed,
+            performance: GetIssuesPerformance {
+                duration_ms,
+                api_calls,
+                fetched_by_search,
+                fetched_individually,
+            },
+        })
+    }
+}
+
+/// Normalize and de-duplicate issue keys; normalized keys are safe to put in JQL
+fn normalize_issue_keys(jira_url: &str, issue_keys: &[String]) -> JiraMcpResult<Vec<String>> {
+    let mut seen = HashSet::new();
+    let mut keys = issue_key::normalize_issue_keys(jira_url, issue_keys, "issue_keys")?;
+    keys.retain(|key| seen.insert(key.clone()));
+
+    if keys.is_empty() {
+        return Err(JiraMcpError::invalid_param(
+            "issue_keys",
+            "Provide at least one issue key",
+        ));
+    }
+    if keys.len() > MAX_ISSUE_KEYS {
+        return Err(JiraMcpError::invalid_param(
+            "issue_keys",
+            format!(
+                "At most {} issue keys per call, got {}",
+                MAX_ISSUE_KEYS,
+                keys.len()
+            ),
+        ));
+    }
+
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JIRA_URL: &str = "https://jira.example.com";
+
+    #[test]
+    fn test_normalize_issue_keys() {
+        let keys = normalize_issue_keys(
+            JIRA_URL,
+            &[
+                " proj-2 ".to_string(),
+                "PROJ-1".to_string(),
+                "proj-2".to_string(),
+                "MY_APP2-10".to_string(),
+            ],
+        )
+        .unwrap();
+        assert_eq!(keys, vec!["PROJ-2", "PROJ-1", "MY_APP2-10"]);
+
+        assert!(normalize_issue_keys(JIRA_URL, &[]).is_err());
+        assert!(normalize_issue_keys(JIRA_URL, &["PROJ-1) OR key in (X-1".to_string()]).is_err());
+        assert!(normalize_issue_keys(JIRA_URL, &["123-4".to_string()]).is_err());
+
+        let too_many: Vec<String> = (1..=51).map(|n| format!("PROJ-{}", n)).collect();
+        assert!(normalize_issue_keys(JIRA_URL, &too_many).is_err());
+    }
+}
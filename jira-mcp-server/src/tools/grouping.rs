@@ -0,0 +1,205 @@
+//! Result grouping for search-style tools
+//!
+//! Buckets an already-fetched page of issues by a field so agents don't have to
+//! re-group the same list for standups and planning.
+
+use crate::jira_client::{IssueInfo, SearchResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Field to group search results by
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum GroupBy {
+    Status,
+    Assignee,
+    IssueType,
+    Priority,
+    Project,
+}
+
+impl GroupBy {
+    /// Group value for an issue, with a readable placeholder for empty fields
+    fn value_of(&self, issue: &IssueInfo) -> String {
+        match self {
+            GroupBy::Status => issue.status.clone(),
+            GroupBy::Assignee => issue
+                .assignee
+                .clone()
+                .unwrap_or_else(|| "Unassigned".to_string()),
+            GroupBy::IssueType => issue.issue_type.clone(),
+            GroupBy::Priority => issue.priority.clone().unwrap_or_else(|| "None".to_string()),
+            GroupBy::Project => issue.project_key.clone(),
+        }
+    }
+}
+
+/// A single group of issues
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueGroup {
+    /// Number of issues in the group
+    pub count: usize,
+
+    /// Keys of the issues in the group, in result order
+    pub issue_keys: Vec<String>,
+
+    /// Sum of story points of the issues in the group
+    pub story_points_sum: f64,
+}
+
+/// Grouped view of a result page
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupedIssues {
+    /// Field the issues were grouped by
+    pub group_by: GroupBy,
+
+    /// Group value -> group
+    pub groups: BTreeMap<String, IssueGroup>,
+
+    /// True when the groups only cover the current page of a larger result
+    pub partial: bool,
+
+    /// Explanation when the grouping is partial
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// Group the issues of a result page by the given field
+pub fn group_issues(search_result: &SearchResult, group_by: GroupBy) -> GroupedIssues {
+    let mut groups: BTreeMap<String, IssueGroup> = BTreeMap::new();
+
+    for issue in &search_result.issues {
+        let group = groups
+            .entry(group_by.value_of(issue))
+            .or_insert_with(|| IssueGroup {
+                count: 0,
+                issue_keys: Vec::new(),
+                story_points_sum: 0.0,
+            });
+        group.count += 1;
+        group.issue_keys.push(issue.key.clone());
+        group.story_points_sum += issue.story_points.unwrap_or(0.0);
+    }
+
+    let shown = search_result.issues.len();
+    let partial = search_result.start_at > 0
+        || !search_result.is_last
+        || search_result.total.is_some_and(|total| shown < total);
+    let note = partial.then(|| {
+        let of = match search_result.total {
+            Some(total) => format!("{} of {} issues", shown, total),
+            None => format!("{} issues, more available", shown),
+        };
+        format!(
+            "Grouping covers only the current page ({}). \
+             Page through with start_at or add filters for complete groups.",
+            of
+        )
+    });
+
+    GroupedIssues {
+        group_by,
+        groups,
+        partial,
+        note,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn issue(key: &str, status: &str, assignee: Option<&str>, points: Option<f64>) -> IssueInfo {
+        IssueInfo {
+            key: key.to_string(),
+            id: key.to_string(),
+            url: String::new(),
+            summary: String::new(),
+            description: None,
+            issue_type: "Story".to_string(),
+            status: status.to_string(),
+            status_category: None,
+            status_color: None,
+            issue_type_icon_url: None,
+            priority: None,
+            assignee: assignee.map(str::to_string),
+            reporter: None,
+            created: String::new(),
+            updated: String::new(),
+            due_date: None,
+            project_key: "TEST".to_string(),
+            project_name: "Test".to_string(),
+            labels: Vec::new(),
+            components: Vec::new(),
+            story_points: points,
+            acceptance_criteria: None,
+            match_snippet: None,
+            match_field: None,
+            time_tracking: None,
+            security_level: None,
+            resolution: None,
+            comments: Vec::new(),
+        }
+    }
+
+    fn result(issues: Vec<IssueInfo>, total: Option<usize>) -> SearchResult {
+        SearchResult {
+            is_last: total.is_none_or(|total| issues.len() >= total),
+            issues,
+            total,
+            start_at: 0,
+            max_results: 50,
+        }
+    }
+
+    #[test]
+    fn test_group_by_status() {
+        let page = result(
+            vec![
+                issue("TEST-1", "To Do", None, Some(3.0)),
+                issue("TEST-2", "Done", Some("Alice"), Some(5.0)),
+                issue("TEST-3", "To Do", Some("Alice"), None),
+            ],
+            Some(3),
+        );
+
+        let grouped = group_issues(&page, GroupBy::Status);
+        assert!(!grouped.partial);
+        assert!(grouped.note.is_none());
+
+        let todo = &grouped.groups["To Do"];
+        assert_eq!(todo.count, 2);
+        assert_eq!(todo.issue_keys, vec!["TEST-1", "TEST-3"]);
+        assert_eq!(todo.story_points_sum, 3.0);
+        assert_eq!(grouped.groups["Done"].story_points_sum, 5.0);
+    }
+
+    #[test]
+    fn test_group_by_assignee_partial_page() {
+        let page = result(
+            vec![
+                issue("TEST-1", "To Do", None, None),
+                issue("TEST-2", "To Do", Some("Alice"), None),
+            ],
+            Some(10),
+        );
+
+        let grouped = group_issues(&page, GroupBy::Assignee);
+        assert!(grouped.partial);
+        assert!(grouped.note.unwrap().contains("2 of 10"));
+        assert_eq!(grouped.groups["Unassigned"].count, 1);
+        assert_eq!(grouped.groups["Alice"].count, 1);
+    }
+
+    #[test]
+    fn test_group_without_total() {
+        let mut page = result(vec![issue("TEST-1", "To Do", None, None)], None);
+        assert!(!group_issues(&page, GroupBy::Status).partial);
+
+        page.is_last = false;
+        let grouped = group_issues(&page, GroupBy::Status);
+        assert!(grouped.partial);
+        assert!(grouped.note.unwrap().contains("1 issues, more available"));
+    }
+}
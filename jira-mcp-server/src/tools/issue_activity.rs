@@ -0,0 +1,514 @@
+//! Activity stream of one issue
+//!
+//! Merges the changelog, comments, worklogs and attachments of an issue into a
+//! single time-ordered list of events with a uniform shape, so callers do not
+//! need to stitch the parts together themselves. diff_issue_since is built on
+//! the same stream.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::{
+    AttachmentInfo, CommentInfo, HistoryEntry, HistoryItem, IssueDetails, JiraClient, WorklogInfo,
+};
+use crate::semantic_mapping::resolve_instant;
+use crate::tools::issue_key::normalize_issue_id_or_key;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+const DEFAULT_LIMIT: usize = 50;
+const MAX_LIMIT: usize = 500;
+
+/// Characters of a comment or worklog comment kept in its summary line
+const COMMENT_PREVIEW_CHARS: usize = 200;
+
+/// Characters of an old or new field value kept in its summary line
+const VALUE_PREVIEW_CHARS: usize = 80;
+
+/// Changelog fields left out because another event already covers them
+/// (attachments and worklogs are events of their own)
+const DUPLICATE_FIELDS: &[&str] = &["attachment", "worklogid", "timespent"];
+
+/// Changelog field JIRA records issue links under
+const LINK_FIELD: &str = "link";
+
+/// Parameters for the get_issue_activity tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetIssueActivityParams {
+    /// The JIRA issue key (e.g., "PROJ-123")
+    pub issue_key: String,
+
+    /// Only events after this point in time (optional)
+    /// Examples: "2 hours ago", "yesterday", "2024-01-15T09:00:00Z"
+    pub since: Option<String>,
+
+    /// Maximum events to return, the most recent ones (optional, default: 50, max: 500)
+    pub limit: Option<usize>,
+
+    /// Only these kinds of events (optional, default: all)
+    /// Examples: ["comment"], ["field_change", "link_added"]
+    pub event_types: Option<Vec<ActivityEventType>>,
+}
+
+/// Kind of an activity event
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityEventType {
+    Comment,
+    Worklog,
+    FieldChange,
+    AttachmentAdded,
+    LinkAdded,
+}
+
+/// One event of the stream
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityEvent {
+    /// When it happened, as reported by JIRA
+    pub timestamp: String,
+
+    /// Display name of the user behind the event
+    pub actor: String,
+
+    #[serde(rename = "type")]
+    pub event_type: ActivityEventType,
+
+    /// The event in one line, e.g. "status: In Progress → In Review"
+    pub summary_line: String,
+
+    /// Type-specific data: ids, full texts, old and new values
+    pub details: serde_json::Value,
+}
+
+/// Result from the get_issue_activity tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetIssueActivityResult {
+    pub issue_key: String,
+    pub summary: String,
+
+    /// The cutoff `since` resolved to (UTC), if given
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+
+    /// Matching events, oldest first
+    pub events: Vec<ActivityEvent>,
+
+    /// Events matching `since` and `event_types`, including those cut by `limit`
+    pub total_events: usize,
+
+    /// Whether older events were left out because of `limit`
+    pub truncated: bool,
+
+    /// Parts of the issue that could not be loaded, with the error
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// An issue with its full activity stream
+pub struct IssueActivity {
+    pub details: IssueDetails,
+
+    /// All events with their parsed time, oldest first
+    pub events: Vec<(DateTime<Utc>, ActivityEvent)>,
+
+    /// Parts that could not be loaded, with the error
+    pub warnings: Vec<String>,
+}
+
+/// Read the issue (with comments and attachments), its changelog and its
+/// worklogs concurrently and merge them into one stream
+///
+/// Only the issue itself is required; a failed changelog or worklog read is
+/// reported in `warnings` and costs just its events.
+pub async fn load_activity(
+    jira_client: &JiraClient,
+    issue_key: &str,
+) -> JiraMcpResult<IssueActivity> {
+    let (details, changelog, worklogs) = tokio::join!(
+        jira_client.get_issue_details(issue_key, true, true, false),
+        jira_client.get_changelog(issue_key),
+        jira_client.get_worklogs(issue_key),
+    );
+    let details = details?;
+
+    let mut warnings = Vec::new();
+    let history = match changelog {
+        Ok((history, total)) => {
+            if total > history.len() {
+                warnings.push(format!(
+                    "changelog: JIRA returned {} of {} entries, field changes may be missing",
+                    history.len(),
+                    total
+                ));
+            }
+            history
+        }
+        Err(e) => {
+            warn!("Could not load the changelog of {}: {}", issue_key, e);
+            warnings.push(format!("changelog: {}", e));
+            Vec::new()
+        }
+    };
+    let worklogs = worklogs.unwrap_or_else(|e| {
+        warn!("Could not load worklogs of {}: {}", issue_key, e);
+        warnings.push(format!("worklogs: {}", e));
+        Vec::new()
+    });
+
+    let events = collect_events(
+        &history,
+        details.comments.as_deref().unwrap_or_default(),
+        &worklogs,
+        details.attachments.as_deref().unwrap_or_default(),
+    );
+
+    Ok(IssueActivity {
+        details,
+        events,
+        warnings,
+    })
+}
+
+/// Implementation of the get_issue_activity tool
+pub struct GetIssueActivityTool {
+    jira_client: Arc<JiraClient>,
+}
+
+impl GetIssueActivityTool {
+    pub fn new(jira_client: Arc<JiraClient>) -> Self {
+        Self { jira_client }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: GetIssueActivityParams,
+    ) -> JiraMcpResult<GetIssueActivityResult> {
+        let issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+        let since = params
+            .since
+            .as_deref()
+            .map(|since| resolve_instant(since, Utc::now(), "since"))
+            .transpose()?;
+        if params.limit == Some(0) {
+            return Err(JiraMcpError::invalid_param(
+                "limit",
+                "limit must be at least 1",
+            ));
+        }
+        let limit = params.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+        let event_types = params.event_types.unwrap_or_default();
+
+        info!("Collecting the activity of {}", issue_key);
+
+        let activity = load_activity(&self.jira_client, &issue_key).await?;
+        let mut events: Vec<ActivityEvent> = activity
+            .events
+            .into_iter()
+            .filter(|(time, _)| since.is_none_or(|since| *time > since))
+            .filter(|(_, event)| event_types.is_empty() || event_types.contains(&event.event_type))
+            .map(|(_, event)| event)
+            .collect();
+
+        // Keep the most recent events, still oldest first
+        let total_events = events.len();
+        let truncated = total_events > limit;
+        events.drain(..total_events.saturating_sub(limit));
+
+        Ok(GetIssueActivityResult {
+            issue_key: activity.details.issue_info.key,
+            summary: activity.details.issue_info.summary,
+            since: since.map(|since| since.to_rfc3339()),
+            events,
+            total_events,
+            truncated,
+            warnings: activity.warnings,
+        })
+    }
+}
+
+/// Merge everything into one stream, oldest first
+///
+/// Events at the same time keep the order changelog, comments, worklogs,
+/// attachments, and within each part the order JIRA returned. Entries whose
+/// timestamp cannot be read are left out, since they cannot be placed.
+pub(crate) fn collect_events(
+    history: &[HistoryEntry],
+    comments: &[CommentInfo],
+    worklogs: &[WorklogInfo],
+    attachments: &[AttachmentInfo],
+) -> Vec<(DateTime<Utc>, ActivityEvent)> {
+    let mut events = Vec::new();
+    let mut push = |timestamp: &str,
+                    actor: &str,
+                    event_type,
+                    summary_line: String,
+                    details: serde_json::Value| {
+        if let Some(time) = parse_jira_time(timestamp) {
+            let event = ActivityEvent {
+                timestamp: timestamp.to_string(),
+                actor: actor.to_string(),
+                event_type,
+                summary_line,
+                details,
+            };
+            events.push((time, event));
+        }
+    };
+
+    for entry in history {
+        for item in &entry.items {
+            let field = item.field.to_lowercase();
+            if DUPLICATE_FIELDS.contains(&field.as_str()) {
+                continue;
+            }
+            let added_link = item
+                .to_string
+                .as_deref()
+                .filter(|_| field == LINK_FIELD)
+                .map(str::trim)
+                .filter(|link| !link.is_empty());
+            let (event_type, summary_line) = match added_link {
+                Some(link) => (
+                    ActivityEventType::LinkAdded,
+                    format!("link added: {}", preview(link, VALUE_PREVIEW_CHARS)),
+                ),
+                None => (ActivityEventType::FieldChange, describe_field_change(item)),
+            };
+            push(
+                &entry.created,
+                &entry.author,
+                event_type,
+                summary_line,
+                json!({
+                    "history_id": entry.id,
+                    "field": item.field,
+                    "from": item.from_string.as_ref().or(item.from.as_ref()),
+                    "to": item.to_string.as_ref().or(item.to.as_ref()),
+                }),
+            );
+        }
+    }
+
+    for comment in comments {
+        push(
+            &comment.created,
+            &comment.author,
+            ActivityEventType::Comment,
+            format!("comment: {}", preview(&comment.body, COMMENT_PREVIEW_CHARS)),
+            json!({
+                "comment_id": comment.id,
+                "body": comment.body,
+                "url": Some(&comment.url).filter(|url| !url.is_empty()),
+            }),
+        );
+    }
+
+    for worklog in worklogs {
+        let spent = worklog.time_spent.as_deref().unwrap_or("time");
+        let summary_line = match worklog.comment.as_deref().map(str::trim) {
+            Some(comment) if !comment.is_empty() => format!(
+                "logged {}: {}",
+                spent,
+                preview(comment, COMMENT_PREVIEW_CHARS)
+            ),
+            _ => format!("logged {}", spent),
+        };
+        push(
+            &worklog.created,
+            &worklog.author,
+            ActivityEventType::Worklog,
+            summary_line,
+            json!({
+                "worklog_id": worklog.id,
+                "started": worklog.started,
+                "time_spent": worklog.time_spent,
+                "time_spent_seconds": worklog.time_spent_seconds,
+                "comment": worklog.comment,
+            }),
+        );
+    }
+
+    for attachment in attachments {
+        push(
+            &attachment.created,
+            &attachment.author,
+            ActivityEventType::AttachmentAdded,
+            format!("attached {}", attachment.filename),
+            json!({
+                "attachment_id": attachment.id,
+                "filename": attachment.filename,
+                "size": attachment.size,
+                "mime_type": attachment.mime_type,
+                "content_url": attachment.content_url,
+            }),
+        );
+    }
+
+    // Stable, so events at the same time keep the order above
+    events.sort_by_key(|(time, _)| *time);
+    events
+}
+
+/// "status: In Progress → In Review", with long values shortened
+fn describe_field_change(item: &HistoryItem) -> String {
+    let value = |display: &Option<String>, raw: &Option<String>| {
+        display
+            .as_deref()
+            .or(raw.as_deref())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| preview(value, VALUE_PREVIEW_CHARS))
+    };
+
+    match (
+        value(&item.from_string, &item.from),
+        value(&item.to_string, &item.to),
+    ) {
+        (Some(from), Some(to)) => format!("{}: {} → {}", item.field, from, to),
+        (None, Some(to)) => format!("{}: set to {}", item.field, to),
+        (Some(from), None) => format!("{}: {} removed", item.field, from),
+        (None, None) => format!("{}: changed", item.field),
+    }
+}
+
+/// First `max_chars` characters of `text` on a single line
+fn preview(text: &str, max_chars: usize) -> String {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if flat.chars().count() <= max_chars {
+        return flat;
+    }
+    let kept: String = flat.chars().take(max_chars).collect();
+    format!("{}…", kept.trim_end())
+}
+
+/// Timestamps as JIRA sends them ("2024-01-15T10:30:00.000+0000"), or RFC 3339
+fn parse_jira_time(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z")
+        .or_else(|_| DateTime::parse_from_rfc3339(value))
+        .ok()
+        .map(|time| time.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(field: &str, from: Option<&str>, to: Option<&str>) -> HistoryItem {
+        HistoryItem {
+            field: field.to_string(),
+            field_type: "jira".to_string(),
+            from: None,
+            from_string: from.map(String::from),
+            to: None,
+            to_string: to.map(String::from),
+        }
+    }
+
+    fn history(id: &str, author: &str, created: &str, items: Vec<HistoryItem>) -> HistoryEntry {
+        HistoryEntry {
+            id: id.to_string(),
+            author: author.to_string(),
+            created: created.to_string(),
+            items,
+        }
+    }
+
+    #[test]
+    fn test_describe_field_change() {
+        assert_eq!(
+            describe_field_change(&item("status", Some("In Progress"), Some("In Review"))),
+            "status: In Progress → In Review"
+        );
+        assert_eq!(
+            describe_field_change(&item("assignee", None, Some("Alice"))),
+            "assignee: set to Alice"
+        );
+        assert_eq!(
+            describe_field_change(&item("labels", Some("urgent"), Some(""))),
+            "labels: urgent removed"
+        );
+
+        let long = "x".repeat(200);
+        let text = describe_field_change(&item("description", Some(&long), Some("short")));
+        assert!(text.starts_with("description: xxx"));
+        assert!(text.ends_with("… → short"));
+    }
+
+    #[test]
+    fn test_collect_events_types_and_ties() {
+        let at = "2025-03-12T10:00:00.000+0000";
+        let history = vec![
+            history(
+                "h2",
+                "Alice",
+                "2025-03-12T11:00:00.000+0000",
+                vec![
+                    item("Link", None, Some("This issue blocks PROJ-7")),
+                    item("Link", Some("This issue relates to PROJ-3"), None),
+                    item("Attachment", None, Some("trace.log")),
+                ],
+            ),
+            history(
+                "h1",
+                "Alice",
+                at,
+                vec![item("priority", Some("Medium"), Some("High"))],
+            ),
+        ];
+        let comments = vec![CommentInfo {
+            id: "c1".to_string(),
+            author: "Bob".to_string(),
+            body: "Same time as the priority change".to_string(),
+            created: at.to_string(),
+            updated: at.to_string(),
+            url: String::new(),
+            visibility: None,
+            jsm_internal: None,
+        }];
+        let attachments = vec![AttachmentInfo {
+            id: "a1".to_string(),
+            filename: "trace.log".to_string(),
+            author: "Alice".to_string(),
+            created: "not a time".to_string(),
+            size: 1024,
+            mime_type: "text/plain".to_string(),
+            content_url: String::new(),
+            thumbnail_url: None,
+            author_account_id: None,
+        }];
+
+        let events = collect_events(&history, &comments, &[], &attachments);
+        let lines: Vec<(ActivityEventType, &str)> = events
+            .iter()
+            .map(|(_, event)| (event.event_type, event.summary_line.as_str()))
+            .collect();
+
+        // The changelog goes before a comment at the same time; the changelog's
+        // attachment item and the attachment without a readable time are dropped
+        assert_eq!(
+            lines,
+            vec![
+                (ActivityEventType::FieldChange, "priority: Medium → High"),
+                (
+                    ActivityEventType::Comment,
+                    "comment: Same time as the priority change"
+                ),
+                (
+                    ActivityEventType::LinkAdded,
+                    "link added: This issue blocks PROJ-7"
+                ),
+                (
+                    ActivityEventType::FieldChange,
+                    "Link: This issue relates to PROJ-3 removed"
+                ),
+            ]
+        );
+        assert_eq!(events[1].1.details["url"], serde_json::Value::Null);
+        assert_eq!(events[2].1.details["to"], "This issue blocks PROJ-7");
+    }
+}
@@ -5,13 +5,23 @@
 
 use crate::cache::MetadataCache;
 use crate::config::JiraConfig;
-use crate::error::{JiraMcpError, JiraMcpResult};
-use crate::jira_client::{IssueDetails, JiraClient};
+use crate::error::JiraMcpResult;
+use crate::jira_client::{IssueDetails, IssueInfo, JiraClient, WorklogSummary};
+use crate::metrics::{self, Measured, ToolPerformance};
+use crate::tools::issue_key::{normalize_issue_id_or_key, normalize_issue_key};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use tokio::task::JoinSet;
 use tracing::{info, instrument, warn};
 
+/// Requests in flight at once when expanding subtasks and linked issues
+const MAX_CONCURRENT_EXPANSIONS: usize = 5;
+
+/// Most recent worklogs returned with include_worklogs; the summary covers all
+const MAX_WORKLOGS: usize = 50;
+
 /// Parameters for the get_issue_details tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -21,6 +31,7 @@ pub struct GetIssueDetailsParams {
     pub issue_key: String,
 
     /// Include comments in the response (optional, default: false)
+    /// Restricted comments carry a `visibility` object; treat them as non-public
     pub include_comments: Option<bool>,
 
     /// Include attachment metadata in the response (optional, default: false)
@@ -28,6 +39,21 @@ pub struct GetIssueDetailsParams {
 
     /// Include change history in the response (optional, default: false)
     pub include_history: Option<bool>,
+
+    /// Include worklogs and a logged-time summary per author (optional, default: false)
+    /// Only the 50 most recent worklogs are listed; the summary counts all of them
+    pub include_worklogs: Option<bool>,
+
+    /// Replace the subtask stubs with full issue information (optional, default: false)
+    /// Saves a get_issue_details call per subtask; subtasks are fetched concurrently
+    pub expand_subtasks: Option<bool>,
+
+    /// Add full issue information as `details` to each linked issue (optional, default: false)
+    pub expand_linked: Option<bool>,
+
+    /// Maximum characters of the serialized result, overriding max_response_chars (optional, min: 1000)
+    /// Trailing list items are dropped and long strings shortened; `truncated` says what
+    pub response_budget: Option<usize>,
 }
 
 /// Result from the get_issue_details tool
@@ -55,8 +81,26 @@ pub struct IssueDetailsPerformance {
     /// Number of JIRA API calls made
     pub api_calls: u32,
 
+    /// API calls made to expand subtasks and linked issues (included in api_calls)
+    pub expansion_calls: u32,
+
     /// Size of the response data (estimated)
     pub response_size_estimate: usize,
+
+    /// Metadata lookups answered from the cache
+    pub cache_hits: u32,
+
+    /// Requests repeated after a retryable failure
+    pub retries: u32,
+}
+
+impl Measured for GetIssueDetailsResult {
+    fn set_performance(&mut self, performance: ToolPerformance) {
+        self.performance.duration_ms = performance.duration_ms;
+        self.performance.api_calls = performance.api_calls;
+        self.performance.cache_hits = performance.cache_hits;
+        self.performance.retries = performance.retries;
+    }
 }
 
 /// Metadata about the issue details operation
@@ -72,9 +116,15 @@ pub struct IssueDetailsMetadata {
     pub includes_comments: bool,
     pub includes_attachments: bool,
     pub includes_history: bool,
+    #[serde(default)]
+    pub includes_worklogs: bool,
 
     /// Data freshness information
     pub data_freshness: String, // "fresh", "cached", "partially_cached"
+
+    /// Subtasks or linked issues that could not be expanded, with the error
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub expansion_failures: Vec<String>,
 }
 
 /// Implementation of the get_issue_details tool
@@ -120,19 +170,20 @@ impl GetIssueDetailsTool {
             params.issue_key
         );
 
-        // Validate parameters
-        self.validate_params(&params)?;
-
         // Extract options with defaults
         let include_comments = params.include_comments.unwrap_or(false);
         let include_attachments = params.include_attachments.unwrap_or(false);
         let include_history = params.include_history.unwrap_or(false);
+        let include_worklogs = params.include_worklogs.unwrap_or(false);
+        let expand_subtasks = params.expand_subtasks.unwrap_or(false);
+        let expand_linked = params.expand_linked.unwrap_or(false);
 
-        // Normalize issue key (convert to uppercase, handle different formats)
-        let normalized_key = self.normalize_issue_key(&params.issue_key)?;
+        // Normalize issue key (trim, uppercase, extract from browse URLs; IDs pass through)
+        let normalized_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
 
         // Get issue details from JIRA
-        let issue_details = self
+        let mut issue_details = self
             .jira_client
             .get_issue_details(
                 &normalized_key,
@@ -143,6 +194,52 @@ impl GetIssueDetailsTool {
             .await?;
 
         api_calls += 1;
+
+        if include_worklogs {
+            let mut worklogs = self.jira_client.get_worklogs(&normalized_key).await?;
+            api_calls += 1;
+
+            let mut summary = WorklogSummary::from_worklogs(&worklogs);
+            if worklogs.len() > MAX_WORKLOGS {
+                summary.omitted = worklogs.len() - MAX_WORKLOGS;
+                worklogs.drain(..summary.omitted);
+            }
+            issue_details.worklogs = Some(worklogs);
+            issue_details.worklog_summary = Some(summary);
+        }
+
+        // Fetch full subtasks and linked issues, each key once
+        let mut expand_keys: Vec<String> = Vec::new();
+        if expand_subtasks {
+            expand_keys.extend(issue_details.subtasks.iter().map(|s| s.key.clone()));
+        }
+        if expand_linked {
+            expand_keys.extend(issue_details.linked_issues.iter().map(|l| l.key.clone()));
+        }
+        let mut seen = HashSet::new();
+        expand_keys.retain(|key| seen.insert(key.clone()));
+
+        let expansion_calls = expand_keys.len() as u32;
+        let mut expansion_failures = Vec::new();
+        if !expand_keys.is_empty() {
+            let (expanded, failures) = self.fetch_issue_infos(expand_keys).await;
+            expansion_failures = failures;
+
+            if expand_subtasks {
+                for subtask in &mut issue_details.subtasks {
+                    if let Some(info) = expanded.get(&subtask.key) {
+                        *subtask = info.clone();
+                    }
+                }
+            }
+            if expand_linked {
+                for linked in &mut issue_details.linked_issues {
+                    linked.details = expanded.get(&linked.key).cloned();
+                }
+            }
+        }
+        api_calls += expansion_calls;
+
         let duration = start_time.elapsed();
 
         // Estimate response size (rough calculation)
@@ -176,7 +273,10 @@ impl GetIssueDetailsTool {
                 duration_ms: duration.as_millis() as u64,
                 cache_hit,
                 api_calls,
+                expansion_calls,
                 response_size_estimate,
+                cache_hits: 0,
+                retries: 0,
             },
             metadata: IssueDetailsMetadata {
                 requested_key: params.issue_key,
@@ -184,80 +284,59 @@ impl GetIssueDetailsTool {
                 includes_comments: include_comments,
                 includes_attachments: include_attachments,
                 includes_history: include_history,
+                includes_worklogs: include_worklogs,
                 data_freshness,
+                expansion_failures,
             },
         })
     }
 
-    /// Validate issue details parameters
-    fn validate_params(&self, params: &GetIssueDetailsParams) -> JiraMcpResult<()> {
-        // Validate issue key format
-        if params.issue_key.trim().is_empty() {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key is required. Please provide a JIRA issue key (e.g., 'PROJ-123'). Use the search_issues tool first to find issues if you don't know the key.",
-            ));
-        }
-
-        // Basic format validation (PROJECT-NUMBER pattern)
-        let key = params.issue_key.trim();
-        if !key.contains('-') {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key must follow PROJECT-NUMBER format (e.g., 'PROJ-123')",
-            ));
-        }
-
-        let parts: Vec<&str> = key.split('-').collect();
-        if parts.len() < 2 {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key must contain at least one hyphen separating project and number",
-            ));
-        }
-
-        // Validate project part (should be alphabetic)
-        let project_part = parts[0];
-        if project_part.is_empty() || !project_part.chars().all(|c| c.is_alphabetic()) {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Project part of issue key must contain only letters",
-            ));
-        }
-
-        // Validate number part (should be numeric)
-        let number_part = parts[1];
-        if number_part.is_empty() || !number_part.chars().all(|c| c.is_numeric()) {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Number part of issue key must contain only digits",
-            ));
-        }
-
-        // Check key length
-        if key.len() > 100 {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key cannot exceed 100 characters",
-            ));
-        }
-
-        Ok(())
-    }
-
-    /// Normalize issue key (uppercase, trim whitespace)
-    fn normalize_issue_key(&self, key: &str) -> JiraMcpResult<String> {
-        let normalized = key.trim().to_uppercase();
+    /// Fetch issue information for `keys`, at most MAX_CONCURRENT_EXPANSIONS at a time
+    ///
+    /// Returns the issues that could be fetched by key, and an entry per failure.
+    async fn fetch_issue_infos(
+        &self,
+        keys: Vec<String>,
+    ) -> (HashMap<String, IssueInfo>, Vec<String>) {
+        let mut infos = HashMap::new();
+        let mut failures = Vec::new();
+        let mut pending = keys.into_iter();
+        let mut join_set = JoinSet::new();
+
+        loop {
+            while join_set.len() < MAX_CONCURRENT_EXPANSIONS {
+                let Some(key) = pending.next() else {
+                    break;
+                };
+                let jira_client = Arc::clone(&self.jira_client);
+                join_set.spawn(metrics::in_current_scope(async move {
+                    let result = jira_client
+                        .get_issue_details(&key, false, false, false)
+                        .await;
+                    (key, result)
+                }));
+            }
 
-        // Additional validation after normalization
-        if normalized.len() < 3 {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key too short after normalization",
-            ));
+            let Some(joined) = join_set.join_next().await else {
+                break;
+            };
+            match joined {
+                Ok((key, Ok(details))) => {
+                    infos.insert(key, details.issue_info);
+                }
+                Ok((key, Err(e))) => {
+                    warn!("Could not expand {}: {}", key, e);
+                    failures.push(format!("{}: {}", key, e));
+                }
+                Err(e) => {
+                    warn!("Expansion task failed: {}", e);
+                    failures.push(format!("task: {}", e));
+                }
+            }
         }
 
-        Ok(normalized)
+        failures.sort();
+        (infos, failures)
     }
 
     /// Validate the response from JIRA
@@ -323,6 +402,11 @@ impl GetIssueDetailsTool {
             }
         }
 
+        // Worklogs
+        if let Some(worklogs) = &issue_details.worklogs {
+            size += worklogs.len() * 300; // Rough estimate per worklog
+        }
+
         // History
         if include_history {
             if let Some(history) = &issue_details.history {
@@ -338,24 +422,9 @@ impl GetIssueDetailsTool {
         issue_key.split('-').next().map(|s| s.to_uppercase())
     }
 
-    /// Check if issue key format is valid (basic check)
+    /// Check if issue key format is valid
     pub fn is_valid_issue_key_format(&self, key: &str) -> bool {
-        if key.trim().is_empty() {
-            return false;
-        }
-
-        let parts: Vec<&str> = key.split('-').collect();
-        if parts.len() < 2 {
-            return false;
-        }
-
-        let project_part = parts[0];
-        let number_part = parts[1];
-
-        !project_part.is_empty()
-            && project_part.chars().all(|c| c.is_alphabetic())
-            && !number_part.is_empty()
-            && number_part.chars().all(|c| c.is_numeric())
+        normalize_issue_key(self.jira_client.base_url(), key, "issue_key").is_ok()
     }
 }
 
@@ -373,6 +442,10 @@ mod tests {
             include_comments: Some(true),
             include_attachments: Some(true),
             include_history: Some(false),
+            include_worklogs: None,
+            expand_subtasks: None,
+            expand_linked: None,
+            response_budget: None,
         }
     }
 
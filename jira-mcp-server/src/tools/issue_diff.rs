@@ -0,0 +1,314 @@
+//! "What changed since I last looked" digest of one issue
+//!
+//! Takes the events of the issue's activity stream (see `issue_activity`) that
+//! are newer than a cutoff and turns them into one chronological list of
+//! one-line entries, so an agent can catch a user up on an issue in a single
+//! call.
+
+use crate::error::JiraMcpResult;
+use crate::jira_client::JiraClient;
+use crate::semantic_mapping::resolve_instant;
+use crate::tools::issue_activity::{load_activity, ActivityEvent, ActivityEventType};
+use crate::tools::issue_key::normalize_issue_id_or_key;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// Parameters for the diff_issue_since tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DiffIssueSinceParams {
+    /// The JIRA issue key (e.g., "PROJ-123")
+    pub issue_key: String,
+
+    /// Only changes after this point in time
+    /// Examples: "2 hours ago", "yesterday", "3 days ago", "2024-01-15T09:00:00Z"
+    pub since: String,
+}
+
+/// Kind of a change in the digest
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueChangeKind {
+    FieldChange,
+    Comment,
+    Worklog,
+    Attachment,
+}
+
+/// One entry of the digest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueChange {
+    /// When it happened, as reported by JIRA
+    pub at: String,
+
+    pub kind: IssueChangeKind,
+
+    /// Display name of the user who made the change
+    pub author: String,
+
+    /// The change in one line,
+    /// e.g. "status: In Progress → In Review (by Alice, 2h ago)"
+    pub text: String,
+
+    /// Permalink, for comments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Number of digest entries per kind
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IssueChangeCounts {
+    pub field_changes: usize,
+    pub comments: usize,
+    pub worklogs: usize,
+    pub attachments: usize,
+}
+
+/// Result from the diff_issue_since tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiffIssueSinceResult {
+    pub issue_key: String,
+    pub summary: String,
+
+    /// Current status
+    pub status: String,
+
+    /// The cutoff `since` resolved to (UTC)
+    pub since: String,
+
+    /// Changes after the cutoff, oldest first
+    pub changes: Vec<IssueChange>,
+
+    pub counts: IssueChangeCounts,
+
+    /// Parts of the issue that could not be loaded, with the error
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Implementation of the diff_issue_since tool
+pub struct DiffIssueSinceTool {
+    jira_client: Arc<JiraClient>,
+}
+
+impl DiffIssueSinceTool {
+    pub fn new(jira_client: Arc<JiraClient>) -> Self {
+        Self { jira_client }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: DiffIssueSinceParams,
+    ) -> JiraMcpResult<DiffIssueSinceResult> {
+        let issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+        let now = Utc::now();
+        let since = resolve_instant(&params.since, now, "since")?;
+
+        info!("Collecting changes to {} since {}", issue_key, since);
+
+        let activity = load_activity(&self.jira_client, &issue_key).await?;
+        let changes = activity
+            .events
+            .into_iter()
+            .filter(|(time, _)| *time > since)
+            .map(|(time, event)| to_change(time, event, now))
+            .collect::<Vec<_>>();
+
+        let mut counts = IssueChangeCounts::default();
+        for change in &changes {
+            match change.kind {
+                IssueChangeKind::FieldChange => counts.field_changes += 1,
+                IssueChangeKind::Comment => counts.comments += 1,
+                IssueChangeKind::Worklog => counts.worklogs += 1,
+                IssueChangeKind::Attachment => counts.attachments += 1,
+            }
+        }
+
+        Ok(DiffIssueSinceResult {
+            issue_key: activity.details.issue_info.key,
+            summary: activity.details.issue_info.summary,
+            status: activity.details.issue_info.status,
+            since: since.to_rfc3339(),
+            changes,
+            counts,
+            warnings: activity.warnings,
+        })
+    }
+}
+
+/// Digest entry of an activity event, e.g.
+/// "status: In Progress → In Review (by Alice, 2h ago)"
+fn to_change(time: DateTime<Utc>, event: ActivityEvent, now: DateTime<Utc>) -> IssueChange {
+    let kind = match event.event_type {
+        ActivityEventType::FieldChange | ActivityEventType::LinkAdded => {
+            IssueChangeKind::FieldChange
+        }
+        ActivityEventType::Comment => IssueChangeKind::Comment,
+        ActivityEventType::Worklog => IssueChangeKind::Worklog,
+        ActivityEventType::AttachmentAdded => IssueChangeKind::Attachment,
+    };
+    let text = format!(
+        "{} (by {}, {})",
+        event.summary_line,
+        event.actor,
+        relative_time(time, now)
+    );
+    let url = event.details["url"].as_str().map(String::from);
+    IssueChange {
+        at: event.timestamp,
+        kind,
+        author: event.actor,
+        text,
+        url,
+    }
+}
+
+/// "just now", "5m ago", "2h ago", "3d ago", or the date for anything older
+/// than a month
+fn relative_time(time: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let elapsed = now.signed_duration_since(time);
+    if elapsed.num_minutes() < 1 {
+        "just now".to_string()
+    } else if elapsed.num_hours() < 1 {
+        format!("{}m ago", elapsed.num_minutes())
+    } else if elapsed.num_days() < 1 {
+        format!("{}h ago", elapsed.num_hours())
+    } else if elapsed.num_days() <= 30 {
+        format!("{}d ago", elapsed.num_days())
+    } else {
+        format!("on {}", time.format("%Y-%m-%d"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jira_client::{AttachmentInfo, CommentInfo, HistoryEntry, HistoryItem, WorklogInfo};
+    use crate::tools::issue_activity::collect_events;
+
+    /// Everything after `since`, oldest first
+    fn collect_changes(
+        history: &[HistoryEntry],
+        comments: &[CommentInfo],
+        worklogs: &[WorklogInfo],
+        attachments: &[AttachmentInfo],
+        since: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Vec<IssueChange> {
+        collect_events(history, comments, worklogs, attachments)
+            .into_iter()
+            .filter(|(time, _)| *time > since)
+            .map(|(time, event)| to_change(time, event, now))
+            .collect()
+    }
+
+    fn time(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+
+    fn item(field: &str, from: Option<&str>, to: Option<&str>) -> HistoryItem {
+        HistoryItem {
+            field: field.to_string(),
+            field_type: "jira".to_string(),
+            from: None,
+            from_string: from.map(String::from),
+            to: None,
+            to_string: to.map(String::from),
+        }
+    }
+
+    fn comment(author: &str, body: &str, created: &str) -> CommentInfo {
+        CommentInfo {
+            id: "1".to_string(),
+            author: author.to_string(),
+            body: body.to_string(),
+            created: created.to_string(),
+            updated: created.to_string(),
+            url: "https://jira.example.com/browse/PROJ-1?focusedCommentId=1".to_string(),
+            visibility: None,
+            jsm_internal: None,
+        }
+    }
+
+    #[test]
+    fn test_relative_time() {
+        let now = time("2025-03-12T15:30:00Z");
+        assert_eq!(relative_time(time("2025-03-12T15:29:30Z"), now), "just now");
+        assert_eq!(relative_time(time("2025-03-12T15:25:00Z"), now), "5m ago");
+        assert_eq!(relative_time(time("2025-03-12T13:00:00Z"), now), "2h ago");
+        assert_eq!(relative_time(time("2025-03-09T15:30:00Z"), now), "3d ago");
+        assert_eq!(
+            relative_time(time("2025-01-02T08:00:00Z"), now),
+            "on 2025-01-02"
+        );
+    }
+
+    #[test]
+    fn test_collect_changes_merges_in_order() {
+        let now = time("2025-03-12T15:30:00Z");
+        let since = time("2025-03-12T00:00:00Z");
+
+        let history = vec![
+            HistoryEntry {
+                id: "h1".to_string(),
+                author: "Alice".to_string(),
+                created: "2025-03-11T09:00:00.000+0000".to_string(),
+                items: vec![item("priority", Some("Medium"), Some("High"))],
+            },
+            HistoryEntry {
+                id: "h2".to_string(),
+                author: "Alice".to_string(),
+                created: "2025-03-12T13:30:00.000+0000".to_string(),
+                items: vec![
+                    item("status", Some("In Progress"), Some("In Review")),
+                    item("Attachment", None, Some("trace.log")),
+                ],
+            },
+        ];
+        let long_body = format!("Root cause found.\n\n{}", "details ".repeat(50));
+        let comments = vec![
+            comment("Bob", "Old news", "2025-03-11T10:00:00.000+0000"),
+            comment("Bob", &long_body, "2025-03-12T10:00:00.000+0000"),
+        ];
+        let worklogs = vec![WorklogInfo {
+            id: "w1".to_string(),
+            author: "Carol".to_string(),
+            comment: None,
+            created: "2025-03-12T15:00:00.000+0000".to_string(),
+            updated: "2025-03-12T15:00:00.000+0000".to_string(),
+            started: "2025-03-12T13:00:00.000+0000".to_string(),
+            time_spent: Some("2h".to_string()),
+            time_spent_seconds: Some(7200),
+        }];
+        let attachments = vec![AttachmentInfo {
+            id: "a1".to_string(),
+            filename: "trace.log".to_string(),
+            author: "Alice".to_string(),
+            created: "2025-03-12T13:30:00.000+0000".to_string(),
+            size: 1024,
+            mime_type: "text/plain".to_string(),
+            content_url: String::new(),
+            thumbnail_url: None,
+            author_account_id: None,
+        }];
+
+        let changes = collect_changes(&history, &comments, &worklogs, &attachments, since, now);
+        let texts: Vec<&str> = changes.iter().map(|change| change.text.as_str()).collect();
+
+        assert_eq!(texts.len(), 4, "{:?}", texts);
+        assert!(texts[0].starts_with("comment: Root cause found. details details"));
+        assert!(texts[0].ends_with("… (by Bob, 5h ago)"));
+        assert_eq!(
+            texts[1],
+            "status: In Progress → In Review (by Alice, 2h ago)"
+        );
+        assert_eq!(texts[2], "attached trace.log (by Alice, 2h ago)");
+        assert_eq!(texts[3], "logged 2h (by Carol, 30m ago)");
+        assert!(changes[0].url.is_some());
+    }
+}
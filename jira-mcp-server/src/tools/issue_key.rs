@@ -0,0 +1,252 @@
+//! Issue key normalization shared by every tool that takes an issue key
+//!
+//! Agents pass issue keys as "proj-123", " PROJ-123 ", a browse URL copied from
+//! JIRA or a markdown link to one. Every tool normalizes them the same way
+//! before use, and input that isn't an issue key fails with the same error
+//! everywhere. A URL pointing at another JIRA instance is rejected up front;
+//! looking its key up here would at best 404 and at worst find an unrelated
+//! issue with the same key.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+
+/// Accepted formats, for the error message
+const KEY_FORMATS: &str = "\"PROJ-123\" (any case), a browse URL such as \
+                           \"https://jira.example.com/browse/PROJ-123\" or a markdown link to one";
+
+/// Normalize an issue key: trim, extract it from a URL or markdown link and
+/// uppercase the project part
+///
+/// `jira_url` is the configured instance; URLs on any other host are rejected.
+/// Use this where the key itself matters (JQL, undo history, todo sessions, a
+/// project derived from the key); [`normalize_issue_id_or_key`] also accepts
+/// numeric issue IDs.
+pub fn normalize_issue_key(jira_url: &str, input: &str, parameter: &str) -> JiraMcpResult<String> {
+    let candidate = key_candidate(jira_url, input, parameter)?;
+    parse_issue_key(candidate).ok_or_else(|| {
+        JiraMcpError::invalid_param(
+            parameter,
+            format!(
+                "'{}' is not an issue key. Accepted formats: {}",
+                input.trim(),
+                KEY_FORMATS
+            ),
+        )
+    })
+}
+
+/// Like [`normalize_issue_key`], but also accepts numeric issue IDs ("10042"),
+/// which JIRA's /issue/{issueIdOrKey} endpoints take in place of a key
+pub fn normalize_issue_id_or_key(
+    jira_url: &str,
+    input: &str,
+    parameter: &str,
+) -> JiraMcpResult<String> {
+    let candidate = key_candidate(jira_url, input, parameter)?;
+    if !candidate.is_empty() && candidate.bytes().all(|b| b.is_ascii_digit()) {
+        return Ok(candidate.to_string());
+    }
+
+    parse_issue_key(candidate).ok_or_else(|| {
+        JiraMcpError::invalid_param(
+            parameter,
+            format!(
+                "'{}' is not an issue key or ID. Accepted formats: {}, or a numeric issue ID \
+                 such as \"10042\"",
+                input.trim(),
+                KEY_FORMATS
+            ),
+        )
+    })
+}
+
+/// Normalize a list of issue keys, naming the failing entry ("issue_keys[2]")
+pub fn normalize_issue_keys(
+    jira_url: &str,
+    inputs: &[String],
+    parameter: &str,
+) -> JiraMcpResult<Vec<String>> {
+    inputs
+        .iter()
+        .enumerate()
+        .map(|(i, input)| normalize_issue_key(jira_url, input, &format!("{}[{}]", parameter, i)))
+        .collect()
+}
+
+/// The part of the input that should be the key: the input itself, or the key
+/// in a URL or markdown link, after checking the URL's host
+fn key_candidate<'a>(jira_url: &str, input: &'a str, parameter: &str) -> JiraMcpResult<&'a str> {
+    let input = input.trim();
+    let link = markdown_link_target(input).unwrap_or(input);
+    let link = link
+        .strip_prefix('<')
+        .and_then(|link| link.strip_suffix('>'))
+        .unwrap_or(link)
+        .trim();
+
+    let Some(key) = url_issue_key(link) else {
+        return Ok(link);
+    };
+    if let (Some(host), Some(expected)) = (url_host(link), url_host(jira_url)) {
+        if !host.eq_ignore_ascii_case(expected) {
+            return Err(JiraMcpError::invalid_param(
+                parameter,
+                format!(
+                    "'{}' belongs to a different JIRA instance ({}). This server is connected \
+                     to {}",
+                    input,
+                    host,
+                    jira_url.trim_end_matches('/')
+                ),
+            ));
+        }
+    }
+    Ok(key)
+}
+
+fn parse_issue_key(candidate: &str) -> Option<String> {
+    let (project, number) = candidate.split_once('-')?;
+    let valid = project.starts_with(|c: char| c.is_ascii_alphabetic())
+        && project
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !number.is_empty()
+        && number.chars().all(|c| c.is_ascii_digit());
+
+    valid.then(|| format!("{}-{}", project.to_ascii_uppercase(), number))
+}
+
+/// The target of a markdown link such as "[PROJ-123](https://...)"
+fn markdown_link_target(input: &str) -> Option<&str> {
+    let (_, target) = input.strip_prefix('[')?.split_once("](")?;
+    target.strip_suffix(')')
+}
+
+/// Host (and port) of a URL; None for relative URLs
+fn url_host(url: &str) -> Option<&str> {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let authority = rest.split(['/', '?', '#']).next()?;
+    let host = authority
+        .rsplit_once('@')
+        .map_or(authority, |(_, host)| host);
+    (!host.is_empty()).then_some(host)
+}
+
+/// The key in a ".../browse/PROJ-123" URL or a board URL's "selectedIssue="
+fn url_issue_key(input: &str) -> Option<&str> {
+    let start = match input.find("/browse/") {
+        Some(index) => index + "/browse/".len(),
+        None => input.find("selectedIssue=")? + "selectedIssue=".len(),
+    };
+    let rest = &input[start..];
+    let end = rest.find(['/', '?', '#', '&']).unwrap_or(rest.len());
+    Some(&rest[..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const JIRA_URL: &str = "https://jira.example.com/";
+
+    #[test]
+    fn test_normalize_issue_key() {
+        for input in [
+            "PROJ-123",
+            "proj-123",
+            "  Proj-123\n",
+            "https://jira.example.com/browse/proj-123",
+            "https://JIRA.example.com/browse/PROJ-123?focusedCommentId=1#comment-1",
+            "jira.example.com/browse/PROJ-123/",
+            "https://jira.example.com/jira/software/projects/PROJ/boards/1?selectedIssue=PROJ-123",
+            "[PROJ-123](https://jira.example.com/browse/PROJ-123)",
+            " [Checkout fails](<https://jira.example.com/browse/proj-123>) ",
+            "<https://jira.example.com/browse/PROJ-123>",
+        ] {
+            assert_eq!(
+                normalize_issue_key(JIRA_URL, input, "issue_key").unwrap(),
+                "PROJ-123"
+            );
+        }
+        assert_eq!(
+            normalize_issue_key(JIRA_URL, "my_team2-7", "issue_key").unwrap(),
+            "MY_TEAM2-7"
+        );
+
+        for input in [
+            "",
+            "PROJ",
+            "PROJ-",
+            "PROJ-12a",
+            "123-4",
+            "PROJ-1) OR key in (X-1",
+            "https://jira.example.com/browse/",
+            "[PROJ-1](https://jira.example.com/issues)",
+            "10042",
+        ] {
+            let error = normalize_issue_key(JIRA_URL, input, "issue_key")
+                .unwrap_err()
+                .to_string();
+            assert!(error.contains("Accepted formats"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_issue_url_of_another_instance() {
+        for input in [
+            "https://other.atlassian.net/browse/PROJ-123",
+            "[PROJ-123](https://other.atlassian.net/browse/PROJ-123)",
+            "https://jira.example.com:8443/browse/PROJ-123",
+        ] {
+            let error = normalize_issue_key(JIRA_URL, input, "root_issue_key")
+                .unwrap_err()
+                .to_string();
+            assert!(error.contains("different JIRA instance"), "{}", error);
+            assert!(error.contains("root_issue_key"), "{}", error);
+        }
+
+        // Without a configured instance (mock mode) any host is accepted
+        assert_eq!(
+            normalize_issue_key("", "https://other.atlassian.net/browse/PROJ-1", "issue_key")
+                .unwrap(),
+            "PROJ-1"
+        );
+    }
+
+    #[test]
+    fn test_normalize_issue_id_or_key() {
+        assert_eq!(
+            normalize_issue_id_or_key(JIRA_URL, " 10042 ", "issue_key").unwrap(),
+            "10042"
+        );
+        assert_eq!(
+            normalize_issue_id_or_key(JIRA_URL, "proj-1", "issue_key").unwrap(),
+            "PROJ-1"
+        );
+        let error = normalize_issue_id_or_key(JIRA_URL, "-1", "issue_key")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("numeric issue ID"), "{}", error);
+
+        let keys = normalize_issue_keys(
+            JIRA_URL,
+            &[
+                "A-1".to_string(),
+                "[B-2](https://jira.example.com/browse/B-2)".to_string(),
+            ],
+            "issue_keys",
+        )
+        .unwrap();
+        assert_eq!(keys, vec!["A-1", "B-2"]);
+        let error = normalize_issue_keys(
+            JIRA_URL,
+            &[
+                "A-1".to_string(),
+                "https://other.atlassian.net/browse/B-2".to_string(),
+            ],
+            "issue_keys",
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("issue_keys[1]"), "{}", error);
+    }
+}
@@ -3,14 +3,19 @@
 //! Provides tools for managing links between JIRA issues, including creating,
 //! deleting, and retrieving link types.
 
+use crate::cache::MetadataCache;
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_key;
 use gouqi::CreateIssueLinkInput;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::sync::Arc;
 use tracing::{info, instrument};
 
+pub use crate::cache::IssueLinkTypeInfo;
+
 /// Parameters for the link_issues tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -120,27 +125,6 @@ impl std::fmt::Display for GetIssueLinkTypesResult {
     }
 }
 
-/// Information about an issue link type
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IssueLinkTypeInfo {
-    /// Link type ID
-    pub id: String,
-
-    /// Link type name (use this when creating links)
-    pub name: String,
-
-    /// Description when viewing from the inward issue
-    /// Example: "is blocked by"
-    pub inward: String,
-
-    /// Description when viewing from the outward issue
-    /// Example: "blocks"
-    pub outward: String,
-
-    /// Direct link to the link type
-    pub self_link: String,
-}
-
 /// Tool for linking issues
 pub struct LinkIssuesTool {
     jira_client: Arc<JiraClient>,
@@ -152,26 +136,23 @@ impl LinkIssuesTool {
     }
 
     #[instrument(skip(self))]
-    pub async fn execute(&self, params: LinkIssuesParams) -> JiraMcpResult<LinkIssuesResult> {
+    pub async fn execute(&self, mut params: LinkIssuesParams) -> JiraMcpResult<LinkIssuesResult> {
         info!(
             "Linking issues {} -> {} with type '{}'",
             params.inward_issue_key, params.outward_issue_key, params.link_type
         );
 
         // Validate parameters
-        if params.inward_issue_key.trim().is_empty() {
-            return Err(JiraMcpError::invalid_param(
-                "inward_issue_key",
-                "Inward issue key is required",
-            ));
-        }
-
-        if params.outward_issue_key.trim().is_empty() {
-            return Err(JiraMcpError::invalid_param(
-                "outward_issue_key",
-                "Outward issue key is required",
-            ));
-        }
+        params.inward_issue_key = normalize_issue_key(
+            self.jira_client.base_url(),
+            &params.inward_issue_key,
+            "inward_issue_key",
+        )?;
+        params.outward_issue_key = normalize_issue_key(
+            self.jira_client.base_url(),
+            &params.outward_issue_key,
+            "outward_issue_key",
+        )?;
 
         if params.link_type.trim().is_empty() {
             return Err(JiraMcpError::invalid_param(
@@ -192,23 +173,29 @@ impl LinkIssuesTool {
         }
 
         self.jira_client
-            .client
-            .issue_links()
-            .create(link_input)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") {
-                    JiraMcpError::not_found(
-                        "issue or link type",
-                        format!(
-                            "{}, {}, or {}",
-                            params.inward_issue_key, params.outward_issue_key, params.link_type
-                        ),
-                    )
-                } else {
-                    JiraMcpError::internal(format!("Failed to create issue link: {}", e))
-                }
-            })?;
+            .post_json::<Value>(
+                &format!(
+                    "linking {} to {}",
+                    params.inward_issue_key, params.outward_issue_key
+                ),
+                "api",
+                "/issueLink",
+                link_input,
+                |e| {
+                    if e.to_string().contains("404") {
+                        JiraMcpError::not_found(
+                            "issue or link type",
+                            format!(
+                                "{}, {}, or {}",
+                                params.inward_issue_key, params.outward_issue_key, params.link_type
+                            ),
+                        )
+                    } else {
+                        JiraMcpError::internal(format!("Failed to create issue link: {}", e))
+                    }
+                },
+            )
+            .await?;
 
         let message = format!(
             "Successfully linked {} to {} with '{}' link type",
@@ -252,17 +239,19 @@ impl DeleteIssueLinkTool {
         }
 
         self.jira_client
-            .client
-            .issue_links()
-            .delete(&params.link_id)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") {
-                    JiraMcpError::not_found("issue link", &params.link_id)
-                } else {
-                    JiraMcpError::internal(format!("Failed to delete issue link: {}", e))
-                }
-            })?;
+            .delete_json::<Value>(
+                &format!("deleting issue link {}", params.link_id),
+                "api",
+                &format!("/issueLink/{}", params.link_id),
+                |e| {
+                    if e.to_string().contains("404") {
+                        JiraMcpError::not_found("issue link", &params.link_id)
+                    } else {
+                        JiraMcpError::internal(format!("Failed to delete issue link: {}", e))
+                    }
+                },
+            )
+            .await?;
 
         let message = format!("Successfully deleted issue link {}", params.link_id);
 
@@ -279,48 +268,26 @@ impl DeleteIssueLinkTool {
 /// Tool for getting available issue link types
 pub struct GetIssueLinkTypesTool {
     jira_client: Arc<JiraClient>,
+    cache: Arc<MetadataCache>,
 }
 
 impl GetIssueLinkTypesTool {
-    pub fn new(jira_client: Arc<JiraClient>) -> Self {
-        Self { jira_client }
+    pub fn new(jira_client: Arc<JiraClient>, cache: Arc<MetadataCache>) -> Self {
+        Self { jira_client, cache }
     }
 
     #[instrument(skip(self))]
     pub async fn execute(&self) -> JiraMcpResult<GetIssueLinkTypesResult> {
         info!("Getting issue link types");
 
-        // Make direct API call to get link types
-        // gouqi doesn't have a dedicated method for this, so we'll use the raw client
-        let response: serde_json::Value = self
-            .jira_client
-            .client
-            .get("api", "/issueLinkType")
-            .await
-            .map_err(|e| {
-                JiraMcpError::internal(format!("Failed to get issue link types: {}", e))
-            })?;
-
-        // Parse the response
-        let link_types_array = response
-            .get("issueLinkTypes")
-            .and_then(|v| v.as_array())
-            .ok_or_else(|| {
-                JiraMcpError::internal("Invalid response format from JIRA API".to_string())
-            })?;
-
-        let link_types: Vec<IssueLinkTypeInfo> = link_types_array
-            .iter()
-            .filter_map(|lt| {
-                Some(IssueLinkTypeInfo {
-                    id: lt.get("id")?.as_str()?.to_string(),
-                    name: lt.get("name")?.as_str()?.to_string(),
-                    inward: lt.get("inward")?.as_str()?.to_string(),
-                    outward: lt.get("outward")?.as_str()?.to_string(),
-                    self_link: lt.get("self")?.as_str()?.to_string(),
-                })
-            })
-            .collect();
+        let link_types = match self.cache.get_link_types() {
+            Some(link_types) => link_types,
+            None => {
+                let link_types = self.jira_client.get_issue_link_types().await?;
+                self.cache.set_link_types(link_types.clone())?;
+                link_types
+            }
+        };
 
         let total = link_types.len();
 
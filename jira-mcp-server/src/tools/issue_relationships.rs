@@ -3,14 +3,21 @@
 //! This tool leverages gouqi's relationship graph capabilities to extract and visualize
 //! JIRA issue relationships, allowing AI agents to understand issue dependencies,
 //! blockers, and connections.
+//!
+//! With `time_budget_ms`, a large graph is returned in parts: each result
+//! carries the issues visited so far and a continuation token for the rest.
 
 use crate::cache::MetadataCache;
 use crate::config::JiraConfig;
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::JiraClient;
+use crate::metrics::{impl_measured, ToolPerformance};
+use crate::tools::continuation::{decode_token, encode_token, TimeBudget};
+use crate::tools::issue_key::normalize_issue_key;
 use gouqi::relationships::GraphOptions;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, VecDeque};
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::{debug, info, instrument};
@@ -45,6 +52,44 @@ pub struct IssueRelationshipsParams {
     /// Whether to include duplicate relationships
     #[serde(default)]
     pub include_duplicates: bool,
+
+    /// Return what was found once this many milliseconds are nearly used up,
+    /// with a continuation_token for the rest (optional, default: no limit)
+    pub time_budget_ms: Option<u64>,
+
+    /// continuation_token of a partial result, to visit the rest of the graph;
+    /// pass the other parameters unchanged (optional)
+    pub continuation_token: Option<String>,
+}
+
+/// Name the continuation tokens of this tool are issued under
+const TOOL_NAME: &str = "get_issue_relationships";
+
+/// Breadth-first walk of the graph, carried in continuation tokens
+#[derive(Debug, Serialize, Deserialize)]
+struct GraphWalk {
+    root: String,
+    /// Issues still to visit, with their depth
+    frontier: VecDeque<(String, u32)>,
+    /// Issues visited or queued, so none is visited twice
+    seen: BTreeSet<String>,
+}
+
+impl GraphWalk {
+    fn new(root: &str) -> Self {
+        Self {
+            root: root.to_string(),
+            frontier: VecDeque::from([(root.to_string(), 0)]),
+            seen: BTreeSet::from([root.to_string()]),
+        }
+    }
+
+    /// Queue an issue unless it was seen before
+    fn discover(&mut self, issue_key: &str, depth: u32) {
+        if self.seen.insert(issue_key.to_string()) {
+            self.frontier.push_back((issue_key.to_string(), depth));
+        }
+    }
 }
 
 fn default_depth() -> u32 {
@@ -110,19 +155,33 @@ pub struct IssueRelationshipsResult {
     /// Maximum depth that was traversed
     pub max_depth: u32,
 
-    /// All issue nodes in the relationship graph
+    /// Issue nodes visited by this call; earlier partial results hold the rest
     pub nodes: Vec<IssueNode>,
 
-    /// All relationships found between issues
+    /// Relationships of the issues visited by this call
     pub relationships: Vec<IssueRelationship>,
 
+    /// True when the time budget ran out before the whole graph was visited
+    #[serde(default)]
+    pub partial: bool,
+
+    /// Pass back as continuation_token to visit the rest of the graph
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+
     /// Summary statistics
     pub summary: RelationshipSummary,
 
     /// Execution time in milliseconds
     pub execution_time_ms: u64,
+
+    /// Timing and request counts of the call
+    #[serde(default)]
+    pub performance: ToolPerformance,
 }
 
+impl_measured!(IssueRelationshipsResult);
+
 /// Summary statistics about the relationship graph
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct RelationshipSummary {
@@ -167,48 +226,145 @@ impl IssueRelationshipsTool {
     }
 
     /// Execute the relationship extraction
+    ///
+    /// The graph is walked breadth-first, one issue per step. When the time
+    /// budget runs out first, the issues still to visit go into the
+    /// continuation token.
     #[instrument(skip(self))]
     pub async fn execute(
         &self,
-        params: IssueRelationshipsParams,
+        mut params: IssueRelationshipsParams,
     ) -> JiraMcpResult<IssueRelationshipsResult> {
         let start_time = Instant::now();
+        let mut budget = TimeBudget::new(params.time_budget_ms)?;
 
         info!(
             "Extracting relationship graph for issue {} with depth {}",
             params.root_issue_key, params.max_depth
         );
 
-        // Validate the root issue key format
-        self.validate_issue_key(&params.root_issue_key)?;
+        params.root_issue_key = normalize_issue_key(
+            self.jira_client.base_url(),
+            &params.root_issue_key,
+            "root_issue_key",
+        )?;
+
+        let mut walk = match &params.continuation_token {
+            Some(token) => {
+                let walk: GraphWalk = decode_token(TOOL_NAME, token)?;
+                if walk.root != params.root_issue_key {
+                    return Err(JiraMcpError::invalid_param(
+                        "continuation_token",
+                        format!(
+                            "The token continues the graph of {}, not {}",
+                            walk.root, params.root_issue_key
+                        ),
+                    ));
+                }
+                walk
+            }
+            None => GraphWalk::new(&params.root_issue_key),
+        };
 
-        // Build GraphOptions from parameters
-        let options = self.build_graph_options(&params);
+        let mut nodes = Vec::new();
+        let mut relationships = Vec::new();
+        let mut issues_by_depth = std::collections::HashMap::new();
+        let mut relationships_by_type = std::collections::HashMap::new();
+        let mut inaccessible_issues = Vec::new();
 
-        debug!(
-            "Built graph options with include_types: {:?}",
-            options.include_types
-        );
+        while budget.allows_step() {
+            let Some((issue_key, depth)) = walk.frontier.pop_front() else {
+                break;
+            };
+            let step_started = Instant::now();
+
+            let graph = self
+                .jira_client
+                .get_issue_relationships(&issue_key, self.build_graph_options(&params))
+                .await;
+            let issue_rels = match graph {
+                Ok(mut graph) => graph.issues.remove(&issue_key),
+                Err(e) if issue_key == walk.root && depth == 0 => return Err(e),
+                Err(e) => {
+                    debug!("Failed to fetch links of {}: {}", issue_key, e);
+                    inaccessible_issues.push(issue_key);
+                    budget.finish_step(step_started);
+                    continue;
+                }
+            };
+
+            // Fetch full issue details for this node
+            match self
+                .jira_client
+                .get_issue_details(&issue_key, false, false, false)
+                .await
+            {
+                Ok(details) => {
+                    nodes.push(IssueNode {
+                        key: details.issue_info.key,
+                        summary: details.issue_info.summary,
+                        issue_type: details.issue_info.issue_type,
+                        status: details.issue_info.status,
+                        priority: details.issue_info.priority,
+                        assignee: details.issue_info.assignee,
+                        project_key: details.issue_info.project_key,
+                        depth,
+                    });
+                    *issues_by_depth.entry(depth).or_insert(0) += 1;
+                }
+                Err(e) => {
+                    debug!("Failed to fetch details for {}: {}", issue_key, e);
+                    inaccessible_issues.push(issue_key.clone());
+                }
+            }
 
-        // Use gouqi's built-in recursive relationship graph extraction
-        let graph = self
-            .jira_client
-            .client
-            .issues()
-            .get_relationship_graph(&params.root_issue_key, params.max_depth, Some(options))
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
-                    JiraMcpError::not_found("issue", &params.root_issue_key)
-                } else {
-                    JiraMcpError::from(e)
+            let first_new = relationships.len();
+            if let Some(issue_rels) = &issue_rels {
+                self.add_relationships(
+                    &issue_key,
+                    issue_rels,
+                    &params,
+                    &mut relationships,
+                    &mut relationships_by_type,
+                );
+            }
+            if depth < params.max_depth {
+                for relationship in &relationships[first_new..] {
+                    walk.discover(&relationship.to_issue, depth + 1);
                 }
-            })?;
+            }
+
+            budget.finish_step(step_started);
+        }
 
-        // Convert gouqi's RelationshipGraph to our MCP format
-        let result = self
-            .convert_graph_to_result(graph, params, start_time)
-            .await?;
+        let partial = !walk.frontier.is_empty();
+        let continuation_token = if partial {
+            info!(
+                "Time budget used up with {} issues left to visit",
+                walk.frontier.len()
+            );
+            Some(encode_token(TOOL_NAME, &walk)?)
+        } else {
+            None
+        };
+
+        let result = IssueRelationshipsResult {
+            root_issue: params.root_issue_key,
+            max_depth: params.max_depth,
+            summary: RelationshipSummary {
+                total_issues: nodes.len(),
+                total_relationships: relationships.len(),
+                issues_by_depth,
+                relationships_by_type,
+                inaccessible_issues,
+            },
+            nodes,
+            relationships,
+            partial,
+            continuation_token,
+            execution_time_ms: start_time.elapsed().as_millis() as u64,
+            performance: ToolPerformance::default(),
+        };
 
         info!(
             "Relationship extraction completed in {}ms: {} issues, {} relationships",
@@ -261,90 +417,6 @@ impl IssueRelationshipsTool {
         }
     }
 
-    /// Convert gouqi's RelationshipGraph to our MCP result format
-    async fn convert_graph_to_result(
-        &self,
-        graph: gouqi::relationships::RelationshipGraph,
-        params: IssueRelationshipsParams,
-        start_time: Instant,
-    ) -> JiraMcpResult<IssueRelationshipsResult> {
-        let mut nodes = Vec::new();
-        let mut relationships = Vec::new();
-        let mut issues_by_depth = std::collections::HashMap::new();
-        let mut relationships_by_type = std::collections::HashMap::new();
-        let mut inaccessible_issues = Vec::new();
-
-        // Process each issue in the graph
-        for (issue_key, issue_rels) in &graph.issues {
-            // Fetch full issue details for this node
-            let issue_details = match self
-                .jira_client
-                .get_issue_details(issue_key, false, false, false)
-                .await
-            {
-                Ok(details) => details,
-                Err(e) => {
-                    debug!("Failed to fetch details for {}: {}", issue_key, e);
-                    inaccessible_issues.push(issue_key.clone());
-                    continue;
-                }
-            };
-
-            // Calculate depth from root (simplified - assumes breadth-first traversal)
-            let depth = if issue_key == &params.root_issue_key {
-                0
-            } else {
-                graph
-                    .get_path(&params.root_issue_key, issue_key)
-                    .map(|path| (path.len() - 1) as u32)
-                    .unwrap_or(params.max_depth)
-            };
-
-            // Create issue node
-            let node = IssueNode {
-                key: issue_details.issue_info.key.clone(),
-                summary: issue_details.issue_info.summary.clone(),
-                issue_type: issue_details.issue_info.issue_type.clone(),
-                status: issue_details.issue_info.status.clone(),
-                priority: issue_details.issue_info.priority.clone(),
-                assignee: issue_details.issue_info.assignee.clone(),
-                project_key: issue_details.issue_info.project_key.clone(),
-                depth,
-            };
-
-            nodes.push(node);
-            *issues_by_depth.entry(depth).or_insert(0) += 1;
-
-            // Process relationships
-            self.add_relationships(
-                issue_key,
-                issue_rels,
-                &params,
-                &mut relationships,
-                &mut relationships_by_type,
-            );
-        }
-
-        let summary = RelationshipSummary {
-            total_issues: nodes.len(),
-            total_relationships: relationships.len(),
-            issues_by_depth,
-            relationships_by_type,
-            inaccessible_issues,
-        };
-
-        let execution_time = start_time.elapsed().as_millis() as u64;
-
-        Ok(IssueRelationshipsResult {
-            root_issue: params.root_issue_key,
-            max_depth: params.max_depth,
-            nodes,
-            relationships,
-            summary,
-            execution_time_ms: execution_time,
-        })
-    }
-
     /// Add relationships from gouqi's IssueRelationships to our format
     fn add_relationships(
         &self,
@@ -474,43 +546,6 @@ impl IssueRelationshipsTool {
             }
         }
     }
-
-    /// Validate issue key format
-    fn validate_issue_key(&self, issue_key: &str) -> JiraMcpResult<()> {
-        if issue_key.is_empty() {
-            return Err(JiraMcpError::invalid_param(
-                "root_issue_key",
-                "Issue key cannot be empty",
-            ));
-        }
-
-        let parts: Vec<&str> = issue_key.split('-').collect();
-        if parts.len() != 2 {
-            return Err(JiraMcpError::invalid_param(
-                "root_issue_key",
-                "Issue key must be in format 'PROJECT-123'",
-            ));
-        }
-
-        let project_key = parts[0];
-        let issue_number = parts[1];
-
-        if project_key.is_empty() || !project_key.chars().all(|c| c.is_ascii_uppercase()) {
-            return Err(JiraMcpError::invalid_param(
-                "root_issue_key",
-                "Project key must contain only uppercase letters",
-            ));
-        }
-
-        if issue_number.is_empty() || !issue_number.chars().all(|c| c.is_ascii_digit()) {
-            return Err(JiraMcpError::invalid_param(
-                "root_issue_key",
-                "Issue number must contain only digits",
-            ));
-        }
-
-        Ok(())
-    }
 }
 
 #[cfg(test)]
@@ -535,4 +570,25 @@ mod tests {
         // assert!(tool.validate_issue_key("proj-123").is_err());
         // assert!(tool.validate_issue_key("PROJ-abc").is_err());
     }
+
+    #[test]
+    fn test_graph_walk_survives_token() {
+        let mut walk = GraphWalk::new("PROJ-1");
+        walk.frontier.pop_front();
+        walk.discover("PROJ-2", 1);
+        walk.discover("PROJ-3", 1);
+        walk.discover("PROJ-2", 2);
+        walk.discover("PROJ-1", 1);
+        assert_eq!(walk.frontier.len(), 2);
+
+        let token = encode_token(TOOL_NAME, &walk).unwrap();
+        let mut resumed: GraphWalk = decode_token(TOOL_NAME, &token).unwrap();
+        assert_eq!(resumed.root, "PROJ-1");
+        assert_eq!(
+            resumed.frontier.pop_front(),
+            Some(("PROJ-2".to_string(), 1))
+        );
+        resumed.discover("PROJ-3", 2);
+        assert_eq!(resumed.frontier.len(), 1);
+    }
 }
@@ -1,13 +1,23 @@
+use crate::cache::MetadataCache;
+use crate::config::OperationClass;
 use crate::error::{JiraMcpError, JiraMcpResult};
-use crate::jira_client::JiraClient;
+use crate::jira_client::{JiraClient, SearchResult};
+use crate::jql;
+use crate::tools::issue_key::normalize_issue_id_or_key;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::sync::Arc;
-use tracing::{info, instrument};
+use tracing::{debug, info, instrument};
+
+/// Page size for walking the /label endpoint
+const LABEL_PAGE_SIZE: u32 = 1000;
+
+/// Upper bound on /label pages fetched when loading the full label list
+const MAX_LABEL_PAGES: u32 = 50;
 
 /// Parameters for managing issue labels
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct ManageLabelsParams {
     /// The JIRA issue key (e.g., "PROJ-123")
     pub issue_key: String,
@@ -81,21 +91,101 @@ pub struct GetAvailableLabelsResult {
     pub is_last: bool,
 }
 
+/// Parameters for listing labels
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ListLabelsParams {
+    /// Case-insensitive substring filter (optional)
+    /// Examples: "front", "tech-debt"
+    #[serde(default)]
+    pub filter: Option<String>,
+
+    /// Maximum number of labels to return (default: 100, max: 1000)
+    #[serde(default)]
+    pub max_results: Option<u32>,
+
+    /// Starting index for pagination over the filtered labels (default: 0)
+    #[serde(default)]
+    pub start_at: Option<u32>,
+
+    /// Bypass the label cache and reload from JIRA (default: false)
+    #[serde(default)]
+    pub refresh: bool,
+}
+
+/// Result from listing labels
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ListLabelsResult {
+    /// Labels on this page, sorted
+    pub labels: Vec<String>,
+
+    /// Number of labels matching the filter
+    pub total: u32,
+
+    /// Starting index
+    pub start_at: u32,
+
+    /// Maximum results per page
+    pub max_results: u32,
+
+    /// Whether this is the last page
+    pub is_last: bool,
+
+    /// Whether the label list came from the cache
+    pub from_cache: bool,
+}
+
+/// Parameters for searching issues by label
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GetIssuesByLabelParams {
+    /// Labels to search for (required)
+    pub labels: Vec<String>,
+
+    /// Require every label instead of any of them (default: false)
+    #[serde(default)]
+    pub match_all: bool,
+
+    /// Restrict to one project (optional)
+    #[serde(default)]
+    pub project_key: Option<String>,
+
+    /// Maximum number of issues to return (default: 50)
+    #[serde(default)]
+    pub limit: Option<u32>,
+
+    /// Starting index for pagination (default: 0)
+    #[serde(default)]
+    pub start_at: Option<u32>,
+}
+
+/// Result from searching issues by label
+#[derive(Debug, Serialize)]
+pub struct GetIssuesByLabelResult {
+    /// The matching issues
+    pub search_result: SearchResult,
+
+    /// The JQL query that was executed
+    pub jql_query: String,
+}
+
 /// Tool for managing JIRA issue labels
 pub struct LabelsTool {
     jira_client: Arc<JiraClient>,
+    cache: Arc<MetadataCache>,
 }
 
 impl LabelsTool {
-    pub fn new(jira_client: Arc<JiraClient>) -> Self {
-        Self { jira_client }
+    pub fn new(jira_client: Arc<JiraClient>, cache: Arc<MetadataCache>) -> Self {
+        Self { jira_client, cache }
     }
 
     #[instrument(skip(self))]
     pub async fn manage_labels(
         &self,
-        params: ManageLabelsParams,
+        mut params: ManageLabelsParams,
     ) -> JiraMcpResult<ManageLabelsResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
         info!(
             "Managing labels for issue {}: add={:?}, remove={:?}, replace={}",
             params.issue_key, params.add_labels, params.remove_labels, params.replace_all
@@ -149,24 +239,32 @@ impl LabelsTool {
         // Make the API call
         let endpoint = format!("/issue/{}", params.issue_key);
         self.jira_client
-            .client
-            .put::<(), _>("api", &endpoint, update_body)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
-                    JiraMcpError::not_found("issue", &params.issue_key)
-                } else {
-                    JiraMcpError::internal(format!("Failed to update labels: {}", e))
-                }
-            })?;
+            .put_json::<Value>(
+                &format!("updating the labels of {}", params.issue_key),
+                "api",
+                &endpoint,
+                update_body,
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("issue", &params.issue_key)
+                    } else {
+                        JiraMcpError::internal(format!("Failed to update labels: {}", e))
+                    }
+                },
+            )
+            .await?;
 
         // Fetch the issue to get current labels
         let issue: Value = self
             .jira_client
-            .client
-            .get("api", &format!("/issue/{}?fields=labels", params.issue_key))
-            .await
-            .map_err(|e| JiraMcpError::internal(format!("Failed to fetch updated issue: {}", e)))?;
+            .get_json(
+                OperationClass::Read,
+                &format!("reading the labels of {}", params.issue_key),
+                "api",
+                &format!("/issue/{}?fields=labels", params.issue_key),
+                |e| JiraMcpError::internal(format!("Failed to fetch updated issue: {}", e)),
+            )
+            .await?;
 
         let current_labels = issue["fields"]["labels"]
             .as_array()
@@ -224,43 +322,31 @@ impl LabelsTool {
 
         if let Some(project_key) = &params.project_key {
             // Get labels specific to a project by searching issues
-            // Use JQL search via POST to avoid URL encoding issues and use v3 API
-            let jql = format!("project = {} AND labels is not EMPTY", project_key);
-            let search_body = serde_json::json!({
-                "jql": jql,
-                "fields": ["labels"],
-                "maxResults": max_results,
-                "startAt": start_at
-            });
-
-            let response: Value = self
+            let jql = format!(
+                "{} = {} AND {} is not EMPTY",
+                jql::PROJECT,
+                jql::quote(project_key),
+                jql::LABELS
+            );
+            // Issues start_at.. of the search; the pages before are fetched and skipped
+            let paged = self
                 .jira_client
-                .client
-                .post("api", "/search", search_body)
-                .await
-                .map_err(|e| {
-                    JiraMcpError::internal(format!("Failed to search for labels: {}", e))
-                })?;
+                .search_all_issues_jql(&jql, start_at.saturating_add(max_results) as usize)
+                .await?;
 
             // Extract unique labels from all issues
-            let mut labels_set = std::collections::HashSet::new();
-            if let Some(issues) = response["issues"].as_array() {
-                for issue in issues {
-                    if let Some(labels) = issue["fields"]["labels"].as_array() {
-                        for label in labels {
-                            if let Some(label_str) = label.as_str() {
-                                labels_set.insert(label_str.to_string());
-                            }
-                        }
-                    }
-                }
-            }
+            let labels_set: std::collections::HashSet<String> = paged
+                .issues
+                .into_iter()
+                .skip(start_at as usize)
+                .flat_map(|issue| issue.labels)
+                .collect();
 
             let mut labels: Vec<String> = labels_set.into_iter().collect();
             labels.sort();
 
             let total = labels.len() as u32;
-            let is_last = true; // We fetch all at once from issues
+            let is_last = paged.complete;
 
             Ok(GetAvailableLabelsResult {
                 labels,
@@ -275,12 +361,14 @@ impl LabelsTool {
 
             let response: Value = self
                 .jira_client
-                .client
-                .get("api", &endpoint)
-                .await
-                .map_err(|e| {
-                    JiraMcpError::internal(format!("Failed to get available labels: {}", e))
-                })?;
+                .get_json(
+                    OperationClass::Read,
+                    "listing labels",
+                    "api",
+                    &endpoint,
+                    |e| JiraMcpError::internal(format!("Failed to get available labels: {}", e)),
+                )
+                .await?;
 
             let labels: Vec<String> = response["values"]
                 .as_array()
@@ -303,4 +391,243 @@ impl LabelsTool {
             })
         }
     }
+
+    #[instrument(skip(self))]
+    pub async fn list_labels(&self, params: ListLabelsParams) -> JiraMcpResult<ListLabelsResult> {
+        let max_results = params.max_results.unwrap_or(100).clamp(1, 1000);
+        let start_at = params.start_at.unwrap_or(0);
+
+        let from_cache = !params.refresh && self.cache.get_labels().is_some();
+        let all_labels = self.known_labels(params.refresh).await?;
+
+        let filter = params.filter.as_deref().map(str::to_lowercase);
+        let matching: Vec<String> = all_labels
+            .into_iter()
+            .filter(|label| {
+                filter
+                    .as_deref()
+                    .is_none_or(|f| label.to_lowercase().contains(f))
+            })
+            .collect();
+
+        let total = matching.len() as u32;
+        let labels: Vec<String> = matching
+            .into_iter()
+            .skip(start_at as usize)
+            .take(max_results as usize)
+            .collect();
+        let is_last = start_at + (labels.len() as u32) >= total;
+
+        Ok(ListLabelsResult {
+            labels,
+            total,
+            start_at,
+            max_results,
+            is_last,
+            from_cache,
+        })
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_issues_by_label(
+        &self,
+        params: GetIssuesByLabelParams,
+    ) -> JiraMcpResult<GetIssuesByLabelResult> {
+        let jql = build_label_jql(
+            &params.labels,
+            params.match_all,
+            params.project_key.as_deref(),
+        )?;
+
+        let search_result = self
+            .jira_client
+            .search_issues_jql(
+                &jql,
+                params.start_at.map(|s| s as usize),
+                Some(params.limit.unwrap_or(50) as usize),
+                None,
+            )
+            .await?;
+
+        info!(
+            "Found {} issues for labels {:?}",
+            search_result.issues.len(),
+            params.labels
+        );
+
+        Ok(GetIssuesByLabelResult {
+            search_result,
+            jql_query: jql,
+        })
+    }
+
+    /// All labels in the instance, from the cache when available
+    ///
+    /// Walks the Cloud-only /label endpoint page by page.
+    pub async fn known_labels(&self, refresh: bool) -> JiraMcpResult<Vec<String>> {
+        if !refresh {
+            if let Some(labels) = self.cache.get_labels() {
+                return Ok(labels);
+            }
+        }
+
+        let mut labels = Vec::new();
+        let mut start_at = 0;
+        for _ in 0..MAX_LABEL_PAGES {
+            let endpoint = format!("/label?startAt={}&maxResults={}", start_at, LABEL_PAGE_SIZE);
+            let response: Value = self
+                .jira_client
+                .get_json(
+                    OperationClass::Read,
+                    "listing labels",
+                    "api",
+                    &endpoint,
+                    |e| {
+                        if e.to_string().contains("404") {
+                            JiraMcpError::internal(
+                                "Listing labels requires JIRA Cloud; use get_available_labels \
+                                 with a project_key instead",
+                            )
+                        } else {
+                            JiraMcpError::internal(format!("Failed to list labels: {}", e))
+                        }
+                    },
+                )
+                .await?;
+
+            let page: Vec<String> = response["values"]
+                .as_array()
+                .map(|arr| {
+                    arr.iter()
+                        .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                        .collect()
+                })
+                .unwrap_or_default();
+            let page_len = page.len() as u32;
+            labels.extend(page);
+
+            if response["isLast"].as_bool().unwrap_or(true) || page_len == 0 {
+                break;
+            }
+            start_at += page_len;
+        }
+
+        labels.sort();
+        labels.dedup();
+        debug!("Loaded {} labels from JIRA", labels.len());
+
+        self.cache.set_labels(labels.clone())?;
+        Ok(labels)
+    }
+}
+
+/// Normalized form used to spot near-duplicate labels
+///
+/// Ignores case, treats `_` and spaces like `-`, and drops a plural trailing "s".
+fn normalize_label(label: &str) -> String {
+    let normalized: String = label
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c == '_' || c == ' ' { '-' } else { c })
+        .collect();
+
+    match normalized.strip_suffix('s') {
+        Some(stem) if stem.len() >= 3 && !stem.ends_with('s') => stem.to_string(),
+        _ => normalized,
+    }
+}
+
+/// Warnings for labels that would be new but closely match an existing label
+pub fn find_near_duplicate_labels(new_labels: &[String], existing: &[String]) -> Vec<String> {
+    new_labels
+        .iter()
+        .filter(|label| !existing.contains(label))
+        .filter_map(|label| {
+            let normalized = normalize_label(label);
+            let similar: Vec<&str> = existing
+                .iter()
+                .filter(|existing| normalize_label(existing) == normalized)
+                .map(|existing| existing.as_str())
+                .collect();
+            (!similar.is_empty()).then(|| {
+                format!(
+                    "'{}' would be a new label but looks like existing '{}'",
+                    label,
+                    similar.join("', '")
+                )
+            })
+        })
+        .collect()
+}
+
+fn build_label_jql(
+    labels: &[String],
+    match_all: bool,
+    project_key: Option<&str>,
+) -> JiraMcpResult<String> {
+    if labels.is_empty() || labels.iter().any(|l| l.trim().is_empty()) {
+        return Err(JiraMcpError::invalid_param(
+            "labels",
+            "Provide at least one non-empty label",
+        ));
+    }
+
+    let mut jql = if match_all {
+        labels
+            .iter()
+            .map(|l| format!("{} = {}", jql::LABELS, jql::quote(l)))
+            .collect::<Vec<_>>()
+            .join(" AND ")
+    } else {
+        format!("{} in ({})", jql::LABELS, jql::quote_list(labels))
+    };
+
+    if let Some(project_key) = project_key {
+        jql = format!("{} = {} AND {}", jql::PROJECT, jql::quote(project_key), jql);
+    }
+
+    Ok(format!("{} ORDER BY updated DESC", jql))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_near_duplicate_labels() {
+        let existing = vec![
+            "backend".to_string(),
+            "tech-debt".to_string(),
+            "status".to_string(),
+        ];
+        let new_labels = vec![
+            "Backends".to_string(),
+            "tech_debt".to_string(),
+            "backend".to_string(),
+            "frontend".to_string(),
+        ];
+
+        let warnings = find_near_duplicate_labels(&new_labels, &existing);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("'Backends'") && warnings[0].contains("'backend'"));
+        assert!(warnings[1].contains("'tech-debt'"));
+
+        assert_eq!(normalize_label("Tech_Debts"), "tech-debt");
+        assert_eq!(normalize_label("class"), "class");
+    }
+
+    #[test]
+    fn test_build_label_jql() {
+        let labels = vec!["backend".to_string(), "urgent".to_string()];
+        assert_eq!(
+            build_label_jql(&labels, false, Some("PROJ")).unwrap(),
+            r#"project = "PROJ" AND labels in ("backend", "urgent") ORDER BY updated DESC"#
+        );
+        assert_eq!(
+            build_label_jql(&labels, true, None).unwrap(),
+            r#"labels = "backend" AND labels = "urgent" ORDER BY updated DESC"#
+        );
+        assert!(build_label_jql(&[], false, None).is_err());
+    }
 }
@@ -5,8 +5,9 @@
 
 use crate::cache::MetadataCache;
 use crate::config::JiraConfig;
-use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::error::JiraMcpResult;
 use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_id_or_key;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -39,10 +40,13 @@ pub struct AttachmentInfo {
     /// Author who uploaded the attachment
     pub author: String,
 
+    /// Account ID of the author (username on Server/Data Center)
+    pub author_account_id: Option<String>,
+
     /// When the attachment was created
     pub created: String,
 
-    /// Content URL for downloading
+    /// Content URL for downloading; download_attachment accepts it as content_url
     pub content_url: String,
 
     /// Thumbnail URL (if available)
@@ -116,11 +120,8 @@ impl ListAttachmentsTool {
             params.issue_key
         );
 
-        // Validate parameters
-        self.validate_params(&params)?;
-
-        // Normalize issue key
-        let normalized_key = params.issue_key.trim().to_uppercase();
+        let normalized_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
 
         // For now, we'll use the issue details to get attachments
         // In the future, we could add a direct attachment listing method to the client
@@ -141,9 +142,10 @@ impl ListAttachmentsTool {
                     size: att.size,
                     mime_type: att.mime_type.clone(),
                     author: att.author.clone(),
+                    author_account_id: att.author_account_id.clone(),
                     created: att.created.clone(),
-                    content_url: format!("jira://attachment/{}", att.id),
-                    thumbnail_url: None, // Would need to be implemented based on JIRA API
+                    content_url: att.content_url.clone(),
+                    thumbnail_url: att.thumbnail_url.clone(),
                 })
                 .collect()
         } else {
@@ -179,36 +181,6 @@ impl ListAttachmentsTool {
             },
         })
     }
-
-    /// Validate list attachments parameters
-    fn validate_params(&self, params: &ListAttachmentsParams) -> JiraMcpResult<()> {
-        // Validate issue key format
-        if params.issue_key.trim().is_empty() {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key is required. Please provide a JIRA issue key (e.g., 'PROJ-123')",
-            ));
-        }
-
-        // Basic format validation (PROJECT-NUMBER pattern)
-        let key = params.issue_key.trim();
-        if !key.contains('-') {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key must follow PROJECT-NUMBER format (e.g., 'PROJ-123')",
-            ));
-        }
-
-        let parts: Vec<&str> = key.split('-').collect();
-        if parts.len() < 2 {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key must contain at least one hyphen separating project and number",
-            ));
-        }
-
-        Ok(())
-    }
 }
 
 #[cfg(test)]
@@ -3,46 +3,121 @@
 //! Contains all the MCP tools that provide AI-friendly interfaces to JIRA operations.
 
 pub mod add_comment;
+pub mod archive_issue;
 pub mod assign_issue;
+pub mod audit_log;
+pub mod board_configuration;
 pub mod bulk_operations;
+pub mod check_issue_quality;
+pub mod clear_cache_scope;
 pub mod components;
+pub mod context_bundle;
+pub mod continuation;
+pub mod count_issues;
 pub mod create_issue;
+pub mod description_metadata;
 pub mod download_attachment;
+pub mod estimate_rollup;
+pub mod find_similar_issues;
 pub mod get_create_metadata;
 pub mod get_custom_fields;
+pub mod get_issues;
+pub mod grouping;
+pub mod issue_activity;
 pub mod issue_details;
+pub mod issue_diff;
+pub mod issue_key;
 pub mod issue_links;
 pub mod issue_relationships;
 pub mod labels;
 pub mod list_attachments;
+pub mod my_mentions;
+pub mod my_permissions;
+pub mod notify_issue;
+pub mod param_validation;
+pub mod priorities;
+pub mod project_stats;
 pub mod rate_limiter;
+pub mod render;
+pub mod request_log;
+pub mod response_budget;
 pub mod search_issues;
+pub mod security_levels;
+pub mod session_history;
+pub mod set_reporter;
+pub mod signature;
+pub mod sprint_planning;
 pub mod sprints;
+pub mod standup_summary;
+pub mod text_diff;
+pub mod text_limit;
+pub mod timesheet;
+pub mod todo_backend;
 pub mod todo_tracker;
+pub mod todos_across_issues;
+pub mod tool_response;
+pub mod toolset;
 pub mod transitions;
+pub mod undo_last_change;
 pub mod update_custom_fields;
 pub mod update_description;
+pub mod update_description_section;
+pub mod update_summary;
 pub mod upload_attachment;
 pub mod user_issues;
+pub mod votes;
+pub mod watch_queries;
 
 pub use add_comment::*;
+pub use archive_issue::*;
 pub use assign_issue::*;
+pub use audit_log::*;
+pub use board_configuration::*;
 pub use bulk_operations::*;
+pub use check_issue_quality::*;
+pub use clear_cache_scope::*;
 pub use components::*;
+pub use context_bundle::*;
+pub use count_issues::*;
 pub use create_issue::*;
+pub use description_metadata::*;
 pub use download_attachment::*;
+pub use estimate_rollup::*;
+pub use find_similar_issues::*;
 pub use get_create_metadata::*;
 pub use get_custom_fields::*;
+pub use get_issues::*;
+pub use issue_activity::*;
 pub use issue_details::*;
+pub use issue_diff::*;
 pub use issue_links::*;
 pub use issue_relationships::*;
 pub use labels::*;
 pub use list_attachments::*;
+pub use my_mentions::*;
+pub use my_permissions::*;
+pub use notify_issue::*;
+pub use priorities::*;
+pub use project_stats::*;
+pub use request_log::*;
 pub use search_issues::*;
+pub use security_levels::*;
+pub use session_history::*;
+pub use set_reporter::*;
+pub use sprint_planning::*;
 pub use sprints::*;
+pub use standup_summary::*;
+pub use timesheet::*;
 pub use todo_tracker::*;
+pub use todos_across_issues::*;
+pub use toolset::*;
 pub use transitions::*;
+pub use undo_last_change::*;
 pub use update_custom_fields::*;
 pub use update_description::*;
+pub use update_description_section::*;
+pub use update_summary::*;
 pub use upload_attachment::*;
 pub use user_issues::*;
+pub use votes::*;
+pub use watch_queries::*;
@@ -0,0 +1,426 @@
+//! Mentions of the current user in comments
+//!
+//! JQL can't reliably find comments that mention someone (`comment ~
+//! currentUser()` matches on text, not on mentions), so get_my_mentions
+//! searches the issues updated since a point in time, reads their comments and
+//! scans the bodies for the current user's mention markup or `@display name`.
+//! The comments already reported by this server are remembered, so polling
+//! with the returned cursor doesn't report a mention twice.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::{CommentInfo, JiraClient, UserInfo};
+use crate::jql;
+use crate::semantic_mapping::resolve_instant;
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinSet;
+use tracing::{info, instrument, warn};
+
+const DEFAULT_MENTION_ISSUES: u32 = 50;
+const MAX_MENTION_ISSUES: u32 = 200;
+
+/// Comment fetches in flight at once
+const MAX_CONCURRENT_FETCHES: usize = 5;
+
+/// Characters of the comment shown before and after the mention
+const SNIPPET_BEFORE: usize = 60;
+const SNIPPET_AFTER: usize = 140;
+
+/// Parameters for the get_my_mentions tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetMyMentionsParams {
+    /// Only comments written after this (optional, default: "7 days ago")
+    /// Pass the `cursor` of the previous call to get only new mentions
+    /// Examples: "yesterday", "3 days ago", "2024-01-15T10:30:00Z"
+    pub since: Option<String>,
+
+    /// Project keys to look in (optional, default: all projects)
+    /// Examples: ["PROJ"], ["PROJ", "OPS"]
+    pub project_keys: Option<Vec<String>>,
+
+    /// Most recently updated issues whose comments are read (optional,
+    /// default: 50, max: 200)
+    pub max_issues: Option<u32>,
+
+    /// Also return mentions this server has reported before (default: false)
+    #[serde(default)]
+    pub include_seen: bool,
+}
+
+/// A comment that mentions the current user
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Mention {
+    pub issue_key: String,
+    pub issue_summary: String,
+    pub comment_id: String,
+
+    /// Display name of the comment's author
+    pub author: String,
+
+    /// When the comment was written
+    pub created: String,
+
+    /// The comment around the mention
+    pub snippet: String,
+
+    /// Permalink to the comment
+    pub url: String,
+}
+
+/// Result from the get_my_mentions tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetMyMentionsResult {
+    /// Display name of the current user
+    pub user: String,
+
+    /// Start of the window (RFC 3339)
+    pub since: String,
+
+    /// Pass as `since` next time to get only newer mentions (RFC 3339)
+    pub cursor: String,
+
+    /// Mentions, newest first
+    pub mentions: Vec<Mention>,
+
+    /// Issues whose comments were read
+    pub issues_scanned: usize,
+
+    /// Mentions left out because they were reported before
+    pub already_reported: usize,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Implementation of the get_my_mentions tool
+pub struct GetMyMentionsTool {
+    jira_client: Arc<JiraClient>,
+    /// IDs of the comments already reported
+    reported: Mutex<HashSet<String>>,
+}
+
+impl GetMyMentionsTool {
+    pub fn new(jira_client: Arc<JiraClient>) -> Self {
+        Self {
+            jira_client,
+            reported: Mutex::new(HashSet::new()),
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, params: GetMyMentionsParams) -> JiraMcpResult<GetMyMentionsResult> {
+        let since = resolve_instant(
+            params.since.as_deref().unwrap_or("7 days ago"),
+            Utc::now(),
+            "since",
+        )?;
+        let max_issues = params.max_issues.unwrap_or(DEFAULT_MENTION_ISSUES);
+        if max_issues == 0 || max_issues > MAX_MENTION_ISSUES {
+            return Err(JiraMcpError::invalid_param(
+                "max_issues",
+                format!("max_issues must be between 1 and {}", MAX_MENTION_ISSUES),
+            ));
+        }
+        let project_keys: Vec<String> = params
+            .project_keys
+            .unwrap_or_default()
+            .iter()
+            .map(|key| key.trim().to_uppercase())
+            .filter(|key| !key.is_empty())
+            .collect();
+
+        let jql = mentions_jql(since, &project_keys);
+        let (user, search) = tokio::join!(
+            self.jira_client.get_current_user(),
+            self.jira_client
+                .search_all_issues_jql(&jql, max_issues as usize),
+        );
+        let (user, search) = (user?, search?);
+
+        let mut warnings = Vec::new();
+        if !search.complete {
+            warnings.push(format!(
+                "Only the comments of the {} most recently updated issues were read; \
+                 older mentions may be missing",
+                max_issues
+            ));
+        }
+
+        let summaries: HashMap<String, String> = search
+            .issues
+            .iter()
+            .map(|issue| (issue.key.clone(), issue.summary.clone()))
+            .collect();
+        let issues_scanned = search.issues.len();
+        let mut cursor = since;
+        let mut found = Vec::new();
+        let mut pending = search.issues.into_iter().map(|issue| issue.key);
+        let mut join_set = JoinSet::new();
+        loop {
+            while join_set.len() < MAX_CONCURRENT_FETCHES {
+                let Some(key) = pending.next() else {
+                    break;
+                };
+                let jira_client = Arc::clone(&self.jira_client);
+                join_set.spawn(async move {
+                    let details = jira_client
+                        .get_issue_details(&key, true, false, false)
+                        .await;
+                    (key, details)
+                });
+            }
+
+            let Some(joined) = join_set.join_next().await else {
+                break;
+            };
+            let (key, details) = joined
+                .map_err(|e| JiraMcpError::internal(format!("Comment task failed: {}", e)))?;
+            let comments = match details {
+                Ok(details) => details.comments.unwrap_or_default(),
+                Err(e) => {
+                    warn!("Could not read comments of {}: {}", key, e);
+                    warnings.push(format!("{}: {}", key, e));
+                    continue;
+                }
+            };
+            for comment in &comments {
+                let Some(created) = comment.created_at().map(|at| at.with_timezone(&Utc)) else {
+                    continue;
+                };
+                if created <= since {
+                    continue;
+                }
+                cursor = cursor.max(created);
+                if let Some(mention) = mention(&key, comment, &user) {
+                    let summary = summaries.get(&key).cloned().unwrap_or_default();
+                    found.push((
+                        created,
+                        Mention {
+                            issue_summary: summary,
+                            ..mention
+                        },
+                    ));
+                }
+            }
+        }
+
+        found.sort_by_key(|(created, _)| Reverse(*created));
+        let mut mentions = Vec::new();
+        let mut already_reported = 0;
+        {
+            let mut reported = self
+                .reported
+                .lock()
+                .map_err(|_| JiraMcpError::internal("Reported mentions lock poisoned"))?;
+            for (_, mention) in found {
+                if reported.insert(mention.comment_id.clone()) || params.include_seen {
+                    mentions.push(mention);
+                } else {
+                    already_reported += 1;
+                }
+            }
+        }
+
+        info!(
+            "{} mentions of {} in {} issues since {}",
+            mentions.len(),
+            user.display_name,
+            issues_scanned,
+            since
+        );
+
+        Ok(GetMyMentionsResult {
+            user: user.display_name,
+            since: since.to_rfc3339_opts(SecondsFormat::Millis, true),
+            cursor: cursor.to_rfc3339_opts(SecondsFormat::Millis, true),
+            mentions,
+            issues_scanned,
+            already_reported,
+            warnings,
+        })
+    }
+}
+
+/// Issues updated since `since`, most recent first
+///
+/// JQL dates follow the JIRA profile's time zone and have minute precision, so
+/// the window is padded by a day; comments are cut exactly by their timestamp.
+fn mentions_jql(since: DateTime<Utc>, project_keys: &[String]) -> String {
+    let padded = since - Duration::days(1);
+    let mut conditions = vec![format!(
+        "{} >= {}",
+        jql::UPDATED,
+        jql::quote(&padded.format("%Y/%m/%d %H:%M").to_string())
+    )];
+    conditions.extend(jql::eq_or_in(&jql::PROJECT, project_keys));
+    format!(
+        "{} ORDER BY {} DESC",
+        conditions.join(" AND "),
+        jql::UPDATED
+    )
+}
+
+/// The comment as a mention of `user`, when it mentions them and they didn't
+/// write it
+///
+/// Wiki markup mentions read `[~accountid:ID]` (Cloud) or `[~username]`
+/// (Server/Data Center); rendered ADF mentions read `@Display Name`.
+fn mention(issue_key: &str, comment: &CommentInfo, user: &UserInfo) -> Option<Mention> {
+    if comment.author == user.display_name {
+        return None;
+    }
+    let at = find_mention(&comment.body, &user.account_id, &user.display_name)?;
+    Some(Mention {
+        issue_key: issue_key.to_string(),
+        issue_summary: String::new(),
+        comment_id: comment.id.clone(),
+        author: comment.author.clone(),
+        created: comment.created.clone(),
+        snippet: snippet(&comment.body, at),
+        url: comment.url.clone(),
+    })
+}
+
+/// Byte offset of the first mention of the user in `body`
+fn find_mention(body: &str, account_id: &str, display_name: &str) -> Option<usize> {
+    let markup = [
+        format!("[~accountid:{}]", account_id),
+        format!("[~{}]", account_id),
+    ];
+    let by_markup = markup
+        .iter()
+        .filter_map(|marker| body.find(marker.as_str()));
+
+    // Names are matched ignoring ASCII case, which keeps byte offsets intact,
+    // and must not run on into a longer name ("@Ann" in "@Anna")
+    let lower_body = body.to_ascii_lowercase();
+    let name = format!("@{}", display_name.trim()).to_ascii_lowercase();
+    let by_name = (name.len() > 1)
+        .then(|| {
+            lower_body
+                .match_indices(&name)
+                .map(|(at, _)| at)
+                .find(|at| {
+                    !lower_body[at + name.len()..]
+                        .chars()
+                        .next()
+                        .is_some_and(char::is_alphanumeric)
+                })
+        })
+        .flatten();
+
+    by_markup.chain(by_name).min()
+}
+
+/// The text around byte offset `at`, on one line
+fn snippet(body: &str, at: usize) -> String {
+    let start = body[..at]
+        .char_indices()
+        .rev()
+        .nth(SNIPPET_BEFORE - 1)
+        .map_or(0, |(i, _)| i);
+    let end = body[at..]
+        .char_indices()
+        .nth(SNIPPET_AFTER)
+        .map_or(body.len(), |(i, _)| at + i);
+    let text = body[start..end]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    format!(
+        "{}{}{}",
+        if start > 0 { "…" } else { "" },
+        text,
+        if end < body.len() { "…" } else { "" }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user() -> UserInfo {
+        UserInfo {
+            account_id: "5b10ac8d82e05b22cc7d4ef5".to_string(),
+            display_name: "Ann Smith".to_string(),
+            email_address: None,
+            active: true,
+        }
+    }
+
+    fn comment(author: &str, body: &str) -> CommentInfo {
+        CommentInfo {
+            id: "10001".to_string(),
+            author: author.to_string(),
+            body: body.to_string(),
+            created: "2024-01-15T10:30:00.000+0000".to_string(),
+            updated: "2024-01-15T10:30:00.000+0000".to_string(),
+            url: "https://example.atlassian.net/browse/PROJ-1?focusedCommentId=10001".to_string(),
+            visibility: None,
+            jsm_internal: None,
+        }
+    }
+
+    #[test]
+    fn test_find_mention() {
+        let user = user();
+        let find = |body: &str| find_mention(body, &user.account_id, &user.display_name);
+
+        assert_eq!(
+            find("[~accountid:5b10ac8d82e05b22cc7d4ef5] please review"),
+            Some(0)
+        );
+        assert_eq!(find("cc [~5b10ac8d82e05b22cc7d4ef5]"), Some(3));
+        assert_eq!(find("Thanks @ann smith!"), Some(7));
+        assert_eq!(find("@Ann Smithers, see above"), None);
+        assert_eq!(find("@Ann Smithers and @Ann Smith"), Some(18));
+        assert_eq!(find("Ann Smith said so"), None);
+    }
+
+    #[test]
+    fn test_mention() {
+        let user = user();
+        assert!(mention("PROJ-1", &comment("Bob", "no mention here"), &user).is_none());
+        // Mentioning yourself is not a mention to report
+        assert!(mention("PROJ-1", &comment("Ann Smith", "@Ann Smith note"), &user).is_none());
+
+        let found = mention("PROJ-1", &comment("Bob", "@Ann Smith can you look?"), &user).unwrap();
+        assert_eq!(found.issue_key, "PROJ-1");
+        assert_eq!(found.author, "Bob");
+        assert_eq!(found.snippet, "@Ann Smith can you look?");
+    }
+
+    #[test]
+    fn test_snippet() {
+        let body = format!("{}\n\n@Ann Smith {}", "é".repeat(100), "x".repeat(200));
+        let at = body.find('@').unwrap();
+        let snippet = snippet(&body, at);
+        assert!(snippet.starts_with('…'));
+        assert!(snippet.ends_with('…'));
+        assert!(snippet.contains("é @Ann Smith x"));
+        assert_eq!(
+            snippet.chars().count(),
+            2 + SNIPPET_BEFORE + SNIPPET_AFTER - 1
+        );
+    }
+
+    #[test]
+    fn test_mentions_jql() {
+        let since = DateTime::parse_from_rfc3339("2024-01-15T10:30:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            mentions_jql(since, &[]),
+            "updated >= \"2024/01/14 10:30\" ORDER BY updated DESC"
+        );
+        assert_eq!(
+            mentions_jql(since, &["PROJ".to_string(), "OPS".to_string()]),
+            "updated >= \"2024/01/14 10:30\" AND project IN (\"PROJ\", \"OPS\") ORDER BY updated DESC"
+        );
+    }
+}
@@ -0,0 +1,374 @@
+//! Permissions of the current user
+//!
+//! get_my_permissions asks JIRA (/mypermissions) which of the permissions the
+//! tools depend on the configured user holds, in a project, on an issue or in
+//! any project. Answers are cached per project or issue for a few minutes (the
+//! "permissions" cache category). With `permission_precheck` enabled, writes
+//! are checked against the cached answer first, so a missing permission fails
+//! fast as "You lack EDIT_ISSUES in PROJ" rather than as a 403 halfway through.
+
+use crate::audit::collect_issue_keys;
+use crate::cache::MetadataCache;
+use crate::config::JiraConfig;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_key;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::{debug, info, instrument, warn};
+
+/// Permissions reported, by their JIRA keys
+pub const PERMISSIONS: &[&str] = &[
+    "BROWSE_PROJECTS",
+    "CREATE_ISSUES",
+    "EDIT_ISSUES",
+    "TRANSITION_ISSUES",
+    "ADD_COMMENTS",
+    "DELETE_ISSUES",
+    "WORK_ON_ISSUES",
+    "MANAGE_SPRINTS_PERMISSION",
+    "ASSIGN_ISSUES",
+    "LINK_ISSUES",
+    "CREATE_ATTACHMENTS",
+];
+
+/// Parameters for the get_my_permissions tool
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetMyPermissionsParams {
+    /// Project to check in (optional, default: whether each is held in any project)
+    /// Examples: "PROJ"
+    pub project_key: Option<String>,
+
+    /// Issue to check on, taking issue security into account (optional, wins
+    /// over project_key)
+    /// Examples: "PROJ-123", "https://company.atlassian.net/browse/PROJ-123"
+    pub issue_key: Option<String>,
+
+    /// Ask JIRA again instead of using the cached answer (default: false)
+    #[serde(default)]
+    pub refresh: bool,
+}
+
+/// Result from the get_my_permissions tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct GetMyPermissionsResult {
+    /// What the permissions apply to: "any project", "project PROJ" or "issue PROJ-123"
+    pub context: String,
+
+    /// Whether the current user holds each permission, by JIRA key
+    pub permissions: BTreeMap<String, bool>,
+
+    /// Permissions the current user lacks here
+    pub missing: Vec<String>,
+
+    /// Whether the answer came from the cache
+    pub cached: bool,
+
+    /// Whether writes are checked against these permissions before they are sent
+    pub precheck_enabled: bool,
+}
+
+/// Implementation of the get_my_permissions tool
+pub struct GetMyPermissionsTool {
+    jira_client: Arc<JiraClient>,
+    config: Arc<JiraConfig>,
+    cache: Arc<MetadataCache>,
+}
+
+impl GetMyPermissionsTool {
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        config: Arc<JiraConfig>,
+        cache: Arc<MetadataCache>,
+    ) -> Self {
+        Self {
+            jira_client,
+            config,
+            cache,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: GetMyPermissionsParams,
+    ) -> JiraMcpResult<GetMyPermissionsResult> {
+        let issue_key = params
+            .issue_key
+            .as_deref()
+            .filter(|key| !key.trim().is_empty())
+            .map(|key| normalize_issue_key(&self.config.jira_url, key, "issue_key"))
+            .transpose()?;
+        let project_key = params
+            .project_key
+            .map(|key| key.trim().to_uppercase())
+            .filter(|key| !key.is_empty());
+
+        let (permissions, cached) = self
+            .permissions(project_key.as_deref(), issue_key.as_deref(), params.refresh)
+            .await?;
+        let context = match (&issue_key, &project_key) {
+            (Some(issue_key), _) => format!("issue {}", issue_key),
+            (None, Some(project_key)) => format!("project {}", project_key),
+            (None, None) => "any project".to_string(),
+        };
+        let missing: Vec<String> = permissions
+            .iter()
+            .filter(|(_, held)| !**held)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        info!(
+            "Permissions in {}: {} missing{}",
+            context,
+            missing.len(),
+            if cached { " (cached)" } else { "" }
+        );
+
+        Ok(GetMyPermissionsResult {
+            context,
+            permissions,
+            missing,
+            cached,
+            precheck_enabled: self.config.permission_precheck,
+        })
+    }
+
+    /// Permissions on an issue, in a project or in any project, and whether
+    /// they came from the cache
+    pub async fn permissions(
+        &self,
+        project_key: Option<&str>,
+        issue_key: Option<&str>,
+        refresh: bool,
+    ) -> JiraMcpResult<(BTreeMap<String, bool>, bool)> {
+        let cache_key = issue_key.or(project_key);
+        if !refresh {
+            if let Some(permissions) = self.cache.get_permissions(cache_key) {
+                return Ok((permissions, true));
+            }
+        }
+
+        let permissions = self
+            .jira_client
+            .get_my_permissions(project_key, issue_key, PERMISSIONS)
+            .await
+            .map_err(|e| match (e, issue_key, project_key) {
+                (JiraMcpError::NotFound { .. }, Some(issue_key), _) => {
+                    JiraMcpError::not_found("issue", issue_key)
+                }
+                (JiraMcpError::NotFound { .. }, None, Some(project_key)) => {
+                    JiraMcpError::not_found("project", project_key)
+                }
+                (e, _, _) => e,
+            })?;
+        if let Err(e) = self.cache.set_permissions(cache_key, permissions.clone()) {
+            warn!("Failed to cache permissions: {}", e);
+        }
+        Ok((permissions, false))
+    }
+
+    /// Fail fast when the current user lacks the permission a write needs
+    ///
+    /// Does nothing unless `permission_precheck` is enabled or the tool needs
+    /// no known permission. Writes that name no project (sprints, work sessions
+    /// picked by todo) are checked against any project: lacking the permission
+    /// everywhere means lacking it there too. Projects whose permissions cannot
+    /// be read are let through; JIRA still has the final word.
+    pub async fn precheck(&self, tool: &str, params: &Value) -> JiraMcpResult<()> {
+        if !self.config.permission_precheck {
+            return Ok(());
+        }
+        let Some(permission) = required_permission(tool) else {
+            return Ok(());
+        };
+
+        let projects = precheck_projects(&self.config.jira_url, params);
+        let contexts: Vec<Option<&str>> = if projects.is_empty() {
+            vec![None]
+        } else {
+            projects
+                .iter()
+                .map(|project| Some(project.as_str()))
+                .collect()
+        };
+        for project_key in contexts {
+            let context = project_key.unwrap_or("any project");
+            match self.permissions(project_key, None, false).await {
+                Ok((permissions, _)) => {
+                    if permissions.get(permission) == Some(&false) {
+                        return Err(JiraMcpError::permission(format!(
+                            "You lack {} in {}",
+                            permission, context
+                        )));
+                    }
+                }
+                Err(e) => debug!(
+                    "Skipping the {} pre-check in {}: {}",
+                    permission, context, e
+                ),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Permission a write tool needs, for the tools that need one of [`PERMISSIONS`]
+///
+/// Every tool that goes through `audited()` is listed here except
+/// cancel_todo_work, which only drops the local work session.
+fn required_permission(tool: &str) -> Option<&'static str> {
+    match tool {
+        "create_issue" | "bulk_create_issues" => Some("CREATE_ISSUES"),
+        "update_issue_description"
+        | "update_description_section"
+        | "add_todo"
+        | "update_todo"
+        | "update_description_metadata"
+        | "update_issue_summary"
+        | "update_custom_fields"
+        | "manage_labels"
+        | "update_components"
+        | "bulk_update_fields"
+        | "bulk_add_labels"
+        | "set_reporter"
+        | "update_security_level"
+        | "archive_issue"
+        | "undo_last_change" => Some("EDIT_ISSUES"),
+        "transition_issue" | "bulk_transition_issues" => Some("TRANSITION_ISSUES"),
+        "add_comment" => Some("ADD_COMMENTS"),
+        "assign_issue" | "bulk_assign_issues" => Some("ASSIGN_ISSUES"),
+        "link_issues" | "delete_issue_link" => Some("LINK_ISSUES"),
+        "upload_attachment" => Some("CREATE_ATTACHMENTS"),
+        "start_todo_work" | "checkpoint_todo_work" | "pause_todo_work" | "complete_todo_work" => {
+            Some("WORK_ON_ISSUES")
+        }
+        "move_to_sprint" | "create_sprint" | "start_sprint" | "close_sprint" | "update_sprint" => {
+            Some("MANAGE_SPRINTS_PERMISSION")
+        }
+        "add_vote" | "remove_vote" | "notify_issue" => Some("BROWSE_PROJECTS"),
+        _ => None,
+    }
+}
+
+/// Projects a write touches: those of the issue keys in its parameters and any
+/// `project_key` values
+///
+/// Issue keys are normalized first, so browse URLs and markdown links count;
+/// IDs and other input the tool itself will reject are left out.
+fn precheck_projects(jira_url: &str, params: &Value) -> Vec<String> {
+    let mut issue_keys = Vec::new();
+    collect_issue_keys(params, &mut issue_keys);
+
+    let mut projects: Vec<String> = issue_keys
+        .iter()
+        .filter_map(|key| normalize_issue_key(jira_url, key, "issue_key").ok())
+        .filter_map(|key| key.split_once('-').map(|(project, _)| project.to_string()))
+        .collect();
+    collect_project_keys(params, &mut projects);
+    projects.sort();
+    projects.dedup();
+    projects
+}
+
+fn collect_project_keys(value: &Value, projects: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (name, v) in map {
+                match (name.as_str(), v) {
+                    ("project_key", Value::String(key)) if !key.trim().is_empty() => {
+                        projects.push(key.trim().to_uppercase())
+                    }
+                    _ => collect_project_keys(v, projects),
+                }
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_project_keys(item, projects);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const JIRA_URL: &str = "https://example.atlassian.net";
+
+    #[test]
+    fn test_precheck_projects() {
+        assert_eq!(
+            precheck_projects(
+                JIRA_URL,
+                &json!({"issue_key": "proj-12", "comment": "PROJ-3 is related"})
+            ),
+            ["PROJ"]
+        );
+        assert_eq!(
+            precheck_projects(
+                JIRA_URL,
+                &json!({
+                    "issues": [{"project_key": "ops", "summary": "a"}, {"project_key": "PROJ"}],
+                    "issue_keys": ["PROJ-1", "OPS-2", "https://example.atlassian.net/browse/web-3"]
+                })
+            ),
+            ["OPS", "PROJ", "WEB"]
+        );
+        assert_eq!(
+            precheck_projects(
+                JIRA_URL,
+                &json!({"issue_key": "[DOCS-4](https://example.atlassian.net/browse/DOCS-4)"})
+            ),
+            ["DOCS"]
+        );
+        assert!(precheck_projects(JIRA_URL, &json!({"issue_key": "10001"})).is_empty());
+        assert!(precheck_projects(
+            JIRA_URL,
+            &json!({"issue_key": "https://other.atlassian.net/browse/WEB-3"})
+        )
+        .is_empty());
+    }
+
+    #[test]
+    fn test_required_permission() {
+        assert_eq!(required_permission("add_comment"), Some("ADD_COMMENTS"));
+        assert_eq!(
+            required_permission("bulk_update_fields"),
+            Some("EDIT_ISSUES")
+        );
+        assert_eq!(
+            required_permission("close_sprint"),
+            Some("MANAGE_SPRINTS_PERMISSION")
+        );
+        assert_eq!(required_permission("get_issue_details"), None);
+    }
+
+    #[test]
+    fn test_every_audited_write_is_prechecked() {
+        let writes: Vec<&str> = include_str!("../lib.rs")
+            .split("audited(")
+            .skip(1)
+            .filter_map(|call| call.trim_start().strip_prefix('"')?.split('"').next())
+            .collect();
+        assert!(writes.len() > 30, "{:?}", writes);
+
+        for tool in writes {
+            assert!(
+                required_permission(tool).is_some() || tool == "cancel_todo_work",
+                "{} writes without a permission pre-check",
+                tool
+            );
+            if let Some(permission) = required_permission(tool) {
+                assert!(PERMISSIONS.contains(&permission), "{}", permission);
+            }
+        }
+    }
+}
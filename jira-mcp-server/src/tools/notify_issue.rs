@@ -0,0 +1,280 @@
+//! Notify issue tool
+//!
+//! Sends an explicit notification email about an issue to selected participants,
+//! for updates that shouldn't rely on watchers noticing them.
+
+use crate::config::DeploymentType;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_id_or_key;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// Who should receive the notification
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NotifyRecipients {
+    /// Notify the reporter (default: false)
+    #[serde(default)]
+    pub reporter: bool,
+
+    /// Notify the assignee (default: false)
+    #[serde(default)]
+    pub assignee: bool,
+
+    /// Notify all watchers (default: false)
+    #[serde(default)]
+    pub watchers: bool,
+
+    /// Notify all voters (default: false)
+    #[serde(default)]
+    pub voters: bool,
+
+    /// Individual users by account ID (usernames on Server/Data Center), or "me" (optional)
+    #[serde(default)]
+    pub users: Vec<String>,
+
+    /// Group names (optional)
+    /// Examples: ["jira-administrators"]
+    #[serde(default)]
+    pub groups: Vec<String>,
+}
+
+/// Parameters for the notify_issue tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct NotifyIssueParams {
+    /// Issue key to notify about (required)
+    /// Examples: "PROJ-123"
+    pub issue_key: String,
+
+    /// Email subject (optional, default: "Update on <issue_key>")
+    pub subject: Option<String>,
+
+    /// Plain text email body (required)
+    pub body: String,
+
+    /// Recipients; at least one selector must be set (required)
+    /// Examples: {"assignee": true, "watchers": true}, {"users": ["5b10ac8d82e05b22cc7d4ef5"]}
+    pub to: NotifyRecipients,
+}
+
+/// Result from the notify_issue tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct NotifyIssueResult {
+    /// Issue the notification was about
+    pub issue_key: String,
+
+    /// Recipient categories that were notified
+    /// Examples: ["reporter", "watchers", "user:5b10ac8d...", "group:developers"]
+    pub recipients: Vec<String>,
+
+    /// Success message
+    pub message: String,
+}
+
+/// Tool for sending issue notifications
+pub struct NotifyIssueTool {
+    jira_client: Arc<JiraClient>,
+}
+
+impl NotifyIssueTool {
+    pub fn new(jira_client: Arc<JiraClient>) -> Self {
+        Self { jira_client }
+    }
+
+    #[instrument(skip(self, params), fields(issue_key = params.issue_key.as_str()))]
+    pub async fn execute(&self, mut params: NotifyIssueParams) -> JiraMcpResult<NotifyIssueResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        validate_params(&params)?;
+
+        let mut to = params.to.clone();
+        if to.users.iter().any(|u| u == "me") {
+            let current_user = self.jira_client.get_current_user().await?;
+            for user in to.users.iter_mut().filter(|u| *u == "me") {
+                *user = current_user.account_id.clone();
+            }
+        }
+
+        let subject = params
+            .subject
+            .clone()
+            .unwrap_or_else(|| format!("Update on {}", params.issue_key));
+
+        self.jira_client
+            .notify_issue(
+                &params.issue_key,
+                &subject,
+                &params.body,
+                build_recipients_json(&to, self.jira_client.deployment_type()),
+            )
+            .await?;
+
+        let recipients = describe_recipients(&to);
+        info!(
+            "Notified {} recipient categories about {}",
+            recipients.len(),
+            params.issue_key
+        );
+
+        Ok(NotifyIssueResult {
+            issue_key: params.issue_key.clone(),
+            message: format!(
+                "Notification sent for {} to {}",
+                params.issue_key,
+                recipients.join(", ")
+            ),
+            recipients,
+        })
+    }
+}
+
+fn validate_params(params: &NotifyIssueParams) -> JiraMcpResult<()> {
+    if params.body.trim().is_empty() {
+        return Err(JiraMcpError::invalid_param(
+            "body",
+            "Notification body cannot be empty",
+        ));
+    }
+
+    let to = &params.to;
+    if !(to.reporter || to.assignee || to.watchers || to.voters)
+        && to.users.is_empty()
+        && to.groups.is_empty()
+    {
+        return Err(JiraMcpError::invalid_param(
+            "to",
+            "Select at least one recipient: reporter, assignee, watchers, voters, users or groups",
+        ));
+    }
+
+    if to
+        .users
+        .iter()
+        .chain(&to.groups)
+        .any(|r| r.trim().is_empty())
+    {
+        return Err(JiraMcpError::invalid_param(
+            "to",
+            "User and group entries cannot be empty",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Shape the recipients into JIRA's restriction object
+///
+/// Unset flags and empty lists are left out rather than sent as false/[].
+fn build_recipients_json(to: &NotifyRecipients, deployment_type: DeploymentType) -> Value {
+    let mut json = serde_json::Map::new();
+
+    for (flag, name) in [
+        (to.reporter, "reporter"),
+        (to.assignee, "assignee"),
+        (to.watchers, "watchers"),
+        (to.voters, "voters"),
+    ] {
+        if flag {
+            json.insert(name.to_string(), Value::Bool(true));
+        }
+    }
+
+    if !to.users.is_empty() {
+        let users = to
+            .users
+            .iter()
+            .map(|user| deployment_type.user_field(user))
+            .collect();
+        json.insert("users".to_string(), Value::Array(users));
+    }
+
+    if !to.groups.is_empty() {
+        let groups = to
+            .groups
+            .iter()
+            .map(|name| serde_json::json!({ "name": name }))
+            .collect();
+        json.insert("groups".to_string(), Value::Array(groups));
+    }
+
+    Value::Object(json)
+}
+
+fn describe_recipients(to: &NotifyRecipients) -> Vec<String> {
+    let mut recipients: Vec<String> = [
+        (to.reporter, "reporter"),
+        (to.assignee, "assignee"),
+        (to.watchers, "watchers"),
+        (to.voters, "voters"),
+    ]
+    .into_iter()
+    .filter(|(flag, _)| *flag)
+    .map(|(_, name)| name.to_string())
+    .collect();
+
+    recipients.extend(to.users.iter().map(|u| format!("user:{}", u)));
+    recipients.extend(to.groups.iter().map(|g| format!("group:{}", g)));
+    recipients
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(to: NotifyRecipients) -> NotifyIssueParams {
+        NotifyIssueParams {
+            issue_key: "PROJ-1".to_string(),
+            subject: None,
+            body: "Deployment rolled back".to_string(),
+            to,
+        }
+    }
+
+    #[test]
+    fn test_requires_a_recipient() {
+        assert!(validate_params(&params(NotifyRecipients::default())).is_err());
+        assert!(validate_params(&params(NotifyRecipients {
+            watchers: true,
+            ..Default::default()
+        }))
+        .is_ok());
+        assert!(validate_params(&params(NotifyRecipients {
+            groups: vec![" ".to_string()],
+            ..Default::default()
+        }))
+        .is_err());
+    }
+
+    #[test]
+    fn test_build_recipients_json() {
+        let to = NotifyRecipients {
+            assignee: true,
+            users: vec!["abc123".to_string()],
+            groups: vec!["developers".to_string()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            build_recipients_json(&to, DeploymentType::Cloud),
+            serde_json::json!({
+                "assignee": true,
+                "users": [{ "accountId": "abc123" }],
+                "groups": [{ "name": "developers" }]
+            })
+        );
+        assert_eq!(
+            build_recipients_json(&to, DeploymentType::DataCenter)["users"],
+            serde_json::json!([{ "name": "abc123" }])
+        );
+        assert_eq!(
+            describe_recipients(&to),
+            vec!["assignee", "user:abc123", "group:developers"]
+        );
+    }
+}
@@ -0,0 +1,564 @@
+//! Parameter validation against the tool schema
+//!
+//! serde's errors for a malformed payload are terse ("invalid type: string,
+//! expected a sequence") and stop at the first problem. Wrapping a tool's
+//! parameters in [`Validated`] inspects the raw JSON against the parameters'
+//! JSON schema first and reports every problem at once: the offending field, the
+//! expected type, the received value and, for unknown fields, the closest valid
+//! name. Unknown top-level fields are rejected even for parameter types that
+//! don't deny them, since they are almost always a misspelled parameter; serde
+//! aliases, which the schema doesn't list, are only recognized on types that do.
+
+use crate::semantic_mapping::edit_distance;
+use schemars::JsonSchema;
+use serde::de::{DeserializeOwned, Deserializer, Error as _};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::ops::Deref;
+
+/// Longest rendering of a received value in an error
+const MAX_ECHO_CHARS: usize = 80;
+
+/// Tool parameters checked against their schema before they are deserialized
+///
+/// The schema exposed to clients is the one of `P`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[serde(transparent)]
+pub struct Validated<P>(pub P);
+
+impl<P> Validated<P> {
+    pub fn into_inner(self) -> P {
+        self.0
+    }
+}
+
+impl<P> Deref for Validated<P> {
+    type Target = P;
+
+    fn deref(&self) -> &P {
+        &self.0
+    }
+}
+
+impl<'de, P: DeserializeOwned + JsonSchema> Deserialize<'de> for Validated<P> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let mut problems = check_params::<P>(&value);
+
+        let result = serde_json::from_value::<P>(value);
+        if result.is_ok() {
+            // serde knows aliases the schema doesn't list; only fields it would
+            // silently drop are still worth failing the call for
+            problems.retain(|problem| problem.unknown_field);
+        }
+        if !problems.is_empty() {
+            let lines: Vec<&str> = problems.iter().map(|p| p.message.as_str()).collect();
+            return Err(D::Error::custom(format!(
+                "Invalid parameters:\n- {}",
+                lines.join("\n- ")
+            )));
+        }
+
+        result.map(Validated).map_err(D::Error::custom)
+    }
+}
+
+/// One problem with a payload
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamProblem {
+    /// Names the field, e.g. "`limit`: expected integer, received \"ten\" (string)"
+    pub message: String,
+
+    /// The field is not a parameter of the tool
+    pub unknown_field: bool,
+}
+
+impl ParamProblem {
+    fn invalid(message: String) -> Self {
+        Self {
+            message,
+            unknown_field: false,
+        }
+    }
+}
+
+/// Problems with `value` as parameters of type `P`, one message per problem;
+/// empty when the payload matches the schema
+pub fn check_params<P: JsonSchema + DeserializeOwned>(value: &Value) -> Vec<ParamProblem> {
+    let schema = Value::from(schemars::schema_for!(P));
+    let denies_unknown = schema.get("additionalProperties") == Some(&Value::Bool(false));
+    // serde checks fields as it reads them, so a lone field it doesn't know fails
+    // with "unknown field" before any missing required field is noticed
+    let is_alias = |name: &str, value: &Value| {
+        denies_unknown
+            && serde_json::from_value::<P>(Value::Object(Map::from_iter([(
+                name.to_string(),
+                value.clone(),
+            )])))
+            .err()
+            .is_none_or(|e| !e.to_string().starts_with("unknown field"))
+    };
+    let mut problems = Vec::new();
+
+    match value {
+        Value::Object(map) => check_object(&schema, &schema, map, "", &is_alias, &mut problems),
+        // A tool called without arguments
+        Value::Null => check_object(&schema, &schema, &Map::new(), "", &is_alias, &mut problems),
+        other => problems.push(ParamProblem::invalid(format!(
+            "parameters must be an object, received {}",
+            echo(other)
+        ))),
+    }
+
+    problems
+}
+
+fn check_value(
+    schema: &Value,
+    root: &Value,
+    value: &Value,
+    path: &str,
+    problems: &mut Vec<ParamProblem>,
+) {
+    let schema = resolve(schema, root);
+    let Some(object) = schema.as_object() else {
+        // `true` accepts anything
+        return;
+    };
+
+    if let Some(alternatives) = object
+        .get("anyOf")
+        .or_else(|| object.get("oneOf"))
+        .and_then(Value::as_array)
+    {
+        check_alternatives(alternatives, root, value, path, problems);
+        return;
+    }
+
+    if let Some(allowed) = object.get("enum").and_then(Value::as_array) {
+        if !allowed.contains(value) {
+            problems.push(not_allowed(path, allowed, value));
+        }
+        return;
+    }
+
+    let types = schema_types(object);
+    if !types.is_empty() && !types.iter().any(|t| type_matches(t, value)) {
+        let mut message = format!(
+            "`{}`: expected {}, received {}",
+            path,
+            describe(object, root),
+            echo(value)
+        );
+        if let Some(hint) = type_hint(&types, value) {
+            message.push_str(&format!("; {}", hint));
+        }
+        problems.push(ParamProblem::invalid(message));
+        return;
+    }
+
+    match value {
+        Value::Array(items) => {
+            if let Some(item_schema) = object.get("items") {
+                for (index, item) in items.iter().enumerate() {
+                    check_value(
+                        item_schema,
+                        root,
+                        item,
+                        &format!("{}[{}]", path, index),
+                        problems,
+                    );
+                }
+            }
+        }
+        Value::Object(map) => {
+            let open = schema.get("additionalProperties") != Some(&Value::Bool(false));
+            check_object(schema, root, map, path, &|_, _| open, problems)
+        }
+        _ => {}
+    }
+}
+
+/// Check an object's fields; fields the schema doesn't list are reported
+/// unless `allow_unknown` accepts them
+fn check_object(
+    schema: &Value,
+    root: &Value,
+    map: &Map<String, Value>,
+    path: &str,
+    allow_unknown: &dyn Fn(&str, &Value) -> bool,
+    problems: &mut Vec<ParamProblem>,
+) {
+    let Some(properties) = schema.get("properties").and_then(Value::as_object) else {
+        return;
+    };
+    let field_path = |name: &str| {
+        if path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", path, name)
+        }
+    };
+
+    for (name, value) in map {
+        match properties.get(name) {
+            Some(property) => check_value(property, root, value, &field_path(name), problems),
+            None if !allow_unknown(name, value) => {
+                let known: Vec<&str> = properties.keys().map(String::as_str).collect();
+                let mut message = format!("`{}`: unknown parameter.", field_path(name));
+                if let Some(closest) = closest_name(name, &known) {
+                    message.push_str(&format!(" Did you mean `{}`?", field_path(closest)));
+                }
+                message.push_str(&format!(" Valid parameters: {}", known.join(", ")));
+                problems.push(ParamProblem {
+                    message,
+                    unknown_field: true,
+                });
+            }
+            None => {}
+        }
+    }
+
+    let required = schema
+        .get("required")
+        .and_then(Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str);
+    for name in required {
+        if !map.contains_key(name) {
+            let expected = properties
+                .get(name)
+                .and_then(|property| resolve(property, root).as_object())
+                .map(|property| describe(property, root))
+                .unwrap_or_else(|| "a value".to_string());
+            problems.push(ParamProblem::invalid(format!(
+                "`{}`: required parameter is missing (expected {})",
+                field_path(name),
+                expected
+            )));
+        }
+    }
+}
+
+/// `anyOf` / `oneOf`, as generated for optional enums and nested types
+fn check_alternatives(
+    alternatives: &[Value],
+    root: &Value,
+    value: &Value,
+    path: &str,
+    problems: &mut Vec<ParamProblem>,
+) {
+    let non_null: Vec<&Value> = alternatives
+        .iter()
+        .filter(|alternative| resolve(alternative, root).get("type") != Some(&"null".into()))
+        .collect();
+    if value.is_null() && non_null.len() < alternatives.len() {
+        return;
+    }
+    if let [single] = non_null.as_slice() {
+        check_value(single, root, value, path, problems);
+        return;
+    }
+
+    let matches = |alternative: &Value| {
+        let mut found = Vec::new();
+        check_value(alternative, root, value, path, &mut found);
+        found.is_empty()
+    };
+    if non_null.iter().any(|alternative| matches(alternative)) {
+        return;
+    }
+
+    // Unit enum variants with doc comments become one `const` per variant
+    let constants: Option<Vec<Value>> = non_null
+        .iter()
+        .map(|alternative| resolve(alternative, root).get("const").cloned())
+        .collect();
+    match constants {
+        Some(allowed) => problems.push(not_allowed(path, &allowed, value)),
+        None => problems.push(ParamProblem::invalid(format!(
+            "`{}`: expected {}, received {}",
+            path,
+            non_null
+                .iter()
+                .filter_map(|alternative| resolve(alternative, root).as_object())
+                .map(|alternative| describe(alternative, root))
+                .collect::<Vec<_>>()
+                .join(" or "),
+            echo(value)
+        ))),
+    }
+}
+
+/// Follow a local `$ref` ("#/$defs/Name")
+fn resolve<'a>(schema: &'a Value, root: &'a Value) -> &'a Value {
+    match schema.get("$ref").and_then(Value::as_str) {
+        Some(reference) => reference
+            .strip_prefix("#/")
+            .and_then(|pointer| root.pointer(&format!("/{}", pointer)))
+            .unwrap_or(schema),
+        None => schema,
+    }
+}
+
+/// Types a schema allows, without "null"
+fn schema_types(schema: &Map<String, Value>) -> Vec<&str> {
+    match schema.get("type") {
+        Some(Value::String(t)) => vec![t.as_str()],
+        Some(Value::Array(types)) => types.iter().filter_map(Value::as_str).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn type_matches(expected: &str, value: &Value) -> bool {
+    match expected {
+        "null" => value.is_null(),
+        "boolean" => value.is_boolean(),
+        "string" => value.is_string(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        "number" => value.is_number(),
+        "integer" => value.is_i64() || value.is_u64(),
+        _ => true,
+    }
+}
+
+/// Expected type in words, e.g. "array of string" or "integer"
+fn describe(schema: &Map<String, Value>, root: &Value) -> String {
+    if let Some(allowed) = schema.get("enum").and_then(Value::as_array) {
+        return format!("one of {}", list_values(allowed));
+    }
+
+    let types: Vec<&str> = schema_types(schema)
+        .into_iter()
+        .filter(|t| *t != "null")
+        .collect();
+    if types.is_empty() {
+        return "a value".to_string();
+    }
+
+    types
+        .iter()
+        .map(|t| match (*t, schema.get("items")) {
+            ("array", Some(items)) => match resolve(items, root).as_object() {
+                Some(items) => format!("array of {}", describe(items, root)),
+                None => "array".to_string(),
+            },
+            (t, _) => t.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+/// How to fix the common shape mistakes
+fn type_hint(expected: &[&str], value: &Value) -> Option<String> {
+    let expects = |t: &str| expected.contains(&t);
+    match value {
+        Value::String(_) | Value::Number(_) | Value::Bool(_) if expects("array") => {
+            Some(format!("wrap it in a list: [{}]", value))
+        }
+        Value::Array(items) if items.len() == 1 && !expects("array") => Some(format!(
+            "pass the single value without the list: {}",
+            items[0]
+        )),
+        Value::String(s) if expects("integer") || expects("number") => s
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .map(|_| format!("pass it as a number without quotes: {}", s.trim())),
+        Value::String(s) if expects("boolean") => {
+            let s = s.trim().to_lowercase();
+            (s == "true" || s == "false").then(|| format!("pass it without quotes: {}", s))
+        }
+        Value::Number(_) | Value::Bool(_) if expects("string") => {
+            Some(format!("pass it as a string: \"{}\"", value))
+        }
+        _ => None,
+    }
+}
+
+fn not_allowed(path: &str, allowed: &[Value], value: &Value) -> ParamProblem {
+    let mut message = format!(
+        "`{}`: expected one of {}, received {}",
+        path,
+        list_values(allowed),
+        echo(value)
+    );
+    if let Some(received) = value.as_str() {
+        let names: Vec<&str> = allowed.iter().filter_map(Value::as_str).collect();
+        if let Some(closest) = closest_name(received, &names) {
+            message.push_str(&format!("; did you mean \"{}\"?", closest));
+        }
+    }
+    ParamProblem::invalid(message)
+}
+
+fn list_values(values: &[Value]) -> String {
+    values
+        .iter()
+        .map(Value::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// The received value and its JSON type, shortened
+fn echo(value: &Value) -> String {
+    let kind = match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_f64() => "number",
+        Value::Number(_) => "integer",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    };
+    let rendered = value.to_string();
+    if rendered.chars().count() > MAX_ECHO_CHARS {
+        let kept: String = rendered.chars().take(MAX_ECHO_CHARS).collect();
+        format!("{}… ({})", kept, kind)
+    } else {
+        format!("{} ({})", rendered, kind)
+    }
+}
+
+/// Closest known name, ignoring case and separators, if it is close enough
+fn closest_name<'a>(name: &str, known: &[&'a str]) -> Option<&'a str> {
+    let normalize = |s: &str| {
+        s.chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .collect::<String>()
+            .to_lowercase()
+    };
+    let wanted = normalize(name);
+    if wanted.is_empty() {
+        return None;
+    }
+
+    known
+        .iter()
+        .map(|candidate| {
+            let normalized = normalize(candidate);
+            let distance = if normalized.contains(&wanted) || wanted.contains(&normalized) {
+                // "project" for "project_key", "status" for "status_filter"
+                1
+            } else {
+                // A misspelled prefix, like "projct" for "project_key"
+                let length = wanted.chars().count();
+                let prefix_distance = (length..=length + 1)
+                    .map(|n| normalized.chars().take(n).collect::<String>())
+                    .map(|prefix| edit_distance(&prefix, &wanted) + 1)
+                    .min()
+                    .unwrap_or(usize::MAX);
+                edit_distance(&normalized, &wanted).min(prefix_distance)
+            };
+            (distance, *candidate)
+        })
+        .filter(|(distance, _)| *distance <= (wanted.len() / 3).max(2))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tools::{CreateIssueParams, SearchIssuesParams, UpdateCustomFieldsParams};
+    use serde_json::json;
+
+    /// Malformed payloads and a fragment every reported problem list must contain
+    fn malformed_search_payloads() -> Vec<(Value, &'static str)> {
+        vec![
+            (
+                json!({"issue_types": "bug"}),
+                "`issue_types`: expected array of string, received \"bug\" (string); wrap it in a list: [\"bug\"]",
+            ),
+            (
+                json!({"projectKey": "PROJ"}),
+                "`projectKey`: unknown parameter. Did you mean `project_key`?",
+            ),
+            (json!({"stauts": ["open"]}), "Did you mean `status`?"),
+            (
+                json!({"limit": "10"}),
+                "`limit`: expected integer, received \"10\" (string); pass it as a number without quotes: 10",
+            ),
+            (
+                json!({"project_key": ["PROJ"]}),
+                "pass the single value without the list: \"PROJ\"",
+            ),
+            (json!("PROJ"), "parameters must be an object"),
+        ]
+    }
+
+    #[test]
+    fn test_malformed_search_payloads() {
+        for (payload, expected) in malformed_search_payloads() {
+            let problems = check_params::<SearchIssuesParams>(&payload);
+            assert!(
+                problems.iter().any(|p| p.message.contains(expected)),
+                "{} -> {:?}",
+                payload,
+                problems
+            );
+
+            let error = serde_json::from_value::<Validated<SearchIssuesParams>>(payload)
+                .unwrap_err()
+                .to_string();
+            assert!(error.starts_with("Invalid parameters:"), "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_reports_every_problem() {
+        let problems = check_params::<CreateIssueParams>(&json!({
+            "project_key": "PROJ",
+            "sumary": "Typo",
+            "labels": "backend"
+        }));
+
+        assert!(problems.iter().any(|p| p
+            .message
+            .contains("`sumary`: unknown parameter. Did you mean `summary`?")));
+        assert!(problems.iter().any(|p| p
+            .message
+            .contains("`summary`: required parameter is missing (expected string)")));
+        assert!(problems
+            .iter()
+            .any(|p| p.message.contains("wrap it in a list")));
+    }
+
+    #[test]
+    fn test_valid_payloads_pass() {
+        assert!(check_params::<SearchIssuesParams>(&json!({
+            "project_key": "PROJ",
+            "issue_types": ["bug"],
+            "limit": 10
+        }))
+        .is_empty());
+        // serde aliases of a field and of an enum value
+        assert!(check_params::<SearchIssuesParams>(&json!({"status_filter": ["open"]})).is_empty());
+        let params: Validated<SearchIssuesParams> = serde_json::from_value(json!({
+            "order_by": {"field": "created", "direction": "ASC"}
+        }))
+        .unwrap();
+        assert!(params.order_by.is_some());
+        assert!(check_params::<SearchIssuesParams>(&Value::Null).is_empty());
+
+        let params: Validated<UpdateCustomFieldsParams> = serde_json::from_value(json!({
+            "issue_key": "PROJ-1",
+            "story_points": 3,
+            "custom_field_updates": {"customfield_10020": {"value": "RT-1"}}
+        }))
+        .unwrap();
+        assert_eq!(params.issue_key, "PROJ-1");
+        assert_eq!(params.into_inner().story_points, Some(3.0));
+    }
+
+    #[test]
+    fn test_closest_name() {
+        let known = ["project_key", "status_filter", "limit"];
+        assert_eq!(closest_name("projectKey", &known), Some("project_key"));
+        assert_eq!(closest_name("limt", &known), Some("limit"));
+        assert_eq!(closest_name("projct", &known), Some("project_key"));
+        assert_eq!(closest_name("assignee", &known), None);
+    }
+}
@@ -0,0 +1,127 @@
+//! Tool for listing priorities and resolving priority names
+//!
+//! Priority schemes differ between instances (Highest..Lowest on Cloud,
+//! Blocker..Trivial on many Server installs), so names are checked against the
+//! instance's list before they are written or used in JQL.
+
+use crate::cache::{MetadataCache, PriorityInfo};
+use crate::config::JiraConfig;
+use crate::error::JiraMcpResult;
+use crate::jira_client::JiraClient;
+use crate::semantic_mapping::{
+    configured_priority_aliases, default_priority_mappings, resolve_priority_name,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// Parameters for listing priorities
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListPrioritiesParams {
+    /// Bypass the priority cache and reload from JIRA (default: false)
+    #[serde(default)]
+    pub refresh: bool,
+}
+
+/// Result from listing priorities
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ListPrioritiesResult {
+    /// Priorities defined on the instance, highest first
+    pub priorities: Vec<PriorityInfo>,
+
+    /// Semantic aliases accepted in priority parameters and what they resolve to
+    /// on this instance (e.g., "critical" -> "Highest")
+    pub aliases: BTreeMap<String, String>,
+
+    /// Whether the priority list came from the cache
+    pub from_cache: bool,
+}
+
+/// Tool for listing priorities and validating priority names
+pub struct PrioritiesTool {
+    jira_client: Arc<JiraClient>,
+    cache: Arc<MetadataCache>,
+    /// `priority_mappings` and locale aliases from the config
+    aliases: HashMap<String, Vec<String>>,
+}
+
+impl PrioritiesTool {
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        config: Arc<JiraConfig>,
+        cache: Arc<MetadataCache>,
+    ) -> Self {
+        Self {
+            jira_client,
+            cache,
+            aliases: configured_priority_aliases(&config),
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn list_priorities(
+        &self,
+        params: ListPrioritiesParams,
+    ) -> JiraMcpResult<ListPrioritiesResult> {
+        let from_cache = !params.refresh && self.cache.get_priorities().is_some();
+        let priorities = self.known_priorities(params.refresh).await?;
+        let names: Vec<String> = priorities.iter().map(|p| p.name.clone()).collect();
+
+        let mut aliases: BTreeMap<String, String> = BTreeMap::new();
+        let alias_names = default_priority_mappings()
+            .into_keys()
+            .chain(self.aliases.keys().cloned());
+        for alias in alias_names {
+            if let Ok(name) = resolve_priority_name(&alias, &names, &self.aliases, "priority") {
+                aliases.insert(alias.to_lowercase(), name);
+            }
+        }
+
+        info!("Listed {} priorities", priorities.len());
+
+        Ok(ListPrioritiesResult {
+            priorities,
+            aliases,
+            from_cache,
+        })
+    }
+
+    /// Resolve a priority name or alias to the instance's spelling
+    ///
+    /// Unknown names are rejected with suggestions. If the priority list cannot
+    /// be loaded (e.g. missing permission), the name is passed through and JIRA
+    /// gets the final say.
+    pub async fn resolve_priority(&self, priority: &str, parameter: &str) -> JiraMcpResult<String> {
+        let names: Vec<String> = match self.known_priorities(false).await {
+            Ok(priorities) if !priorities.is_empty() => {
+                priorities.into_iter().map(|p| p.name).collect()
+            }
+            Ok(_) => return Ok(priority.trim().to_string()),
+            Err(e) => {
+                warn!(
+                    "Could not load priorities to validate '{}': {}",
+                    priority, e
+                );
+                return Ok(priority.trim().to_string());
+            }
+        };
+
+        resolve_priority_name(priority, &names, &self.aliases, parameter)
+    }
+
+    /// All priorities in the instance, from the cache when available
+    pub async fn known_priorities(&self, refresh: bool) -> JiraMcpResult<Vec<PriorityInfo>> {
+        if !refresh {
+            if let Some(priorities) = self.cache.get_priorities() {
+                return Ok(priorities);
+            }
+        }
+
+        let priorities = self.jira_client.get_priorities().await?;
+        self.cache.set_priorities(priorities.clone())?;
+        Ok(priorities)
+    }
+}
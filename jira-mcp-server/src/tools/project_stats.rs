@@ -0,0 +1,439 @@
+//! Project statistics
+//!
+//! A one-call health snapshot of a project for reporting: open vs closed issues
+//! per issue type, issues created vs resolved per week, the assignees with the
+//! most open issues and the oldest unresolved issues. The counts come from
+//! count-only queries (no issue bodies), run concurrently a few at a time; the
+//! JIRA client's rate limiter paces them like any other request. Only the
+//! assignee ranking reads issues, one page of them, since counts carry no names.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::jql;
+use chrono::{Duration, NaiveDate, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tracing::{info, instrument, warn};
+
+/// Default and maximum window, in days
+const DEFAULT_WINDOW_DAYS: u32 = 28;
+const MAX_WINDOW_DAYS: u32 = 91;
+
+/// Issue types broken down; two count queries each
+const MAX_ISSUE_TYPES: usize = 10;
+
+/// Open issues looked at to rank assignees
+const MAX_ASSIGNEE_SAMPLE: usize = 200;
+
+/// Assignees and oldest issues listed
+const TOP_ASSIGNEES: usize = 5;
+const OLDEST_ISSUES: usize = 5;
+
+/// Count queries in flight at once
+const MAX_CONCURRENT_QUERIES: usize = 5;
+
+/// Parameters for the get_project_stats tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetProjectStatsParams {
+    /// Project key
+    /// Examples: "PROJ"
+    pub project_key: String,
+
+    /// Days covered by the weekly trend, rounded up to whole weeks
+    /// (optional, default: 28, max: 91)
+    pub window_days: Option<u32>,
+}
+
+/// Open and closed issues of one issue type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueTypeStats {
+    pub issue_type: String,
+    pub open: usize,
+    pub closed: usize,
+}
+
+/// Issues created and resolved in one week
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeeklyTrend {
+    /// First day of the week (inclusive)
+    pub week_start: String,
+
+    /// Last day of the week (inclusive)
+    pub week_end: String,
+
+    pub created: usize,
+    pub resolved: usize,
+}
+
+/// Open issues of one assignee
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssigneeStats {
+    pub assignee: String,
+    pub open: usize,
+}
+
+/// An unresolved issue, oldest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnresolvedIssue {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    pub assignee: Option<String>,
+    pub created: String,
+}
+
+/// Result from the get_project_stats tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetProjectStatsResult {
+    pub project_key: String,
+
+    /// Days covered by weekly_trend
+    pub window_days: u32,
+
+    /// Unresolved issues
+    pub open: usize,
+
+    /// Resolved issues
+    pub closed: usize,
+
+    /// Open unassigned issues
+    pub unassigned_open: usize,
+
+    /// Open and closed issues per issue type (types without issues left out)
+    pub by_issue_type: Vec<IssueTypeStats>,
+
+    /// Created vs resolved per week, oldest week first
+    pub weekly_trend: Vec<WeeklyTrend>,
+
+    /// Assignees with the most open issues
+    pub top_assignees: Vec<AssigneeStats>,
+
+    /// Oldest unresolved issues
+    pub oldest_unresolved: Vec<UnresolvedIssue>,
+
+    /// JQL queries sent to JIRA for this snapshot
+    pub jql_queries: usize,
+
+    /// Where numbers are approximate or parts were left out
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<String>,
+}
+
+/// What a count query is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Count {
+    Open,
+    Closed,
+    UnassignedOpen,
+    TypeOpen(usize),
+    TypeClosed(usize),
+    Created(usize),
+    Resolved(usize),
+}
+
+/// Implementation of the get_project_stats tool
+pub struct GetProjectStatsTool {
+    jira_client: Arc<JiraClient>,
+}
+
+impl GetProjectStatsTool {
+    pub fn new(jira_client: Arc<JiraClient>) -> Self {
+        Self { jira_client }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: GetProjectStatsParams,
+    ) -> JiraMcpResult<GetProjectStatsResult> {
+        let project_key = params.project_key.trim().to_uppercase();
+        if project_key.is_empty() {
+            return Err(JiraMcpError::invalid_param(
+                "project_key",
+                "project_key must not be empty",
+            ));
+        }
+        let window_days = params.window_days.unwrap_or(DEFAULT_WINDOW_DAYS);
+        if window_days == 0 || window_days > MAX_WINDOW_DAYS {
+            return Err(JiraMcpError::invalid_param(
+                "window_days",
+                format!("window_days must be between 1 and {}", MAX_WINDOW_DAYS),
+            ));
+        }
+
+        info!("Collecting statistics of project {}", project_key);
+
+        let mut notes = Vec::new();
+        let mut issue_types: Vec<String> = self
+            .jira_client
+            .get_issue_types(Some(&project_key))
+            .await?
+            .into_iter()
+            .map(|issue_type| issue_type.name)
+            .collect();
+        if issue_types.len() > MAX_ISSUE_TYPES {
+            notes.push(format!(
+                "by_issue_type: only the first {} of {} issue types are broken down",
+                MAX_ISSUE_TYPES,
+                issue_types.len()
+            ));
+            issue_types.truncate(MAX_ISSUE_TYPES);
+        }
+        let weeks = weeks_ending(Utc::now().date_naive(), window_days);
+
+        // Assignees need names, so they are ranked from one page of open issues
+        let project = format!("{} = {}", jql::PROJECT, jql::quote(&project_key));
+        let sample_jql = format!(
+            "{} AND {} IS EMPTY AND {} IS NOT EMPTY ORDER BY updated DESC",
+            project,
+            jql::RESOLUTION,
+            jql::ASSIGNEE
+        );
+        let oldest_jql = format!(
+            "{} AND {} IS EMPTY ORDER BY {} ASC",
+            project,
+            jql::RESOLUTION,
+            jql::CREATED
+        );
+        let queries = count_queries(&project_key, &issue_types, &weeks);
+        let (counts, sample, oldest) = tokio::join!(
+            self.run_counts(&queries),
+            self.jira_client
+                .search_issues_jql(&sample_jql, None, Some(MAX_ASSIGNEE_SAMPLE), None),
+            self.jira_client
+                .search_issues_jql(&oldest_jql, None, Some(OLDEST_ISSUES), None),
+        );
+        let (counts, sample, oldest) = (counts?, sample?, oldest?);
+        let count = |slot: Count| counts.get(&slot).copied().unwrap_or_default();
+
+        let open = count(Count::Open);
+        let assigned_open = open.saturating_sub(count(Count::UnassignedOpen));
+        if sample.issues.len() < assigned_open {
+            notes.push(format!(
+                "top_assignees: counted over the {} most recently updated of {} assigned open issues",
+                sample.issues.len(),
+                assigned_open
+            ));
+        }
+        let top_assignees =
+            top_assignees(sample.issues.iter().filter_map(|i| i.assignee.as_deref()));
+
+        let oldest_unresolved = oldest
+            .issues
+            .into_iter()
+            .map(|issue| UnresolvedIssue {
+                key: issue.key,
+                summary: issue.summary,
+                status: issue.status,
+                assignee: issue.assignee,
+                created: issue.created,
+            })
+            .collect();
+
+        let by_issue_type = issue_types
+            .into_iter()
+            .enumerate()
+            .map(|(i, issue_type)| IssueTypeStats {
+                issue_type,
+                open: count(Count::TypeOpen(i)),
+                closed: count(Count::TypeClosed(i)),
+            })
+            .filter(|stats| stats.open + stats.closed > 0)
+            .collect();
+        let weekly_trend = weeks
+            .iter()
+            .enumerate()
+            .map(|(i, (start, end))| WeeklyTrend {
+                week_start: start.to_string(),
+                week_end: (*end - Duration::days(1)).to_string(),
+                created: count(Count::Created(i)),
+                resolved: count(Count::Resolved(i)),
+            })
+            .collect();
+
+        Ok(GetProjectStatsResult {
+            project_key,
+            window_days: weeks.len() as u32 * 7,
+            open,
+            closed: count(Count::Closed),
+            unassigned_open: count(Count::UnassignedOpen),
+            by_issue_type,
+            weekly_trend,
+            top_assignees,
+            oldest_unresolved,
+            jql_queries: queries.len() + 2,
+            notes,
+        })
+    }
+
+    /// Run the count queries, a few at a time
+    async fn run_counts(
+        &self,
+        queries: &[(Count, String)],
+    ) -> JiraMcpResult<HashMap<Count, usize>> {
+        let mut counts = HashMap::new();
+        let mut pending = queries.iter().cloned();
+        let mut join_set = JoinSet::new();
+        loop {
+            while join_set.len() < MAX_CONCURRENT_QUERIES {
+                let Some((slot, jql)) = pending.next() else {
+                    break;
+                };
+                let jira_client = Arc::clone(&self.jira_client);
+                join_set.spawn(async move { (slot, jira_client.count_issues_jql(&jql).await) });
+            }
+
+            let Some(joined) = join_set.join_next().await else {
+                break;
+            };
+            let (slot, count) =
+                joined.map_err(|e| JiraMcpError::internal(format!("Count task failed: {}", e)))?;
+            let count = count.inspect_err(|e| warn!("Count query {:?} failed: {}", slot, e))?;
+            counts.insert(slot, count);
+        }
+        Ok(counts)
+    }
+}
+
+/// Week ranges (start inclusive, end exclusive) covering `window_days` up to
+/// and including `today`, oldest first
+fn weeks_ending(today: NaiveDate, window_days: u32) -> Vec<(NaiveDate, NaiveDate)> {
+    let weeks = window_days.div_ceil(7) as i64;
+    let end = today + Duration::days(1);
+    (0..weeks)
+        .map(|i| {
+            let week_end = end - Duration::days(7 * (weeks - 1 - i));
+            (week_end - Duration::days(7), week_end)
+        })
+        .collect()
+}
+
+/// Every count query of a snapshot
+fn count_queries(
+    project_key: &str,
+    issue_types: &[String],
+    weeks: &[(NaiveDate, NaiveDate)],
+) -> Vec<(Count, String)> {
+    let project = format!("{} = {}", jql::PROJECT, jql::quote(project_key));
+    let open = format!("{} AND {} IS EMPTY", project, jql::RESOLUTION);
+    let closed = format!("{} AND {} IS NOT EMPTY", project, jql::RESOLUTION);
+    let in_week = |field: &jql::Field, (start, end): &(NaiveDate, NaiveDate)| {
+        format!(
+            "{} AND {} >= {} AND {} < {}",
+            project,
+            field,
+            jql::quote(&start.to_string()),
+            field,
+            jql::quote(&end.to_string())
+        )
+    };
+
+    let mut queries = vec![
+        (Count::Open, open.clone()),
+        (Count::Closed, closed.clone()),
+        (
+            Count::UnassignedOpen,
+            format!("{} AND {} IS EMPTY", open, jql::ASSIGNEE),
+        ),
+    ];
+    for (i, issue_type) in issue_types.iter().enumerate() {
+        let is_type = format!("{} = {}", jql::ISSUE_TYPE, jql::quote(issue_type));
+        queries.push((Count::TypeOpen(i), format!("{} AND {}", open, is_type)));
+        queries.push((Count::TypeClosed(i), format!("{} AND {}", closed, is_type)));
+    }
+    for (i, week) in weeks.iter().enumerate() {
+        queries.push((Count::Created(i), in_week(&jql::CREATED, week)));
+        queries.push((Count::Resolved(i), in_week(&jql::RESOLVED, week)));
+    }
+    queries
+}
+
+/// Assignees by number of issues, most first; ties by name
+fn top_assignees<'a>(assignees: impl Iterator<Item = &'a str>) -> Vec<AssigneeStats> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for assignee in assignees {
+        *counts.entry(assignee).or_default() += 1;
+    }
+    let mut ranked: Vec<AssigneeStats> = counts
+        .into_iter()
+        .map(|(assignee, open)| AssigneeStats {
+            assignee: assignee.to_string(),
+            open,
+        })
+        .collect();
+    ranked.sort_by(|a, b| {
+        b.open
+            .cmp(&a.open)
+            .then_with(|| a.assignee.cmp(&b.assignee))
+    });
+    ranked.truncate(TOP_ASSIGNEES);
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weeks_ending() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+        let weeks = weeks_ending(today, 10);
+        assert_eq!(
+            weeks
+                .iter()
+                .map(|(start, end)| (start.to_string(), end.to_string()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("2025-02-27".to_string(), "2025-03-06".to_string()),
+                ("2025-03-06".to_string(), "2025-03-13".to_string()),
+            ]
+        );
+        assert_eq!(weeks_ending(today, 7).len(), 1);
+    }
+
+    #[test]
+    fn test_count_queries() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+        let queries = count_queries(
+            "PROJ",
+            &["Bug".to_string(), "Story".to_string()],
+            &weeks_ending(today, 14),
+        );
+
+        // open, closed, unassigned, two per type and two per week
+        assert_eq!(queries.len(), 3 + 2 * 2 + 2 * 2);
+        let jql = |slot| &queries.iter().find(|(s, _)| *s == slot).unwrap().1;
+        assert_eq!(
+            jql(Count::TypeClosed(0)),
+            r#"project = "PROJ" AND resolution IS NOT EMPTY AND issuetype = "Bug""#
+        );
+        assert_eq!(
+            jql(Count::Resolved(1)),
+            r#"project = "PROJ" AND resolved >= "2025-03-06" AND resolved < "2025-03-13""#
+        );
+    }
+
+    #[test]
+    fn test_top_assignees() {
+        let names = [
+            "Bob", "Alice", "Bob", "Carol", "Alice", "Bob", "Dan", "Eve", "Frank",
+        ];
+        let top = top_assignees(names.into_iter());
+        let ranked: Vec<(&str, usize)> =
+            top.iter().map(|a| (a.assignee.as_str(), a.open)).collect();
+        assert_eq!(
+            ranked,
+            vec![
+                ("Bob", 3),
+                ("Alice", 2),
+                ("Carol", 1),
+                ("Dan", 1),
+                ("Eve", 1)
+            ]
+        );
+    }
+}
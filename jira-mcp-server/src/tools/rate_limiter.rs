@@ -2,15 +2,40 @@
 //!
 //! Similar to Atlassian's approach: prevents hitting rate limits by queuing requests
 //! Uses a sliding window to track requests over time
+//!
+//! The limiter also backs the `rate_limit` block of tool results: requests made by
+//! the JIRA client count against its budget, and 429 responses it receives are
+//! recorded with [`RateLimiter::record_throttle`], so agents can be told to pause.
 
-use std::sync::Arc;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::{Duration, Instant};
-use tokio::sync::Mutex;
 use tracing::{debug, warn};
 
+/// Share of the budget below which tool results suggest pausing
+const LOW_BUDGET_PERCENT: usize = 10;
+
+/// Throttling information attached to tool results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitStatus {
+    /// Whether JIRA answered with 429 and its retry window has not passed yet
+    pub throttled: bool,
+
+    /// How long to wait before the next request, when throttled
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_after_ms: Option<u64>,
+
+    /// Requests left in the current window of `rate_limit_per_minute`
+    pub remaining_budget: usize,
+
+    /// Suggestion for the caller when it should slow down
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hint: Option<String>,
+}
+
 /// Sliding window rate limiter
 /// Tracks request timestamps and enforces limits by blocking requests
-#[derive(Clone)]
+#[derive(Debug, Clone)]
 pub struct RateLimiter {
     /// Request timestamps within the current window
     state: Arc<Mutex<RateLimiterState>>,
@@ -20,9 +45,12 @@ pub struct RateLimiter {
     window_duration: Duration,
 }
 
+#[derive(Debug)]
 struct RateLimiterState {
     /// Timestamps of recent requests
     request_times: Vec<Instant>,
+    /// End of the throttling window announced by the last 429 response
+    throttled_until: Option<Instant>,
 }
 
 impl RateLimiter {
@@ -44,6 +72,7 @@ impl RateLimiter {
         Self {
             state: Arc::new(Mutex::new(RateLimiterState {
                 request_times: Vec::with_capacity(max_requests),
+                throttled_until: None,
             })),
             max_requests,
             window_duration,
@@ -55,51 +84,133 @@ impl RateLimiter {
     /// This method blocks until a request slot is available, ensuring we never
     /// exceed the rate limit. Uses a sliding window algorithm.
     pub async fn wait_for_slot(&self) {
-        loop {
-            let mut state = self.state.lock().await;
-            let now = Instant::now();
+        self.acquire(true).await
+    }
 
-            // Remove timestamps older than the window
-            state
-                .request_times
-                .retain(|&time| now.duration_since(time) < self.window_duration);
-
-            // Check if we have capacity
-            if state.request_times.len() < self.max_requests {
-                // Add current timestamp and allow the request
-                state.request_times.push(now);
-                debug!(
-                    "Rate limiter: Allowed request ({}/{} used)",
+    /// Wait until the budget has room, without counting a request
+    ///
+    /// For callers whose requests go through the JIRA client, which counts them
+    /// itself (see [`RateLimiter::record_request`]).
+    pub async fn wait_for_capacity(&self) {
+        self.acquire(false).await
+    }
+
+    async fn acquire(&self, record: bool) {
+        loop {
+            // The lock is released at the end of this block, before sleeping
+            let wait_time = {
+                let mut state = self.lock_state();
+                let now = Instant::now();
+
+                // Remove timestamps older than the window
+                state
+                    .request_times
+                    .retain(|&time| now.duration_since(time) < self.window_duration);
+
+                // Check if we have capacity
+                if state.request_times.len() < self.max_requests {
+                    // Add current timestamp and allow the request
+                    if record {
+                        state.request_times.push(now);
+                    }
+                    debug!(
+                        "Rate limiter: Allowed request ({}/{} used)",
+                        state.request_times.len(),
+                        self.max_requests
+                    );
+                    return;
+                }
+
+                // Calculate how long to wait
+                let oldest_request = state.request_times[0];
+                let elapsed = now.duration_since(oldest_request);
+                let wait_time =
+                    self.window_duration.saturating_sub(elapsed) + Duration::from_millis(100);
+
+                warn!(
+                    "Rate limiter: Limit reached ({}/{}), waiting {:?}",
                     state.request_times.len(),
-                    self.max_requests
+                    self.max_requests,
+                    wait_time
                 );
-                return;
-            }
-
-            // Calculate how long to wait
-            let oldest_request = state.request_times[0];
-            let elapsed = now.duration_since(oldest_request);
-            let wait_time =
-                self.window_duration.saturating_sub(elapsed) + Duration::from_millis(100);
-
-            warn!(
-                "Rate limiter: Limit reached ({}/{}), waiting {:?}",
-                state.request_times.len(),
-                self.max_requests,
                 wait_time
-            );
-
-            // Release the lock before sleeping
-            drop(state);
+            };
 
             // Wait before trying again
             tokio::time::sleep(wait_time).await;
         }
     }
 
+    /// Count a request against the budget without waiting for a slot
+    ///
+    /// Used for foreground requests, which are never delayed by the limiter.
+    pub fn record_request(&self) {
+        let mut state = self.lock_state();
+        let now = Instant::now();
+        state
+            .request_times
+            .retain(|&time| now.duration_since(time) < self.window_duration);
+        state.request_times.push(now);
+    }
+
+    /// Record a 429 from JIRA asking to retry after `retry_after`
+    pub fn record_throttle(&self, retry_after: Duration) {
+        let until = Instant::now() + retry_after;
+        let mut state = self.lock_state();
+        if state.throttled_until.is_none_or(|current| current < until) {
+            warn!("JIRA is throttling requests, retry after {:?}", retry_after);
+            state.throttled_until = Some(until);
+        }
+    }
+
+    /// Current throttling and budget information
+    pub fn status(&self) -> RateLimitStatus {
+        let (remaining_budget, throttled_for) = {
+            let state = self.lock_state();
+            let now = Instant::now();
+            let active = state
+                .request_times
+                .iter()
+                .filter(|&&time| now.duration_since(time) < self.window_duration)
+                .count();
+            // Time left until JIRA accepts requests again, if it is throttling
+            let throttled_for = state
+                .throttled_until
+                .map(|until| until.saturating_duration_since(now))
+                .filter(|remaining| !remaining.is_zero());
+            (self.max_requests.saturating_sub(active), throttled_for)
+        };
+
+        let low_budget = remaining_budget * 100 <= self.max_requests * LOW_BUDGET_PERCENT;
+        let hint = match throttled_for {
+            Some(wait) => Some(format!(
+                "JIRA is rate limiting requests; pause for {}s before the next call",
+                (wait.as_millis() as u64).div_ceil(1000)
+            )),
+            None if low_budget => Some(format!(
+                "Request budget nearly exhausted ({} of {} left this minute); \
+                 pause or batch requests before continuing",
+                remaining_budget, self.max_requests
+            )),
+            None => None,
+        };
+
+        RateLimitStatus {
+            throttled: throttled_for.is_some(),
+            retry_after_ms: throttled_for.map(|wait| wait.as_millis() as u64),
+            remaining_budget,
+            hint,
+        }
+    }
+
+    /// The status, if the caller should slow down
+    pub fn backpressure(&self) -> Option<RateLimitStatus> {
+        Some(self.status()).filter(|status| status.hint.is_some())
+    }
+
     /// Get current usage statistics
     pub async fn get_stats(&self) -> RateLimiterStats {
-        let state = self.state.lock().await;
+        let state = self.lock_state();
         let now = Instant::now();
 
         // Count requests in current window
@@ -120,10 +231,15 @@ impl RateLimiter {
     /// Reset the rate limiter (clear all timestamps)
     #[allow(dead_code)]
     pub async fn reset(&self) {
-        let mut state = self.state.lock().await;
+        let mut state = self.lock_state();
         state.request_times.clear();
         debug!("Rate limiter reset");
     }
+
+    /// The state is never held across an await, so a plain mutex will do
+    fn lock_state(&self) -> MutexGuard<'_, RateLimiterState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
 }
 
 /// Statistics about rate limiter usage
@@ -175,6 +291,36 @@ mod tests {
         assert!(elapsed >= Duration::from_millis(400));
     }
 
+    #[test]
+    fn test_status_reports_backpressure() {
+        let limiter = RateLimiter::new(10, Duration::from_secs(60));
+        for _ in 0..5 {
+            limiter.record_request();
+        }
+        let status = limiter.status();
+        assert_eq!(status.remaining_budget, 5);
+        assert!(status.hint.is_none());
+
+        for _ in 0..4 {
+            limiter.record_request();
+        }
+        let status = limiter.status();
+        assert_eq!(status.remaining_budget, 1);
+        assert!(status.hint.unwrap().contains("1 of 10 left"));
+
+        limiter.record_throttle(Duration::from_secs(30));
+        let status = limiter.status();
+        assert!(status.throttled);
+        assert!(status.retry_after_ms.unwrap() > 29_000);
+
+        // The throttle belongs to the limiter that saw the 429
+        assert!(
+            !RateLimiter::new(10, Duration::from_secs(60))
+                .status()
+                .throttled
+        );
+    }
+
     #[tokio::test]
     async fn test_sliding_window() {
         let limiter = RateLimiter::new(2, Duration::from_millis(500));
@@ -0,0 +1,244 @@
+//! Markdown rendering for read-heavy tools
+//!
+//! Some MCP clients show plain text better than JSON. With `format: "markdown"`
+//! search_issues, get_user_issues, get_sprint_issues and list_todos add a
+//! `rendered` string next to the usual JSON fields: a compact issue table or a
+//! todo checklist. Cells are kept on one line and cut to a column width, so one
+//! long summary cannot stretch the table.
+
+use crate::jira_client::SearchResult;
+use crate::tools::todo_tracker::{TodoItem, TodoStatus};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Output format of a tool result
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    /// JSON fields only
+    #[default]
+    Json,
+    /// JSON fields plus a `rendered` markdown string
+    Markdown,
+}
+
+/// Widest summary cell, in characters
+const SUMMARY_WIDTH: usize = 60;
+
+/// Widest status cell, in characters
+const STATUS_WIDTH: usize = 20;
+
+/// Widest assignee cell, in characters
+const ASSIGNEE_WIDTH: usize = 24;
+
+/// Widest todo line, in characters
+const TODO_WIDTH: usize = 120;
+
+impl OutputFormat {
+    /// The markdown from `render` when markdown was asked for
+    pub fn render(self, render: impl FnOnce() -> String) -> Option<String> {
+        match self {
+            OutputFormat::Json => None,
+            OutputFormat::Markdown => Some(render()),
+        }
+    }
+}
+
+/// Table of a result page: key | summary | status | assignee
+pub fn issue_table(result: &SearchResult) -> String {
+    if result.issues.is_empty() {
+        return "_No issues found._".to_string();
+    }
+
+    let mut lines = vec![
+        "| Key | Summary | Status | Assignee |".to_string(),
+        "|---|---|---|---|".to_string(),
+    ];
+    for issue in &result.issues {
+        lines.push(format!(
+            "| {} | {} | {} | {} |",
+            cell(&issue.key, usize::MAX),
+            cell(&issue.summary, SUMMARY_WIDTH),
+            cell(&issue.status, STATUS_WIDTH),
+            cell(
+                issue.assignee.as_deref().unwrap_or("Unassigned"),
+                ASSIGNEE_WIDTH
+            ),
+        ));
+    }
+
+    let shown = result.issues.len();
+    if let Some(total) = result.total.filter(|total| *total > shown) {
+        let first = result.start_at + 1;
+        lines.push(String::new());
+        lines.push(format!(
+            "_Issues {}–{} of {}._",
+            first,
+            result.start_at + shown,
+            total
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Checklist of todos, under their section headings
+pub fn todo_checklist(todos: &[TodoItem]) -> String {
+    if todos.is_empty() {
+        return "_No todos found._".to_string();
+    }
+
+    let mut lines = Vec::new();
+    let mut section = None;
+    for todo in todos {
+        if todo.section.is_some() && todo.section != section {
+            if !lines.is_empty() {
+                lines.push(String::new());
+            }
+            lines.push(format!(
+                "### {}",
+                cell(todo.section.as_deref().unwrap_or_default(), TODO_WIDTH)
+            ));
+            section = todo.section.clone();
+        }
+        let mark = if todo.completed { "x" } else { " " };
+        let wip = if todo.status == TodoStatus::Wip {
+            " _(in progress)_"
+        } else {
+            ""
+        };
+        lines.push(format!(
+            "- [{}] {}{}",
+            mark,
+            cell(&todo.text, TODO_WIDTH),
+            wip
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Text fit for a table cell: one line, pipes escaped, cut to `width`
+/// characters with an ellipsis
+fn cell(text: &str, width: usize) -> String {
+    let line = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let line = if line.chars().count() > width {
+        let mut cut: String = line.chars().take(width.saturating_sub(1)).collect();
+        cut.truncate(cut.trim_end().len());
+        cut.push('…');
+        cut
+    } else {
+        line
+    };
+    line.replace('|', "\\|")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jira_client::IssueInfo;
+
+    fn issue(key: &str, summary: &str, status: &str, assignee: Option<&str>) -> IssueInfo {
+        IssueInfo {
+            key: key.to_string(),
+            id: "1".to_string(),
+            url: String::new(),
+            summary: summary.to_string(),
+            description: None,
+            issue_type: "Task".to_string(),
+            status: status.to_string(),
+            status_category: None,
+            status_color: None,
+            issue_type_icon_url: None,
+            priority: None,
+            assignee: assignee.map(String::from),
+            reporter: None,
+            created: String::new(),
+            updated: String::new(),
+            due_date: None,
+            project_key: "PROJ".to_string(),
+            project_name: "Project".to_string(),
+            labels: Vec::new(),
+            components: Vec::new(),
+            story_points: None,
+            acceptance_criteria: None,
+            match_snippet: None,
+            match_field: None,
+            time_tracking: None,
+            security_level: None,
+            resolution: None,
+            comments: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_issue_table() {
+        let result = SearchResult {
+            issues: vec![
+                issue(
+                    "PROJ-1",
+                    "Überprüfung der Zahlungsabwicklung für Bestellungen mit Gutscheinen schlägt fehl 💳",
+                    "In Bearbeitung",
+                    Some("Zoë Ångström"),
+                ),
+                issue(
+                    "PROJ-2",
+                    "Pipe | in\nsummary",
+                    "Waiting for customer approval (legal)",
+                    None,
+                ),
+                issue(
+                    "PROJ-3",
+                    "日本語の概要がとても長い場合でも表の列幅は一定に保たれるべきです。\
+                     これは六十文字を超える長い概要の例で、最後の部分は省略されます。",
+                    "完了",
+                    Some("山田 太郎"),
+                ),
+            ],
+            total: Some(12),
+            start_at: 0,
+            max_results: 3,
+            is_last: false,
+        };
+
+        assert_eq!(
+            issue_table(&result),
+            "| Key | Summary | Status | Assignee |\n\
+             |---|---|---|---|\n\
+             | PROJ-1 | Überprüfung der Zahlungsabwicklung für Bestellungen mit Gut… | In Bearbeitung | Zoë Ångström |\n\
+             | PROJ-2 | Pipe \\| in summary | Waiting for custome… | Unassigned |\n\
+             | PROJ-3 | 日本語の概要がとても長い場合でも表の列幅は一定に保たれるべきです。これは六十文字を超える長い概要の例で、最後の部分は省… | 完了 | 山田 太郎 |\n\
+             \n\
+             _Issues 1–3 of 12._"
+        );
+
+        let long = "あ".repeat(61);
+        assert_eq!(cell(&long, SUMMARY_WIDTH).chars().count(), SUMMARY_WIDTH);
+        assert!(cell(&long, SUMMARY_WIDTH).ends_with("あ…"));
+    }
+
+    #[test]
+    fn test_todo_checklist() {
+        let todo = |text: &str, status: TodoStatus, section: Option<&str>| TodoItem {
+            text: text.to_string(),
+            completed: status == TodoStatus::Completed,
+            status,
+            line_number: 0,
+            id: String::new(),
+            section: section.map(String::from),
+        };
+        let todos = vec![
+            todo("Write plan", TodoStatus::Completed, Some("Tasks")),
+            todo("Wire up form ✨", TodoStatus::Wip, Some("Tasks")),
+            todo(&"長い".repeat(70), TodoStatus::Open, Some("Backend")),
+        ];
+
+        assert_eq!(
+            todo_checklist(&todos),
+            format!(
+                "### Tasks\n- [x] Write plan\n- [ ] Wire up form ✨ _(in progress)_\n\n\
+                 ### Backend\n- [ ] {}…",
+                "長い".repeat(59) + "長"
+            )
+        );
+        assert_eq!(todo_checklist(&[]), "_No todos found._");
+    }
+}
@@ -0,0 +1,61 @@
+//! Recent requests tool
+//!
+//! Reads back the outbound JIRA requests recorded by `crate::request_log`, for
+//! debugging slow or failing calls without turning on debug logging.
+
+use crate::error::JiraMcpResult;
+use crate::jira_client::JiraClient;
+use crate::request_log::RequestLogEntry;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+const DEFAULT_LIMIT: usize = 20;
+const MAX_LIMIT: usize = 500;
+
+/// Parameters for the get_recent_requests tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetRecentRequestsParams {
+    /// Maximum entries to return, newest first (optional, default: 20, max: 500)
+    pub limit: Option<usize>,
+}
+
+/// Result from the get_recent_requests tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct GetRecentRequestsResult {
+    /// Recorded requests, newest first, with secrets redacted
+    pub entries: Vec<RequestLogEntry>,
+
+    /// Whether requests are being recorded (request_log.enabled / JIRA_LOG_REQUESTS)
+    pub enabled: bool,
+}
+
+/// Tool for reading the request log
+pub struct GetRecentRequestsTool {
+    jira_client: Arc<JiraClient>,
+}
+
+impl GetRecentRequestsTool {
+    pub fn new(jira_client: Arc<JiraClient>) -> Self {
+        Self { jira_client }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: GetRecentRequestsParams,
+    ) -> JiraMcpResult<GetRecentRequestsResult> {
+        let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let request_log = self.jira_client.request_log();
+
+        let entries = request_log.recent(limit);
+        info!("Returning {} request log entries", entries.len());
+
+        Ok(GetRecentRequestsResult {
+            entries,
+            enabled: request_log.enabled(),
+        })
+    }
+}
@@ -0,0 +1,285 @@
+//! Response size budget
+//!
+//! MCP clients feed tool results into a model context of limited size, and an issue
+//! with hundreds of comments or a search over long descriptions can exceed it on its
+//! own. Every structured tool result is serialized through [`fit_to_budget`] (see
+//! `ToolResponse::envelope`), which trims it to `max_response_chars`:
+//!
+//! - the largest list loses items from its tail,
+//! - the largest string loses characters from its middle,
+//!
+//! repeated until the result fits. Trimming works on the JSON value, so the output
+//! stays valid JSON, and the same input always yields the same output. A trimmed
+//! result carries a top-level `truncated` object saying what was dropped.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Characters kept free for the `truncated` marker itself
+const MARKER_ALLOWANCE: usize = 400;
+
+/// Strings up to this length are never shortened
+const MIN_ELIDED_STRING_CHARS: usize = 256;
+
+/// Characters kept from a shortened string, split between its start and end
+const MIN_KEPT_STRING_CHARS: usize = 128;
+
+/// Smallest per-call budget accepted, so the marker always fits
+pub const MIN_RESPONSE_BUDGET: usize = 1_000;
+
+/// What was removed from a result to fit the budget
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Truncation {
+    /// List items dropped from the end of lists
+    pub dropped_items: usize,
+
+    /// Characters cut from the middle of long strings
+    pub elided_chars: usize,
+
+    /// JSON pointers of the trimmed lists and strings, e.g. "/comments"
+    pub paths: Vec<String>,
+
+    /// Why the result was trimmed
+    pub reason: String,
+}
+
+/// A list or string that can still be trimmed
+struct Candidate {
+    pointer: String,
+    chars: usize,
+}
+
+/// Trim `value` until its serialized form fits in `max_chars` characters
+///
+/// A budget of 0 disables trimming. When anything is removed, the returned
+/// [`Truncation`] is also inserted into `value` as `truncated` (if it is an object).
+pub fn fit_to_budget(value: &mut Value, max_chars: usize) -> Option<Truncation> {
+    if max_chars == 0 {
+        return None;
+    }
+
+    let original_chars = json_chars(value);
+    if original_chars <= max_chars {
+        return None;
+    }
+
+    let target = max_chars.saturating_sub(MARKER_ALLOWANCE);
+    let mut truncation = Truncation {
+        dropped_items: 0,
+        elided_chars: 0,
+        paths: Vec::new(),
+        reason: format!(
+            "Result of {} characters exceeded the response budget of {}; trailing list \
+             items were dropped and long strings shortened in the middle",
+            original_chars, max_chars
+        ),
+    };
+
+    let mut chars = original_chars;
+    while chars > target {
+        let mut candidates = Vec::new();
+        collect_candidates(value, &mut String::new(), &mut candidates);
+
+        // The first of equally large candidates wins, keeping the outcome deterministic
+        let Some(candidate) =
+            candidates
+                .into_iter()
+                .reduce(|best, next| if next.chars > best.chars { next } else { best })
+        else {
+            break;
+        };
+
+        let excess = chars - target;
+        match value.pointer_mut(&candidate.pointer) {
+            Some(Value::Array(items)) => {
+                truncation.dropped_items += drop_tail(items, excess);
+            }
+            Some(Value::String(text)) => {
+                truncation.elided_chars += elide_middle(text, excess);
+            }
+            _ => break,
+        }
+        if !truncation.paths.contains(&candidate.pointer) {
+            truncation.paths.push(candidate.pointer);
+        }
+
+        chars = json_chars(value);
+    }
+
+    if let Value::Object(fields) = value {
+        if let Ok(marker) = serde_json::to_value(&truncation) {
+            fields.insert("truncated".to_string(), marker);
+        }
+    }
+
+    Some(truncation)
+}
+
+/// Drop items from the end of `items` until `excess` characters are saved,
+/// keeping at least one; returns the number of dropped items
+fn drop_tail(items: &mut Vec<Value>, excess: usize) -> usize {
+    let mut saved = 0;
+    let mut keep = items.len();
+    while keep > 1 && saved < excess {
+        keep -= 1;
+        // The item plus the comma separating it from its predecessor
+        saved += json_chars(&items[keep]) + 1;
+    }
+
+    let dropped = items.len() - keep;
+    items.truncate(keep);
+    dropped
+}
+
+/// Replace the middle of `text` with an elision note so it shrinks by about
+/// `excess` characters; returns the number of removed characters
+fn elide_middle(text: &mut String, excess: usize) -> usize {
+    let total = text.chars().count();
+    let keep = total
+        .saturating_sub(excess + 32)
+        .max(MIN_KEPT_STRING_CHARS)
+        .min(total);
+    let removed = total - keep;
+    if removed == 0 {
+        return 0;
+    }
+
+    let head: String = text.chars().take(keep / 2).collect();
+    let tail: String = text.chars().skip(total - (keep - keep / 2)).collect();
+    *text = format!("{} … [{} chars elided] … {}", head, removed, tail);
+    removed
+}
+
+/// Collect trimmable lists and strings below `value`, returning its serialized size
+///
+/// Sizes are summed from the children rather than serializing every subtree, so a
+/// pass over the result stays linear.
+fn collect_candidates(value: &Value, pointer: &mut String, out: &mut Vec<Candidate>) -> usize {
+    match value {
+        Value::Array(items) => {
+            let mut chars = 2 + items.len().saturating_sub(1);
+            let index = out.len();
+            for (i, item) in items.iter().enumerate() {
+                let len = pointer.len();
+                pointer.push('/');
+                pointer.push_str(&i.to_string());
+                chars += collect_candidates(item, pointer, out);
+                pointer.truncate(len);
+            }
+            if items.len() > 1 {
+                out.insert(
+                    index,
+                    Candidate {
+                        pointer: pointer.clone(),
+                        chars,
+                    },
+                );
+            }
+            chars
+        }
+        Value::Object(fields) => {
+            let mut chars = 2 + fields.len().saturating_sub(1);
+            for (key, field) in fields {
+                let len = pointer.len();
+                pointer.push('/');
+                pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+                chars += json_chars(&Value::String(key.clone())) + 1;
+                chars += collect_candidates(field, pointer, out);
+                pointer.truncate(len);
+            }
+            chars
+        }
+        Value::String(text) => {
+            let chars = json_chars(value);
+            if text.chars().count() > MIN_ELIDED_STRING_CHARS {
+                out.push(Candidate {
+                    pointer: pointer.clone(),
+                    chars,
+                });
+            }
+            chars
+        }
+        _ => json_chars(value),
+    }
+}
+
+/// Length of the compact JSON serialization of `value` in characters
+fn json_chars(value: &Value) -> usize {
+    serde_json::to_string(value)
+        .map(|json| json.chars().count())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_within_budget_is_untouched() {
+        let mut value = json!({"key": "PROJ-1", "comments": ["a", "b"]});
+        let before = value.clone();
+        assert!(fit_to_budget(&mut value, 10_000).is_none());
+        assert!(fit_to_budget(&mut value, 0).is_none());
+        assert_eq!(value, before);
+    }
+
+    #[test]
+    fn test_drops_list_tail() {
+        let comments: Vec<Value> = (0..200)
+            .map(|i| json!({"id": i, "body": format!("comment number {}", i)}))
+            .collect();
+        let mut value = json!({"key": "PROJ-1", "comments": comments});
+
+        let truncation = fit_to_budget(&mut value, 2_000).unwrap();
+        assert!(json_chars(&value) <= 2_000);
+        assert_eq!(truncation.paths, vec!["/comments"]);
+        assert_eq!(truncation.elided_chars, 0);
+
+        let kept = value["comments"].as_array().unwrap();
+        assert_eq!(kept.len() + truncation.dropped_items, 200);
+        assert_eq!(kept[0]["id"], 0);
+        assert_eq!(
+            value["truncated"]["dropped_items"],
+            truncation.dropped_items
+        );
+    }
+
+    #[test]
+    fn test_elides_long_string_in_the_middle() {
+        let description = format!("START{}END", "x".repeat(50_000));
+        let mut value = json!({"key": "PROJ-1", "description": description});
+
+        let truncation = fit_to_budget(&mut value, 1_000).unwrap();
+        assert!(json_chars(&value) <= 1_000);
+        assert_eq!(truncation.paths, vec!["/description"]);
+
+        let description = value["description"].as_str().unwrap();
+        assert!(description.starts_with("START"));
+        assert!(description.ends_with("END"));
+        assert!(description.contains("chars elided"));
+    }
+
+    #[test]
+    fn test_is_deterministic_and_valid_json() {
+        let build = || {
+            json!({
+                "issues": (0..50).map(|i| json!({
+                    "key": format!("PROJ-{}", i),
+                    "description": "ü".repeat(1_000),
+                })).collect::<Vec<_>>(),
+                "notes": "é".repeat(5_000),
+            })
+        };
+
+        let mut first = build();
+        let mut second = build();
+        fit_to_budget(&mut first, 3_000);
+        fit_to_budget(&mut second, 3_000);
+        assert_eq!(first, second);
+
+        let json = serde_json::to_string(&first).unwrap();
+        assert!(json.chars().count() <= 3_000);
+        assert!(serde_json::from_str::<Value>(&json).is_ok());
+    }
+}
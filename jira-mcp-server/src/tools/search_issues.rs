@@ -7,27 +7,73 @@ use crate::cache::MetadataCache;
 use crate::config::JiraConfig;
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::{JiraClient, SearchResult};
-use crate::semantic_mapping::{QueryComplexity, SemanticMapper};
+use crate::jql;
+use crate::metrics::{Measured, ToolPerformance};
+use crate::semantic_mapping::{
+    JqlClause, JqlQuery, OrderBy, QueryComplexity, RecentFilter, SemanticMapper, TextField,
+};
+use crate::tools::assign_issue::{AssignIssueTool, ResolvedUser};
+use crate::tools::grouping::{group_issues, GroupBy, GroupedIssues};
+use crate::tools::issue_key::normalize_issue_key;
+use crate::tools::priorities::PrioritiesTool;
+use crate::tools::render::{issue_table, OutputFormat};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::future::Future;
 use std::sync::Arc;
 use tracing::{info, instrument, warn};
 
+/// Number of characters kept on either side of a text match in `match_snippet`
+const SNIPPET_CONTEXT_CHARS: usize = 120;
+
+/// text_search_fallback when search_in had to be dropped
+const TEXT_SEARCH_UNSUPPORTED: &str =
+    "This JIRA instance rejects text queries on single fields, so all text fields were searched";
+
+/// recent_fallback when issueHistory() is unavailable
+const RECENT_UNSUPPORTED: &str = "This JIRA instance has no issueHistory() function, so issues \
+     you created, reported or are assigned were returned instead, most recently updated first";
+
 /// Parameters for the search_issues tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct SearchIssuesParams {
     /// Natural language search text (optional)
+    /// Matching issues get a `match_snippet` and `match_field` showing where the text was found
     pub query_text: Option<String>,
 
+    /// Fields query_text is searched in (optional, default: all text fields incl. comments)
+    /// With search_in the text is matched literally, several words as a phrase
+    /// Examples: ["summary"], ["summary", "description"], ["comments"]
+    #[serde(default)]
+    pub search_in: Option<Vec<TextField>>,
+
     /// Semantic issue types (optional)
     /// Examples: ["story", "bug", "feature", "task", "capability"]
     pub issue_types: Option<Vec<String>>,
 
     /// User assignment filter (optional)
-    /// Examples: "me", "current_user", username, or "unassigned"
+    /// "me"/"current_user", "unassigned", an account ID (username on Server/Data Center),
+    /// or an email address or display name resolved through a user search
+    /// Examples: "me", "unassigned", "jane@example.com", "Jane Doe"
     pub assigned_to: Option<String>,
 
+    /// Reporter filter (optional), same values as assigned_to
+    /// ("unassigned" matches issues without a reporter)
+    #[serde(default)]
+    pub reporter: Option<String>,
+
+    /// Creator filter (optional), same values as assigned_to
+    /// Examples: "me" (issues I created), "jane@example.com"
+    #[serde(default)]
+    pub created_by: Option<String>,
+
+    /// Only issues the current user viewed recently, most recently viewed first (optional)
+    /// Uses issueHistory(); where that is unavailable, issues the current user created,
+    /// reported or is assigned are returned instead and `recent_fallback` says so
+    #[serde(default)]
+    pub recent: bool,
+
     /// Project key for project-scoped search (optional)
     pub project_key: Option<String>,
 
@@ -35,13 +81,15 @@ pub struct SearchIssuesParams {
     /// Note: This will be resolved to project_key if board info is cached
     pub board_name: Option<String>,
 
-    /// Semantic status categories (optional)
-    /// Examples: ["open", "in_progress", "done", "blocked"]
+    /// Status filter (optional)
+    /// Categories "open", "in_progress" and "done" match every status in that category;
+    /// other values name statuses (e.g. "Ready for QA") or configured aliases ("blocked")
+    /// Examples: ["open", "in_progress"], ["done"], ["Ready for QA"]
     #[serde(alias = "status_filter")]
     pub status: Option<Vec<String>>,
 
     /// Created after date filter (optional)
-    /// Examples: "2024-01-01", "7 days ago", "2 weeks ago"
+    /// Examples: "2024-01-01", "7 days ago", "2 weeks ago", "yesterday", "start of month"
     pub created_after: Option<String>,
 
     /// Label filters (optional)
@@ -59,11 +107,76 @@ pub struct SearchIssuesParams {
     /// Examples: "none" (not in epic), "any" (in an epic), "PROJ-456" (specific epic key)
     pub epic_filter: Option<String>,
 
+    /// Limit the search to one epic, sprint or parent issue (optional, set one of them)
+    /// Epics match through parent or Epic Link, whichever the epic's project uses
+    /// Examples: {"epic": "PROJ-456"}, {"sprint": 42}, {"sprint": "Sprint 7"}, {"parent": "PROJ-123"}
+    #[serde(default)]
+    pub within: Option<Within>,
+
+    /// Priority filter (optional), case-insensitive
+    /// Examples: ["High", "Highest"], ["critical"], ["low", "trivial"]
+    /// Aliases map to the instance's priority scheme (see list_priorities)
+    #[serde(default)]
+    pub priority_filter: Option<Vec<String>>,
+
+    /// Result ordering (optional, default: most recently updated first)
+    /// Examples: {"field": "created", "direction": "asc"}, {"field": "priority"}, {"field": "lastViewed"}
+    /// Ordering by "rank" requires board_name
+    pub order_by: Option<OrderBy>,
+
+    /// Group the returned page by a field (optional)
+    /// Examples: "status", "assignee", "issue_type", "priority", "project"
+    pub group_by: Option<GroupBy>,
+
+    /// Output format (optional, default: "json")
+    /// "markdown" adds `rendered`, a key | summary | status | assignee table of the page
+    #[serde(default)]
+    pub format: OutputFormat,
+
     /// Maximum results to return (optional, default: 50, max: 200)
     pub limit: Option<u32>,
 
     /// Starting offset for pagination (optional, default: 0)
     pub start_at: Option<u32>,
+
+    /// Maximum characters of the serialized result, overriding max_response_chars (optional, min: 1000)
+    /// Trailing list items are dropped and long strings shortened; `truncated` says what
+    pub response_budget: Option<usize>,
+}
+
+/// The epic, sprint or parent issue a search is limited to
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Within {
+    /// Epic key: the issues in the epic
+    pub epic: Option<String>,
+
+    /// Sprint ID or name: the issues in the sprint
+    pub sprint: Option<SprintRef>,
+
+    /// Parent issue key: its subtasks or child issues
+    pub parent: Option<String>,
+}
+
+/// A sprint given by ID or by name
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum SprintRef {
+    Id(u64),
+    Name(String),
+}
+
+/// What `within` was matched through
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedWithin {
+    /// "epic", "sprint" or "parent"
+    pub scope: String,
+
+    /// The epic or parent key, or the sprint as given
+    pub value: String,
+
+    /// "parent", "epic_link" or "sprint"
+    pub mechanism: String,
 }
 
 /// Result from the search_issues tool
@@ -72,12 +185,48 @@ pub struct SearchIssuesResult {
     /// The search results
     pub search_result: SearchResult,
 
+    /// Results grouped by the requested field (only when group_by is set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<GroupedIssues>,
+
+    /// Markdown table of the page (only with format "markdown")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rendered: Option<String>,
+
     /// The JQL query that was executed
     pub jql_query: String,
 
+    /// Each input and the JQL it became, plus inputs that were ignored
+    pub explanation: Vec<String>,
+
     /// Query complexity indicator
     pub query_complexity: String,
 
+    /// Set when search_in could not be used and all text fields were searched
+    /// instead, saying why
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_search_fallback: Option<String>,
+
+    /// Set when recent could not use issueHistory(), saying what was searched instead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recent_fallback: Option<String>,
+
+    /// What assigned_to resolved to (omitted when not given)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub assigned_to: Option<UserFilter>,
+
+    /// What reporter resolved to (omitted when not given)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reporter: Option<UserFilter>,
+
+    /// What created_by resolved to (omitted when not given)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<UserFilter>,
+
+    /// How `within` was matched (omitted when not given)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub within: Option<AppliedWithin>,
+
     /// Performance information
     pub performance: SearchPerformance,
 }
@@ -97,28 +246,55 @@ impl std::fmt::Display for SearchIssuesResult {
     }
 }
 
+/// A user filter (assigned_to, reporter, created_by) as it went into the JQL
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum UserFilter {
+    /// Issues with nobody in the field
+    Unassigned,
+
+    /// Issues of one user
+    User(ResolvedUser),
+}
+
+impl UserFilter {
+    /// The user reference handed to the semantic mapper
+    fn jql_value(&self) -> &str {
+        match self {
+            UserFilter::Unassigned => "unassigned",
+            UserFilter::User(user) => &user.account_id,
+        }
+    }
+}
+
 /// Performance metrics for search operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchPerformance {
-    /// Time taken for the search in milliseconds
-    pub duration_ms: u64,
+    /// Timing and request counts shared by all tools
+    #[serde(flatten)]
+    pub call: ToolPerformance,
 
     /// Whether the query hit the cache
     pub cache_hit: bool,
 
-    /// Number of JIRA API calls made
-    pub api_calls: u32,
-
     /// Estimated result count (if available)
     pub estimated_total: Option<usize>,
 }
 
+impl Measured for SearchIssuesResult {
+    fn set_performance(&mut self, performance: ToolPerformance) {
+        self.performance.call = performance;
+    }
+}
+
 /// Implementation of the search_issues tool
 pub struct SearchIssuesTool {
     jira_client: Arc<JiraClient>,
     semantic_mapper: Arc<SemanticMapper>,
     config: Arc<JiraConfig>,
     cache: Arc<MetadataCache>,
+    priorities_tool: Arc<PrioritiesTool>,
+    assign_issue_tool: Arc<AssignIssueTool>,
 }
 
 impl SearchIssuesTool {
@@ -127,6 +303,8 @@ impl SearchIssuesTool {
         jira_client: Arc<JiraClient>,
         config: Arc<JiraConfig>,
         cache: Arc<MetadataCache>,
+        priorities_tool: Arc<PrioritiesTool>,
+        assign_issue_tool: Arc<AssignIssueTool>,
     ) -> Self {
         let semantic_mapper =
             Arc::new(SemanticMapper::new(Arc::clone(&config), Arc::clone(&cache)));
@@ -136,6 +314,8 @@ impl SearchIssuesTool {
             semantic_mapper,
             config,
             cache,
+            priorities_tool,
+            assign_issue_tool,
         }
     }
 
@@ -144,12 +324,14 @@ impl SearchIssuesTool {
         query_text = params.query_text.as_deref(),
         issue_types = ?params.issue_types,
         assigned_to = params.assigned_to.as_deref(),
+        reporter = params.reporter.as_deref(),
+        created_by = params.created_by.as_deref(),
+        recent = params.recent,
         project_key = params.project_key.as_deref(),
         board_name = params.board_name.as_deref(),
     ))]
     pub async fn execute(&self, params: SearchIssuesParams) -> JiraMcpResult<SearchIssuesResult> {
         let start_time = std::time::Instant::now();
-        let mut api_calls = 0u32;
         let mut cache_hit = false;
 
         info!("Executing search_issues tool with parameters");
@@ -158,6 +340,7 @@ impl SearchIssuesTool {
         self.validate_params(&params)?;
 
         // Resolve board name to project key if specified
+        let mut board_ignored = None;
         let resolved_project_key = if let Some(board_name) = &params.board_name {
             if let Some(board_id) = self.cache.get_board_id(board_name) {
                 if let Some(board_info) = self.cache.get_board_info(&board_id) {
@@ -170,6 +353,7 @@ impl SearchIssuesTool {
                         "Board ID cached but board info missing for board: {}",
                         board_name
                     );
+                    board_ignored = Some("board details not cached");
                     params.project_key.clone()
                 }
             } else {
@@ -179,6 +363,7 @@ impl SearchIssuesTool {
                     "Board '{}' not found in cache, ignoring board filter",
                     board_name
                 );
+                board_ignored = Some("board not found in cache");
                 params.project_key.clone()
             }
         } else {
@@ -207,19 +392,103 @@ impl SearchIssuesTool {
             .as_ref()
             .filter(|arr| !arr.is_empty())
             .map(|arr| arr.as_slice());
+        let priorities = params
+            .priority_filter
+            .as_ref()
+            .filter(|arr| !arr.is_empty())
+            .map(|arr| arr.as_slice());
 
-        let jql_result = self.semantic_mapper.build_search_jql_with_components(
-            params.query_text.as_deref(),
-            issue_types,
-            params.assigned_to.as_deref(),
-            resolved_project_key.as_deref(),
-            status,
-            params.created_after.as_deref(),
-            labels,
-            components,
-            params.parent_filter.as_deref(),
-            params.epic_filter.as_deref(),
-        )?;
+        // Load the project's issue types so semantic types resolve to its own names
+        if issue_types.is_some() {
+            self.semantic_mapper
+                .load_issue_types(&self.jira_client, resolved_project_key.as_deref())
+                .await;
+        }
+
+        // Load the status catalog so custom statuses can be matched by name
+        if status.is_some() {
+            self.semantic_mapper
+                .load_status_catalog(&self.jira_client, resolved_project_key.as_deref())
+                .await;
+        }
+
+        // Load the priority list so the mapper can validate names against it
+        if priorities.is_some() {
+            if let Err(e) = self.priorities_tool.known_priorities(false).await {
+                warn!(
+                    "Could not load priorities, filtering by name as given: {}",
+                    e
+                );
+            }
+        }
+
+        let assigned_to = match params.assigned_to.as_deref() {
+            Some(input) => Some(self.resolve_user_filter(input, "assigned_to").await?),
+            None => None,
+        };
+        let reporter = match params.reporter.as_deref() {
+            Some(input) => Some(self.resolve_user_filter(input, "reporter").await?),
+            None => None,
+        };
+        let created_by = match params.created_by.as_deref() {
+            Some(input) => Some(self.resolve_user_filter(input, "created_by").await?),
+            None => None,
+        };
+        let (within_clause, within) = match &params.within {
+            Some(within) => {
+                let (clause, applied) = self.resolve_within(within).await?;
+                (Some(clause), Some(applied))
+            }
+            None => (None, None),
+        };
+
+        let build_jql = |options: QueryOptions| {
+            self.semantic_mapper
+                .build_search_jql_with_components(
+                    params.query_text.as_deref(),
+                    options.search_in,
+                    issue_types,
+                    assigned_to.as_ref().map(UserFilter::jql_value),
+                    reporter.as_ref().map(UserFilter::jql_value),
+                    created_by.as_ref().map(UserFilter::jql_value),
+                    resolved_project_key.as_deref(),
+                    status,
+                    params.created_after.as_deref(),
+                    labels,
+                    components,
+                    params.parent_filter.as_deref(),
+                    params.epic_filter.as_deref(),
+                    priorities,
+                    options.recent,
+                )
+                .map(|jql_result| match &within_clause {
+                    Some(clause) => jql_result.and(clause.clone()),
+                    None => jql_result,
+                })
+                .map(|jql_result| match &params.order_by {
+                    Some(order_by) => jql_result.with_order_by(order_by),
+                    None => jql_result,
+                })
+        };
+
+        // Limit the text query to the requested fields, unless the instance is
+        // known to reject that
+        let mut text_search_fallback = None;
+        let search_in = params
+            .search_in
+            .as_deref()
+            .filter(|fields| !fields.is_empty() && params.query_text.is_some());
+        let targeted = match search_in {
+            Some(_) if self.jira_client.targeted_text_search() == Some(false) => {
+                text_search_fallback = Some(TEXT_SEARCH_UNSUPPORTED.to_string());
+                None
+            }
+            search_in => search_in,
+        };
+        let options = QueryOptions {
+            search_in: targeted,
+            recent: params.recent.then_some(RecentFilter::History),
+        };
 
         // Apply pagination
         let limit = params
@@ -229,50 +498,238 @@ impl SearchIssuesTool {
         let start_at = params.start_at.unwrap_or(0) as usize;
 
         // Execute search
-        let search_result = self
-            .jira_client
-            .search_issues_jql(
-                &jql_result.jql,
-                Some(start_at),
-                Some(limit),
-                None, // No expand for basic search
-            )
-            .await?;
+        let search = |jql: String| async move {
+            self.jira_client
+                .search_issues_jql(
+                    &jql,
+                    Some(start_at),
+                    Some(limit),
+                    None, // No expand for basic search
+                )
+                .await
+        };
+        let searched = search_with_fallbacks(options, build_jql, search).await?;
+
+        // Only a query that worked after dropping a feature says the instance
+        // lacks it; a plain failure may be about anything else in the query
+        let mut recent_fallback = None;
+        for fallback in &searched.fallbacks {
+            match fallback {
+                Fallback::TextSearch(error) => {
+                    self.jira_client.set_targeted_text_search(false);
+                    text_search_fallback = Some(format!("{} ({})", TEXT_SEARCH_UNSUPPORTED, error));
+                }
+                Fallback::Recent(error) => {
+                    recent_fallback = Some(format!("{} ({})", RECENT_UNSUPPORTED, error));
+                }
+            }
+        }
+        if searched.options.search_in.is_some() {
+            self.jira_client.set_targeted_text_search(true);
+        }
+
+        let mut jql_result = searched.query;
+        let mut search_result = searched.result;
+
+        // Make inputs that did not reach the query visible
+        jql_result.ignore_empty_lists(&[
+            ("issue_types", params.issue_types.as_ref()),
+            ("status", params.status.as_ref()),
+            ("labels", params.labels.as_ref()),
+            ("components", params.components.as_ref()),
+            ("priority_filter", params.priority_filter.as_ref()),
+        ]);
+        if let Some(reason) = board_ignored {
+            jql_result.ignore("board_name", reason);
+        }
+        if search_in.is_some() && searched.options.search_in.is_none() {
+            jql_result.ignore("search_in", "unsupported by this JIRA instance");
+        } else if params.query_text.is_none()
+            && params
+                .search_in
+                .as_ref()
+                .is_some_and(|fields| !fields.is_empty())
+        {
+            jql_result.ignore("search_in", "no query_text to search for");
+        }
+
+        // Show the agent where the text query matched without needing full descriptions
+        if let Some(query_text) = params.query_text.as_deref().map(str::trim) {
+            if !query_text.is_empty() {
+                annotate_match_snippets(&mut search_result, query_text);
+            }
+        }
 
-        api_calls += 1;
         let duration = start_time.elapsed();
 
         // Log performance information
         info!(
-            "Search completed in {}ms, found {} issues (total: {})",
+            "Search completed in {}ms, found {} issues (total: {:?})",
             duration.as_millis(),
             search_result.issues.len(),
             search_result.total
         );
 
         // Check if we should warn about large result sets
-        if search_result.total > 1000 {
+        if let Some(total) = search_result.total.filter(|total| *total > 1000) {
             warn!(
                 "Large result set ({} total issues). Consider adding more specific filters.",
-                search_result.total
+                total
             );
         }
 
         let total = search_result.total;
 
+        let groups = params
+            .group_by
+            .map(|group_by| group_issues(&search_result, group_by));
+        let rendered = params.format.render(|| issue_table(&search_result));
+
         Ok(SearchIssuesResult {
             search_result,
+            groups,
+            rendered,
+            explanation: jql_result.explanation(),
             jql_query: jql_result.jql,
             query_complexity: self.complexity_to_string(&jql_result.complexity),
+            text_search_fallback,
+            recent_fallback,
+            assigned_to,
+            reporter,
+            created_by,
+            within,
             performance: SearchPerformance {
-                duration_ms: duration.as_millis() as u64,
+                call: ToolPerformance::default(),
                 cache_hit,
-                api_calls,
-                estimated_total: Some(total),
+                estimated_total: total,
             },
         })
     }
 
+    /// Resolve an assigned_to, reporter or created_by value
+    ///
+    /// "me" goes through the authenticated user's account ID, names and emails
+    /// through a user search (see [`AssignIssueTool::resolve_user`]).
+    async fn resolve_user_filter(&self, input: &str, parameter: &str) -> JiraMcpResult<UserFilter> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Err(JiraMcpError::invalid_param(
+                parameter,
+                "User filter cannot be empty; use \"unassigned\" for issues without a user",
+            ));
+        }
+
+        let lookup = match input.to_lowercase().as_str() {
+            "unassigned" => return Ok(UserFilter::Unassigned),
+            "me" | "current_user" | "currentuser" => "me",
+            _ => input,
+        };
+        let mut user = self
+            .assign_issue_tool
+            .resolve_user(lookup, parameter)
+            .await?;
+        user.input = input.to_string();
+        Ok(UserFilter::User(user))
+    }
+
+    /// Translate `within` into its JQL clause
+    async fn resolve_within(&self, within: &Within) -> JiraMcpResult<(JqlClause, AppliedWithin)> {
+        let issue_key = |input: &str, parameter: &str| {
+            normalize_issue_key(&self.config.jira_url, input, parameter)
+        };
+
+        match (&within.epic, &within.sprint, &within.parent) {
+            (Some(epic), None, None) => {
+                let key = issue_key(epic, "within.epic")?;
+                let team_managed = self.is_team_managed(project_of(&key)).await;
+                let epic_link_field = self.jira_client.field_map().epic_link.is_present();
+                Ok(epic_clause(&key, team_managed, epic_link_field))
+            }
+            (None, Some(sprint), None) => {
+                let (value, jql) = match sprint {
+                    SprintRef::Id(id) => (id.to_string(), format!("{} = {}", jql::SPRINT, id)),
+                    SprintRef::Name(name) => {
+                        let name = name.trim();
+                        if name.is_empty() {
+                            return Err(JiraMcpError::invalid_param(
+                                "within.sprint",
+                                "Sprint must be an ID or a name",
+                            ));
+                        }
+                        let jql = match name.parse::<u64>() {
+                            Ok(id) => format!("{} = {}", jql::SPRINT, id),
+                            Err(_) => format!("{} = {}", jql::SPRINT, jql::quote(name)),
+                        };
+                        (name.to_string(), jql)
+                    }
+                };
+                Ok((
+                    JqlClause::new("within.sprint", jql),
+                    AppliedWithin {
+                        scope: "sprint".to_string(),
+                        value,
+                        mechanism: "sprint".to_string(),
+                    },
+                ))
+            }
+            (None, None, Some(parent)) => {
+                let key = issue_key(parent, "within.parent")?;
+                Ok((
+                    JqlClause::new(
+                        "within.parent",
+                        format!("{} = {}", jql::PARENT, jql::quote(&key)),
+                    ),
+                    AppliedWithin {
+                        scope: "parent".to_string(),
+                        value: key,
+                        mechanism: "parent".to_string(),
+                    },
+                ))
+            }
+            (None, None, None) => Err(JiraMcpError::invalid_param(
+                "within",
+                "Set one of epic, sprint or parent",
+            )),
+            _ => Err(JiraMcpError::invalid_param(
+                "within",
+                "Set only one of epic, sprint or parent; to combine them, search within one \
+                 and filter with parent_filter or epic_filter",
+            )),
+        }
+    }
+
+    /// Whether `project_key` is a team-managed project, from the project cache
+    ///
+    /// Loads and caches the project list on a miss. Projects that cannot be
+    /// looked up count as company-managed.
+    async fn is_team_managed(&self, project_key: &str) -> bool {
+        if let Some(project) = self.cache.get_project_info(project_key) {
+            return project.team_managed;
+        }
+
+        match self.jira_client.get_projects().await {
+            Ok(projects) => {
+                let mut team_managed = false;
+                for project in projects {
+                    if project.key == project_key {
+                        team_managed = project.team_managed;
+                    }
+                    if let Err(e) = self.cache.set_project_info(project.key.clone(), project) {
+                        warn!("Failed to cache project info: {}", e);
+                    }
+                }
+                team_managed
+            }
+            Err(e) => {
+                warn!(
+                    "Could not look up project {}, treating it as company-managed: {}",
+                    project_key, e
+                );
+                false
+            }
+        }
+    }
+
     /// Validate search parameters
     fn validate_params(&self, params: &SearchIssuesParams) -> JiraMcpResult<()> {
         // Check limit
@@ -377,17 +834,40 @@ impl SearchIssuesTool {
             }
         }
 
+        if let Some(order_by) = &params.order_by {
+            order_by.validate(params.board_name.is_some())?;
+        }
+
+        if let Some(within) = &params.within {
+            if within.epic.is_some() && params.epic_filter.is_some() {
+                return Err(JiraMcpError::invalid_param(
+                    "within",
+                    "within.epic and epic_filter both select an epic; use one of them",
+                ));
+            }
+            if within.parent.is_some() && params.parent_filter.is_some() {
+                return Err(JiraMcpError::invalid_param(
+                    "within",
+                    "within.parent and parent_filter both select a parent; use one of them",
+                ));
+            }
+        }
+
         // Validate that at least one search criterion is provided
         // (unless it's a general "list all" query)
         let has_criteria = params.query_text.is_some()
             || params.issue_types.is_some()
             || params.assigned_to.is_some()
+            || params.reporter.is_some()
+            || params.created_by.is_some()
+            || params.recent
             || params.project_key.is_some()
             || params.board_name.is_some()
             || params.status.is_some()
             || params.created_after.is_some()
             || params.labels.is_some()
-            || params.components.is_some();
+            || params.components.is_some()
+            || params.within.is_some();
 
         if !has_criteria {
             warn!("No search criteria provided, will return recent issues");
@@ -406,19 +886,224 @@ impl SearchIssuesTool {
     }
 }
 
+/// Project key of an issue key
+fn project_of(issue_key: &str) -> &str {
+    issue_key.split('-').next().unwrap_or(issue_key)
+}
+
+/// JQL clause matching the issues in an epic
+///
+/// Team-managed projects, and instances without an Epic Link field, link
+/// issues to epics through `parent`; company-managed projects through Epic Link.
+fn epic_clause(
+    epic_key: &str,
+    team_managed: bool,
+    epic_link_field: bool,
+) -> (JqlClause, AppliedWithin) {
+    let (field, mechanism) = if team_managed || !epic_link_field {
+        (jql::PARENT, "parent")
+    } else {
+        (jql::EPIC_LINK, "epic_link")
+    };
+    (
+        JqlClause::new(
+            "within.epic",
+            format!("{} = {}", field, jql::quote(epic_key)),
+        ),
+        AppliedWithin {
+            scope: "epic".to_string(),
+            value: epic_key.to_string(),
+            mechanism: mechanism.to_string(),
+        },
+    )
+}
+
+/// Whether JIRA refused the query itself (400) rather than failing to run it
+fn is_rejected_query(error: &JiraMcpError) -> bool {
+    let message = error.to_string();
+    message.contains("400") || message.contains("Bad Request")
+}
+
+/// The parts of a search query that not every JIRA instance accepts
+#[derive(Debug, Clone, Copy)]
+struct QueryOptions<'a> {
+    /// Fields the text query is limited to
+    search_in: Option<&'a [TextField]>,
+
+    /// How the `recent` shortcut is expressed
+    recent: Option<RecentFilter>,
+}
+
+impl QueryOptions<'_> {
+    /// The options to retry with after `error`, giving up one feature the
+    /// error points at, or `None` when there is nothing left to give up
+    fn degrade(self, error: &JiraMcpError) -> Option<(Self, Fallback)> {
+        let message = error.to_string();
+        if self.recent == Some(RecentFilter::History)
+            && message.to_lowercase().contains("issuehistory")
+        {
+            let options = Self {
+                recent: Some(RecentFilter::Involved),
+                ..self
+            };
+            return Some((options, Fallback::Recent(message)));
+        }
+        if self.search_in.is_some() && is_rejected_query(error) {
+            let options = Self {
+                search_in: None,
+                ..self
+            };
+            return Some((options, Fallback::TextSearch(message)));
+        }
+        None
+    }
+}
+
+/// A feature dropped from the query, with the error that caused it
+#[derive(Debug, Clone, PartialEq)]
+enum Fallback {
+    TextSearch(String),
+    Recent(String),
+}
+
+/// Outcome of [`search_with_fallbacks`]
+struct FallbackSearch<'a> {
+    result: SearchResult,
+    query: JqlQuery,
+    /// The options the successful query was built with
+    options: QueryOptions<'a>,
+    fallbacks: Vec<Fallback>,
+}
+
+/// Run the query built from `options`, rebuilding it without whatever JIRA
+/// rejects and retrying until it runs or fails for another reason
+async fn search_with_fallbacks<'a, B, S, F>(
+    mut options: QueryOptions<'a>,
+    build: B,
+    search: S,
+) -> JiraMcpResult<FallbackSearch<'a>>
+where
+    B: Fn(QueryOptions<'a>) -> JiraMcpResult<JqlQuery>,
+    S: Fn(String) -> F,
+    F: Future<Output = JiraMcpResult<SearchResult>>,
+{
+    let mut fallbacks = Vec::new();
+    loop {
+        let query = build(options)?;
+        match search(query.jql.clone()).await {
+            Ok(result) => {
+                return Ok(FallbackSearch {
+                    result,
+                    query,
+                    options,
+                    fallbacks,
+                })
+            }
+            Err(e) => match options.degrade(&e) {
+                Some((degraded, fallback)) => {
+                    warn!("Search rejected, retrying with a simpler query: {}", e);
+                    fallbacks.push(fallback);
+                    options = degraded;
+                }
+                None => return Err(e),
+            },
+        }
+    }
+}
+
+/// Fill in `match_snippet`/`match_field` for every issue the text query matches
+///
+/// The summary is checked before the description and the description before the
+/// comments. The whole query is tried first, then its individual terms, mirroring
+/// how JIRA's `text ~` search matches.
+fn annotate_match_snippets(search_result: &mut SearchResult, query_text: &str) {
+    let mut needles = vec![query_text];
+    needles.extend(
+        query_text
+            .split_whitespace()
+            .filter(|term| *term != query_text),
+    );
+
+    for issue in &mut search_result.issues {
+        let candidates: Vec<(&str, &str)> = std::iter::once(("summary", issue.summary.as_str()))
+            .chain(
+                issue
+                    .description
+                    .as_deref()
+                    .map(|text| ("description", text)),
+            )
+            .chain(issue.comments.iter().map(|text| ("comment", text.as_str())))
+            .collect();
+
+        let found = needles.iter().find_map(|needle| {
+            candidates.iter().find_map(|(field, text)| {
+                build_match_snippet(text, needle).map(|snippet| (*field, snippet))
+            })
+        });
+
+        if let Some((field, snippet)) = found {
+            issue.match_field = Some(field.to_string());
+            issue.match_snippet = Some(snippet);
+        }
+    }
+}
+
+/// Build a snippet of up to `SNIPPET_CONTEXT_CHARS` characters either side of the
+/// first case-insensitive occurrence of `needle` in `text`
+///
+/// Works on chars rather than bytes so multi-byte text is never split mid-character.
+/// Returns `None` when `needle` is empty or does not occur in `text`.
+fn build_match_snippet(text: &str, needle: &str) -> Option<String> {
+    fn fold(c: char) -> char {
+        c.to_lowercase().next().unwrap_or(c)
+    }
+
+    let haystack: Vec<char> = text.chars().collect();
+    let needle: Vec<char> = needle.chars().map(fold).collect();
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return None;
+    }
+
+    let position = (0..=haystack.len() - needle.len()).find(|&start| {
+        haystack[start..start + needle.len()]
+            .iter()
+            .zip(&needle)
+            .all(|(h, n)| fold(*h) == *n)
+    })?;
+
+    let from = position.saturating_sub(SNIPPET_CONTEXT_CHARS);
+    let to = (position + needle.len() + SNIPPET_CONTEXT_CHARS).min(haystack.len());
+
+    let mut snippet = String::new();
+    if from > 0 {
+        snippet.push_str("...");
+    }
+    snippet.extend(&haystack[from..to]);
+    if to < haystack.len() {
+        snippet.push_str("...");
+    }
+
+    Some(snippet)
+}
+
 #[cfg(test)]
 mod tests {
     #[allow(unused_imports)]
     use super::*;
     #[allow(unused_imports)]
     use crate::config::JiraConfig;
+    use crate::jira_client::IssueInfo;
 
     #[allow(dead_code)]
     fn create_test_params() -> SearchIssuesParams {
         SearchIssuesParams {
             query_text: Some("test query".to_string()),
+            search_in: None,
             issue_types: Some(vec!["story".to_string(), "bug".to_string()]),
             assigned_to: Some("me".to_string()),
+            reporter: None,
+            created_by: None,
+            recent: false,
             project_key: Some("TEST".to_string()),
             board_name: None,
             status: Some(vec!["open".to_string(), "in_progress".to_string()]),
@@ -427,11 +1112,286 @@ mod tests {
             components: Some(vec!["Backend".to_string()]),
             parent_filter: None,
             epic_filter: None,
+            within: None,
+            priority_filter: None,
+            order_by: None,
+            group_by: None,
+            format: OutputFormat::Json,
             limit: Some(50),
             start_at: Some(0),
+            response_budget: None,
+        }
+    }
+
+    #[test]
+    fn test_match_snippet_case_insensitive() {
+        let snippet = build_match_snippet("Login fails on Safari", "login FAILS").unwrap();
+        assert_eq!(snippet, "Login fails on Safari");
+        assert!(build_match_snippet("Login fails", "logout").is_none());
+        assert!(build_match_snippet("Login fails", "").is_none());
+    }
+
+    #[test]
+    fn test_match_snippet_at_string_boundaries() {
+        let padding = "x".repeat(300);
+
+        let at_start = format!("needle{}", padding);
+        let snippet = build_match_snippet(&at_start, "needle").unwrap();
+        assert!(snippet.starts_with("needle"));
+        assert!(snippet.ends_with("..."));
+        assert_eq!(snippet.chars().count(), 6 + SNIPPET_CONTEXT_CHARS + 3);
+
+        let at_end = format!("{}needle", padding);
+        let snippet = build_match_snippet(&at_end, "NEEDLE").unwrap();
+        assert!(snippet.starts_with("..."));
+        assert!(snippet.ends_with("needle"));
+        assert_eq!(snippet.chars().count(), 3 + SNIPPET_CONTEXT_CHARS + 6);
+
+        assert_eq!(build_match_snippet("needle", "needle").unwrap(), "needle");
+        assert!(build_match_snippet("need", "needle").is_none());
+    }
+
+    #[test]
+    fn test_match_snippet_unicode() {
+        let snippet = build_match_snippet("Größe der Übersicht ändern", "ÜBERSICHT").unwrap();
+        assert_eq!(snippet, "Größe der Übersicht ändern");
+
+        // Multi-byte context must be cut on char boundaries, not bytes
+        let text = format!("{}Fehler{}", "ü".repeat(200), "日本".repeat(100));
+        let snippet = build_match_snippet(&text, "fehler").unwrap();
+        assert!(snippet.starts_with("..."));
+        assert!(snippet.ends_with("..."));
+        assert_eq!(
+            snippet.chars().count(),
+            3 + SNIPPET_CONTEXT_CHARS + 6 + SNIPPET_CONTEXT_CHARS + 3
+        );
+    }
+
+    #[test]
+    fn test_epic_clause_follows_project_type() {
+        let (clause, applied) = epic_clause("PROJ-7", false, true);
+        assert_eq!(clause.jql, "\"Epic Link\" = \"PROJ-7\"");
+        assert_eq!(applied.mechanism, "epic_link");
+
+        for (team_managed, epic_link_field) in [(true, true), (false, false)] {
+            let (clause, applied) = epic_clause("PROJ-7", team_managed, epic_link_field);
+            assert_eq!(clause.jql, "parent = \"PROJ-7\"");
+            assert_eq!(applied.mechanism, "parent");
+        }
+        assert_eq!(project_of("PROJ-7"), "PROJ");
+
+        let within: Within = serde_json::from_value(serde_json::json!({"sprint": 42})).unwrap();
+        assert_eq!(within.sprint, Some(SprintRef::Id(42)));
+        let within: Within =
+            serde_json::from_value(serde_json::json!({"sprint": "Sprint 7"})).unwrap();
+        assert_eq!(within.sprint, Some(SprintRef::Name("Sprint 7".to_string())));
+        assert!(serde_json::from_value::<Within>(serde_json::json!({"board": "A"})).is_err());
+    }
+
+    #[test]
+    fn test_annotate_prefers_summary_then_terms() {
+        let issue = |summary: &str, description: Option<&str>| IssueInfo {
+            key: "TEST-1".to_string(),
+            id: "1".to_string(),
+            url: String::new(),
+            summary: summary.to_string(),
+            description: description.map(str::to_string),
+            issue_type: "Bug".to_string(),
+            status: "Open".to_string(),
+            status_category: None,
+            status_color: None,
+            issue_type_icon_url: None,
+            priority: None,
+            assignee: None,
+            reporter: None,
+            created: String::new(),
+            updated: String::new(),
+            due_date: None,
+            project_key: "TEST".to_string(),
+            project_name: "Test".to_string(),
+            labels: Vec::new(),
+            components: Vec::new(),
+            story_points: None,
+            acceptance_criteria: None,
+            match_snippet: None,
+            match_field: None,
+            time_tracking: None,
+            security_level: None,
+            resolution: None,
+            comments: Vec::new(),
+        };
+
+        let mut result = SearchResult {
+            issues: vec![
+                issue("Payment timeout", Some("payment timeout in checkout")),
+                issue("Checkout", Some("The gateway hits a timeout")),
+                issue("Unrelated", None),
+                IssueInfo {
+                    comments: vec![
+                        "Looked into it".to_string(),
+                        "Seen a payment timeout again in production".to_string(),
+                    ],
+                    ..issue("Checkout errors", Some("Orders fail sometimes"))
+                },
+            ],
+            total: Some(4),
+            start_at: 0,
+            max_results: 50,
+            is_last: true,
+        };
+
+        annotate_match_snippets(&mut result, "payment timeout");
+
+        assert_eq!(result.issues[0].match_field.as_deref(), Some("summary"));
+        assert_eq!(result.issues[1].match_field.as_deref(), Some("description"));
+        assert_eq!(
+            result.issues[1].match_snippet.as_deref(),
+            Some("The gateway hits a timeout")
+        );
+        assert!(result.issues[2].match_snippet.is_none());
+
+        // A match only in a comment is still shown
+        assert_eq!(result.issues[3].match_field.as_deref(), Some("comment"));
+        assert_eq!(
+            result.issues[3].match_snippet.as_deref(),
+            Some("Seen a payment timeout again in production")
+        );
+    }
+
+    fn empty_result() -> SearchResult {
+        SearchResult {
+            issues: Vec::new(),
+            total: Some(0),
+            start_at: 0,
+            max_results: 50,
+            is_last: true,
         }
     }
 
+    /// Search the JQL built from `options`, failing every query that contains
+    /// one of `rejected`; returns the outcome and every JQL that was sent
+    async fn search_rejecting(
+        options: QueryOptions<'_>,
+        rejected: &[(&str, &str)],
+    ) -> (JiraMcpResult<Vec<Fallback>>, Vec<String>) {
+        let mapper = SemanticMapper::new(
+            Arc::new(JiraConfig::default()),
+            Arc::new(MetadataCache::new(300)),
+        );
+        let sent = std::sync::Mutex::new(Vec::new());
+
+        let outcome = search_with_fallbacks(
+            options,
+            |options: QueryOptions| {
+                mapper.build_search_jql_with_components(
+                    Some("login"),
+                    options.search_in,
+                    None,
+                    None,
+                    None,
+                    None,
+                    Some("TEST"),
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    options.recent,
+                )
+            },
+            |jql: String| {
+                sent.lock().unwrap().push(jql.clone());
+                let error = rejected
+                    .iter()
+                    .find(|(needle, _)| jql.contains(needle))
+                    .map(|(_, message)| {
+                        JiraMcpError::internal(format!("JIRA API error: {}", message))
+                    });
+                async move {
+                    match error {
+                        Some(error) => Err(error),
+                        None => Ok(empty_result()),
+                    }
+                }
+            },
+        )
+        .await;
+
+        let outcome = outcome.map(|searched| searched.fallbacks);
+        (outcome, sent.into_inner().unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_recent_falls_back_without_issue_history() {
+        let options = QueryOptions {
+            search_in: None,
+            recent: Some(RecentFilter::History),
+        };
+        let (outcome, sent) = search_rejecting(
+            options,
+            &[(
+                "issueHistory",
+                "400 Bad Request: Unable to find JQL function 'issueHistory()'.",
+            )],
+        )
+        .await;
+
+        let fallbacks = outcome.unwrap();
+        assert!(matches!(fallbacks.as_slice(), [Fallback::Recent(_)]));
+        assert_eq!(sent.len(), 2);
+        assert!(sent[0].contains("issuekey in issueHistory()"));
+        assert!(sent[0].ends_with("ORDER BY lastViewed DESC"));
+        assert!(!sent[1].contains("issueHistory"));
+        assert!(sent[1].contains("creator = currentUser()"));
+        assert!(sent[1].ends_with("ORDER BY updated DESC"));
+    }
+
+    #[tokio::test]
+    async fn test_fallbacks_apply_one_at_a_time() {
+        let fields = [TextField::Summary];
+        let options = QueryOptions {
+            search_in: Some(&fields),
+            recent: Some(RecentFilter::History),
+        };
+        let (outcome, sent) = search_rejecting(
+            options,
+            &[
+                (
+                    "issueHistory",
+                    "400 Bad Request: Unable to find JQL function 'issueHistory()'",
+                ),
+                ("summary ~", "400 Bad Request: field not searchable"),
+            ],
+        )
+        .await;
+
+        let fallbacks = outcome.unwrap();
+        assert!(matches!(
+            fallbacks.as_slice(),
+            [Fallback::Recent(_), Fallback::TextSearch(_)]
+        ));
+        assert_eq!(sent.len(), 3);
+        assert!(sent[2].contains("text ~ \"login\""));
+    }
+
+    #[tokio::test]
+    async fn test_unrelated_errors_are_not_retried() {
+        let options = QueryOptions {
+            search_in: None,
+            recent: Some(RecentFilter::History),
+        };
+        let (outcome, sent) = search_rejecting(
+            options,
+            &[("project", "400 Bad Request: project 'TEST' does not exist")],
+        )
+        .await;
+
+        assert!(outcome.is_err());
+        assert_eq!(sent.len(), 1);
+    }
+
     /*
     // All tests disabled due to unsafe std::mem::zeroed usage
     // TODO: Implement proper mocking for tests
@@ -0,0 +1,249 @@
+//! Tool for setting issue security levels
+//!
+//! Security levels restrict who can see an issue. The levels come from the
+//! project's security scheme, and JIRA only lists (and accepts) the ones the
+//! current user may set, which needs the "Set Issue Security" permission.
+
+use crate::cache::{MetadataCache, SecurityLevelInfo};
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_id_or_key;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// Parameters for the update_security_level tool
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateSecurityLevelParams {
+    /// The JIRA issue key (e.g., "PROJ-123")
+    pub issue_key: String,
+
+    /// Security level name (case-insensitive) or ID; null or empty removes the
+    /// security level
+    #[serde(default)]
+    pub security_level: Option<String>,
+}
+
+/// Result from the update_security_level tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct UpdateSecurityLevelResult {
+    /// The issue key
+    pub issue_key: String,
+
+    /// Security level before the change
+    pub previous_security_level: Option<String>,
+
+    /// Security level after the change (None when removed)
+    pub security_level: Option<String>,
+
+    /// Levels of the issue's project the current user may set
+    pub available_levels: Vec<SecurityLevelInfo>,
+
+    /// Success message
+    pub message: String,
+}
+
+/// Tool for listing and setting issue security levels
+pub struct SecurityLevelsTool {
+    jira_client: Arc<JiraClient>,
+    cache: Arc<MetadataCache>,
+}
+
+impl SecurityLevelsTool {
+    pub fn new(jira_client: Arc<JiraClient>, cache: Arc<MetadataCache>) -> Self {
+        Self { jira_client, cache }
+    }
+
+    #[instrument(skip(self), fields(issue_key = %params.issue_key))]
+    pub async fn update_security_level(
+        &self,
+        mut params: UpdateSecurityLevelParams,
+    ) -> JiraMcpResult<UpdateSecurityLevelResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        info!(
+            "Setting security level of {} to {:?}",
+            params.issue_key, params.security_level
+        );
+
+        let current = self
+            .jira_client
+            .get_issue_fields(
+                &params.issue_key,
+                &["project".to_string(), "security".to_string()],
+            )
+            .await?;
+        let project_key = current
+            .get("project")
+            .and_then(|project| project["key"].as_str())
+            .map(String::from)
+            .ok_or_else(|| {
+                JiraMcpError::internal(format!("No project on issue {}", params.issue_key))
+            })?;
+        let previous_security_level = current
+            .get("security")
+            .and_then(|security| security["name"].as_str())
+            .map(String::from);
+
+        let available_levels = self.known_security_levels(&project_key).await?;
+        let level = match params.security_level.as_deref().map(str::trim) {
+            Some("") | None => None,
+            Some(name) => Some(find_security_level(
+                &available_levels,
+                name,
+                &project_key,
+                "security_level",
+            )?),
+        };
+
+        let endpoint = format!("/issue/{}", params.issue_key);
+        let update_body = serde_json::json!({
+            "fields": {
+                "security": level.as_ref().map(|level| serde_json::json!({ "id": level.id }))
+            }
+        });
+        self.jira_client
+            .put_json::<serde_json::Value>(
+                &format!("setting the security level of {}", params.issue_key),
+                "api",
+                &endpoint,
+                update_body,
+                |e| map_security_error(e, &params.issue_key),
+            )
+            .await?;
+
+        let security_level = level.map(|level| level.name);
+        let message = match &security_level {
+            Some(name) => format!("Set security level of {} to {}", params.issue_key, name),
+            None => format!("Removed the security level of {}", params.issue_key),
+        };
+        info!("{}", message);
+
+        Ok(UpdateSecurityLevelResult {
+            issue_key: params.issue_key,
+            previous_security_level,
+            security_level,
+            available_levels,
+            message,
+        })
+    }
+
+    /// Resolve a security level name or ID in a project
+    pub async fn resolve_security_level(
+        &self,
+        project_key: &str,
+        name: &str,
+        parameter: &str,
+    ) -> JiraMcpResult<SecurityLevelInfo> {
+        let levels = self.known_security_levels(project_key).await?;
+        find_security_level(&levels, name, project_key, parameter)
+    }
+
+    /// Security levels of a project, from the cache when available
+    pub async fn known_security_levels(
+        &self,
+        project_key: &str,
+    ) -> JiraMcpResult<Vec<SecurityLevelInfo>> {
+        if let Some(levels) = self.cache.get_security_levels(project_key) {
+            return Ok(levels);
+        }
+
+        let levels = self.jira_client.get_security_levels(project_key).await?;
+        self.cache
+            .set_security_levels(project_key.to_string(), levels.clone())?;
+        Ok(levels)
+    }
+}
+
+/// Find a level by ID or case-insensitive name
+fn find_security_level(
+    levels: &[SecurityLevelInfo],
+    name: &str,
+    project_key: &str,
+    parameter: &str,
+) -> JiraMcpResult<SecurityLevelInfo> {
+    if levels.is_empty() {
+        return Err(JiraMcpError::permission(format!(
+            "No security levels can be set in project {}. Setting one needs the \"Set Issue \
+             Security\" permission and a security scheme on the project",
+            project_key
+        )));
+    }
+
+    levels
+        .iter()
+        .find(|level| level.id == name || level.name.eq_ignore_ascii_case(name))
+        .cloned()
+        .ok_or_else(|| {
+            let names = levels
+                .iter()
+                .map(|level| level.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", ");
+            JiraMcpError::invalid_param(
+                parameter,
+                format!(
+                    "Unknown security level '{}' in project {}. Available: {}",
+                    name, project_key, names
+                ),
+            )
+        })
+}
+
+/// Map errors from writing `fields.security`
+///
+/// Without "Set Issue Security" JIRA answers 403, or a 400 saying the security
+/// field cannot be set, since the field is hidden from such users.
+pub fn map_security_error(e: gouqi::Error, issue_key: &str) -> JiraMcpError {
+    let message = e.to_string();
+    if message.contains("404") || message.contains("Not Found") {
+        JiraMcpError::not_found("issue", issue_key)
+    } else if message.contains("403")
+        || message.contains("Forbidden")
+        || (message.contains("400") && message.contains("security"))
+    {
+        JiraMcpError::permission(format!(
+            "Setting the security level of {} needs the \"Set Issue Security\" project \
+             permission: {}",
+            issue_key, message
+        ))
+    } else {
+        JiraMcpError::from(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn level(id: &str, name: &str) -> SecurityLevelInfo {
+        SecurityLevelInfo {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: None,
+        }
+    }
+
+    #[test]
+    fn test_find_security_level() {
+        let levels = vec![level("10000", "Internal"), level("10001", "Security Team")];
+
+        let found = find_security_level(&levels, "security team", "PROJ", "security_level");
+        assert_eq!(found.unwrap().id, "10001");
+        let found = find_security_level(&levels, "10000", "PROJ", "security_level");
+        assert_eq!(found.unwrap().name, "Internal");
+
+        let error = find_security_level(&levels, "Public", "PROJ", "security_level")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("Internal, Security Team"), "{}", error);
+
+        let error = find_security_level(&[], "Internal", "PROJ", "security_level")
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("Set Issue Security"), "{}", error);
+    }
+}
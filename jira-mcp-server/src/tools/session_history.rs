@@ -0,0 +1,355 @@
+//! Reading back the local work history
+//!
+//! Totals the work sessions the todo tracker recorded (see
+//! `crate::work_history`) per issue, per day or per todo. Unlike
+//! get_my_timesheet this makes no JIRA calls and also knows about paused and
+//! cancelled sessions, but it only covers time tracked through this server
+//! since the history was turned on. Days follow the configured `timezone`.
+
+use crate::config::JiraConfig;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::semantic_mapping::resolve_date;
+use crate::tools::issue_key::normalize_issue_key;
+use crate::work_history::{HistoryQuery, SessionOutcome, SessionRecord, WorkHistory};
+use chrono::{DateTime, Days, FixedOffset, NaiveDate, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// Sessions listed when include_sessions is set
+const MAX_LISTED_SESSIONS: usize = 100;
+
+/// How the work history totals are grouped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkHistoryGroupBy {
+    /// One group per issue, most time first
+    #[default]
+    Issue,
+    /// One group per day, in order
+    Day,
+    /// One group per todo, most time first
+    Todo,
+}
+
+/// Parameters for the query_work_history tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct QueryWorkHistoryParams {
+    /// First day of sessions to include (optional, default: "7 days ago")
+    /// Examples: "today", "yesterday", "30 days ago", "2024-01-01"
+    pub since: Option<String>,
+
+    /// Last day of sessions to include, inclusive (optional, default: today)
+    pub until: Option<String>,
+
+    /// Only sessions on this issue (optional)
+    pub issue_key: Option<String>,
+
+    /// "issue", "day" or "todo" (default: "issue")
+    #[serde(default)]
+    pub group_by: WorkHistoryGroupBy,
+
+    /// Also list the sessions themselves, newest first, up to 100 (default: false)
+    #[serde(default)]
+    pub include_sessions: bool,
+}
+
+/// Sessions of one issue, day or todo
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WorkHistoryGroup {
+    /// Issue key, the date as YYYY-MM-DD, or "ISSUE-KEY:todo-id"
+    pub key: String,
+
+    /// Text of the todo, for groups by todo
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todo_text: Option<String>,
+
+    pub session_count: usize,
+
+    /// Time of completed and paused sessions
+    pub tracked_seconds: u64,
+
+    /// tracked_seconds in JIRA notation, e.g. "2h 30m"
+    pub tracked_time: String,
+
+    /// Time of cancelled sessions, which was not logged
+    pub discarded_seconds: u64,
+}
+
+/// Result from the query_work_history tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryWorkHistoryResult {
+    /// First and last day of the query
+    pub since: String,
+    pub until: String,
+
+    /// Offset the days are counted in, e.g. "+02:00"
+    pub timezone: String,
+
+    pub group_by: WorkHistoryGroupBy,
+
+    pub groups: Vec<WorkHistoryGroup>,
+
+    pub session_count: usize,
+
+    /// Time of all completed and paused sessions
+    pub total_tracked_seconds: u64,
+
+    /// total_tracked_seconds in JIRA notation
+    pub total_tracked_time: String,
+
+    /// Time of all cancelled sessions
+    pub total_discarded_seconds: u64,
+
+    /// The sessions, newest first, when include_sessions was set
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub sessions: Vec<SessionRecord>,
+}
+
+/// Implementation of the query_work_history tool
+pub struct QueryWorkHistoryTool {
+    work_history: Arc<WorkHistory>,
+    config: Arc<JiraConfig>,
+}
+
+impl QueryWorkHistoryTool {
+    pub fn new(work_history: Arc<WorkHistory>, config: Arc<JiraConfig>) -> Self {
+        Self {
+            work_history,
+            config,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: QueryWorkHistoryParams,
+    ) -> JiraMcpResult<QueryWorkHistoryResult> {
+        let offset = self.config.utc_offset();
+        let today = Utc::now().with_timezone(&offset).date_naive();
+        let since = resolve_date(
+            params.since.as_deref().unwrap_or("7 days ago"),
+            today,
+            "since",
+        )?;
+        let until = match params.until.as_deref() {
+            Some(until) => resolve_date(until, today, "until")?,
+            None => today,
+        };
+        if since > until {
+            return Err(JiraMcpError::invalid_param(
+                "since",
+                format!("since ({}) is after until ({})", since, until),
+            ));
+        }
+        let issue_key = params
+            .issue_key
+            .as_deref()
+            .map(|key| normalize_issue_key(&self.config.jira_url, key, "issue_key"))
+            .transpose()?;
+
+        let mut sessions = self
+            .work_history
+            .query(HistoryQuery {
+                since: day_start(since, offset),
+                until: until
+                    .checked_add_days(Days::new(1))
+                    .and_then(|day| day_start(day, offset)),
+                issue_key,
+            })
+            .await?;
+
+        let groups = group_sessions(&sessions, offset, params.group_by);
+        let tracked = |record: &&SessionRecord| record.outcome != SessionOutcome::Cancelled;
+        let total_tracked_seconds = sessions
+            .iter()
+            .filter(tracked)
+            .map(|record| record.seconds)
+            .sum();
+        let total_discarded_seconds = sessions
+            .iter()
+            .filter(|record| !tracked(record))
+            .map(|record| record.seconds)
+            .sum();
+        let session_count = sessions.len();
+
+        info!(
+            "Work history from {} to {}: {} sessions, {}s tracked",
+            since, until, session_count, total_tracked_seconds
+        );
+
+        if params.include_sessions {
+            sessions.reverse();
+            sessions.truncate(MAX_LISTED_SESSIONS);
+        } else {
+            sessions.clear();
+        }
+
+        let day = |date: NaiveDate| date.format("%Y-%m-%d").to_string();
+        Ok(QueryWorkHistoryResult {
+            since: day(since),
+            until: day(until),
+            timezone: offset.to_string(),
+            group_by: params.group_by,
+            groups,
+            session_count,
+            total_tracked_seconds,
+            total_tracked_time: jira_duration(total_tracked_seconds),
+            total_discarded_seconds,
+            sessions,
+        })
+    }
+}
+
+/// Midnight of `day` in `offset`
+fn day_start(day: NaiveDate, offset: FixedOffset) -> Option<DateTime<Utc>> {
+    day.and_hms_opt(0, 0, 0)?
+        .and_local_timezone(offset)
+        .single()
+        .map(|start| start.with_timezone(&Utc))
+}
+
+/// Total the sessions per issue or todo (most time first) or per day (in order)
+fn group_sessions(
+    sessions: &[SessionRecord],
+    offset: FixedOffset,
+    group_by: WorkHistoryGroupBy,
+) -> Vec<WorkHistoryGroup> {
+    let mut groups: BTreeMap<String, WorkHistoryGroup> = BTreeMap::new();
+    for record in sessions {
+        let key = match group_by {
+            WorkHistoryGroupBy::Issue => record.issue_key.clone(),
+            WorkHistoryGroupBy::Day => record
+                .started_at
+                .with_timezone(&offset)
+                .format("%Y-%m-%d")
+                .to_string(),
+            WorkHistoryGroupBy::Todo => format!("{}:{}", record.issue_key, record.todo_id),
+        };
+        let group = groups
+            .entry(key.clone())
+            .or_insert_with(|| WorkHistoryGroup {
+                key,
+                todo_text: (group_by == WorkHistoryGroupBy::Todo).then(|| record.todo_text.clone()),
+                session_count: 0,
+                tracked_seconds: 0,
+                tracked_time: String::new(),
+                discarded_seconds: 0,
+            });
+        group.session_count += 1;
+        match record.outcome {
+            SessionOutcome::Cancelled => group.discarded_seconds += record.seconds,
+            SessionOutcome::Completed | SessionOutcome::Paused => {
+                group.tracked_seconds += record.seconds
+            }
+        }
+    }
+
+    let mut groups: Vec<WorkHistoryGroup> = groups
+        .into_values()
+        .map(|mut group| {
+            group.tracked_time = jira_duration(group.tracked_seconds);
+            group
+        })
+        .collect();
+    if group_by != WorkHistoryGroupBy::Day {
+        groups.sort_by_key(|group| Reverse(group.tracked_seconds));
+    }
+    groups
+}
+
+/// Seconds in JIRA notation; unlike worklog input, zero stays zero
+fn jira_duration(seconds: u64) -> String {
+    if seconds == 0 {
+        "0m".to_string()
+    } else {
+        JiraClient::format_duration_jira(seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(
+        issue_key: &str,
+        started_at: &str,
+        seconds: u64,
+        outcome: SessionOutcome,
+    ) -> SessionRecord {
+        let started_at = DateTime::parse_from_rfc3339(started_at)
+            .unwrap()
+            .with_timezone(&Utc);
+        SessionRecord {
+            issue_key: issue_key.to_string(),
+            todo_id: "todo-1".to_string(),
+            todo_text: "Write tests".to_string(),
+            started_at,
+            ended_at: started_at + chrono::Duration::seconds(seconds as i64),
+            seconds,
+            worklog_id: None,
+            outcome,
+        }
+    }
+
+    #[test]
+    fn test_group_sessions() {
+        let sessions = vec![
+            session(
+                "PROJ-1",
+                "2026-03-02T23:30:00Z",
+                1800,
+                SessionOutcome::Completed,
+            ),
+            session(
+                "PROJ-2",
+                "2026-03-03T09:00:00Z",
+                7200,
+                SessionOutcome::Paused,
+            ),
+            session(
+                "PROJ-1",
+                "2026-03-03T13:00:00Z",
+                600,
+                SessionOutcome::Cancelled,
+            ),
+        ];
+        let utc = FixedOffset::east_opt(0).unwrap();
+
+        let by_issue = group_sessions(&sessions, utc, WorkHistoryGroupBy::Issue);
+        assert_eq!(by_issue[0].key, "PROJ-2");
+        assert_eq!(by_issue[0].tracked_time, "2h");
+        assert_eq!(by_issue[1].session_count, 2);
+        assert_eq!(by_issue[1].tracked_seconds, 1800);
+        assert_eq!(by_issue[1].discarded_seconds, 600);
+        assert!(by_issue[1].todo_text.is_none());
+
+        let by_todo = group_sessions(&sessions, utc, WorkHistoryGroupBy::Todo);
+        assert_eq!(by_todo[1].key, "PROJ-1:todo-1");
+        assert_eq!(by_todo[1].todo_text.as_deref(), Some("Write tests"));
+
+        let by_day = group_sessions(&sessions, utc, WorkHistoryGroupBy::Day);
+        assert_eq!(by_day[0].key, "2026-03-02");
+        assert_eq!(by_day[0].tracked_seconds, 1800);
+
+        // Two hours ahead, the late session moves onto the 3rd
+        let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+        let by_day = group_sessions(&sessions, cest, WorkHistoryGroupBy::Day);
+        assert_eq!(by_day.len(), 1);
+        assert_eq!(by_day[0].key, "2026-03-03");
+        assert_eq!(by_day[0].tracked_seconds, 9000);
+    }
+
+    #[test]
+    fn test_day_start_follows_the_offset() {
+        let day = NaiveDate::from_ymd_opt(2026, 3, 3).unwrap();
+        let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+        let start = day_start(day, cest).unwrap();
+        assert_eq!(start.to_rfc3339(), "2026-03-02T22:00:00+00:00");
+    }
+}
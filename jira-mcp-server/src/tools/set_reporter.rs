@@ -0,0 +1,153 @@
+//! Tool for changing an issue's reporter
+//!
+//! Changing the reporter needs the "Modify Reporter" project permission, and the
+//! Reporter field has to be on the issue type's edit screen. Both are common
+//! reasons for the change to fail, so each gets its own error instead of JIRA's
+//! generic 400/403.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::assign_issue::{AssignIssueTool, ResolvedUser};
+use crate::tools::issue_key::normalize_issue_id_or_key;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// Parameters for the set_reporter tool
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SetReporterParams {
+    /// The JIRA issue key (e.g., "PROJ-123")
+    pub issue_key: String,
+
+    /// The new reporter: "me", an account ID (username on Server/Data Center),
+    /// an email address or a display name
+    pub reporter: String,
+}
+
+/// Result from the set_reporter tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct SetReporterResult {
+    /// The issue key
+    pub issue_key: String,
+
+    /// Display name of the reporter before the change (None if there was none)
+    pub previous_reporter: Option<String>,
+
+    /// How the reporter parameter was resolved
+    pub reporter: ResolvedUser,
+
+    /// Success message
+    pub message: String,
+}
+
+/// Tool for changing the reporter of an issue
+pub struct SetReporterTool {
+    jira_client: Arc<JiraClient>,
+    assign_issue_tool: Arc<AssignIssueTool>,
+}
+
+impl SetReporterTool {
+    pub fn new(jira_client: Arc<JiraClient>, assign_issue_tool: Arc<AssignIssueTool>) -> Self {
+        Self {
+            jira_client,
+            assign_issue_tool,
+        }
+    }
+
+    #[instrument(skip(self), fields(issue_key = %params.issue_key))]
+    pub async fn execute(&self, mut params: SetReporterParams) -> JiraMcpResult<SetReporterResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        info!(
+            "Setting reporter of {} to {}",
+            params.issue_key, params.reporter
+        );
+
+        let reporter = params.reporter.trim();
+        if reporter.is_empty() {
+            return Err(JiraMcpError::invalid_param(
+                "reporter",
+                "Reporter cannot be empty",
+            ));
+        }
+
+        let edit_meta = self.jira_client.get_edit_meta(&params.issue_key).await?;
+        if !edit_meta.contains_key("reporter") {
+            return Err(JiraMcpError::invalid_param(
+                "reporter",
+                format!(
+                    "The Reporter field is not on the edit screen for {}'s issue type. Add it \
+                     to the screen in JIRA, or ask an administrator to change the reporter.",
+                    params.issue_key
+                ),
+            ));
+        }
+
+        let resolved = self
+            .assign_issue_tool
+            .resolve_user(reporter, "reporter")
+            .await?;
+
+        let previous = self
+            .jira_client
+            .get_issue_fields(&params.issue_key, &["reporter".to_string()])
+            .await?;
+        let previous_reporter = previous
+            .get("reporter")
+            .and_then(|reporter| reporter["displayName"].as_str())
+            .map(String::from);
+
+        let endpoint = format!("/issue/{}", params.issue_key);
+        let update_body = serde_json::json!({
+            "fields": {
+                "reporter": self.jira_client.deployment_type().user_field(&resolved.account_id)
+            }
+        });
+        self.jira_client
+            .put_json::<Value>(
+                &format!("setting the reporter of {}", params.issue_key),
+                "api",
+                &endpoint,
+                update_body,
+                |e| {
+                    let message = e.to_string();
+                    if message.contains("404") || message.contains("Not Found") {
+                        JiraMcpError::not_found("issue", &params.issue_key)
+                    } else if message.contains("403") || message.contains("Forbidden") {
+                        JiraMcpError::permission(format!(
+                            "Changing the reporter of {} needs the \"Modify Reporter\" project \
+                         permission",
+                            params.issue_key
+                        ))
+                    } else if message.contains("400") || message.contains("Bad Request") {
+                        JiraMcpError::invalid_param(
+                            "reporter",
+                            format!("JIRA rejected the reporter: {}", message),
+                        )
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        let message = format!(
+            "Changed reporter of {} from {} to {}",
+            params.issue_key,
+            previous_reporter.as_deref().unwrap_or("nobody"),
+            resolved.display_name
+        );
+        info!("{}", message);
+
+        Ok(SetReporterResult {
+            issue_key: params.issue_key,
+            previous_reporter,
+            reporter: resolved,
+            message,
+        })
+    }
+}
@@ -0,0 +1,47 @@
+//! Signatures marking comments and worklogs written through the server
+//!
+//! `comment_signature` and `worklog_signature` are appended as a last line so
+//! readers can tell agent-made entries apart. Signing is idempotent: a text that
+//! already ends with the signature (e.g. a retried body) is left as it is.
+
+/// Separator between the text and the signature
+const SEPARATOR: &str = "\n\n";
+
+/// `text` with the signature as its last line
+pub fn sign(text: &str, signature: Option<&str>) -> String {
+    match signature.map(str::trim).filter(|s| !s.is_empty()) {
+        Some(signature) if !text.trim_end().ends_with(signature) => {
+            format!("{}{}{}", text.trim_end(), SEPARATOR, signature)
+        }
+        _ => text.to_string(),
+    }
+}
+
+/// Characters signing adds at most, to keep room for it under a length limit
+pub fn signature_chars(signature: Option<&str>) -> usize {
+    signature
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map_or(0, |s| SEPARATOR.len() + s.chars().count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign() {
+        let signature = Some("— posted via JIRA MCP Server");
+        let signed = sign("Fixed in 1.2\n", signature);
+        assert_eq!(signed, "Fixed in 1.2\n\n— posted via JIRA MCP Server");
+        assert_eq!(sign(&signed, signature), signed);
+        assert_eq!(
+            signature_chars(signature),
+            signed.chars().count() - "Fixed in 1.2".len()
+        );
+
+        assert_eq!(sign("Fixed", None), "Fixed");
+        assert_eq!(sign("Fixed", Some("  ")), "Fixed");
+        assert_eq!(signature_chars(None), 0);
+    }
+}
@@ -0,0 +1,380 @@
+//! Sprint planning summary
+//!
+//! Assembles what a planning session needs for one board: the top of the ranked
+//! backlog, candidates that still need refinement (no story points or acceptance
+//! criteria), open work per assignee and the velocity of the last closed sprints.
+//! The sections are loaded concurrently and independently; a failing section is
+//! reported in `section_errors` while the others are still returned.
+
+use crate::config::JiraConfig;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::{BoardInfo, IssueInfo, JiraClient};
+use crate::jql;
+use crate::tools::grouping::{group_issues, GroupBy};
+use crate::tools::render::OutputFormat;
+use crate::tools::sprints::{
+    GetSprintIssuesParams, GetSprintIssuesTool, ListSprintsParams, ListSprintsTool,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tracing::{info, instrument, warn};
+
+/// Default and maximum number of backlog candidates
+const DEFAULT_CANDIDATE_LIMIT: u32 = 20;
+const MAX_CANDIDATE_LIMIT: u32 = 50;
+
+/// Closed sprints the velocity is computed over
+const VELOCITY_SPRINTS: usize = 3;
+
+/// Open issues looked at for the workload section
+const MAX_WORKLOAD_ISSUES: usize = 200;
+
+/// Parameters for the get_sprint_planning_summary tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetSprintPlanningSummaryParams {
+    /// Board to plan for (optional if project_key is given)
+    pub board_id: Option<u64>,
+
+    /// Project to plan for; its first board is used when board_id is omitted
+    /// (optional if board_id is given)
+    /// Examples: "PROJ"
+    pub project_key: Option<String>,
+
+    /// Backlog candidates to return (optional, default: 20, max: 50)
+    pub candidate_limit: Option<u32>,
+}
+
+/// A ranked backlog item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlanningCandidate {
+    /// Position in the backlog, starting at 1
+    pub rank: usize,
+    pub key: String,
+    pub summary: String,
+    pub issue_type: String,
+    pub status: String,
+    pub story_points: Option<f64>,
+    pub assignee: Option<String>,
+}
+
+/// A story candidate that is not ready to be planned
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefinementGap {
+    pub key: String,
+    pub summary: String,
+
+    /// What is missing: "story_points" and/or "acceptance_criteria"
+    pub missing: Vec<String>,
+}
+
+/// Open work of one assignee
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssigneeWorkload {
+    pub open_issues: usize,
+    pub story_points: f64,
+}
+
+/// Story points of a closed sprint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SprintVelocity {
+    pub sprint_id: u64,
+    pub name: String,
+
+    /// Points of all issues in the sprint
+    pub committed_points: f64,
+
+    /// Points of the sprint's issues that are done
+    pub completed_points: f64,
+}
+
+/// Result from the get_sprint_planning_summary tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetSprintPlanningSummaryResult {
+    pub board_id: u64,
+    pub board_name: String,
+    pub project_key: Option<String>,
+
+    /// Top of the backlog, in rank order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backlog: Option<Vec<PlanningCandidate>>,
+
+    /// Stories among the candidates lacking story points or acceptance criteria
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub needs_refinement: Option<Vec<RefinementGap>>,
+
+    /// Open issues and points per assignee in the project
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workload: Option<BTreeMap<String, AssigneeWorkload>>,
+
+    /// Last closed sprints, most recent first
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub velocity: Option<Vec<SprintVelocity>>,
+
+    /// Average completed points over `velocity`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_velocity: Option<f64>,
+
+    /// Sections that could not be loaded, with the error
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub section_errors: BTreeMap<String, String>,
+}
+
+/// Implementation of the get_sprint_planning_summary tool
+pub struct GetSprintPlanningSummaryTool {
+    jira_client: Arc<JiraClient>,
+    config: Arc<JiraConfig>,
+    list_sprints_tool: Arc<ListSprintsTool>,
+    sprint_issues_tool: Arc<GetSprintIssuesTool>,
+}
+
+impl GetSprintPlanningSummaryTool {
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        config: Arc<JiraConfig>,
+        list_sprints_tool: Arc<ListSprintsTool>,
+        sprint_issues_tool: Arc<GetSprintIssuesTool>,
+    ) -> Self {
+        Self {
+            jira_client,
+            config,
+            list_sprints_tool,
+            sprint_issues_tool,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: GetSprintPlanningSummaryParams,
+    ) -> JiraMcpResult<GetSprintPlanningSummaryResult> {
+        let candidate_limit = params
+            .candidate_limit
+            .unwrap_or(DEFAULT_CANDIDATE_LIMIT)
+            .clamp(1, MAX_CANDIDATE_LIMIT) as usize;
+        let board = self.resolve_board(&params).await?;
+        let project_key = params
+            .project_key
+            .as_deref()
+            .map(|key| key.trim().to_uppercase())
+            .or_else(|| board.project_key.clone());
+
+        let (backlog, workload, velocity) = tokio::join!(
+            self.jira_client
+                .get_board_backlog(board.id, candidate_limit),
+            self.workload(project_key.as_deref()),
+            self.velocity(board.id),
+        );
+
+        let mut section_errors = BTreeMap::new();
+        let mut record = |section: &str, e: JiraMcpError| {
+            warn!("Planning section {} failed: {}", section, e);
+            section_errors.insert(section.to_string(), e.to_string());
+        };
+
+        let (backlog, needs_refinement) = match backlog {
+            Ok(result) => {
+                let mut issues = result.issues;
+                issues.truncate(candidate_limit);
+                let gaps = issues
+                    .iter()
+                    .filter(|issue| self.is_story(issue))
+                    .filter_map(refinement_gap)
+                    .collect();
+                let candidates = issues
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, issue)| PlanningCandidate {
+                        rank: index + 1,
+                        key: issue.key,
+                        summary: issue.summary,
+                        issue_type: issue.issue_type,
+                        status: issue.status,
+                        story_points: issue.story_points,
+                        assignee: issue.assignee,
+                    })
+                    .collect();
+                (Some(candidates), Some(gaps))
+            }
+            Err(e) => {
+                record("backlog", e);
+                (None, None)
+            }
+        };
+        let workload = workload.map_err(|e| record("workload", e)).ok();
+        let velocity = velocity.map_err(|e| record("velocity", e)).ok();
+        let average_velocity = velocity
+            .as_ref()
+            .filter(|v| !v.is_empty())
+            .map(|v| v.iter().map(|sprint| sprint.completed_points).sum::<f64>() / v.len() as f64);
+
+        info!(
+            "Planning summary for board {}: {} candidates, {} sections failed",
+            board.id,
+            backlog.as_ref().map_or(0, Vec::len),
+            section_errors.len()
+        );
+
+        Ok(GetSprintPlanningSummaryResult {
+            board_id: board.id,
+            board_name: board.name,
+            project_key,
+            backlog,
+            needs_refinement,
+            workload,
+            velocity,
+            average_velocity,
+            section_errors,
+        })
+    }
+
+    async fn resolve_board(
+        &self,
+        params: &GetSprintPlanningSummaryParams,
+    ) -> JiraMcpResult<BoardInfo> {
+        match (params.board_id, params.project_key.as_deref()) {
+            (Some(board_id), _) => self.jira_client.get_board(board_id).await,
+            (None, Some(project_key)) if !project_key.trim().is_empty() => {
+                self.jira_client
+                    .find_project_board(&project_key.trim().to_uppercase())
+                    .await
+            }
+            _ => Err(JiraMcpError::invalid_param(
+                "board_id",
+                "Provide board_id or project_key",
+            )),
+        }
+    }
+
+    /// Open issues and points per assignee in the project
+    async fn workload(
+        &self,
+        project_key: Option<&str>,
+    ) -> JiraMcpResult<BTreeMap<String, AssigneeWorkload>> {
+        let project_key = project_key.ok_or_else(|| {
+            JiraMcpError::invalid_param(
+                "project_key",
+                "The board is not located in a project; pass project_key for the workload",
+            )
+        })?;
+        let jql = format!(
+            "{} = {} AND {} != Done ORDER BY {}",
+            jql::PROJECT,
+            jql::quote(project_key),
+            jql::STATUS_CATEGORY,
+            jql::ASSIGNEE
+        );
+        let search = self
+            .jira_client
+            .search_issues_jql(&jql, None, Some(MAX_WORKLOAD_ISSUES), None)
+            .await?;
+
+        Ok(group_issues(&search, GroupBy::Assignee)
+            .groups
+            .into_iter()
+            .map(|(assignee, group)| {
+                (
+                    assignee,
+                    AssigneeWorkload {
+                        open_issues: group.count,
+                        story_points: group.story_points_sum,
+                    },
+                )
+            })
+            .collect())
+    }
+
+    /// Committed and completed points of the last closed sprints
+    async fn velocity(&self, board_id: u64) -> JiraMcpResult<Vec<SprintVelocity>> {
+        let closed = self
+            .list_sprints_tool
+            .execute(ListSprintsParams {
+                board_id,
+                state: Some("closed".to_string()),
+                limit: Some(100),
+                start_at: None,
+            })
+            .await?;
+
+        // Sprints are listed oldest first
+        let mut join_set = JoinSet::new();
+        for (order, sprint) in closed
+            .sprints
+            .into_iter()
+            .rev()
+            .take(VELOCITY_SPRINTS)
+            .enumerate()
+        {
+            let sprint_issues_tool = Arc::clone(&self.sprint_issues_tool);
+            join_set.spawn(async move {
+                let result = sprint_issues_tool
+                    .execute(GetSprintIssuesParams {
+                        sprint_id: sprint.id,
+                        limit: Some(200),
+                        start_at: None,
+                        format: OutputFormat::Json,
+                    })
+                    .await;
+                (order, sprint.id, sprint.name, result)
+            });
+        }
+
+        let mut velocity = Vec::new();
+        while let Some(joined) = join_set.join_next().await {
+            let (order, sprint_id, name, result) =
+                joined.map_err(|e| JiraMcpError::internal(format!("Sprint task failed: {}", e)))?;
+            let issues = result?.search_result.issues;
+            velocity.push((
+                order,
+                SprintVelocity {
+                    sprint_id,
+                    name,
+                    committed_points: issues.iter().filter_map(|i| i.story_points).sum(),
+                    completed_points: issues
+                        .iter()
+                        .filter(|i| i.is_done())
+                        .filter_map(|i| i.story_points)
+                        .sum(),
+                },
+            ));
+        }
+        velocity.sort_by_key(|(order, _)| *order);
+
+        Ok(velocity.into_iter().map(|(_, sprint)| sprint).collect())
+    }
+
+    /// Whether the issue type is one of the configured story types
+    fn is_story(&self, issue: &IssueInfo) -> bool {
+        self.config
+            .issue_type_mappings
+            .get("story")
+            .into_iter()
+            .chain(self.config.locale_aliases.issue_types.get("story"))
+            .flatten()
+            .any(|name| name.eq_ignore_ascii_case(&issue.issue_type))
+    }
+}
+
+/// What keeps an issue from being ready, if anything
+fn refinement_gap(issue: &IssueInfo) -> Option<RefinementGap> {
+    let mut missing = Vec::new();
+    if issue.story_points.is_none() {
+        missing.push("story_points".to_string());
+    }
+    if issue
+        .acceptance_criteria
+        .as_deref()
+        .is_none_or(|ac| ac.trim().is_empty())
+    {
+        missing.push("acceptance_criteria".to_string());
+    }
+
+    (!missing.is_empty()).then(|| RefinementGap {
+        key: issue.key.clone(),
+        summary: issue.summary.clone(),
+        missing,
+    })
+}
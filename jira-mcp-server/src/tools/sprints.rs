@@ -2,15 +2,26 @@
 //!
 //! Provides tools for managing sprints, including listing sprints, getting sprint details,
 //! moving issues to sprints, and getting issues in a sprint.
+//!
+//! Sprint dates accept ISO 8601 date-times or plain dates ("2025-01-20"), which mean
+//! midnight UTC.
 
 use crate::error::{JiraMcpError, JiraMcpResult};
-use crate::jira_client::{JiraClient, SearchResult};
-use gouqi::{Board, SearchOptions, Sprint};
+use crate::jira_client::{JiraClient, SearchResult, SprintUpdate};
+use crate::jql;
+use crate::messages::message;
+use crate::tools::issue_key::normalize_issue_keys;
+use crate::tools::render::{issue_table, OutputFormat};
+use gouqi::Sprint;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
+use time::OffsetDateTime;
 use tracing::{info, instrument};
 
+/// Issues the Agile API accepts in one move request
+const MAX_ISSUES_PER_MOVE: usize = 50;
+
 /// Parameters for the list_sprints tool
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
@@ -101,6 +112,11 @@ pub struct GetSprintIssuesParams {
 
     /// Starting offset for pagination (optional, default: 0)
     pub start_at: Option<u32>,
+
+    /// Output format (optional, default: "json")
+    /// "markdown" adds `rendered`, a key | summary | status | assignee table of the page
+    #[serde(default)]
+    pub format: OutputFormat,
 }
 
 /// Result from the get_sprint_issues tool
@@ -111,6 +127,10 @@ pub struct GetSprintIssuesResult {
 
     /// Sprint information
     pub sprint: SprintInfo,
+
+    /// Markdown table of the page (only with format "markdown")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rendered: Option<String>,
 }
 
 // Workaround for pulseengine-mcp-macros issue
@@ -156,7 +176,8 @@ pub struct MoveToSprintResult {
     /// Issue keys that were moved
     pub issue_keys: Vec<String>,
 
-    /// Success message
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub message: String,
 }
 
@@ -194,6 +215,10 @@ pub struct SprintInfo {
     /// Sprint complete date (ISO 8601 format)
     pub complete_date: Option<String>,
 
+    /// Sprint goal (only known when the sprint was read through the Agile API directly)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub goal: Option<String>,
+
     /// Origin board ID
     pub origin_board_id: Option<u64>,
 
@@ -201,6 +226,24 @@ pub struct SprintInfo {
     pub self_link: String,
 }
 
+impl SprintInfo {
+    /// Sprint from an Agile API response, as stored by JIRA
+    fn from_json(sprint: &serde_json::Value) -> Option<Self> {
+        let text = |field: &str| sprint[field].as_str().map(String::from);
+        Some(SprintInfo {
+            id: sprint["id"].as_u64()?,
+            name: text("name").unwrap_or_default(),
+            state: text("state"),
+            start_date: text("startDate"),
+            end_date: text("endDate"),
+            complete_date: text("completeDate"),
+            goal: text("goal").filter(|goal| !goal.is_empty()),
+            origin_board_id: sprint["originBoardId"].as_u64(),
+            self_link: text("self").unwrap_or_default(),
+        })
+    }
+}
+
 impl From<Sprint> for SprintInfo {
     fn from(sprint: Sprint) -> Self {
         SprintInfo {
@@ -210,6 +253,7 @@ impl From<Sprint> for SprintInfo {
             start_date: sprint.start_date.map(|dt| dt.to_string()),
             end_date: sprint.end_date.map(|dt| dt.to_string()),
             complete_date: sprint.complete_date.map(|dt| dt.to_string()),
+            goal: None,
             origin_board_id: sprint.origin_board_id,
             self_link: sprint.self_link,
         }
@@ -233,27 +277,10 @@ impl ListSprintsTool {
         let limit = params.limit.unwrap_or(50).min(100) as u64;
         let start_at = params.start_at.unwrap_or(0) as u64;
 
-        // Create a board object (we only need the ID)
-        let board = Board {
-            id: params.board_id,
-            name: String::new(), // Not needed for API call
-            self_link: String::new(),
-            type_name: String::new(),
-            location: None,
-        };
-
-        let options = SearchOptions::builder()
-            .max_results(limit)
-            .start_at(start_at)
-            .build();
-
         let result = self
             .jira_client
-            .client
-            .sprints()
-            .list(&board, &options)
-            .await
-            .map_err(|e| JiraMcpError::internal(format!("Failed to list sprints: {}", e)))?;
+            .list_sprints(params.board_id, start_at, limit)
+            .await?;
 
         // Filter by state if requested
         let mut sprints: Vec<SprintInfo> =
@@ -301,19 +328,7 @@ impl GetSprintInfoTool {
     pub async fn execute(&self, params: GetSprintInfoParams) -> JiraMcpResult<GetSprintInfoResult> {
         info!("Getting sprint info for sprint {}", params.sprint_id);
 
-        let sprint = self
-            .jira_client
-            .client
-            .sprints()
-            .get(params.sprint_id.to_string())
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") {
-                    JiraMcpError::not_found("sprint", params.sprint_id.to_string())
-                } else {
-                    JiraMcpError::internal(format!("Failed to get sprint: {}", e))
-                }
-            })?;
+        let sprint = self.jira_client.get_sprint(params.sprint_id).await?;
 
         Ok(GetSprintInfoResult {
             sprint: SprintInfo::from(sprint),
@@ -339,23 +354,11 @@ impl GetSprintIssuesTool {
         info!("Getting issues for sprint {}", params.sprint_id);
 
         // First get sprint info
-        let sprint = self
-            .jira_client
-            .client
-            .sprints()
-            .get(params.sprint_id.to_string())
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") {
-                    JiraMcpError::not_found("sprint", params.sprint_id.to_string())
-                } else {
-                    JiraMcpError::internal(format!("Failed to get sprint: {}", e))
-                }
-            })?;
+        let sprint = self.jira_client.get_sprint(params.sprint_id).await?;
 
         // Use JQL to get issues in this sprint
         // Sprint field uses customfield_10020 or similar, but we can use "Sprint = <sprint_id>"
-        let jql = format!("Sprint = {}", params.sprint_id);
+        let jql = format!("{} = {}", jql::SPRINT, params.sprint_id);
 
         let limit = params.limit.unwrap_or(50).min(200) as usize;
         let start_at = params.start_at.unwrap_or(0) as usize;
@@ -367,11 +370,14 @@ impl GetSprintIssuesTool {
             .map_err(|e| JiraMcpError::internal(format!("Failed to get sprint issues: {}", e)))?;
 
         info!(
-            "Found {} issues in sprint {}",
-            search_result.total, params.sprint_id
+            "Found {} issues in sprint {} (total: {:?})",
+            search_result.issues.len(),
+            params.sprint_id,
+            search_result.total
         );
 
         Ok(GetSprintIssuesResult {
+            rendered: params.format.render(|| issue_table(&search_result)),
             search_result,
             sprint: SprintInfo::from(sprint),
         })
@@ -389,7 +395,10 @@ impl MoveToSprintTool {
     }
 
     #[instrument(skip(self))]
-    pub async fn execute(&self, params: MoveToSprintParams) -> JiraMcpResult<MoveToSprintResult> {
+    pub async fn execute(
+        &self,
+        mut params: MoveToSprintParams,
+    ) -> JiraMcpResult<MoveToSprintResult> {
         info!(
             "Moving {} issues to sprint {}",
             params.issue_keys.len(),
@@ -402,39 +411,32 @@ impl MoveToSprintTool {
                 "At least one issue key is required",
             ));
         }
+        params.issue_keys = normalize_issue_keys(
+            self.jira_client.base_url(),
+            &params.issue_keys,
+            "issue_keys",
+        )?;
 
         // Get sprint info first
-        let sprint = self
-            .jira_client
-            .client
-            .sprints()
-            .get(params.sprint_id.to_string())
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") {
-                    JiraMcpError::not_found("sprint", params.sprint_id.to_string())
-                } else {
-                    JiraMcpError::internal(format!("Failed to get sprint: {}", e))
-                }
-            })?;
+        let sprint = self.jira_client.get_sprint(params.sprint_id).await?;
 
         // Move issues to sprint
         self.jira_client
-            .client
-            .sprints()
-            .move_issues(params.sprint_id, params.issue_keys.clone())
-            .await
-            .map_err(|e| {
-                JiraMcpError::internal(format!("Failed to move issues to sprint: {}", e))
-            })?;
+            .move_issues_to_sprint(params.sprint_id, &params.issue_keys)
+            .await?;
 
         let issues_moved = params.issue_keys.len();
-        let message = format!(
-            "Successfully moved {} issue(s) to sprint '{}'",
-            issues_moved, sprint.name
+        let message = message!(
+            self.jira_client.messages(),
+            "sprint.issues_moved",
+            count = issues_moved,
+            sprint = sprint.name,
         );
 
-        info!("{}", message);
+        info!(
+            "Moved {} issue(s) to sprint '{}'",
+            issues_moved, sprint.name
+        );
 
         Ok(MoveToSprintResult {
             success: true,
@@ -480,7 +482,8 @@ pub struct CreateSprintResult {
     /// Created sprint information
     pub sprint: SprintInfo,
 
-    /// Success message
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub message: String,
 }
 
@@ -505,11 +508,11 @@ pub struct StartSprintParams {
     pub sprint_id: u64,
 
     /// Sprint start date (optional, defaults to now if not set)
-    /// Example: "2025-01-20T00:00:00Z"
+    /// Examples: "2025-01-20T08:00:00Z", "2025-01-20" (midnight UTC)
     pub start_date: Option<String>,
 
-    /// Sprint end date (required if not already set on sprint)
-    /// Example: "2025-02-03T23:59:59Z"
+    /// Sprint end date (required if not already set on sprint), after start_date
+    /// Examples: "2025-02-03T23:59:59Z", "2025-02-03" (midnight UTC)
     pub end_date: Option<String>,
 
     /// Update sprint goal (optional)
@@ -519,13 +522,14 @@ pub struct StartSprintParams {
 /// Result from the start_sprint tool
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StartSprintResult {
-    /// Updated sprint information
+    /// Sprint as stored by JIRA after starting, including goal and dates
     pub sprint: SprintInfo,
 
     /// Number of issues in the sprint
     pub issue_count: usize,
 
-    /// Success message
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub message: String,
 
     /// Warnings (e.g., "Sprint has no issues")
@@ -572,7 +576,15 @@ pub struct CloseSprintResult {
     /// Number of issues moved (if move_incomplete_to was specified)
     pub moved_issues: Option<usize>,
 
-    /// Success message
+    /// Incomplete issues left in the closed sprint (all of them unless
+    /// move_incomplete_to was specified)
+    pub remaining_issue_keys: Vec<String>,
+
+    /// Number of search pages fetched to collect the sprint's issues
+    pub pages_fetched: usize,
+
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub message: String,
 
     /// Warnings (e.g., completion statistics)
@@ -592,6 +604,55 @@ impl std::fmt::Display for CloseSprintResult {
     }
 }
 
+/// Parameters for the update_sprint tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateSprintParams {
+    /// Sprint ID to update (required)
+    pub sprint_id: u64,
+
+    /// New sprint name (optional)
+    pub name: Option<String>,
+
+    /// New sprint goal (optional, "" clears it)
+    pub goal: Option<String>,
+
+    /// New start date (optional)
+    /// Examples: "2025-01-20T08:00:00Z", "2025-01-20" (midnight UTC)
+    pub start_date: Option<String>,
+
+    /// New end date (optional), after the start date
+    /// Examples: "2025-02-03T23:59:59Z", "2025-02-03" (midnight UTC)
+    pub end_date: Option<String>,
+}
+
+/// Result from the update_sprint tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateSprintResult {
+    /// Sprint as stored by JIRA after the update
+    pub sprint: SprintInfo,
+
+    /// Fields that were sent, e.g. ["goal", "end_date"]
+    pub updated_fields: Vec<String>,
+
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub message: String,
+}
+
+// Workaround for pulseengine-mcp-macros issue
+impl std::fmt::Display for UpdateSprintResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match serde_json::to_string(self) {
+            Ok(json) => write!(f, "{}", json),
+            Err(_) => write!(
+                f,
+                "{{\"error\": \"Failed to serialize UpdateSprintResult\"}}"
+            ),
+        }
+    }
+}
+
 /// Tool for creating a new sprint
 pub struct CreateSprintTool {
     jira_client: Arc<JiraClient>,
@@ -617,37 +678,24 @@ impl CreateSprintTool {
             ));
         }
 
-        // Create a board object
-        let board = Board {
-            id: params.board_id,
-            name: String::new(),
-            self_link: String::new(),
-            type_name: String::new(),
-            location: None,
-        };
-
-        // Create the sprint using gouqi
         let sprint = self
             .jira_client
-            .client
-            .sprints()
-            .create(board, params.name.clone())
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") {
-                    JiraMcpError::not_found("board", params.board_id.to_string())
-                } else {
-                    JiraMcpError::internal(format!("Failed to create sprint: {}", e))
-                }
-            })?;
+            .create_sprint(params.board_id, &params.name)
+            .await?;
+
+        let message = message!(
+            self.jira_client.messages(),
+            "sprint.created",
+            sprint = sprint.name,
+            sprint_id = sprint.id,
+            board_id = params.board_id,
+        );
 
-        let message = format!(
-            "Successfully created sprint '{}' (ID: {}) on board {}",
+        info!(
+            "Created sprint '{}' (ID: {}) on board {}",
             sprint.name, sprint.id, params.board_id
         );
 
-        info!("{}", message);
-
         Ok(CreateSprintResult {
             sprint: SprintInfo::from(sprint),
             message,
@@ -670,19 +718,7 @@ impl StartSprintTool {
         info!("Starting sprint {}", params.sprint_id);
 
         // Get current sprint info
-        let current_sprint = self
-            .jira_client
-            .client
-            .sprints()
-            .get(params.sprint_id.to_string())
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") {
-                    JiraMcpError::not_found("sprint", params.sprint_id.to_string())
-                } else {
-                    JiraMcpError::internal(format!("Failed to get sprint: {}", e))
-                }
-            })?;
+        let current_sprint = self.jira_client.get_sprint(params.sprint_id).await?;
 
         // Validate sprint state
         if let Some(state) = &current_sprint.state {
@@ -704,68 +740,67 @@ impl StartSprintTool {
         }
 
         // Parse dates
-        let start_date = if let Some(date_str) = &params.start_date {
-            Some(parse_iso8601_date(date_str)?)
-        } else {
-            Some(time::OffsetDateTime::now_utc())
+        let start_date = match &params.start_date {
+            Some(date_str) => parse_sprint_date(date_str, "start_date")?,
+            None => OffsetDateTime::now_utc(),
         };
 
-        let end_date = if let Some(date_str) = &params.end_date {
-            Some(parse_iso8601_date(date_str)?)
-        } else {
-            current_sprint.end_date
+        let end_date = match &params.end_date {
+            Some(date_str) => parse_sprint_date(date_str, "end_date")?,
+            None => current_sprint.end_date.ok_or_else(|| {
+                JiraMcpError::invalid_param(
+                    "end_date",
+                    "Sprint end date must be set before starting (provide end_date parameter or set it on the sprint)",
+                )
+            })?,
         };
+        validate_date_order(start_date, end_date)?;
 
-        // Validate end date is set
-        if end_date.is_none() {
-            return Err(JiraMcpError::invalid_param(
-                "end_date",
-                "Sprint end date must be set before starting (provide end_date parameter or set it on the sprint)",
-            ));
-        }
-
-        // Build update request
-        let update_data = gouqi::UpdateSprint {
-            name: None, // Keep current name
-            start_date,
-            end_date,
+        // Update sprint to active state, together with its dates and goal
+        let update = SprintUpdate {
+            goal: params.goal.clone(),
+            start_date: Some(format_sprint_date(start_date)?),
+            end_date: Some(format_sprint_date(end_date)?),
             state: Some("active".to_string()),
+            ..Default::default()
         };
-
-        // Update sprint to active state
-        let updated_sprint = self
+        let stored = self
             .jira_client
-            .client
-            .sprints()
-            .update(params.sprint_id, update_data)
-            .await
-            .map_err(|e| JiraMcpError::internal(format!("Failed to start sprint: {}", e)))?;
+            .update_sprint(params.sprint_id, &update)
+            .await?;
+        let updated_sprint = SprintInfo::from_json(&stored)
+            .ok_or_else(|| JiraMcpError::internal("Unexpected sprint response"))?;
 
         // Get issue count in sprint
-        let jql = format!("Sprint = {}", params.sprint_id);
-        let search_result = self
-            .jira_client
-            .search_issues_jql(&jql, Some(0), Some(1), None)
-            .await
-            .map_err(|e| JiraMcpError::internal(format!("Failed to count sprint issues: {}", e)))?;
-
-        let issue_count = search_result.total;
+        let jql = format!("{} = {}", jql::SPRINT, params.sprint_id);
+        let issue_count =
+            self.jira_client.count_issues_jql(&jql).await.map_err(|e| {
+                JiraMcpError::internal(format!("Failed to count sprint issues: {}", e))
+            })?;
 
         // Generate warnings
         let mut warnings = Vec::new();
         if issue_count == 0 {
             warnings.push("Warning: Sprint has no issues".to_string());
         }
+        if params.goal.is_some() && updated_sprint.goal != params.goal {
+            warnings.push("JIRA did not store the requested sprint goal".to_string());
+        }
 
-        let message = format!(
-            "Successfully started sprint '{}' with {} issue(s)",
-            updated_sprint.name, issue_count
+        let message = message!(
+            self.jira_client.messages(),
+            "sprint.started",
+            sprint = updated_sprint.name,
+            count = issue_count,
         );
 
-        info!("{}", message);
+        info!(
+            "Started sprint '{}' with {} issue(s)",
+            updated_sprint.name, issue_count
+        );
 
         Ok(StartSprintResult {
-            sprint: SprintInfo::from(updated_sprint),
+            sprint: updated_sprint,
             issue_count,
             message,
             warnings,
@@ -788,19 +823,7 @@ impl CloseSprintTool {
         info!("Closing sprint {}", params.sprint_id);
 
         // Get current sprint info
-        let current_sprint = self
-            .jira_client
-            .client
-            .sprints()
-            .get(params.sprint_id.to_string())
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") {
-                    JiraMcpError::not_found("sprint", params.sprint_id.to_string())
-                } else {
-                    JiraMcpError::internal(format!("Failed to get sprint: {}", e))
-                }
-            })?;
+        let current_sprint = self.jira_client.get_sprint(params.sprint_id).await?;
 
         // Validate sprint state
         if let Some(state) = &current_sprint.state {
@@ -812,80 +835,86 @@ impl CloseSprintTool {
             }
         }
 
-        // Get issue statistics before closing
-        let jql = format!("Sprint = {}", params.sprint_id);
+        // Get issue statistics before closing, from every page of the sprint
+        let jql = format!("{} = {}", jql::SPRINT, params.sprint_id);
         let all_issues = self
             .jira_client
-            .search_issues_jql(&jql, Some(0), Some(1000), None)
+            .search_all_issues_jql(&jql, usize::MAX)
             .await
             .map_err(|e| JiraMcpError::internal(format!("Failed to get sprint issues: {}", e)))?;
 
-        let total_issues = all_issues.total;
-        let completed_issues = all_issues
+        // Completion goes by status category, so custom and localized statuses count
+        let total_issues = all_issues.issues.len();
+        let incomplete_keys: Vec<String> = all_issues
             .issues
             .iter()
-            .filter(|issue| {
-                issue.status.to_lowercase() == "done"
-                    || issue.status.to_lowercase() == "closed"
-                    || issue.status.to_lowercase() == "resolved"
-            })
-            .count();
-        let incomplete_issues = total_issues - completed_issues;
-
-        // Move incomplete issues if requested
+            .filter(|issue| !issue.is_done())
+            .map(|issue| issue.key.clone())
+            .collect();
+        let incomplete_issues = incomplete_keys.len();
+        let completed_issues = total_issues - incomplete_issues;
+
+        // Move incomplete issues if requested, in chunks the Agile API accepts.
+        // A failed chunk leaves the sprint open so close_sprint can be retried.
         let mut moved_issues = None;
+        let mut remaining_issue_keys = incomplete_keys.clone();
         if let Some(target_sprint_id) = params.move_incomplete_to {
             if incomplete_issues > 0 {
-                let incomplete_keys: Vec<String> = all_issues
-                    .issues
-                    .iter()
-                    .filter(|issue| {
-                        issue.status.to_lowercase() != "done"
-                            && issue.status.to_lowercase() != "closed"
-                            && issue.status.to_lowercase() != "resolved"
-                    })
-                    .map(|issue| issue.key.clone())
-                    .collect();
-
                 info!(
                     "Moving {} incomplete issue(s) to sprint {}",
-                    incomplete_keys.len(),
-                    target_sprint_id
+                    incomplete_issues, target_sprint_id
                 );
 
-                self.jira_client
-                    .client
-                    .sprints()
-                    .move_issues(target_sprint_id, incomplete_keys)
-                    .await
-                    .map_err(|e| {
-                        JiraMcpError::internal(format!("Failed to move incomplete issues: {}", e))
-                    })?;
+                let mut moved = 0;
+                for chunk in incomplete_keys.chunks(MAX_ISSUES_PER_MOVE) {
+                    if let Err(e) = self
+                        .jira_client
+                        .move_issues_to_sprint(target_sprint_id, chunk)
+                        .await
+                    {
+                        return Err(JiraMcpError::internal(format!(
+                            "Failed to move incomplete issues after moving {} of {}: {}. \
+                             Not moved: {}. Sprint {} was left open; run close_sprint again \
+                             to retry",
+                            moved,
+                            incomplete_issues,
+                            e,
+                            incomplete_keys[moved..].join(", "),
+                            params.sprint_id
+                        )));
+                    }
+                    moved += chunk.len();
+                }
 
-                moved_issues = Some(incomplete_issues);
+                moved_issues = Some(moved);
+                remaining_issue_keys.clear();
             }
         }
 
-        // Build update request
-        // Note: JIRA automatically sets complete_date to now when transitioning to closed
-        let update_data = gouqi::UpdateSprint {
-            name: None,
-            start_date: None,
-            end_date: None,
+        // Close the sprint
+        // Note: JIRA sets complete_date to now and keeps end_date as the planned end
+        let update = SprintUpdate {
             state: Some("closed".to_string()),
+            ..Default::default()
         };
-
-        // Close the sprint
-        let updated_sprint = self
+        let stored = self
             .jira_client
-            .client
-            .sprints()
-            .update(params.sprint_id, update_data)
-            .await
-            .map_err(|e| JiraMcpError::internal(format!("Failed to close sprint: {}", e)))?;
+            .update_sprint(params.sprint_id, &update)
+            .await?;
+        let updated_sprint = SprintInfo::from_json(&stored)
+            .ok_or_else(|| JiraMcpError::internal("Unexpected sprint response"))?;
 
         // Generate warnings
         let mut warnings = Vec::new();
+        if let Some(end_date) = current_sprint
+            .end_date
+            .filter(|end_date| *end_date > OffsetDateTime::now_utc())
+        {
+            warnings.push(format!(
+                "Sprint closed before its planned end date ({})",
+                format_sprint_date(end_date)?
+            ));
+        }
         if total_issues > 0 {
             let completion_rate = (completed_issues as f64 / total_issues as f64) * 100.0;
             warnings.push(format!(
@@ -893,48 +922,243 @@ impl CloseSprintTool {
                 completed_issues, total_issues, completion_rate
             ));
         }
-        if incomplete_issues > 0 && moved_issues.is_none() {
+        if !remaining_issue_keys.is_empty() {
             warnings.push(format!(
-                "{} incomplete issue(s) remain in closed sprint (not moved)",
-                incomplete_issues
+                "{} incomplete issue(s) remain in closed sprint (not moved), see remaining_issue_keys",
+                remaining_issue_keys.len()
             ));
         }
 
         let message = if let Some(moved_count) = moved_issues {
-            format!(
-                "Successfully closed sprint '{}' ({}/{} completed, {} moved to next sprint)",
-                updated_sprint.name, completed_issues, total_issues, moved_count
+            message!(
+                self.jira_client.messages(),
+                "sprint.closed_and_moved",
+                sprint = updated_sprint.name,
+                completed = completed_issues,
+                total = total_issues,
+                moved = moved_count,
             )
         } else {
-            format!(
-                "Successfully closed sprint '{}' ({}/{} completed)",
-                updated_sprint.name, completed_issues, total_issues
+            message!(
+                self.jira_client.messages(),
+                "sprint.closed",
+                sprint = updated_sprint.name,
+                completed = completed_issues,
+                total = total_issues,
             )
         };
 
-        info!("{}", message);
+        info!(
+            "Closed sprint '{}' ({}/{} completed, {:?} moved)",
+            updated_sprint.name, completed_issues, total_issues, moved_issues
+        );
 
         Ok(CloseSprintResult {
-            sprint: SprintInfo::from(updated_sprint),
+            sprint: updated_sprint,
             completed_issues,
             incomplete_issues,
             moved_issues,
+            remaining_issue_keys,
+            pages_fetched: all_issues.pages,
             message,
             warnings,
         })
     }
 }
 
-/// Helper function to parse ISO 8601 date strings
-fn parse_iso8601_date(date_str: &str) -> JiraMcpResult<time::OffsetDateTime> {
-    time::OffsetDateTime::parse(
-        date_str,
-        &time::format_description::well_known::Iso8601::DEFAULT,
-    )
-    .map_err(|e| {
+/// Tool for updating the name, goal or dates of a sprint, including an active one
+pub struct UpdateSprintTool {
+    jira_client: Arc<JiraClient>,
+}
+
+impl UpdateSprintTool {
+    pub fn new(jira_client: Arc<JiraClient>) -> Self {
+        Self { jira_client }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(&self, params: UpdateSprintParams) -> JiraMcpResult<UpdateSprintResult> {
+        info!("Updating sprint {}", params.sprint_id);
+
+        if let Some(name) = &params.name {
+            if name.trim().is_empty() {
+                return Err(JiraMcpError::invalid_param(
+                    "name",
+                    "Sprint name cannot be empty",
+                ));
+            }
+        }
+
+        let start_date = params
+            .start_date
+            .as_deref()
+            .map(|date_str| parse_sprint_date(date_str, "start_date"))
+            .transpose()?;
+        let end_date = params
+            .end_date
+            .as_deref()
+            .map(|date_str| parse_sprint_date(date_str, "end_date"))
+            .transpose()?;
+
+        let mut updated_fields = Vec::new();
+        for (field, present) in [
+            ("name", params.name.is_some()),
+            ("goal", params.goal.is_some()),
+            ("start_date", start_date.is_some()),
+            ("end_date", end_date.is_some()),
+        ] {
+            if present {
+                updated_fields.push(field.to_string());
+            }
+        }
+        if updated_fields.is_empty() {
+            return Err(JiraMcpError::invalid_param(
+                "sprint_id",
+                "Nothing to update: provide name, goal, start_date or end_date",
+            ));
+        }
+
+        // A single new date is checked against the one already on the sprint
+        if start_date.is_some() || end_date.is_some() {
+            let current_sprint = self.jira_client.get_sprint(params.sprint_id).await?;
+            if let (Some(start), Some(end)) = (
+                start_date.or(current_sprint.start_date),
+                end_date.or(current_sprint.end_date),
+            ) {
+                validate_date_order(start, end)?;
+            }
+        }
+
+        let update = SprintUpdate {
+            name: params.name.clone(),
+            goal: params.goal.clone(),
+            start_date: start_date.map(format_sprint_date).transpose()?,
+            end_date: end_date.map(format_sprint_date).transpose()?,
+            state: None,
+        };
+        let stored = self
+            .jira_client
+            .update_sprint(params.sprint_id, &update)
+            .await?;
+        let sprint = SprintInfo::from_json(&stored)
+            .ok_or_else(|| JiraMcpError::internal("Unexpected sprint response"))?;
+
+        let message = message!(
+            self.jira_client.messages(),
+            "sprint.updated",
+            fields = updated_fields.join(", "),
+            sprint = sprint.name,
+            sprint_id = sprint.id,
+        );
+        info!(
+            "Updated {} of sprint '{}' (ID: {})",
+            updated_fields.join(", "),
+            sprint.name,
+            sprint.id
+        );
+
+        Ok(UpdateSprintResult {
+            sprint,
+            updated_fields,
+            message,
+        })
+    }
+}
+
+/// Parse a sprint date: an ISO 8601 date-time, or a plain date meaning midnight UTC
+fn parse_sprint_date(date_str: &str, parameter: &str) -> JiraMcpResult<OffsetDateTime> {
+    use time::format_description::well_known::Iso8601;
+
+    // Parsing as a date alone would accept a date-time and drop its time part
+    let date_str = date_str.trim();
+    if date_str.len() == 10 {
+        if let Ok(date) = time::Date::parse(date_str, &Iso8601::DATE) {
+            return Ok(date.midnight().assume_utc());
+        }
+    }
+
+    OffsetDateTime::parse(date_str, &Iso8601::DEFAULT).map_err(|e| {
         JiraMcpError::invalid_param(
-            "date",
-            format!("Invalid ISO 8601 date format: {} (error: {})", date_str, e),
+            parameter,
+            format!(
+                "Invalid date: '{}'. Use ISO 8601, e.g. \"2025-01-20\" or \"2025-01-20T08:00:00Z\" \
+                 (error: {})",
+                date_str, e
+            ),
         )
     })
 }
+
+/// RFC 3339 timestamp for the Agile API
+fn format_sprint_date(date: OffsetDateTime) -> JiraMcpResult<String> {
+    date.format(&time::format_description::well_known::Rfc3339)
+        .map_err(|e| JiraMcpError::internal(format!("Failed to format sprint date: {}", e)))
+}
+
+/// Reject sprints that would end before (or when) they start
+fn validate_date_order(start_date: OffsetDateTime, end_date: OffsetDateTime) -> JiraMcpResult<()> {
+    if end_date <= start_date {
+        return Err(JiraMcpError::invalid_param(
+            "end_date",
+            format!(
+                "end_date ({}) must be after start_date ({})",
+                format_sprint_date(end_date)?,
+                format_sprint_date(start_date)?
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_sprint_date() {
+        let date_only = parse_sprint_date("2025-01-20", "start_date").unwrap();
+        assert_eq!(
+            format_sprint_date(date_only).unwrap(),
+            "2025-01-20T00:00:00Z"
+        );
+
+        let date_time = parse_sprint_date("2025-01-20T08:30:00+02:00", "start_date").unwrap();
+        assert_eq!(
+            date_time.unix_timestamp(),
+            date_only.unix_timestamp() + 6 * 3600 + 1800
+        );
+
+        let err = parse_sprint_date("20.01.2025", "end_date").unwrap_err();
+        assert!(err.to_string().contains("end_date"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_date_order() {
+        let start = parse_sprint_date("2025-01-20", "start_date").unwrap();
+        let end = parse_sprint_date("2025-02-03", "end_date").unwrap();
+
+        assert!(validate_date_order(start, end).is_ok());
+        assert!(validate_date_order(end, start).is_err());
+        assert!(validate_date_order(start, start).is_err());
+    }
+
+    #[test]
+    fn test_sprint_info_from_json_keeps_goal() {
+        let sprint = SprintInfo::from_json(&serde_json::json!({
+            "id": 7,
+            "self": "http://jira.local/rest/agile/1.0/sprint/7",
+            "state": "active",
+            "name": "Sprint 7",
+            "startDate": "2026-01-05T09:00:00.000Z",
+            "endDate": "2026-01-19T09:00:00.000Z",
+            "originBoardId": 1,
+            "goal": "Ship payments"
+        }))
+        .unwrap();
+
+        assert_eq!(sprint.goal.as_deref(), Some("Ship payments"));
+        assert_eq!(sprint.end_date.as_deref(), Some("2026-01-19T09:00:00.000Z"));
+        assert_eq!(sprint.origin_board_id, Some(1));
+        assert!(SprintInfo::from_json(&serde_json::json!({"name": "no id"})).is_none());
+    }
+}
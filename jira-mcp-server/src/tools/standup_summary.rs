@@ -0,0 +1,265 @@
+//! Standup summary
+//!
+//! Composes a "yesterday / today / blockers" summary for one user from three
+//! get_user_issues queries run concurrently (recently completed, in progress and
+//! blocked) plus the time they logged since the given date, so an agent can
+//! answer "what did I do yesterday?" in a single call.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::{IssueInfo, JiraClient};
+use crate::jql;
+use crate::semantic_mapping::resolve_date;
+use crate::tools::render::OutputFormat;
+use crate::tools::user_issues::{GetUserIssuesParams, GetUserIssuesResult, GetUserIssuesTool};
+use chrono::{NaiveDate, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tracing::{info, instrument, warn};
+
+/// Issues per bucket
+const MAX_BUCKET_ISSUES: u32 = 50;
+
+/// Issues whose worklogs are read for time_logged_seconds
+const MAX_WORKLOG_ISSUES: usize = 20;
+
+/// Worklog fetches in flight at once
+const MAX_CONCURRENT_FETCHES: usize = 5;
+
+/// Parameters for the get_standup_summary tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetStandupSummaryParams {
+    /// Username, account ID, or "me" (optional, default: "me")
+    pub username: Option<String>,
+
+    /// Project keys to limit the summary to (optional)
+    /// Examples: ["PROJ"], ["PROJ", "OPS"]
+    pub project_filter: Option<Vec<String>>,
+
+    /// Start of the "yesterday" window (optional, default: "1 day ago")
+    /// Examples: "yesterday", "3 days ago", "2024-01-01"
+    pub since: Option<String>,
+}
+
+/// An issue in one of the standup buckets
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct StandupIssue {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+}
+
+impl From<&IssueInfo> for StandupIssue {
+    fn from(issue: &IssueInfo) -> Self {
+        Self {
+            key: issue.key.clone(),
+            summary: issue.summary.clone(),
+            status: issue.status.clone(),
+        }
+    }
+}
+
+/// Result from the get_standup_summary tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetStandupSummaryResult {
+    /// Display name of the user the summary is for
+    pub user: String,
+
+    /// Date the "yesterday" window starts at
+    pub since: String,
+
+    /// Issues completed since then
+    pub yesterday: Vec<StandupIssue>,
+
+    /// Issues in progress
+    pub today: Vec<StandupIssue>,
+
+    /// Issues in a blocked status
+    pub blockers: Vec<StandupIssue>,
+
+    /// Seconds the user logged since then
+    pub time_logged_seconds: u64,
+
+    /// Parts of the summary that could not be loaded, with the error
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Implementation of the get_standup_summary tool
+pub struct GetStandupSummaryTool {
+    jira_client: Arc<JiraClient>,
+    user_issues_tool: Arc<GetUserIssuesTool>,
+}
+
+impl GetStandupSummaryTool {
+    pub fn new(jira_client: Arc<JiraClient>, user_issues_tool: Arc<GetUserIssuesTool>) -> Self {
+        Self {
+            jira_client,
+            user_issues_tool,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: GetStandupSummaryParams,
+    ) -> JiraMcpResult<GetStandupSummaryResult> {
+        let username = params.username.unwrap_or_else(|| "me".to_string());
+        let since_input = params.since.as_deref().unwrap_or("1 day ago");
+        let since = resolve_date(since_input, Utc::now().date_naive(), "since")?;
+        let since_str = since.format("%Y-%m-%d").to_string();
+
+        let bucket = |status: &str, updated_since: Option<String>| GetUserIssuesParams {
+            username: Some(username.clone()),
+            usernames: None,
+            role: None,
+            status_filter: Some(vec![status.to_string()]),
+            issue_types: None,
+            board_filter: None,
+            project_filter: params.project_filter.clone(),
+            due_date_filter: None,
+            priority_filter: None,
+            updated_since,
+            text_filter: None,
+            order_by: None,
+            group_by: None,
+            format: OutputFormat::Json,
+            limit: Some(MAX_BUCKET_ISSUES),
+            start_at: None,
+            response_budget: None,
+        };
+
+        let (done, in_progress, blocked) = tokio::join!(
+            self.user_issues_tool
+                .execute(bucket("done", Some(since_str.clone()))),
+            self.user_issues_tool.execute(bucket("in_progress", None)),
+            self.user_issues_tool.execute(bucket("blocked", None)),
+        );
+
+        // The user has to resolve for any bucket to make sense
+        let done = done?;
+        let user = done
+            .resolved_user
+            .clone()
+            .ok_or_else(|| JiraMcpError::invalid_param("username", "Could not resolve user"))?;
+
+        let mut warnings = Vec::new();
+        let blockers = bucket_issues("blockers", blocked, &mut warnings);
+        let blocker_keys: HashSet<&str> = blockers.iter().map(|i| i.key.as_str()).collect();
+        // Blocked statuses often sit in the in-progress category; report them once
+        let today: Vec<StandupIssue> = bucket_issues("today", in_progress, &mut warnings)
+            .into_iter()
+            .filter(|issue| !blocker_keys.contains(issue.key.as_str()))
+            .collect();
+        let yesterday = done
+            .search_result
+            .issues
+            .iter()
+            .map(StandupIssue::from)
+            .collect();
+
+        let time_logged_seconds = match self
+            .time_logged(&user.account_id, &user.display_name, since)
+            .await
+        {
+            Ok(seconds) => seconds,
+            Err(e) => {
+                warn!("Could not total worklogs: {}", e);
+                warnings.push(format!("time_logged_seconds: {}", e));
+                0
+            }
+        };
+
+        info!(
+            "Standup for {}: {} done, {} in progress, {} blocked, {}s logged",
+            user.display_name,
+            done.search_result.issues.len(),
+            today.len(),
+            blockers.len(),
+            time_logged_seconds
+        );
+
+        Ok(GetStandupSummaryResult {
+            user: user.display_name,
+            since: since_str,
+            yesterday,
+            today,
+            blockers,
+            time_logged_seconds,
+            warnings,
+        })
+    }
+
+    /// Seconds the user logged on or after `since`, over the issues they logged work on
+    async fn time_logged(
+        &self,
+        account_id: &str,
+        display_name: &str,
+        since: NaiveDate,
+    ) -> JiraMcpResult<u64> {
+        let since_str = since.format("%Y-%m-%d").to_string();
+        let jql = format!(
+            "{} = {} AND {} >= {} ORDER BY updated DESC",
+            jql::WORKLOG_AUTHOR,
+            jql::quote(account_id),
+            jql::WORKLOG_DATE,
+            jql::quote(&since_str)
+        );
+        let search = self
+            .jira_client
+            .search_issues_jql(&jql, None, Some(MAX_WORKLOG_ISSUES), None)
+            .await?;
+
+        let mut total = 0;
+        let mut pending = search.issues.into_iter().map(|issue| issue.key);
+        let mut join_set = JoinSet::new();
+        loop {
+            while join_set.len() < MAX_CONCURRENT_FETCHES {
+                let Some(key) = pending.next() else {
+                    break;
+                };
+                let jira_client = Arc::clone(&self.jira_client);
+                join_set.spawn(async move { jira_client.get_worklogs(&key).await });
+            }
+
+            let Some(joined) = join_set.join_next().await else {
+                break;
+            };
+            let worklogs = joined
+                .map_err(|e| JiraMcpError::internal(format!("Worklog task failed: {}", e)))??;
+            total += worklogs
+                .iter()
+                .filter(|w| {
+                    w.author == display_name && w.started.get(..10) >= Some(since_str.as_str())
+                })
+                .filter_map(|w| w.time_spent_seconds)
+                .sum::<u64>();
+        }
+
+        Ok(total)
+    }
+}
+
+/// Issues of a bucket query, recording a warning instead of failing the summary
+fn bucket_issues(
+    bucket: &str,
+    result: JiraMcpResult<GetUserIssuesResult>,
+    warnings: &mut Vec<String>,
+) -> Vec<StandupIssue> {
+    match result {
+        Ok(result) => result
+            .search_result
+            .issues
+            .iter()
+            .map(StandupIssue::from)
+            .collect(),
+        Err(e) => {
+            warn!("Could not load standup bucket {}: {}", bucket, e);
+            warnings.push(format!("{}: {}", bucket, e));
+            Vec::new()
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Line-based unified diffs
+//!
+//! Used to preview (dry_run) and report description rewrites. Descriptions are
+//! small, so a plain LCS table is enough; very large inputs fall back to a single
+//! replace hunk instead of allocating a huge table.
+
+use std::fmt::Write;
+
+/// Unchanged lines shown around each change
+const CONTEXT_LINES: usize = 3;
+
+/// Largest LCS table (old lines x new lines) we are willing to build
+const MAX_LCS_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Unified diff from `before` to `after`; empty when the texts have the same lines
+pub fn unified_diff(before: &str, after: &str) -> String {
+    let old: Vec<&str> = before.lines().collect();
+    let new: Vec<&str> = after.lines().collect();
+    let ops = diff_ops(&old, &new);
+
+    // Group changes (plus context) into hunks, merging ones that touch
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for (i, _) in ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (op, _))| *op != Op::Equal)
+    {
+        let start = i.saturating_sub(CONTEXT_LINES);
+        let end = (i + 1 + CONTEXT_LINES).min(ops.len());
+        match hunks.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    if hunks.is_empty() {
+        return String::new();
+    }
+
+    // Lines of each side consumed before op i
+    let mut old_before = Vec::with_capacity(ops.len());
+    let mut new_before = Vec::with_capacity(ops.len());
+    let (mut old_seen, mut new_seen) = (0, 0);
+    for (op, _) in &ops {
+        old_before.push(old_seen);
+        new_before.push(new_seen);
+        match op {
+            Op::Equal => {
+                old_seen += 1;
+                new_seen += 1;
+            }
+            Op::Delete => old_seen += 1,
+            Op::Insert => new_seen += 1,
+        }
+    }
+
+    let mut out = String::from("--- before\n+++ after\n");
+    for (start, end) in hunks {
+        let hunk = &ops[start..end];
+        let old_count = hunk.iter().filter(|(op, _)| *op != Op::Insert).count();
+        let new_count = hunk.iter().filter(|(op, _)| *op != Op::Delete).count();
+        // Unified diff ranges are 1-based, except that an empty range names the line before it
+        let old_start = old_before[start] + usize::from(old_count > 0);
+        let new_start = new_before[start] + usize::from(new_count > 0);
+
+        let _ = writeln!(
+            out,
+            "@@ -{},{} +{},{} @@",
+            old_start, old_count, new_start, new_count
+        );
+        for (op, line) in hunk {
+            let marker = match op {
+                Op::Equal => ' ',
+                Op::Delete => '-',
+                Op::Insert => '+',
+            };
+            let _ = writeln!(out, "{}{}", marker, line);
+        }
+    }
+
+    out
+}
+
+fn diff_ops<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(Op, &'a str)> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let mut ops: Vec<(Op, &str)> = old[..prefix].iter().map(|l| (Op::Equal, *l)).collect();
+
+    if old_mid.len() * new_mid.len() > MAX_LCS_CELLS {
+        ops.extend(old_mid.iter().map(|l| (Op::Delete, *l)));
+        ops.extend(new_mid.iter().map(|l| (Op::Insert, *l)));
+    } else {
+        // lcs[i * width + j] = LCS length of old_mid[i..] and new_mid[j..]
+        let (n, m) = (old_mid.len(), new_mid.len());
+        let width = m + 1;
+        let mut lcs = vec![0u32; (n + 1) * width];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i * width + j] = if old_mid[i] == new_mid[j] {
+                    lcs[(i + 1) * width + j + 1] + 1
+                } else {
+                    lcs[(i + 1) * width + j].max(lcs[i * width + j + 1])
+                };
+            }
+        }
+
+        let (mut i, mut j) = (0, 0);
+        while i < n && j < m {
+            if old_mid[i] == new_mid[j] {
+                ops.push((Op::Equal, old_mid[i]));
+                i += 1;
+                j += 1;
+            } else if lcs[(i + 1) * width + j] >= lcs[i * width + j + 1] {
+                ops.push((Op::Delete, old_mid[i]));
+                i += 1;
+            } else {
+                ops.push((Op::Insert, new_mid[j]));
+                j += 1;
+            }
+        }
+        ops.extend(old_mid[i..].iter().map(|l| (Op::Delete, *l)));
+        ops.extend(new_mid[j..].iter().map(|l| (Op::Insert, *l)));
+    }
+
+    ops.extend(old[old.len() - suffix..].iter().map(|l| (Op::Equal, *l)));
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identical_texts_have_no_diff() {
+        assert_eq!(unified_diff("a\nb", "a\nb\n"), "");
+        assert_eq!(unified_diff("", ""), "");
+    }
+
+    #[test]
+    fn test_checkbox_change() {
+        let before = "Intro\n\n- [ ] one\n- [ ] two\n- [ ] three";
+        let after = "Intro\n\n- [ ] one\n- [x] two\n- [ ] three";
+        assert_eq!(
+            unified_diff(before, after),
+            "--- before\n+++ after\n@@ -1,5 +1,5 @@\n Intro\n \n - [ ] one\n-- [ ] two\n+- [x] two\n - [ ] three\n"
+        );
+    }
+
+    #[test]
+    fn test_separate_hunks_and_empty_side() {
+        let before: Vec<String> = (1..=20).map(|i| format!("line {}", i)).collect();
+        let mut after = before.clone();
+        after[1] = "changed 2".to_string();
+        after[17] = "changed 18".to_string();
+
+        let diff = unified_diff(&before.join("\n"), &after.join("\n"));
+        assert!(diff.contains("@@ -1,5 +1,5 @@"));
+        assert!(diff.contains("@@ -15,6 +15,6 @@"));
+
+        // Adding text to an empty description
+        assert_eq!(
+            unified_diff("", "- [ ] first"),
+            "--- before\n+++ after\n@@ -0,0 +1,1 @@\n+- [ ] first\n"
+        );
+    }
+}
@@ -0,0 +1,88 @@
+//! Length limits of descriptions and comments written to JIRA
+//!
+//! JIRA rejects text fields longer than 32,767 characters, but only after the
+//! whole payload was uploaded. Writes check `[write_limits]` before sending and
+//! fail with the actual and allowed size, or, when the caller passed
+//! `auto_truncate`, cut the text and end it with a marker saying how much was
+//! dropped.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use std::borrow::Cow;
+
+/// Marker ending a truncated text
+pub fn truncation_marker(removed_chars: usize) -> String {
+    format!(
+        "\n\n[… {} characters truncated to fit the length limit]",
+        removed_chars
+    )
+}
+
+/// Error for a text over its limit, naming both sizes
+pub fn too_long(parameter: &str, what: &str, chars: usize, max_chars: usize) -> JiraMcpError {
+    JiraMcpError::invalid_param(
+        parameter,
+        format!(
+            "The {} would be {} characters, more than the {} allowed; shorten it or pass \
+             auto_truncate: true to cut it",
+            what, chars, max_chars
+        ),
+    )
+}
+
+/// Fit `text` into `max_chars` characters
+///
+/// Returns the text to write and, when it was cut, the number of characters
+/// removed. Without `auto_truncate` an oversized text is an error.
+pub fn fit_text<'a>(
+    text: &'a str,
+    max_chars: usize,
+    auto_truncate: bool,
+    parameter: &str,
+    what: &str,
+) -> JiraMcpResult<(Cow<'a, str>, Option<usize>)> {
+    let chars = text.chars().count();
+    if chars <= max_chars {
+        return Ok((Cow::Borrowed(text), None));
+    }
+    if !auto_truncate {
+        return Err(too_long(parameter, what, chars, max_chars));
+    }
+
+    // The marker for the full length is at least as long as the final one
+    let kept = max_chars.saturating_sub(truncation_marker(chars).chars().count());
+    let removed = chars - kept;
+    let mut truncated: String = text.chars().take(kept).collect();
+    truncated.push_str(&truncation_marker(removed));
+    Ok((Cow::Owned(truncated), Some(removed)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fit_text_within_limit() {
+        let (text, removed) = fit_text("short", 10, false, "content", "description").unwrap();
+        assert_eq!(text, "short");
+        assert_eq!(removed, None);
+    }
+
+    #[test]
+    fn test_fit_text_rejects_oversized_text() {
+        let error = fit_text(&"x".repeat(500), 100, false, "content", "description").unwrap_err();
+        let message = error.to_string();
+        assert!(message.contains("500 characters"), "{}", message);
+        assert!(message.contains("100 allowed"), "{}", message);
+    }
+
+    #[test]
+    fn test_fit_text_truncates_with_marker() {
+        let text = "ü".repeat(500);
+        let (fitted, removed) = fit_text(&text, 100, true, "content", "description").unwrap();
+        let removed = removed.unwrap();
+
+        assert!(fitted.chars().count() <= 100);
+        assert!(fitted.ends_with(&truncation_marker(removed)));
+        assert_eq!(fitted.chars().filter(|c| *c == 'ü').count() + removed, 500);
+    }
+}
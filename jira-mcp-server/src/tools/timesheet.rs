@@ -0,0 +1,385 @@
+//! Timesheet of the current user
+//!
+//! Answers "what did I log today?": finds the issues the current user logged
+//! work on in a date window, reads their worklogs and totals the user's own
+//! entries per issue and per day. Days follow the configured `timezone`, so a
+//! worklog started at 23:30 UTC counts for the next day at "+02:00".
+
+use crate::config::JiraConfig;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::{JiraClient, WorklogInfo};
+use crate::jql;
+use crate::semantic_mapping::resolve_date;
+use chrono::{Days, FixedOffset, NaiveDate, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tracing::{info, instrument, warn};
+
+/// Issues whose worklogs are read
+const MAX_TIMESHEET_ISSUES: usize = 200;
+
+/// Worklog fetches in flight at once
+const MAX_CONCURRENT_FETCHES: usize = 5;
+
+/// How the timesheet totals are grouped
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TimesheetGroupBy {
+    /// One group per issue, most time first, broken down by day
+    #[default]
+    Issue,
+    /// One group per day, in order, broken down by issue
+    Day,
+}
+
+/// Parameters for the get_my_timesheet tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct GetMyTimesheetParams {
+    /// First day of the timesheet (optional, default: "today")
+    /// Examples: "today", "yesterday", "7 days ago", "2024-01-01"
+    pub since: Option<String>,
+
+    /// Last day of the timesheet, inclusive (optional, default: today)
+    pub until: Option<String>,
+
+    /// "issue" or "day" (default: "issue")
+    #[serde(default)]
+    pub group_by: TimesheetGroupBy,
+}
+
+/// Logged time of one issue or one day
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TimesheetGroup {
+    /// Issue key, or the date as YYYY-MM-DD
+    pub key: String,
+
+    /// Summary of the issue, for groups by issue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+
+    pub time_spent_seconds: u64,
+
+    /// Logged time in JIRA notation, e.g. "2h 30m"
+    pub time_spent: String,
+
+    /// Seconds per day of an issue, or per issue of a day
+    pub breakdown: BTreeMap<String, u64>,
+}
+
+/// Result from the get_my_timesheet tool
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetMyTimesheetResult {
+    /// Display name of the current user
+    pub user: String,
+
+    /// First and last day of the timesheet
+    pub since: String,
+    pub until: String,
+
+    /// Offset the days are counted in, e.g. "+02:00"
+    pub timezone: String,
+
+    pub group_by: TimesheetGroupBy,
+
+    /// Totals per issue or per day, following group_by
+    pub groups: Vec<TimesheetGroup>,
+
+    /// Seconds logged over the whole timesheet
+    pub total_seconds: u64,
+
+    /// total_seconds in JIRA notation
+    pub total_time_spent: String,
+
+    /// Number of worklogs counted
+    pub worklog_count: usize,
+
+    /// Issues whose worklogs could not be read, and a capped search
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// One worklog of the timesheet
+#[derive(Debug, Clone, PartialEq)]
+struct TimesheetEntry {
+    issue_key: String,
+    day: NaiveDate,
+    seconds: u64,
+}
+
+/// Implementation of the get_my_timesheet tool
+pub struct GetMyTimesheetTool {
+    jira_client: Arc<JiraClient>,
+    config: Arc<JiraConfig>,
+}
+
+impl GetMyTimesheetTool {
+    pub fn new(jira_client: Arc<JiraClient>, config: Arc<JiraConfig>) -> Self {
+        Self {
+            jira_client,
+            config,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: GetMyTimesheetParams,
+    ) -> JiraMcpResult<GetMyTimesheetResult> {
+        let offset = self.config.utc_offset();
+        let today = Utc::now().with_timezone(&offset).date_naive();
+        let since = resolve_date(params.since.as_deref().unwrap_or("today"), today, "since")?;
+        let until = match params.until.as_deref() {
+            Some(until) => resolve_date(until, today, "until")?,
+            None => today,
+        };
+        if since > until {
+            return Err(JiraMcpError::invalid_param(
+                "since",
+                format!("since ({}) is after until ({})", since, until),
+            ));
+        }
+
+        // worklogDate follows the JIRA profile's time zone; pad a day on each
+        // side and cut the window exactly once the worklogs are read
+        let day = |date: NaiveDate| date.format("%Y-%m-%d").to_string();
+        let padded_since = since.checked_sub_days(Days::new(1)).unwrap_or(since);
+        let padded_until = until.checked_add_days(Days::new(1)).unwrap_or(until);
+        let jql = format!(
+            "{} = currentUser() AND {} >= {} AND {} <= {} ORDER BY updated DESC",
+            jql::WORKLOG_AUTHOR,
+            jql::WORKLOG_DATE,
+            jql::quote(&day(padded_since)),
+            jql::WORKLOG_DATE,
+            jql::quote(&day(padded_until))
+        );
+        let (user, search) = tokio::join!(
+            self.jira_client.get_current_user(),
+            self.jira_client
+                .search_all_issues_jql(&jql, MAX_TIMESHEET_ISSUES),
+        );
+        let (user, search) = (user?, search?);
+
+        let mut warnings = Vec::new();
+        if !search.complete {
+            warnings.push(format!(
+                "Only the worklogs of the {} most recently updated issues were read",
+                MAX_TIMESHEET_ISSUES
+            ));
+        }
+
+        let summaries: HashMap<String, String> = search
+            .issues
+            .iter()
+            .map(|issue| (issue.key.clone(), issue.summary.clone()))
+            .collect();
+        let mut entries = Vec::new();
+        let mut pending = search.issues.into_iter().map(|issue| issue.key);
+        let mut join_set = JoinSet::new();
+        loop {
+            while join_set.len() < MAX_CONCURRENT_FETCHES {
+                let Some(key) = pending.next() else {
+                    break;
+                };
+                let jira_client = Arc::clone(&self.jira_client);
+                join_set.spawn(async move {
+                    let worklogs = jira_client.get_worklogs(&key).await;
+                    (key, worklogs)
+                });
+            }
+
+            let Some(joined) = join_set.join_next().await else {
+                break;
+            };
+            let (key, worklogs) = joined
+                .map_err(|e| JiraMcpError::internal(format!("Worklog task failed: {}", e)))?;
+            match worklogs {
+                Ok(worklogs) => entries.extend(timesheet_entries(
+                    &key,
+                    &worklogs,
+                    &user.display_name,
+                    offset,
+                    since,
+                    until,
+                )),
+                Err(e) => {
+                    warn!("Could not read worklogs of {}: {}", key, e);
+                    warnings.push(format!("{}: {}", key, e));
+                }
+            }
+        }
+
+        let total_seconds = entries.iter().map(|entry| entry.seconds).sum();
+        let worklog_count = entries.len();
+        let groups = group_entries(&entries, &summaries, params.group_by);
+
+        info!(
+            "Timesheet of {} from {} to {}: {}s in {} worklogs",
+            user.display_name, since, until, total_seconds, worklog_count
+        );
+
+        Ok(GetMyTimesheetResult {
+            user: user.display_name,
+            since: day(since),
+            until: day(until),
+            timezone: offset.to_string(),
+            group_by: params.group_by,
+            groups,
+            total_seconds,
+            total_time_spent: jira_duration(total_seconds),
+            worklog_count,
+            warnings,
+        })
+    }
+}
+
+/// Worklogs of `author` on an issue that started between `since` and `until`,
+/// both inclusive, counting days in `offset`
+fn timesheet_entries(
+    issue_key: &str,
+    worklogs: &[WorklogInfo],
+    author: &str,
+    offset: FixedOffset,
+    since: NaiveDate,
+    until: NaiveDate,
+) -> Vec<TimesheetEntry> {
+    worklogs
+        .iter()
+        .filter(|worklog| worklog.author == author)
+        .filter_map(|worklog| {
+            let day = worklog.started_at()?.with_timezone(&offset).date_naive();
+            (since..=until).contains(&day).then(|| TimesheetEntry {
+                issue_key: issue_key.to_string(),
+                day,
+                seconds: worklog.time_spent_seconds.unwrap_or(0),
+            })
+        })
+        .collect()
+}
+
+/// Total the entries per issue (most time first) or per day (in order)
+fn group_entries(
+    entries: &[TimesheetEntry],
+    summaries: &HashMap<String, String>,
+    group_by: TimesheetGroupBy,
+) -> Vec<TimesheetGroup> {
+    let mut groups: BTreeMap<String, TimesheetGroup> = BTreeMap::new();
+    for entry in entries {
+        let day = entry.day.format("%Y-%m-%d").to_string();
+        let (key, other) = match group_by {
+            TimesheetGroupBy::Issue => (entry.issue_key.clone(), day),
+            TimesheetGroupBy::Day => (day, entry.issue_key.clone()),
+        };
+        let group = groups.entry(key.clone()).or_insert_with(|| TimesheetGroup {
+            key,
+            summary: match group_by {
+                TimesheetGroupBy::Issue => summaries.get(&entry.issue_key).cloned(),
+                TimesheetGroupBy::Day => None,
+            },
+            time_spent_seconds: 0,
+            time_spent: String::new(),
+            breakdown: BTreeMap::new(),
+        });
+        group.time_spent_seconds += entry.seconds;
+        *group.breakdown.entry(other).or_default() += entry.seconds;
+    }
+
+    let mut groups: Vec<TimesheetGroup> = groups
+        .into_values()
+        .map(|mut group| {
+            group.time_spent = jira_duration(group.time_spent_seconds);
+            group
+        })
+        .collect();
+    if group_by == TimesheetGroupBy::Issue {
+        groups.sort_by_key(|group| Reverse(group.time_spent_seconds));
+    }
+    groups
+}
+
+/// Seconds in JIRA notation; unlike worklog input, zero stays zero
+fn jira_duration(seconds: u64) -> String {
+    if seconds == 0 {
+        "0m".to_string()
+    } else {
+        JiraClient::format_duration_jira(seconds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn worklog(author: &str, started: &str, seconds: u64) -> WorklogInfo {
+        WorklogInfo {
+            id: "1".to_string(),
+            author: author.to_string(),
+            comment: None,
+            created: started.to_string(),
+            updated: started.to_string(),
+            started: started.to_string(),
+            time_spent: None,
+            time_spent_seconds: Some(seconds),
+        }
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_entries_follow_the_configured_offset() {
+        let worklogs = vec![
+            worklog("Me", "2026-01-05T23:30:00.000+0000", 1800),
+            worklog("Me", "2026-01-06 22:30:00.0 +00:00:00", 3600),
+            worklog("Someone Else", "2026-01-06T10:00:00.000+0000", 7200),
+        ];
+
+        // In UTC only the second one falls on the 6th
+        let utc = FixedOffset::east_opt(0).unwrap();
+        let day = date("2026-01-06");
+        let entries = timesheet_entries("PROJ-1", &worklogs, "Me", utc, day, day);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].seconds, 3600);
+
+        // Two hours ahead, the first moves onto the 6th and the second off it
+        let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+        let entries = timesheet_entries("PROJ-1", &worklogs, "Me", cest, day, day);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].seconds, 1800);
+    }
+
+    #[test]
+    fn test_group_entries() {
+        let entry = |issue_key: &str, day: &str, seconds| TimesheetEntry {
+            issue_key: issue_key.to_string(),
+            day: date(day),
+            seconds,
+        };
+        let entries = vec![
+            entry("PROJ-1", "2026-01-05", 1800),
+            entry("PROJ-2", "2026-01-05", 7200),
+            entry("PROJ-1", "2026-01-06", 3600),
+        ];
+        let summaries = HashMap::from([("PROJ-2".to_string(), "Fix login".to_string())]);
+
+        let by_issue = group_entries(&entries, &summaries, TimesheetGroupBy::Issue);
+        assert_eq!(by_issue[0].key, "PROJ-2");
+        assert_eq!(by_issue[0].summary.as_deref(), Some("Fix login"));
+        assert_eq!(by_issue[0].time_spent, "2h");
+        assert_eq!(by_issue[1].time_spent_seconds, 5400);
+        assert_eq!(by_issue[1].time_spent, "1h 30m");
+        assert_eq!(by_issue[1].breakdown["2026-01-06"], 3600);
+
+        let by_day = group_entries(&entries, &summaries, TimesheetGroupBy::Day);
+        assert_eq!(by_day[0].key, "2026-01-05");
+        assert_eq!(by_day[0].time_spent_seconds, 9000);
+        assert_eq!(by_day[0].breakdown["PROJ-2"], 7200);
+        assert!(by_day[0].summary.is_none());
+        assert_eq!(jira_duration(0), "0m");
+    }
+}
@@ -0,0 +1,480 @@
+//! Storage backends of the todo tools
+//!
+//! By default todos are markdown checkboxes in the issue description. Instances
+//! running the "Checklist for Jira" app keep them in a custom field instead: an
+//! array of items with `name`, `checked`, `rank`, `isHeader` and, once saved by
+//! the app, a stable `id`. A [`TodoBackend`] reads an issue's todos and writes
+//! changes back, so list_todos, add_todo, update_todo and the work-session tools
+//! behave the same whichever storage an issue uses.
+//!
+//! The backend is picked per issue by `[todos]` in the config: `description`,
+//! `checklist_field`, or `auto` (the default), which uses the checklist field
+//! when `field_mappings.checklist` is set and the issue has a value in it.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::todo_tracker::{TodoItem, TodoStatus, TodoTracker};
+use crate::undo::{FieldChange, UndoHistory};
+use async_trait::async_trait;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// The todos of one issue as read from a backend
+#[derive(Debug, Clone)]
+pub struct TodoSnapshot {
+    /// Todos in stored order, without work-session status
+    pub todos: Vec<TodoItem>,
+
+    /// Text form of the stored todos, for results and diffs: the description,
+    /// or the checklist written as markdown checkboxes
+    pub content: String,
+
+    /// The issue's `updated` value when read, for the concurrency check on write
+    pub updated: String,
+
+    /// The stored value as JIRA returned it
+    pub stored: Value,
+
+    /// False when the backend's field holds nothing (auto selection then falls
+    /// back to the description)
+    pub is_set: bool,
+}
+
+/// A change computed from a snapshot, not yet written
+#[derive(Debug, Clone)]
+pub struct TodoEdit {
+    /// The todos after the change
+    pub todos: Vec<TodoItem>,
+
+    /// Text form after the change (see [`TodoSnapshot::content`])
+    pub content: String,
+
+    /// The value to store
+    pub stored: Value,
+}
+
+/// Where the todos of an issue are stored
+#[async_trait]
+pub trait TodoBackend: Send + Sync {
+    /// Name reported in results: "description" or "checklist_field"
+    fn name(&self) -> &'static str;
+
+    /// Read the todos of an issue
+    async fn read(&self, issue_key: &str) -> JiraMcpResult<TodoSnapshot>;
+
+    /// The snapshot with a new open todo, first or last
+    fn add(&self, snapshot: &TodoSnapshot, text: &str, prepend: bool) -> TodoEdit;
+
+    /// The snapshot with `todo` checked or unchecked
+    fn set_completed(&self, snapshot: &TodoSnapshot, todo: &TodoItem, completed: bool) -> TodoEdit;
+
+    /// Store an edit; with `expected_updated`, nothing is written if the issue
+    /// changed since it was read
+    async fn write(
+        &self,
+        issue_key: &str,
+        snapshot: &TodoSnapshot,
+        edit: &TodoEdit,
+        expected_updated: Option<&str>,
+    ) -> JiraMcpResult<()>;
+}
+
+/// Markdown checkboxes (`- [ ] text`) in the issue description
+pub struct DescriptionMarkdown {
+    jira_client: Arc<JiraClient>,
+    undo_history: Arc<UndoHistory>,
+}
+
+impl DescriptionMarkdown {
+    pub fn new(jira_client: Arc<JiraClient>, undo_history: Arc<UndoHistory>) -> Self {
+        Self {
+            jira_client,
+            undo_history,
+        }
+    }
+
+    fn edit(description: String) -> TodoEdit {
+        TodoEdit {
+            todos: TodoTracker::parse_todos(&description),
+            stored: Value::String(description.clone()),
+            content: description,
+        }
+    }
+}
+
+#[async_trait]
+impl TodoBackend for DescriptionMarkdown {
+    fn name(&self) -> &'static str {
+        "description"
+    }
+
+    async fn read(&self, issue_key: &str) -> JiraMcpResult<TodoSnapshot> {
+        // Raw, not the redacted IssueInfo: the description is written back whole
+        let issue = self.jira_client.get_raw_issue(issue_key).await?;
+        let description = issue.description().unwrap_or_default();
+
+        Ok(TodoSnapshot {
+            todos: TodoTracker::parse_todos(&description),
+            stored: Value::String(description.clone()),
+            content: description,
+            updated: issue
+                .fields
+                .get("updated")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            is_set: true,
+        })
+    }
+
+    fn add(&self, snapshot: &TodoSnapshot, text: &str, prepend: bool) -> TodoEdit {
+        Self::edit(TodoTracker::add_todo_to_description(
+            &snapshot.content,
+            text,
+            prepend,
+        ))
+    }
+
+    fn set_completed(&self, snapshot: &TodoSnapshot, todo: &TodoItem, completed: bool) -> TodoEdit {
+        Self::edit(TodoTracker::update_todo_status(
+            &snapshot.content,
+            todo.line_number,
+            completed,
+        ))
+    }
+
+    async fn write(
+        &self,
+        issue_key: &str,
+        _snapshot: &TodoSnapshot,
+        edit: &TodoEdit,
+        expected_updated: Option<&str>,
+    ) -> JiraMcpResult<()> {
+        use crate::tools::update_description::{
+            UpdateDescription, UpdateDescriptionParams, UpdateMode,
+        };
+
+        let updater = UpdateDescription::new(self.jira_client.clone(), self.undo_history.clone());
+        updater
+            .execute_with_source(
+                UpdateDescriptionParams {
+                    issue_key: issue_key.to_string(),
+                    content: edit.content.clone(),
+                    mode: UpdateMode::Replace,
+                    separator: Default::default(),
+                    dry_run: false,
+                    include_diff: false,
+                    expected_updated: expected_updated.map(String::from),
+                    // A cut checklist would lose todos, so fail instead
+                    auto_truncate: false,
+                },
+                "todo_tracker",
+            )
+            .await?;
+
+        Ok(())
+    }
+}
+
+/// The custom field of the "Checklist for Jira" app
+pub struct ChecklistField {
+    jira_client: Arc<JiraClient>,
+    undo_history: Arc<UndoHistory>,
+    field_id: String,
+}
+
+impl ChecklistField {
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        undo_history: Arc<UndoHistory>,
+        field_id: impl Into<String>,
+    ) -> Self {
+        Self {
+            jira_client,
+            undo_history,
+            field_id: field_id.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl TodoBackend for ChecklistField {
+    fn name(&self) -> &'static str {
+        "checklist_field"
+    }
+
+    async fn read(&self, issue_key: &str) -> JiraMcpResult<TodoSnapshot> {
+        let fields = self
+            .jira_client
+            .get_issue_fields(issue_key, &[self.field_id.clone(), "updated".to_string()])
+            .await?;
+        let stored = fields.get(&self.field_id).cloned().unwrap_or(Value::Null);
+        let items = checklist_items(&stored)?;
+
+        Ok(TodoSnapshot {
+            todos: checklist_todos(&items),
+            content: checklist_text(&items),
+            updated: fields
+                .get("updated")
+                .and_then(Value::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            is_set: !stored.is_null(),
+            stored,
+        })
+    }
+
+    fn add(&self, snapshot: &TodoSnapshot, text: &str, prepend: bool) -> TodoEdit {
+        checklist_add(&snapshot.stored, text, prepend)
+    }
+
+    fn set_completed(&self, snapshot: &TodoSnapshot, todo: &TodoItem, completed: bool) -> TodoEdit {
+        checklist_set_checked(&snapshot.stored, todo.line_number, completed)
+    }
+
+    async fn write(
+        &self,
+        issue_key: &str,
+        snapshot: &TodoSnapshot,
+        edit: &TodoEdit,
+        expected_updated: Option<&str>,
+    ) -> JiraMcpResult<()> {
+        if let Some(expected) = expected_updated.filter(|expected| !expected.is_empty()) {
+            let fields = self
+                .jira_client
+                .get_issue_fields(issue_key, &["updated".to_string()])
+                .await?;
+            let updated = fields
+                .get("updated")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            if updated != expected {
+                return Err(JiraMcpError::invalid_param(
+                    "issue_key",
+                    format!(
+                        "{} was modified at {} (expected {}); list the todos again and retry",
+                        issue_key, updated, expected
+                    ),
+                ));
+            }
+        }
+
+        let endpoint = format!("/issue/{}", issue_key);
+        self.jira_client
+            .put_json::<Value>(
+                &format!("writing the todos of {}", issue_key),
+                "api",
+                &endpoint,
+                json!({ "fields": { &self.field_id: &edit.stored } }),
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("issue", issue_key)
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        self.undo_history.record(
+            issue_key,
+            "todo_tracker",
+            BTreeMap::from([(
+                self.field_id.clone(),
+                FieldChange {
+                    previous: snapshot.stored.clone(),
+                    written: edit.stored.clone(),
+                },
+            )]),
+        );
+        Ok(())
+    }
+}
+
+/// Items of a checklist field value: an array, or the same array as a JSON
+/// string; an empty field has no items
+fn checklist_items(value: &Value) -> JiraMcpResult<Vec<Value>> {
+    let unreadable = || {
+        JiraMcpError::internal(
+            "The checklist field does not hold a list of checklist items; check \
+             field_mappings.checklist",
+        )
+    };
+    match value {
+        Value::Null => Ok(Vec::new()),
+        Value::Array(items) => Ok(items.clone()),
+        Value::String(text) if text.trim().is_empty() => Ok(Vec::new()),
+        Value::String(text) => serde_json::from_str(text).map_err(|_| unreadable()),
+        _ => Err(unreadable()),
+    }
+}
+
+/// Todos of checklist items; headers become sections, and `line_number` is the
+/// position in the item list
+fn checklist_todos(items: &[Value]) -> Vec<TodoItem> {
+    let mut todos: Vec<TodoItem> = Vec::new();
+    let mut section = None;
+
+    for (position, item) in items.iter().enumerate() {
+        let name = item
+            .get("name")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        if item.get("isHeader").and_then(Value::as_bool) == Some(true) {
+            section = Some(name).filter(|name| !name.is_empty());
+            continue;
+        }
+        if name.is_empty() {
+            continue;
+        }
+
+        // Saved items carry the app's own ID, which survives renames and moves
+        let id = match item.get("id") {
+            Some(Value::Number(id)) => format!("checklist-{}", id),
+            Some(Value::String(id)) if !id.is_empty() => format!("checklist-{}", id),
+            _ => TodoTracker::generate_todo_id(&todos, &name),
+        };
+        let completed = item.get("checked").and_then(Value::as_bool) == Some(true);
+        todos.push(TodoItem {
+            text: name,
+            completed,
+            status: if completed {
+                TodoStatus::Completed
+            } else {
+                TodoStatus::Open
+            },
+            line_number: position,
+            id,
+            section: section.clone(),
+        });
+    }
+
+    todos
+}
+
+/// Checklist items as markdown: headers as `## Name`, items as checkboxes
+fn checklist_text(items: &[Value]) -> String {
+    items
+        .iter()
+        .filter_map(|item| {
+            let name = item.get("name").and_then(Value::as_str)?.trim();
+            if item.get("isHeader").and_then(Value::as_bool) == Some(true) {
+                return Some(format!("## {}", name));
+            }
+            let mark = if item.get("checked").and_then(Value::as_bool) == Some(true) {
+                "x"
+            } else {
+                " "
+            };
+            Some(format!("- [{}] {}", mark, name))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The checklist with a new unchecked item, first or last
+fn checklist_add(stored: &Value, text: &str, prepend: bool) -> TodoEdit {
+    let mut items = checklist_items(stored).unwrap_or_default();
+    let item = json!({ "name": text, "checked": false, "mandatory": false });
+    if prepend {
+        items.insert(0, item);
+    } else {
+        items.push(item);
+    }
+    checklist_edit(stored, items)
+}
+
+/// The checklist with the item at `position` checked or unchecked
+fn checklist_set_checked(stored: &Value, position: usize, checked: bool) -> TodoEdit {
+    let mut items = checklist_items(stored).unwrap_or_default();
+    if let Some(item) = items.get_mut(position).and_then(Value::as_object_mut) {
+        item.insert("checked".to_string(), Value::Bool(checked));
+    }
+    checklist_edit(stored, items)
+}
+
+/// An edit storing `items` in the shape of `previous`, with ranks renumbered
+/// to the item order
+fn checklist_edit(previous: &Value, mut items: Vec<Value>) -> TodoEdit {
+    for (rank, item) in items.iter_mut().enumerate() {
+        if let Some(item) = item.as_object_mut() {
+            item.insert("rank".to_string(), json!(rank));
+        }
+    }
+
+    let stored = if previous.is_string() {
+        Value::String(Value::Array(items.clone()).to_string())
+    } else {
+        Value::Array(items.clone())
+    };
+    TodoEdit {
+        todos: checklist_todos(&items),
+        content: checklist_text(&items),
+        stored,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Value {
+        json!([
+            {"id": 11, "name": "Backend", "checked": false, "isHeader": true, "rank": 0},
+            {"id": 12, "name": "Add retry budget", "checked": true, "mandatory": true, "rank": 1},
+            {"name": "Document the limit", "checked": false, "rank": 2},
+            {"name": "Document the limit", "checked": false, "rank": 3}
+        ])
+    }
+
+    #[test]
+    fn test_checklist_todos() {
+        let todos = checklist_todos(&checklist_items(&items()).unwrap());
+        assert_eq!(todos.len(), 3);
+        assert_eq!(todos[0].id, "checklist-12");
+        assert!(todos[0].completed);
+        assert_eq!(todos[0].section.as_deref(), Some("Backend"));
+        assert_eq!(todos[0].line_number, 1);
+        // Unsaved items fall back to content IDs, unique among duplicates
+        assert!(todos[1].id.starts_with("todo-"));
+        assert_ne!(todos[1].id, todos[2].id);
+
+        // The app may hand the list over as a JSON string
+        let as_string = Value::String(items().to_string());
+        assert_eq!(
+            checklist_todos(&checklist_items(&as_string).unwrap()).len(),
+            3
+        );
+        assert!(checklist_items(&Value::Null).unwrap().is_empty());
+        assert!(checklist_items(&json!({"name": "x"})).is_err());
+    }
+
+    #[test]
+    fn test_checklist_edits() {
+        let edit = checklist_add(&items(), "Load test", false);
+        assert_eq!(edit.todos.last().unwrap().text, "Load test");
+        assert_eq!(edit.stored[4]["rank"], 4);
+        // Other keys of existing items are kept
+        assert_eq!(edit.stored[1]["mandatory"], true);
+        assert_eq!(
+            edit.content,
+            "## Backend\n- [x] Add retry budget\n- [ ] Document the limit\n\
+             - [ ] Document the limit\n- [ ] Load test"
+        );
+
+        // A list read as a JSON string is written back as one
+        let edit = checklist_add(&Value::String(items().to_string()), "Load test", true);
+        let stored: Value = serde_json::from_str(edit.stored.as_str().unwrap()).unwrap();
+        assert_eq!(stored[0]["name"], "Load test");
+        assert_eq!(stored[1]["rank"], 1);
+        assert_eq!(edit.todos[0].text, "Load test");
+
+        let edit = checklist_set_checked(&items(), 1, false);
+        assert!(!edit.todos[0].completed);
+        assert_eq!(edit.todos[0].id, "checklist-12");
+        assert_eq!(edit.stored[1]["id"], 12);
+    }
+}
@@ -1,24 +1,47 @@
 //! Todo tracking tool for managing todos in issue descriptions with time tracking
 //!
 //! This module provides comprehensive todo management:
-//! - Extract todos from issue descriptions (markdown checkbox format)
+//! - Extract todos from issue descriptions (markdown checkbox format), or from
+//!   a "Checklist for Jira" field (see `todo_backend`)
 //! - Add new todos to descriptions
 //! - Update todo status (complete/incomplete)
 //! - Track time spent on todos
 //! - Log work time to JIRA worklogs
+//!
+//! Active work sessions are saved to the state directory on shutdown and
+//! restored on the next start.
 
 use crate::cache::MetadataCache;
-use crate::config::JiraConfig;
+use crate::clock::{Clock, SystemClock};
+use crate::config::{JiraConfig, SessionOnDone, TodoBackendKind, CHECKLIST_FIELD};
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::{JiraClient, WorklogInfo};
-use chrono::{DateTime, Utc};
+use crate::messages::message;
+use crate::metrics::{impl_measured, ToolPerformance};
+use crate::tools::issue_key::normalize_issue_key;
+use crate::tools::render::{todo_checklist, OutputFormat};
+use crate::tools::text_diff::unified_diff;
+use crate::tools::todo_backend::{ChecklistField, DescriptionMarkdown, TodoBackend, TodoSnapshot};
+use crate::undo::UndoHistory;
+use crate::work_history::{SessionOutcome, SessionRecord, WorkHistory};
+use chrono::{DateTime, Duration, Utc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{broadcast, RwLock};
 use tracing::{info, instrument, warn};
 
+/// Active work sessions saved on shutdown, in the state directory
+const WORK_SESSIONS_FILE: &str = "work_sessions.json";
+
+/// Todo ID of the session transition_issue keeps for a whole issue
+pub const ISSUE_SESSION_ID: &str = "issue";
+
+/// Events kept for subscribers that fall behind
+const EVENT_CAPACITY: usize = 16;
+
 /// Todo status for filtering
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq)]
 #[serde(rename_all = "lowercase")]
@@ -43,11 +66,17 @@ pub struct TodoItem {
     /// Current status (open, completed, or wip)
     pub status: TodoStatus,
 
-    /// Line number in the description (0-based)
+    /// Line number in the description, or position in the checklist field (0-based)
     pub line_number: usize,
 
-    /// Unique ID for tracking (generated from content hash)
+    /// Unique ID for tracking: the checklist item's own ID when the checklist
+    /// app provides one, else generated from a content hash
     pub id: String,
+
+    /// Nearest markdown heading above the todo (e.g. "Tasks" for "## Tasks"),
+    /// or checklist header
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
 }
 
 /// Parameters for setting the base issue
@@ -63,8 +92,12 @@ pub struct SetTodoBaseResult {
     /// The base issue key that was set
     pub base_issue_key: String,
 
-    /// Success message
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
+
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
 }
 
 /// Parameters for listing todos
@@ -79,6 +112,11 @@ pub struct ListTodosParams {
     /// If not provided, returns all todos
     #[serde(default)]
     pub status_filter: Option<Vec<TodoStatus>>,
+
+    /// Output format (optional, default: "json")
+    /// "markdown" adds `rendered`, the todos as a checklist under their section headings
+    #[serde(default)]
+    pub format: OutputFormat,
 }
 
 /// Result from listing todos
@@ -92,10 +130,20 @@ pub struct ListTodosResult {
 
     /// Issue key
     pub issue_key: String,
+
+    /// Where the todos are stored: "description" or "checklist_field"
+    pub backend: String,
+
+    /// Markdown checklist of the todos (only with format "markdown")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rendered: Option<String>,
+
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
 }
 
 /// Parameters for adding a new todo
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct AddTodoParams {
     /// The JIRA issue key (e.g., "PROJ-123")
     /// If not provided, uses the current base issue
@@ -108,6 +156,14 @@ pub struct AddTodoParams {
     /// Whether to add at the beginning or end of todos section (default: end)
     #[serde(default)]
     pub prepend: bool,
+
+    /// Preview only: return the new description and a diff without writing (default: false)
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Include a unified diff of the description change (default: false; always on for dry_run)
+    #[serde(default)]
+    pub include_diff: bool,
 }
 
 /// Result from adding a todo
@@ -116,15 +172,27 @@ pub struct AddTodoResult {
     /// The added todo
     pub todo: TodoItem,
 
-    /// Success message
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
 
-    /// Updated description
+    /// Updated description; for checklist field issues, the checklist as
+    /// markdown checkboxes
     pub updated_description: String,
+
+    /// True when nothing was written (dry_run)
+    pub dry_run: bool,
+
+    /// Unified diff of the description change (dry_run or include_diff)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
 }
 
 /// Parameters for updating a todo status
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct UpdateTodoParams {
     /// The JIRA issue key (e.g., "PROJ-123")
     /// If not provided, uses the current base issue
@@ -136,6 +204,14 @@ pub struct UpdateTodoParams {
 
     /// Whether to mark as completed
     pub completed: bool,
+
+    /// Preview only: return the diff without writing (default: false)
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Include a unified diff of the description change (default: false; always on for dry_run)
+    #[serde(default)]
+    pub include_diff: bool,
 }
 
 /// Result from updating a todo
@@ -144,12 +220,23 @@ pub struct UpdateTodoResult {
     /// The updated todo
     pub todo: TodoItem,
 
-    /// Success message
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
+
+    /// True when nothing was written (dry_run)
+    pub dry_run: bool,
+
+    /// Unified diff of the description change (dry_run or include_diff)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
 }
 
 /// Parameters for starting work on a todo
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct StartTodoWorkParams {
     /// The JIRA issue key (e.g., "PROJ-123")
     /// If not provided, uses the current base issue
@@ -158,6 +245,12 @@ pub struct StartTodoWorkParams {
 
     /// The todo ID or 1-based index
     pub todo_id_or_index: String,
+
+    /// Minutes to aim for, e.g. 25 for a pomodoro (optional)
+    /// get_active_work_sessions reports progress toward it, and passing it
+    /// raises a target_reached event
+    #[serde(default)]
+    pub target_minutes: Option<u64>,
 }
 
 /// Result from starting work
@@ -169,12 +262,20 @@ pub struct StartTodoWorkResult {
     /// When work started
     pub started_at: String,
 
-    /// Success message
+    /// Minutes aimed for, if a target was set
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_minutes: Option<u64>,
+
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
+
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
 }
 
 /// Parameters for completing work on a todo
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct CompleteTodoWorkParams {
     /// The JIRA issue key (e.g., "PROJ-123")
     /// If not provided, uses the current base issue
@@ -187,6 +288,10 @@ pub struct CompleteTodoWorkParams {
     /// Optional comment for the worklog entry
     pub worklog_comment: Option<String>,
 
+    /// Leave out the worklog_signature configured for the server (default: false)
+    #[serde(default)]
+    pub skip_signature: bool,
+
     /// Whether to mark the todo as completed (default: true)
     #[serde(default = "default_true")]
     pub mark_completed: bool,
@@ -209,7 +314,7 @@ fn default_true() -> bool {
 }
 
 /// Parameters for checkpointing work on a todo
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct CheckpointTodoWorkParams {
     /// The JIRA issue key (e.g., "PROJ-123")
     /// If not provided, uses the current base issue
@@ -221,6 +326,10 @@ pub struct CheckpointTodoWorkParams {
 
     /// Optional comment for the worklog entry
     pub worklog_comment: Option<String>,
+
+    /// Leave out the worklog_signature configured for the server (default: false)
+    #[serde(default)]
+    pub skip_signature: bool,
 }
 
 /// Result from checkpointing work
@@ -241,12 +350,16 @@ pub struct CheckpointTodoWorkResult {
     /// The created worklog
     pub worklog: WorklogInfo,
 
-    /// Success message
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
+
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
 }
 
 /// Parameters for pausing work on a todo
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct PauseTodoWorkParams {
     /// The JIRA issue key (e.g., "PROJ-123")
     /// If not provided, uses the current base issue
@@ -258,6 +371,10 @@ pub struct PauseTodoWorkParams {
 
     /// Optional comment for the worklog entry
     pub worklog_comment: Option<String>,
+
+    /// Leave out the worklog_signature configured for the server (default: false)
+    #[serde(default)]
+    pub skip_signature: bool,
 }
 
 /// Result from pausing work
@@ -275,12 +392,16 @@ pub struct PauseTodoWorkResult {
     /// The created worklog
     pub worklog: WorklogInfo,
 
-    /// Success message
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
+
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
 }
 
 /// Parameters for canceling work on a todo
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct CancelTodoWorkParams {
     /// The JIRA issue key (e.g., "PROJ-123")
     /// If not provided, uses the current base issue
@@ -300,8 +421,12 @@ pub struct CancelTodoWorkResult {
     /// Time that would have been logged (in seconds)
     pub discarded_time_seconds: u64,
 
-    /// Success message
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
+
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
 }
 
 /// Active work session information
@@ -324,6 +449,61 @@ pub struct ActiveWorkSession {
 
     /// Current duration formatted
     pub duration_formatted: String,
+
+    /// Progress toward target_minutes, if the session has a target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<SessionTarget>,
+}
+
+/// Time worked in a session against its target_minutes
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SessionTarget {
+    pub target_minutes: u64,
+
+    /// Time worked in the session, checkpointed time included
+    pub elapsed_seconds: u64,
+
+    /// Share of the target worked; above 100 once it is passed
+    pub progress_percent: u64,
+
+    /// Seconds still to go; 0 once the target is reached
+    pub remaining_seconds: u64,
+
+    /// Seconds worked past the target; 0 until it is reached
+    pub overshoot_seconds: u64,
+}
+
+impl SessionTarget {
+    fn new(target_minutes: u64, elapsed_seconds: u64) -> Self {
+        let target_seconds = target_minutes * 60;
+        Self {
+            target_minutes,
+            elapsed_seconds,
+            progress_percent: (elapsed_seconds * 100)
+                .checked_div(target_seconds)
+                .unwrap_or(100),
+            remaining_seconds: target_seconds.saturating_sub(elapsed_seconds),
+            overshoot_seconds: elapsed_seconds.saturating_sub(target_seconds),
+        }
+    }
+
+    /// Whether the session has worked its target
+    pub fn is_reached(&self) -> bool {
+        self.remaining_seconds == 0
+    }
+}
+
+/// What the todo tracker reports on its own, see [`TodoTracker::subscribe_events`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum WorkSessionEvent {
+    /// A session worked past its target_minutes; raised once per session
+    TargetReached {
+        issue_key: String,
+        todo_id: String,
+        todo_text: String,
+        target: SessionTarget,
+    },
 }
 
 /// Result from getting active work sessions
@@ -334,6 +514,9 @@ pub struct GetActiveWorkSessionsResult {
 
     /// Total number of active sessions
     pub total_count: usize,
+
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
 }
 
 /// Result from completing work
@@ -351,10 +534,61 @@ pub struct CompleteTodoWorkResult {
     /// The created worklog
     pub worklog: WorklogInfo,
 
-    /// Success message
+    /// Time worked against target_minutes, if the session had a target
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<SessionTarget>,
+
+    /// Success message; empty and omitted when `[messages] style = "none"`
+    #[serde(skip_serializing_if = "String::is_empty")]
     pub message: String,
+
+    /// Timing and request counts of the call
+    pub performance: ToolPerformance,
+}
+
+/// What a status change did to the issue work session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueSessionAction {
+    /// A new session started
+    Started,
+    /// A session was already running and keeps running
+    AlreadyActive,
+    /// The session ended and its time was logged
+    Completed,
+}
+
+/// The issue work session after a status change
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueSessionChange {
+    /// started, already_active or completed
+    pub action: IssueSessionAction,
+
+    /// When the session started
+    pub started_at: String,
+
+    /// Seconds logged when the session completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_logged_seconds: Option<u64>,
+
+    /// ID of the worklog created when the session completed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worklog_id: Option<String>,
 }
 
+impl_measured!(
+    SetTodoBaseResult,
+    ListTodosResult,
+    AddTodoResult,
+    UpdateTodoResult,
+    StartTodoWorkResult,
+    CheckpointTodoWorkResult,
+    PauseTodoWorkResult,
+    CancelTodoWorkResult,
+    GetActiveWorkSessionsResult,
+    CompleteTodoWorkResult,
+);
+
 /// Work tracking entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct WorkSession {
@@ -366,12 +600,31 @@ struct WorkSession {
     initial_worklog_id: Option<String>,
     /// Total time logged in previous checkpoints (seconds)
     accumulated_time: u64,
+    /// Minutes aimed for, set by start_todo_work
+    #[serde(default)]
+    target_minutes: Option<u64>,
+    /// Whether the target_reached event was raised
+    #[serde(default)]
+    target_notified: bool,
+}
+
+impl WorkSession {
+    /// Time worked by `now`: checkpointed time plus the current segment
+    fn elapsed_seconds(&self, now: DateTime<Utc>) -> u64 {
+        let segment = now.signed_duration_since(self.started_at).num_seconds();
+        self.accumulated_time + segment.max(0) as u64
+    }
+
+    /// Progress toward the target by `now`, if the session has one
+    fn target(&self, now: DateTime<Utc>) -> Option<SessionTarget> {
+        self.target_minutes
+            .map(|minutes| SessionTarget::new(minutes, self.elapsed_seconds(now)))
+    }
 }
 
 /// Todo tracker implementation
 pub struct TodoTracker {
     jira_client: Arc<JiraClient>,
-    #[allow(dead_code)]
     config: Arc<JiraConfig>,
     #[allow(dead_code)]
     cache: Arc<MetadataCache>,
@@ -379,6 +632,17 @@ pub struct TodoTracker {
     active_sessions: Arc<RwLock<HashMap<String, WorkSession>>>,
     // Base issue context
     base_issue: Arc<RwLock<Option<String>>>,
+    // Todos in the description, and in the checklist field when one is mapped
+    description_todos: DescriptionMarkdown,
+    checklist_todos: Option<ChecklistField>,
+    // Where active sessions are saved on shutdown
+    sessions_path: PathBuf,
+    // Events such as a session passing its target
+    events: broadcast::Sender<WorkSessionEvent>,
+    // Time source of all session math
+    clock: Arc<dyn Clock>,
+    // Where finished sessions are recorded
+    work_history: Arc<WorkHistory>,
 }
 
 impl TodoTracker {
@@ -386,22 +650,94 @@ impl TodoTracker {
         jira_client: Arc<JiraClient>,
         config: Arc<JiraConfig>,
         cache: Arc<MetadataCache>,
+        undo_history: Arc<UndoHistory>,
     ) -> Self {
+        let sessions_path = config.state_dir().join(WORK_SESSIONS_FILE);
+        let active_sessions = load_sessions(&sessions_path);
+        if !active_sessions.is_empty() {
+            info!("Restored {} active work sessions", active_sessions.len());
+        }
+
+        let checklist_todos = config
+            .field_mappings
+            .get(CHECKLIST_FIELD)
+            .filter(|field_id| !field_id.trim().is_empty())
+            .map(|field_id| {
+                ChecklistField::new(
+                    Arc::clone(&jira_client),
+                    Arc::clone(&undo_history),
+                    field_id.trim(),
+                )
+            });
+
         Self {
+            description_todos: DescriptionMarkdown::new(Arc::clone(&jira_client), undo_history),
+            checklist_todos,
             jira_client,
             config,
             cache,
-            active_sessions: Arc::new(RwLock::new(HashMap::new())),
+            active_sessions: Arc::new(RwLock::new(active_sessions)),
             base_issue: Arc::new(RwLock::new(None)),
+            sessions_path,
+            events: broadcast::channel(EVENT_CAPACITY).0,
+            clock: Arc::new(SystemClock),
+            work_history: Arc::new(WorkHistory::disabled()),
         }
     }
 
+    /// Read the time from `clock` instead of the system clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Record completed, paused and cancelled sessions in `work_history`
+    pub fn with_work_history(mut self, work_history: Arc<WorkHistory>) -> Self {
+        self.work_history = work_history;
+        self
+    }
+
+    /// Receive the events the tracker raises, e.g. to forward them as
+    /// server notifications; each event is also logged
+    pub fn subscribe_events(&self) -> broadcast::Receiver<WorkSessionEvent> {
+        self.events.subscribe()
+    }
+
+    /// Save the active work sessions, so the next start restores them
+    pub async fn persist_sessions(&self) -> JiraMcpResult<()> {
+        let sessions = self.active_sessions.read().await;
+        let content = serde_json::to_string(&*sessions)?;
+        let io_error = |e: std::io::Error| {
+            JiraMcpError::internal(format!(
+                "Failed to save work sessions to {}: {}",
+                self.sessions_path.display(),
+                e
+            ))
+        };
+
+        if let Some(dir) = self.sessions_path.parent() {
+            std::fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let tmp_path = self.sessions_path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, content).map_err(io_error)?;
+        std::fs::rename(&tmp_path, &self.sessions_path).map_err(io_error)?;
+        info!(
+            "Saved {} active work sessions to {}",
+            sessions.len(),
+            self.sessions_path.display()
+        );
+        Ok(())
+    }
+
     /// Set the base issue for todo operations
     #[instrument(skip(self))]
     pub async fn set_todo_base(
         &self,
-        params: SetTodoBaseParams,
+        mut params: SetTodoBaseParams,
     ) -> JiraMcpResult<SetTodoBaseResult> {
+        params.issue_key =
+            normalize_issue_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
         info!("Setting base issue to: {}", params.issue_key);
 
         // Verify the issue exists
@@ -420,17 +756,19 @@ impl TodoTracker {
 
         Ok(SetTodoBaseResult {
             base_issue_key: params.issue_key.clone(),
-            message: format!(
-                "Base issue set to {}. You can now omit issue_key in todo commands.",
-                params.issue_key
+            message: message!(
+                self.jira_client.messages(),
+                "todo.base_set",
+                issue_key = params.issue_key,
             ),
+            performance: ToolPerformance::default(),
         })
     }
 
     /// Get the base issue or return an error if not set
     async fn get_issue_key(&self, provided: Option<String>) -> JiraMcpResult<String> {
         if let Some(key) = provided {
-            return Ok(key);
+            return normalize_issue_key(self.jira_client.base_url(), &key, "issue_key");
         }
 
         let base = self.base_issue.read().await;
@@ -448,14 +786,8 @@ impl TodoTracker {
         let issue_key = self.get_issue_key(params.issue_key).await?;
         info!("Listing todos from issue: {}", issue_key);
 
-        // Get the issue details
-        let issue = self
-            .jira_client
-            .get_issue_details(&issue_key, false, false, false)
-            .await?;
-
-        let description = issue.issue_info.description.as_deref().unwrap_or("");
-        let mut todos = self.parse_todos_with_status(description, &issue_key).await;
+        let (backend, snapshot) = self.read_todos(&issue_key).await?;
+        let mut todos = snapshot.todos;
 
         // Apply status filter if provided
         if let Some(ref filters) = params.status_filter {
@@ -470,8 +802,11 @@ impl TodoTracker {
 
         Ok(ListTodosResult {
             total_count: todos.len(),
+            rendered: params.format.render(|| todo_checklist(&todos)),
             todos,
             issue_key,
+            backend: backend.name().to_string(),
+            performance: ToolPerformance::default(),
         })
     }
 
@@ -481,24 +816,22 @@ impl TodoTracker {
         let issue_key = self.get_issue_key(params.issue_key).await?;
         info!("Adding todo to issue {}: {}", issue_key, params.todo_text);
 
-        // Get current description
-        let issue = self
-            .jira_client
-            .get_issue_details(&issue_key, false, false, false)
-            .await?;
-        let current_description = issue.issue_info.description.as_deref().unwrap_or("");
-
-        // Generate new description with the added todo
-        let new_description =
-            Self::add_todo_to_description(current_description, &params.todo_text, params.prepend);
+        // Get the current todos and add the new one
+        let (backend, snapshot) = self.read_todos(&issue_key).await?;
+        let edit = backend.add(&snapshot, &params.todo_text, params.prepend);
+        let diff = (params.dry_run || params.include_diff)
+            .then(|| unified_diff(&snapshot.content, &edit.content));
 
-        // Update the issue description
-        self.update_description(&issue_key, &new_description)
-            .await?;
+        // Update the issue
+        if !params.dry_run {
+            backend
+                .write(&issue_key, &snapshot, &edit, Some(&snapshot.updated))
+                .await?;
+        }
 
-        // Parse todos again to get the newly added one
-        let updated_todos = self
-            .parse_todos_with_status(&new_description, &issue_key)
+        // Find the newly added todo
+        let mut updated_todos = edit.todos;
+        self.mark_work_in_progress(&issue_key, &mut updated_todos)
             .await;
         let new_todo = if params.prepend {
             updated_todos.first()
@@ -508,12 +841,34 @@ impl TodoTracker {
         .cloned()
         .ok_or_else(|| JiraMcpError::internal("Failed to find newly added todo"))?;
 
+        if params.dry_run {
+            return Ok(AddTodoResult {
+                todo: new_todo,
+                message: message!(
+                    self.jira_client.messages(),
+                    "todo.added_dry_run",
+                    issue_key = issue_key,
+                ),
+                updated_description: edit.content,
+                dry_run: true,
+                diff,
+                performance: ToolPerformance::default(),
+            });
+        }
+
         info!("Successfully added todo to issue {}", issue_key);
 
         Ok(AddTodoResult {
             todo: new_todo,
-            message: format!("Todo added to issue {}", issue_key),
-            updated_description: new_description,
+            message: message!(
+                self.jira_client.messages(),
+                "todo.added",
+                issue_key = issue_key,
+            ),
+            updated_description: edit.content,
+            dry_run: false,
+            diff,
+            performance: ToolPerformance::default(),
         })
     }
 
@@ -526,51 +881,68 @@ impl TodoTracker {
             issue_key, params.todo_id_or_index
         );
 
-        // Get current description and todos
-        let issue = self
-            .jira_client
-            .get_issue_details(&issue_key, false, false, false)
-            .await?;
-        let current_description = issue.issue_info.description.as_deref().unwrap_or("");
-        let todos = self
-            .parse_todos_with_status(current_description, &issue_key)
-            .await;
+        // Get current todos
+        let (backend, snapshot) = self.read_todos(&issue_key).await?;
+        let todos = &snapshot.todos;
 
         // Find the todo to update
-        let todo_index = Self::resolve_todo_index(&todos, &params.todo_id_or_index)?;
+        let todo_index = Self::resolve_todo_index(todos, &params.todo_id_or_index)?;
         let todo = todos
             .get(todo_index)
             .ok_or_else(|| JiraMcpError::invalid_param("todo_id_or_index", "Todo not found"))?;
 
-        // Update the description
-        let new_description =
-            Self::update_todo_status(current_description, todo.line_number, params.completed);
+        // Check or uncheck it
+        let edit = backend.set_completed(&snapshot, todo, params.completed);
+        let diff = (params.dry_run || params.include_diff)
+            .then(|| unified_diff(&snapshot.content, &edit.content));
 
-        self.update_description(&issue_key, &new_description)
-            .await?;
+        if !params.dry_run {
+            backend
+                .write(&issue_key, &snapshot, &edit, Some(&snapshot.updated))
+                .await?;
+        }
 
         // Get updated todo
-        let updated_todos = self
-            .parse_todos_with_status(&new_description, &issue_key)
+        let mut updated_todos = edit.todos;
+        self.mark_work_in_progress(&issue_key, &mut updated_todos)
             .await;
         let updated_todo = updated_todos
             .get(todo_index)
             .cloned()
             .ok_or_else(|| JiraMcpError::internal("Failed to find updated todo"))?;
 
+        let (message_id, dry_run_message_id) = if params.completed {
+            ("todo.completed", "todo.completed_dry_run")
+        } else {
+            ("todo.reopened", "todo.reopened_dry_run")
+        };
+
+        if params.dry_run {
+            return Ok(UpdateTodoResult {
+                todo: updated_todo,
+                message: message!(
+                    self.jira_client.messages(),
+                    dry_run_message_id,
+                    issue_key = issue_key,
+                ),
+                dry_run: true,
+                diff,
+                performance: ToolPerformance::default(),
+            });
+        }
+
         info!("Successfully updated todo in issue {}", issue_key);
 
         Ok(UpdateTodoResult {
             todo: updated_todo,
-            message: format!(
-                "Todo {} in issue {}",
-                if params.completed {
-                    "completed"
-                } else {
-                    "reopened"
-                },
-                issue_key
+            message: message!(
+                self.jira_client.messages(),
+                message_id,
+                issue_key = issue_key,
             ),
+            dry_run: false,
+            diff,
+            performance: ToolPerformance::default(),
         })
     }
 
@@ -585,14 +957,16 @@ impl TodoTracker {
             "Starting work on todo in issue {}: {}",
             issue_key, params.todo_id_or_index
         );
+        if params.target_minutes == Some(0) {
+            return Err(JiraMcpError::invalid_param(
+                "target_minutes",
+                "Target must be at least one minute",
+            ));
+        }
 
         // Get todos
-        let issue = self
-            .jira_client
-            .get_issue_details(&issue_key, false, false, false)
-            .await?;
-        let description = issue.issue_info.description.as_deref().unwrap_or("");
-        let todos = self.parse_todos_with_status(description, &issue_key).await;
+        let (_, snapshot) = self.read_todos(&issue_key).await?;
+        let todos = snapshot.todos;
 
         // Find the todo
         let todo_index = Self::resolve_todo_index(&todos, &params.todo_id_or_index)?;
@@ -603,7 +977,7 @@ impl TodoTracker {
 
         // Create work session
         let session_key = format!("{}:{}", issue_key, todo.id);
-        let started_at = Utc::now();
+        let started_at = self.clock.now();
 
         // Check if session already exists
         {
@@ -626,6 +1000,8 @@ impl TodoTracker {
             started_at,
             initial_worklog_id: None,
             accumulated_time: 0,
+            target_minutes: params.target_minutes,
+            target_notified: false,
         };
 
         // Store the session
@@ -639,10 +1015,13 @@ impl TodoTracker {
         Ok(StartTodoWorkResult {
             todo,
             started_at: started_at.to_rfc3339(),
-            message: format!(
-                "Started tracking work on todo in issue {}. Time will be logged when you complete, checkpoint, or auto-checkpoint runs.",
-                issue_key
+            target_minutes: params.target_minutes,
+            message: message!(
+                self.jira_client.messages(),
+                "todo.work_started",
+                issue_key = issue_key,
             ),
+            performance: ToolPerformance::default(),
         })
     }
 
@@ -659,12 +1038,8 @@ impl TodoTracker {
         );
 
         // Get todos
-        let issue = self
-            .jira_client
-            .get_issue_details(&issue_key, false, false, false)
-            .await?;
-        let description = issue.issue_info.description.as_deref().unwrap_or("");
-        let todos = self.parse_todos_with_status(description, &issue_key).await;
+        let (_, snapshot) = self.read_todos(&issue_key).await?;
+        let todos = snapshot.todos;
 
         // Find the todo
         let todo_index = Self::resolve_todo_index(&todos, &params.todo_id_or_index)?;
@@ -686,7 +1061,7 @@ impl TodoTracker {
         };
 
         // Calculate time spent since last checkpoint
-        let now = Utc::now();
+        let now = self.clock.now();
         let duration = now.signed_duration_since(session.started_at);
         let checkpoint_seconds = duration.num_seconds().max(0) as u64;
 
@@ -710,6 +1085,7 @@ impl TodoTracker {
                 checkpoint_seconds,
                 Some(worklog_comment),
                 Some(session.started_at),
+                !params.skip_signature,
             )
             .await?;
 
@@ -737,10 +1113,14 @@ impl TodoTracker {
             total_accumulated_seconds: session.accumulated_time,
             checkpoint_time_formatted: checkpoint_formatted.clone(),
             worklog,
-            message: format!(
-                "Logged {} to issue {}. Session continues. Total time logged: {}",
-                checkpoint_formatted, issue_key, total_formatted
+            message: message!(
+                self.jira_client.messages(),
+                "todo.checkpoint",
+                logged = checkpoint_formatted,
+                issue_key = issue_key,
+                total = total_formatted,
             ),
+            performance: ToolPerformance::default(),
         })
     }
 
@@ -757,12 +1137,8 @@ impl TodoTracker {
         );
 
         // Get todos
-        let issue = self
-            .jira_client
-            .get_issue_details(&issue_key, false, false, false)
-            .await?;
-        let description = issue.issue_info.description.as_deref().unwrap_or("");
-        let todos = self.parse_todos_with_status(description, &issue_key).await;
+        let (_, snapshot) = self.read_todos(&issue_key).await?;
+        let todos = snapshot.todos;
 
         // Find the todo
         let todo_index = Self::resolve_todo_index(&todos, &params.todo_id_or_index)?;
@@ -784,7 +1160,7 @@ impl TodoTracker {
         };
 
         // Calculate time spent since last checkpoint/start
-        let now = Utc::now();
+        let now = self.clock.now();
         let duration = now.signed_duration_since(session.started_at);
         let current_segment_seconds = duration.num_seconds().max(0) as u64;
 
@@ -800,6 +1176,7 @@ impl TodoTracker {
                     current_segment_seconds,
                     Some(worklog_comment),
                     Some(session.started_at),
+                    !params.skip_signature,
                 )
                 .await?
         } else {
@@ -820,6 +1197,12 @@ impl TodoTracker {
         // Calculate total time logged including accumulated time
         let total_time_seconds = session.accumulated_time + current_segment_seconds;
         let time_formatted = Self::format_duration(total_time_seconds);
+        self.record_session(
+            &session,
+            total_time_seconds,
+            (current_segment_seconds > 0).then(|| worklog.id.clone()),
+            SessionOutcome::Paused,
+        );
 
         info!(
             "Paused work on todo in issue {}: {} logged (including {} accumulated)",
@@ -833,10 +1216,13 @@ impl TodoTracker {
             time_spent_seconds: total_time_seconds,
             time_spent_formatted: time_formatted.clone(),
             worklog,
-            message: format!(
-                "Logged {} to issue {}. Session paused, you can start work again later.",
-                time_formatted, issue_key
+            message: message!(
+                self.jira_client.messages(),
+                "todo.paused",
+                logged = time_formatted,
+                issue_key = issue_key,
             ),
+            performance: ToolPerformance::default(),
         })
     }
 
@@ -853,12 +1239,8 @@ impl TodoTracker {
         );
 
         // Get todos
-        let issue = self
-            .jira_client
-            .get_issue_details(&issue_key, false, false, false)
-            .await?;
-        let description = issue.issue_info.description.as_deref().unwrap_or("");
-        let todos = self.parse_todos_with_status(description, &issue_key).await;
+        let (_, snapshot) = self.read_todos(&issue_key).await?;
+        let todos = snapshot.todos;
 
         // Find the todo
         let todo_index = Self::resolve_todo_index(&todos, &params.todo_id_or_index)?;
@@ -880,10 +1262,11 @@ impl TodoTracker {
         };
 
         // Calculate time that would have been logged (including accumulated)
-        let now = Utc::now();
+        let now = self.clock.now();
         let duration = now.signed_duration_since(session.started_at);
         let current_segment_seconds = duration.num_seconds().max(0) as u64;
         let total_discarded = session.accumulated_time + current_segment_seconds;
+        self.record_session(&session, total_discarded, None, SessionOutcome::Cancelled);
 
         info!(
             "Canceled work on todo in issue {}: {} discarded (current: {}, accumulated: {})",
@@ -896,10 +1279,12 @@ impl TodoTracker {
         Ok(CancelTodoWorkResult {
             todo,
             discarded_time_seconds: total_discarded,
-            message: format!(
-                "Work session canceled. {} of work was discarded (not logged).",
-                Self::format_duration(total_discarded)
+            message: message!(
+                self.jira_client.messages(),
+                "todo.canceled",
+                discarded = Self::format_duration(total_discarded),
             ),
+            performance: ToolPerformance::default(),
         })
     }
 
@@ -909,7 +1294,7 @@ impl TodoTracker {
         info!("Getting active work sessions");
 
         let sessions = self.active_sessions.read().await;
-        let now = Utc::now();
+        let now = self.clock.now();
 
         let active_sessions: Vec<ActiveWorkSession> = sessions
             .values()
@@ -924,6 +1309,7 @@ impl TodoTracker {
                     started_at: session.started_at.to_rfc3339(),
                     duration_seconds,
                     duration_formatted: Self::format_duration(duration_seconds),
+                    target: session.target(now),
                 }
             })
             .collect();
@@ -935,6 +1321,7 @@ impl TodoTracker {
         Ok(GetActiveWorkSessionsResult {
             sessions: active_sessions,
             total_count: total,
+            performance: ToolPerformance::default(),
         })
     }
 
@@ -951,15 +1338,11 @@ impl TodoTracker {
         );
 
         // Get todos
-        let issue = self
-            .jira_client
-            .get_issue_details(&issue_key, false, false, false)
-            .await?;
-        let description = issue.issue_info.description.as_deref().unwrap_or("");
-        let todos = self.parse_todos_with_status(description, &issue_key).await;
+        let (backend, snapshot) = self.read_todos(&issue_key).await?;
+        let todos = &snapshot.todos;
 
         // Find the todo
-        let todo_index = Self::resolve_todo_index(&todos, &params.todo_id_or_index)?;
+        let todo_index = Self::resolve_todo_index(todos, &params.todo_id_or_index)?;
         let mut todo = todos
             .get(todo_index)
             .cloned()
@@ -977,7 +1360,7 @@ impl TodoTracker {
             })?
         };
 
-        let now = Utc::now();
+        let now = self.clock.now();
         let duration = now.signed_duration_since(session.started_at);
         // This is the time since last checkpoint/start
         let current_segment_seconds = duration.num_seconds().max(0) as u64;
@@ -988,8 +1371,7 @@ impl TodoTracker {
         let crosses_day_boundary = started_date != current_date;
 
         // Also check for current segment longer than 24 hours
-        const SECONDS_PER_DAY: u64 = 86400; // 24 * 60 * 60
-        let is_multi_day = crosses_day_boundary || current_segment_seconds > SECONDS_PER_DAY;
+        let is_multi_day = Self::spans_days(session.started_at, now);
 
         // Calculate explicit time if provided
         let explicit_time_seconds = if let Some(seconds) = params.time_spent_seconds {
@@ -1075,6 +1457,7 @@ impl TodoTracker {
                     current_log_seconds,
                     Some(worklog_comment),
                     Some(session.started_at),
+                    !params.skip_signature,
                 )
                 .await?
         } else {
@@ -1093,9 +1476,9 @@ impl TodoTracker {
 
         // Mark todo as completed if requested
         if params.mark_completed && !todo.completed {
-            let updated_description = Self::update_todo_status(description, todo.line_number, true);
-            self.update_description(&issue_key, &updated_description)
-                .await?;
+            let edit = backend.set_completed(&snapshot, &todo, true);
+            // The worklog above bumps `updated`, so no concurrency check here
+            backend.write(&issue_key, &snapshot, &edit, None).await?;
             todo.completed = true;
             todo.status = TodoStatus::Completed;
         }
@@ -1103,6 +1486,15 @@ impl TodoTracker {
         // Calculate total time (accumulated + current segment)
         let total_time_seconds = session.accumulated_time + current_log_seconds;
         let time_formatted = Self::format_duration(total_time_seconds);
+        let target = session
+            .target_minutes
+            .map(|minutes| SessionTarget::new(minutes, total_time_seconds));
+        self.record_session(
+            &session,
+            total_time_seconds,
+            (current_log_seconds > 0).then(|| worklog.id.clone()),
+            SessionOutcome::Completed,
+        );
 
         info!(
             "Completed work on todo in issue {}: {} logged total (current: {}, accumulated: {})",
@@ -1117,23 +1509,257 @@ impl TodoTracker {
             time_spent_seconds: total_time_seconds,
             time_spent_formatted: time_formatted.clone(),
             worklog,
-            message: format!(
-                "Logged {} to issue {} (total including checkpoints)",
-                time_formatted, issue_key
+            target,
+            message: message!(
+                self.jira_client.messages(),
+                "todo.work_completed",
+                logged = time_formatted,
+                issue_key = issue_key,
             ),
+            performance: ToolPerformance::default(),
         })
     }
 
+    /// Whether transition_issue tracks time when the call leaves track_time out
+    pub fn tracks_time_on_transition(&self) -> bool {
+        self.config.todos.track_time_on_transition
+    }
+
+    /// Start the issue-level work session, unless one is already running
+    pub async fn start_issue_session(&self, issue_key: &str) -> IssueSessionChange {
+        let session_key = format!("{}:{}", issue_key, ISSUE_SESSION_ID);
+        let mut sessions = self.active_sessions.write().await;
+        if let Some(session) = sessions.get(&session_key) {
+            return IssueSessionChange {
+                action: IssueSessionAction::AlreadyActive,
+                started_at: session.started_at.to_rfc3339(),
+                time_logged_seconds: None,
+                worklog_id: None,
+            };
+        }
+
+        let started_at = self.clock.now();
+        sessions.insert(
+            session_key,
+            WorkSession {
+                issue_key: issue_key.to_string(),
+                todo_id: ISSUE_SESSION_ID.to_string(),
+                todo_text: format!("Work on {}", issue_key),
+                started_at,
+                initial_worklog_id: None,
+                accumulated_time: 0,
+                target_minutes: None,
+                target_notified: false,
+            },
+        );
+        info!("Started issue work session for {}", issue_key);
+
+        IssueSessionChange {
+            action: IssueSessionAction::Started,
+            started_at: started_at.to_rfc3339(),
+            time_logged_seconds: None,
+            worklog_id: None,
+        }
+    }
+
+    /// Refuse a move to done while the issue session needs an explicit time
+    ///
+    /// Runs before the transition, so a refused call leaves both the issue and
+    /// the session as they were.
+    pub async fn check_issue_session_done(
+        &self,
+        issue_key: &str,
+        explicit_seconds: Option<u64>,
+    ) -> JiraMcpResult<()> {
+        if explicit_seconds.is_some() {
+            return Ok(());
+        }
+        let session_key = format!("{}:{}", issue_key, ISSUE_SESSION_ID);
+        let sessions = self.active_sessions.read().await;
+        let Some(session) = sessions.get(&session_key) else {
+            return Ok(());
+        };
+
+        let now = self.clock.now();
+        let spans_days = Self::spans_days(session.started_at, now);
+        if self.config.todos.session_on_done == SessionOnDone::AutoComplete && !spans_days {
+            return Ok(());
+        }
+
+        let elapsed = now
+            .signed_duration_since(session.started_at)
+            .num_seconds()
+            .max(0) as u64;
+        Err(JiraMcpError::invalid_param(
+            "time_spent_hours",
+            format!(
+                "A work session on {} has been running since {} ({}{}).\n\
+                 Pass 'time_spent_hours', 'time_spent_minutes' or 'time_spent_seconds' \
+                 to log it with this transition, or set 'track_time' to false \
+                 to transition and keep the session running.",
+                issue_key,
+                session.started_at.format("%B %d, %Y at %H:%M"),
+                Self::format_duration(elapsed),
+                if spans_days {
+                    ", spanning multiple days"
+                } else {
+                    ""
+                }
+            ),
+        ))
+    }
+
+    /// End the issue-level work session and log its time, if one is running
+    ///
+    /// `explicit_seconds` replaces the elapsed time of the current segment.
+    pub async fn complete_issue_session(
+        &self,
+        issue_key: &str,
+        explicit_seconds: Option<u64>,
+        to_status: &str,
+    ) -> JiraMcpResult<Option<IssueSessionChange>> {
+        let session_key = format!("{}:{}", issue_key, ISSUE_SESSION_ID);
+        let Some(session) = self.active_sessions.write().await.remove(&session_key) else {
+            return Ok(None);
+        };
+
+        let elapsed = self
+            .clock
+            .now()
+            .signed_duration_since(session.started_at)
+            .num_seconds()
+            .max(0) as u64;
+        let log_seconds = explicit_seconds.unwrap_or(elapsed);
+        let worklog_id = if log_seconds > 0 {
+            let worklog = self
+                .jira_client
+                .add_worklog(
+                    issue_key,
+                    log_seconds,
+                    Some(format!("Work on {} until {}", issue_key, to_status)),
+                    Some(session.started_at),
+                    true,
+                )
+                .await?;
+            Some(worklog.id)
+        } else {
+            None
+        };
+        self.record_session(
+            &session,
+            session.accumulated_time + log_seconds,
+            worklog_id.clone(),
+            SessionOutcome::Completed,
+        );
+
+        info!(
+            "Completed issue work session for {}: {} logged",
+            issue_key,
+            Self::format_duration(session.accumulated_time + log_seconds)
+        );
+
+        Ok(Some(IssueSessionChange {
+            action: IssueSessionAction::Completed,
+            started_at: session.started_at.to_rfc3339(),
+            time_logged_seconds: Some(session.accumulated_time + log_seconds),
+            worklog_id,
+        }))
+    }
+
     // Helper methods
 
-    /// Parse markdown checkboxes from description with status detection
-    async fn parse_todos_with_status(&self, description: &str, issue_key: &str) -> Vec<TodoItem> {
-        let mut todos = Vec::new();
+    /// Hand a finished session to the work history, which writes it in the
+    /// background
+    fn record_session(
+        &self,
+        session: &WorkSession,
+        seconds: u64,
+        worklog_id: Option<String>,
+        outcome: SessionOutcome,
+    ) {
+        let ended_at = self.clock.now();
+        self.work_history.record(SessionRecord {
+            issue_key: session.issue_key.clone(),
+            todo_id: session.todo_id.clone(),
+            todo_text: session.todo_text.clone(),
+            started_at: ended_at - Duration::seconds(seconds as i64),
+            ended_at,
+            seconds,
+            worklog_id,
+            outcome,
+        });
+    }
+
+    /// Whether a segment crosses midnight or runs longer than a day, so its
+    /// time has to be given explicitly
+    fn spans_days(started_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        const SECONDS_PER_DAY: i64 = 86400; // 24 * 60 * 60
+        started_at.date_naive() != now.date_naive()
+            || now.signed_duration_since(started_at).num_seconds() > SECONDS_PER_DAY
+    }
+
+    /// Read the todos of an issue from the backend configured for it, with
+    /// work-session status
+    async fn read_todos(&self, issue_key: &str) -> JiraMcpResult<(&dyn TodoBackend, TodoSnapshot)> {
+        let description: &dyn TodoBackend = &self.description_todos;
+        let checklist = self
+            .checklist_todos
+            .as_ref()
+            .map(|backend| backend as &dyn TodoBackend);
+
+        let (backend, mut snapshot) = match (self.config.todos.backend_for(issue_key), checklist) {
+            (TodoBackendKind::Description, _) | (TodoBackendKind::Auto, None) => {
+                (description, description.read(issue_key).await?)
+            }
+            (TodoBackendKind::ChecklistField, None) => {
+                return Err(JiraMcpError::config(format!(
+                    "The todo backend for {} is checklist_field, but no checklist field is \
+                     configured; set field_mappings.checklist (or JIRA_CHECKLIST_FIELD)",
+                    issue_key
+                )))
+            }
+            (TodoBackendKind::ChecklistField, Some(checklist)) => {
+                (checklist, checklist.read(issue_key).await?)
+            }
+            (TodoBackendKind::Auto, Some(checklist)) => {
+                let snapshot = checklist.read(issue_key).await?;
+                if snapshot.is_set {
+                    (checklist, snapshot)
+                } else {
+                    (description, description.read(issue_key).await?)
+                }
+            }
+        };
+
+        self.mark_work_in_progress(issue_key, &mut snapshot.todos)
+            .await;
+        Ok((backend, snapshot))
+    }
+
+    /// Mark todos with an active work session as in progress
+    async fn mark_work_in_progress(&self, issue_key: &str, todos: &mut [TodoItem]) {
         let sessions = self.active_sessions.read().await;
 
+        for todo in todos {
+            if sessions.contains_key(&format!("{}:{}", issue_key, todo.id)) {
+                todo.status = TodoStatus::Wip;
+            }
+        }
+    }
+
+    /// Parse markdown checkboxes from a description, without work sessions
+    pub(crate) fn parse_todos(description: &str) -> Vec<TodoItem> {
+        let mut todos = Vec::new();
+        let mut section = None;
+
         for (line_num, line) in description.lines().enumerate() {
             let trimmed = line.trim();
 
+            if let Some(heading) = Self::parse_heading_line(trimmed) {
+                section = Some(heading);
+                continue;
+            }
+
             // Match patterns like:
             // - [ ] todo item
             // - [x] todo item
@@ -1144,11 +1770,7 @@ impl TodoTracker {
                 // Generate ID based on text and position among duplicates (not line number)
                 let id = Self::generate_todo_id(&todos, &todo_text);
 
-                // Determine status based on completion and active work session
-                let session_key = format!("{}:{}", issue_key, id);
-                let status = if sessions.contains_key(&session_key) {
-                    TodoStatus::Wip
-                } else if completed {
+                let status = if completed {
                     TodoStatus::Completed
                 } else {
                     TodoStatus::Open
@@ -1160,6 +1782,7 @@ impl TodoTracker {
                     status,
                     line_number: line_num,
                     id,
+                    section: section.clone(),
                 });
             }
         }
@@ -1167,8 +1790,18 @@ impl TodoTracker {
         todos
     }
 
+    /// Parse a markdown heading line ("## Backend") into its text
+    fn parse_heading_line(line: &str) -> Option<String> {
+        let text = line.trim_start_matches('#');
+        if text.len() == line.len() || !(text.is_empty() || text.starts_with(' ')) {
+            return None;
+        }
+        let text = text.trim();
+        (!text.is_empty()).then(|| text.to_string())
+    }
+
     /// Parse a single checkbox line
-    fn parse_checkbox_line(line: &str) -> Option<String> {
+    pub(crate) fn parse_checkbox_line(line: &str) -> Option<String> {
         // Match: - [ ] text or - [x] text
         if line.starts_with("- [") || line.starts_with("* [") {
             if let Some(idx) = line.find(']') {
@@ -1184,7 +1817,7 @@ impl TodoTracker {
     }
 
     /// Generate a unique ID for a todo
-    fn generate_todo_id(todos_so_far: &[TodoItem], text: &str) -> String {
+    pub(crate) fn generate_todo_id(todos_so_far: &[TodoItem], text: &str) -> String {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
 
@@ -1204,7 +1837,11 @@ impl TodoTracker {
     }
 
     /// Add a todo to a description (preserves all existing content)
-    fn add_todo_to_description(description: &str, todo_text: &str, prepend: bool) -> String {
+    pub(crate) fn add_todo_to_description(
+        description: &str,
+        todo_text: &str,
+        prepend: bool,
+    ) -> String {
         let new_todo_line = format!("- [ ] {}", todo_text);
 
         // Convert to owned strings to avoid lifetime issues
@@ -1264,7 +1901,11 @@ impl TodoTracker {
     }
 
     /// Update todo status in description (preserves all existing content)
-    fn update_todo_status(description: &str, line_number: usize, completed: bool) -> String {
+    pub(crate) fn update_todo_status(
+        description: &str,
+        line_number: usize,
+        completed: bool,
+    ) -> String {
         let mut lines: Vec<String> = description.lines().map(String::from).collect();
 
         if line_number < lines.len() {
@@ -1321,27 +1962,33 @@ impl TodoTracker {
         }
     }
 
-    /// Update issue description
-    async fn update_description(&self, issue_key: &str, description: &str) -> JiraMcpResult<()> {
-        use crate::tools::update_description::{
-            UpdateDescription, UpdateDescriptionParams, UpdateMode,
-        };
-
-        let updater = UpdateDescription::new(self.jira_client.clone());
-        updater
-            .execute(UpdateDescriptionParams {
-                issue_key: issue_key.to_string(),
-                content: description.to_string(),
-                mode: UpdateMode::Replace,
-            })
-            .await?;
-
-        Ok(())
+    /// Log and broadcast a target_reached event for each session that passed
+    /// its target since the last check
+    async fn raise_target_events(&self, now: DateTime<Utc>) {
+        let events = targets_reached(&mut *self.active_sessions.write().await, now);
+        for event in events {
+            let WorkSessionEvent::TargetReached {
+                issue_key,
+                todo_text,
+                target,
+                ..
+            } = &event;
+            info!(
+                "Work session on {} ({}) reached its {}m target: {} worked",
+                issue_key,
+                todo_text,
+                target.target_minutes,
+                Self::format_duration(target.elapsed_seconds)
+            );
+            // Nobody listening is fine; the log line above is the fallback
+            let _ = self.events.send(event);
+        }
     }
 
     /// Start auto-checkpoint background task
     ///
-    /// Periodically checkpoints all active sessions to JIRA to prevent data loss.
+    /// Periodically checkpoints all active sessions to JIRA to prevent data loss,
+    /// and checks every minute whether a session passed its target_minutes.
     /// Returns a task handle that can be used to stop the background task.
     pub fn start_auto_checkpoint_task(
         self: Arc<Self>,
@@ -1351,9 +1998,18 @@ impl TodoTracker {
             let mut interval =
                 tokio::time::interval(tokio::time::Duration::from_secs(interval_minutes * 60));
             interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            // Targets are checked every minute, so reminders are not a checkpoint late
+            let mut target_interval = tokio::time::interval(tokio::time::Duration::from_secs(60));
+            target_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
             loop {
-                interval.tick().await;
+                tokio::select! {
+                    _ = interval.tick() => {}
+                    _ = target_interval.tick() => {
+                        self.raise_target_events(self.clock.now()).await;
+                        continue;
+                    }
+                }
 
                 info!("Auto-checkpoint: Checking for active sessions to checkpoint");
 
@@ -1378,7 +2034,7 @@ impl TodoTracker {
 
                 // Checkpoint each session
                 for (session_key, session) in sessions_to_checkpoint {
-                    let now = Utc::now();
+                    let now = self.clock.now();
                     let duration = now.signed_duration_since(session.started_at);
                     let checkpoint_seconds = duration.num_seconds().max(0) as u64;
 
@@ -1405,6 +2061,7 @@ impl TodoTracker {
                             checkpoint_seconds,
                             Some(format!("Auto-checkpoint: {}", session.todo_text)),
                             Some(session.started_at),
+                            true,
                         )
                         .await
                     {
@@ -1441,9 +2098,101 @@ impl TodoTracker {
     }
 }
 
+/// Mark the sessions that passed their target by `now`, returning one
+/// target_reached event for each
+fn targets_reached(
+    sessions: &mut HashMap<String, WorkSession>,
+    now: DateTime<Utc>,
+) -> Vec<WorkSessionEvent> {
+    let mut events = Vec::new();
+    for session in sessions.values_mut() {
+        if session.target_notified {
+            continue;
+        }
+        let Some(target) = session.target(now).filter(SessionTarget::is_reached) else {
+            continue;
+        };
+        session.target_notified = true;
+        events.push(WorkSessionEvent::TargetReached {
+            issue_key: session.issue_key.clone(),
+            todo_id: session.todo_id.clone(),
+            todo_text: session.todo_text.clone(),
+            target,
+        });
+    }
+    events
+}
+
+/// Work sessions saved by the previous run; an unreadable file is ignored
+fn load_sessions(path: &Path) -> HashMap<String, WorkSession> {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+    serde_json::from_str(&content).unwrap_or_else(|e| {
+        warn!(
+            "Ignoring unreadable work sessions {}: {}",
+            path.display(),
+            e
+        );
+        HashMap::new()
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::clock::MockClock;
+    use serde_json::json;
+
+    fn instant(value: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(value)
+            .unwrap()
+            .with_timezone(&Utc)
+    }
+
+    /// Tracker on the mock fixtures whose time only moves with `clock`
+    async fn mock_tracker(name: &str, clock: Arc<MockClock>) -> TodoTracker {
+        let state_dir =
+            std::env::temp_dir().join(format!("jira-todo-clock-{}-{}", name, std::process::id()));
+        let config = Arc::new(JiraConfig {
+            mock_fixtures_dir: Some(
+                concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/mock").to_string(),
+            ),
+            state_dir: Some(state_dir.to_string_lossy().into_owned()),
+            ..Default::default()
+        });
+        let jira_client = Arc::new(JiraClient::new(Arc::clone(&config)).await.unwrap());
+        TodoTracker::new(
+            jira_client,
+            Arc::clone(&config),
+            Arc::new(MetadataCache::from_config(&config)),
+            Arc::new(UndoHistory::from_config(&config)),
+        )
+        .with_clock(clock)
+    }
+
+    async fn start(tracker: &TodoTracker) {
+        tracker
+            .start_todo_work(
+                serde_json::from_value(json!({"issue_key": "MOCK-1", "todo_id_or_index": "1"}))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+    }
+
+    fn complete(extra: serde_json::Value) -> CompleteTodoWorkParams {
+        let mut params = json!({
+            "issue_key": "MOCK-1",
+            "todo_id_or_index": "1",
+            "mark_completed": false
+        });
+        params
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        serde_json::from_value(params).unwrap()
+    }
 
     #[test]
     fn test_format_duration() {
@@ -1452,4 +2201,159 @@ mod tests {
         assert_eq!(TodoTracker::format_duration(3665), "1h 1m");
         assert_eq!(TodoTracker::format_duration(7200), "2h 0m");
     }
+
+    #[test]
+    fn test_load_sessions() {
+        let path =
+            std::env::temp_dir().join(format!("jira-work-sessions-{}.json", std::process::id()));
+        assert!(load_sessions(&path).is_empty());
+
+        let session = WorkSession {
+            issue_key: "PROJ-1".to_string(),
+            todo_id: "todo-1".to_string(),
+            todo_text: "Write tests".to_string(),
+            started_at: Utc::now(),
+            initial_worklog_id: None,
+            accumulated_time: 120,
+            target_minutes: None,
+            target_notified: false,
+        };
+        let sessions = HashMap::from([("PROJ-1:todo-1".to_string(), session)]);
+        std::fs::write(&path, serde_json::to_string(&sessions).unwrap()).unwrap();
+        let loaded = load_sessions(&path);
+        assert_eq!(loaded["PROJ-1:todo-1"].accumulated_time, 120);
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(load_sessions(&path).is_empty());
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[tokio::test]
+    async fn test_checkpoints_accumulate_at_controlled_times() {
+        let clock = Arc::new(MockClock::new(instant("2026-01-06T09:00:00Z")));
+        let tracker = mock_tracker("checkpoints", Arc::clone(&clock)).await;
+        start(&tracker).await;
+
+        clock.advance(chrono::Duration::seconds(90));
+        let sessions = tracker.get_active_work_sessions().await.unwrap();
+        assert_eq!(sessions.sessions[0].duration_seconds, 90);
+        assert_eq!(sessions.sessions[0].duration_formatted, "1m");
+
+        clock.advance(chrono::Duration::seconds(20 * 60 - 90));
+        let checkpoint = tracker
+            .checkpoint_todo_work(
+                serde_json::from_value(json!({"issue_key": "MOCK-1", "todo_id_or_index": "1"}))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(checkpoint.checkpoint_time_seconds, 1200);
+
+        // Completing logs only the segment since the checkpoint
+        clock.advance(chrono::Duration::minutes(10));
+        let result = tracker
+            .complete_todo_work(complete(json!({})))
+            .await
+            .unwrap();
+        assert_eq!(result.time_spent_seconds, 1800);
+        assert_eq!(result.time_spent_formatted, "30m");
+        let worklogs = tracker
+            .jira_client
+            .mock_backend()
+            .unwrap()
+            .writes_to("POST", "api/issue/MOCK-1/worklog");
+        assert_eq!(worklogs.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_day_boundary_guard() {
+        let clock = Arc::new(MockClock::new(instant("2026-01-06T23:30:00Z")));
+        let tracker = mock_tracker("day-boundary", Arc::clone(&clock)).await;
+        start(&tracker).await;
+
+        // An hour later it is the 7th, so the time has to be given
+        clock.advance(chrono::Duration::hours(1));
+        let err = tracker
+            .complete_todo_work(complete(json!({})))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("spans multiple days"));
+
+        // With the time given, the same segment is accepted
+        clock.set(instant("2026-01-06T23:30:00Z"));
+        start(&tracker).await;
+        clock.advance(chrono::Duration::hours(1));
+        let result = tracker
+            .complete_todo_work(complete(json!({"time_spent_minutes": 45})))
+            .await
+            .unwrap();
+        assert_eq!(result.time_spent_seconds, 2700);
+    }
+
+    #[tokio::test]
+    async fn test_session_across_dst_change() {
+        // Central Europe moves to summer time at 01:00 UTC on 2026-03-29; the
+        // wall clock skips an hour, the session does not
+        let clock = Arc::new(MockClock::new(instant("2026-03-29T00:30:00Z")));
+        let tracker = mock_tracker("dst", Arc::clone(&clock)).await;
+        start(&tracker).await;
+
+        clock.set(instant("2026-03-29T03:30:00+02:00"));
+        let result = tracker
+            .complete_todo_work(complete(json!({})))
+            .await
+            .unwrap();
+        assert_eq!(result.time_spent_seconds, 3600);
+        assert_eq!(result.time_spent_formatted, "1h 0m");
+    }
+
+    #[test]
+    fn test_session_targets() {
+        let started_at = DateTime::parse_from_rfc3339("2026-01-06T09:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let at = |minutes: i64| started_at + chrono::Duration::minutes(minutes);
+        let session = WorkSession {
+            issue_key: "PROJ-1".to_string(),
+            todo_id: "todo-1".to_string(),
+            todo_text: "Write tests".to_string(),
+            started_at,
+            initial_worklog_id: None,
+            // Five minutes checkpointed before this segment
+            accumulated_time: 300,
+            target_minutes: Some(25),
+            target_notified: false,
+        };
+
+        let target = session.target(at(10)).unwrap();
+        assert_eq!(target.elapsed_seconds, 900);
+        assert_eq!(target.progress_percent, 60);
+        assert_eq!(target.remaining_seconds, 600);
+        assert!(!target.is_reached());
+
+        let target = session.target(at(30)).unwrap();
+        assert_eq!(target.progress_percent, 140);
+        assert_eq!(target.overshoot_seconds, 600);
+        assert!(target.is_reached());
+
+        let untargeted = WorkSession {
+            target_minutes: None,
+            todo_id: "todo-2".to_string(),
+            ..session.clone()
+        };
+        let mut sessions = HashMap::from([
+            ("PROJ-1:todo-1".to_string(), session),
+            ("PROJ-1:todo-2".to_string(), untargeted),
+        ]);
+        assert!(targets_reached(&mut sessions, at(15)).is_empty());
+        let events = targets_reached(&mut sessions, at(20));
+        assert_eq!(events.len(), 1);
+        let WorkSessionEvent::TargetReached {
+            todo_id, target, ..
+        } = &events[0];
+        assert_eq!(todo_id, "todo-1");
+        assert_eq!(target.overshoot_seconds, 0);
+        // Raised once per session
+        assert!(targets_reached(&mut sessions, at(40)).is_empty());
+    }
 }
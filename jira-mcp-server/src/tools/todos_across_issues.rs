@@ -0,0 +1,355 @@
+//! Todos across several issues
+//!
+//! list_todos_across_issues runs the todo parser over a set of issues, named
+//! by key or picked with the get_user_issues filters, and returns the todos as
+//! one flat list with per-issue and overall counts. Issues are read a few at a
+//! time through the rate limiter; an issue that can't be read is reported in
+//! `failed` instead of failing the call.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_keys;
+use crate::tools::todo_tracker::{ListTodosParams, TodoItem, TodoStatus, TodoTracker};
+use crate::tools::user_issues::{GetUserIssuesParams, GetUserIssuesTool};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tokio::task::JoinSet;
+use tracing::{info, instrument, warn};
+
+const DEFAULT_TODO_ISSUES: u32 = 20;
+const MAX_TODO_ISSUES: u32 = 50;
+
+/// Issue reads in flight at once
+const MAX_CONCURRENT_FETCHES: usize = 5;
+
+/// Parameters for the list_todos_across_issues tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ListTodosAcrossIssuesParams {
+    /// Issues to read (give either this or `filters`)
+    /// Examples: ["PROJ-123", "PROJ-124"]
+    pub issue_keys: Option<Vec<String>>,
+
+    /// get_user_issues filters picking the issues to read (give either this or
+    /// `issue_keys`); their limit is replaced by `max_issues`
+    /// Examples: {"status_filter": ["in_progress"]}, {"project_filter": ["PROJ"], "updated_since": "1 week ago"}
+    pub filters: Option<GetUserIssuesParams>,
+
+    /// Only return todos with these statuses (optional, default: all); the
+    /// counts always cover every todo
+    /// Examples: ["open"], ["open", "wip"]
+    pub status_filter: Option<Vec<TodoStatus>>,
+
+    /// Most issues read (optional, default: 20, max: 50)
+    pub max_issues: Option<u32>,
+}
+
+/// A todo and the issue it belongs to
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueTodo {
+    pub issue_key: String,
+    #[serde(flatten)]
+    pub todo: TodoItem,
+}
+
+/// Todos by status
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct TodoCounts {
+    pub open: usize,
+    pub completed: usize,
+    pub wip: usize,
+    pub total: usize,
+}
+
+impl TodoCounts {
+    fn of(todos: &[TodoItem]) -> Self {
+        let mut counts = Self::default();
+        for todo in todos {
+            match todo.status {
+                TodoStatus::Open => counts.open += 1,
+                TodoStatus::Completed => counts.completed += 1,
+                TodoStatus::Wip => counts.wip += 1,
+            }
+            counts.total += 1;
+        }
+        counts
+    }
+
+    fn add(&mut self, other: TodoCounts) {
+        self.open += other.open;
+        self.completed += other.completed;
+        self.wip += other.wip;
+        self.total += other.total;
+    }
+}
+
+/// Todo counts of one issue
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct IssueTodoCounts {
+    pub issue_key: String,
+
+    /// Where the issue's todos are stored: "description" or "checklist_field"
+    pub backend: String,
+
+    #[serde(flatten)]
+    pub counts: TodoCounts,
+}
+
+/// An issue whose todos couldn't be read
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FailedIssue {
+    pub issue_key: String,
+    pub error: String,
+}
+
+/// Result from the list_todos_across_issues tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ListTodosAcrossIssuesResult {
+    /// Todos of all issues, issue by issue in the order the issues were given
+    /// or found
+    pub todos: Vec<IssueTodo>,
+
+    /// Todo counts per issue that was read
+    pub issues: Vec<IssueTodoCounts>,
+
+    /// Todo counts over all issues that were read
+    pub summary: TodoCounts,
+
+    /// Issues that couldn't be read
+    pub failed: Vec<FailedIssue>,
+
+    /// JQL that picked the issues (only with `filters`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jql_query: Option<String>,
+
+    /// Whether the filters matched more issues than were read
+    pub truncated: bool,
+}
+
+/// Implementation of the list_todos_across_issues tool
+pub struct ListTodosAcrossIssuesTool {
+    jira_client: Arc<JiraClient>,
+    todo_tracker: Arc<TodoTracker>,
+    user_issues_tool: Arc<GetUserIssuesTool>,
+}
+
+impl ListTodosAcrossIssuesTool {
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        todo_tracker: Arc<TodoTracker>,
+        user_issues_tool: Arc<GetUserIssuesTool>,
+    ) -> Self {
+        Self {
+            jira_client,
+            todo_tracker,
+            user_issues_tool,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        params: ListTodosAcrossIssuesParams,
+    ) -> JiraMcpResult<ListTodosAcrossIssuesResult> {
+        let max_issues = params.max_issues.unwrap_or(DEFAULT_TODO_ISSUES);
+        if max_issues == 0 || max_issues > MAX_TODO_ISSUES {
+            return Err(JiraMcpError::invalid_param(
+                "max_issues",
+                format!("max_issues must be between 1 and {}", MAX_TODO_ISSUES),
+            ));
+        }
+
+        let (issue_keys, jql_query, truncated) = match (params.issue_keys, params.filters) {
+            (Some(issue_keys), None) => {
+                if issue_keys.is_empty() {
+                    return Err(JiraMcpError::invalid_param(
+                        "issue_keys",
+                        "Give at least one issue key",
+                    ));
+                }
+                let issue_keys = dedup_keys(normalize_issue_keys(
+                    self.jira_client.base_url(),
+                    &issue_keys,
+                    "issue_keys",
+                )?);
+                if issue_keys.len() > max_issues as usize {
+                    return Err(JiraMcpError::invalid_param(
+                        "issue_keys",
+                        format!(
+                            "{} issues given, but at most {} are read (see max_issues)",
+                            issue_keys.len(),
+                            max_issues
+                        ),
+                    ));
+                }
+                (issue_keys, None, false)
+            }
+            (None, Some(filters)) => {
+                let found = self
+                    .user_issues_tool
+                    .execute(GetUserIssuesParams {
+                        limit: Some(max_issues),
+                        start_at: None,
+                        group_by: None,
+                        format: Default::default(),
+                        ..filters
+                    })
+                    .await?;
+                let search = found.search_result;
+                let truncated = !search.is_last;
+                let issue_keys = search.issues.into_iter().map(|issue| issue.key).collect();
+                (issue_keys, Some(found.jql_query), truncated)
+            }
+            _ => {
+                return Err(JiraMcpError::invalid_param(
+                    "issue_keys",
+                    "Give either issue_keys or filters",
+                ))
+            }
+        };
+
+        let mut read = HashMap::new();
+        let mut failed = Vec::new();
+        let mut pending = issue_keys.iter().cloned();
+        let mut join_set = JoinSet::new();
+        loop {
+            while join_set.len() < MAX_CONCURRENT_FETCHES {
+                let Some(key) = pending.next() else {
+                    break;
+                };
+                self.jira_client.rate_limiter().wait_for_capacity().await;
+                let todo_tracker = Arc::clone(&self.todo_tracker);
+                join_set.spawn(async move {
+                    let todos = todo_tracker
+                        .list_todos(ListTodosParams {
+                            issue_key: Some(key.clone()),
+                            status_filter: None,
+                            format: Default::default(),
+                        })
+                        .await;
+                    (key, todos)
+                });
+            }
+
+            let Some(joined) = join_set.join_next().await else {
+                break;
+            };
+            let (key, todos) =
+                joined.map_err(|e| JiraMcpError::internal(format!("Todo task failed: {}", e)))?;
+            match todos {
+                Ok(todos) => {
+                    read.insert(key, todos);
+                }
+                Err(e) => {
+                    warn!("Could not read todos of {}: {}", key, e);
+                    failed.push(FailedIssue {
+                        issue_key: key,
+                        error: e.to_string(),
+                    });
+                }
+            }
+        }
+
+        let mut todos = Vec::new();
+        let mut issues = Vec::new();
+        let mut summary = TodoCounts::default();
+        for key in &issue_keys {
+            let Some(result) = read.remove(key) else {
+                continue;
+            };
+            let counts = TodoCounts::of(&result.todos);
+            summary.add(counts);
+            issues.push(IssueTodoCounts {
+                issue_key: key.clone(),
+                backend: result.backend,
+                counts,
+            });
+            todos.extend(
+                result
+                    .todos
+                    .into_iter()
+                    .filter(|todo| {
+                        params
+                            .status_filter
+                            .as_ref()
+                            .is_none_or(|statuses| statuses.contains(&todo.status))
+                    })
+                    .map(|todo| IssueTodo {
+                        issue_key: key.clone(),
+                        todo,
+                    }),
+            );
+        }
+        failed.sort_by_key(|failure| issue_keys.iter().position(|key| *key == failure.issue_key));
+
+        info!(
+            "{} todos ({} open) across {} issues, {} failed",
+            summary.total,
+            summary.open,
+            issues.len(),
+            failed.len()
+        );
+
+        Ok(ListTodosAcrossIssuesResult {
+            todos,
+            issues,
+            summary,
+            failed,
+            jql_query,
+            truncated,
+        })
+    }
+}
+
+/// Keys in their first-seen order, without repeats
+fn dedup_keys(keys: Vec<String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    keys.into_iter()
+        .filter(|key| seen.insert(key.clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn todo(id: &str, status: TodoStatus) -> TodoItem {
+        TodoItem {
+            text: format!("Todo {}", id),
+            completed: status == TodoStatus::Completed,
+            status,
+            line_number: 1,
+            id: id.to_string(),
+            section: None,
+        }
+    }
+
+    #[test]
+    fn test_todo_counts() {
+        let mut summary = TodoCounts::of(&[
+            todo("a", TodoStatus::Open),
+            todo("b", TodoStatus::Completed),
+            todo("c", TodoStatus::Open),
+        ]);
+        summary.add(TodoCounts::of(&[todo("d", TodoStatus::Wip)]));
+        summary.add(TodoCounts::of(&[]));
+
+        assert_eq!(
+            summary,
+            TodoCounts {
+                open: 2,
+                completed: 1,
+                wip: 1,
+                total: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_dedup_keys() {
+        let keys = ["PROJ-2", "PROJ-1", "PROJ-2", "OPS-7"].map(String::from);
+        assert_eq!(dedup_keys(keys.to_vec()), ["PROJ-2", "PROJ-1", "OPS-7"]);
+    }
+}
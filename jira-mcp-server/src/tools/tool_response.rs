@@ -0,0 +1,143 @@
+//! The envelope around structured tool results
+//!
+//! Tools return their own result types; the server wraps each in a
+//! [`ToolResponse`] holding what every result is sent with: the rate limit
+//! status when JIRA throttles or the request budget runs low, and the response
+//! budget. The JSON sent to the client is built by [`ToolResponse::envelope`],
+//! which adds `rate_limit` and a `redactions` count (see `crate::redaction`)
+//! next to the result's own fields and then trims the whole to the budget (see
+//! `crate::tools::response_budget`). The wrapped result itself is never changed.
+
+use crate::redaction::count_redactions;
+use crate::tools::rate_limiter::RateLimitStatus;
+use crate::tools::response_budget::{fit_to_budget, MIN_RESPONSE_BUDGET};
+use serde::ser::Error as _;
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+use std::ops::{Deref, DerefMut};
+
+/// A tool result with the fields every tool response carries
+#[derive(Debug, Clone)]
+pub struct ToolResponse<T> {
+    pub result: T,
+
+    /// Only present when JIRA is throttling or the request budget runs low
+    pub rate_limit: Option<RateLimitStatus>,
+
+    /// Largest serialized size in characters; 0 disables trimming
+    pub response_budget: usize,
+}
+
+impl<T> ToolResponse<T> {
+    pub fn into_inner(self) -> T {
+        self.result
+    }
+
+    /// Override the configured budget for this call (never below 1000 characters)
+    pub fn with_response_budget(mut self, max_chars: Option<usize>) -> Self {
+        if let Some(max_chars) = max_chars {
+            self.response_budget = max_chars.max(MIN_RESPONSE_BUDGET);
+        }
+        self
+    }
+}
+
+impl<T: Serialize> ToolResponse<T> {
+    /// The JSON sent to the client: the result's fields plus `rate_limit` and
+    /// `redactions`, trimmed to the response budget
+    pub fn envelope(&self) -> serde_json::Result<Value> {
+        let mut value = serde_json::to_value(&self.result)?;
+        let redactions = count_redactions(&value);
+        if let Value::Object(fields) = &mut value {
+            if let Some(rate_limit) = &self.rate_limit {
+                fields.insert("rate_limit".to_string(), serde_json::to_value(rate_limit)?);
+            }
+            if redactions > 0 {
+                fields.insert("redactions".to_string(), redactions.into());
+            }
+        }
+
+        fit_to_budget(&mut value, self.response_budget);
+        Ok(value)
+    }
+}
+
+/// Serializes as [`ToolResponse::envelope`]
+impl<T: Serialize> Serialize for ToolResponse<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.envelope()
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<T> Deref for ToolResponse<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.result
+    }
+}
+
+impl<T> DerefMut for ToolResponse<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn response(result: Value, response_budget: usize) -> ToolResponse<Value> {
+        ToolResponse {
+            result,
+            rate_limit: None,
+            response_budget,
+        }
+    }
+
+    #[test]
+    fn test_envelope_adds_rate_limit_and_redactions() {
+        let plain = response(json!({"issue_key": "PROJ-1"}), 0);
+        assert_eq!(plain.envelope().unwrap(), json!({"issue_key": "PROJ-1"}));
+
+        let mut masked = response(
+            json!({"comments": ["key [REDACTED:aws_key]", "token [REDACTED:github_token]"]}),
+            0,
+        );
+        masked.rate_limit = Some(RateLimitStatus {
+            throttled: true,
+            retry_after_ms: Some(5_000),
+            remaining_budget: 0,
+            hint: None,
+        });
+        let envelope = masked.envelope().unwrap();
+        assert_eq!(envelope["redactions"], 2);
+        assert_eq!(envelope["rate_limit"]["throttled"], true);
+        assert_eq!(envelope["rate_limit"]["retry_after_ms"], 5_000);
+    }
+
+    #[test]
+    fn test_envelope_trims_to_the_budget() {
+        let comments: Vec<String> = (0..100)
+            .map(|n| format!("Comment {} with enough text to take up some room", n))
+            .collect();
+        let huge = response(json!({"issue_key": "PROJ-1", "comments": comments}), 0)
+            .with_response_budget(Some(2_000));
+        assert_eq!(huge.response_budget, 2_000);
+
+        let envelope = huge.envelope().unwrap();
+        assert!(envelope.to_string().chars().count() <= 2_000);
+        assert_eq!(envelope["truncated"]["paths"], json!(["/comments"]));
+        assert!(envelope["truncated"]["dropped_items"].as_u64().unwrap() > 0);
+        // Serializing gives the envelope and leaves the result whole
+        assert_eq!(serde_json::to_value(&huge).unwrap(), envelope);
+        assert_eq!(huge.result["comments"].as_array().unwrap().len(), 100);
+
+        // Budgets below the minimum are raised to it
+        let tiny = response(json!({}), 0).with_response_budget(Some(10));
+        assert_eq!(tiny.response_budget, MIN_RESPONSE_BUDGET);
+    }
+}
@@ -0,0 +1,277 @@
+//! Construction of every tool the server exposes
+//!
+//! The server builds one [`ToolSet`] from a [`ToolContext`], whichever
+//! constructor it was created with. Adding a tool means a field here and one
+//! line in [`ToolSet::build`]; tools other tools depend on are built first.
+
+use crate::audit::AuditLog;
+use crate::cache::MetadataCache;
+use crate::config::JiraConfig;
+use crate::idempotency::IdempotencyStore;
+use crate::jira_client::JiraClient;
+use crate::tools::*;
+use crate::undo::UndoHistory;
+use crate::work_history::WorkHistory;
+use std::sync::Arc;
+
+/// Shared state the tools are built from
+pub struct ToolContext {
+    pub jira_client: Arc<JiraClient>,
+    pub config: Arc<JiraConfig>,
+    pub cache: Arc<MetadataCache>,
+    /// Previous description/field values, shared by every tool that overwrites them
+    pub undo_history: Arc<UndoHistory>,
+    pub audit_log: Arc<AuditLog>,
+    /// Results of create/comment calls by idempotency key, for safe retries
+    pub idempotency: Arc<IdempotencyStore>,
+    /// Finished work sessions, recorded by the todo tracker
+    pub work_history: Arc<WorkHistory>,
+}
+
+/// Every tool implementation of the server
+#[derive(Clone)]
+pub struct ToolSet {
+    pub search_tool: Arc<SearchIssuesTool>,
+    pub issue_details_tool: Arc<GetIssueDetailsTool>,
+    pub user_issues_tool: Arc<GetUserIssuesTool>,
+    pub list_attachments_tool: Arc<ListAttachmentsTool>,
+    pub download_attachment_tool: Arc<DownloadAttachmentTool>,
+    pub upload_attachment_tool: Arc<UploadAttachmentTool>,
+    pub add_comment_tool: Arc<AddCommentTool>,
+    pub issue_relationships_tool: Arc<IssueRelationshipsTool>,
+    pub update_description_tool: Arc<UpdateDescription>,
+    pub get_available_transitions_tool: Arc<GetAvailableTransitionsTool>,
+    pub transition_issue_tool: Arc<TransitionIssueTool>,
+    pub assign_issue_tool: Arc<AssignIssueTool>,
+    pub get_custom_fields_tool: Arc<GetCustomFieldsTool>,
+    pub update_custom_fields_tool: Arc<UpdateCustomFieldsTool>,
+    pub create_issue_tool: Arc<CreateIssueTool>,
+    pub get_create_metadata_tool: Arc<GetCreateMetadataTool>,
+    pub todo_tracker: Arc<TodoTracker>,
+    pub list_sprints_tool: Arc<ListSprintsTool>,
+    pub get_sprint_info_tool: Arc<GetSprintInfoTool>,
+    pub get_sprint_issues_tool: Arc<GetSprintIssuesTool>,
+    pub move_to_sprint_tool: Arc<MoveToSprintTool>,
+    pub create_sprint_tool: Arc<CreateSprintTool>,
+    pub start_sprint_tool: Arc<StartSprintTool>,
+    pub close_sprint_tool: Arc<CloseSprintTool>,
+    pub link_issues_tool: Arc<LinkIssuesTool>,
+    pub delete_issue_link_tool: Arc<DeleteIssueLinkTool>,
+    pub get_issue_link_types_tool: Arc<GetIssueLinkTypesTool>,
+    pub labels_tool: Arc<LabelsTool>,
+    pub components_tool: Arc<ComponentsTool>,
+    pub bulk_operations_tool: Arc<BulkOperationsTool>,
+    pub count_issues_tool: Arc<CountIssuesTool>,
+    pub find_similar_issues_tool: Arc<FindSimilarIssuesTool>,
+    pub notify_issue_tool: Arc<NotifyIssueTool>,
+    pub votes_tool: Arc<VotesTool>,
+    pub watch_queries_tool: Arc<WatchQueriesTool>,
+    pub get_audit_log_tool: Arc<GetAuditLogTool>,
+    pub undo_last_change_tool: Arc<UndoLastChangeTool>,
+    pub update_summary_tool: Arc<UpdateSummaryTool>,
+    pub priorities_tool: Arc<PrioritiesTool>,
+    pub archive_issue_tool: Arc<ArchiveIssueTool>,
+    pub clear_cache_scope_tool: Arc<ClearCacheScopeTool>,
+    pub get_issues_tool: Arc<GetIssuesTool>,
+    pub check_issue_quality_tool: Arc<CheckIssueQualityTool>,
+    pub standup_summary_tool: Arc<GetStandupSummaryTool>,
+    pub sprint_planning_summary_tool: Arc<GetSprintPlanningSummaryTool>,
+    pub get_recent_requests_tool: Arc<GetRecentRequestsTool>,
+    pub update_sprint_tool: Arc<UpdateSprintTool>,
+    pub set_reporter_tool: Arc<SetReporterTool>,
+    pub security_levels_tool: Arc<SecurityLevelsTool>,
+    pub update_description_section_tool: Arc<UpdateDescriptionSectionTool>,
+    pub diff_issue_since_tool: Arc<DiffIssueSinceTool>,
+    pub get_issue_activity_tool: Arc<GetIssueActivityTool>,
+    pub get_project_stats_tool: Arc<GetProjectStatsTool>,
+    pub description_metadata_tool: Arc<DescriptionMetadataTool>,
+    pub get_my_timesheet_tool: Arc<GetMyTimesheetTool>,
+    pub query_work_history_tool: Arc<QueryWorkHistoryTool>,
+    pub issue_context_bundle_tool: Arc<GetIssueContextBundleTool>,
+    pub estimate_rollup_tool: Arc<GetEstimateRollupTool>,
+    pub board_configuration_tool: Arc<GetBoardConfigurationTool>,
+    pub board_column_issues_tool: Arc<GetBoardColumnIssuesTool>,
+    pub my_mentions_tool: Arc<GetMyMentionsTool>,
+    pub my_permissions_tool: Arc<GetMyPermissionsTool>,
+    pub todos_across_issues_tool: Arc<ListTodosAcrossIssuesTool>,
+}
+
+impl ToolSet {
+    pub fn build(ctx: &ToolContext) -> Self {
+        let client = || Arc::clone(&ctx.jira_client);
+        let config = || Arc::clone(&ctx.config);
+        let cache = || Arc::clone(&ctx.cache);
+        let undo_history = || Arc::clone(&ctx.undo_history);
+
+        // Tools used by other tools
+        // Priority list, shared by the tools that validate priority names
+        let priorities_tool = Arc::new(PrioritiesTool::new(client(), config(), cache()));
+        let assign_issue_tool = Arc::new(AssignIssueTool::new(client(), cache()));
+        let user_issues_tool = Arc::new(GetUserIssuesTool::new(client(), config(), cache()));
+        let update_description_tool = Arc::new(UpdateDescription::new(client(), undo_history()));
+        let todo_tracker = Arc::new(
+            TodoTracker::new(client(), config(), cache(), undo_history())
+                .with_work_history(Arc::clone(&ctx.work_history)),
+        );
+        let transition_issue_tool = Arc::new(
+            TransitionIssueTool::new(client()).with_work_sessions(Arc::clone(&todo_tracker)),
+        );
+        let security_levels_tool = Arc::new(SecurityLevelsTool::new(client(), cache()));
+        let create_issue_tool = Arc::new(CreateIssueTool::new(
+            client(),
+            config(),
+            cache(),
+            Arc::clone(&priorities_tool),
+            Arc::clone(&security_levels_tool),
+            Arc::clone(&ctx.idempotency),
+        ));
+        let labels_tool = Arc::new(LabelsTool::new(client(), cache()));
+        let list_sprints_tool = Arc::new(ListSprintsTool::new(client()));
+        let get_sprint_issues_tool = Arc::new(GetSprintIssuesTool::new(client()));
+        let download_attachment_tool =
+            Arc::new(DownloadAttachmentTool::new(client(), config(), cache()));
+        let board_configuration_tool = Arc::new(GetBoardConfigurationTool::new(client(), cache()));
+
+        Self {
+            search_tool: Arc::new(SearchIssuesTool::new(
+                client(),
+                config(),
+                cache(),
+                Arc::clone(&priorities_tool),
+                Arc::clone(&assign_issue_tool),
+            )),
+            issue_details_tool: Arc::new(GetIssueDetailsTool::new(client(), config(), cache())),
+            list_attachments_tool: Arc::new(ListAttachmentsTool::new(client(), config(), cache())),
+            upload_attachment_tool: Arc::new(UploadAttachmentTool::new(
+                client(),
+                config(),
+                cache(),
+            )),
+            add_comment_tool: Arc::new(AddCommentTool::new(
+                client(),
+                config(),
+                cache(),
+                Arc::clone(&ctx.idempotency),
+            )),
+            issue_relationships_tool: Arc::new(IssueRelationshipsTool::new(
+                client(),
+                config(),
+                cache(),
+            )),
+            get_available_transitions_tool: Arc::new(GetAvailableTransitionsTool::new(client())),
+            get_custom_fields_tool: Arc::new(GetCustomFieldsTool::new(client())),
+            update_custom_fields_tool: Arc::new(UpdateCustomFieldsTool::new(
+                client(),
+                config(),
+                undo_history(),
+            )),
+            get_create_metadata_tool: Arc::new(GetCreateMetadataTool::new(client())),
+
+            // Sprint management tools
+            get_sprint_info_tool: Arc::new(GetSprintInfoTool::new(client())),
+            move_to_sprint_tool: Arc::new(MoveToSprintTool::new(client())),
+            create_sprint_tool: Arc::new(CreateSprintTool::new(client())),
+            start_sprint_tool: Arc::new(StartSprintTool::new(client())),
+            close_sprint_tool: Arc::new(CloseSprintTool::new(client())),
+            update_sprint_tool: Arc::new(UpdateSprintTool::new(client())),
+            sprint_planning_summary_tool: Arc::new(GetSprintPlanningSummaryTool::new(
+                client(),
+                config(),
+                Arc::clone(&list_sprints_tool),
+                Arc::clone(&get_sprint_issues_tool),
+            )),
+
+            // Issue linking tools
+            link_issues_tool: Arc::new(LinkIssuesTool::new(client())),
+            delete_issue_link_tool: Arc::new(DeleteIssueLinkTool::new(client())),
+            get_issue_link_types_tool: Arc::new(GetIssueLinkTypesTool::new(client(), cache())),
+
+            components_tool: Arc::new(ComponentsTool::new(client())),
+            bulk_operations_tool: Arc::new(BulkOperationsTool::new(
+                client(),
+                Arc::clone(&labels_tool),
+                Arc::clone(&create_issue_tool),
+                Arc::clone(&assign_issue_tool),
+            )),
+            count_issues_tool: Arc::new(CountIssuesTool::new(client(), config(), cache())),
+            find_similar_issues_tool: Arc::new(FindSimilarIssuesTool::new(client())),
+            notify_issue_tool: Arc::new(NotifyIssueTool::new(client())),
+            votes_tool: Arc::new(VotesTool::new(client())),
+            watch_queries_tool: Arc::new(WatchQueriesTool::new(client(), config(), cache())),
+            get_audit_log_tool: Arc::new(GetAuditLogTool::new(
+                Arc::clone(&ctx.audit_log),
+                config(),
+            )),
+            undo_last_change_tool: Arc::new(UndoLastChangeTool::new(client(), undo_history())),
+            update_summary_tool: Arc::new(UpdateSummaryTool::new(
+                client(),
+                Arc::clone(&priorities_tool),
+                undo_history(),
+            )),
+            archive_issue_tool: Arc::new(ArchiveIssueTool::new(
+                client(),
+                config(),
+                Arc::clone(&transition_issue_tool),
+            )),
+            clear_cache_scope_tool: Arc::new(ClearCacheScopeTool::new(cache())),
+            get_issues_tool: Arc::new(GetIssuesTool::new(client())),
+            check_issue_quality_tool: Arc::new(CheckIssueQualityTool::new(client(), config())),
+            standup_summary_tool: Arc::new(GetStandupSummaryTool::new(
+                client(),
+                Arc::clone(&user_issues_tool),
+            )),
+            get_recent_requests_tool: Arc::new(GetRecentRequestsTool::new(client())),
+            set_reporter_tool: Arc::new(SetReporterTool::new(
+                client(),
+                Arc::clone(&assign_issue_tool),
+            )),
+            update_description_section_tool: Arc::new(UpdateDescriptionSectionTool::new(
+                client(),
+                Arc::clone(&update_description_tool),
+            )),
+            diff_issue_since_tool: Arc::new(DiffIssueSinceTool::new(client())),
+            get_issue_activity_tool: Arc::new(GetIssueActivityTool::new(client())),
+            get_project_stats_tool: Arc::new(GetProjectStatsTool::new(client())),
+            description_metadata_tool: Arc::new(DescriptionMetadataTool::new(
+                client(),
+                Arc::clone(&update_description_tool),
+            )),
+            get_my_timesheet_tool: Arc::new(GetMyTimesheetTool::new(client(), config())),
+            query_work_history_tool: Arc::new(QueryWorkHistoryTool::new(
+                Arc::clone(&ctx.work_history),
+                config(),
+            )),
+            issue_context_bundle_tool: Arc::new(GetIssueContextBundleTool::new(
+                client(),
+                Arc::clone(&todo_tracker),
+                Arc::clone(&download_attachment_tool),
+            )),
+            estimate_rollup_tool: Arc::new(GetEstimateRollupTool::new(client(), config())),
+            board_column_issues_tool: Arc::new(GetBoardColumnIssuesTool::new(
+                client(),
+                Arc::clone(&board_configuration_tool),
+            )),
+            my_mentions_tool: Arc::new(GetMyMentionsTool::new(client())),
+            my_permissions_tool: Arc::new(GetMyPermissionsTool::new(client(), config(), cache())),
+            todos_across_issues_tool: Arc::new(ListTodosAcrossIssuesTool::new(
+                client(),
+                Arc::clone(&todo_tracker),
+                Arc::clone(&user_issues_tool),
+            )),
+
+            priorities_tool,
+            assign_issue_tool,
+            user_issues_tool,
+            update_description_tool,
+            todo_tracker,
+            transition_issue_tool,
+            security_levels_tool,
+            create_issue_tool,
+            labels_tool,
+            list_sprints_tool,
+            get_sprint_issues_tool,
+            download_attachment_tool,
+            board_configuration_tool,
+        }
+    }
+}
@@ -3,12 +3,15 @@
 //! This module provides tools for managing JIRA issue status changes through transitions.
 //! JIRA doesn't allow direct status updates - you must trigger transitions between states.
 
+use crate::config::OperationClass;
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_id_or_key;
+use crate::tools::todo_tracker::{IssueSessionChange, TodoTracker};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use tracing::{debug, info, instrument};
+use tracing::{debug, info, instrument, warn};
 
 /// Parameters for getting available transitions
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -32,6 +35,10 @@ pub struct TransitionInfo {
 
     /// The target status ID
     pub to_status_id: String,
+
+    /// Category of the target status: "new", "indeterminate" (in progress) or "done"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to_status_category: Option<String>,
 }
 
 /// Result from get_available_transitions
@@ -69,6 +76,24 @@ pub struct TransitionIssueParams {
 
     /// Optional resolution name (for transitions that require resolution, e.g., "Done", "Won't Fix")
     pub resolution: Option<String>,
+
+    /// Start an issue work session when moving to an in-progress status, and
+    /// log and end it when moving to done (default: the server's
+    /// `todos.track_time_on_transition`)
+    #[serde(default)]
+    pub track_time: Option<bool>,
+
+    /// Time to log for the work session when moving to done, in hours
+    #[serde(default)]
+    pub time_spent_hours: Option<f64>,
+
+    /// Alternative to time_spent_hours, in minutes
+    #[serde(default)]
+    pub time_spent_minutes: Option<u64>,
+
+    /// Alternative to time_spent_hours, in seconds
+    #[serde(default)]
+    pub time_spent_seconds: Option<u64>,
 }
 
 /// Result from transition_issue
@@ -83,6 +108,16 @@ pub struct TransitionIssueResult {
     /// The transition that was executed
     pub transition_used: TransitionInfo,
 
+    /// What happened to the issue work session, when time tracking was on and
+    /// the target status started or finished work
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub work_session: Option<IssueSessionChange>,
+
+    /// Resolution set from the configured `default_resolution` after JIRA
+    /// refused the transition without one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_resolution_applied: Option<String>,
+
     /// Execution time in milliseconds
     pub execution_time_ms: u64,
 }
@@ -98,23 +133,33 @@ struct TransitionOption {
     id: String,
     name: String,
     to: TransitionTo,
+    /// Fields on the transition screen, with `expand=transitions.fields`
+    #[serde(default)]
+    fields: std::collections::BTreeMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
 struct TransitionTo {
     name: String,
     id: String,
+    #[serde(default, rename = "statusCategory")]
+    status_category: Option<StatusCategory>,
+}
+
+#[derive(Debug, Deserialize)]
+struct StatusCategory {
+    key: String,
 }
 
 /// Gouqi's transition trigger structure (for serialization)
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct TransitionTriggerOptions {
     transition: Transition,
     #[serde(skip_serializing_if = "std::collections::BTreeMap::is_empty")]
     fields: std::collections::BTreeMap<String, serde_json::Value>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 struct Transition {
     id: String,
 }
@@ -133,8 +178,11 @@ impl GetAvailableTransitionsTool {
     #[instrument(skip(self))]
     pub async fn execute(
         &self,
-        params: GetAvailableTransitionsParams,
+        mut params: GetAvailableTransitionsParams,
     ) -> JiraMcpResult<GetAvailableTransitionsResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
         let start_time = std::time::Instant::now();
 
         info!(
@@ -142,23 +190,8 @@ impl GetAvailableTransitionsTool {
             params.issue_key
         );
 
-        // Validate issue key format
-        self.validate_issue_key(&params.issue_key)?;
-
         // Get current issue status
-        let issue = self
-            .jira_client
-            .client
-            .issues()
-            .get(&params.issue_key)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
-                    JiraMcpError::not_found("issue", &params.issue_key)
-                } else {
-                    JiraMcpError::from(e)
-                }
-            })?;
+        let issue = self.jira_client.get_raw_issue(&params.issue_key).await?;
 
         let current_status = issue
             .field::<serde_json::Value>("status")
@@ -172,17 +205,8 @@ impl GetAvailableTransitionsTool {
             .unwrap_or_else(|| "Unknown".to_string());
 
         // Get available transitions
-        let endpoint = format!(
-            "/issue/{}/transitions?expand=transitions.fields",
-            params.issue_key
-        );
-
-        let transition_options: TransitionOptions = self
-            .jira_client
-            .client
-            .get("api", &endpoint)
-            .await
-            .map_err(JiraMcpError::from)?;
+        let transition_options: TransitionOptions =
+            self.jira_client.get_transitions(&params.issue_key).await?;
 
         let transitions = transition_options
             .transitions
@@ -192,6 +216,7 @@ impl GetAvailableTransitionsTool {
                 name: t.name,
                 to_status: t.to.name,
                 to_status_id: t.to.id,
+                to_status_category: t.to.status_category.map(|category| category.key),
             })
             .collect::<Vec<_>>();
 
@@ -211,43 +236,36 @@ impl GetAvailableTransitionsTool {
             execution_time_ms: execution_time,
         })
     }
-
-    fn validate_issue_key(&self, issue_key: &str) -> JiraMcpResult<()> {
-        if issue_key.is_empty() {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key cannot be empty",
-            ));
-        }
-
-        let parts: Vec<&str> = issue_key.split('-').collect();
-        if parts.len() != 2 {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key must be in format 'PROJECT-123'",
-            ));
-        }
-
-        Ok(())
-    }
 }
 
 /// Tool for transitioning an issue
-#[derive(Debug)]
 pub struct TransitionIssueTool {
     jira_client: Arc<JiraClient>,
+    todo_tracker: Option<Arc<TodoTracker>>,
 }
 
 impl TransitionIssueTool {
     pub fn new(jira_client: Arc<JiraClient>) -> Self {
-        Self { jira_client }
+        Self {
+            jira_client,
+            todo_tracker: None,
+        }
+    }
+
+    /// Start and finish issue work sessions on transitions (see `track_time`)
+    pub fn with_work_sessions(mut self, todo_tracker: Arc<TodoTracker>) -> Self {
+        self.todo_tracker = Some(todo_tracker);
+        self
     }
 
     #[instrument(skip(self))]
     pub async fn execute(
         &self,
-        params: TransitionIssueParams,
+        mut params: TransitionIssueParams,
     ) -> JiraMcpResult<TransitionIssueResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
         let start_time = std::time::Instant::now();
 
         info!("Transitioning issue: {}", params.issue_key);
@@ -309,6 +327,26 @@ impl TransitionIssueTool {
             transition_to_use.name, transition_to_use.id, transition_to_use.to_status
         );
 
+        // Work sessions only follow transitions when tracking is on for the call
+        let work_sessions = self.todo_tracker.as_ref().filter(|tracker| {
+            params
+                .track_time
+                .unwrap_or_else(|| tracker.tracks_time_on_transition())
+        });
+        let status_category = transition_to_use.to_status_category.as_deref();
+        let explicit_seconds = if let Some(seconds) = params.time_spent_seconds {
+            Some(seconds)
+        } else if let Some(minutes) = params.time_spent_minutes {
+            Some(minutes * 60)
+        } else {
+            params.time_spent_hours.map(|hours| (hours * 3600.0) as u64)
+        };
+        if let (Some(tracker), Some("done")) = (work_sessions, status_category) {
+            tracker
+                .check_issue_session_done(&params.issue_key, explicit_seconds)
+                .await?;
+        }
+
         // Build transition request
         let mut fields = std::collections::BTreeMap::new();
 
@@ -334,29 +372,54 @@ impl TransitionIssueTool {
             );
         }
 
-        let trigger_options = TransitionTriggerOptions {
+        let mut trigger_options = TransitionTriggerOptions {
             transition: Transition {
                 id: transition_to_use.id.clone(),
             },
             fields,
         };
 
-        // Execute transition
-        let endpoint = format!("/issue/{}/transitions", params.issue_key);
-        self.jira_client
-            .client
-            .post::<serde_json::Value, _>("api", &endpoint, trigger_options)
+        // Execute transition, retrying once with the default resolution when
+        // JIRA asks for one the call didn't give
+        let mut default_resolution_applied = None;
+        match self
+            .post_transition(&params.issue_key, trigger_options.clone())
             .await
-            .or_else(|e| {
-                // JIRA transitions endpoint returns 204 No Content on success,
-                // which can cause serde deserialization errors. Treat these as success.
-                if e.to_string().contains("expected value") {
-                    debug!("Ignoring deserialization error (likely 204 No Content response)");
-                    Ok(serde_json::Value::Null)
-                } else {
-                    Err(JiraMcpError::from(e))
-                }
-            })?;
+        {
+            Err(e) if e.is_resolution_required() && params.resolution.is_none() => {
+                let resolution = self
+                    .default_resolution_for(&params.issue_key, &transition_to_use)
+                    .await?;
+                info!(
+                    "{} needs a resolution, retrying with the default '{}'",
+                    params.issue_key, resolution
+                );
+                trigger_options.fields.insert(
+                    "resolution".to_string(),
+                    serde_json::json!({ "name": resolution }),
+                );
+                self.post_transition(&params.issue_key, trigger_options)
+                    .await?;
+                default_resolution_applied = Some(resolution);
+            }
+            result => result?,
+        }
+
+        let work_session = match (work_sessions, status_category) {
+            (Some(tracker), Some("indeterminate")) => {
+                Some(tracker.start_issue_session(&params.issue_key).await)
+            }
+            (Some(tracker), Some("done")) => {
+                tracker
+                    .complete_issue_session(
+                        &params.issue_key,
+                        explicit_seconds,
+                        &transition_to_use.to_status,
+                    )
+                    .await?
+            }
+            _ => None,
+        };
 
         let execution_time = start_time.elapsed().as_millis() as u64;
 
@@ -369,27 +432,108 @@ impl TransitionIssueTool {
             success: true,
             issue_key: params.issue_key,
             transition_used: transition_to_use,
+            work_session,
+            default_resolution_applied,
             execution_time_ms: execution_time,
         })
     }
 
-    fn validate_params(&self, params: &TransitionIssueParams) -> JiraMcpResult<()> {
-        // Validate issue key
-        if params.issue_key.is_empty() {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key cannot be empty",
-            ));
-        }
+    async fn post_transition(
+        &self,
+        issue_key: &str,
+        trigger_options: TransitionTriggerOptions,
+    ) -> JiraMcpResult<()> {
+        self.jira_client
+            .transition_issue(issue_key, trigger_options)
+            .await
+    }
 
-        let parts: Vec<&str> = params.issue_key.split('-').collect();
-        if parts.len() != 2 {
+    /// The configured default resolution, checked against the ones the
+    /// transition allows; without a default, an error listing those
+    async fn default_resolution_for(
+        &self,
+        issue_key: &str,
+        transition: &TransitionInfo,
+    ) -> JiraMcpResult<String> {
+        let allowed = self.allowed_resolutions(issue_key, &transition.id).await;
+        let listed = if allowed.is_empty() {
+            "unknown".to_string()
+        } else {
+            allowed.join(", ")
+        };
+
+        let Some(default) = self.jira_client.default_resolution() else {
             return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key must be in format 'PROJECT-123'",
+                "resolution",
+                format!(
+                    "The '{}' transition requires a resolution. Allowed: {}. Pass one as \
+                     resolution, or set default_resolution in the config to apply one \
+                     automatically",
+                    transition.name, listed
+                ),
             ));
+        };
+
+        pick_resolution(default, &allowed).ok_or_else(|| {
+            JiraMcpError::invalid_param(
+                "resolution",
+                format!(
+                    "The '{}' transition requires a resolution, and the default_resolution \
+                     '{}' is not one it allows. Allowed: {}",
+                    transition.name, default, listed
+                ),
+            )
+        })
+    }
+
+    /// Names of the resolutions a transition allows, from its screen or, when
+    /// the screen doesn't list them, all of the instance's resolutions
+    ///
+    /// Empty when they could not be read; the caller is already handling an
+    /// error, so a failed lookup only warns.
+    async fn allowed_resolutions(&self, issue_key: &str, transition_id: &str) -> Vec<String> {
+        self.read_resolutions(issue_key, transition_id)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Could not read the resolutions of {}: {}", issue_key, e);
+                Vec::new()
+            })
+    }
+
+    async fn read_resolutions(
+        &self,
+        issue_key: &str,
+        transition_id: &str,
+    ) -> JiraMcpResult<Vec<String>> {
+        let transition_options: TransitionOptions =
+            self.jira_client.get_transitions(issue_key).await?;
+        let on_screen = transition_options
+            .transitions
+            .into_iter()
+            .find(|t| t.id == transition_id)
+            .and_then(|t| t.fields.get("resolution").map(resolution_names))
+            .unwrap_or_default();
+        if !on_screen.is_empty() {
+            return Ok(on_screen);
         }
 
+        let resolutions: Vec<serde_json::Value> = self
+            .jira_client
+            .get_json(
+                OperationClass::Read,
+                "reading resolutions",
+                "api",
+                "/resolution",
+                JiraMcpError::from,
+            )
+            .await?;
+        Ok(resolutions
+            .iter()
+            .filter_map(|resolution| resolution["name"].as_str().map(String::from))
+            .collect())
+    }
+
+    fn validate_params(&self, params: &TransitionIssueParams) -> JiraMcpResult<()> {
         // Must provide either transition_id or transition_name
         if params.transition_id.is_none() && params.transition_name.is_none() {
             return Err(JiraMcpError::invalid_param(
@@ -421,18 +565,73 @@ impl TransitionIssueTool {
         Ok(())
     }
 
+    /// Move an issue to `target_status` using whichever transition leads there
+    ///
+    /// Returns `None` without touching the issue when it is already in that
+    /// status, so callers can treat the step as done.
+    pub async fn transition_to_status(
+        &self,
+        issue_key: &str,
+        target_status: &str,
+        resolution: Option<&str>,
+    ) -> JiraMcpResult<Option<TransitionInfo>> {
+        let fields = self
+            .jira_client
+            .get_issue_fields(issue_key, &["status".to_string()])
+            .await?;
+        let current_status = fields
+            .get("status")
+            .and_then(|status| status["name"].as_str())
+            .unwrap_or_default();
+        if current_status.eq_ignore_ascii_case(target_status) {
+            debug!("{} is already in status {}", issue_key, current_status);
+            return Ok(None);
+        }
+
+        let available_transitions = self.get_available_transitions(issue_key).await?;
+        let transition = available_transitions
+            .iter()
+            .find(|t| t.to_status.eq_ignore_ascii_case(target_status))
+            .ok_or_else(|| {
+                JiraMcpError::invalid_param(
+                    "status",
+                    format!(
+                        "No transition from '{}' leads to '{}'. Available: {}",
+                        current_status,
+                        target_status,
+                        available_transitions
+                            .iter()
+                            .map(|t| format!("{} -> {}", t.name, t.to_status))
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                )
+            })?;
+
+        let result = self
+            .execute(TransitionIssueParams {
+                issue_key: issue_key.to_string(),
+                transition_id: Some(transition.id.clone()),
+                transition_name: None,
+                comment: None,
+                resolution: resolution.map(String::from),
+                // Moves made on behalf of other tools leave work sessions alone
+                track_time: Some(false),
+                time_spent_hours: None,
+                time_spent_minutes: None,
+                time_spent_seconds: None,
+            })
+            .await?;
+
+        Ok(Some(result.transition_used))
+    }
+
     async fn get_available_transitions(
         &self,
         issue_key: &str,
     ) -> JiraMcpResult<Vec<TransitionInfo>> {
-        let endpoint = format!("/issue/{}/transitions?expand=transitions.fields", issue_key);
-
-        let transition_options: TransitionOptions = self
-            .jira_client
-            .client
-            .get("api", &endpoint)
-            .await
-            .map_err(JiraMcpError::from)?;
+        let transition_options: TransitionOptions =
+            self.jira_client.get_transitions(issue_key).await?;
 
         Ok(transition_options
             .transitions
@@ -442,7 +641,62 @@ impl TransitionIssueTool {
                 name: t.name,
                 to_status: t.to.name,
                 to_status_id: t.to.id,
+                to_status_category: t.to.status_category.map(|category| category.key),
             })
             .collect())
     }
 }
+
+/// Names in the `allowedValues` of a transition screen field
+fn resolution_names(field: &serde_json::Value) -> Vec<String> {
+    field["allowedValues"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|value| value["name"].as_str().map(String::from))
+        .collect()
+}
+
+/// The allowed resolution matching `default` (ignoring case), or `default`
+/// itself when the allowed ones are unknown
+fn pick_resolution(default: &str, allowed: &[String]) -> Option<String> {
+    if allowed.is_empty() {
+        return Some(default.to_string());
+    }
+    allowed
+        .iter()
+        .find(|name| name.eq_ignore_ascii_case(default.trim()))
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_resolution_from_transition_screen() {
+        let options: TransitionOptions = serde_json::from_value(serde_json::json!({
+            "transitions": [{
+                "id": "31",
+                "name": "Done",
+                "to": {"id": "10002", "name": "Done"},
+                "fields": {
+                    "resolution": {
+                        "required": true,
+                        "allowedValues": [
+                            {"id": "10000", "name": "Done"},
+                            {"id": "10001", "name": "Won't Do"}
+                        ]
+                    }
+                }
+            }]
+        }))
+        .unwrap();
+        let allowed = resolution_names(&options.transitions[0].fields["resolution"]);
+        assert_eq!(allowed, vec!["Done", "Won't Do"]);
+
+        assert_eq!(pick_resolution("done", &allowed).as_deref(), Some("Done"));
+        assert_eq!(pick_resolution("Fixed", &allowed), None);
+        assert_eq!(pick_resolution("Fixed", &[]).as_deref(), Some("Fixed"));
+    }
+}
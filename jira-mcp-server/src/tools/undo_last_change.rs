@@ -0,0 +1,197 @@
+//! Undo last change tool
+//!
+//! Restores the values captured before the most recent description or field write
+//! made through this server, after checking nobody has changed them since.
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_key;
+use crate::undo::{restorable, values_match, UndoHistory};
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::{info, instrument, warn};
+
+/// Parameters for the undo_last_change tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UndoLastChangeParams {
+    /// Issue whose most recent change should be undone (required)
+    /// Examples: "PROJ-123"
+    pub issue_key: String,
+}
+
+/// A field that changed remotely since our write
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct UndoConflict {
+    /// Field ID (e.g. "description", "customfield_10016")
+    pub field: String,
+
+    /// What this server wrote
+    pub expected: Value,
+
+    /// What the issue holds now
+    pub current: Value,
+}
+
+/// Result from the undo_last_change tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct UndoLastChangeResult {
+    /// Issue key
+    pub issue_key: String,
+
+    /// Whether the previous values were restored
+    pub restored: bool,
+
+    /// Tool that made the change being undone
+    pub source: String,
+
+    /// When that change was made
+    #[schemars(with = "String")]
+    pub changed_at: DateTime<Utc>,
+
+    /// Fields covered by the change
+    pub fields: Vec<String>,
+
+    /// Fields edited since our write; nothing is restored when any exist
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub conflicts: Vec<UndoConflict>,
+
+    /// Undo entries still held for this issue
+    pub remaining_undo_entries: usize,
+
+    /// Human-readable summary
+    pub message: String,
+}
+
+/// Tool for undoing description and field writes
+pub struct UndoLastChangeTool {
+    jira_client: Arc<JiraClient>,
+    undo_history: Arc<UndoHistory>,
+}
+
+impl UndoLastChangeTool {
+    pub fn new(jira_client: Arc<JiraClient>, undo_history: Arc<UndoHistory>) -> Self {
+        Self {
+            jira_client,
+            undo_history,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn execute(
+        &self,
+        mut params: UndoLastChangeParams,
+    ) -> JiraMcpResult<UndoLastChangeResult> {
+        params.issue_key =
+            normalize_issue_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        let entry = self.undo_history.latest(&params.issue_key).ok_or_else(|| {
+            JiraMcpError::invalid_param(
+                "issue_key",
+                format!(
+                    "No undo history for {}. Only description and field changes made by this server are kept, and entries expire.",
+                    params.issue_key
+                ),
+            )
+        })?;
+
+        let fields: Vec<String> = entry.fields.keys().cloned().collect();
+        let current = self
+            .jira_client
+            .get_issue_fields(&params.issue_key, &fields)
+            .await?;
+
+        let conflicts: Vec<UndoConflict> = entry
+            .fields
+            .iter()
+            .filter_map(|(field, change)| {
+                let current = current.get(field).cloned().unwrap_or(Value::Null);
+                (!values_match(&change.written, &current)).then(|| UndoConflict {
+                    field: field.clone(),
+                    expected: change.written.clone(),
+                    current,
+                })
+            })
+            .collect();
+
+        if !conflicts.is_empty() {
+            warn!(
+                "Not undoing change to {}: {} field(s) edited since",
+                params.issue_key,
+                conflicts.len()
+            );
+            return Ok(UndoLastChangeResult {
+                message: format!(
+                    "Conflict: {} was edited after the {} change ({}), so nothing was restored. Compare expected and current values before retrying manually.",
+                    params.issue_key,
+                    entry.source,
+                    conflicts
+                        .iter()
+                        .map(|c| c.field.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                remaining_undo_entries: self.undo_history.count_for(&params.issue_key),
+                issue_key: params.issue_key,
+                restored: false,
+                source: entry.source,
+                changed_at: entry.captured_at,
+                fields,
+                conflicts,
+            });
+        }
+
+        let restore: serde_json::Map<String, Value> = entry
+            .fields
+            .iter()
+            .map(|(field, change)| (field.clone(), restorable(&change.previous)))
+            .collect();
+
+        let endpoint = format!("/issue/{}", params.issue_key);
+        self.jira_client
+            .put_json::<Value>(
+                &format!("restoring {}", params.issue_key),
+                "api",
+                &endpoint,
+                serde_json::json!({ "fields": restore }),
+                |e| {
+                    if e.to_string().contains("400") || e.to_string().contains("Bad Request") {
+                        JiraMcpError::invalid_param(
+                            "issue_key",
+                            format!("JIRA rejected the restored values: {}", e),
+                        )
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        self.undo_history.remove(&params.issue_key, entry.id);
+        info!(
+            "Undid {} change to {} ({})",
+            entry.source,
+            params.issue_key,
+            fields.join(", ")
+        );
+
+        Ok(UndoLastChangeResult {
+            message: format!(
+                "Restored {} on {} to the values before the {} change",
+                fields.join(", "),
+                params.issue_key,
+                entry.source
+            ),
+            remaining_undo_entries: self.undo_history.count_for(&params.issue_key),
+            issue_key: params.issue_key,
+            restored: true,
+            source: entry.source,
+            changed_at: entry.captured_at,
+            fields,
+            conflicts: Vec::new(),
+        })
+    }
+}
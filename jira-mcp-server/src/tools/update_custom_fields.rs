@@ -1,14 +1,20 @@
 //! Tool for updating custom fields in JIRA issues
 
+use crate::config::{JiraConfig, START_DATE_FIELD};
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::JiraClient;
+use crate::semantic_mapping::resolve_date;
+use crate::tools::issue_key::normalize_issue_key;
+use crate::undo::{FieldChange, UndoHistory};
+use chrono::{NaiveDate, Utc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::sync::Arc;
 use tracing::{info, instrument};
 
 /// Parameters for updating custom fields
-#[derive(Debug, Deserialize, JsonSchema)]
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct UpdateCustomFieldsParams {
     /// The JIRA issue key to update (e.g., "PROJ-123")
     pub issue_key: String,
@@ -33,6 +39,19 @@ pub struct UpdateCustomFieldsParams {
     /// Override acceptance criteria field ID (if auto-detection fails)
     #[serde(default)]
     pub acceptance_criteria_field_id: Option<String>,
+
+    /// Due date: ISO date or expression like "next friday", "+2w", "end of month";
+    /// "none" clears it
+    #[serde(default)]
+    pub due_date: Option<String>,
+
+    /// Start date, same forms as due_date; needs field_mappings.start_date or start_date_field_id
+    #[serde(default)]
+    pub start_date: Option<String>,
+
+    /// Override the start date field ID
+    #[serde(default)]
+    pub start_date_field_id: Option<String>,
 }
 
 /// Result from updating custom fields
@@ -51,18 +70,31 @@ pub struct UpdateCustomFieldsResult {
 /// Tool for updating custom fields
 pub struct UpdateCustomFieldsTool {
     jira_client: Arc<JiraClient>,
+    config: Arc<JiraConfig>,
+    undo_history: Arc<UndoHistory>,
 }
 
 impl UpdateCustomFieldsTool {
-    pub fn new(jira_client: Arc<JiraClient>) -> Self {
-        Self { jira_client }
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        config: Arc<JiraConfig>,
+        undo_history: Arc<UndoHistory>,
+    ) -> Self {
+        Self {
+            jira_client,
+            config,
+            undo_history,
+        }
     }
 
     #[instrument(skip(self))]
     pub async fn execute(
         &self,
-        params: UpdateCustomFieldsParams,
+        mut params: UpdateCustomFieldsParams,
     ) -> JiraMcpResult<UpdateCustomFieldsResult> {
+        params.issue_key =
+            normalize_issue_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
         info!("Updating custom fields for issue: {}", params.issue_key);
 
         let mut updates = serde_json::Map::new();
@@ -105,6 +137,44 @@ impl UpdateCustomFieldsTool {
             }
         }
 
+        // Handle due and start dates (Some(None) clears the field)
+        let today = Utc::now().date_naive();
+        let due_date = params
+            .due_date
+            .as_deref()
+            .map(|input| parse_date_param(input, today, "due_date"))
+            .transpose()?;
+        if let Some(due) = due_date {
+            updates.insert(
+                "duedate".to_string(),
+                serde_json::json!(due.map(|d| d.to_string())),
+            );
+            updated_field_names.push("due_date (duedate)".to_string());
+        }
+
+        let start_date_field = params
+            .start_date_field_id
+            .clone()
+            .or_else(|| self.config.field_mappings.get(START_DATE_FIELD).cloned());
+        let start_date = params
+            .start_date
+            .as_deref()
+            .map(|input| parse_date_param(input, today, "start_date"))
+            .transpose()?;
+        if let Some(start) = start_date {
+            let field_id = start_date_field.clone().ok_or_else(|| {
+                JiraMcpError::invalid_param(
+                    "start_date",
+                    "No start date field configured. Set field_mappings.start_date (or JIRA_START_DATE_FIELD), or pass start_date_field_id",
+                )
+            })?;
+            updates.insert(
+                field_id.clone(),
+                serde_json::json!(start.map(|d| d.to_string())),
+            );
+            updated_field_names.push(format!("start_date ({})", field_id));
+        }
+
         if updates.is_empty() {
             return Err(JiraMcpError::invalid_param(
                 "updates",
@@ -112,29 +182,99 @@ impl UpdateCustomFieldsTool {
             ));
         }
 
+        // JIRA rejects the whole update with a vague 400 when a field is not on the
+        // edit screen; name the field instead
+        let edit_meta = self.jira_client.get_edit_meta(&params.issue_key).await?;
+        if let Some(field_id) = updates.keys().find(|id| !edit_meta.contains_key(*id)) {
+            let label = updated_field_names
+                .iter()
+                .find(|name| *name == field_id || name.ends_with(&format!("({})", field_id)))
+                .cloned()
+                .unwrap_or_else(|| field_id.clone());
+            return Err(JiraMcpError::invalid_param(
+                label,
+                format!(
+                    "Field {} is not on the edit screen for this issue type. Add it to the \
+                     screen in JIRA, or use get_custom_fields to find an editable field.",
+                    field_id
+                ),
+            ));
+        }
+
+        // Capture the current values for undo_last_change, plus the other date
+        // field when only one of due/start is being changed
+        let mut field_ids: Vec<String> = updates.keys().cloned().collect();
+        if due_date.is_some() || start_date.is_some() {
+            field_ids.extend(
+                std::iter::once("duedate".to_string())
+                    .chain(start_date_field.clone())
+                    .filter(|id| !updates.contains_key(id)),
+            );
+        }
+        let previous_values = self
+            .jira_client
+            .get_issue_fields(&params.issue_key, &field_ids)
+            .await?;
+
+        if due_date.is_some() || start_date.is_some() {
+            let current_date = |field_id: &str| {
+                previous_values
+                    .get(field_id)
+                    .and_then(Value::as_str)
+                    .and_then(|value| NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d").ok())
+            };
+            let due = due_date.unwrap_or_else(|| current_date("duedate"));
+            let start =
+                start_date.unwrap_or_else(|| start_date_field.as_deref().and_then(current_date));
+            check_date_order(due, start)?;
+        }
+
         // Build the update request
         let update_body = serde_json::json!({
-            "fields": updates
+            "fields": &updates
         });
 
         // Make the API call
         let endpoint = format!("/issue/{}", params.issue_key);
         self.jira_client
-            .client
-            .put::<(), _>("api", &endpoint, update_body)
-            .await
-            .map_err(|e| {
-                if e.to_string().contains("404") || e.to_string().contains("Not Found") {
-                    JiraMcpError::not_found("issue", &params.issue_key)
-                } else if e.to_string().contains("400") || e.to_string().contains("Bad Request") {
-                    JiraMcpError::invalid_param(
-                        "field_updates",
-                        format!("Invalid field update: {}", e),
-                    )
-                } else {
-                    JiraMcpError::from(e)
-                }
-            })?;
+            .put_json::<Value>(
+                &format!("updating the custom fields of {}", params.issue_key),
+                "api",
+                &endpoint,
+                update_body,
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("issue", &params.issue_key)
+                    } else if e.to_string().contains("400") || e.to_string().contains("Bad Request")
+                    {
+                        JiraMcpError::invalid_param(
+                            "field_updates",
+                            format!("Invalid field update: {}", e),
+                        )
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        self.undo_history.record(
+            &params.issue_key,
+            "update_custom_fields",
+            updates
+                .iter()
+                .map(|(field_id, written)| {
+                    let change = FieldChange {
+                        previous: previous_values
+                            .get(field_id)
+                            .cloned()
+                            .unwrap_or(Value::Null),
+                        written: written.clone(),
+                    };
+                    (field_id.clone(), change)
+                })
+                .collect(),
+        );
 
         info!(
             "Updated {} custom fields for issue {}",
@@ -155,20 +295,19 @@ impl UpdateCustomFieldsTool {
 
     /// Try to detect the story points field ID for an issue
     async fn detect_story_points_field(&self, issue_key: &str) -> JiraMcpResult<Option<String>> {
-        let issue = self
-            .jira_client
-            .client
-            .issues()
-            .get(issue_key)
-            .await
-            .map_err(JiraMcpError::from)?;
+        let issue = self.jira_client.get_raw_issue(issue_key).await?;
+
+        // Configured or detected from the field catalog
+        let story_points = &self.jira_client.field_map().story_points;
+        if let Some(field_id) = story_points.field_for(&issue.fields) {
+            return Ok(Some(field_id.to_string()));
+        }
+        if story_points.source != "default" {
+            return Ok(story_points.field_ids.first().cloned());
+        }
 
         // Try common field IDs
-        let common_ids = vec![
-            "customfield_10016",
-            "customfield_10026",
-            "customfield_10106",
-        ];
+        let common_ids = vec!["customfield_10026", "customfield_10106"];
 
         for field_id in common_ids {
             if let Some(value) = issue.fields.get(field_id) {
@@ -193,20 +332,19 @@ impl UpdateCustomFieldsTool {
         &self,
         issue_key: &str,
     ) -> JiraMcpResult<Option<String>> {
-        let issue = self
-            .jira_client
-            .client
-            .issues()
-            .get(issue_key)
-            .await
-            .map_err(JiraMcpError::from)?;
+        let issue = self.jira_client.get_raw_issue(issue_key).await?;
+
+        // Configured or detected from the field catalog
+        let acceptance_criteria = &self.jira_client.field_map().acceptance_criteria;
+        if let Some(field_id) = acceptance_criteria.field_for(&issue.fields) {
+            return Ok(Some(field_id.to_string()));
+        }
+        if acceptance_criteria.source != "default" {
+            return Ok(acceptance_criteria.field_ids.first().cloned());
+        }
 
         // Try common field IDs
-        let common_ids = vec![
-            "customfield_10100",
-            "customfield_10007",
-            "customfield_10200",
-        ];
+        let common_ids = vec!["customfield_10200"];
 
         for field_id in common_ids {
             if let Some(value) = issue.fields.get(field_id) {
@@ -219,3 +357,52 @@ impl UpdateCustomFieldsTool {
         Ok(None)
     }
 }
+
+/// Resolve a date parameter; "none" (or an empty string) clears the field
+pub(crate) fn parse_date_param(
+    input: &str,
+    today: NaiveDate,
+    parameter: &str,
+) -> JiraMcpResult<Option<NaiveDate>> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() || trimmed.eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+    resolve_date(trimmed, today, parameter).map(Some)
+}
+
+/// The due date may not be before the start date
+fn check_date_order(due: Option<NaiveDate>, start: Option<NaiveDate>) -> JiraMcpResult<()> {
+    match (due, start) {
+        (Some(due), Some(start)) if due < start => Err(JiraMcpError::invalid_param(
+            "due_date",
+            format!("Due date {} is before the start date {}", due, start),
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_date_params() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 12).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+
+        assert_eq!(
+            parse_date_param("next friday", today, "due_date").unwrap(),
+            date(2025, 3, 21)
+        );
+        assert_eq!(parse_date_param("none", today, "due_date").unwrap(), None);
+        assert_eq!(parse_date_param("", today, "due_date").unwrap(), None);
+        assert!(parse_date_param("whenever", today, "due_date").is_err());
+
+        assert!(check_date_order(date(2025, 3, 21), date(2025, 3, 12)).is_ok());
+        assert!(check_date_order(date(2025, 3, 12), date(2025, 3, 12)).is_ok());
+        assert!(check_date_order(date(2025, 3, 11), date(2025, 3, 12)).is_err());
+        assert!(check_date_order(None, date(2025, 3, 12)).is_ok());
+        assert!(check_date_order(date(2025, 3, 11), None).is_ok());
+    }
+}
@@ -1,7 +1,14 @@
-use crate::error::JiraMcpResult;
+use crate::adf;
+use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_key;
+use crate::tools::text_diff::unified_diff;
+use crate::tools::text_limit::{fit_text, too_long, truncation_marker};
+use crate::undo::{FieldChange, UndoHistory};
+use chrono::{DateTime, Utc};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::BTreeMap;
 use std::sync::Arc;
 use tracing::{debug, info, instrument};
@@ -18,7 +25,20 @@ pub enum UpdateMode {
     Prepend,
 }
 
-#[derive(Debug, Deserialize, JsonSchema)]
+/// What goes between the existing description and appended/prepended content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DescriptionSeparator {
+    /// A blank line only (default)
+    #[default]
+    None,
+    /// A horizontal rule
+    Rule,
+    /// A horizontal rule plus an "Updated <UTC time>" line, for audit-style logs
+    TimestampedRule,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct UpdateDescriptionParams {
     /// The JIRA issue key (e.g., "PROJ-123")
     pub issue_key: String,
@@ -29,6 +49,29 @@ pub struct UpdateDescriptionParams {
     /// How to update the description: "replace", "append" (default), or "prepend"
     #[serde(default)]
     pub mode: UpdateMode,
+
+    /// Separator for append/prepend: "none" (default), "rule" or "timestamped_rule"
+    #[serde(default)]
+    pub separator: DescriptionSeparator,
+
+    /// Preview only: return the new description and a diff without writing (default: false)
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Include a unified diff of the change in the result (default: false; always on for dry_run)
+    #[serde(default)]
+    pub include_diff: bool,
+
+    /// Only write if the issue's `updated` timestamp still equals this value
+    /// (as returned by get_issue_details); otherwise fail instead of overwriting
+    /// an edit made since it was read
+    #[serde(default)]
+    pub expected_updated: Option<String>,
+
+    /// Cut a description longer than max_description_chars (default 32767) and
+    /// end it with a truncation marker, instead of failing (default: false)
+    #[serde(default)]
+    pub auto_truncate: bool,
 }
 
 #[derive(Debug, Serialize)]
@@ -44,97 +87,583 @@ pub struct UpdateDescriptionResult {
 
     /// The new description (for confirmation)
     pub new_description: String,
+
+    /// True when nothing was written (dry_run)
+    pub dry_run: bool,
+
+    /// Unified diff from the current to the new description (dry_run or include_diff)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+
+    /// Characters auto_truncate cut from the end of the description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncated_chars: Option<usize>,
+}
+
+/// How edit_description checks and writes the edited description
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct EditOptions<'a> {
+    /// Only write if the issue's `updated` timestamp still equals this value
+    pub expected_updated: Option<&'a str>,
+
+    /// Return the new description without writing it
+    pub dry_run: bool,
+
+    /// Compute a unified diff of the change
+    pub include_diff: bool,
+
+    /// Cut a description over max_description_chars instead of failing
+    pub auto_truncate: bool,
+}
+
+/// Outcome of an edit_description call
+pub(crate) struct DescriptionEdit {
+    /// Plain-text view of the new description
+    pub new_description: String,
+
+    /// Unified diff from the current to the new description, when requested
+    pub diff: Option<String>,
+
+    /// Characters cut by auto_truncate
+    pub truncated_chars: Option<usize>,
 }
 
 pub struct UpdateDescription {
     jira_client: Arc<JiraClient>,
+    undo_history: Arc<UndoHistory>,
 }
 
 impl UpdateDescription {
-    pub fn new(jira_client: Arc<JiraClient>) -> Self {
-        Self { jira_client }
+    pub fn new(jira_client: Arc<JiraClient>, undo_history: Arc<UndoHistory>) -> Self {
+        Self {
+            jira_client,
+            undo_history,
+        }
     }
 
-    #[instrument(skip(self), fields(issue_key = %params.issue_key))]
     pub async fn execute(
         &self,
         params: UpdateDescriptionParams,
     ) -> JiraMcpResult<UpdateDescriptionResult> {
+        self.execute_with_source(params, "update_issue_description")
+            .await
+    }
+
+    /// Update the description, recording the undo entry under `source`
+    #[instrument(skip(self), fields(issue_key = %params.issue_key))]
+    pub(crate) async fn execute_with_source(
+        &self,
+        mut params: UpdateDescriptionParams,
+        source: &str,
+    ) -> JiraMcpResult<UpdateDescriptionResult> {
+        params.issue_key =
+            normalize_issue_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
         info!(
             "Updating description for issue {} with mode: {:?}",
             params.issue_key, params.mode
         );
 
-        // First, get the current description if we're appending or prepending
-        let new_description = match params.mode {
-            UpdateMode::Replace => params.content.clone(),
-            UpdateMode::Append | UpdateMode::Prepend => {
-                debug!(
-                    "Fetching current description for issue: {}",
-                    params.issue_key
-                );
-
-                let current_issue = self
-                    .jira_client
-                    .client
-                    .issues()
-                    .get(&params.issue_key)
-                    .await?;
-
-                let current_description =
-                    current_issue.description().unwrap_or_default().to_string();
-
-                match params.mode {
-                    UpdateMode::Append => {
-                        if current_description.is_empty() {
-                            params.content.clone()
-                        } else {
-                            format!("{}\n\n{}", current_description, params.content)
-                        }
-                    }
-                    UpdateMode::Prepend => {
-                        if current_description.is_empty() {
-                            params.content.clone()
-                        } else {
-                            format!("{}\n\n{}", params.content, current_description)
-                        }
-                    }
-                    UpdateMode::Replace => unreachable!(),
-                }
+        // ADF documents (Cloud) are edited as JSON; wiki/markdown as text
+        let edit = self
+            .edit_description(
+                &params.issue_key,
+                EditOptions {
+                    expected_updated: params.expected_updated.as_deref(),
+                    dry_run: params.dry_run,
+                    include_diff: params.include_diff,
+                    auto_truncate: params.auto_truncate,
+                },
+                source,
+                |current| {
+                    Ok(merge_description(
+                        current,
+                        &params.content,
+                        &params.mode,
+                        params.separator,
+                        Utc::now(),
+                    ))
+                },
+            )
+            .await?;
+
+        Ok(UpdateDescriptionResult {
+            success: true,
+            issue_key: params.issue_key,
+            mode: format!("{:?}", params.mode).to_lowercase(),
+            new_description: edit.new_description,
+            dry_run: params.dry_run,
+            diff: edit.diff,
+            truncated_chars: edit.truncated_chars,
+        })
+    }
+
+    /// Read the stored description, apply `edit` to it and write the result
+    ///
+    /// The current value is always fetched: edits build on it, and it is
+    /// captured for undo_last_change. With `expected_updated`, nothing is written
+    /// if the issue changed since the caller read it. A result over
+    /// max_description_chars fails before anything is sent, unless
+    /// `auto_truncate` cuts it.
+    pub(crate) async fn edit_description(
+        &self,
+        issue_key: &str,
+        options: EditOptions<'_>,
+        source: &str,
+        edit: impl FnOnce(&Value) -> JiraMcpResult<Value>,
+    ) -> JiraMcpResult<DescriptionEdit> {
+        debug!("Fetching current description for issue: {}", issue_key);
+
+        let current_issue = self.jira_client.get_raw_issue(issue_key).await?;
+
+        if let Some(expected) = options
+            .expected_updated
+            .filter(|expected| !expected.is_empty())
+        {
+            // Raw, as IssueInfo carries it
+            let updated = current_issue
+                .fields
+                .get("updated")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+            if updated != expected {
+                return Err(JiraMcpError::invalid_param(
+                    "expected_updated",
+                    format!(
+                        "{} was modified at {} (expected {}); re-read the description and retry",
+                        issue_key, updated, expected
+                    ),
+                ));
             }
-        };
+        }
+
+        let previous_value = current_issue
+            .fields
+            .get("description")
+            .cloned()
+            .unwrap_or(Value::Null);
+        let current_description = description_text(&previous_value);
+
+        let (new_value, truncated_chars) = fit_description(
+            edit(&previous_value)?,
+            self.jira_client.write_limits().max_description_chars,
+            options.auto_truncate,
+        )?;
+        let new_description = description_text(&new_value);
 
         debug!(
             "New description length: {} characters",
             new_description.len()
         );
 
+        let diff = (options.dry_run || options.include_diff)
+            .then(|| unified_diff(&current_description, &new_description));
+
+        if options.dry_run {
+            info!("Dry run: not writing description for issue {}", issue_key);
+            return Ok(DescriptionEdit {
+                new_description,
+                diff,
+                truncated_chars,
+            });
+        }
+
         // Build the update payload using gouqi's EditIssue
         let mut fields = BTreeMap::new();
-        fields.insert(
-            "description".to_string(),
-            serde_json::json!(new_description),
-        );
+        fields.insert("description".to_string(), new_value.clone());
 
         let edit_issue = gouqi::issues::EditIssue { fields };
 
         // Perform the update
         self.jira_client
-            .client
-            .issues()
-            .update(&params.issue_key, edit_issue)
+            .put_json::<Value>(
+                &format!("updating the description of {}", issue_key),
+                "api",
+                &format!("/issue/{}", issue_key),
+                edit_issue,
+                JiraMcpError::from,
+            )
             .await?;
 
-        info!(
-            "Successfully updated description for issue {}",
-            params.issue_key
+        self.undo_history.record(
+            issue_key,
+            source,
+            BTreeMap::from([(
+                "description".to_string(),
+                FieldChange {
+                    previous: previous_value,
+                    written: new_value,
+                },
+            )]),
         );
 
-        Ok(UpdateDescriptionResult {
-            success: true,
-            issue_key: params.issue_key,
-            mode: format!("{:?}", params.mode).to_lowercase(),
+        info!("Successfully updated description for issue {}", issue_key);
+
+        Ok(DescriptionEdit {
             new_description,
+            diff,
+            truncated_chars,
         })
     }
 }
+
+/// Fit a description value into `max_chars` characters of text
+///
+/// Text is cut at the limit; ADF documents lose trailing nodes instead, so the
+/// document stays valid. Either way it then ends with a truncation marker.
+pub(crate) fn fit_description(
+    value: Value,
+    max_chars: usize,
+    auto_truncate: bool,
+) -> JiraMcpResult<(Value, Option<usize>)> {
+    if !adf::is_doc(&value) {
+        let Some(text) = value.as_str() else {
+            return Ok((value, None));
+        };
+        let (fitted, removed) =
+            fit_text(text, max_chars, auto_truncate, "description", "description")?;
+        let fitted = removed.map(|_| fitted.into_owned());
+        return Ok(match fitted {
+            Some(fitted) => (Value::String(fitted), removed),
+            None => (value, None),
+        });
+    }
+
+    let chars = description_text(&value).chars().count();
+    if chars <= max_chars {
+        return Ok((value, None));
+    }
+    if !auto_truncate {
+        return Err(too_long("description", "description", chars, max_chars));
+    }
+
+    let mut doc = value;
+    let marker_chars = truncation_marker(chars).chars().count();
+    while description_text(&doc).chars().count() + marker_chars > max_chars {
+        match doc["content"].as_array_mut() {
+            Some(nodes) if !nodes.is_empty() => nodes.pop(),
+            _ => break,
+        };
+    }
+
+    let removed = chars - description_text(&doc).chars().count();
+    if let Some(nodes) = doc["content"].as_array_mut() {
+        nodes.extend(adf::paragraphs(&truncation_marker(removed)));
+    }
+    Ok((doc, Some(removed)))
+}
+
+/// Apply `mode` to the stored description, keeping its format
+///
+/// ADF documents get new paragraph nodes; wiki markup and markdown are joined
+/// with exactly one blank line, whatever whitespace the stored text ends with.
+pub(crate) fn merge_description(
+    current: &Value,
+    content: &str,
+    mode: &UpdateMode,
+    separator: DescriptionSeparator,
+    now: DateTime<Utc>,
+) -> Value {
+    if adf::is_doc(current) {
+        return merge_adf(current, content, mode, separator, now);
+    }
+
+    let current = trim_blank_lines(current.as_str().unwrap_or_default());
+    if matches!(mode, UpdateMode::Replace) {
+        return Value::String(content.to_string());
+    }
+
+    let content = trim_blank_lines(content);
+    if current.is_empty() {
+        return Value::String(content.to_string());
+    }
+
+    let rule = (separator != DescriptionSeparator::None).then(|| "----".to_string());
+    let stamp = (separator == DescriptionSeparator::TimestampedRule)
+        .then(|| format!("_{}_", updated_line(now)));
+
+    // The timestamp heads the new content, the rule sits between old and new
+    let blocks: Vec<String> = match mode {
+        UpdateMode::Prepend => stamp
+            .into_iter()
+            .chain([content.to_string()])
+            .chain(rule)
+            .chain([current.to_string()])
+            .collect(),
+        _ => [current.to_string()]
+            .into_iter()
+            .chain(rule)
+            .chain(stamp)
+            .chain([content.to_string()])
+            .collect(),
+    };
+
+    Value::String(blocks.join("\n\n"))
+}
+
+fn merge_adf(
+    current: &Value,
+    content: &str,
+    mode: &UpdateMode,
+    separator: DescriptionSeparator,
+    now: DateTime<Utc>,
+) -> Value {
+    let mut doc = current.clone();
+    let mut new_nodes = adf::paragraphs(content);
+
+    let mut existing: Vec<Value> = match mode {
+        UpdateMode::Replace => Vec::new(),
+        _ => current
+            .get("content")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default(),
+    };
+    // Drop empty paragraphs at the edge we are joining on
+    let is_empty_paragraph = |node: &Value| {
+        node.get("type").and_then(Value::as_str) == Some("paragraph")
+            && node
+                .get("content")
+                .and_then(Value::as_array)
+                .is_none_or(|content| content.is_empty())
+    };
+    match mode {
+        UpdateMode::Prepend => {
+            let leading = existing
+                .iter()
+                .take_while(|node| is_empty_paragraph(node))
+                .count();
+            existing.drain(..leading);
+        }
+        _ => {
+            while existing.last().is_some_and(is_empty_paragraph) {
+                existing.pop();
+            }
+        }
+    }
+
+    if !existing.is_empty() && separator == DescriptionSeparator::TimestampedRule {
+        new_nodes.insert(
+            0,
+            serde_json::json!({
+                "type": "paragraph",
+                "content": [{
+                    "type": "text",
+                    "text": updated_line(now),
+                    "marks": [{ "type": "em" }]
+                }]
+            }),
+        );
+    }
+    let rule = (!existing.is_empty() && separator != DescriptionSeparator::None)
+        .then(|| serde_json::json!({ "type": "rule" }));
+
+    let nodes: Vec<Value> = match mode {
+        UpdateMode::Prepend => new_nodes.into_iter().chain(rule).chain(existing).collect(),
+        _ => existing.into_iter().chain(rule).chain(new_nodes).collect(),
+    };
+
+    doc["content"] = Value::Array(nodes);
+    if doc.get("version").is_none() {
+        doc["version"] = serde_json::json!(1);
+    }
+    doc
+}
+
+/// Plain-text view of a description, used for results and diffs
+pub(crate) fn description_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        value if adf::is_doc(value) => value
+            .get("content")
+            .and_then(Value::as_array)
+            .map(|nodes| {
+                nodes
+                    .iter()
+                    .map(adf::node_text)
+                    .filter(|text| !text.is_empty())
+                    .collect::<Vec<_>>()
+                    .join("\n\n")
+            })
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+fn updated_line(now: DateTime<Utc>) -> String {
+    format!("Updated {}", now.format("%Y-%m-%d %H:%M UTC"))
+}
+
+/// Strip whitespace-only lines at both ends and trailing whitespace, keeping the first line's indentation
+pub(crate) fn trim_blank_lines(text: &str) -> &str {
+    let text = text.trim_end();
+    match text.find(|c: char| !c.is_whitespace()) {
+        Some(first) => &text[text[..first].rfind('\n').map_or(0, |nl| nl + 1)..],
+        None => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const ADF: &str = include_str!("../../tests/fixtures/descriptions/adf.json");
+    const WIKI: &str = include_str!("../../tests/fixtures/descriptions/wiki.txt");
+    const MARKDOWN: &str = include_str!("../../tests/fixtures/descriptions/markdown.md");
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, 14, 9, 30, 0).unwrap()
+    }
+
+    fn merge(current: &Value, content: &str, mode: UpdateMode, sep: DescriptionSeparator) -> Value {
+        merge_description(current, content, &mode, sep, now())
+    }
+
+    #[test]
+    fn test_wiki_append_and_prepend() {
+        let current = Value::String(WIKI.to_string());
+
+        let appended = merge(
+            &current,
+            "\nh3. Update\nFixed in 2.3.1\n\n",
+            UpdateMode::Append,
+            DescriptionSeparator::None,
+        );
+        let appended = appended.as_str().unwrap();
+        assert!(appended.ends_with("* compare with 2.2\n\nh3. Update\nFixed in 2.3.1"));
+        assert!(!appended.contains("\n\n\n"));
+
+        let prepended = merge(
+            &current,
+            "{warning}Hotfix pending{warning}",
+            UpdateMode::Prepend,
+            DescriptionSeparator::Rule,
+        );
+        assert!(prepended
+            .as_str()
+            .unwrap()
+            .starts_with("{warning}Hotfix pending{warning}\n\n----\n\nh2. Summary\n"));
+    }
+
+    #[test]
+    fn test_markdown_timestamped_append_and_replace() {
+        let current = Value::String(MARKDOWN.to_string());
+
+        let appended = merge(
+            &current,
+            "Deployed to staging.",
+            UpdateMode::Append,
+            DescriptionSeparator::TimestampedRule,
+        );
+        assert!(appended.as_str().unwrap().ends_with(
+            "- [x] compare with 2.2\n\n----\n\n_Updated 2025-03-14 09:30 UTC_\n\nDeployed to staging."
+        ));
+
+        // Replace keeps the content exactly, and empty descriptions get no separator
+        let replaced = merge(
+            &current,
+            "New\n",
+            UpdateMode::Replace,
+            DescriptionSeparator::Rule,
+        );
+        assert_eq!(replaced, Value::String("New\n".to_string()));
+        let first = merge(
+            &Value::Null,
+            "First entry",
+            UpdateMode::Append,
+            DescriptionSeparator::TimestampedRule,
+        );
+        assert_eq!(first, Value::String("First entry".to_string()));
+    }
+
+    #[test]
+    fn test_adf_append_prepend_and_replace() {
+        let current: Value = serde_json::from_str(ADF).unwrap();
+
+        let appended = merge(
+            &current,
+            "Root cause found.\nSee PROJ-2.\n\nFix merged.",
+            UpdateMode::Append,
+            DescriptionSeparator::TimestampedRule,
+        );
+        let nodes = appended["content"].as_array().unwrap();
+        let types: Vec<&str> = nodes.iter().map(|n| n["type"].as_str().unwrap()).collect();
+        // The trailing empty paragraph is dropped before the new nodes
+        assert_eq!(
+            types,
+            vec![
+                "heading",
+                "paragraph",
+                "rule",
+                "paragraph",
+                "paragraph",
+                "paragraph"
+            ]
+        );
+        assert_eq!(
+            nodes[3]["content"][0]["text"],
+            "Updated 2025-03-14 09:30 UTC"
+        );
+        assert_eq!(nodes[4]["content"][1]["type"], "hardBreak");
+        assert_eq!(nodes[5]["content"][0]["text"], "Fix merged.");
+        // Existing marks are untouched
+        assert_eq!(nodes[1], current["content"][1]);
+
+        let prepended = merge(
+            &current,
+            "Blocked on IdP vendor",
+            UpdateMode::Prepend,
+            DescriptionSeparator::None,
+        );
+        assert_eq!(
+            prepended["content"][0]["content"][0]["text"],
+            "Blocked on IdP vendor"
+        );
+        assert_eq!(prepended["content"][1]["type"], "heading");
+        assert_eq!(prepended["version"], 1);
+
+        let replaced = merge(
+            &current,
+            "Only this",
+            UpdateMode::Replace,
+            DescriptionSeparator::None,
+        );
+        assert_eq!(replaced["type"], "doc");
+        assert_eq!(replaced["content"].as_array().unwrap().len(), 1);
+
+        assert_eq!(
+            description_text(&appended),
+            "Summary\n\nLogin fails for SSO users\nsince the 2.3 release.\n\n----\n\nUpdated 2025-03-14 09:30 UTC\n\nRoot cause found.\nSee PROJ-2.\n\nFix merged."
+        );
+    }
+
+    #[test]
+    fn test_fit_description_limits() {
+        let long = Value::String("x".repeat(400));
+        assert!(fit_description(long.clone(), 200, false).is_err());
+
+        let (fitted, removed) = fit_description(long, 200, true).unwrap();
+        assert!(fitted.as_str().unwrap().chars().count() <= 200);
+        assert!(fitted
+            .as_str()
+            .unwrap()
+            .ends_with("characters truncated to fit the length limit]"));
+        assert!(removed.unwrap() > 200);
+
+        // ADF documents lose whole trailing nodes and stay valid
+        let current: Value = serde_json::from_str(ADF).unwrap();
+        let doc = merge(
+            &current,
+            &"Log line\n\n".repeat(40),
+            UpdateMode::Append,
+            DescriptionSeparator::None,
+        );
+        let (fitted, removed) = fit_description(doc, 200, true).unwrap();
+        let text = description_text(&fitted);
+        assert!(text.chars().count() <= 200, "{}", text);
+        assert!(text.starts_with("Summary\n\nLogin fails for SSO users"));
+        assert!(text.ends_with(truncation_marker(removed.unwrap()).trim_start()));
+        assert_eq!(fitted["type"], "doc");
+    }
+}
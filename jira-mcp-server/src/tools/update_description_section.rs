@@ -0,0 +1,745 @@
+//! Tool for editing one section of an issue description
+//!
+//! A section starts at a markdown ("## Investigation") or wiki markup
+//! ("h2. Investigation") heading and runs until the next heading of the same or
+//! a higher level. Only that span is rewritten; the rest of the description is
+//! kept byte for byte. ADF descriptions (Cloud) are edited as heading and
+//! paragraph nodes instead.
+
+use crate::adf;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_key;
+use crate::tools::update_description::{trim_blank_lines, EditOptions, UpdateDescription};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// How to edit the section
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionEditMode {
+    /// Replace the section's content, keeping its heading (default)
+    #[default]
+    ReplaceSection,
+    /// Add content after the section's existing content
+    AppendToSection,
+    /// Replace the section's content, or add the section at the end when missing
+    CreateIfMissing,
+}
+
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct UpdateDescriptionSectionParams {
+    /// The JIRA issue key (e.g., "PROJ-123")
+    pub issue_key: String,
+
+    /// Heading of the section, matched case-insensitively (e.g. "Investigation").
+    /// With a level ("## Investigation" or "h2. Investigation") only headings of
+    /// that level match
+    pub section_heading: String,
+
+    /// The section content, without the heading
+    pub content: String,
+
+    /// "replace_section" (default), "append_to_section" or "create_if_missing"
+    #[serde(default)]
+    pub mode: SectionEditMode,
+
+    /// Preview only: return the new description and a diff without writing (default: false)
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Include a unified diff of the change in the result (default: false; always on for dry_run)
+    #[serde(default)]
+    pub include_diff: bool,
+
+    /// Only write if the issue's `updated` timestamp still equals this value
+    /// (as returned by get_issue_details)
+    #[serde(default)]
+    pub expected_updated: Option<String>,
+
+    /// Cut a description longer than max_description_chars and end it with a
+    /// truncation marker, instead of failing (default: false)
+    #[serde(default)]
+    pub auto_truncate: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpdateDescriptionSectionResult {
+    /// Whether the update was successful
+    pub success: bool,
+
+    /// The issue key that was updated
+    pub issue_key: String,
+
+    /// The section heading as it appears in the description
+    pub section_heading: String,
+
+    /// The mode used for the update
+    pub mode: SectionEditMode,
+
+    /// True when the section did not exist and was added (create_if_missing)
+    pub created: bool,
+
+    /// The new description (for confirmation)
+    pub new_description: String,
+
+    /// True when nothing was written (dry_run)
+    pub dry_run: bool,
+
+    /// Unified diff from the current to the new description (dry_run or include_diff)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+
+    /// Characters auto_truncate cut from the end of the description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncated_chars: Option<usize>,
+}
+
+pub struct UpdateDescriptionSectionTool {
+    jira_client: Arc<JiraClient>,
+    update_description: Arc<UpdateDescription>,
+}
+
+impl UpdateDescriptionSectionTool {
+    pub fn new(jira_client: Arc<JiraClient>, update_description: Arc<UpdateDescription>) -> Self {
+        Self {
+            jira_client,
+            update_description,
+        }
+    }
+
+    #[instrument(skip(self), fields(issue_key = %params.issue_key))]
+    pub async fn execute(
+        &self,
+        mut params: UpdateDescriptionSectionParams,
+    ) -> JiraMcpResult<UpdateDescriptionSectionResult> {
+        params.issue_key =
+            normalize_issue_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        info!(
+            "Updating section '{}' of issue {} with mode: {:?}",
+            params.section_heading, params.issue_key, params.mode
+        );
+
+        let target = SectionTarget::parse(&params.section_heading)?;
+        if params.mode == SectionEditMode::AppendToSection && params.content.trim().is_empty() {
+            return Err(JiraMcpError::invalid_param(
+                "content",
+                "Content to append cannot be empty",
+            ));
+        }
+
+        let mut outcome = SectionOutcome::default();
+        let edit = self
+            .update_description
+            .edit_description(
+                &params.issue_key,
+                EditOptions {
+                    expected_updated: params.expected_updated.as_deref(),
+                    dry_run: params.dry_run,
+                    include_diff: params.include_diff,
+                    auto_truncate: params.auto_truncate,
+                },
+                "update_description_section",
+                |current| {
+                    let (value, section) =
+                        edit_section(current, &target, &params.content, params.mode)?;
+                    outcome = section;
+                    Ok(value)
+                },
+            )
+            .await?;
+
+        Ok(UpdateDescriptionSectionResult {
+            success: true,
+            issue_key: params.issue_key,
+            section_heading: outcome.heading,
+            mode: params.mode,
+            created: outcome.created,
+            new_description: edit.new_description,
+            dry_run: params.dry_run,
+            diff: edit.diff,
+            truncated_chars: edit.truncated_chars,
+        })
+    }
+}
+
+/// Heading markup of a text description
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HeadingStyle {
+    /// "## Heading"
+    Markdown,
+    /// "h2. Heading"
+    Wiki,
+}
+
+/// The section_heading parameter: its text, plus level and markup if given
+#[derive(Debug)]
+struct SectionTarget {
+    text: String,
+    level: Option<u8>,
+    style: Option<HeadingStyle>,
+}
+
+impl SectionTarget {
+    fn parse(input: &str) -> JiraMcpResult<Self> {
+        if let Some((level, text, style)) = parse_heading(input) {
+            return Ok(Self {
+                text: text.to_string(),
+                level: Some(level),
+                style: Some(style),
+            });
+        }
+
+        let text = input.trim();
+        if text.is_empty() {
+            return Err(JiraMcpError::invalid_param(
+                "section_heading",
+                "Section heading cannot be empty",
+            ));
+        }
+        Ok(Self {
+            text: text.to_string(),
+            level: None,
+            style: None,
+        })
+    }
+
+    fn matches(&self, heading: &Heading) -> bool {
+        heading.text.eq_ignore_ascii_case(&self.text)
+            && self.level.is_none_or(|level| level == heading.level)
+    }
+}
+
+/// A heading found in the description
+#[derive(Debug)]
+struct Heading {
+    /// Line index (text) or node index (ADF)
+    position: usize,
+    level: u8,
+    text: String,
+    style: Option<HeadingStyle>,
+}
+
+/// Which section was edited
+#[derive(Debug, Default)]
+struct SectionOutcome {
+    heading: String,
+    created: bool,
+}
+
+/// Parse a markdown or wiki markup heading line into (level, text, style)
+fn parse_heading(line: &str) -> Option<(u8, &str, HeadingStyle)> {
+    let line = line.trim();
+
+    let hashes = line.len() - line.trim_start_matches('#').len();
+    if hashes > 0 {
+        let rest = &line[hashes..];
+        let text = rest.trim();
+        return (hashes <= 6 && rest.starts_with([' ', '\t']) && !text.is_empty()).then_some((
+            hashes as u8,
+            text,
+            HeadingStyle::Markdown,
+        ));
+    }
+
+    let bytes = line.as_bytes();
+    if bytes.len() > 3
+        && bytes[0] == b'h'
+        && (b'1'..=b'6').contains(&bytes[1])
+        && bytes[2] == b'.'
+        && matches!(bytes[3], b' ' | b'\t')
+    {
+        let text = line[3..].trim();
+        return (!text.is_empty()).then_some((bytes[1] - b'0', text, HeadingStyle::Wiki));
+    }
+
+    None
+}
+
+/// Index of the heading matching `target`; more than one match is an error
+fn find_section(
+    headings: &[Heading],
+    target: &SectionTarget,
+    unit: &str,
+) -> JiraMcpResult<Option<usize>> {
+    let matches: Vec<usize> = headings
+        .iter()
+        .enumerate()
+        .filter(|(_, heading)| target.matches(heading))
+        .map(|(i, _)| i)
+        .collect();
+
+    if matches.len() > 1 {
+        let positions = matches
+            .iter()
+            .map(|&i| (headings[i].position + 1).to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        return Err(JiraMcpError::invalid_param(
+            "section_heading",
+            format!(
+                "'{}' matches {} headings (at {} {}). Include the heading level (e.g. \
+                 \"## {}\") if they differ, or rename one of them",
+                target.text,
+                matches.len(),
+                unit,
+                positions,
+                target.text
+            ),
+        ));
+    }
+
+    Ok(matches.first().copied())
+}
+
+/// Where the section after `headings[index]` ends: the next heading of the
+/// same or a higher level
+fn section_end(headings: &[Heading], index: usize) -> Option<usize> {
+    let level = headings[index].level;
+    headings[index + 1..]
+        .iter()
+        .find(|heading| heading.level <= level)
+        .map(|heading| heading.position)
+}
+
+/// Apply a section edit to the stored description, keeping its format
+fn edit_section(
+    current: &Value,
+    target: &SectionTarget,
+    content: &str,
+    mode: SectionEditMode,
+) -> JiraMcpResult<(Value, SectionOutcome)> {
+    if adf::is_doc(current) {
+        return edit_adf_section(current, target, content, mode);
+    }
+
+    let (text, outcome) =
+        edit_text_section(current.as_str().unwrap_or_default(), target, content, mode)?;
+    Ok((Value::String(text), outcome))
+}
+
+fn missing_section_error(target: &SectionTarget, headings: &[Heading]) -> JiraMcpError {
+    let available = if headings.is_empty() {
+        "the description has no headings".to_string()
+    } else {
+        format!(
+            "available: {}",
+            headings
+                .iter()
+                .map(|heading| heading.text.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+    JiraMcpError::invalid_param(
+        "section_heading",
+        format!(
+            "No section '{}' ({}). Use mode \"create_if_missing\" to add it",
+            target.text, available
+        ),
+    )
+}
+
+/// Headings of a wiki/markdown description, ignoring code blocks
+fn text_headings(lines: &[&str]) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut in_code = false;
+
+    for (position, line) in lines.iter().enumerate() {
+        let trimmed = line.trim();
+        // {code}/{noformat} open and close with the same tag; an even count
+        // on one line is a block opened and closed in place
+        let wiki_tags = trimmed.matches("{code").count() + trimmed.matches("{noformat").count();
+        if trimmed.starts_with("```") || wiki_tags % 2 == 1 {
+            in_code = !in_code;
+        } else if !in_code {
+            if let Some((level, text, style)) = parse_heading(line) {
+                headings.push(Heading {
+                    position,
+                    level,
+                    text: text.to_string(),
+                    style: Some(style),
+                });
+            }
+        }
+    }
+
+    headings
+}
+
+fn is_list_item(line: &str) -> bool {
+    let line = line.trim_start();
+    ["- ", "* ", "+ ", "# "]
+        .iter()
+        .any(|marker| line.starts_with(marker))
+        || line.split_once(". ").is_some_and(|(number, _)| {
+            !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit())
+        })
+}
+
+fn edit_text_section(
+    text: &str,
+    target: &SectionTarget,
+    content: &str,
+    mode: SectionEditMode,
+) -> JiraMcpResult<(String, SectionOutcome)> {
+    let lines: Vec<&str> = text.split_inclusive('\n').collect();
+    let mut starts: Vec<usize> = lines
+        .iter()
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len();
+            Some(start)
+        })
+        .collect();
+    starts.push(text.len());
+
+    let headings = text_headings(&lines);
+    let content = trim_blank_lines(content);
+
+    let Some(index) = find_section(&headings, target, "lines")? else {
+        if mode != SectionEditMode::CreateIfMissing {
+            return Err(missing_section_error(target, &headings));
+        }
+
+        let style = target
+            .style
+            .or_else(|| headings.first().and_then(|heading| heading.style))
+            .unwrap_or(HeadingStyle::Markdown);
+        let level = target
+            .level
+            .or_else(|| headings.first().map(|heading| heading.level))
+            .unwrap_or(2);
+        let heading_line = match style {
+            HeadingStyle::Markdown => format!("{} {}", "#".repeat(level as usize), target.text),
+            HeadingStyle::Wiki => format!("h{}. {}", level, target.text),
+        };
+
+        let mut new_text = text.to_string();
+        if !new_text.is_empty() && !new_text.ends_with('\n') {
+            new_text.push('\n');
+        }
+        if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+            new_text.push('\n');
+        }
+        new_text.push_str(&heading_line);
+        new_text.push('\n');
+        if !content.is_empty() {
+            new_text.push('\n');
+            new_text.push_str(content);
+            new_text.push('\n');
+        }
+
+        return Ok((
+            new_text,
+            SectionOutcome {
+                heading: target.text.clone(),
+                created: true,
+            },
+        ));
+    };
+
+    let heading = &headings[index];
+    let end_line = section_end(&headings, index);
+    let body_start = starts[heading.position + 1];
+    let body_end = end_line.map_or(text.len(), |line| starts[line]);
+    let body_lines = &lines[heading.position + 1..end_line.unwrap_or(lines.len())];
+
+    // Keep the blank lines around the section content as they were
+    let leading = body_lines
+        .iter()
+        .take_while(|line| line.trim().is_empty())
+        .count();
+    let (leading_blank, trailing_blank, core) = if leading == body_lines.len() {
+        let blank = body_lines.concat();
+        let trailing = if end_line.is_some() {
+            blank.clone()
+        } else {
+            String::new()
+        };
+        (blank, trailing, Vec::new())
+    } else {
+        let trailing = body_lines
+            .iter()
+            .rev()
+            .take_while(|line| line.trim().is_empty())
+            .count();
+        (
+            body_lines[..leading].concat(),
+            body_lines[body_lines.len() - trailing..].concat(),
+            body_lines[leading..body_lines.len() - trailing].to_vec(),
+        )
+    };
+
+    let mut body = String::new();
+    if body_start == text.len() && !text.ends_with('\n') {
+        // The heading is the last line, without a line break
+        body.push('\n');
+    }
+    body.push_str(&leading_blank);
+    if mode == SectionEditMode::AppendToSection && !core.is_empty() {
+        body.push_str(&core.concat());
+        if !body.ends_with('\n') {
+            body.push('\n');
+        }
+        let last = core.last().copied().unwrap_or_default();
+        if !(is_list_item(last) && content.lines().next().is_some_and(is_list_item)) {
+            body.push('\n');
+        }
+    }
+    if !content.is_empty() {
+        body.push_str(content);
+        body.push('\n');
+    }
+    body.push_str(&trailing_blank);
+    if body_end == text.len() && !text.ends_with('\n') && body.ends_with('\n') {
+        body.pop();
+    }
+
+    let new_text = format!("{}{}{}", &text[..body_start], body, &text[body_end..]);
+    Ok((
+        new_text,
+        SectionOutcome {
+            heading: heading.text.clone(),
+            created: false,
+        },
+    ))
+}
+
+fn is_empty_paragraph(node: &Value) -> bool {
+    node.get("type").and_then(Value::as_str) == Some("paragraph")
+        && node
+            .get("content")
+            .and_then(Value::as_array)
+            .is_none_or(|content| content.is_empty())
+}
+
+fn edit_adf_section(
+    current: &Value,
+    target: &SectionTarget,
+    content: &str,
+    mode: SectionEditMode,
+) -> JiraMcpResult<(Value, SectionOutcome)> {
+    let mut doc = current.clone();
+    let mut nodes = current
+        .get("content")
+        .and_then(Value::as_array)
+        .cloned()
+        .unwrap_or_default();
+
+    let headings: Vec<Heading> = nodes
+        .iter()
+        .enumerate()
+        .filter(|(_, node)| node.get("type").and_then(Value::as_str) == Some("heading"))
+        .map(|(position, node)| Heading {
+            position,
+            level: node["attrs"]["level"].as_u64().unwrap_or(1).clamp(1, 6) as u8,
+            text: adf::node_text(node).trim().to_string(),
+            style: None,
+        })
+        .collect();
+
+    let new_nodes = adf::paragraphs(content);
+    let outcome = match find_section(&headings, target, "blocks")? {
+        Some(index) => {
+            let heading = &headings[index];
+            let start = heading.position + 1;
+            let end = section_end(&headings, index).unwrap_or(nodes.len());
+            let insert_at = match mode {
+                SectionEditMode::AppendToSection => {
+                    // After the last non-empty node, so trailing empty paragraphs stay last
+                    start
+                        + nodes[start..end]
+                            .iter()
+                            .rposition(|node| !is_empty_paragraph(node))
+                            .map_or(0, |i| i + 1)
+                }
+                _ => {
+                    nodes.drain(start..end);
+                    start
+                }
+            };
+            nodes.splice(insert_at..insert_at, new_nodes);
+            SectionOutcome {
+                heading: heading.text.clone(),
+                created: false,
+            }
+        }
+        None if mode == SectionEditMode::CreateIfMissing => {
+            let level = target
+                .level
+                .or_else(|| headings.first().map(|heading| heading.level))
+                .unwrap_or(2);
+            nodes.push(serde_json::json!({
+                "type": "heading",
+                "attrs": { "level": level },
+                "content": [{ "type": "text", "text": target.text }]
+            }));
+            nodes.extend(new_nodes);
+            SectionOutcome {
+                heading: target.text.clone(),
+                created: true,
+            }
+        }
+        None => return Err(missing_section_error(target, &headings)),
+    };
+
+    doc["content"] = Value::Array(nodes);
+    if doc.get("version").is_none() {
+        doc["version"] = serde_json::json!(1);
+    }
+    Ok((doc, outcome))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADF: &str = include_str!("../../tests/fixtures/descriptions/adf.json");
+    const WIKI: &str = include_str!("../../tests/fixtures/descriptions/wiki.txt");
+    const MARKDOWN: &str = include_str!("../../tests/fixtures/descriptions/markdown.md");
+
+    fn edit(current: &str, heading: &str, content: &str, mode: SectionEditMode) -> String {
+        let target = SectionTarget::parse(heading).unwrap();
+        edit_text_section(current, &target, content, mode)
+            .unwrap()
+            .0
+    }
+
+    #[test]
+    fn test_markdown_section_edits() {
+        let replaced = edit(
+            MARKDOWN,
+            "summary",
+            "Only SAML users are affected.",
+            SectionEditMode::ReplaceSection,
+        );
+        assert_eq!(
+            replaced,
+            MARKDOWN.replace(
+                "Login fails for **SSO users**\nsince the 2.3 release.",
+                "Only SAML users are affected."
+            )
+        );
+
+        // List items continue the list instead of starting a new paragraph
+        let appended = edit(
+            MARKDOWN,
+            "## Todos",
+            "- [ ] roll back",
+            SectionEditMode::AppendToSection,
+        );
+        assert_eq!(
+            appended,
+            MARKDOWN.replace("compare with 2.2\n", "compare with 2.2\n- [ ] roll back\n")
+        );
+
+        let created = edit(
+            MARKDOWN,
+            "Investigation",
+            "IdP certificate expired.",
+            SectionEditMode::CreateIfMissing,
+        );
+        assert_eq!(
+            created,
+            format!("{}## Investigation\n\nIdP certificate expired.\n", MARKDOWN)
+        );
+
+        // Headings in code blocks and deeper subsections
+        let text = "# Incident\n\n## Notes\nfirst\n### Detail\n```\n## Notes\n```\n## Timeline\n- 09:00 alert";
+        let replaced = edit(text, "notes", "second", SectionEditMode::ReplaceSection);
+        assert_eq!(
+            replaced,
+            "# Incident\n\n## Notes\nsecond\n## Timeline\n- 09:00 alert"
+        );
+        let appended = edit(
+            text,
+            "Timeline",
+            "- 09:05 paged",
+            SectionEditMode::AppendToSection,
+        );
+        assert!(appended.ends_with("## Timeline\n- 09:00 alert\n- 09:05 paged"));
+    }
+
+    #[test]
+    fn test_wiki_section_edits_and_errors() {
+        let appended = edit(
+            WIKI,
+            "h2. Summary",
+            "Affects 2.3.0 and 2.3.1.",
+            SectionEditMode::AppendToSection,
+        );
+        assert!(appended.contains("* compare with 2.2\n\nAffects 2.3.0 and 2.3.1.\n"));
+        assert!(appended.starts_with("h2. Summary\n\nLogin fails for *SSO users*\n"));
+
+        let created = edit(
+            WIKI,
+            "Root cause",
+            "Expired certificate",
+            SectionEditMode::CreateIfMissing,
+        );
+        assert!(created.ends_with("\nh2. Root cause\n\nExpired certificate\n"));
+
+        let target = SectionTarget::parse("h3. Summary").unwrap();
+        let error = edit_text_section(WIKI, &target, "x", SectionEditMode::ReplaceSection)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("available: Summary"), "{}", error);
+
+        let target = SectionTarget::parse("Notes").unwrap();
+        let error = edit_text_section(
+            "h2. Notes\na\nh2. Other\nh2. notes\nb",
+            &target,
+            "x",
+            SectionEditMode::ReplaceSection,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("at lines 1, 4"), "{}", error);
+    }
+
+    #[test]
+    fn test_adf_section_edits() {
+        let current: Value = serde_json::from_str(ADF).unwrap();
+        let target = SectionTarget::parse("Summary").unwrap();
+
+        let (appended, outcome) = edit_adf_section(
+            &current,
+            &target,
+            "Fix merged.",
+            SectionEditMode::AppendToSection,
+        )
+        .unwrap();
+        assert_eq!(outcome.heading, "Summary");
+        let nodes = appended["content"].as_array().unwrap();
+        assert_eq!(nodes.len(), 4);
+        assert_eq!(nodes[1], current["content"][1]);
+        assert_eq!(nodes[2]["content"][0]["text"], "Fix merged.");
+        assert_eq!(nodes[3], current["content"][2]);
+
+        let (replaced, _) = edit_adf_section(
+            &current,
+            &target,
+            "Only SAML",
+            SectionEditMode::ReplaceSection,
+        )
+        .unwrap();
+        assert_eq!(replaced["content"].as_array().unwrap().len(), 2);
+
+        let target = SectionTarget::parse("Next steps").unwrap();
+        let (created, outcome) = edit_adf_section(
+            &current,
+            &target,
+            "Rotate certificate",
+            SectionEditMode::CreateIfMissing,
+        )
+        .unwrap();
+        assert!(outcome.created);
+        assert_eq!(created["content"][3]["type"], "heading");
+        assert_eq!(created["content"][3]["attrs"]["level"], 2);
+    }
+}
@@ -0,0 +1,298 @@
+//! Tool for updating an issue's summary, labels and priority
+
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_key;
+use crate::tools::priorities::PrioritiesTool;
+use crate::undo::{FieldChange, UndoHistory};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// JIRA's limit on summary length
+pub const MAX_SUMMARY_CHARS: usize = 255;
+
+/// Parameters for the update_issue_summary tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateSummaryParams {
+    /// The JIRA issue key to update (e.g., "PROJ-123")
+    pub issue_key: String,
+
+    /// The new summary, or the text to add when append is true
+    pub summary: String,
+
+    /// Append `summary` to the current summary instead of replacing it (default: false)
+    #[serde(default)]
+    pub append: bool,
+
+    /// Replace the issue's labels with this list (labels cannot contain spaces)
+    #[serde(default)]
+    pub labels: Option<Vec<String>>,
+
+    /// Set the priority by name, case-insensitive (e.g., "High", "Critical");
+    /// aliases like "urgent" or "normal" map to the instance's scheme
+    #[serde(default)]
+    pub priority: Option<String>,
+}
+
+/// Result from the update_issue_summary tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct UpdateSummaryResult {
+    /// Issue key
+    pub issue_key: String,
+
+    /// Summary before the update
+    pub old_summary: String,
+
+    /// Summary after the update
+    pub new_summary: String,
+
+    /// Labels after the update (only when labels were changed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub labels: Option<Vec<String>>,
+
+    /// Priority after the update (only when priority was changed)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+
+    /// Fields that were written
+    pub updated_fields: Vec<String>,
+
+    /// Success message
+    pub message: String,
+}
+
+/// Tool for updating summary, labels and priority together
+pub struct UpdateSummaryTool {
+    jira_client: Arc<JiraClient>,
+    priorities: Arc<PrioritiesTool>,
+    undo_history: Arc<UndoHistory>,
+}
+
+impl UpdateSummaryTool {
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        priorities: Arc<PrioritiesTool>,
+        undo_history: Arc<UndoHistory>,
+    ) -> Self {
+        Self {
+            jira_client,
+            priorities,
+            undo_history,
+        }
+    }
+
+    #[instrument(skip(self), fields(issue_key = %params.issue_key))]
+    pub async fn execute(
+        &self,
+        mut params: UpdateSummaryParams,
+    ) -> JiraMcpResult<UpdateSummaryResult> {
+        params.issue_key =
+            normalize_issue_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        info!("Updating summary for issue: {}", params.issue_key);
+
+        if let Some(labels) = &params.labels {
+            validate_labels(labels)?;
+        }
+        let priority = match params.priority.as_deref() {
+            Some(p) if p.trim().is_empty() => {
+                return Err(JiraMcpError::invalid_param(
+                    "priority",
+                    "Priority name cannot be empty",
+                ));
+            }
+            Some(p) => Some(self.priorities.resolve_priority(p, "priority").await?),
+            None => None,
+        };
+
+        let field_ids: Vec<String> = ["summary", "labels", "priority"]
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        let previous_values = self
+            .jira_client
+            .get_issue_fields(&params.issue_key, &field_ids)
+            .await?;
+
+        let old_summary = previous_values
+            .get("summary")
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string();
+        let new_summary = build_summary(&old_summary, &params.summary, params.append)?;
+
+        let mut updates = serde_json::Map::new();
+        updates.insert("summary".to_string(), serde_json::json!(new_summary));
+        if let Some(labels) = &params.labels {
+            updates.insert("labels".to_string(), serde_json::json!(labels));
+        }
+        if let Some(priority) = &priority {
+            updates.insert(
+                "priority".to_string(),
+                serde_json::json!({ "name": priority }),
+            );
+        }
+
+        let endpoint = format!("/issue/{}", params.issue_key);
+        self.jira_client
+            .put_json::<Value>(
+                &format!("updating {}", params.issue_key),
+                "api",
+                &endpoint,
+                serde_json::json!({ "fields": &updates }),
+                |e| {
+                    if e.to_string().contains("404") || e.to_string().contains("Not Found") {
+                        JiraMcpError::not_found("issue", &params.issue_key)
+                    } else if e.to_string().contains("400") || e.to_string().contains("Bad Request")
+                    {
+                        let param =
+                            if params.priority.is_some() && e.to_string().contains("priority") {
+                                "priority"
+                            } else {
+                                "summary"
+                            };
+                        JiraMcpError::invalid_param(
+                            param,
+                            format!("JIRA rejected the update: {}", e),
+                        )
+                    } else {
+                        JiraMcpError::from(e)
+                    }
+                },
+            )
+            .await?;
+
+        self.undo_history.record(
+            &params.issue_key,
+            "update_issue_summary",
+            updates
+                .iter()
+                .map(|(field_id, written)| {
+                    let change = FieldChange {
+                        previous: previous_values
+                            .get(field_id)
+                            .cloned()
+                            .unwrap_or(Value::Null),
+                        written: written.clone(),
+                    };
+                    (field_id.clone(), change)
+                })
+                .collect::<BTreeMap<_, _>>(),
+        );
+
+        let updated_fields: Vec<String> = updates.keys().cloned().collect();
+        info!(
+            "Updated {} on issue {}",
+            updated_fields.join(", "),
+            params.issue_key
+        );
+
+        Ok(UpdateSummaryResult {
+            message: format!(
+                "Updated {} on {}: \"{}\" -> \"{}\"",
+                updated_fields.join(", "),
+                params.issue_key,
+                old_summary,
+                new_summary
+            ),
+            issue_key: params.issue_key,
+            old_summary,
+            new_summary,
+            labels: params.labels,
+            priority,
+            updated_fields,
+        })
+    }
+}
+
+/// Build and validate the new summary
+fn build_summary(current: &str, summary: &str, append: bool) -> JiraMcpResult<String> {
+    let summary = summary.trim();
+    if summary.is_empty() {
+        return Err(JiraMcpError::invalid_param(
+            "summary",
+            "Summary cannot be empty",
+        ));
+    }
+
+    let new_summary = if append && !current.trim().is_empty() {
+        format!("{} {}", current.trim_end(), summary)
+    } else {
+        summary.to_string()
+    };
+    if new_summary.contains(['\n', '\r']) {
+        return Err(JiraMcpError::invalid_param(
+            "summary",
+            "Summary must be a single line",
+        ));
+    }
+
+    let length = new_summary.chars().count();
+    if length > MAX_SUMMARY_CHARS {
+        return Err(JiraMcpError::invalid_param(
+            "summary",
+            format!(
+                "Summary is {} characters; JIRA allows at most {}",
+                length, MAX_SUMMARY_CHARS
+            ),
+        ));
+    }
+
+    Ok(new_summary)
+}
+
+fn validate_labels(labels: &[String]) -> JiraMcpResult<()> {
+    if let Some(label) = labels
+        .iter()
+        .find(|label| label.is_empty() || label.contains(char::is_whitespace))
+    {
+        return Err(JiraMcpError::invalid_param(
+            "labels",
+            format!(
+                "Invalid label '{}': labels must be non-empty and cannot contain spaces",
+                label
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_summary() {
+        assert_eq!(
+            build_summary("Old title", "  New title ", false).unwrap(),
+            "New title"
+        );
+        assert_eq!(
+            build_summary("Login fails", "(SSO only)", true).unwrap(),
+            "Login fails (SSO only)"
+        );
+        assert_eq!(build_summary("", "First", true).unwrap(), "First");
+
+        assert!(build_summary("x", "   ", false).is_err());
+        assert!(build_summary("x", "two\nlines", false).is_err());
+
+        let at_limit = "a".repeat(MAX_SUMMARY_CHARS);
+        assert!(build_summary("", &at_limit, false).is_ok());
+        assert!(build_summary(&at_limit, "b", true).is_err());
+        // The limit counts characters, not bytes
+        assert!(build_summary("", &"é".repeat(MAX_SUMMARY_CHARS), false).is_ok());
+    }
+
+    #[test]
+    fn test_validate_labels() {
+        assert!(validate_labels(&["backend".to_string(), "sso-login".to_string()]).is_ok());
+        assert!(validate_labels(&[]).is_ok());
+        assert!(validate_labels(&["two words".to_string()]).is_err());
+        assert!(validate_labels(&[String::new()]).is_err());
+    }
+}
@@ -7,6 +7,7 @@ use crate::cache::MetadataCache;
 use crate::config::JiraConfig;
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_id_or_key;
 use base64::{engine::general_purpose, Engine as _};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -113,8 +114,8 @@ pub struct UploadPerformance {
 
 /// Implementation of the upload_attachment tool
 pub struct UploadAttachmentTool {
-    #[allow(dead_code)]
     jira_client: Arc<JiraClient>,
+    #[allow(dead_code)]
     config: Arc<JiraConfig>,
     #[allow(dead_code)]
     cache: Arc<MetadataCache>,
@@ -137,8 +138,11 @@ impl UploadAttachmentTool {
     /// Execute the upload_attachment tool
     pub async fn execute(
         &self,
-        params: UploadAttachmentParams,
+        mut params: UploadAttachmentParams,
     ) -> JiraMcpResult<UploadAttachmentResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
         let start_time = std::time::Instant::now();
         let mut api_calls = 0u32;
 
@@ -173,27 +177,10 @@ impl UploadAttachmentTool {
             total_count, total_bytes, params.issue_key
         );
 
-        // Upload using gouqi's upload_attachment API
-        // Use spawn_blocking with sync client to work around Send issues
-        let jira_url = self.config.jira_url.clone();
-        let credentials = self.config.to_gouqi_credentials();
-        let issue_key = params.issue_key.clone();
-
-        let uploaded = tokio::task::spawn_blocking(move || {
-            let sync_client = gouqi::Jira::new(&jira_url, credentials)?;
-
-            // Convert Vec<(String, Vec<u8>)> to Vec<(&str, Vec<u8>)> for gouqi API
-            let files_for_upload: Vec<(&str, Vec<u8>)> = files_to_upload
-                .iter()
-                .map(|(name, bytes)| (name.as_str(), bytes.clone()))
-                .collect();
-
-            sync_client
-                .issues()
-                .upload_attachment(&issue_key, files_for_upload)
-        })
-        .await
-        .map_err(|e| JiraMcpError::internal(format!("Task join error: {}", e)))??;
+        let uploaded = self
+            .jira_client
+            .upload_attachments(&params.issue_key, files_to_upload)
+            .await?;
 
         api_calls += 1;
 
@@ -256,23 +243,6 @@ impl UploadAttachmentTool {
 
     /// Validate upload attachment parameters
     fn validate_params(&self, params: &UploadAttachmentParams) -> JiraMcpResult<()> {
-        // Validate issue key
-        if params.issue_key.trim().is_empty() {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key is required. Please provide a JIRA issue key (e.g., 'PROJ-123')",
-            ));
-        }
-
-        // Basic format validation (PROJECT-NUMBER pattern)
-        let key = params.issue_key.trim();
-        if !key.contains('-') {
-            return Err(JiraMcpError::invalid_param(
-                "issue_key",
-                "Issue key must follow PROJECT-NUMBER format (e.g., 'PROJ-123')",
-            ));
-        }
-
         // Validate that either files or file_paths is provided, but not both
         match (&params.files, &params.file_paths) {
             (None, None) => {
@@ -3,11 +3,17 @@
 //! This tool provides a convenient way to get issues assigned to a user
 //! with semantic filtering options.
 
-use crate::cache::MetadataCache;
+use crate::cache::{BoardInfo, MetadataCache};
 use crate::config::JiraConfig;
 use crate::error::{JiraMcpError, JiraMcpResult};
 use crate::jira_client::{JiraClient, SearchResult};
-use crate::semantic_mapping::SemanticMapper;
+use crate::jql;
+use crate::metrics::{Measured, ToolPerformance};
+use crate::semantic_mapping::{
+    due_date_clause, DateExpr, JqlClause, JqlQuery, OrderBy, QueryComplexity, SemanticMapper,
+};
+use crate::tools::grouping::{group_issues, GroupBy, GroupedIssues};
+use crate::tools::render::{issue_table, OutputFormat};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
@@ -18,11 +24,20 @@ use tracing::{info, instrument, warn};
 #[serde(deny_unknown_fields)]
 pub struct GetUserIssuesParams {
     /// Username, account ID, or special reference (optional)
-    /// Examples: "me", "current_user", "john.doe", "account123", or omit for current user
+    /// Examples: "me", "current_user", "john.doe", "account123", "unassigned", or omit for current user
     pub username: Option<String>,
 
-    /// Status filter using semantic categories (optional)
-    /// Examples: ["open", "in_progress", "done"]
+    /// Several users at once, combined with IN (...) (optional, replaces username)
+    /// Examples: ["me", "john.doe"], ["alice", "unassigned"]
+    pub usernames: Option<Vec<String>>,
+
+    /// Which user relationship to match (optional, default: "assignee")
+    /// Examples: "assignee", "reporter", "watcher"
+    pub role: Option<UserRole>,
+
+    /// Status filter using semantic categories or status names (optional)
+    /// Categories "open", "in_progress" and "done" match every status in that category
+    /// Examples: ["open", "in_progress"], ["done"], ["Ready for QA"]
     pub status_filter: Option<Vec<String>>,
 
     /// Issue type filter using semantic types (optional)
@@ -30,7 +45,9 @@ pub struct GetUserIssuesParams {
     pub issue_types: Option<Vec<String>>,
 
     /// Board name filter (optional)
-    /// Limits results to issues from specific boards
+    /// Limits results to issues from specific boards: a board's project, or its
+    /// saved filter for boards not located in a project
+    /// Examples: ["Team Alpha"], ["Team Alpha", "Support"]
     pub board_filter: Option<Vec<String>>,
 
     /// Project key filter (optional)
@@ -38,7 +55,8 @@ pub struct GetUserIssuesParams {
     pub project_filter: Option<Vec<String>>,
 
     /// Due date filter (optional)
-    /// Examples: "overdue", "this_week", "next_week", "2024-01-01"
+    /// Periods match issues due within them, single dates match issues due by that date
+    /// Examples: "overdue", "due_today", "due_this_week", "no_due_date", "next_week", "in 3 days", "2024-01-01"
     pub due_date_filter: Option<String>,
 
     /// Priority filter (optional)
@@ -46,14 +64,59 @@ pub struct GetUserIssuesParams {
     pub priority_filter: Option<Vec<String>>,
 
     /// Only show issues updated recently (optional)
-    /// Examples: "today", "7 days ago", "2024-01-01"
+    /// Examples: "today", "yesterday", "7 days ago", "start of week", "2024-01-01"
     pub updated_since: Option<String>,
 
+    /// Text the summary or description must contain, ignoring case (optional)
+    /// Searched with JQL, then the page is narrowed to literal matches; see
+    /// `text_filter` in the result for how many of the fetched issues matched
+    /// Examples: "login", "payment timeout"
+    pub text_filter: Option<String>,
+
+    /// Result ordering (optional, default: most recently updated first)
+    /// Examples: {"field": "duedate", "direction": "asc"}, {"field": "priority"}
+    /// Ordering by "rank" requires board_filter
+    pub order_by: Option<OrderBy>,
+
+    /// Group the returned page by a field (optional)
+    /// Examples: "status", "assignee", "issue_type", "priority", "project"
+    pub group_by: Option<GroupBy>,
+
+    /// Output format (optional, default: "json")
+    /// "markdown" adds `rendered`, a key | summary | status | assignee table of the page
+    #[serde(default)]
+    pub format: OutputFormat,
+
     /// Maximum results to return (optional, default: 50, max: 200)
     pub limit: Option<u32>,
 
     /// Starting offset for pagination (optional, default: 0)
     pub start_at: Option<u32>,
+
+    /// Maximum characters of the serialized result, overriding max_response_chars (optional, min: 1000)
+    /// Trailing list items are dropped and long strings shortened; `truncated` says what
+    pub response_budget: Option<usize>,
+}
+
+/// User relationship to an issue that get_user_issues filters on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum UserRole {
+    #[default]
+    Assignee,
+    Reporter,
+    Watcher,
+}
+
+impl UserRole {
+    /// JQL field name for this role
+    pub fn jql_field(&self) -> jql::Field {
+        match self {
+            UserRole::Assignee => jql::ASSIGNEE,
+            UserRole::Reporter => jql::REPORTER,
+            UserRole::Watcher => jql::WATCHER,
+        }
+    }
 }
 
 /// Result from the get_user_issues tool
@@ -62,15 +125,37 @@ pub struct GetUserIssuesResult {
     /// The search results
     pub search_result: SearchResult,
 
-    /// User information that was resolved
-    pub resolved_user: UserInfo,
+    /// Results grouped by the requested field (only when group_by is set)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub groups: Option<GroupedIssues>,
+
+    /// Markdown table of the page (only with format "markdown")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rendered: Option<String>,
+
+    /// First resolved user, kept for single-user callers
+    /// None when only unassigned issues were requested
+    pub resolved_user: Option<UserInfo>,
+
+    /// All users that were resolved
+    pub resolved_users: Vec<UserInfo>,
+
+    /// Whether unassigned issues (empty role field) were included
+    pub includes_unassigned: bool,
 
     /// The JQL query that was executed
     pub jql_query: String,
 
+    /// Each input and the JQL it became, plus inputs that were ignored
+    pub explanation: Vec<String>,
+
     /// Summary of applied filters
     pub applied_filters: AppliedFilters,
 
+    /// How text_filter narrowed the fetched page (only when text_filter is set)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text_filter: Option<TextFilterSummary>,
+
     /// Performance information
     pub performance: UserIssuesPerformance,
 }
@@ -94,20 +179,60 @@ pub struct UserInfo {
 /// Summary of filters that were applied
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppliedFilters {
+    pub role: UserRole,
     pub status_categories: Option<Vec<String>>,
+    /// JQL condition for the resolved status filter
+    #[serde(skip)]
+    pub status_clause: Option<String>,
     pub issue_types: Option<Vec<String>>,
     pub projects: Option<Vec<String>>,
-    pub boards: Option<Vec<String>>,
+    /// Boards from board_filter, with what each resolved to
+    pub boards: Option<Vec<AppliedBoard>>,
+    /// JQL condition for the resolved boards
+    #[serde(skip)]
+    pub board_clause: Option<String>,
     pub due_date: Option<String>,
     pub priorities: Option<Vec<String>>,
     pub updated_since: Option<String>,
+    pub text: Option<String>,
+    pub order_by: Option<String>,
+}
+
+/// What the text_filter post-filter kept of the fetched page
+///
+/// JQL text search matches word stems and also searches comments, so the page
+/// JIRA returns is narrowed to issues whose summary or description contains
+/// the text literally. `search_result.total` still counts the JQL matches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TextFilterSummary {
+    /// Issues JIRA returned for the page
+    pub fetched: usize,
+
+    /// Issues of the page that contain the text
+    pub matched: usize,
+
+    /// start_at of the next page, counting fetched rather than matched issues
+    /// (None on the last page)
+    pub next_start_at: Option<usize>,
+}
+
+/// A board_filter entry and the board it resolved to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppliedBoard {
+    /// The board name as given
+    pub input: String,
+    /// Board ID
+    pub id: String,
+    /// The board's name in JIRA
+    pub name: String,
 }
 
 /// Performance metrics for user issues operations
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UserIssuesPerformance {
-    /// Time taken for the operation in milliseconds
-    pub duration_ms: u64,
+    /// Timing and request counts shared by all tools
+    #[serde(flatten)]
+    pub call: ToolPerformance,
 
     /// Whether user data hit the cache
     pub user_cache_hit: bool,
@@ -115,13 +240,16 @@ pub struct UserIssuesPerformance {
     /// Whether any filter metadata hit the cache
     pub metadata_cache_hit: bool,
 
-    /// Number of JIRA API calls made
-    pub api_calls: u32,
-
     /// Query complexity
     pub query_complexity: String,
 }
 
+impl Measured for GetUserIssuesResult {
+    fn set_performance(&mut self, performance: ToolPerformance) {
+        self.performance.call = performance;
+    }
+}
+
 /// Implementation of the get_user_issues tool
 pub struct GetUserIssuesTool {
     jira_client: Arc<JiraClient>,
@@ -157,7 +285,6 @@ impl GetUserIssuesTool {
     ))]
     pub async fn execute(&self, params: GetUserIssuesParams) -> JiraMcpResult<GetUserIssuesResult> {
         let start_time = std::time::Instant::now();
-        let mut api_calls = 0u32;
         let mut user_cache_hit = false;
         let mut metadata_cache_hit = false;
 
@@ -166,17 +293,73 @@ impl GetUserIssuesTool {
         // Validate parameters
         self.validate_params(&params)?;
 
-        // Resolve user (default to current user if none specified)
-        let user_info = self
-            .resolve_user(&params.username, &mut user_cache_hit)
-            .await?;
+        // Resolve users (default to current user if none specified)
+        let user_refs = collect_user_refs(&params);
+        let includes_unassigned = user_refs.iter().any(|user_ref| is_unassigned(user_ref));
+        let mut resolved_users = Vec::new();
+        for user_ref in user_refs.iter().filter(|user_ref| !is_unassigned(user_ref)) {
+            let mut hit = false;
+            resolved_users.push(self.resolve_user(&Some(user_ref.clone()), &mut hit).await?);
+            user_cache_hit = if resolved_users.len() == 1 {
+                hit
+            } else {
+                user_cache_hit && hit
+            };
+        }
+        let users_label = describe_users(&resolved_users, includes_unassigned);
 
         // Build filters and resolve them to JIRA terms
-        let applied_filters = self.build_applied_filters(&params, &mut metadata_cache_hit)?;
+        let project_key = params
+            .project_filter
+            .as_ref()
+            .and_then(|projects| projects.first())
+            .map(|s| s.as_str());
+        if params.issue_types.as_ref().is_some_and(|t| !t.is_empty()) {
+            self.semantic_mapper
+                .load_issue_types(&self.jira_client, project_key)
+                .await;
+        }
+        if params.status_filter.as_ref().is_some_and(|s| !s.is_empty()) {
+            self.semantic_mapper
+                .load_status_catalog(&self.jira_client, project_key)
+                .await;
+        }
+        let mut applied_filters = self.build_applied_filters(&params, &mut metadata_cache_hit)?;
+        if let Some(names) = params.board_filter.as_ref().filter(|arr| !arr.is_empty()) {
+            let boards = self.resolve_boards(names, &mut metadata_cache_hit).await?;
+            check_boards_searchable(&boards)?;
+            applied_filters.board_clause = build_board_clause(&boards);
+            applied_filters.boards = Some(
+                names
+                    .iter()
+                    .zip(boards)
+                    .map(|(input, board)| AppliedBoard {
+                        input: input.clone(),
+                        id: board.id,
+                        name: board.name,
+                    })
+                    .collect(),
+            );
+        }
 
         // Build JQL query
-        let jql_result =
-            self.build_user_issues_jql(&user_info.account_id, &params, &applied_filters)?;
+        let user_input = match &params.usernames {
+            Some(usernames) if !usernames.is_empty() => "usernames",
+            _ => "username",
+        };
+        let user_clause = JqlClause::new(
+            user_input,
+            build_user_clause(applied_filters.role, &resolved_users, includes_unassigned),
+        );
+        let mut jql_result = build_user_issues_jql(user_clause, &applied_filters)?;
+        jql_result.ignore_empty_lists(&[
+            ("usernames", params.usernames.as_ref()),
+            ("status_filter", params.status_filter.as_ref()),
+            ("issue_types", params.issue_types.as_ref()),
+            ("board_filter", params.board_filter.as_ref()),
+            ("project_filter", params.project_filter.as_ref()),
+            ("priority_filter", params.priority_filter.as_ref()),
+        ]);
 
         // Apply pagination
         let limit = params
@@ -185,40 +368,71 @@ impl GetUserIssuesTool {
             .min(200) as usize;
         let start_at = params.start_at.unwrap_or(0) as usize;
 
+        if params
+            .text_filter
+            .as_deref()
+            .is_some_and(|text| text.trim().is_empty())
+        {
+            jql_result.ignore("text_filter", "blank text");
+        }
+
         // Execute search
-        let search_result = self
+        let mut search_result = self
             .jira_client
             .search_issues_jql(&jql_result.jql, Some(start_at), Some(limit), None)
             .await?;
+        let text_filter = applied_filters
+            .text
+            .as_deref()
+            .map(|text| filter_by_text(&mut search_result, text));
 
-        api_calls += 1;
         let duration = start_time.elapsed();
 
         info!(
-            "Found {} issues for user {} in {}ms",
+            "Found {} issues for {} in {}ms",
             search_result.issues.len(),
-            user_info.display_name,
+            users_label,
             duration.as_millis()
         );
 
         // Warn about large result sets
-        if search_result.total > 500 {
+        if let Some(total) = search_result.total.filter(|total| *total > 500) {
             warn!(
-                "User {} has {} total issues. Consider adding more filters for better performance.",
-                user_info.display_name, search_result.total
+                "{} has {} total issues. Consider adding more filters for better performance.",
+                users_label, total
+            );
+        }
+
+        if let Some(summary) = &text_filter {
+            info!(
+                "{} of {} fetched issues contain '{}'",
+                summary.matched,
+                summary.fetched,
+                applied_filters.text.as_deref().unwrap_or_default()
             );
         }
 
+        let groups = params
+            .group_by
+            .map(|group_by| group_issues(&search_result, group_by));
+
+        let rendered = params.format.render(|| issue_table(&search_result));
+
         Ok(GetUserIssuesResult {
             search_result,
-            resolved_user: user_info,
+            groups,
+            rendered,
+            resolved_user: resolved_users.first().cloned(),
+            resolved_users,
+            includes_unassigned,
+            explanation: jql_result.explanation(),
             jql_query: jql_result.jql,
             applied_filters,
+            text_filter,
             performance: UserIssuesPerformance {
-                duration_ms: duration.as_millis() as u64,
+                call: ToolPerformance::default(),
                 user_cache_hit,
                 metadata_cache_hit,
-                api_calls,
                 query_complexity: format!("{:?}", jql_result.complexity),
             },
         })
@@ -309,16 +523,56 @@ impl GetUserIssuesTool {
             }
         }
 
-        // Validate due date filter format
-        if let Some(due_date) = &params.due_date_filter {
-            if due_date.trim().is_empty() {
+        if params.username.is_some()
+            && params
+                .usernames
+                .as_ref()
+                .is_some_and(|users| !users.is_empty())
+        {
+            return Err(JiraMcpError::invalid_param(
+                "usernames",
+                "Use either username or usernames, not both",
+            ));
+        }
+
+        if let Some(usernames) = &params.usernames {
+            if usernames.iter().any(|user| user.trim().is_empty()) {
                 return Err(JiraMcpError::invalid_param(
-                    "due_date_filter",
-                    "due_date_filter cannot be empty",
+                    "usernames",
+                    "usernames cannot contain empty entries",
                 ));
             }
         }
 
+        if params.role == Some(UserRole::Watcher)
+            && collect_user_refs(params)
+                .iter()
+                .any(|user_ref| is_unassigned(user_ref))
+        {
+            return Err(JiraMcpError::invalid_param(
+                "role",
+                "'unassigned' cannot be combined with role 'watcher'",
+            ));
+        }
+
+        // Validate due date filter format
+        if let Some(due_date) = &params.due_date_filter {
+            due_date_clause(due_date)?;
+        }
+
+        if let Some(order_by) = &params.order_by {
+            let has_board = params
+                .board_filter
+                .as_ref()
+                .is_some_and(|boards| !boards.is_empty());
+            order_by.validate(has_board)?;
+        }
+
+        // Validate updated_since format
+        if let Some(updated_since) = &params.updated_since {
+            DateExpr::parse(updated_since, "updated_since")?;
+        }
+
         Ok(())
     }
 
@@ -392,11 +646,6 @@ impl GetUserIssuesTool {
             .as_ref()
             .filter(|arr| !arr.is_empty())
             .cloned();
-        let board_filter = params
-            .board_filter
-            .as_ref()
-            .filter(|arr| !arr.is_empty())
-            .cloned();
         let priority_filter = params
             .priority_filter
             .as_ref()
@@ -404,20 +653,37 @@ impl GetUserIssuesTool {
             .cloned();
 
         let mut applied_filters = AppliedFilters {
+            role: params.role.unwrap_or_default(),
             status_categories: None,
+            status_clause: None,
             issue_types: None,
             projects: project_filter,
-            boards: board_filter,
+            boards: None,
+            board_clause: None,
             due_date: params.due_date_filter.clone(),
             priorities: priority_filter,
             updated_since: params.updated_since.clone(),
+            text: params
+                .text_filter
+                .as_deref()
+                .map(str::trim)
+                .filter(|text| !text.is_empty())
+                .map(String::from),
+            order_by: params.order_by.as_ref().map(OrderBy::to_jql),
         };
 
         // Resolve status categories (only if non-empty)
         if let Some(status_filter) = &params.status_filter {
             if !status_filter.is_empty() {
-                let jira_statuses = self.semantic_mapper.map_status_categories(status_filter)?;
-                applied_filters.status_categories = Some(jira_statuses);
+                let project_key = params
+                    .project_filter
+                    .as_ref()
+                    .and_then(|projects| projects.first());
+                let resolved = self
+                    .semantic_mapper
+                    .resolve_status_filter(status_filter, project_key.map(|s| s.as_str()))?;
+                applied_filters.status_categories = Some(resolved.describe());
+                applied_filters.status_clause = resolved.to_jql();
             }
         }
 
@@ -436,143 +702,299 @@ impl GetUserIssuesTool {
             }
         }
 
-        // Note: Board resolution would require additional API calls, skipping for now
-        // In a complete implementation, we'd resolve board names to project keys here
-
         Ok(applied_filters)
     }
 
-    /// Build JQL query for user issues
-    fn build_user_issues_jql(
+    /// Resolve board names to their project or saved filter, through the board cache
+    ///
+    /// Fails when a name matches no board, so a typo cannot silently widen the
+    /// search to all of the user's issues.
+    async fn resolve_boards(
         &self,
-        account_id: &str,
-        _params: &GetUserIssuesParams,
-        applied_filters: &AppliedFilters,
-    ) -> JiraMcpResult<crate::semantic_mapping::JqlQuery> {
-        let mut jql_parts = vec![format!("assignee = \"{}\"", account_id)];
-
-        // Add status filter
-        if let Some(statuses) = &applied_filters.status_categories {
-            if statuses.len() == 1 {
-                jql_parts.push(format!("status = \"{}\"", statuses[0]));
-            } else {
-                let status_list = statuses
-                    .iter()
-                    .map(|s| format!("\"{}\"", s))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                jql_parts.push(format!("status IN ({})", status_list));
+        board_names: &[String],
+        cache_hit: &mut bool,
+    ) -> JiraMcpResult<Vec<BoardInfo>> {
+        let mut boards = Vec::with_capacity(board_names.len());
+        for name in board_names {
+            let cached = self
+                .cache
+                .get_board_id(name)
+                .and_then(|board_id| self.cache.get_board_info(&board_id));
+            if let Some(board) = cached {
+                *cache_hit = true;
+                boards.push(board);
+                continue;
             }
-        }
 
-        // Add issue type filter
-        if let Some(types) = &applied_filters.issue_types {
-            if types.len() == 1 {
-                jql_parts.push(format!("issuetype = \"{}\"", types[0]));
-            } else {
-                let type_list = types
-                    .iter()
-                    .map(|t| format!("\"{}\"", t))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                jql_parts.push(format!("issuetype IN ({})", type_list));
+            let found = self
+                .jira_client
+                .find_board_by_name(name)
+                .await
+                .map_err(|e| unknown_board_error(name, e))?;
+
+            // Boards outside a project select issues through their saved filter
+            let filter_id = match &found.project_key {
+                Some(_) => None,
+                None => Some(self.jira_client.get_board_filter_id(found.id).await?),
+            };
+
+            let board = BoardInfo {
+                id: found.id.to_string(),
+                name: found.name,
+                type_: found.board_type,
+                project_key: found.project_key,
+                filter_id,
+            };
+            if let Err(e) = self.cache.set_board_id(name.clone(), board.id.clone()) {
+                warn!("Failed to cache board '{}': {}", name, e);
             }
-        }
-
-        // Add project filter
-        if let Some(projects) = &applied_filters.projects {
-            if projects.len() == 1 {
-                jql_parts.push(format!("project = \"{}\"", projects[0]));
-            } else {
-                let project_list = projects
-                    .iter()
-                    .map(|p| format!("\"{}\"", p))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                jql_parts.push(format!("project IN ({})", project_list));
+            if let Err(e) = self.cache.set_board_info(board.id.clone(), board.clone()) {
+                warn!("Failed to cache board info for '{}': {}", name, e);
             }
+            boards.push(board);
         }
 
-        // Add priority filter
-        if let Some(priorities) = &applied_filters.priorities {
-            if priorities.len() == 1 {
-                jql_parts.push(format!("priority = \"{}\"", priorities[0]));
-            } else {
-                let priority_list = priorities
-                    .iter()
-                    .map(|p| format!("\"{}\"", p))
-                    .collect::<Vec<_>>()
-                    .join(", ");
-                jql_parts.push(format!("priority IN ({})", priority_list));
-            }
-        }
+        Ok(boards)
+    }
+}
 
-        // Add due date filter
-        if let Some(due_date) = &applied_filters.due_date {
-            match due_date.to_lowercase().as_str() {
-                "overdue" => jql_parts.push("due < now()".to_string()),
-                "today" => jql_parts.push("due = now()".to_string()),
-                "this_week" => {
-                    jql_parts.push("due >= startOfWeek() AND due <= endOfWeek()".to_string())
-                }
-                "next_week" => {
-                    jql_parts.push("due >= startOfWeek(1w) AND due <= endOfWeek(1w)".to_string())
-                }
-                _ => {
-                    // Assume it's a date string
-                    jql_parts.push(format!("due <= \"{}\"", due_date));
-                }
-            }
-        }
+/// Build JQL query for user issues
+///
+/// `user_clause` comes first, so every query is limited to the requested users.
+fn build_user_issues_jql(
+    user_clause: JqlClause,
+    applied_filters: &AppliedFilters,
+) -> JiraMcpResult<JqlQuery> {
+    let mut clauses = vec![user_clause];
+
+    // Add status filter
+    if let Some(status_clause) = &applied_filters.status_clause {
+        clauses.push(JqlClause::new("status_filter", status_clause.clone()));
+    }
 
-        // Add updated since filter
-        if let Some(updated_since) = &applied_filters.updated_since {
-            if updated_since.to_lowercase() == "today" {
-                jql_parts.push("updated >= startOfDay()".to_string());
-            } else if updated_since.contains("ago") {
-                // Parse relative date
-                let date_filter = crate::semantic_mapping::parse_date_filter(updated_since)
-                    .unwrap_or_else(|_| updated_since.clone());
-                jql_parts.push(format!("updated >= \"{}\"", date_filter));
-            } else {
-                jql_parts.push(format!("updated >= \"{}\"", updated_since));
-            }
-        }
+    // Add issue type filter
+    if let Some(types) = &applied_filters.issue_types {
+        clauses.extend(
+            jql::eq_or_in(&jql::ISSUE_TYPE, types)
+                .map(|clause| JqlClause::new("issue_types", clause)),
+        );
+    }
 
-        // Build final JQL with ORDER BY clause
-        let jql = if jql_parts.is_empty() {
-            // Should not happen since we always have assignee filter, but handle it gracefully
-            "ORDER BY updated DESC".to_string()
-        } else {
-            let conditions = jql_parts.join(" AND ");
-            format!("{} ORDER BY updated DESC", conditions)
-        };
+    // Add project filter
+    if let Some(projects) = &applied_filters.projects {
+        clauses.extend(
+            jql::eq_or_in(&jql::PROJECT, projects)
+                .map(|clause| JqlClause::new("project_filter", clause)),
+        );
+    }
 
-        // Determine complexity (don't count ORDER BY as a part)
-        let complexity = if jql_parts.len() > 4 {
-            crate::semantic_mapping::QueryComplexity::Complex
-        } else if jql_parts.len() > 2 {
-            crate::semantic_mapping::QueryComplexity::Moderate
-        } else {
-            crate::semantic_mapping::QueryComplexity::Simple
-        };
+    // Add board filter
+    if let Some(board_clause) = &applied_filters.board_clause {
+        clauses.push(JqlClause::new("board_filter", board_clause.clone()));
+    }
 
-        Ok(crate::semantic_mapping::JqlQuery {
-            jql,
-            estimated_results: None,
-            complexity,
-        })
+    // Add priority filter
+    if let Some(priorities) = &applied_filters.priorities {
+        clauses.extend(
+            jql::eq_or_in(&jql::PRIORITY, priorities)
+                .map(|clause| JqlClause::new("priority_filter", clause)),
+        );
+    }
+
+    // Add due date filter
+    if let Some(due_date) = &applied_filters.due_date {
+        clauses.push(JqlClause::new(
+            "due_date_filter",
+            due_date_clause(due_date)?,
+        ));
+    }
+
+    // Add updated since filter
+    if let Some(updated_since) = &applied_filters.updated_since {
+        let date_expr = DateExpr::parse(updated_since, "updated_since")?;
+        clauses.push(JqlClause::new(
+            "updated_since",
+            date_expr.since_clause("updated"),
+        ));
+    }
+
+    // Add text filter; the page is narrowed to literal matches afterwards
+    if let Some(text) = &applied_filters.text {
+        clauses.push(JqlClause::new(
+            "text_filter",
+            format!("{} ~ {}", jql::TEXT, jql::quote_text(text)),
+        ));
+    }
+
+    // Build final JQL with ORDER BY clause
+    let order_clause = applied_filters
+        .order_by
+        .clone()
+        .unwrap_or_else(|| "ORDER BY updated DESC".to_string());
+    let conditions = clauses
+        .iter()
+        .map(|clause| clause.jql.as_str())
+        .collect::<Vec<_>>()
+        .join(" AND ");
+    let jql = format!("{} {}", conditions, order_clause);
+
+    // Determine complexity (don't count ORDER BY as a part)
+    let complexity = if clauses.len() > 4 {
+        QueryComplexity::Complex
+    } else if clauses.len() > 2 {
+        QueryComplexity::Moderate
+    } else {
+        QueryComplexity::Simple
+    };
+
+    Ok(JqlQuery {
+        jql,
+        estimated_results: None,
+        complexity,
+        clauses,
+        ignored: Vec::new(),
+    })
+}
+
+/// A failed board lookup; a board that doesn't exist is the caller's mistake
+fn unknown_board_error(name: &str, error: JiraMcpError) -> JiraMcpError {
+    match error {
+        JiraMcpError::NotFound { .. } => JiraMcpError::invalid_param(
+            "board_filter",
+            format!(
+                "No board named '{}' was found. Check the name, or use \
+                 project_filter instead.",
+                name
+            ),
+        ),
+        error => error,
+    }
+}
+
+/// Fails on a board with neither a project nor a numeric saved filter, which
+/// [`build_board_clause`] would otherwise leave out of the search
+fn check_boards_searchable(boards: &[BoardInfo]) -> JiraMcpResult<()> {
+    let unscoped = boards.iter().find(|board| {
+        board.project_key.is_none()
+            && board
+                .filter_id
+                .as_deref()
+                .and_then(|id| id.parse::<u64>().ok())
+                .is_none()
+    });
+    match unscoped {
+        Some(board) => Err(JiraMcpError::invalid_param(
+            "board_filter",
+            format!(
+                "Board '{}' has neither a project nor a saved filter to search by",
+                board.name
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// JQL condition matching issues on any of `boards`: the board's project when it
+/// is located in one, its saved filter otherwise
+fn build_board_clause(boards: &[BoardInfo]) -> Option<String> {
+    let projects: Vec<&str> = boards
+        .iter()
+        .filter_map(|board| board.project_key.as_deref())
+        .collect();
+    // Filter IDs are numeric; a quoted value would also match filters by name
+    let filters: Vec<String> = boards
+        .iter()
+        .filter(|board| board.project_key.is_none())
+        .filter_map(|board| board.filter_id.as_deref()?.parse::<u64>().ok())
+        .map(|id| id.to_string())
+        .collect();
+    let filter_clause = match filters.as_slice() {
+        [] => None,
+        [id] => Some(format!("{} = {}", jql::FILTER, id)),
+        ids => Some(format!("{} IN ({})", jql::FILTER, ids.join(", "))),
+    };
+
+    let parts: Vec<String> = jql::eq_or_in(&jql::PROJECT, &projects)
+        .into_iter()
+        .chain(filter_clause)
+        .collect();
+    match parts.len() {
+        0 => None,
+        1 => parts.into_iter().next(),
+        _ => Some(format!("({})", parts.join(" OR "))),
+    }
+}
+
+/// Keep the issues of the page whose summary or description contains `text`,
+/// ignoring case
+fn filter_by_text(search_result: &mut SearchResult, text: &str) -> TextFilterSummary {
+    let needle = text.to_lowercase();
+    let fetched = search_result.issues.len();
+    search_result.issues.retain(|issue| {
+        issue.summary.to_lowercase().contains(&needle)
+            || issue
+                .description
+                .as_deref()
+                .is_some_and(|description| description.to_lowercase().contains(&needle))
+    });
+
+    TextFilterSummary {
+        fetched,
+        matched: search_result.issues.len(),
+        next_start_at: (!search_result.is_last).then_some(search_result.start_at + fetched),
+    }
+}
+
+/// User references requested by the caller, defaulting to the current user
+fn collect_user_refs(params: &GetUserIssuesParams) -> Vec<String> {
+    match &params.usernames {
+        Some(usernames) if !usernames.is_empty() => usernames.clone(),
+        _ => vec![params.username.clone().unwrap_or_else(|| "me".to_string())],
+    }
+}
+
+fn is_unassigned(user_ref: &str) -> bool {
+    matches!(
+        user_ref.trim().to_lowercase().as_str(),
+        "unassigned" | "none" | "nobody"
+    )
+}
+
+/// Build the JQL condition selecting issues by user role
+fn build_user_clause(role: UserRole, users: &[UserInfo], include_unassigned: bool) -> String {
+    let field = role.jql_field();
+
+    let account_ids: Vec<&str> = users.iter().map(|u| u.account_id.as_str()).collect();
+    let users_clause = jql::eq_or_in(&field, &account_ids);
+    let empty_clause = format!("{} is EMPTY", field);
+
+    match (users_clause, include_unassigned) {
+        (Some(users_clause), true) => format!("({} OR {})", users_clause, empty_clause),
+        (Some(users_clause), false) => users_clause,
+        (None, _) => empty_clause,
+    }
+}
+
+/// Short description of the requested users for logging
+fn describe_users(users: &[UserInfo], include_unassigned: bool) -> String {
+    let mut names: Vec<&str> = users.iter().map(|u| u.display_name.as_str()).collect();
+    if include_unassigned {
+        names.push("unassigned");
     }
+    names.join(", ")
 }
 
 #[cfg(test)]
 mod tests {
     #[allow(unused_imports)]
     use super::*;
+    use crate::jira_client::IssueInfo;
     #[allow(dead_code)]
     fn create_test_params() -> GetUserIssuesParams {
         GetUserIssuesParams {
             username: Some("me".to_string()),
+            usernames: None,
+            role: None,
             status_filter: Some(vec!["open".to_string(), "in_progress".to_string()]),
             issue_types: Some(vec!["story".to_string(), "bug".to_string()]),
             board_filter: None,
@@ -580,11 +1002,259 @@ mod tests {
             due_date_filter: Some("overdue".to_string()),
             priority_filter: Some(vec!["high".to_string()]),
             updated_since: Some("7 days ago".to_string()),
+            text_filter: None,
+            order_by: None,
+            group_by: None,
+            format: OutputFormat::Json,
             limit: Some(50),
             start_at: Some(0),
+            response_budget: None,
         }
     }
 
+    fn user(account_id: &str) -> UserInfo {
+        UserInfo {
+            account_id: account_id.to_string(),
+            display_name: account_id.to_string(),
+            email_address: None,
+            is_current_user: false,
+        }
+    }
+
+    #[test]
+    fn test_build_user_clause() {
+        let alice = user("alice-id");
+        let bob = user("bob-id");
+
+        assert_eq!(
+            build_user_clause(UserRole::Assignee, std::slice::from_ref(&alice), false),
+            "assignee = \"alice-id\""
+        );
+        assert_eq!(
+            build_user_clause(UserRole::Assignee, &[], true),
+            "assignee is EMPTY"
+        );
+        assert_eq!(
+            build_user_clause(UserRole::Reporter, &[alice.clone(), bob.clone()], false),
+            "reporter IN (\"alice-id\", \"bob-id\")"
+        );
+        assert_eq!(
+            build_user_clause(UserRole::Assignee, &[alice, bob], true),
+            "(assignee IN (\"alice-id\", \"bob-id\") OR assignee is EMPTY)"
+        );
+    }
+
+    fn board(key: Option<&str>, filter_id: Option<&str>) -> BoardInfo {
+        BoardInfo {
+            id: "1".to_string(),
+            name: "Board".to_string(),
+            type_: "scrum".to_string(),
+            project_key: key.map(String::from),
+            filter_id: filter_id.map(String::from),
+        }
+    }
+
+    fn no_filters() -> AppliedFilters {
+        AppliedFilters {
+            role: UserRole::Assignee,
+            status_categories: None,
+            status_clause: None,
+            issue_types: None,
+            projects: None,
+            boards: None,
+            board_clause: None,
+            due_date: None,
+            priorities: None,
+            updated_since: None,
+            text: None,
+            order_by: None,
+        }
+    }
+
+    #[test]
+    fn test_every_applied_filter_adds_a_clause() {
+        let user_clause = "assignee = \"alice-id\"";
+        let base =
+            build_user_issues_jql(JqlClause::new("username", user_clause), &no_filters()).unwrap();
+        assert_eq!(base.jql, "assignee = \"alice-id\" ORDER BY updated DESC");
+
+        let filters = AppliedFilters {
+            role: UserRole::Reporter,
+            status_categories: Some(vec!["In Progress".to_string()]),
+            status_clause: Some("statusCategory = \"In Progress\"".to_string()),
+            issue_types: Some(vec!["Story".to_string(), "Bug".to_string()]),
+            projects: Some(vec!["TEST".to_string()]),
+            boards: Some(vec![AppliedBoard {
+                input: "Team Board".to_string(),
+                id: "1".to_string(),
+                name: "Team Board".to_string(),
+            }]),
+            board_clause: build_board_clause(&[board(None, Some("10001"))]),
+            due_date: Some("overdue".to_string()),
+            priorities: Some(vec!["High".to_string()]),
+            updated_since: Some("7 days ago".to_string()),
+            text: Some("login fails".to_string()),
+            order_by: Some("ORDER BY priority DESC".to_string()),
+        };
+        let query =
+            build_user_issues_jql(JqlClause::new("username", user_clause), &filters).unwrap();
+        let jql = &query.jql;
+
+        for clause in [
+            user_clause,
+            "statusCategory = \"In Progress\"",
+            "issuetype IN (\"Story\", \"Bug\")",
+            "project = \"TEST\"",
+            "filter = 10001",
+            "priority = \"High\"",
+            "due < now()",
+            "updated >= -7d",
+            "text ~ \"\\\"login fails\\\"\"",
+        ] {
+            assert!(jql.contains(clause), "missing '{}' in {}", clause, jql);
+        }
+        assert!(jql.ends_with(" ORDER BY priority DESC"), "{}", jql);
+        assert_eq!(jql.matches(" AND ").count(), 8, "{}", jql);
+
+        let explanation = query.explanation();
+        assert_eq!(explanation.len(), 10, "{:?}", explanation);
+        assert!(explanation.contains(&"board_filter: filter = 10001".to_string()));
+        assert_eq!(explanation[9], "ordering: ORDER BY priority DESC");
+    }
+
+    #[test]
+    fn test_filter_by_text() {
+        let issue = |key: &str, summary: &str, description: Option<&str>| IssueInfo {
+            key: key.to_string(),
+            id: "1".to_string(),
+            url: String::new(),
+            summary: summary.to_string(),
+            description: description.map(String::from),
+            issue_type: "Task".to_string(),
+            status: "Open".to_string(),
+            status_category: None,
+            status_color: None,
+            issue_type_icon_url: None,
+            priority: None,
+            assignee: None,
+            reporter: None,
+            created: String::new(),
+            updated: String::new(),
+            due_date: None,
+            project_key: "PROJ".to_string(),
+            project_name: "Project".to_string(),
+            labels: Vec::new(),
+            components: Vec::new(),
+            story_points: None,
+            acceptance_criteria: None,
+            match_snippet: None,
+            match_field: None,
+            time_tracking: None,
+            security_level: None,
+            resolution: None,
+            comments: Vec::new(),
+        };
+        let mut result = SearchResult {
+            issues: vec![
+                issue("PROJ-1", "Login fails on Safari", None),
+                issue(
+                    "PROJ-2",
+                    "Crash on start",
+                    Some("Happens after login fails twice"),
+                ),
+                issue("PROJ-3", "Logins are slow", None),
+            ],
+            total: Some(7),
+            start_at: 3,
+            max_results: 3,
+            is_last: false,
+        };
+
+        let summary = filter_by_text(&mut result, "LOGIN FAILS");
+        let keys: Vec<&str> = result
+            .issues
+            .iter()
+            .map(|issue| issue.key.as_str())
+            .collect();
+        assert_eq!(keys, ["PROJ-1", "PROJ-2"]);
+        assert_eq!((summary.fetched, summary.matched), (3, 2));
+        assert_eq!(summary.next_start_at, Some(6));
+
+        result.is_last = true;
+        assert_eq!(filter_by_text(&mut result, "safari").next_start_at, None);
+    }
+
+    #[test]
+    fn test_build_board_clause() {
+        assert_eq!(build_board_clause(&[]), None);
+        assert_eq!(
+            build_board_clause(&[board(Some("MOCK"), None)]).as_deref(),
+            Some("project = \"MOCK\"")
+        );
+        assert_eq!(
+            build_board_clause(&[
+                board(Some("A"), None),
+                board(Some("B"), Some("3")),
+                board(None, Some("10001")),
+                board(None, Some("10002")),
+            ])
+            .as_deref(),
+            Some("(project IN (\"A\", \"B\") OR filter IN (10001, 10002))")
+        );
+    }
+
+    #[test]
+    fn test_unknown_board_error() {
+        let error = unknown_board_error("Mobile", JiraMcpError::not_found("board", "Mobile"));
+        assert!(matches!(
+            &error,
+            JiraMcpError::InvalidParameter { parameter, .. } if parameter == "board_filter"
+        ));
+        assert!(
+            error.to_string().contains("No board named 'Mobile'"),
+            "{}",
+            error
+        );
+
+        // Other failures, e.g. a network error, are not blamed on the name
+        let error = unknown_board_error("Mobile", JiraMcpError::internal("timed out"));
+        assert!(matches!(error, JiraMcpError::Internal { .. }));
+    }
+
+    #[test]
+    fn test_check_boards_searchable() {
+        assert!(check_boards_searchable(&[]).is_ok());
+        assert!(
+            check_boards_searchable(&[board(Some("MOCK"), None), board(None, Some("10001"))])
+                .is_ok()
+        );
+
+        for unscoped in [board(None, None), board(None, Some("My filter"))] {
+            let error = check_boards_searchable(&[board(Some("MOCK"), None), unscoped])
+                .unwrap_err()
+                .to_string();
+            assert!(
+                error.contains("Board 'Board' has neither a project nor a saved filter"),
+                "{}",
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn test_collect_user_refs() {
+        let mut params = create_test_params();
+        params.username = None;
+        assert_eq!(collect_user_refs(&params), vec!["me".to_string()]);
+
+        params.username = Some("Unassigned".to_string());
+        assert!(is_unassigned(&collect_user_refs(&params)[0]));
+
+        params.username = None;
+        params.usernames = Some(vec!["alice".to_string(), "unassigned".to_string()]);
+        assert_eq!(collect_user_refs(&params).len(), 2);
+    }
+
     // #[test]
     // fn test_param_validation_success() {
     //     // Disabled: Uses unsafe std::mem::zeroed which causes undefined behavior
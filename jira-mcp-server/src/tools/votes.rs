@@ -0,0 +1,229 @@
+//! Vote management tools
+//!
+//! Add, remove and inspect votes on an issue via /issue/{key}/votes.
+
+use crate::config::OperationClass;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::JiraClient;
+use crate::tools::issue_key::normalize_issue_id_or_key;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::{info, instrument};
+
+/// Parameters for add_vote, remove_vote and get_votes
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct IssueVoteParams {
+    /// The JIRA issue key (e.g., "PROJ-123")
+    pub issue_key: String,
+}
+
+/// Result from add_vote / remove_vote
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct VoteResult {
+    /// The issue key
+    pub issue_key: String,
+
+    /// Whether the current user has voted after the operation
+    pub has_voted: bool,
+
+    /// Success message
+    pub message: String,
+}
+
+/// A user who voted on an issue
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct VoterInfo {
+    /// Account ID (Cloud) or username (Server/Data Center)
+    pub account_id: String,
+
+    /// Display name
+    pub display_name: String,
+}
+
+/// Result from get_votes
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct GetVotesResult {
+    /// The issue key
+    pub issue_key: String,
+
+    /// Number of votes
+    pub votes: u64,
+
+    /// Whether the current user has voted
+    pub has_voted: bool,
+
+    /// Voters, when the instance lets the current user see them
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub voters: Option<Vec<VoterInfo>>,
+}
+
+/// Tool for managing issue votes
+pub struct VotesTool {
+    jira_client: Arc<JiraClient>,
+}
+
+impl VotesTool {
+    pub fn new(jira_client: Arc<JiraClient>) -> Self {
+        Self { jira_client }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn add_vote(&self, mut params: IssueVoteParams) -> JiraMcpResult<VoteResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        info!("Adding vote to issue {}", params.issue_key);
+
+        let endpoint = format!("/issue/{}/votes", params.issue_key);
+        self.jira_client
+            .post_json::<Value>(
+                &format!("voting for {}", params.issue_key),
+                "api",
+                &endpoint,
+                serde_json::json!({}),
+                |e| map_vote_error(e, &params.issue_key),
+            )
+            .await?;
+
+        Ok(VoteResult {
+            message: format!("Voted for issue {}", params.issue_key),
+            issue_key: params.issue_key,
+            has_voted: true,
+        })
+    }
+
+    #[instrument(skip(self))]
+    pub async fn remove_vote(&self, mut params: IssueVoteParams) -> JiraMcpResult<VoteResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        info!("Removing vote from issue {}", params.issue_key);
+
+        let endpoint = format!("/issue/{}/votes", params.issue_key);
+        self.jira_client
+            .delete_json::<Value>(
+                &format!("removing the vote from {}", params.issue_key),
+                "api",
+                &endpoint,
+                |e| map_vote_error(e, &params.issue_key),
+            )
+            .await?;
+
+        Ok(VoteResult {
+            message: format!("Removed vote from issue {}", params.issue_key),
+            issue_key: params.issue_key,
+            has_voted: false,
+        })
+    }
+
+    #[instrument(skip(self))]
+    pub async fn get_votes(&self, mut params: IssueVoteParams) -> JiraMcpResult<GetVotesResult> {
+        params.issue_key =
+            normalize_issue_id_or_key(self.jira_client.base_url(), &params.issue_key, "issue_key")?;
+
+        info!("Getting votes for issue {}", params.issue_key);
+
+        let endpoint = format!("/issue/{}/votes", params.issue_key);
+        let response: Value = self
+            .jira_client
+            .get_json(
+                OperationClass::Read,
+                &format!("reading votes of {}", params.issue_key),
+                "api",
+                &endpoint,
+                |e| map_vote_error(e, &params.issue_key),
+            )
+            .await?;
+
+        Ok(parse_votes(&params.issue_key, &response))
+    }
+}
+
+/// Map vote errors, keeping JIRA's own explanation for rejected votes
+/// (e.g. voting on an issue you reported, or voting being disabled)
+fn map_vote_error(e: gouqi::Error, issue_key: &str) -> JiraMcpError {
+    let message = e.to_string();
+    let lower = message.to_lowercase();
+
+    if lower.contains("cannot vote")
+        || lower.contains("voting")
+        || lower.contains("you have reported")
+        || lower.contains("not voted")
+    {
+        JiraMcpError::invalid_param("issue_key", format!("JIRA rejected the vote: {}", message))
+    } else if message.contains("404") || message.contains("Not Found") {
+        JiraMcpError::not_found("issue", issue_key)
+    } else if message.contains("403") || message.contains("Forbidden") {
+        JiraMcpError::permission(format!(
+            "Permission denied managing votes on issue {}",
+            issue_key
+        ))
+    } else {
+        JiraMcpError::from(e)
+    }
+}
+
+fn parse_votes(issue_key: &str, response: &Value) -> GetVotesResult {
+    // Voters are omitted (or empty) without the "view voters and watchers" permission
+    let voters = response["voters"]
+        .as_array()
+        .filter(|voters| !voters.is_empty())
+        .map(|voters| {
+            voters
+                .iter()
+                .map(|voter| VoterInfo {
+                    account_id: voter["accountId"]
+                        .as_str()
+                        .or_else(|| voter["name"].as_str())
+                        .unwrap_or_default()
+                        .to_string(),
+                    display_name: voter["displayName"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string(),
+                })
+                .collect()
+        });
+
+    GetVotesResult {
+        issue_key: issue_key.to_string(),
+        votes: response["votes"].as_u64().unwrap_or(0),
+        has_voted: response["hasVoted"].as_bool().unwrap_or(false),
+        voters,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_votes() {
+        let response = serde_json::json!({
+            "votes": 2,
+            "hasVoted": true,
+            "voters": [
+                {"accountId": "abc", "displayName": "Alice"},
+                {"name": "bob", "displayName": "Bob"}
+            ]
+        });
+
+        let result = parse_votes("PROJ-1", &response);
+        assert_eq!(result.votes, 2);
+        assert!(result.has_voted);
+        let voters = result.voters.unwrap();
+        assert_eq!(voters[0].account_id, "abc");
+        assert_eq!(voters[1].account_id, "bob");
+
+        // Voter list hidden by permissions
+        let hidden = parse_votes(
+            "PROJ-1",
+            &serde_json::json!({"votes": 3, "hasVoted": false}),
+        );
+        assert_eq!(hidden.votes, 3);
+        assert!(hidden.voters.is_none());
+    }
+}
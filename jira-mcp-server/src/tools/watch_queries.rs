@@ -0,0 +1,598 @@
+//! Watch query tools
+//!
+//! Named queries that remember the newest `updated` timestamp they have seen, so
+//! agents can ask "what changed since I last looked?" without webhooks. Watches are
+//! stored as JSON in the configured state directory and survive restarts.
+
+use crate::cache::MetadataCache;
+use crate::config::JiraConfig;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use crate::jira_client::{HistoryEntry, IssueInfo, JiraClient};
+use crate::semantic_mapping::SemanticMapper;
+use chrono::{DateTime, FixedOffset, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info, instrument};
+
+const WATCHES_FILE: &str = "watch_queries.json";
+const DEFAULT_POLL_RESULTS: u32 = 50;
+const MAX_POLL_RESULTS: u32 = 100;
+
+/// Parameters for the create_watch_query tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateWatchQueryParams {
+    /// Unique name for the watch (required)
+    /// Examples: "my-project", "open-bugs"
+    pub name: String,
+
+    /// Raw JQL to watch (optional, alternative to the semantic filters)
+    /// Any ORDER BY clause is ignored
+    pub jql: Option<String>,
+
+    /// Project key filter (optional)
+    pub project_key: Option<String>,
+
+    /// Semantic issue types (optional)
+    /// Examples: ["story", "bug"]
+    pub issue_types: Option<Vec<String>>,
+
+    /// User assignment filter (optional)
+    /// Examples: "me", "unassigned"
+    pub assigned_to: Option<String>,
+
+    /// Semantic status categories (optional)
+    /// Examples: ["open", "in_progress"]
+    pub status: Option<Vec<String>>,
+
+    /// Label filters (optional)
+    pub labels: Option<Vec<String>>,
+
+    /// Replace an existing watch with the same name (default: false)
+    #[serde(default)]
+    pub replace: bool,
+}
+
+/// Parameters for the poll_watch_query tool
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PollWatchQueryParams {
+    /// Name of the watch to poll (required)
+    pub name: String,
+
+    /// Maximum changed issues to return (optional, default: 50, max: 100)
+    /// Remaining changes are returned by the next poll
+    pub max_results: Option<u32>,
+}
+
+/// A stored watch query
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WatchQuery {
+    /// Watch name
+    pub name: String,
+
+    /// Conditions being watched (without ORDER BY)
+    pub jql: String,
+
+    /// Newest `updated` timestamp already reported (RFC 3339)
+    pub cursor: String,
+
+    /// When the watch was created (RFC 3339)
+    pub created_at: String,
+
+    /// When the watch was last polled (RFC 3339)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_polled_at: Option<String>,
+}
+
+/// Result from the create_watch_query tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct CreateWatchQueryResult {
+    /// The stored watch
+    pub watch: WatchQuery,
+
+    /// Names of all stored watches
+    pub watch_names: Vec<String>,
+
+    /// Success message
+    pub message: String,
+}
+
+/// An issue that changed since the previous poll
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct WatchedIssueChange {
+    /// Issue key
+    pub key: String,
+
+    /// Issue summary
+    pub summary: String,
+
+    /// Current status name
+    pub status: String,
+
+    /// Last update timestamp
+    pub updated: String,
+
+    /// True when the issue was created after the previous cursor
+    pub is_new: bool,
+
+    /// Fields changed since the previous cursor, from the changelog
+    pub changed_fields: Vec<String>,
+}
+
+/// Result from the poll_watch_query tool
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct PollWatchQueryResult {
+    /// Watch name
+    pub name: String,
+
+    /// The JQL query that was executed
+    pub jql_query: String,
+
+    /// Cursor the poll started from
+    pub since: String,
+
+    /// Cursor after this poll
+    pub cursor: String,
+
+    /// Issues created or updated since the previous poll, oldest first
+    pub changes: Vec<WatchedIssueChange>,
+
+    /// True when more changes are waiting for the next poll
+    pub has_more: bool,
+}
+
+/// JSON file holding all watches, keyed by name
+struct WatchStore {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl WatchStore {
+    fn new(state_dir: PathBuf) -> Self {
+        Self {
+            path: state_dir.join(WATCHES_FILE),
+            lock: Mutex::new(()),
+        }
+    }
+
+    /// Load, modify and save the watches under the store lock
+    fn update<T>(
+        &self,
+        f: impl FnOnce(&mut BTreeMap<String, WatchQuery>) -> JiraMcpResult<T>,
+    ) -> JiraMcpResult<T> {
+        let _guard = self
+            .lock
+            .lock()
+            .map_err(|_| JiraMcpError::internal("Watch store lock poisoned"))?;
+
+        let mut watches = self.load()?;
+        let result = f(&mut watches)?;
+        self.save(&watches)?;
+        Ok(result)
+    }
+
+    fn get(&self, name: &str) -> JiraMcpResult<WatchQuery> {
+        let _guard = self
+            .lock
+            .lock()
+            .map_err(|_| JiraMcpError::internal("Watch store lock poisoned"))?;
+
+        let watches = self.load()?;
+        watches.get(name).cloned().ok_or_else(|| {
+            let known: Vec<&str> = watches.keys().map(|k| k.as_str()).collect();
+            JiraMcpError::invalid_param(
+                "name",
+                format!(
+                    "No watch named '{}'. Existing watches: [{}]",
+                    name,
+                    known.join(", ")
+                ),
+            )
+        })
+    }
+
+    fn load(&self) -> JiraMcpResult<BTreeMap<String, WatchQuery>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(BTreeMap::new()),
+            Err(e) => Err(JiraMcpError::internal(format!(
+                "Failed to read {}: {}",
+                self.path.display(),
+                e
+            ))),
+        }
+    }
+
+    /// Write via a temporary file so a crash never leaves a truncated store
+    fn save(&self, watches: &BTreeMap<String, WatchQuery>) -> JiraMcpResult<()> {
+        let io_error = |e: std::io::Error| {
+            JiraMcpError::internal(format!("Failed to write {}: {}", self.path.display(), e))
+        };
+
+        if let Some(dir) = self.path.parent() {
+            std::fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let tmp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_string_pretty(watches)?).map_err(io_error)?;
+        std::fs::rename(&tmp_path, &self.path).map_err(io_error)?;
+        Ok(())
+    }
+}
+
+/// Tool for creating and polling watch queries
+pub struct WatchQueriesTool {
+    jira_client: Arc<JiraClient>,
+    semantic_mapper: Arc<SemanticMapper>,
+    store: WatchStore,
+}
+
+impl WatchQueriesTool {
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        config: Arc<JiraConfig>,
+        cache: Arc<MetadataCache>,
+    ) -> Self {
+        let store = WatchStore::new(config.state_dir());
+        let semantic_mapper = Arc::new(SemanticMapper::new(config, cache));
+
+        Self {
+            jira_client,
+            semantic_mapper,
+            store,
+        }
+    }
+
+    #[instrument(skip(self))]
+    pub async fn create_watch_query(
+        &self,
+        params: CreateWatchQueryParams,
+    ) -> JiraMcpResult<CreateWatchQueryResult> {
+        let name = params.name.trim().to_string();
+        if name.is_empty() {
+            return Err(JiraMcpError::invalid_param(
+                "name",
+                "Watch name cannot be empty",
+            ));
+        }
+
+        let jql = self.build_conditions(&params)?;
+
+        // Start from the newest matching issue so the first poll only reports new changes
+        let newest = self
+            .jira_client
+            .search_issues_jql(
+                &format!("{} ORDER BY updated DESC", jql),
+                None,
+                Some(1),
+                None,
+            )
+            .await
+            .map_err(|e| {
+                if e.to_string().contains("400") {
+                    JiraMcpError::jql(format!("JIRA rejected the watch query: {}", e))
+                } else {
+                    e
+                }
+            })?;
+
+        let now = Utc::now().fixed_offset();
+        let cursor = newest
+            .issues
+            .first()
+            .and_then(|issue| parse_jira_datetime(&issue.updated))
+            .unwrap_or(now);
+
+        let watch = WatchQuery {
+            name: name.clone(),
+            jql,
+            cursor: cursor.to_rfc3339(),
+            created_at: now.to_rfc3339(),
+            last_polled_at: None,
+        };
+
+        let watch_names = self.store.update(|watches| {
+            if watches.contains_key(&name) && !params.replace {
+                return Err(JiraMcpError::invalid_param(
+                    "name",
+                    format!(
+                        "A watch named '{}' already exists. Set replace to true to overwrite it.",
+                        name
+                    ),
+                ));
+            }
+            watches.insert(name.clone(), watch.clone());
+            Ok(watches.keys().cloned().collect())
+        })?;
+
+        info!("Created watch '{}' at cursor {}", name, watch.cursor);
+
+        Ok(CreateWatchQueryResult {
+            message: format!(
+                "Watching '{}'. Poll with poll_watch_query to get changes after {}",
+                name, watch.cursor
+            ),
+            watch,
+            watch_names,
+        })
+    }
+
+    #[instrument(skip(self))]
+    pub async fn poll_watch_query(
+        &self,
+        params: PollWatchQueryParams,
+    ) -> JiraMcpResult<PollWatchQueryResult> {
+        let watch = self.store.get(params.name.trim())?;
+        let since = DateTime::parse_from_rfc3339(&watch.cursor).map_err(|e| {
+            JiraMcpError::internal(format!("Corrupt cursor for watch '{}': {}", watch.name, e))
+        })?;
+        let max_results = params
+            .max_results
+            .unwrap_or(DEFAULT_POLL_RESULTS)
+            .clamp(1, MAX_POLL_RESULTS);
+
+        let jql = poll_jql(&watch.jql, &since);
+        let result = self
+            .jira_client
+            .search_issues_jql(&jql, None, Some(max_results as usize), None)
+            .await?;
+
+        let mut cursor = since;
+        let mut changes = Vec::new();
+        for issue in result.issues {
+            let Some(updated) = updated_after(&issue, &since) else {
+                continue;
+            };
+            let (histories, _) = self.jira_client.get_changelog(&issue.key).await?;
+            cursor = cursor.max(updated);
+            changes.push(issue_change(issue, &histories, &since));
+        }
+        let has_more = !result.is_last;
+
+        let polled_at = Utc::now().fixed_offset().to_rfc3339();
+        let cursor = cursor.to_rfc3339();
+        self.store.update(|watches| {
+            // The watch may have been replaced while we were polling
+            if let Some(stored) = watches.get_mut(&watch.name) {
+                if stored.cursor == watch.cursor {
+                    stored.cursor = cursor.clone();
+                }
+                stored.last_polled_at = Some(polled_at.clone());
+            }
+            Ok(())
+        })?;
+
+        debug!(
+            "Watch '{}' moved from {} to {}",
+            watch.name, watch.cursor, cursor
+        );
+        info!("Watch '{}' reported {} changes", watch.name, changes.len());
+
+        Ok(PollWatchQueryResult {
+            name: watch.name,
+            jql_query: jql,
+            since: watch.cursor,
+            cursor,
+            changes,
+            has_more,
+        })
+    }
+
+    /// Conditions for the watch, without ORDER BY
+    fn build_conditions(&self, params: &CreateWatchQueryParams) -> JiraMcpResult<String> {
+        let jql = match &params.jql {
+            Some(jql) => jql.clone(),
+            None => {
+                let non_empty = |arr: &Option<Vec<String>>| -> Option<Vec<String>> {
+                    arr.as_ref().filter(|arr| !arr.is_empty()).cloned()
+                };
+                let issue_types = non_empty(&params.issue_types);
+                let status = non_empty(&params.status);
+                let labels = non_empty(&params.labels);
+
+                self.semantic_mapper
+                    .build_search_jql_with_components(
+                        None,
+                        None,
+                        issue_types.as_deref(),
+                        params.assigned_to.as_deref(),
+                        None,
+                        None,
+                        params.project_key.as_deref(),
+                        status.as_deref(),
+                        None,
+                        labels.as_deref(),
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                    )?
+                    .jql
+            }
+        };
+
+        let conditions = strip_order_by(&jql);
+        if conditions.is_empty() {
+            return Err(JiraMcpError::invalid_param(
+                "jql",
+                "Provide a JQL query or at least one filter to watch",
+            ));
+        }
+        Ok(conditions)
+    }
+}
+
+fn strip_order_by(jql: &str) -> String {
+    match jql.to_ascii_uppercase().find("ORDER BY") {
+        Some(index) => jql[..index].trim().to_string(),
+        None => jql.trim().to_string(),
+    }
+}
+
+/// Search for issues updated at or after the cursor, oldest first
+///
+/// The cursor goes in as epoch milliseconds: a "yyyy/MM/dd HH:mm" date would be
+/// read in the JIRA user's time zone, not the cursor's. Issues updated exactly
+/// at the cursor were reported already and are dropped by [`issue_change`].
+fn poll_jql(conditions: &str, since: &DateTime<FixedOffset>) -> String {
+    format!(
+        "({}) AND updated >= {} ORDER BY updated ASC",
+        conditions,
+        since.timestamp_millis()
+    )
+}
+
+/// Parse JIRA timestamps like "2024-01-15T10:30:00.000+0000" (or RFC 3339)
+fn parse_jira_datetime(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z")
+        .or_else(|_| DateTime::parse_from_rfc3339(value))
+        .ok()
+}
+
+/// When the issue was last updated, or None if that was reported already
+fn updated_after(
+    issue: &IssueInfo,
+    since: &DateTime<FixedOffset>,
+) -> Option<DateTime<FixedOffset>> {
+    parse_jira_datetime(&issue.updated).filter(|updated| updated > since)
+}
+
+/// Describe a changed issue, with the fields its changelog shows changed after the cursor
+fn issue_change(
+    issue: IssueInfo,
+    histories: &[HistoryEntry],
+    since: &DateTime<FixedOffset>,
+) -> WatchedIssueChange {
+    let after_cursor =
+        |timestamp: &str| parse_jira_datetime(timestamp).is_some_and(|time| time > *since);
+
+    let changed_fields: BTreeSet<String> = histories
+        .iter()
+        .filter(|history| after_cursor(&history.created))
+        .flat_map(|history| history.items.iter().map(|item| item.field.clone()))
+        .collect();
+
+    WatchedIssueChange {
+        is_new: after_cursor(&issue.created),
+        key: issue.key,
+        summary: issue.summary,
+        status: issue.status,
+        updated: issue.updated,
+        changed_fields: changed_fields.into_iter().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_change_uses_changelog_since_cursor() {
+        let since = parse_jira_datetime("2024-01-15T10:00:00.000+0000").unwrap();
+        let issue: IssueInfo = serde_json::from_value(serde_json::json!({
+            "key": "PROJ-1",
+            "id": "10001",
+            "summary": "Fix login",
+            "issue_type": "Bug",
+            "status": "In Progress",
+            "created": "2024-01-01T09:00:00.000+0000",
+            "updated": "2024-01-15T11:00:00.000+0000",
+            "project_key": "PROJ",
+            "project_name": "Project",
+            "labels": [],
+            "components": []
+        }))
+        .unwrap();
+        let history = |created: &str, fields: &[&str]| HistoryEntry {
+            id: "1".to_string(),
+            author: "Alice".to_string(),
+            created: created.to_string(),
+            items: fields
+                .iter()
+                .map(|field| {
+                    serde_json::from_value(serde_json::json!({
+                        "field": field,
+                        "field_type": "jira"
+                    }))
+                    .unwrap()
+                })
+                .collect(),
+        };
+        let histories = [
+            history("2024-01-10T09:00:00.000+0000", &["priority"]),
+            history("2024-01-15T10:30:00.000+0000", &["status", "assignee"]),
+            history("2024-01-15T11:00:00.000+0000", &["status"]),
+        ];
+
+        // Already reported at the cursor
+        let later = parse_jira_datetime("2024-01-15T11:00:00.000+0000").unwrap();
+        assert!(updated_after(&issue, &later).is_none());
+        assert_eq!(updated_after(&issue, &since), Some(later));
+
+        let change = issue_change(issue, &histories, &since);
+        assert!(!change.is_new);
+        assert_eq!(change.changed_fields, vec!["assignee", "status"]);
+    }
+
+    #[test]
+    fn test_poll_jql_uses_epoch_millis() {
+        // The same instant in two offsets gives the same query
+        let utc = parse_jira_datetime("2024-01-15T10:00:30.250+0000").unwrap();
+        let berlin = parse_jira_datetime("2024-01-15T11:00:30.250+0100").unwrap();
+        let jql = poll_jql("project = PROJ", &utc);
+        assert_eq!(
+            jql,
+            "(project = PROJ) AND updated >= 1705312830250 ORDER BY updated ASC"
+        );
+        assert_eq!(poll_jql("project = PROJ", &berlin), jql);
+    }
+
+    #[test]
+    fn test_strip_order_by() {
+        assert_eq!(
+            strip_order_by("project = PROJ order by created DESC"),
+            "project = PROJ"
+        );
+        assert_eq!(strip_order_by(" status = Open "), "status = Open");
+    }
+
+    #[test]
+    fn test_watch_store_round_trip() {
+        let dir = std::env::temp_dir().join(format!(
+            "jira-mcp-watch-test-{}-{}",
+            std::process::id(),
+            Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ));
+        let store = WatchStore::new(dir.clone());
+
+        for name in ["a", "b"] {
+            store
+                .update(|watches| {
+                    watches.insert(
+                        name.to_string(),
+                        WatchQuery {
+                            name: name.to_string(),
+                            jql: "project = PROJ".to_string(),
+                            cursor: "2024-01-15T10:00:00+00:00".to_string(),
+                            created_at: "2024-01-15T10:00:00+00:00".to_string(),
+                            last_polled_at: None,
+                        },
+                    );
+                    Ok(())
+                })
+                .unwrap();
+        }
+
+        // A fresh store over the same directory sees both watches
+        let reopened = WatchStore::new(dir.clone());
+        assert_eq!(reopened.get("b").unwrap().jql, "project = PROJ");
+        assert!(reopened.load().unwrap().contains_key("a"));
+        assert!(reopened.get("missing").is_err());
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,326 @@
+//! Undo history for description and field writes
+//!
+//! Tools that overwrite issue content (update_issue_description, update_custom_fields
+//! and the todo tracker) capture the previous value of every field they write.
+//! `undo_last_change` uses these entries to put the old values back, but only
+//! when the issue still holds what we wrote.
+//!
+//! History is bounded per issue (`undo_history_depth`), expires after
+//! `undo_retention_seconds`, and is optionally persisted to the state directory.
+
+use crate::config::JiraConfig;
+use chrono::{DateTime, Utc};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tracing::{debug, warn};
+
+const UNDO_FILE: &str = "undo_history.json";
+
+/// Longest honoured retention (ten years); larger values would overflow `chrono`
+const MAX_RETENTION_SECONDS: u64 = 10 * 365 * 24 * 60 * 60;
+
+/// Keys JIRA adds to values it returns, which it doesn't accept back on write
+const READ_ONLY_KEYS: &[&str] = &["self", "iconUrl", "avatarUrls"];
+
+/// A single field's value before and after one of our writes
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FieldChange {
+    /// Value before the write (null if the field was empty)
+    pub previous: Value,
+    /// Value we wrote
+    pub written: Value,
+}
+
+/// One captured write
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct UndoEntry {
+    /// Sequence number, unique within the history
+    pub id: u64,
+    /// Issue the write went to
+    pub issue_key: String,
+    /// Tool that made the write (e.g. "update_issue_description", "todo_tracker")
+    pub source: String,
+    /// When the write was made
+    #[schemars(with = "String")]
+    pub captured_at: DateTime<Utc>,
+    /// Field ID -> previous/written values
+    pub fields: BTreeMap<String, FieldChange>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct HistoryState {
+    next_id: u64,
+    /// Per issue, oldest first
+    entries: HashMap<String, VecDeque<UndoEntry>>,
+}
+
+/// Bounded per-issue history of previous field values
+#[derive(Debug)]
+pub struct UndoHistory {
+    state: Mutex<HistoryState>,
+    depth: usize,
+    retention: chrono::Duration,
+    persist_path: Option<PathBuf>,
+}
+
+impl UndoHistory {
+    pub fn new(depth: usize, retention_seconds: u64, persist_path: Option<PathBuf>) -> Self {
+        let state = persist_path
+            .as_ref()
+            .and_then(|path| match std::fs::read_to_string(path) {
+                Ok(content) => serde_json::from_str(&content)
+                    .map_err(|e| {
+                        warn!("Ignoring unreadable undo history {}: {}", path.display(), e)
+                    })
+                    .ok(),
+                Err(_) => None,
+            })
+            .unwrap_or_default();
+
+        Self {
+            state: Mutex::new(state),
+            depth,
+            retention: chrono::Duration::seconds(
+                retention_seconds.min(MAX_RETENTION_SECONDS) as i64
+            ),
+            persist_path,
+        }
+    }
+
+    pub fn from_config(config: &JiraConfig) -> Self {
+        let persist_path = config
+            .undo_persist
+            .then(|| config.state_dir().join(UNDO_FILE));
+        Self::new(
+            config.undo_history_depth,
+            config.undo_retention_seconds,
+            persist_path,
+        )
+    }
+
+    /// Record a successful write; a no-op when undo is disabled (depth 0)
+    pub fn record(&self, issue_key: &str, source: &str, fields: BTreeMap<String, FieldChange>) {
+        if self.depth == 0 || fields.is_empty() {
+            return;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        self.prune(&mut state);
+
+        state.next_id += 1;
+        let entry = UndoEntry {
+            id: state.next_id,
+            issue_key: issue_key.to_string(),
+            source: source.to_string(),
+            captured_at: Utc::now(),
+            fields,
+        };
+        debug!("Captured undo entry {} for {}", entry.id, issue_key);
+
+        let entries = state.entries.entry(issue_key.to_uppercase()).or_default();
+        entries.push_back(entry);
+        while entries.len() > self.depth {
+            entries.pop_front();
+        }
+
+        self.save(&state);
+    }
+
+    /// The most recent unexpired entry for an issue
+    pub fn latest(&self, issue_key: &str) -> Option<UndoEntry> {
+        let mut state = self.state.lock().unwrap();
+        self.prune(&mut state);
+        state
+            .entries
+            .get(&issue_key.to_uppercase())
+            .and_then(|entries| entries.back().cloned())
+    }
+
+    /// Drop an entry once it has been undone
+    pub fn remove(&self, issue_key: &str, id: u64) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(entries) = state.entries.get_mut(&issue_key.to_uppercase()) {
+            entries.retain(|entry| entry.id != id);
+        }
+        self.save(&state);
+    }
+
+    /// Unexpired entries for one issue
+    pub fn count_for(&self, issue_key: &str) -> usize {
+        let mut state = self.state.lock().unwrap();
+        self.prune(&mut state);
+        state
+            .entries
+            .get(&issue_key.to_uppercase())
+            .map_or(0, |entries| entries.len())
+    }
+
+    /// Unexpired entries across all issues
+    pub fn len(&self) -> usize {
+        let mut state = self.state.lock().unwrap();
+        self.prune(&mut state);
+        state.entries.values().map(|entries| entries.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn prune(&self, state: &mut HistoryState) {
+        let cutoff = Utc::now() - self.retention;
+        for entries in state.entries.values_mut() {
+            entries.retain(|entry| entry.captured_at > cutoff);
+        }
+        state.entries.retain(|_, entries| !entries.is_empty());
+    }
+
+    fn save(&self, state: &HistoryState) {
+        let Some(path) = &self.persist_path else {
+            return;
+        };
+
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| {
+                let tmp_path = path.with_extension("json.tmp");
+                let content = serde_json::to_string(state).map_err(std::io::Error::other)?;
+                std::fs::write(&tmp_path, content)?;
+                std::fs::rename(&tmp_path, path)
+            });
+
+        if let Err(e) = result {
+            warn!(
+                "Failed to persist undo history to {}: {}",
+                path.display(),
+                e
+            );
+        }
+    }
+}
+
+/// Whether the issue still holds what we wrote
+///
+/// JIRA echoes values back in a richer shape than we send (option objects gain
+/// `id`/`self`, numbers may come back as floats, line endings may change), so
+/// objects match when every key we wrote matches, and text is compared with
+/// normalized line endings and trailing whitespace.
+pub fn values_match(written: &Value, current: &Value) -> bool {
+    match (written, current) {
+        (Value::String(w), Value::String(c)) => normalize_text(w) == normalize_text(c),
+        (Value::String(w), Value::Null) | (Value::Null, Value::String(w)) => w.trim().is_empty(),
+        (Value::Number(w), Value::Number(c)) => w.as_f64() == c.as_f64(),
+        (Value::Object(w), Value::Object(c)) => w.iter().all(|(key, value)| {
+            c.get(key)
+                .is_some_and(|current| values_match(value, current))
+        }),
+        (Value::Array(w), Value::Array(c)) => {
+            w.len() == c.len() && w.iter().zip(c).all(|(w, c)| values_match(w, c))
+        }
+        (w, c) => w == c,
+    }
+}
+
+fn normalize_text(text: &str) -> String {
+    text.replace("\r\n", "\n").trim_end().to_string()
+}
+
+/// Strip read-only keys from a value JIRA returned so it can be written back
+pub fn restorable(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .filter(|(key, _)| !READ_ONLY_KEYS.contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), restorable(value)))
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(restorable).collect()),
+        other => other.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn change(previous: Value, written: Value) -> BTreeMap<String, FieldChange> {
+        BTreeMap::from([("description".to_string(), FieldChange { previous, written })])
+    }
+
+    #[test]
+    fn test_history_depth_and_latest() {
+        let history = UndoHistory::new(2, 3600, None);
+        history.record(
+            "proj-1",
+            "update_issue_description",
+            change(json!("a"), json!("b")),
+        );
+        history.record("PROJ-1", "todo_tracker", change(json!("b"), json!("c")));
+        history.record("PROJ-1", "todo_tracker", change(json!("c"), json!("d")));
+        history.record("PROJ-2", "todo_tracker", change(Value::Null, json!("x")));
+
+        assert_eq!(history.count_for("PROJ-1"), 2);
+        assert_eq!(history.len(), 3);
+
+        let latest = history.latest("proj-1").unwrap();
+        assert_eq!(latest.fields["description"].previous, json!("c"));
+
+        history.remove("PROJ-1", latest.id);
+        let latest = history.latest("PROJ-1").unwrap();
+        assert_eq!(latest.fields["description"].previous, json!("b"));
+
+        // Disabled history records nothing
+        let disabled = UndoHistory::new(0, 3600, None);
+        disabled.record("PROJ-1", "todo_tracker", change(json!("a"), json!("b")));
+        assert!(disabled.is_empty());
+    }
+
+    #[test]
+    fn test_history_expires_and_persists() {
+        let expired = UndoHistory::new(5, 0, None);
+        expired.record("PROJ-1", "todo_tracker", change(json!("a"), json!("b")));
+        assert!(expired.latest("PROJ-1").is_none());
+
+        let path = std::env::temp_dir().join(format!("jira-undo-{}.json", std::process::id()));
+        let history = UndoHistory::new(5, 3600, Some(path.clone()));
+        history.record("PROJ-1", "todo_tracker", change(json!("a"), json!("b")));
+
+        let reloaded = UndoHistory::new(5, 3600, Some(path.clone()));
+        assert_eq!(reloaded.count_for("PROJ-1"), 1);
+        std::fs::remove_file(&path).ok();
+
+        // Retention beyond what chrono can represent is clamped, not a panic
+        let forever = UndoHistory::new(5, u64::MAX, None);
+        forever.record("PROJ-1", "todo_tracker", change(json!("a"), json!("b")));
+        assert_eq!(forever.count_for("PROJ-1"), 1);
+    }
+
+    #[test]
+    fn test_values_match() {
+        assert!(values_match(
+            &json!("line 1\nline 2"),
+            &json!("line 1\r\nline 2\n")
+        ));
+        assert!(!values_match(&json!("ours"), &json!("edited by someone")));
+        assert!(values_match(&json!(5), &json!(5.0)));
+        assert!(values_match(
+            &json!({"value": "High"}),
+            &json!({"self": "https://x/option/1", "id": "1", "value": "High"})
+        ));
+        assert!(!values_match(
+            &json!({"value": "High"}),
+            &json!({"value": "Low"})
+        ));
+        assert!(values_match(&json!(""), &Value::Null));
+
+        assert_eq!(
+            restorable(&json!({"self": "https://x/option/1", "id": "1", "value": "High"})),
+            json!({"id": "1", "value": "High"})
+        );
+    }
+}
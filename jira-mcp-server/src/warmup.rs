@@ -0,0 +1,251 @@
+//! Background cache warm-up
+//!
+//! With `cache_warmup` enabled, commonly needed metadata (current user, projects,
+//! priorities, link types, statuses and the create metadata of
+//! `cache_warmup_projects`) is prefetched concurrently once the server is serving,
+//! so first tool calls don't pay for those lookups. Requests go through the
+//! client's rate limiter; failures are logged and reported by get_server_status.
+
+use crate::cache::{MetadataCache, UserMapping};
+use crate::config::JiraConfig;
+use crate::error::JiraMcpResult;
+use crate::jira_client::JiraClient;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+use tokio::task::{JoinHandle, JoinSet};
+use tracing::{debug, info, warn};
+
+/// Progress of the cache warm-up
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WarmupState {
+    /// `cache_warmup` is off
+    Disabled,
+    /// Enabled, but the server has not started it yet
+    Pending,
+    Running,
+    /// Finished; see `failed` for items that could not be loaded
+    Completed,
+}
+
+/// Warm-up status reported by get_server_status
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WarmupStatus {
+    pub state: WarmupState,
+
+    /// Items loaded into the cache, e.g. "priorities" or "issue_types:PROJ"
+    pub loaded: Vec<String>,
+
+    /// Items that were already cached (e.g. restored from disk) and skipped
+    pub skipped: Vec<String>,
+
+    /// Items that failed to load, with the error
+    pub failed: Vec<String>,
+
+    /// How long the warm-up took, once completed
+    pub duration_ms: Option<u64>,
+}
+
+/// One piece of metadata the warm-up prefetches
+#[derive(Debug, Clone)]
+enum WarmupItem {
+    CurrentUser,
+    Projects,
+    Priorities,
+    LinkTypes,
+    Statuses(Option<String>),
+    IssueTypes(String),
+}
+
+impl WarmupItem {
+    fn name(&self) -> String {
+        match self {
+            WarmupItem::CurrentUser => "current_user".to_string(),
+            WarmupItem::Projects => "projects".to_string(),
+            WarmupItem::Priorities => "priorities".to_string(),
+            WarmupItem::LinkTypes => "link_types".to_string(),
+            WarmupItem::Statuses(None) => "statuses".to_string(),
+            WarmupItem::Statuses(Some(project)) => format!("statuses:{}", project),
+            WarmupItem::IssueTypes(project) => format!("issue_types:{}", project),
+        }
+    }
+
+    /// Whether the cache already holds this item (the project list has no single entry)
+    fn is_cached(&self, cache: &MetadataCache) -> bool {
+        match self {
+            WarmupItem::CurrentUser => cache.get_current_user().is_some(),
+            WarmupItem::Projects => false,
+            WarmupItem::Priorities => cache.get_priorities().is_some(),
+            WarmupItem::LinkTypes => cache.get_link_types().is_some(),
+            WarmupItem::Statuses(project) => cache.get_statuses(project.as_deref()).is_some(),
+            WarmupItem::IssueTypes(project) => cache.get_project_issue_types(project).is_some(),
+        }
+    }
+
+    async fn load(&self, jira_client: &JiraClient, cache: &MetadataCache) -> JiraMcpResult<()> {
+        jira_client.rate_limiter().wait_for_capacity().await;
+
+        match self {
+            WarmupItem::CurrentUser => {
+                let user = jira_client.get_current_user().await?;
+                cache.set_current_user(UserMapping {
+                    account_id: user.account_id,
+                    display_name: user.display_name,
+                    email_address: user.email_address,
+                    username: None,
+                })
+            }
+            WarmupItem::Projects => {
+                for project in jira_client.get_projects().await? {
+                    cache.set_project_info(project.key.clone(), project)?;
+                }
+                Ok(())
+            }
+            WarmupItem::Priorities => cache.set_priorities(jira_client.get_priorities().await?),
+            WarmupItem::LinkTypes => {
+                cache.set_link_types(jira_client.get_issue_link_types().await?)
+            }
+            WarmupItem::Statuses(project) => cache.set_statuses(
+                project.as_deref(),
+                jira_client.get_statuses(project.as_deref()).await?,
+            ),
+            WarmupItem::IssueTypes(project) => cache.set_project_issue_types(
+                project.clone(),
+                jira_client.get_issue_types(Some(project)).await?,
+            ),
+        }
+    }
+}
+
+/// Prefetches metadata into the cache in the background
+pub struct CacheWarmup {
+    jira_client: Arc<JiraClient>,
+    cache: Arc<MetadataCache>,
+    projects: Vec<String>,
+    status: RwLock<WarmupStatus>,
+}
+
+impl CacheWarmup {
+    pub fn new(
+        jira_client: Arc<JiraClient>,
+        cache: Arc<MetadataCache>,
+        config: &JiraConfig,
+    ) -> Self {
+        let state = if config.cache_warmup {
+            WarmupState::Pending
+        } else {
+            WarmupState::Disabled
+        };
+
+        Self {
+            jira_client,
+            cache,
+            projects: config.cache_warmup_projects.clone(),
+            status: RwLock::new(WarmupStatus {
+                state,
+                loaded: Vec::new(),
+                skipped: Vec::new(),
+                failed: Vec::new(),
+                duration_ms: None,
+            }),
+        }
+    }
+
+    /// Current warm-up status
+    pub fn status(&self) -> WarmupStatus {
+        self.status
+            .read()
+            .map(|status| status.clone())
+            .unwrap_or_else(|e| e.into_inner().clone())
+    }
+
+    /// Start the warm-up in the background; `None` when disabled or already started
+    pub fn start(self: Arc<Self>) -> Option<JoinHandle<()>> {
+        {
+            let mut status = self.status.write().ok()?;
+            if status.state != WarmupState::Pending {
+                return None;
+            }
+            status.state = WarmupState::Running;
+        }
+
+        Some(tokio::spawn(async move { self.run().await }))
+    }
+
+    fn items(&self) -> Vec<WarmupItem> {
+        let mut items = vec![
+            WarmupItem::CurrentUser,
+            WarmupItem::Projects,
+            WarmupItem::Priorities,
+            WarmupItem::LinkTypes,
+            WarmupItem::Statuses(None),
+        ];
+        for project in &self.projects {
+            items.push(WarmupItem::Statuses(Some(project.clone())));
+            items.push(WarmupItem::IssueTypes(project.clone()));
+        }
+        items
+    }
+
+    async fn run(&self) {
+        info!("Starting cache warm-up");
+        let started = Instant::now();
+
+        let mut skipped = Vec::new();
+        let mut join_set = JoinSet::new();
+        for item in self.items() {
+            if item.is_cached(&self.cache) {
+                skipped.push(item.name());
+                continue;
+            }
+
+            let jira_client = Arc::clone(&self.jira_client);
+            let cache = Arc::clone(&self.cache);
+            join_set.spawn(async move {
+                let result = item.load(&jira_client, &cache).await;
+                (item.name(), result)
+            });
+        }
+
+        let mut loaded = Vec::new();
+        let mut failed = Vec::new();
+        while let Some(joined) = join_set.join_next().await {
+            match joined {
+                Ok((name, Ok(()))) => {
+                    debug!("Warm-up loaded {}", name);
+                    loaded.push(name);
+                }
+                Ok((name, Err(e))) => {
+                    warn!("Warm-up could not load {}: {}", name, e);
+                    failed.push(format!("{}: {}", name, e));
+                }
+                Err(e) => {
+                    warn!("Warm-up task failed: {}", e);
+                    failed.push(format!("task: {}", e));
+                }
+            }
+        }
+        loaded.sort();
+        failed.sort();
+
+        let duration_ms = started.elapsed().as_millis() as u64;
+        info!(
+            "Cache warm-up completed in {}ms: {} loaded, {} already cached, {} failed",
+            duration_ms,
+            loaded.len(),
+            skipped.len(),
+            failed.len()
+        );
+
+        if let Ok(mut status) = self.status.write() {
+            *status = WarmupStatus {
+                state: WarmupState::Completed,
+                loaded,
+                skipped,
+                failed,
+                duration_ms: Some(duration_ms),
+            };
+        }
+    }
+}
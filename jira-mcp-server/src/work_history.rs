@@ -0,0 +1,495 @@
+//! Local history of finished work sessions
+//!
+//! With `todos.work_history` on, every todo work session that is completed,
+//! paused or cancelled is recorded in a SQLite database in the state directory,
+//! which query_work_history reads back. The todo tools hand sessions to a
+//! writer thread over a channel and never wait for the disk; queries go through
+//! the same channel, so they see every session recorded before them.
+//!
+//! SQLite support is behind the `work-history` cargo feature. Without it the
+//! history records nothing and queries fail with a configuration error.
+//!
+//! The schema version is kept in `PRAGMA user_version` and migrated on open.
+//! Past `todos.work_history_max_sessions` sessions the oldest are deleted;
+//! SQLite reuses their pages, so the file stops growing.
+
+use crate::config::JiraConfig;
+use crate::error::{JiraMcpError, JiraMcpResult};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use tokio::sync::{mpsc, oneshot};
+use tracing::warn;
+
+pub const WORK_HISTORY_FILE: &str = "work_history.sqlite3";
+
+/// How a work session ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SessionOutcome {
+    Completed,
+    Paused,
+    Cancelled,
+}
+
+impl SessionOutcome {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Completed => "completed",
+            Self::Paused => "paused",
+            Self::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "completed" => Some(Self::Completed),
+            "paused" => Some(Self::Paused),
+            "cancelled" => Some(Self::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+/// One finished work session
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionRecord {
+    pub issue_key: String,
+
+    /// Todo ID, or "issue" for an issue-level session
+    pub todo_id: String,
+
+    pub todo_text: String,
+
+    /// Start of the session's time, counted back from ended_at
+    pub started_at: DateTime<Utc>,
+
+    pub ended_at: DateTime<Utc>,
+
+    /// Time of the whole session including checkpoints; for a cancelled
+    /// session the time that was discarded
+    pub seconds: u64,
+
+    /// Worklog added when the session ended, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worklog_id: Option<String>,
+
+    pub outcome: SessionOutcome,
+}
+
+/// Sessions to read back
+#[derive(Debug, Clone, Default)]
+pub struct HistoryQuery {
+    /// Sessions started at or after this instant
+    pub since: Option<DateTime<Utc>>,
+
+    /// Sessions started before this instant
+    pub until: Option<DateTime<Utc>>,
+
+    pub issue_key: Option<String>,
+}
+
+/// Work for the writer thread, which only exists with the feature
+#[derive(Debug)]
+#[cfg_attr(not(feature = "work-history"), allow(dead_code))]
+enum Command {
+    Record(SessionRecord),
+    Query(
+        HistoryQuery,
+        oneshot::Sender<JiraMcpResult<Vec<SessionRecord>>>,
+    ),
+}
+
+/// Handle to the work history, shared by the todo tracker and
+/// query_work_history
+#[derive(Debug)]
+pub struct WorkHistory {
+    sender: Option<mpsc::UnboundedSender<Command>>,
+}
+
+impl WorkHistory {
+    /// A history that records nothing
+    pub fn disabled() -> Self {
+        Self { sender: None }
+    }
+
+    pub fn from_config(config: &JiraConfig) -> Self {
+        if !config.todos.work_history {
+            return Self::disabled();
+        }
+        Self::open(
+            config.state_dir().join(WORK_HISTORY_FILE),
+            config.todos.work_history_max_sessions,
+        )
+    }
+
+    /// Open the database at `path`, creating or migrating it, and start its
+    /// writer; a disabled history when that fails
+    #[cfg(feature = "work-history")]
+    pub fn open(path: PathBuf, max_sessions: usize) -> Self {
+        let store = match store::Store::open(&path, max_sessions) {
+            Ok(store) => store,
+            Err(e) => {
+                warn!("Work history disabled: {}", e);
+                return Self::disabled();
+            }
+        };
+
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let writer = std::thread::Builder::new()
+            .name("work-history".to_string())
+            .spawn(move || store.run(receiver));
+        if let Err(e) = writer {
+            warn!("Work history disabled, writer did not start: {}", e);
+            return Self::disabled();
+        }
+
+        tracing::info!("Recording work sessions to {}", path.display());
+        Self {
+            sender: Some(sender),
+        }
+    }
+
+    #[cfg(not(feature = "work-history"))]
+    pub fn open(path: PathBuf, _max_sessions: usize) -> Self {
+        warn!(
+            "todos.work_history is on, but the server was built without the work-history \
+             feature; not recording sessions to {}",
+            path.display()
+        );
+        Self::disabled()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.sender.is_some()
+    }
+
+    /// Queue a finished session for the writer; returns at once
+    pub fn record(&self, record: SessionRecord) {
+        let Some(sender) = &self.sender else {
+            return;
+        };
+        if let Err(e) = sender.send(Command::Record(record)) {
+            if let Command::Record(record) = e.0 {
+                warn!(
+                    "Work history writer stopped; session on {} not recorded",
+                    record.issue_key
+                );
+            }
+        }
+    }
+
+    /// Recorded sessions matching `query`, oldest first
+    pub async fn query(&self, query: HistoryQuery) -> JiraMcpResult<Vec<SessionRecord>> {
+        let Some(sender) = &self.sender else {
+            return Err(JiraMcpError::config(if cfg!(feature = "work-history") {
+                "Work history is off. Set todos.work_history = true \
+                 (or JIRA_TODO_WORK_HISTORY=true) and restart the server"
+            } else {
+                "Work history needs a server built with --features work-history"
+            }));
+        };
+
+        let stopped = || JiraMcpError::internal("Work history writer stopped");
+        let (reply, response) = oneshot::channel();
+        sender
+            .send(Command::Query(query, reply))
+            .map_err(|_| stopped())?;
+        response.await.map_err(|_| stopped())?
+    }
+}
+
+#[cfg(feature = "work-history")]
+mod store {
+    use super::{Command, HistoryQuery, SessionOutcome, SessionRecord};
+    use crate::error::{JiraMcpError, JiraMcpResult};
+    use chrono::{DateTime, SecondsFormat, Utc};
+    use rusqlite::types::Type;
+    use rusqlite::{params, Connection, Row};
+    use std::path::Path;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+    use tracing::{debug, warn};
+
+    /// Schema changes in order; `user_version` counts the ones applied
+    const MIGRATIONS: &[&str] = &["CREATE TABLE sessions (
+            id INTEGER PRIMARY KEY,
+            issue_key TEXT NOT NULL,
+            todo_id TEXT NOT NULL,
+            todo_text TEXT NOT NULL,
+            started_at TEXT NOT NULL,
+            ended_at TEXT NOT NULL,
+            seconds INTEGER NOT NULL,
+            worklog_id TEXT,
+            outcome TEXT NOT NULL
+        );
+        CREATE INDEX sessions_started_at ON sessions (started_at);
+        CREATE INDEX sessions_issue_key ON sessions (issue_key);"];
+
+    /// Another server process may hold the database briefly
+    const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// The database, owned by the writer thread
+    pub(super) struct Store {
+        conn: Connection,
+        max_sessions: usize,
+    }
+
+    impl Store {
+        pub(super) fn open(path: &Path, max_sessions: usize) -> JiraMcpResult<Self> {
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir).map_err(|e| {
+                    JiraMcpError::internal(format!("Failed to create {}: {}", dir.display(), e))
+                })?;
+            }
+
+            let mut conn = Connection::open(path).map_err(sql_error)?;
+            conn.busy_timeout(BUSY_TIMEOUT).map_err(sql_error)?;
+            migrate(&mut conn)?;
+            Ok(Self { conn, max_sessions })
+        }
+
+        /// Handle commands until every sender is gone
+        pub(super) fn run(mut self, mut receiver: mpsc::UnboundedReceiver<Command>) {
+            while let Some(command) = receiver.blocking_recv() {
+                match command {
+                    Command::Record(record) => {
+                        if let Err(e) = self.insert(&record) {
+                            warn!(
+                                "Could not record work session on {}: {}",
+                                record.issue_key, e
+                            );
+                        }
+                    }
+                    Command::Query(query, reply) => {
+                        // The caller may have given up waiting
+                        let _ = reply.send(self.query(&query));
+                    }
+                }
+            }
+            debug!("Work history writer stopped");
+        }
+
+        /// Add a session and prune the oldest ones past the cap
+        fn insert(&mut self, record: &SessionRecord) -> JiraMcpResult<()> {
+            let tx = self.conn.transaction().map_err(sql_error)?;
+            tx.execute(
+                "INSERT INTO sessions (issue_key, todo_id, todo_text, started_at, ended_at,
+                    seconds, worklog_id, outcome)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![
+                    record.issue_key.to_uppercase(),
+                    record.todo_id,
+                    record.todo_text,
+                    timestamp(record.started_at),
+                    timestamp(record.ended_at),
+                    record.seconds.min(i64::MAX as u64) as i64,
+                    record.worklog_id,
+                    record.outcome.as_str(),
+                ],
+            )
+            .map_err(sql_error)?;
+            let pruned = tx
+                .execute(
+                    "DELETE FROM sessions WHERE id <=
+                        (SELECT id FROM sessions ORDER BY id DESC LIMIT 1 OFFSET ?1)",
+                    [self.max_sessions.min(i64::MAX as usize) as i64],
+                )
+                .map_err(sql_error)?;
+            tx.commit().map_err(sql_error)?;
+
+            if pruned > 0 {
+                debug!("Pruned {} oldest work sessions", pruned);
+            }
+            Ok(())
+        }
+
+        fn query(&self, query: &HistoryQuery) -> JiraMcpResult<Vec<SessionRecord>> {
+            let mut statement = self
+                .conn
+                .prepare_cached(
+                    "SELECT issue_key, todo_id, todo_text, started_at, ended_at, seconds,
+                        worklog_id, outcome
+                     FROM sessions
+                     WHERE (?1 IS NULL OR started_at >= ?1)
+                       AND (?2 IS NULL OR started_at < ?2)
+                       AND (?3 IS NULL OR issue_key = ?3)
+                     ORDER BY started_at, id",
+                )
+                .map_err(sql_error)?;
+            let rows = statement
+                .query_map(
+                    params![
+                        query.since.map(timestamp),
+                        query.until.map(timestamp),
+                        query.issue_key.as_ref().map(|key| key.to_uppercase()),
+                    ],
+                    read_record,
+                )
+                .map_err(sql_error)?;
+            rows.collect::<Result<_, _>>().map_err(sql_error)
+        }
+    }
+
+    /// Bring the schema up to date, one transaction per migration
+    fn migrate(conn: &mut Connection) -> JiraMcpResult<()> {
+        let version: i64 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(sql_error)?;
+        let version = usize::try_from(version).unwrap_or(usize::MAX);
+        if version > MIGRATIONS.len() {
+            return Err(JiraMcpError::config(format!(
+                "Work history schema version {} is newer than this server knows ({})",
+                version,
+                MIGRATIONS.len()
+            )));
+        }
+
+        for (applied, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+            let tx = conn.transaction().map_err(sql_error)?;
+            tx.execute_batch(migration).map_err(sql_error)?;
+            tx.pragma_update(None, "user_version", (applied + 1) as i64)
+                .map_err(sql_error)?;
+            tx.commit().map_err(sql_error)?;
+            debug!("Migrated work history to schema version {}", applied + 1);
+        }
+        Ok(())
+    }
+
+    /// Fixed-width UTC timestamps, so text order is time order
+    fn timestamp(time: DateTime<Utc>) -> String {
+        time.to_rfc3339_opts(SecondsFormat::Secs, true)
+    }
+
+    fn read_record(row: &Row) -> rusqlite::Result<SessionRecord> {
+        let time = |index: usize| -> rusqlite::Result<DateTime<Utc>> {
+            let value: String = row.get(index)?;
+            DateTime::parse_from_rfc3339(&value)
+                .map(|time| time.with_timezone(&Utc))
+                .map_err(|e| rusqlite::Error::FromSqlConversionFailure(index, Type::Text, e.into()))
+        };
+        let outcome: String = row.get(7)?;
+        let seconds: i64 = row.get(5)?;
+
+        Ok(SessionRecord {
+            issue_key: row.get(0)?,
+            todo_id: row.get(1)?,
+            todo_text: row.get(2)?,
+            started_at: time(3)?,
+            ended_at: time(4)?,
+            seconds: seconds.max(0) as u64,
+            worklog_id: row.get(6)?,
+            outcome: SessionOutcome::parse(&outcome).ok_or_else(|| {
+                rusqlite::Error::FromSqlConversionFailure(
+                    7,
+                    Type::Text,
+                    format!("unknown outcome {:?}", outcome).into(),
+                )
+            })?,
+        })
+    }
+
+    fn sql_error(e: rusqlite::Error) -> JiraMcpError {
+        JiraMcpError::internal(format!("Work history database: {}", e))
+    }
+}
+
+#[cfg(all(test, feature = "work-history"))]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn history_path(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("jira-work-history-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir.join(WORK_HISTORY_FILE)
+    }
+
+    fn record(issue_key: &str, started_at: &str, seconds: u64) -> SessionRecord {
+        let started_at = DateTime::parse_from_rfc3339(started_at)
+            .unwrap()
+            .with_timezone(&Utc);
+        SessionRecord {
+            issue_key: issue_key.to_string(),
+            todo_id: "todo-1".to_string(),
+            todo_text: "Write tests".to_string(),
+            started_at,
+            ended_at: started_at + Duration::seconds(seconds as i64),
+            seconds,
+            worklog_id: Some("10001".to_string()),
+            outcome: SessionOutcome::Completed,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_records_are_filtered_by_time_and_issue() {
+        let history = WorkHistory::open(history_path("filter"), 100);
+        assert!(history.is_enabled());
+        history.record(record("proj-1", "2026-03-02T09:00:00Z", 1800));
+        history.record(record("PROJ-2", "2026-03-02T13:00:00Z", 3600));
+        history.record(record("PROJ-1", "2026-03-03T09:00:00Z", 600));
+
+        let all = history.query(HistoryQuery::default()).await.unwrap();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0], record("PROJ-1", "2026-03-02T09:00:00Z", 1800));
+
+        let day = |value: &str| Some(value.parse::<DateTime<Utc>>().unwrap());
+        let march_2 = history
+            .query(HistoryQuery {
+                since: day("2026-03-02T00:00:00Z"),
+                until: day("2026-03-03T00:00:00Z"),
+                issue_key: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(march_2.len(), 2);
+
+        let proj_1 = history
+            .query(HistoryQuery {
+                issue_key: Some("proj-1".to_string()),
+                ..HistoryQuery::default()
+            })
+            .await
+            .unwrap();
+        let seconds: Vec<u64> = proj_1.iter().map(|record| record.seconds).collect();
+        assert_eq!(seconds, vec![1800, 600]);
+    }
+
+    #[tokio::test]
+    async fn test_oldest_sessions_are_pruned_past_the_cap() {
+        let path = history_path("prune");
+        let history = WorkHistory::open(path.clone(), 2);
+        history.record(record("PROJ-1", "2026-03-02T09:00:00Z", 60));
+        history.record(record("PROJ-2", "2026-03-02T10:00:00Z", 60));
+        history.record(record("PROJ-3", "2026-03-02T11:00:00Z", 60));
+
+        let kept = history.query(HistoryQuery::default()).await.unwrap();
+        let keys: Vec<&str> = kept
+            .iter()
+            .map(|record| record.issue_key.as_str())
+            .collect();
+        assert_eq!(keys, vec!["PROJ-2", "PROJ-3"]);
+
+        // Sessions survive a restart
+        drop(history);
+        let reopened = WorkHistory::open(path, 2);
+        assert_eq!(
+            reopened.query(HistoryQuery::default()).await.unwrap().len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_newer_schema_is_not_touched() {
+        let path = history_path("newer");
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let conn = rusqlite::Connection::open(&path).unwrap();
+        conn.pragma_update(None, "user_version", 99).unwrap();
+        drop(conn);
+
+        let history = WorkHistory::open(path, 100);
+        assert!(!history.is_enabled());
+        assert!(history.query(HistoryQuery::default()).await.is_err());
+    }
+}
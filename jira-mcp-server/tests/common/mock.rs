@@ -0,0 +1,90 @@
+/// Servers on the offline mock backend, for the end-to-end tool tests
+///
+/// Fixture sets live in tests/fixtures. `mock` is the complete base set; the
+/// others (e.g. `mock_fr` for a French-localized instance) only hold what differs
+/// and are layered over it.
+use jira_mcp_server::config::JiraConfig;
+use jira_mcp_server::JiraMcpServer;
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// Directory of the fixture set `tests/fixtures/{fixtures}`
+#[allow(dead_code)]
+pub fn fixture_dir(fixtures: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(fixtures)
+}
+
+/// Fixture set written to a fresh temporary directory, for fixtures built in the test
+///
+/// `files` are (path, content) pairs with paths relative to the set, e.g.
+/// `GET/api/search.json`. Layer the set over others with [`layered_fixtures`].
+#[allow(dead_code)]
+pub fn generated_fixtures(name: &str, files: &[(&str, Value)]) -> PathBuf {
+    let dir =
+        std::env::temp_dir().join(format!("jira-mcp-fixtures-{}-{}", std::process::id(), name));
+    let _ = std::fs::remove_dir_all(&dir);
+    for (path, content) in files {
+        let file = dir.join(path);
+        std::fs::create_dir_all(file.parent().expect("Fixture paths have a parent"))
+            .expect("Failed to create fixture directory");
+        std::fs::write(&file, content.to_string()).expect("Failed to write fixture");
+    }
+    dir
+}
+
+/// `mock_fixtures_dir` layering `dirs`, first one winning, over the base set
+#[allow(dead_code)]
+pub fn layered_fixtures(dirs: &[PathBuf]) -> String {
+    let base = fixture_dir("mock");
+    let dirs = dirs.iter().filter(|dir| **dir != base).chain([&base]);
+    std::env::join_paths(dirs)
+        .expect("Fixture paths can be joined")
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// `mock_fixtures_dir` for the fixture set `tests/fixtures/{fixtures}`
+#[allow(dead_code)]
+pub fn mock_fixtures(fixtures: &str) -> String {
+    layered_fixtures(&[fixture_dir(fixtures)])
+}
+
+/// State directory of the mock servers of this test binary
+#[allow(dead_code)]
+pub fn mock_state_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("jira-mcp-mock-{}", std::process::id()))
+}
+
+/// Server on the base fixture set
+#[allow(dead_code)]
+pub async fn mock_server() -> JiraMcpServer {
+    mock_server_with("mock", JiraConfig::default()).await
+}
+
+/// Server on the fixture set `tests/fixtures/{fixtures}`
+#[allow(dead_code)]
+pub async fn mock_server_with(fixtures: &str, config: JiraConfig) -> JiraMcpServer {
+    mock_server_on(mock_fixtures(fixtures), config).await
+}
+
+/// Server on the fixtures of `mock_fixtures_dir`, e.g. from [`layered_fixtures`]
+///
+/// State goes to the config's `state_dir`, or else to [`mock_state_dir`].
+#[allow(dead_code)]
+pub async fn mock_server_on(mock_fixtures_dir: String, config: JiraConfig) -> JiraMcpServer {
+    let state_dir = config
+        .state_dir
+        .clone()
+        .unwrap_or_else(|| mock_state_dir().to_string_lossy().into_owned());
+    let config = JiraConfig {
+        mock_fixtures_dir: Some(mock_fixtures_dir),
+        state_dir: Some(state_dir),
+        ..config
+    };
+
+    JiraMcpServer::with_config(config)
+        .await
+        .expect("Failed to start server in mock mode")
+}
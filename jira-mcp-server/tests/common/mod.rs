@@ -4,6 +4,8 @@ use std::io::{BufRead, BufReader, Write};
 use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
 use std::sync::atomic::{AtomicU64, Ordering};
 
+pub mod mock;
+
 static REQUEST_ID: AtomicU64 = AtomicU64::new(1);
 
 /// MCP Test Client for sending JSON-RPC requests to the server
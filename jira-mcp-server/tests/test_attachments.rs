@@ -131,13 +131,22 @@ fn test_upload_and_download_attachment() {
     let attachment_id = our_attachment["id"].as_str().expect("No attachment ID");
     println!("   Found uploaded file with ID: {}", attachment_id);
 
-    // 6. Download the attachment
+    let content_url = our_attachment["content_url"]
+        .as_str()
+        .expect("No content URL");
+    assert!(!content_url.is_empty());
+    assert!(
+        our_attachment["author_account_id"].as_str().is_some(),
+        "Attachment should carry the author's account ID"
+    );
+
+    // 6. Download the attachment by its content URL
     println!("\n5. Downloading attachment...");
     let download_response = client
         .call_tool(
             "download_attachment",
             json!({
-                "attachment_id": attachment_id,
+                "content_url": content_url,
                 "base64_encoded": true
             }),
         )
@@ -173,6 +182,10 @@ fn test_upload_and_download_attachment() {
             .unwrap(),
         "mcp_test.txt"
     );
+    assert_eq!(
+        download_result["attachment_info"]["id"].as_str().unwrap(),
+        attachment_id
+    );
 
     println!("\n=== ✅ All Attachment Tests Passed! ===\n");
 }
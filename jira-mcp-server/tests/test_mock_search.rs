@@ -0,0 +1,635 @@
+/// End-to-end tests of the search tools against the offline mock backend
+///
+/// These run without a JIRA instance: the server is pointed at the fixtures in
+/// tests/fixtures and writes are checked through the recorded requests.
+mod common;
+
+use common::mock::{mock_server, mock_server_with};
+use jira_mcp_server::config::{DeploymentType, JiraConfig};
+use jira_mcp_server::tools::UserFilter;
+use jira_mcp_server::GetServerStatusParams;
+use serde_json::json;
+
+#[tokio::test]
+async fn test_mock_search_issues() {
+    let server = mock_server().await;
+
+    let result = server
+        .search_issues(serde_json::from_value(json!({"project_key": "MOCK"})).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(result.search_result.total, Some(1));
+    assert_eq!(result.search_result.issues[0].key, "MOCK-1");
+    assert!(result.jql_query.contains("MOCK"));
+
+    let issue = &result.search_result.issues[0];
+    assert_eq!(issue.status_category.as_deref(), Some("new"));
+    assert_eq!(issue.status_color.as_deref(), Some("blue-gray"));
+    assert!(issue
+        .issue_type_icon_url
+        .as_deref()
+        .unwrap()
+        .contains("avatarType=issuetype"));
+}
+
+#[tokio::test]
+async fn test_mock_cloud_enhanced_search() {
+    let config = JiraConfig {
+        deployment_type: Some(DeploymentType::Cloud),
+        ..Default::default()
+    };
+    let server = mock_server_with("mock", config).await;
+
+    // /search/jql pages by token and reports isLast instead of a total
+    let result = server
+        .search_issues(serde_json::from_value(json!({"project_key": "MOCK"})).unwrap())
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(result.search_result.total, None);
+    assert!(result.search_result.is_last);
+    assert_eq!(result.search_result.issues[0].key, "MOCK-1");
+
+    let issue = &result.search_result.issues[0];
+    assert_eq!(issue.status_category.as_deref(), Some("new"));
+    assert_eq!(issue.status_color.as_deref(), Some("blue-gray"));
+    assert!(issue
+        .issue_type_icon_url
+        .as_deref()
+        .unwrap()
+        .contains("/universal_avatar/view/type/issuetype/"));
+
+    // Counts come from /search/approximate-count
+    let count = server
+        .count_issues(serde_json::from_value(json!({"project_key": "MOCK"})).unwrap())
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(count.total, 1);
+
+    // Both endpoints are sent the project's JQL
+    let mock = server.mock_backend().unwrap();
+    for jql in mock.searches() {
+        assert!(jql.starts_with("project = \"MOCK\""), "{}", jql);
+    }
+    assert_eq!(mock.searches().len(), 2);
+}
+
+#[tokio::test]
+async fn test_mock_search_status_catalog() {
+    let server = mock_server().await;
+
+    let result = server
+        .search_issues(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "status": ["open", "ready for qa"]
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert!(
+        result
+            .jql_query
+            .contains(r#"(statusCategory = "To Do" OR status = "Ready for QA")"#),
+        "{}",
+        result.jql_query
+    );
+    let mock = server.mock_backend().unwrap();
+    assert_eq!(mock.searches().last(), Some(&result.jql_query));
+
+    let unknown = server
+        .search_issues(
+            serde_json::from_value(json!({"project_key": "MOCK", "status": ["Triage"]})).unwrap(),
+        )
+        .await;
+    assert!(unknown.is_err());
+}
+
+#[tokio::test]
+async fn test_mock_search_sends_filters_as_jql() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .search_issues(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "status": ["open"],
+                "assigned_to": "me",
+                "created_after": "7 days ago",
+                "components": ["Backend", "API"]
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+    // The mock answers MOCK-1 for any JQL, so check what JIRA was asked
+    let sent = mock.searches().pop().unwrap();
+    assert_eq!(sent, result.jql_query);
+    assert_eq!(
+        sent,
+        "project = \"MOCK\" AND assignee = \"mock.user\" AND statusCategory = \"To Do\" \
+         AND created >= -7d AND component IN (\"Backend\", \"API\") ORDER BY updated DESC"
+    );
+}
+
+#[tokio::test]
+async fn test_mock_search_explains_jql() {
+    let server = mock_server().await;
+
+    let result = server
+        .search_issues(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "board_name": "Unknown Board",
+                "labels": ["payments"],
+                "status": []
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(
+        result.explanation,
+        vec![
+            "project_key: project = \"MOCK\"",
+            "labels: labels = \"payments\"",
+            "ordering: ORDER BY updated DESC",
+            "status: ignored, empty list",
+            "board_name: ignored, board not found in cache",
+        ]
+    );
+
+    let user_issues = server
+        .get_user_issues(serde_json::from_value(json!({"issue_types": []})).unwrap())
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(user_issues.explanation[0].starts_with("username: assignee"));
+    assert_eq!(
+        user_issues.explanation.last().unwrap(),
+        "issue_types: ignored, empty list"
+    );
+}
+
+#[tokio::test]
+async fn test_mock_search_within() {
+    let server = mock_server().await;
+    let search =
+        |params: serde_json::Value| server.search_issues(serde_json::from_value(params).unwrap());
+
+    // MOCK is company-managed and the instance has an Epic Link field
+    let result = search(json!({"within": {"epic": "mock-2"}}))
+        .await
+        .unwrap()
+        .into_inner();
+    assert_eq!(
+        result.jql_query,
+        "\"Epic Link\" = \"MOCK-2\" ORDER BY updated DESC"
+    );
+    let within = result.within.unwrap();
+    assert_eq!(
+        (within.scope.as_str(), within.mechanism.as_str()),
+        ("epic", "epic_link")
+    );
+
+    let result = search(json!({"project_key": "MOCK", "within": {"sprint": 42}}))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(
+        result.jql_query.contains("Sprint = 42"),
+        "{}",
+        result.jql_query
+    );
+
+    let result = search(json!({"within": {"parent": "MOCK-1"}}))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(result.jql_query.starts_with("parent = \"MOCK-1\""));
+
+    for params in [
+        json!({"within": {"epic": "MOCK-2", "sprint": 42}}),
+        json!({"within": {}}),
+        json!({"within": {"epic": "MOCK-2"}, "epic_filter": "MOCK-3"}),
+        json!({"within": {"parent": "not a key"}}),
+    ] {
+        let error = search(params.clone()).await.unwrap_err();
+        assert!(
+            error.to_string().contains("within"),
+            "{}: {}",
+            params,
+            error
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_mock_get_user_issues_board_filter() {
+    let server = mock_server().await;
+
+    let result = server
+        .get_user_issues(
+            serde_json::from_value(json!({
+                "board_filter": ["mock board"],
+                "priority_filter": ["High"],
+                "updated_since": "7 days ago"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+    // The board resolves to the project it is located in
+    assert!(
+        result.jql_query.contains("project = \"MOCK\""),
+        "{}",
+        result.jql_query
+    );
+    assert!(result.jql_query.contains("priority = \"High\""));
+    assert!(result.jql_query.contains("updated >= -7d"));
+    assert_eq!(result.search_result.issues[0].key, "MOCK-1");
+    let mock = server.mock_backend().unwrap();
+    assert_eq!(mock.searches().last(), Some(&result.jql_query));
+
+    let boards = result.applied_filters.boards.unwrap();
+    assert_eq!(boards.len(), 1);
+    assert_eq!(boards[0].input, "mock board");
+    assert_eq!(boards[0].id, "42");
+    assert_eq!(boards[0].name, "MOCK board");
+}
+
+#[tokio::test]
+async fn test_mock_get_user_issues_text_filter() {
+    let server = mock_server().await;
+    let user_issues = |text: &str| {
+        server.get_user_issues(serde_json::from_value(json!({"text_filter": text})).unwrap())
+    };
+
+    let result = user_issues("Payment Gateway").await.unwrap().into_inner();
+    assert!(
+        result
+            .jql_query
+            .contains("text ~ \"\\\"Payment Gateway\\\"\""),
+        "{}",
+        result.jql_query
+    );
+    assert_eq!(result.search_result.issues[0].key, "MOCK-1");
+    let summary = result.text_filter.unwrap();
+    assert_eq!((summary.fetched, summary.matched), (1, 1));
+
+    // JIRA answers MOCK-1 for any query; the post-filter drops it
+    let result = user_issues("refund").await.unwrap().into_inner();
+    assert!(result.search_result.issues.is_empty());
+    assert_eq!(result.text_filter.unwrap().matched, 0);
+
+    let result = user_issues("  ").await.unwrap().into_inner();
+    assert!(result.text_filter.is_none());
+    assert!(result
+        .explanation
+        .contains(&"text_filter: ignored, blank text".to_string()));
+}
+
+#[tokio::test]
+async fn test_mock_get_project_stats() {
+    let server = mock_server().await;
+
+    let result = server
+        .get_project_stats(serde_json::from_value(json!({"project_key": "mock"})).unwrap())
+        .await
+        .unwrap()
+        .into_inner();
+
+    // The mock counts every query as one issue
+    assert_eq!(result.project_key, "MOCK");
+    assert_eq!(result.window_days, 28);
+    assert_eq!((result.open, result.closed), (1, 1));
+    let types: Vec<&str> = result
+        .by_issue_type
+        .iter()
+        .map(|t| t.issue_type.as_str())
+        .collect();
+    assert_eq!(types, ["Bug", "Task", "Sub-task", "Epic"]);
+    assert_eq!(result.weekly_trend.len(), 4);
+    assert!(result.weekly_trend[0].week_start < result.weekly_trend[3].week_start);
+    assert_eq!(result.top_assignees.len(), 1);
+    assert_eq!(result.top_assignees[0].assignee, "Mock User");
+    assert_eq!(result.oldest_unresolved[0].key, "MOCK-1");
+    // open, closed, unassigned, 2 per issue type, 2 per week and the two searches
+    assert_eq!(result.jql_queries, 3 + 2 * 4 + 2 * 4 + 2);
+    assert!(result.notes.is_empty(), "{:?}", result.notes);
+
+    let error = server
+        .get_project_stats(
+            serde_json::from_value(json!({"project_key": "MOCK", "window_days": 200})).unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("window_days"), "{}", error);
+}
+
+#[tokio::test]
+async fn test_mock_search_user_filters() {
+    let server = mock_server().await;
+    let search =
+        |filters: serde_json::Value| server.search_issues(serde_json::from_value(filters).unwrap());
+    let resolved = |filter: Option<UserFilter>| match filter {
+        Some(UserFilter::User(user)) => user,
+        other => panic!("expected a user, got {:?}", other),
+    };
+
+    // "me" goes through the authenticated user's account, not the session name
+    let result = search(json!({"assigned_to": "me"}))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(
+        result.jql_query.starts_with("assignee = \"mock.user\""),
+        "{}",
+        result.jql_query
+    );
+    let user = resolved(result.assigned_to);
+    assert_eq!(user.input, "me");
+    assert_eq!(user.matched_by, "current_user");
+
+    let result = search(json!({"assigned_to": "Unassigned"}))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(result.jql_query.starts_with("assignee is EMPTY"));
+    assert!(matches!(result.assigned_to, Some(UserFilter::Unassigned)));
+
+    let result = search(json!({"assigned_to": "jdoe", "reporter": "current_user"}))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(
+        result
+            .jql_query
+            .starts_with("assignee = \"jdoe\" AND reporter = \"mock.user\""),
+        "{}",
+        result.jql_query
+    );
+    assert_eq!(resolved(result.assigned_to).matched_by, "account_id");
+    assert_eq!(resolved(result.reporter).input, "current_user");
+
+    // Server identifies users by username
+    let result = search(json!({"reporter": "jane.doe@example.com"}))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(result.jql_query.starts_with("reporter = \"jdoe\""));
+    let user = resolved(result.reporter);
+    assert_eq!(user.display_name, "Jane Doe");
+    assert_eq!(user.matched_by, "email");
+    assert!(result.assigned_to.is_none());
+
+    let result = search(json!({"reporter": "unassigned"}))
+        .await
+        .unwrap()
+        .into_inner();
+    assert!(result.jql_query.starts_with("reporter is EMPTY"));
+
+    // An ambiguous name is refused instead of silently matching nobody
+    let error = search(json!({"reporter": "Jane Doe"}))
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("reporter"), "{}", error);
+    assert!(
+        error.contains("Several users share this display name"),
+        "{}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn test_mock_search_in_fields() {
+    let server = mock_server().await;
+
+    let result = server
+        .search_issues(
+            serde_json::from_value(json!({
+                "query_text": "login fails (again)",
+                "search_in": ["summary", "comments"]
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    let term = r#""\"login fails \\(again\\)\"""#;
+    assert!(
+        result.jql_query.starts_with(&format!(
+            "(summary ~ {term} OR comment ~ {term})",
+            term = term
+        )),
+        "{}",
+        result.jql_query
+    );
+    assert!(result.text_search_fallback.is_none());
+
+    // Without search_in all text fields are searched, as before
+    let result = server
+        .search_issues(serde_json::from_value(json!({"query_text": "login fails"})).unwrap())
+        .await
+        .unwrap();
+    assert!(result.jql_query.starts_with("text ~ \"login fails\""));
+
+    let status = server
+        .get_server_status(GetServerStatusParams::default())
+        .await
+        .unwrap();
+    assert_eq!(status.targeted_text_search, Some(true));
+}
+
+#[tokio::test]
+async fn test_mock_search_recent_and_created_by() {
+    let server = mock_server().await;
+
+    let result = server
+        .search_issues(serde_json::from_value(json!({"recent": true})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(
+        result.jql_query,
+        "issuekey in issueHistory() ORDER BY lastViewed DESC"
+    );
+    assert!(result.recent_fallback.is_none());
+
+    let result = server
+        .search_issues(
+            serde_json::from_value(json!({
+                "created_by": "me",
+                "order_by": {"field": "lastViewed"}
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        result.jql_query,
+        "creator = \"mock.user\" ORDER BY lastViewed DESC"
+    );
+    assert!(matches!(result.created_by, Some(UserFilter::User(_))));
+}
+
+#[tokio::test]
+async fn test_mock_get_my_mentions() {
+    let server = mock_server().await;
+
+    // The mock search answers MOCK-1, which has no comments
+    let result = server
+        .get_my_mentions(serde_json::from_value(json!({"since": "2026-01-01T00:00:00Z"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(result.user, "Mock User");
+    assert_eq!(result.issues_scanned, 1);
+    assert!(result.mentions.is_empty());
+    assert_eq!(result.since, "2026-01-01T00:00:00.000Z");
+    assert_eq!(result.cursor, result.since);
+
+    let err = server
+        .get_my_mentions(serde_json::from_value(json!({"max_issues": 0})).unwrap())
+        .await
+        .unwrap_err();
+    assert_eq!(err.code, -32602);
+}
+
+#[tokio::test]
+async fn test_mock_find_similar_issues() {
+    let server = mock_server().await;
+
+    let result = server
+        .find_similar_issues(
+            serde_json::from_value(json!({
+                "summary_text": "Payment gateway timeout at checkout",
+                "project_key": "MOCK"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.searched, 1);
+    assert!(result
+        .jql_query
+        .starts_with("text ~ \"Payment gateway timeout at checkout\""));
+    let candidate = &result.candidates[0];
+    assert_eq!(candidate.key, "MOCK-1");
+    assert_eq!(
+        candidate.matched_tokens,
+        ["checkout", "gateway", "payment", "timeout"]
+    );
+    assert!(candidate.score > 0.5, "{}", candidate.score);
+
+    let err = server
+        .find_similar_issues(serde_json::from_value(json!({"summary_text": "the of"})).unwrap())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("summary_text"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_mock_list_labels_and_issues_by_label() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .list_labels(serde_json::from_value(json!({"filter": "PAY"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(result.labels, ["payments"]);
+    assert!(!result.from_cache);
+
+    // The second call filters the cached list
+    let requests = mock.request_count();
+    let result = server
+        .list_labels(serde_json::from_value(json!({"max_results": 2})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(result.labels, ["backend", "gateway"]);
+    assert_eq!(result.total, 3);
+    assert!(!result.is_last);
+    assert!(result.from_cache);
+    assert_eq!(mock.request_count(), requests);
+
+    let result = server
+        .get_issues_by_label(
+            serde_json::from_value(json!({
+                "labels": ["payments", "gateway"],
+                "match_all": true,
+                "project_key": "MOCK"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        result.jql_query,
+        "project = \"MOCK\" AND labels = \"payments\" AND labels = \"gateway\" ORDER BY updated DESC"
+    );
+    assert_eq!(result.search_result.issues[0].key, "MOCK-1");
+
+    let err = server
+        .get_issues_by_label(serde_json::from_value(json!({"labels": [" "]})).unwrap())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("labels"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_mock_watch_query() {
+    let server = mock_server().await;
+    let name = format!("payments-{}", std::process::id());
+
+    let created = server
+        .create_watch_query(
+            serde_json::from_value(json!({
+                "name": name,
+                "jql": "project = MOCK AND labels = payments ORDER BY created DESC",
+                "replace": true
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    // The newest match sets the cursor, so only later changes are reported
+    assert_eq!(created.watch.jql, "project = MOCK AND labels = payments");
+    assert_eq!(created.watch.cursor, "2026-01-06T10:30:00+00:00");
+    assert!(created.watch_names.contains(&name));
+
+    let err = server
+        .create_watch_query(
+            serde_json::from_value(json!({"name": name, "project_key": "MOCK"})).unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("already exists"), "{}", err);
+
+    let polled = server
+        .poll_watch_query(serde_json::from_value(json!({"name": name})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(
+        polled.jql_query,
+        "(project = MOCK AND labels = payments) AND updated >= 1767695400000 ORDER BY updated ASC"
+    );
+    assert!(polled.changes.is_empty());
+    assert_eq!(polled.cursor, created.watch.cursor);
+    assert!(!polled.has_more);
+
+    let err = server
+        .poll_watch_query(serde_json::from_value(json!({"name": "no-such-watch"})).unwrap())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("No watch named"), "{}", err);
+}
@@ -0,0 +1,801 @@
+/// End-to-end tests of the server lifecycle, configuration and diagnostics against the offline mock backend
+///
+/// These run without a JIRA instance: the server is pointed at the fixtures in
+/// tests/fixtures and writes are checked through the recorded requests.
+mod common;
+
+use common::mock::{
+    generated_fixtures, layered_fixtures, mock_fixtures, mock_server, mock_server_on,
+    mock_server_with, mock_state_dir,
+};
+use jira_mcp_server::config::{
+    DeploymentType, JiraConfig, MessageStyle, MessagesConfig, RequestLogConfig,
+};
+use jira_mcp_server::jira_client::JiraClient;
+use jira_mcp_server::warmup::WarmupState;
+use jira_mcp_server::{GetServerStatusParams, JiraMcpServer, TOOL_ALIASES};
+use pulseengine_mcp_protocol::CallToolRequestParam;
+use pulseengine_mcp_server::McpBackend;
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn test_mock_connection() {
+    let server = mock_server().await;
+    assert!(server.mock_backend().is_some());
+
+    let message = server.test_connection().await.unwrap();
+    assert!(message.contains("mock.user"), "{}", message);
+}
+
+#[tokio::test]
+async fn test_mock_shutdown_finishes_in_flight_writes() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+    mock.set_write_delay(Duration::from_millis(300));
+
+    let writer = {
+        let server = server.clone();
+        tokio::spawn(async move {
+            server
+                .add_comment(
+                    serde_json::from_value(json!({
+                        "issue_key": "MOCK-1",
+                        "comment_body": "Written during shutdown"
+                    }))
+                    .unwrap(),
+                )
+                .await
+        })
+    };
+    let coordinator = server.shutdown_coordinator();
+    while coordinator.in_flight() == 0 {
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+
+    // Shutdown waits for the slow write instead of cutting it off
+    assert_eq!(server.shutdown().await, 0);
+    assert_eq!(mock.writes_to("POST", "api/issue/MOCK-1/comment").len(), 1);
+    assert!(writer.await.unwrap().is_ok());
+
+    // The write was audited and the work sessions saved before shutdown returned
+    let audit = server
+        .get_audit_log(
+            serde_json::from_value(json!({"tool_name": "add_comment", "limit": 500})).unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(audit
+        .entries
+        .iter()
+        .any(|entry| entry.params["comment_body"] == "Written during shutdown"));
+    let state_dir = mock_state_dir();
+    assert!(state_dir.join("work_sessions.json").is_file());
+
+    // New writes are refused once shutdown began
+    let error = server
+        .add_comment(
+            serde_json::from_value(json!({"issue_key": "MOCK-1", "comment_body": "Too late"}))
+                .unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("shutting down"), "{}", error);
+    assert_eq!(mock.writes_to("POST", "api/issue/MOCK-1/comment").len(), 1);
+}
+
+#[tokio::test]
+async fn test_mock_server_status_uses_cached_connection_state() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    // Startup requests left a fresh connection state, so no probe is made
+    let requests = mock.request_count();
+    let status = server
+        .get_server_status(GetServerStatusParams::default())
+        .await
+        .unwrap();
+    assert_eq!(mock.request_count(), requests);
+    assert_eq!(status.jira_connection_status, "Connected");
+    let connection = status.connection.unwrap();
+    assert!(connection.connected);
+    assert!(!connection.probed);
+
+    let status = server
+        .get_server_status(GetServerStatusParams { force_check: true })
+        .await
+        .unwrap();
+    assert!(mock.request_count() > requests);
+    assert!(status.connection.unwrap().probed);
+    assert_eq!(status.authenticated_user.as_deref(), Some("Mock User"));
+}
+
+#[tokio::test]
+async fn test_mock_server_status_counts_registered_tools() {
+    let server = mock_server().await;
+    let status = server
+        .get_server_status(GetServerStatusParams::default())
+        .await
+        .unwrap();
+
+    let tools = server.try_get_tools_default().unwrap();
+    assert_eq!(status.tools_count, tools.len());
+    // Deprecated aliases are registered next to the tools they point to
+    let names: Vec<&str> = tools.iter().map(|tool| tool.name.as_str()).collect();
+    for entry in TOOL_ALIASES {
+        assert!(names.contains(&entry.alias), "{}", entry.alias);
+        assert!(names.contains(&entry.target), "{}", entry.target);
+    }
+}
+
+#[tokio::test]
+async fn test_mock_cached_lists_and_clearing() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+    let priorities = || server.list_priorities(serde_json::from_value(json!({})).unwrap());
+
+    let result = priorities().await.unwrap();
+    let names: Vec<&str> = result.priorities.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(names, ["Highest", "High", "Medium", "Low"]);
+    assert_eq!(result.aliases["normal"], "Medium");
+    assert!(!result.from_cache);
+    assert!(priorities().await.unwrap().from_cache);
+
+    let link_types = server.get_issue_link_types().await.unwrap();
+    assert_eq!(link_types.total, 2);
+    assert_eq!(link_types.link_types[0].inward, "is blocked by");
+    let requests = mock.request_count();
+    server.get_issue_link_types().await.unwrap();
+    assert_eq!(mock.request_count(), requests);
+
+    // Clearing a scope keeps the rest of the cache
+    let cleared = server
+        .clear_cache_scope(serde_json::from_value(json!({"categories": ["fields"]})).unwrap())
+        .await
+        .unwrap();
+    assert!(cleared.removed >= 1, "{}", cleared.message);
+    assert!(!priorities().await.unwrap().from_cache);
+    server.get_issue_link_types().await.unwrap();
+    assert_eq!(mock.request_count(), requests + 1);
+
+    let err = server
+        .clear_cache_scope(serde_json::from_value(json!({"keys": [" "]})).unwrap())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("categories"), "{}", err);
+
+    server.clear_cache().await.unwrap();
+    assert!(!priorities().await.unwrap().from_cache);
+    server.get_issue_link_types().await.unwrap();
+    assert_eq!(mock.request_count(), requests + 3);
+}
+
+#[tokio::test]
+async fn test_mock_constructors_build_the_same_server() {
+    // new() reads its configuration from the environment
+    let state_dir = std::env::temp_dir().join(format!("jira-mcp-mock-env-{}", std::process::id()));
+    std::env::set_var(jira_mcp_server::backend::MOCK_ENV_VAR, "1");
+    std::env::set_var(
+        jira_mcp_server::backend::MOCK_FIXTURES_ENV_VAR,
+        mock_fixtures("mock"),
+    );
+    std::env::set_var("JIRA_MCP_STATE_DIR", &state_dir);
+    let from_env = JiraMcpServer::new()
+        .await
+        .expect("Failed to start server from the environment");
+    let from_config = mock_server().await;
+
+    let tasks = from_config.background_tasks();
+    assert_eq!(from_env.background_tasks(), tasks);
+    assert!(tasks.contains(&"cache_cleanup"), "{:?}", tasks);
+    assert!(tasks.contains(&"auto_checkpoint"), "{:?}", tasks);
+
+    let status = |server: JiraMcpServer| async move {
+        server
+            .get_server_status(GetServerStatusParams::default())
+            .await
+            .unwrap()
+    };
+    let (env_status, config_status) = (status(from_env).await, status(from_config).await);
+    assert_eq!(env_status.tools_count, config_status.tools_count);
+    assert_eq!(
+        env_status.authenticated_user,
+        config_status.authenticated_user
+    );
+    assert!(env_status.authenticated_user.is_some());
+}
+
+#[tokio::test]
+async fn test_mock_tool_errors_keep_their_category() {
+    let server = mock_server().await;
+
+    let result = server
+        .call_tool(CallToolRequestParam {
+            name: "start_todo_work".to_string(),
+            arguments: Some(json!({
+                "issue_key": "MOCK-1",
+                "todo_id_or_index": "1",
+                "target_minutes": 0
+            })),
+        })
+        .await
+        .unwrap();
+
+    // The tool result clients receive
+    let result = serde_json::to_value(&result).unwrap();
+    assert_eq!(result["isError"], json!(true));
+    let error = json!({
+        "code": -32602,
+        "message": "Invalid parameter: target_minutes - Target must be at least one minute",
+        "data": {
+            "category": "invalid_parameter",
+            "retryable": false,
+            "parameter": "target_minutes"
+        }
+    });
+    assert_eq!(result["structuredContent"], error);
+    let text = result["content"][0]["text"].as_str().unwrap();
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(text).unwrap(),
+        error
+    );
+
+    // Successes are not wrapped either
+    let result = server
+        .call_tool(CallToolRequestParam {
+            name: "get_server_status".to_string(),
+            arguments: Some(json!({})),
+        })
+        .await
+        .unwrap();
+    let result = serde_json::to_value(&result).unwrap();
+    assert_eq!(result["isError"], json!(false));
+    assert_eq!(
+        result["structuredContent"]["server_name"],
+        json!("JIRA MCP Server")
+    );
+}
+
+#[tokio::test]
+async fn test_mock_concurrent_identical_reads_share_one_request() {
+    let client = JiraClient::new(Arc::new(JiraConfig {
+        mock_fixtures_dir: Some(mock_fixtures("mock")),
+        ..JiraConfig::default()
+    }))
+    .await
+    .unwrap();
+    let mock = client.mock_backend().unwrap();
+    mock.set_read_delay(Duration::from_millis(100));
+
+    let requests = mock.request_count();
+    let details = || client.get_issue_details("MOCK-1", true, false, false);
+    let (first, second, third) = tokio::join!(details(), details(), details());
+    assert_eq!(mock.request_count(), requests + 1);
+    assert_eq!(first.unwrap().issue_info.key, "MOCK-1");
+    assert_eq!(second.unwrap().issue_info.key, "MOCK-1");
+    assert_eq!(third.unwrap().issue_info.key, "MOCK-1");
+
+    // Failures reach every caller and are not kept for the next one
+    let requests = mock.request_count();
+    let missing = || client.get_sprint_json(987654);
+    let (first, second) = tokio::join!(missing(), missing());
+    assert!(first.is_err() && second.is_err());
+    assert_eq!(mock.request_count(), requests + 1);
+    assert!(missing().await.is_err());
+    assert_eq!(mock.request_count(), requests + 2);
+}
+
+#[tokio::test]
+async fn test_mock_run_diagnostics() {
+    let server = mock_server().await;
+
+    let report = server
+        .run_diagnostics(serde_json::from_value(json!({"project_key": "MOCK"})).unwrap())
+        .await
+        .unwrap();
+
+    assert!(report.connected);
+    assert_eq!(report.authenticated_user.as_deref(), Some("Mock User"));
+    assert_eq!(report.capabilities.len(), 5);
+    assert!(report
+        .settings
+        .iter()
+        .any(|setting| setting.name == "mock_fixtures_dir"));
+
+    // The fixture denies CREATE_ISSUES
+    assert!(!report.ok);
+    assert_eq!(report.problems.len(), 1);
+    assert_eq!(report.problems[0].check, "create");
+    assert!(report.problems[0].remediation.contains("CREATE_ISSUES"));
+    let create = report
+        .capabilities
+        .iter()
+        .find(|check| check.capability == "create")
+        .unwrap();
+    assert_eq!(create.granted, Some(false));
+}
+
+#[tokio::test]
+async fn test_mock_markdown_format() {
+    let server = mock_server().await;
+
+    let result = server
+        .search_issues(
+            serde_json::from_value(json!({"project_key": "MOCK", "format": "markdown"})).unwrap(),
+        )
+        .await
+        .unwrap();
+    // The JSON fields stay as they are
+    assert_eq!(result.search_result.issues[0].key, "MOCK-1");
+    assert_eq!(
+        result.rendered.as_deref(),
+        Some(
+            "| Key | Summary | Status | Assignee |\n\
+             |---|---|---|---|\n\
+             | MOCK-1 | Checkout fails with payment gateway timeout | Open | Mock User |"
+        )
+    );
+
+    let todos = server
+        .list_todos(
+            serde_json::from_value(json!({"issue_key": "MOCK-1", "format": "markdown"})).unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        todos.rendered.as_deref(),
+        Some("- [ ] Reproduce in staging\n- [x] Collect gateway logs")
+    );
+}
+
+#[tokio::test]
+async fn test_mock_cache_warmup() {
+    let config = JiraConfig {
+        cache_warmup: true,
+        cache_warmup_projects: vec!["MOCK".to_string()],
+        ..Default::default()
+    };
+    let server = mock_server_with("mock", config).await;
+    let warmup = server.cache_warmup();
+    assert_eq!(warmup.status().state, WarmupState::Pending);
+
+    warmup.clone().start().unwrap().await.unwrap();
+    assert!(warmup.clone().start().is_none(), "warm-up runs only once");
+
+    let status = warmup.status();
+    assert_eq!(status.state, WarmupState::Completed);
+    assert!(status.failed.is_empty(), "{:?}", status.failed);
+    assert!(status.skipped.contains(&"current_user".to_string()));
+    for item in [
+        "projects",
+        "priorities",
+        "link_types",
+        "statuses:MOCK",
+        "issue_types:MOCK",
+    ] {
+        assert!(
+            status.loaded.contains(&item.to_string()),
+            "{:?}",
+            status.loaded
+        );
+    }
+
+    let cache = server.metadata_cache();
+    assert!(cache.get_project_info("MOCK").is_some());
+    assert_eq!(cache.get_link_types().unwrap().len(), 2);
+}
+
+/// Assign MOCK-1 to "me" and mention `mention`, returning the assignee and comment body sent
+async fn assign_and_mention(server: &JiraMcpServer, mention: &str) -> (serde_json::Value, String) {
+    let mock = server.mock_backend().unwrap();
+
+    server
+        .assign_issue(
+            serde_json::from_value(json!({"issue_key": "MOCK-1", "assignee": "me"})).unwrap(),
+        )
+        .await
+        .unwrap();
+    server
+        .add_comment(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "comment_body": "please review",
+                "mentions": [mention]
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let assign = mock.writes_to("PUT", "api/issue/MOCK-1");
+    let comment = mock.writes_to("POST", "api/issue/MOCK-1/comment");
+    (
+        assign[0].body.as_ref().unwrap()["fields"]["assignee"].clone(),
+        comment[0].body.as_ref().unwrap()["body"]
+            .as_str()
+            .unwrap()
+            .to_string(),
+    )
+}
+
+#[tokio::test]
+async fn test_mock_server_deployment_detected() {
+    let server = mock_server().await;
+
+    let status = server
+        .get_server_status(GetServerStatusParams::default())
+        .await
+        .unwrap();
+    assert_eq!(status.deployment.deployment_type, DeploymentType::Server);
+    assert_eq!(status.deployment.version.as_deref(), Some("9.12.4"));
+
+    // Server and Data Center identify users by username
+    let (assignee, body) = assign_and_mention(&server, "jdoe").await;
+    assert_eq!(assignee, json!({"name": "mock.user"}));
+    assert_eq!(body, "[~jdoe] please review");
+}
+
+#[tokio::test]
+async fn test_mock_story_points_field_from_catalog() {
+    let server = mock_server().await;
+
+    // The mock instance keeps story points in customfield_10002, as many Server instances do
+    let status = server
+        .get_server_status(GetServerStatusParams::default())
+        .await
+        .unwrap();
+    let story_points = &status.detected_fields.story_points;
+    assert_eq!(story_points.field_ids, vec!["customfield_10002"]);
+    assert_eq!(story_points.source, "field_catalog");
+    assert_eq!(
+        status.detected_fields.acceptance_criteria.field_ids,
+        vec!["customfield_10300"]
+    );
+
+    let result = server
+        .get_issue_details(serde_json::from_value(json!({"issue_key": "MOCK-1"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(result.issue_details.issue_info.story_points, Some(3.0));
+
+    // A configured mapping skips detection
+    let mut config = JiraConfig::default();
+    config
+        .field_mappings
+        .insert("story_points".to_string(), "customfield_10004".to_string());
+    let server = mock_server_with("mock", config).await;
+    let status = server
+        .get_server_status(GetServerStatusParams::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        status.detected_fields.story_points.field_ids,
+        vec!["customfield_10004"]
+    );
+    assert_eq!(status.detected_fields.story_points.source, "config");
+}
+
+#[tokio::test]
+async fn test_mock_cloud_deployment_override() {
+    let config = JiraConfig {
+        deployment_type: Some(DeploymentType::Cloud),
+        ..Default::default()
+    };
+    let server = mock_server_with("mock", config).await;
+
+    let status = server
+        .get_server_status(GetServerStatusParams::default())
+        .await
+        .unwrap();
+    assert_eq!(status.deployment.deployment_type, DeploymentType::Cloud);
+    assert_eq!(status.deployment.source, "config");
+
+    // Cloud uses account IDs, with the current user read from /myself
+    let (assignee, body) = assign_and_mention(&server, "me").await;
+    assert_eq!(assignee, json!({"accountId": "mock-user"}));
+    assert_eq!(body, "[~accountid:mock-user] please review");
+}
+
+#[tokio::test]
+async fn test_mock_get_recent_requests() {
+    let config = JiraConfig {
+        request_log: RequestLogConfig {
+            enabled: true,
+            capacity: 10,
+        },
+        ..Default::default()
+    };
+    let server = mock_server_with("mock", config).await;
+
+    server
+        .get_issue_details(serde_json::from_value(json!({"issue_key": "MOCK-1"})).unwrap())
+        .await
+        .unwrap();
+
+    let result = server
+        .get_recent_requests(serde_json::from_value(json!({"limit": 5})).unwrap())
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(result.enabled);
+    let entry = result
+        .entries
+        .iter()
+        .find(|entry| entry.endpoint == "api/issue/MOCK-1")
+        .expect("issue request recorded");
+    assert_eq!(entry.method, "GET");
+    assert_eq!(entry.status, "ok");
+    assert!(entry.authorization.is_none());
+}
+
+#[tokio::test]
+async fn test_mock_deprecated_tool_alias() {
+    let server = mock_server().await;
+    let params = json!({"issue_key": "MOCK-1"});
+
+    let current = server
+        .list_issue_attachments(serde_json::from_value(params.clone()).unwrap())
+        .await
+        .unwrap();
+    let aliased = server
+        .list_attachments(serde_json::from_value(params).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(aliased.issue_key, current.issue_key);
+    assert_eq!(aliased.total_count, current.total_count);
+    assert!(aliased
+        .deprecation
+        .contains("call 'list_issue_attachments' instead"));
+
+    // The notice sits next to the result's own fields
+    let serialized = serde_json::to_value(&aliased).unwrap();
+    assert_eq!(serialized["issue_key"], "MOCK-1");
+    assert!(serialized["deprecation"].is_string());
+}
+
+#[tokio::test]
+async fn test_mock_message_styles() {
+    let params = || {
+        serde_json::from_value(
+            json!({"issue_keys": ["MOCK-1", "MOCK-2"], "add_labels": ["triaged"]}),
+        )
+        .unwrap()
+    };
+    let server_with = |style, locale: Option<&str>| {
+        mock_server_with(
+            "mock",
+            JiraConfig {
+                messages: MessagesConfig {
+                    style,
+                    locale: locale.map(String::from),
+                },
+                ..Default::default()
+            },
+        )
+    };
+
+    let verbose = server_with(MessageStyle::Verbose, None).await;
+    let result = verbose.bulk_add_labels(params()).await.unwrap();
+    assert_eq!(
+        result.message,
+        "Bulk updated labels on 2/2 issues successfully (0 failed)"
+    );
+
+    let terse = server_with(MessageStyle::Terse, Some("de")).await;
+    let result = terse.bulk_add_labels(params()).await.unwrap();
+    assert_eq!(result.message, "2/2 gelabelt, 0 fehlgeschlagen");
+
+    // Without messages the structured fields are unchanged and "message" is gone
+    let silent = server_with(MessageStyle::None, None).await;
+    let result = silent.bulk_add_labels(params()).await.unwrap();
+    let serialized = serde_json::to_value(&result).unwrap();
+    assert!(serialized.get("message").is_none(), "{}", serialized);
+    assert_eq!(serialized["success_count"], 2);
+}
+
+#[tokio::test]
+async fn test_mock_performance_block() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let before = mock.request_count();
+    let result = server
+        .get_issue_details(
+            serde_json::from_value(json!({"issue_key": "MOCK-2", "expand_subtasks": true}))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let requests = mock.request_count() - before;
+    assert_eq!(result.performance.api_calls as usize, requests);
+    assert_eq!(result.performance.retries, 0);
+
+    // Bulk work runs on spawned tasks and still counts against the call
+    let before = mock.request_count();
+    let result = server
+        .bulk_add_labels(
+            serde_json::from_value(
+                json!({"issue_keys": ["MOCK-1", "MOCK-2"], "add_labels": ["triaged"]}),
+            )
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    let bulk_requests = mock.request_count() - before;
+    assert!(bulk_requests >= 2);
+    assert_eq!(result.performance.api_calls as usize, bulk_requests);
+
+    let serialized = serde_json::to_value(&*result).unwrap();
+    for field in ["duration_ms", "api_calls", "cache_hits", "retries"] {
+        assert!(serialized["performance"].get(field).is_some(), "{}", field);
+    }
+
+    // The search tools report the same block
+    let before = mock.request_count();
+    let result = server
+        .get_user_issues(serde_json::from_value(json!({"status_filter": ["open"]})).unwrap())
+        .await
+        .unwrap();
+    let requests = mock.request_count() - before;
+    assert_eq!(result.performance.call.api_calls as usize, requests);
+    let serialized = serde_json::to_value(&*result).unwrap();
+    for field in ["duration_ms", "api_calls", "cache_hits", "retries"] {
+        assert!(serialized["performance"].get(field).is_some(), "{}", field);
+    }
+    assert!(serialized["performance"].get("query_complexity").is_some());
+
+    let before = mock.request_count();
+    let result = server
+        .search_issues(serde_json::from_value(json!({"project_key": "MOCK"})).unwrap())
+        .await
+        .unwrap();
+    let requests = mock.request_count() - before;
+    assert_eq!(result.performance.call.api_calls as usize, requests);
+    let serialized = serde_json::to_value(&*result).unwrap();
+    for field in [
+        "duration_ms",
+        "api_calls",
+        "cache_hits",
+        "retries",
+        "cache_hit",
+    ] {
+        assert!(serialized["performance"].get(field).is_some(), "{}", field);
+    }
+
+    let status = server
+        .get_server_status(GetServerStatusParams::default())
+        .await
+        .unwrap();
+    let summary = &status.tool_performance["bulk_add_labels"];
+    assert_eq!(summary.calls, 1);
+    assert_eq!(summary.avg_api_calls, bulk_requests as f64);
+    assert!(status.tool_performance.contains_key("get_issue_details"));
+    assert!(status.tool_performance.contains_key("get_user_issues"));
+}
+
+#[tokio::test]
+async fn test_mock_get_my_permissions() {
+    let server = mock_server().await;
+
+    let result = server
+        .get_my_permissions(serde_json::from_value(json!({"project_key": "mock"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(result.context, "project MOCK");
+    assert_eq!(result.permissions.get("EDIT_ISSUES"), Some(&true));
+    assert_eq!(result.missing, ["CREATE_ISSUES"]);
+    assert!(!result.cached);
+    assert!(!result.precheck_enabled);
+
+    let result = server
+        .get_my_permissions(serde_json::from_value(json!({"project_key": "MOCK"})).unwrap())
+        .await
+        .unwrap();
+    assert!(result.cached);
+}
+
+#[tokio::test]
+async fn test_mock_get_my_permissions_on_issue_url() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .get_my_permissions(
+            serde_json::from_value(json!({
+                "issue_key": "[mock-1](https://mock.atlassian.net/browse/mock-1)"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.context, "issue MOCK-1");
+    let read = mock
+        .reads()
+        .into_iter()
+        .find(|endpoint| endpoint.starts_with("api/mypermissions"))
+        .unwrap();
+    assert!(read.ends_with("&issueKey=MOCK-1"), "{}", read);
+
+    let err = server
+        .get_my_permissions(
+            serde_json::from_value(json!({"issue_key": "https://mock.atlassian.net/browse"}))
+                .unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.code, -32602);
+}
+
+#[tokio::test]
+async fn test_mock_permission_precheck() {
+    let server = mock_server_with(
+        "mock",
+        JiraConfig {
+            permission_precheck: true,
+            ..JiraConfig::default()
+        },
+    )
+    .await;
+
+    // The fixture grants everything but CREATE_ISSUES
+    let err = server
+        .create_issue(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "summary": "Add retries to the gateway client",
+                "issue_type": "Task"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.code, -32004);
+    assert!(err.message.contains("You lack CREATE_ISSUES in MOCK"));
+
+    server
+        .add_comment(
+            serde_json::from_value(json!({"issue_key": "MOCK-1", "comment_body": "Looks good"}))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_mock_permission_precheck_without_project() {
+    let fixtures = generated_fixtures(
+        "no-sprint-permission",
+        &[(
+            "GET/api/mypermissions.json",
+            json!({"permissions": {
+                "MANAGE_SPRINTS_PERMISSION": {"key": "MANAGE_SPRINTS_PERMISSION", "havePermission": false}
+            }}),
+        )],
+    );
+    let server = mock_server_on(
+        layered_fixtures(&[fixtures]),
+        JiraConfig {
+            permission_precheck: true,
+            ..JiraConfig::default()
+        },
+    )
+    .await;
+    let mock = server.mock_backend().unwrap();
+
+    // A sprint names no project, so the permission is checked in any project
+    let err = server
+        .start_sprint(serde_json::from_value(json!({"sprint_id": 1})).unwrap())
+        .await
+        .unwrap_err();
+    assert_eq!(err.code, -32004);
+    assert!(
+        err.message
+            .contains("You lack MANAGE_SPRINTS_PERMISSION in any project"),
+        "{}",
+        err.message
+    );
+    assert!(mock.writes().is_empty());
+}
@@ -0,0 +1,443 @@
+/// End-to-end tests of the sprint and board tools against the offline mock backend
+///
+/// These run without a JIRA instance: the server is pointed at the fixtures in
+/// tests/fixtures and writes are checked through the recorded requests.
+mod common;
+
+use common::mock::{
+    fixture_dir, generated_fixtures, layered_fixtures, mock_server, mock_server_on,
+    mock_server_with,
+};
+use jira_mcp_server::config::JiraConfig;
+use jira_mcp_server::JiraMcpServer;
+use serde_json::{json, Value};
+
+/// Search fixture of a sprint of `count` issues BIG-1.., the first `done` of them
+/// finished, served at most `page_size` per page
+fn sprint_search(count: usize, done: usize, page_size: usize) -> Value {
+    let status = |n: usize| {
+        if n <= done {
+            json!({
+                "self": "http://mock.jira.local/rest/api/2/status/10001",
+                "id": "10001",
+                "name": "Done",
+                "statusCategory": {"id": 3, "key": "done", "name": "Done"}
+            })
+        } else {
+            json!({
+                "self": "http://mock.jira.local/rest/api/2/status/3",
+                "id": "3",
+                "name": "In Progress",
+                "statusCategory": {"id": 4, "key": "indeterminate", "name": "In Progress"}
+            })
+        }
+    };
+    let issues: Vec<Value> = (1..=count)
+        .map(|n| {
+            json!({
+                "self": format!("http://mock.jira.local/rest/api/2/issue/{}", 30000 + n),
+                "id": (30000 + n).to_string(),
+                "key": format!("BIG-{}", n),
+                "fields": {
+                    "summary": format!("Sprint issue {}", n),
+                    "issuetype": {"id": "10004", "name": "Task", "subtask": false},
+                    "status": status(n),
+                    "created": "2026-01-05T09:00:00.000+0000",
+                    "updated": "2026-01-06T10:30:00.000+0000",
+                    "project": {"id": "10100", "key": "BIG", "name": "Big Sprint"}
+                }
+            })
+        })
+        .collect();
+
+    json!({"startAt": 0, "maxResults": page_size, "total": count, "issues": issues})
+}
+
+/// Server on the `mock_big_sprint` set, its sprint search generated by [`sprint_search`]
+async fn big_sprint_server(
+    name: &str,
+    count: usize,
+    done: usize,
+    page_size: usize,
+) -> JiraMcpServer {
+    let search = generated_fixtures(
+        name,
+        &[("GET/api/search.json", sprint_search(count, done, page_size))],
+    );
+    mock_server_on(
+        layered_fixtures(&[search, fixture_dir("mock_big_sprint")]),
+        JiraConfig::default(),
+    )
+    .await
+}
+
+#[tokio::test]
+async fn test_mock_get_standup_summary() {
+    let server = mock_server().await;
+
+    let result = server
+        .get_standup_summary(serde_json::from_value(json!({"since": "2026-01-06"})).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(result.user, "Mock User");
+    assert_eq!(result.since, "2026-01-06");
+    assert_eq!(result.yesterday.len(), 1);
+    assert_eq!(result.yesterday[0].key, "MOCK-1");
+    // Only Mock User's worklog started on or after the 6th counts
+    assert_eq!(result.time_logged_seconds, 3600);
+    // Nothing throttled, plenty of budget left
+    assert!(result.rate_limit.is_none());
+}
+
+#[tokio::test]
+async fn test_mock_get_sprint_planning_summary() {
+    let server = mock_server().await;
+
+    let result = server
+        .get_sprint_planning_summary(
+            serde_json::from_value(json!({"project_key": "mock", "candidate_limit": 5})).unwrap(),
+        )
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(result.board_id, 42);
+    assert_eq!(result.project_key.as_deref(), Some("MOCK"));
+
+    let backlog = result.backlog.unwrap();
+    let ranked: Vec<&str> = backlog.iter().map(|c| c.key.as_str()).collect();
+    assert_eq!(ranked, vec!["MOCK-2", "MOCK-1"]);
+    assert_eq!(backlog[0].rank, 1);
+
+    // Only the story is checked for refinement
+    let gaps = result.needs_refinement.unwrap();
+    assert_eq!(gaps.len(), 1);
+    assert_eq!(gaps[0].key, "MOCK-2");
+    assert!(gaps[0].missing.contains(&"story_points".to_string()));
+
+    assert_eq!(result.workload.unwrap()["Mock User"].open_issues, 1);
+
+    // The fixtures have no sprints; that section fails on its own
+    assert!(result.velocity.is_none());
+    assert!(result.section_errors.contains_key("velocity"));
+}
+
+#[tokio::test]
+async fn test_mock_close_sprint_localized_statuses() {
+    let server = mock_server_with("mock_fr", JiraConfig::default()).await;
+    let mock = server.mock_backend().unwrap();
+
+    // FR-1 "Terminé" and FR-3 "Fermée" are in the done category, FR-2 "En cours" is not
+    let result = server
+        .close_sprint(
+            serde_json::from_value(json!({"sprint_id": 7, "move_incomplete_to": 8})).unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.completed_issues, 2);
+    assert_eq!(result.incomplete_issues, 1);
+    assert_eq!(result.moved_issues, Some(1));
+
+    let moves = mock.writes_to("POST", "agile/sprint/8/issue");
+    assert_eq!(moves.len(), 1);
+    assert_eq!(moves[0].body.as_ref().unwrap()["issues"], json!(["FR-2"]));
+
+    // The closed sprint is echoed as stored, goal included
+    assert_eq!(result.sprint.state.as_deref(), Some("closed"));
+    assert_eq!(
+        result.sprint.goal.as_deref(),
+        Some("Stabiliser les paiements")
+    );
+    assert!(result.remaining_issue_keys.is_empty());
+    assert_eq!(result.pages_fetched, 1);
+}
+
+#[tokio::test]
+async fn test_mock_close_sprint_moves_in_chunks() {
+    // 60 issues, of which BIG-6..BIG-60 are in progress, in pages of 25
+    let server = big_sprint_server("close-in-chunks", 60, 5, 25).await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .close_sprint(
+            serde_json::from_value(json!({"sprint_id": 7, "move_incomplete_to": 8})).unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.completed_issues, 5);
+    assert_eq!(result.incomplete_issues, 55);
+    assert_eq!(result.moved_issues, Some(55));
+    assert!(result.remaining_issue_keys.is_empty());
+    assert_eq!(result.pages_fetched, 3);
+
+    let moves = mock.writes_to("POST", "agile/sprint/8/issue");
+    let chunk_sizes: Vec<usize> = moves
+        .iter()
+        .map(|m| m.body.as_ref().unwrap()["issues"].as_array().unwrap().len())
+        .collect();
+    assert_eq!(chunk_sizes, vec![50, 5]);
+    assert_eq!(moves[1].body.as_ref().unwrap()["issues"][4], "BIG-60");
+}
+
+#[tokio::test]
+async fn test_mock_close_sprint_lists_unmoved_issues() {
+    // Seven issues served three at a time; every page counts
+    let server = big_sprint_server("close-unmoved", 7, 2, 3).await;
+
+    let result = server
+        .close_sprint(serde_json::from_value(json!({"sprint_id": 7})).unwrap())
+        .await
+        .unwrap();
+
+    assert_eq!(result.pages_fetched, 3);
+    assert_eq!(result.completed_issues, 2);
+    assert_eq!(result.incomplete_issues, 5);
+    assert_eq!(result.moved_issues, None);
+    assert_eq!(
+        result.remaining_issue_keys,
+        ["BIG-3", "BIG-4", "BIG-5", "BIG-6", "BIG-7"]
+    );
+}
+
+#[tokio::test]
+async fn test_mock_update_active_sprint() {
+    let server = mock_server_with("mock_fr", JiraConfig::default()).await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .update_sprint(
+            serde_json::from_value(json!({
+                "sprint_id": 7,
+                "goal": "Stabiliser les paiements",
+                "end_date": "2026-01-23"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(result.updated_fields, vec!["goal", "end_date"]);
+    assert_eq!(result.sprint.id, 7);
+
+    // Only the given fields are sent; the date-only end means midnight UTC
+    let updates = mock.writes_to("POST", "agile/sprint/7");
+    assert_eq!(updates.len(), 1);
+    assert_eq!(
+        updates[0].body,
+        Some(json!({
+            "goal": "Stabiliser les paiements",
+            "endDate": "2026-01-23T00:00:00Z"
+        }))
+    );
+
+    // Sprint 7 starts on 2026-01-05
+    let err = server
+        .update_sprint(
+            serde_json::from_value(json!({"sprint_id": 7, "end_date": "2026-01-01"})).unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("end_date"), "{}", err);
+
+    assert!(server
+        .update_sprint(serde_json::from_value(json!({"sprint_id": 7})).unwrap())
+        .await
+        .is_err());
+    assert_eq!(mock.writes_to("POST", "agile/sprint/7").len(), 1);
+}
+
+#[tokio::test]
+async fn test_mock_get_estimate_rollup() {
+    let server = mock_server().await;
+
+    // Every child search answers MOCK-1, which has no estimate in search results
+    let result = server
+        .get_estimate_rollup(serde_json::from_value(json!({"issue_key": "MOCK-2"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(result.issue_key, "MOCK-2");
+    assert_eq!(result.children.len(), 1);
+    let child = &result.children[0];
+    assert_eq!(
+        (child.key.as_str(), child.parent_key.as_str()),
+        ("MOCK-1", "MOCK-2")
+    );
+    assert_eq!(child.depth, 1);
+    // Found again one level down, MOCK-1 is not counted twice
+    assert_eq!(result.depth_reached, 1);
+    assert_eq!(result.rollup.issue_count, 1);
+    assert_eq!(result.rollup.without_estimate, 1);
+    assert_eq!(result.rollup.remaining_estimate, "0m");
+    assert!(!result.truncated);
+    assert_eq!(result.warnings.len(), 1);
+
+    let err = server
+        .get_estimate_rollup(
+            serde_json::from_value(json!({"issue_key": "MOCK-2", "max_depth": 0})).unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.code, -32602);
+}
+
+#[tokio::test]
+async fn test_mock_get_board_configuration() {
+    let server = mock_server().await;
+
+    let result = server
+        .get_board_configuration(serde_json::from_value(json!({"project_key": "mock"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(result.configuration.board_id, 42);
+    assert_eq!(result.configuration.filter_id.as_deref(), Some("10100"));
+    assert_eq!(
+        result.configuration.filter_jql.as_deref(),
+        Some("project = MOCK ORDER BY Rank ASC")
+    );
+    let columns: Vec<&str> = result
+        .configuration
+        .columns
+        .iter()
+        .map(|column| column.name.as_str())
+        .collect();
+    assert_eq!(columns, ["To Do", "In Progress", "Review", "Done"]);
+    // Status names come from the status catalog
+    let review = &result.configuration.columns[2];
+    assert_eq!(review.max, Some(3));
+    assert_eq!(review.statuses[0].name.as_deref(), Some("Ready for QA"));
+    assert_eq!(
+        review.statuses[0].category_key.as_deref(),
+        Some("indeterminate")
+    );
+    // The board's estimation field is the detected story points field
+    assert_eq!(result.story_points_fields, ["customfield_10002"]);
+    assert!(result.warnings.is_empty());
+    assert!(!result.cached);
+
+    let result = server
+        .get_board_configuration(serde_json::from_value(json!({"board_id": 42})).unwrap())
+        .await
+        .unwrap();
+    assert!(result.cached);
+
+    let result = server
+        .get_board_column_issues(
+            serde_json::from_value(json!({"project_key": "MOCK", "column": "review"})).unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.column, "Review");
+    assert!(result
+        .jql
+        .starts_with("filter = 10100 AND status IN (10010)"));
+    assert_eq!(result.issues[0].key, "MOCK-1");
+
+    let err = server
+        .get_board_column_issues(
+            serde_json::from_value(json!({"board_id": 42, "column": "Blocked"})).unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert_eq!(err.code, -32602);
+}
+
+#[tokio::test]
+async fn test_mock_list_and_read_sprints() {
+    let server = mock_server().await;
+
+    let result = server
+        .list_sprints(serde_json::from_value(json!({"board_id": 42})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(result.total, 3);
+    assert!(!result.has_more);
+    let active = server
+        .list_sprints(serde_json::from_value(json!({"board_id": 42, "state": "Active"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(active.total, 1);
+    assert_eq!(active.sprints[0].id, 7);
+
+    let info = server
+        .get_sprint_info(serde_json::from_value(json!({"sprint_id": 7})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(info.sprint.name, "Sprint 7");
+    assert_eq!(info.sprint.state.as_deref(), Some("active"));
+    assert_eq!(info.sprint.origin_board_id, Some(42));
+
+    let issues = server
+        .get_sprint_issues(serde_json::from_value(json!({"sprint_id": 7})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(issues.sprint.state.as_deref(), Some("active"));
+    assert_eq!(issues.search_result.issues[0].key, "MOCK-1");
+
+    let err = server
+        .get_sprint_info(serde_json::from_value(json!({"sprint_id": 99})).unwrap())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("not found"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_mock_create_start_and_fill_sprint() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let created = server
+        .create_sprint(serde_json::from_value(json!({"board_id": 42, "name": "Sprint 9"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(created.sprint.id, 9);
+    assert_eq!(
+        mock.writes_to("POST", "agile/sprint")[0].body,
+        Some(json!({"name": "Sprint 9", "originBoardId": 42}))
+    );
+
+    // Sprint 7 is already running
+    let err = server
+        .start_sprint(serde_json::from_value(json!({"sprint_id": 7})).unwrap())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("already active"), "{}", err);
+
+    // The end date comes from the sprint when not given
+    let started = server
+        .start_sprint(
+            serde_json::from_value(json!({
+                "sprint_id": 8,
+                "start_date": "2026-01-19T09:00:00Z",
+                "goal": "Ship the retry budget"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(started.sprint.state.as_deref(), Some("active"));
+    assert_eq!(started.issue_count, 1);
+    assert!(started.warnings.is_empty(), "{:?}", started.warnings);
+    let update = mock.writes_to("POST", "agile/sprint/8")[0]
+        .body
+        .clone()
+        .unwrap();
+    assert_eq!(update["state"], "active");
+    assert_eq!(update["endDate"], "2026-02-02T09:00:00Z");
+
+    let moved = server
+        .move_to_sprint(
+            serde_json::from_value(json!({"sprint_id": 8, "issue_keys": ["MOCK-1", "MOCK-2"]}))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(moved.sprint_name, "Sprint 8");
+    assert_eq!(moved.issues_moved, 2);
+    assert_eq!(
+        mock.writes_to("POST", "agile/sprint/8/issue")[0].body,
+        Some(json!({"issues": ["MOCK-1", "MOCK-2"]}))
+    );
+}
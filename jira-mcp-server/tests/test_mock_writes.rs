@@ -0,0 +1,1366 @@
+/// End-to-end tests of the issue writing tools against the offline mock backend
+///
+/// These run without a JIRA instance: the server is pointed at the fixtures in
+/// tests/fixtures and writes are checked through the recorded requests.
+mod common;
+
+use common::mock::{mock_fixtures, mock_server, mock_server_with};
+use jira_mcp_server::config::{DeploymentType, JiraConfig, LocaleAliases, ProjectDefaults};
+use jira_mcp_server::jira_client::JiraClient;
+use jira_mcp_server::tools::TableFormat;
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn test_mock_attribution_label_and_signatures() {
+    const COMMENT_SIGNATURE: &str = "— posted via JIRA MCP Server on behalf of Mock User";
+    const WORKLOG_SIGNATURE: &str = "— logged via JIRA MCP Server";
+    let signed = JiraConfig {
+        auto_label_created_issues: Some("ai-created".to_string()),
+        comment_signature: Some(COMMENT_SIGNATURE.to_string()),
+        worklog_signature: Some(WORKLOG_SIGNATURE.to_string()),
+        ..JiraConfig::default()
+    };
+
+    for (config, configured) in [(JiraConfig::default(), false), (signed, true)] {
+        let server = mock_server_with("mock", config.clone()).await;
+        let mock = server.mock_backend().unwrap();
+
+        for skip in [false, true] {
+            mock.clear_writes();
+            server
+                .create_issue(
+                    serde_json::from_value(json!({
+                        "project_key": "MOCK",
+                        "summary": "Flaky login test",
+                        "labels": ["tests"],
+                        "skip_auto_label": skip
+                    }))
+                    .unwrap(),
+                )
+                .await
+                .unwrap();
+            let fields = &mock.writes_to("POST", "api/issue")[0].body.clone().unwrap()["fields"];
+            let expected = if configured && !skip {
+                json!(["tests", "ai-created"])
+            } else {
+                json!(["tests"])
+            };
+            assert_eq!(fields["labels"], expected);
+
+            // A retry with the same key replays instead of signing again
+            let key = format!("attribution-{}-{}-{}", configured, skip, std::process::id());
+            for _ in 0..2 {
+                server
+                    .add_comment(
+                        serde_json::from_value(json!({
+                            "issue_key": "MOCK-1",
+                            "comment_body": "Reproduced on main",
+                            "skip_signature": skip,
+                            "idempotency_key": key
+                        }))
+                        .unwrap(),
+                    )
+                    .await
+                    .unwrap();
+            }
+            let comments = mock.writes_to("POST", "api/issue/MOCK-1/comment");
+            assert_eq!(comments.len(), 1);
+            let expected = if configured && !skip {
+                format!("Reproduced on main\n\n{}", COMMENT_SIGNATURE)
+            } else {
+                "Reproduced on main".to_string()
+            };
+            assert_eq!(comments[0].body.as_ref().unwrap()["body"], expected);
+        }
+
+        // Worklogs of the todo tools are signed in the client
+        mock.clear_writes();
+        let client = JiraClient::new(Arc::new(JiraConfig {
+            mock_fixtures_dir: Some(mock_fixtures("mock")),
+            ..config
+        }))
+        .await
+        .unwrap();
+        let mock = client.mock_backend().unwrap();
+        for signed in [true, false] {
+            client
+                .add_worklog(
+                    "MOCK-1",
+                    600,
+                    Some("Paired on the fix".to_string()),
+                    None,
+                    signed,
+                )
+                .await
+                .unwrap();
+        }
+        let worklogs = mock.writes_to("POST", "api/issue/MOCK-1/worklog");
+        let comment = |i: usize| worklogs[i].body.as_ref().unwrap()["comment"].clone();
+        let expected = if configured {
+            format!("Paired on the fix\n\n{}", WORKLOG_SIGNATURE)
+        } else {
+            "Paired on the fix".to_string()
+        };
+        assert_eq!(comment(0), expected);
+        assert_eq!(comment(1), "Paired on the fix");
+    }
+}
+
+#[tokio::test]
+async fn test_mock_add_comment_records_write() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .add_comment(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "comment_body": "Rolled back the gateway config"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.comment.id, "20001");
+
+    let writes = mock.writes_to("POST", "api/issue/MOCK-1/comment");
+    assert_eq!(writes.len(), 1);
+    assert_eq!(
+        writes[0].body.as_ref().unwrap()["body"],
+        "Rolled back the gateway config"
+    );
+}
+
+#[tokio::test]
+async fn test_mock_jsm_internal_comments() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+    let add = |extra: serde_json::Value| {
+        let mut params = json!({"issue_key": "MOCK-1", "comment_body": "Customer called back"});
+        params
+            .as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        server.add_comment(serde_json::from_value(params).unwrap())
+    };
+
+    let result = add(json!({"jsm_internal": true})).await.unwrap();
+    assert_eq!(result.comment.jsm_internal, Some(true));
+    let result = add(json!({})).await.unwrap();
+    assert_eq!(result.comment.jsm_internal, None);
+
+    let writes = mock.writes_to("POST", "api/issue/MOCK-1/comment");
+    assert_eq!(
+        writes[0].body.as_ref().unwrap()["properties"],
+        json!([{"key": "sd.public.comment", "value": {"internal": true}}])
+    );
+    assert!(writes[1].body.as_ref().unwrap().get("properties").is_none());
+
+    // Listed comments carry the flag whether JIRA reports it as jsdPublic or
+    // as the sd.public.comment property
+    let details = server
+        .get_issue_details(
+            serde_json::from_value(json!({"issue_key": "MOCK-3", "include_comments": true}))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let comments = details.issue_details.comments.as_ref().unwrap();
+    assert_eq!(comments[0].jsm_internal, Some(false));
+    assert_eq!(comments[1].jsm_internal, Some(true));
+}
+
+#[tokio::test]
+async fn test_mock_add_comment_length_limit() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+    let body = "stack frame\n".repeat(4_000);
+
+    // Over the limit: rejected before anything is sent
+    let error = server
+        .add_comment(
+            serde_json::from_value(json!({"issue_key": "MOCK-1", "comment_body": body})).unwrap(),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("48000 characters"), "{}", error);
+    assert!(error.contains("32767 allowed"), "{}", error);
+    assert!(mock
+        .writes_to("POST", "api/issue/MOCK-1/comment")
+        .is_empty());
+
+    let result = server
+        .add_comment(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "comment_body": body,
+                "auto_truncate": true
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    let removed = result.truncated_chars.unwrap();
+
+    let writes = mock.writes_to("POST", "api/issue/MOCK-1/comment");
+    let written = writes[0].body.as_ref().unwrap()["body"].as_str().unwrap();
+    assert_eq!(written.chars().count(), 32_767);
+    assert!(written.ends_with(&format!(
+        "[… {} characters truncated to fit the length limit]",
+        removed
+    )));
+}
+
+#[tokio::test]
+async fn test_mock_create_issue() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .create_issue(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "summary": "Add retries to the gateway client",
+                "issue_type": "Task"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.issue_key, "MOCK-2");
+
+    let writes = mock.writes_to("POST", "api/issue");
+    assert_eq!(writes.len(), 1);
+    assert_eq!(
+        writes[0].body.as_ref().unwrap()["fields"]["summary"],
+        "Add retries to the gateway client"
+    );
+}
+
+#[tokio::test]
+async fn test_mock_create_issue_idempotency_key() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+    let params = json!({
+        "project_key": "MOCK",
+        "summary": "Rotate gateway credentials",
+        "idempotency_key": "mock-create-idempotency"
+    });
+
+    let first = server
+        .create_issue(serde_json::from_value(params.clone()).unwrap())
+        .await
+        .unwrap();
+    assert!(!first.replayed);
+
+    let retry = server
+        .create_issue(serde_json::from_value(params.clone()).unwrap())
+        .await
+        .unwrap();
+    assert!(retry.replayed);
+    assert_eq!(retry.issue_key, first.issue_key);
+    assert_eq!(mock.writes_to("POST", "api/issue").len(), 1);
+
+    // The key is persisted in the state directory and survives a restart
+    let restarted = mock_server().await;
+    let replay = restarted
+        .create_issue(serde_json::from_value(params).unwrap())
+        .await
+        .unwrap();
+    assert!(replay.replayed);
+    assert!(restarted
+        .mock_backend()
+        .unwrap()
+        .writes_to("POST", "api/issue")
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_mock_security_levels() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let details = server
+        .get_issue_details(serde_json::from_value(json!({"issue_key": "MOCK-2"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(
+        details.issue_details.issue_info.security_level.as_deref(),
+        Some("Internal")
+    );
+
+    let result = server
+        .update_security_level(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-2",
+                "security_level": "security team"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.previous_security_level.as_deref(), Some("Internal"));
+    assert_eq!(result.security_level.as_deref(), Some("Security Team"));
+    assert_eq!(result.available_levels.len(), 2);
+    assert_eq!(
+        mock.writes_to("PUT", "api/issue/MOCK-2")[0]
+            .body
+            .as_ref()
+            .unwrap()["fields"]["security"],
+        json!({"id": "10001"})
+    );
+
+    let error = server
+        .update_security_level(
+            serde_json::from_value(json!({"issue_key": "MOCK-2", "security_level": "Public"}))
+                .unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("Internal, Security Team"),
+        "{}",
+        error
+    );
+
+    server
+        .create_issue(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "summary": "Token leak in request logs",
+                "security_level": "Internal"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        mock.writes_to("POST", "api/issue")[0]
+            .body
+            .as_ref()
+            .unwrap()["fields"]["security"],
+        json!({"id": "10000"})
+    );
+}
+
+#[tokio::test]
+async fn test_mock_create_issue_project_defaults() {
+    let defaults = |components: &[&str]| JiraConfig {
+        project_defaults: HashMap::from([(
+            "MOCK".to_string(),
+            ProjectDefaults {
+                issue_type: Some("bug".to_string()),
+                labels: vec!["ai-created".to_string()],
+                components: components.iter().map(|c| c.to_string()).collect(),
+                custom_fields: HashMap::from([(
+                    "customfield_10400".to_string(),
+                    json!({"value": "Platform"}),
+                )]),
+                ..ProjectDefaults::default()
+            },
+        )]),
+        ..JiraConfig::default()
+    };
+    let server = mock_server_with("mock", defaults(&["backend"])).await;
+    let mock = server.mock_backend().unwrap();
+
+    // Explicit labels replace the default ones, the rest is filled in
+    let result = server
+        .create_issue(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "summary": "Checkout times out under load",
+                "labels": ["load-test"]
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.issue_type, "Bug");
+    assert_eq!(
+        result.applied_defaults.keys().collect::<Vec<_>>(),
+        ["components", "custom_fields", "issue_type"]
+    );
+    let fields = &mock.writes_to("POST", "api/issue")[0].body.clone().unwrap()["fields"];
+    assert_eq!(fields["labels"], json!(["load-test"]));
+    assert_eq!(fields["components"], json!([{"name": "backend"}]));
+    assert_eq!(fields["customfield_10400"], json!({"value": "Platform"}));
+
+    // Bulk create merges per item; an empty list counts as left out
+    let bulk = server
+        .bulk_create_issues(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "issues": [
+                    {"summary": "Retry storm on deploy"},
+                    {"summary": "Add a health check", "issue_type": "Task", "components": []}
+                ]
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    let applied: Vec<_> = bulk
+        .results
+        .iter()
+        .map(|r| r.issue.as_ref().unwrap().applied_defaults.clone())
+        .collect();
+    assert_eq!(applied[0]["labels"], json!(["ai-created"]));
+    assert!(!applied[1].contains_key("issue_type"));
+    assert_eq!(applied[1]["components"], json!(["backend"]));
+
+    // A default the create screen rejects fails the create, naming the setting
+    let server = mock_server_with("mock", defaults(&["database"])).await;
+    let error = server
+        .create_issue(
+            serde_json::from_value(json!({"project_key": "MOCK", "summary": "Slow queries"}))
+                .unwrap(),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains("project_defaults.MOCK.components")
+            && error.contains("Unknown component 'database'"),
+        "{}",
+        error
+    );
+    assert!(server
+        .mock_backend()
+        .unwrap()
+        .writes_to("POST", "api/issue")
+        .is_empty());
+
+    // Explicit components bypass the bad default
+    server
+        .create_issue(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "summary": "Slow queries",
+                "components": ["frontend"]
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_mock_create_bug_with_system_fields() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    // The Bug screen requires environment and affected versions
+    let metadata = server
+        .get_create_metadata(
+            serde_json::from_value(json!({"project_key": "MOCK", "issue_type": "Bug"})).unwrap(),
+        )
+        .await
+        .unwrap();
+    let versions = metadata.issue_types[0]
+        .all_fields
+        .iter()
+        .find(|field| field.field_id == "versions")
+        .unwrap();
+    assert_eq!(
+        versions.create_issue_param.as_deref(),
+        Some("affects_versions")
+    );
+    assert!(metadata.usage_hints.iter().any(|hint| hint
+        == "Bug issues also require: Environment (create_issue: environment), \
+            Affects Version/s (create_issue: affects_versions)"));
+
+    server
+        .create_issue(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "summary": "Checkout button unresponsive",
+                "issue_type": "Bug",
+                "environment": "Firefox 128 on Windows 11",
+                "affects_versions": ["1.1"],
+                "due_date": "2030-01-15"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    let fields = &mock.writes_to("POST", "api/issue")[0].body.clone().unwrap()["fields"];
+    assert_eq!(fields["environment"], "Firefox 128 on Windows 11");
+    assert_eq!(fields["versions"], json!([{"id": "10101"}]));
+    assert_eq!(fields["duedate"], "2030-01-15");
+
+    // Archived versions cannot be picked
+    let error = server
+        .create_issue(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "summary": "Crash on startup",
+                "issue_type": "Bug",
+                "affects_versions": ["0.9"]
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("Available: 1.0, 1.1"),
+        "{}",
+        error
+    );
+}
+
+#[tokio::test]
+async fn test_mock_create_issue_returns_full_issue() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .create_issue(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "summary": "Harden payment gateway retries",
+                "issue_type": "Task",
+                "story_points": 5,
+                "custom_fields": {"customfield_10300": "Retries stop after three attempts"}
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    // Story points go to the field detected from the catalog
+    assert_eq!(
+        result
+            .auto_detected_fields
+            .get("story_points")
+            .map(String::as_str),
+        Some("customfield_10002")
+    );
+    let fields = &mock.writes_to("POST", "api/issue")[0].body.clone().unwrap()["fields"];
+    assert_eq!(fields["customfield_10002"], json!(5.0));
+
+    // The values JIRA stored come back without a get_issue_details call
+    let issue = result.issue.as_ref().unwrap();
+    assert_eq!(issue.key, "MOCK-2");
+    assert_eq!(issue.story_points, Some(5.0));
+    assert_eq!(
+        issue.acceptance_criteria.as_deref(),
+        Some("Retries stop after three attempts")
+    );
+
+    let result = server
+        .create_issue(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "summary": "No echo needed",
+                "return_full_issue": false
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(result.issue.is_none());
+}
+
+#[tokio::test]
+async fn test_mock_create_issue_resolves_issue_type() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    server
+        .create_issue(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "summary": "Gateway retries exhaust the connection pool",
+                "issue_type": "bug"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let writes = mock.writes_to("POST", "api/issue");
+    assert_eq!(
+        writes[0].body.as_ref().unwrap()["fields"]["issuetype"]["name"],
+        "Bug"
+    );
+
+    let err = server
+        .create_issue(
+            serde_json::from_value(json!({
+                "project_key": "MOCK",
+                "summary": "Typo in the issue type",
+                "issue_type": "Tsk"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("Did you mean: Task"), "{}", err);
+}
+
+#[tokio::test]
+async fn test_mock_locale_priority_aliases() {
+    let config = JiraConfig {
+        locale_aliases: LocaleAliases {
+            priorities: HashMap::from([("high".to_string(), vec!["Élevée".to_string()])]),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let server = mock_server_with("mock_fr", config).await;
+
+    let result = server
+        .search_issues(
+            serde_json::from_value(json!({"project_key": "FR", "priority_filter": ["high"]}))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert!(result.jql_query.contains("Élevée"), "{}", result.jql_query);
+}
+
+#[tokio::test]
+async fn test_mock_votes() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let params = || serde_json::from_value(json!({"issue_key": "MOCK-1"})).unwrap();
+
+    let votes = server.get_votes(params()).await.unwrap();
+    assert_eq!(votes.votes, 1);
+    assert!(!votes.has_voted);
+
+    let result = server.add_vote(params()).await.unwrap();
+    assert!(result.has_voted);
+    assert_eq!(mock.writes_to("POST", "api/issue/MOCK-1/votes").len(), 1);
+
+    server.remove_vote(params()).await.unwrap();
+    assert_eq!(mock.writes_to("DELETE", "api/issue/MOCK-1/votes").len(), 1);
+}
+
+#[tokio::test]
+async fn test_mock_notify_issue() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    server
+        .notify_issue(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "body": "Gateway fix deployed",
+                "to": {"assignee": true}
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let writes = mock.writes_to("POST", "api/issue/MOCK-1/notify");
+    assert_eq!(writes.len(), 1);
+    assert_eq!(
+        writes[0].body.as_ref().unwrap()["to"],
+        json!({"assignee": true})
+    );
+}
+
+#[tokio::test]
+async fn test_mock_assign_by_name_or_email() {
+    let config = JiraConfig {
+        deployment_type: Some(DeploymentType::Cloud),
+        ..Default::default()
+    };
+    let server = mock_server_with("mock", config).await;
+    let mock = server.mock_backend().unwrap();
+    let assign = |assignee: &str| {
+        server.assign_issue(
+            serde_json::from_value(json!({"issue_key": "MOCK-1", "assignee": assignee})).unwrap(),
+        )
+    };
+
+    // Two active users are called Jane Doe, so the name alone is refused
+    let error = assign("Jane Doe").await.unwrap_err().to_string();
+    assert!(
+        error.contains("Several users share this display name"),
+        "{}",
+        error
+    );
+    assert!(error.contains("5b10a2844c20165700ede21a"), "{}", error);
+    assert!(error.contains("5b10a2844c20165700ede21b"), "{}", error);
+    assert!(mock.writes_to("PUT", "api/issue/MOCK-1").is_empty());
+
+    // The refused search cached both namesakes by email, so this needs no search
+    let result = assign("Jane.Doe@example.com").await.unwrap().into_inner();
+    let resolved = result.resolved.unwrap();
+    assert_eq!(resolved.account_id, "5b10a2844c20165700ede21a");
+    assert_eq!(resolved.matched_by, "cache");
+    assert_eq!(result.assignee, "Jane Doe");
+    assert_eq!(
+        mock.writes_to("PUT", "api/issue/MOCK-1")[0]
+            .body
+            .as_ref()
+            .unwrap()["fields"]["assignee"],
+        json!({"accountId": "5b10a2844c20165700ede21a"})
+    );
+
+    // The inactive namesake is ignored
+    let resolved = assign("Sam Smith")
+        .await
+        .unwrap()
+        .into_inner()
+        .resolved
+        .unwrap();
+    assert_eq!(resolved.account_id, "5b10a2844c20165700ede21c");
+    assert_eq!(resolved.matched_by, "display_name");
+
+    // Search results are cached by email
+    let resolved = assign("sam.smith@example.com")
+        .await
+        .unwrap()
+        .into_inner()
+        .resolved
+        .unwrap();
+    assert_eq!(resolved.account_id, "5b10a2844c20165700ede21c");
+    assert_eq!(resolved.matched_by, "cache");
+
+    let resolved = assign("5b10a2844c20165700ede21b")
+        .await
+        .unwrap()
+        .into_inner()
+        .resolved
+        .unwrap();
+    assert_eq!(resolved.matched_by, "account_id");
+}
+
+#[tokio::test]
+async fn test_mock_update_description_section() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let updated = server
+        .get_issue_details(serde_json::from_value(json!({"issue_key": "MOCK-1"})).unwrap())
+        .await
+        .unwrap()
+        .into_inner()
+        .issue_details
+        .issue_info
+        .updated;
+    let result = server
+        .update_description_section(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "section_heading": "Investigation",
+                "content": "Gateway connection pool exhausted",
+                "mode": "create_if_missing",
+                "expected_updated": updated
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(result.created);
+    let expected = "Payments time out under load.\n\n- [ ] Reproduce in staging\n- [x] Collect gateway logs\n\n## Investigation\n\nGateway connection pool exhausted\n";
+    assert_eq!(result.new_description, expected);
+    assert_eq!(
+        mock.writes_to("PUT", "api/issue/MOCK-1")[0]
+            .body
+            .as_ref()
+            .unwrap()["fields"]["description"],
+        json!(expected)
+    );
+
+    // Missing sections and stale reads are refused before writing
+    for (params, message) in [
+        (
+            json!({"issue_key": "MOCK-1", "section_heading": "Investigation", "content": "x"}),
+            "create_if_missing",
+        ),
+        (
+            json!({
+                "issue_key": "MOCK-1",
+                "section_heading": "Investigation",
+                "content": "x",
+                "mode": "create_if_missing",
+                "expected_updated": "2025-12-01T08:00:00.000+0000"
+            }),
+            "re-read the description",
+        ),
+    ] {
+        let error = server
+            .update_description_section(serde_json::from_value(params).unwrap())
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains(message), "{}", error);
+    }
+    assert_eq!(mock.writes_to("PUT", "api/issue/MOCK-1").len(), 1);
+}
+
+#[tokio::test]
+async fn test_mock_description_metadata() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .get_description_metadata(serde_json::from_value(json!({"issue_key": "MOCK-3"})).unwrap())
+        .await
+        .unwrap()
+        .into_inner();
+    let table = &result.tables[0];
+    assert_eq!(table.format, TableFormat::Wiki);
+    assert_eq!(table.headers, vec!["Key", "Value"]);
+    assert_eq!(table.rows.len(), 2);
+    assert_eq!(table.rows[1]["Value"], "3 retries");
+    // The short "Rollout" row is reported, not dropped silently
+    assert_eq!(result.problems.len(), 1);
+    assert_eq!(result.problems[0].line, Some(6));
+
+    let result = server
+        .update_description_metadata(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-3",
+                "table_index": 0,
+                "row_key": "budget",
+                "value": "5 retries"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.previous_value, "3 retries");
+    let expected = "Retry budget for the gateway client.\n\n|| Key || Value ||\n| Owner | [~mock.user] |\n| Budget | 5 retries |\n| Rollout |\n";
+    assert_eq!(result.new_description, expected);
+    assert_eq!(
+        mock.writes_to("PUT", "api/issue/MOCK-3")[0]
+            .body
+            .as_ref()
+            .unwrap()["fields"]["description"],
+        json!(expected)
+    );
+
+    for (params, message) in [
+        (
+            json!({"issue_key": "MOCK-3", "table_index": 0, "row_key": "Rollout", "value": "x"}),
+            "No row 'Rollout'",
+        ),
+        (
+            json!({"issue_key": "MOCK-3", "table_index": 0, "row_key": "Owner", "column": "Due", "value": "x"}),
+            "No column 'Due'",
+        ),
+        (
+            json!({"issue_key": "MOCK-3", "table_index": 2, "row_key": "Owner", "value": "x"}),
+            "has 1 table(s)",
+        ),
+    ] {
+        let error = server
+            .update_description_metadata(serde_json::from_value(params).unwrap())
+            .await
+            .unwrap_err();
+        assert!(error.to_string().contains(message), "{}", error);
+    }
+    assert_eq!(mock.writes_to("PUT", "api/issue/MOCK-3").len(), 1);
+}
+
+#[tokio::test]
+async fn test_mock_set_reporter() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .set_reporter(
+            serde_json::from_value(json!({"issue_key": "MOCK-2", "reporter": "Sam Smith"}))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.previous_reporter.as_deref(), Some("Mock User"));
+    assert_eq!(result.reporter.matched_by, "display_name");
+    // Server identifies users by username
+    assert_eq!(
+        mock.writes_to("PUT", "api/issue/MOCK-2")[0]
+            .body
+            .as_ref()
+            .unwrap()["fields"]["reporter"],
+        json!({"name": "ssmith"})
+    );
+
+    // MOCK-1's edit screen has no Reporter field
+    let error = server
+        .set_reporter(
+            serde_json::from_value(json!({"issue_key": "MOCK-1", "reporter": "me"})).unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("not on the edit screen"),
+        "{}",
+        error
+    );
+    assert!(mock.writes_to("PUT", "api/issue/MOCK-1").is_empty());
+
+    // The bulk path resolves a reporter name once for every issue
+    server
+        .bulk_update_fields(
+            serde_json::from_value(json!({
+                "issue_keys": ["MOCK-1", "MOCK-2"],
+                "field_updates": {"reporter": "jane.doe@example.com"}
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    let write = &mock.writes_to("PUT", "api/issue/MOCK-1")[0];
+    assert_eq!(
+        write.body.as_ref().unwrap()["fields"]["reporter"],
+        json!({"name": "jdoe"})
+    );
+}
+
+#[tokio::test]
+async fn test_mock_custom_fields_edit_screen() {
+    let server = mock_server().await;
+
+    let result = server
+        .get_custom_fields(serde_json::from_value(json!({"issue_key": "MOCK-1"})).unwrap())
+        .await
+        .unwrap();
+    let field = |id: &str| {
+        result
+            .custom_fields
+            .iter()
+            .find(|field| field.field_id == id)
+            .unwrap()
+    };
+
+    let story_points = field("customfield_10002");
+    assert!(story_points.editable);
+    assert_eq!(story_points.operations, vec!["set"]);
+    assert_eq!(story_points.field_name.as_deref(), Some("Story Points"));
+    assert_eq!(
+        field("customfield_10400").allowed_values,
+        Some(vec!["Sev 1".to_string(), "Sev 2".to_string()])
+    );
+    assert!(!field("customfield_10500").editable);
+    assert_eq!(
+        result.detected_mappings.story_points_field.as_deref(),
+        Some("customfield_10002")
+    );
+
+    // A field missing from the edit screen is rejected before anything is written
+    let mock = server.mock_backend().unwrap();
+    let error = server
+        .update_custom_fields(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "custom_field_updates": {"customfield_10500": "changed"}
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(
+        error.to_string().contains("not on the edit screen"),
+        "{}",
+        error
+    );
+    assert!(mock.writes_to("PUT", "api/issue/MOCK-1").is_empty());
+
+    server
+        .update_custom_fields(
+            serde_json::from_value(json!({"issue_key": "MOCK-1", "story_points": 5})).unwrap(),
+        )
+        .await
+        .unwrap();
+    let writes = mock.writes_to("PUT", "api/issue/MOCK-1");
+    assert_eq!(
+        writes[0].body.as_ref().unwrap()["fields"]["customfield_10002"],
+        json!(5.0)
+    );
+}
+
+#[tokio::test]
+async fn test_mock_add_comment_as_adf_on_cloud() {
+    let config = JiraConfig {
+        deployment_type: Some(DeploymentType::Cloud),
+        ..Default::default()
+    };
+    let server = mock_server_with("mock", config).await;
+    let mock = server.mock_backend().unwrap();
+
+    server
+        .add_comment(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "comment_body": "Fixed by:\n\n```bash\nmake migrate\n```",
+                "mentions": ["me"],
+                "adf": true
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let writes = mock.writes_to("POST", "api/issue/MOCK-1/comment");
+    let body = &writes[0].body.as_ref().unwrap()["body"];
+    assert_eq!(body["type"], "doc");
+    assert_eq!(
+        body["content"][0]["content"][0],
+        json!({"type": "mention", "attrs": {"id": "mock-user"}})
+    );
+    assert_eq!(body["content"][0]["content"][2]["text"], "Fixed by:");
+    assert_eq!(body["content"][1]["type"], "codeBlock");
+    assert_eq!(body["content"][1]["attrs"]["language"], "bash");
+    assert_eq!(body["content"][1]["content"][0]["text"], "make migrate");
+}
+
+#[tokio::test]
+async fn test_mock_manage_labels() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .manage_labels(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "add_labels": ["gateway"],
+                "remove_labels": ["flaky"]
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.added, ["gateway"]);
+    assert_eq!(result.current_labels, ["payments"]);
+    assert_eq!(
+        mock.writes_to("PUT", "api/issue/MOCK-1")[0].body,
+        Some(json!({"update": {"labels": [{"add": "gateway"}, {"remove": "flaky"}]}}))
+    );
+
+    server
+        .manage_labels(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "add_labels": ["payments", "gateway"],
+                "replace_all": true
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        mock.writes_to("PUT", "api/issue/MOCK-1")[1].body,
+        Some(json!({"fields": {"labels": ["payments", "gateway"]}}))
+    );
+
+    let err = server
+        .manage_labels(serde_json::from_value(json!({"issue_key": "MOCK-1"})).unwrap())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("at least one label"), "{}", err);
+    assert_eq!(mock.writes_to("PUT", "api/issue/MOCK-1").len(), 2);
+
+    // Instance-wide labels come from /label, project labels from a search
+    let labels = server
+        .get_available_labels(serde_json::from_value(json!({})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(labels.labels, ["backend", "gateway", "payments"]);
+    assert!(labels.is_last);
+    let labels = server
+        .get_available_labels(serde_json::from_value(json!({"project_key": "MOCK"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(labels.labels, ["payments"]);
+}
+
+#[tokio::test]
+async fn test_mock_update_components() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let available = server
+        .get_available_components(serde_json::from_value(json!({"project_key": "MOCK"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(available.total, 2);
+    assert_eq!(
+        available.components[0].description.as_deref(),
+        Some("Cart, checkout and payment flow")
+    );
+
+    // Numeric entries are sent as IDs, anything else as names
+    let result = server
+        .update_components(
+            serde_json::from_value(json!({"issue_key": "MOCK-1", "components": ["Checkout", "2"]}))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.components[0].name, "Checkout");
+    assert_eq!(
+        mock.writes_to("PUT", "api/issue/MOCK-1")[0].body,
+        Some(json!({"fields": {"components": [{"name": "Checkout"}, {"id": "2"}]}}))
+    );
+
+    let result = server
+        .update_components(
+            serde_json::from_value(json!({"issue_key": "MOCK-1", "components": []})).unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(result.message.contains("cleared"), "{}", result.message);
+    assert_eq!(
+        mock.writes_to("PUT", "api/issue/MOCK-1")[1].body,
+        Some(json!({"fields": {"components": []}}))
+    );
+}
+
+#[tokio::test]
+async fn test_mock_link_and_unlink_issues() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .link_issues(
+            serde_json::from_value(json!({
+                "inward_issue_key": "MOCK-1",
+                "outward_issue_key": "MOCK-2",
+                "link_type": "Blocks",
+                "comment": "Gateway fix first"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(result.success);
+    let link = mock.writes_to("POST", "api/issueLink")[0]
+        .body
+        .clone()
+        .unwrap();
+    assert_eq!(link["type"]["name"], "Blocks");
+    assert_eq!(link["inwardIssue"]["key"], "MOCK-1");
+    assert_eq!(link["outwardIssue"]["key"], "MOCK-2");
+    // gouqi always sends link comments as ADF
+    assert_eq!(
+        link["comment"]["body"]["content"][0]["content"][0]["text"],
+        "Gateway fix first"
+    );
+
+    let err = server
+        .link_issues(
+            serde_json::from_value(json!({
+                "inward_issue_key": "MOCK-1",
+                "outward_issue_key": "MOCK-2",
+                "link_type": " "
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("link_type"), "{}", err);
+
+    server
+        .delete_issue_link(serde_json::from_value(json!({"link_id": "10100"})).unwrap())
+        .await
+        .unwrap();
+    assert_eq!(mock.writes_to("DELETE", "api/issueLink/10100").len(), 1);
+    assert_eq!(mock.writes().len(), 2);
+}
+
+#[tokio::test]
+async fn test_mock_archive_issue() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .archive_issue(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "reason": "duplicate of MOCK-2",
+                "remove_from_sprint": true
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(result.archived, "{}", result.message);
+    let steps: Vec<&str> = result.steps.iter().map(|s| s.step.as_str()).collect();
+    assert_eq!(
+        steps,
+        ["label", "transition", "comment", "remove_from_sprint"]
+    );
+
+    assert_eq!(
+        mock.writes_to("PUT", "api/issue/MOCK-1")[0].body,
+        Some(json!({"update": {"labels": [{"add": "archived"}]}}))
+    );
+    assert_eq!(
+        mock.writes_to("POST", "api/issue/MOCK-1/transitions")[0]
+            .body
+            .as_ref()
+            .unwrap()["transition"]["id"],
+        "31"
+    );
+    assert_eq!(
+        mock.writes_to("POST", "api/issue/MOCK-1/comment")[0]
+            .body
+            .as_ref()
+            .unwrap()["body"],
+        "Archived: duplicate of MOCK-2"
+    );
+    assert_eq!(
+        mock.writes_to("POST", "agile/backlog/issue")[0].body,
+        Some(json!({"issues": ["MOCK-1"]}))
+    );
+}
+
+#[tokio::test]
+async fn test_mock_undo_description_change() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+    let undo =
+        || server.undo_last_change(serde_json::from_value(json!({"issue_key": "MOCK-1"})).unwrap());
+
+    let err = undo().await.unwrap_err();
+    assert!(err.to_string().contains("No undo history"), "{}", err);
+
+    let result = server
+        .update_issue_description(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "content": "Rolled back the gateway config"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert!(result
+        .new_description
+        .ends_with("\n\nRolled back the gateway config"));
+    assert_eq!(mock.writes_to("PUT", "api/issue/MOCK-1").len(), 1);
+
+    // The fixture still holds the old description, as if someone edited it since
+    let result = undo().await.unwrap();
+    assert!(!result.restored);
+    assert_eq!(result.source, "update_issue_description");
+    assert_eq!(result.conflicts[0].field, "description");
+    assert_eq!(mock.writes_to("PUT", "api/issue/MOCK-1").len(), 1);
+
+    // Rewriting the stored text leaves nothing in the way of restoring it
+    let current = result.conflicts[0].current.as_str().unwrap().to_string();
+    server
+        .update_issue_description(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "content": current,
+                "mode": "replace"
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    let result = undo().await.unwrap();
+    assert!(result.restored, "{}", result.message);
+    assert_eq!(result.remaining_undo_entries, 1);
+    assert_eq!(
+        mock.writes_to("PUT", "api/issue/MOCK-1")[2].body,
+        Some(json!({"fields": {"description": current}}))
+    );
+}
+
+#[tokio::test]
+async fn test_mock_upload_attachment() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .upload_attachment(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "files": [{"filename": "retry-budget.md", "content_base64": "VGhyZWUgcmV0cmllcyBtYXgK"}]
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.total_count, 1);
+    assert_eq!(result.total_bytes, 18);
+    assert_eq!(result.uploaded_attachments[0].author, "Mock User");
+    assert_eq!(
+        mock.writes_to("POST", "api/issue/MOCK-1/attachments")[0].body,
+        Some(json!({"files": [{"filename": "retry-budget.md", "size": 18}]}))
+    );
+
+    // Oversized uploads are refused before anything is sent
+    let err = server
+        .upload_attachment(
+            serde_json::from_value(json!({
+                "issue_key": "MOCK-1",
+                "files": [{"filename": "retry-budget.md", "content_base64": "VGhyZWUgcmV0cmllcyBtYXgK"}],
+                "max_total_size_bytes": 10
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("exceeds maximum"), "{}", err);
+    assert_eq!(mock.writes().len(), 1);
+}
+
+#[tokio::test]
+async fn test_mock_bulk_assign_and_transition() {
+    let server = mock_server().await;
+    let mock = server.mock_backend().unwrap();
+
+    let result = server
+        .bulk_assign_issues(
+            serde_json::from_value(json!({
+                "issue_keys": ["MOCK-1", "MOCK-2"],
+                "assignee": "me",
+                "max_retries": 0
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.success_count, 2);
+    assert_eq!(result.assignee, "mock.user");
+    for key in ["MOCK-1", "MOCK-2"] {
+        let writes = mock.writes_to("PUT", &format!("api/issue/{}", key));
+        assert_eq!(
+            writes[0].body,
+            Some(json!({"fields": {"assignee": {"name": "mock.user"}}}))
+        );
+    }
+
+    let result = server
+        .bulk_transition_issues(
+            serde_json::from_value(json!({
+                "issue_keys": ["MOCK-1", "MOCK-2"],
+                "transition_name": "Start Progress",
+                "max_retries": 0
+            }))
+            .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(result.success_count, 2, "{:?}", result.results);
+    assert!(result
+        .results
+        .iter()
+        .all(|r| r.new_status.as_deref() == Some("In Progress")));
+    for key in ["MOCK-1", "MOCK-2"] {
+        let writes = mock.writes_to("POST", &format!("api/issue/{}/transitions", key));
+        assert_eq!(writes[0].body.as_ref().unwrap()["transition"]["id"], "11");
+    }
+
+    let err = server
+        .bulk_transition_issues(serde_json::from_value(json!({"issue_keys": ["MOCK-1"]})).unwrap())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("transition_id"), "{}", err);
+}